@@ -0,0 +1,304 @@
+use std::path::Path;
+
+use anyhow::{ensure, Result};
+use rewards_merkle_tree::{read_allocations_file, validate_allocations, Allocation};
+use rewards_program_client::{
+    instructions::{
+        AddDirectRecipientsBuilder, ClaimDirectBuilder, CloseDirectDistributionBuilder,
+        CreateDirectDistributionBuilder, DecreaseDirectAllocationBuilder, IncreaseDirectAllocationBuilder,
+        RevokeDirectRecipientBuilder,
+    },
+    types::{ClosePolicy, DirectRecipientEntry, RevokeMode, TransferFeeMode},
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signer};
+use spl_associated_token_account_interface::{
+    address::get_associated_token_address_with_program_id, instruction::create_associated_token_account_idempotent,
+};
+
+use super::{fetch_direct_distribution, fetch_direct_recipient, seed_keypair};
+use crate::{
+    config::Config,
+    pda::{find_direct_distribution_pda, find_direct_recipient_pda, find_distribution_vault, find_event_authority_pda},
+    schedule::to_client_schedule,
+    WindowArgs,
+};
+
+pub fn create_direct(
+    config: &Config,
+    mint: &Pubkey,
+    revocable: u8,
+    window: WindowArgs,
+    protect_recipients: bool,
+    seed: Option<&Path>,
+) -> Result<()> {
+    let authority = config.payer.pubkey();
+    let seed = seed_keypair(seed)?;
+    let token_program = config.token_program(mint)?;
+    let (distribution, bump) = find_direct_distribution_pda(mint, &authority, &seed.pubkey());
+    let (event_authority, _) = find_event_authority_pda();
+
+    let mut builder = CreateDirectDistributionBuilder::new();
+    builder
+        .payer(authority)
+        .authority(authority)
+        .seeds(seed.pubkey())
+        .distribution(distribution)
+        .mint(*mint)
+        .distribution_vault(find_distribution_vault(&distribution, mint, &token_program))
+        .token_program(token_program)
+        .event_authority(event_authority)
+        .bump(bump)
+        .revocable(revocable)
+        .clawback_ts(window.clawback_ts)
+        .close_policy(if protect_recipients { ClosePolicy::ProtectRecipients } else { ClosePolicy::Sweep })
+        .claim_start_ts(window.claim_start_ts)
+        .claim_end_ts(window.claim_end_ts);
+
+    println!("Distribution: {distribution}");
+    println!("Seed: {}", seed.pubkey());
+    config.process(&[builder.instruction()], &[&seed])
+}
+
+pub fn add_recipients(config: &Config, distribution: &Pubkey, file: &Path, batch_size: usize) -> Result<()> {
+    let allocations = validate_allocations(&read_allocations_file(file)?)?;
+    let batches = recipient_batches(distribution, &allocations, batch_size)?;
+
+    let mint = fetch_direct_distribution(config, distribution)?.mint;
+    let token_program = config.token_program(&mint)?;
+    let authority = config.payer.pubkey();
+    let (event_authority, _) = find_event_authority_pda();
+
+    for (batch_index, batch) in batches.into_iter().enumerate() {
+        let recipient_count = batch.entries.len();
+        let mut builder = AddDirectRecipientsBuilder::new();
+        builder
+            .payer(authority)
+            .authority(authority)
+            .distribution(*distribution)
+            .mint(mint)
+            .distribution_vault(find_distribution_vault(distribution, &mint, &token_program))
+            .authority_token_account(get_associated_token_address_with_program_id(&authority, &mint, &token_program))
+            .token_program(token_program)
+            .event_authority(event_authority)
+            .recipients(batch.entries)
+            .add_remaining_accounts(&batch.remaining_accounts);
+
+        println!("Batch {}: {} recipients", batch_index + 1, recipient_count);
+        config.process(&[builder.instruction()], &[])?;
+    }
+
+    Ok(())
+}
+
+/// Entries and `(recipient PDA, recipient)` account pairs for one `AddDirectRecipients` transaction
+struct RecipientBatch {
+    entries: Vec<DirectRecipientEntry>,
+    remaining_accounts: Vec<AccountMeta>,
+}
+
+/// Split `allocations` into batches of at most `batch_size` recipients, keeping file order
+fn recipient_batches(
+    distribution: &Pubkey,
+    allocations: &[Allocation],
+    batch_size: usize,
+) -> Result<Vec<RecipientBatch>> {
+    ensure!(batch_size > 0, "--batch-size must be at least 1");
+
+    allocations
+        .chunks(batch_size)
+        .map(|batch| {
+            let mut entries = Vec::with_capacity(batch.len());
+            let mut remaining_accounts = Vec::with_capacity(batch.len() * 2);
+            for allocation in batch {
+                let recipient = Pubkey::new_from_array(allocation.claimant.to_bytes());
+                let (recipient_account, bump) = find_direct_recipient_pda(distribution, &recipient);
+                entries.push(DirectRecipientEntry {
+                    bump,
+                    amount: allocation.amount,
+                    schedule: to_client_schedule(&allocation.schedule)?,
+                });
+                remaining_accounts.push(AccountMeta::new(recipient_account, false));
+                remaining_accounts.push(AccountMeta::new_readonly(recipient, false));
+            }
+            Ok(RecipientBatch { entries, remaining_accounts })
+        })
+        .collect()
+}
+
+pub fn claim_direct(config: &Config, distribution: &Pubkey, amount: u64) -> Result<()> {
+    let mint = fetch_direct_distribution(config, distribution)?.mint;
+    let token_program = config.token_program(&mint)?;
+    let recipient = config.payer.pubkey();
+    let (recipient_account, _) = find_direct_recipient_pda(distribution, &recipient);
+    let (event_authority, _) = find_event_authority_pda();
+
+    let mut builder = ClaimDirectBuilder::new();
+    builder
+        .recipient(recipient)
+        .distribution(*distribution)
+        .recipient_account(recipient_account)
+        .mint(mint)
+        .distribution_vault(find_distribution_vault(distribution, &mint, &token_program))
+        .recipient_token_account(get_associated_token_address_with_program_id(&recipient, &mint, &token_program))
+        .token_program(token_program)
+        .event_authority(event_authority)
+        .amount(amount)
+        .fee_mode(TransferFeeMode::Gross);
+
+    let create_ata = create_associated_token_account_idempotent(&recipient, &recipient, &mint, &token_program);
+    config.process(&[create_ata, builder.instruction()], &[])
+}
+
+pub fn revoke_direct(
+    config: &Config,
+    distribution: &Pubkey,
+    recipient: &Pubkey,
+    revoke_mode: RevokeMode,
+) -> Result<()> {
+    let mint = fetch_direct_distribution(config, distribution)?.mint;
+    let token_program = config.token_program(&mint)?;
+    let authority = config.payer.pubkey();
+    let (recipient_account, _) = find_direct_recipient_pda(distribution, recipient);
+    let original_payer = fetch_direct_recipient(config, &recipient_account)?.payer;
+    let recipient_token_account = get_associated_token_address_with_program_id(recipient, &mint, &token_program);
+    let (event_authority, _) = find_event_authority_pda();
+
+    let mut builder = RevokeDirectRecipientBuilder::new();
+    builder
+        .authority(authority)
+        .distribution(*distribution)
+        .recipient_account(recipient_account)
+        .recipient(*recipient)
+        .original_payer(original_payer)
+        .mint(mint)
+        .distribution_vault(find_distribution_vault(distribution, &mint, &token_program))
+        .recipient_token_account(recipient_token_account)
+        .authority_token_account(get_associated_token_address_with_program_id(&authority, &mint, &token_program))
+        .token_program(token_program)
+        .event_authority(event_authority)
+        .revoke_mode(revoke_mode);
+
+    let create_ata = create_associated_token_account_idempotent(&authority, recipient, &mint, &token_program);
+    config.process(&[create_ata, builder.instruction()], &[])
+}
+
+pub fn increase_direct(config: &Config, distribution: &Pubkey, recipient: &Pubkey, amount: u64) -> Result<()> {
+    let mint = fetch_direct_distribution(config, distribution)?.mint;
+    let token_program = config.token_program(&mint)?;
+    let authority = config.payer.pubkey();
+    let (recipient_account, _) = find_direct_recipient_pda(distribution, recipient);
+    let (event_authority, _) = find_event_authority_pda();
+
+    let mut builder = IncreaseDirectAllocationBuilder::new();
+    builder
+        .payer(authority)
+        .authority(authority)
+        .distribution(*distribution)
+        .recipient_account(recipient_account)
+        .recipient(*recipient)
+        .mint(mint)
+        .distribution_vault(find_distribution_vault(distribution, &mint, &token_program))
+        .authority_token_account(get_associated_token_address_with_program_id(&authority, &mint, &token_program))
+        .token_program(token_program)
+        .event_authority(event_authority)
+        .amount(amount);
+
+    config.process(&[builder.instruction()], &[])
+}
+
+pub fn decrease_direct(config: &Config, distribution: &Pubkey, recipient: &Pubkey, amount: u64) -> Result<()> {
+    let mint = fetch_direct_distribution(config, distribution)?.mint;
+    let token_program = config.token_program(&mint)?;
+    let authority = config.payer.pubkey();
+    let authority_token_account = get_associated_token_address_with_program_id(&authority, &mint, &token_program);
+    let (recipient_account, _) = find_direct_recipient_pda(distribution, recipient);
+    let (event_authority, _) = find_event_authority_pda();
+
+    let mut builder = DecreaseDirectAllocationBuilder::new();
+    builder
+        .authority(authority)
+        .distribution(*distribution)
+        .recipient_account(recipient_account)
+        .recipient(*recipient)
+        .mint(mint)
+        .distribution_vault(find_distribution_vault(distribution, &mint, &token_program))
+        .authority_token_account(authority_token_account)
+        .token_program(token_program)
+        .event_authority(event_authority)
+        .amount(amount);
+
+    let create_ata = create_associated_token_account_idempotent(&authority, &authority, &mint, &token_program);
+    config.process(&[create_ata, builder.instruction()], &[])
+}
+
+pub fn close_direct(config: &Config, distribution: &Pubkey) -> Result<()> {
+    let mint = fetch_direct_distribution(config, distribution)?.mint;
+    let token_program = config.token_program(&mint)?;
+    let authority = config.payer.pubkey();
+    let authority_token_account = get_associated_token_address_with_program_id(&authority, &mint, &token_program);
+    let (event_authority, _) = find_event_authority_pda();
+
+    let mut builder = CloseDirectDistributionBuilder::new();
+    builder
+        .authority(authority)
+        .distribution(*distribution)
+        .mint(mint)
+        .distribution_vault(find_distribution_vault(distribution, &mint, &token_program))
+        .authority_token_account(authority_token_account)
+        .token_program(token_program)
+        .event_authority(event_authority);
+
+    let create_ata = create_associated_token_account_idempotent(&authority, &authority, &mint, &token_program);
+    config.process(&[create_ata, builder.instruction()], &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use rewards_program::utils::VestingSchedule as ProgramVestingSchedule;
+    use rewards_program_client::types::VestingSchedule;
+
+    use super::*;
+
+    fn allocations(count: usize) -> Vec<Allocation> {
+        (0..count)
+            .map(|i| Allocation {
+                claimant: [i as u8 + 1; 32].into(),
+                amount: 1_000 * (i as u64 + 1),
+                schedule: ProgramVestingSchedule::Linear { start_ts: 100, end_ts: 200 },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_recipient_batches_splits_in_order() {
+        let distribution = Pubkey::new_unique();
+        let batches = recipient_batches(&distribution, &allocations(5), 2).unwrap();
+
+        assert_eq!(batches.iter().map(|batch| batch.entries.len()).collect::<Vec<_>>(), vec![2, 2, 1]);
+        let amounts: Vec<u64> =
+            batches.iter().flat_map(|batch| batch.entries.iter().map(|entry| entry.amount)).collect();
+        assert_eq!(amounts, vec![1_000, 2_000, 3_000, 4_000, 5_000]);
+    }
+
+    #[test]
+    fn test_recipient_batches_account_pairs() {
+        let distribution = Pubkey::new_unique();
+        let batches = recipient_batches(&distribution, &allocations(2), 8).unwrap();
+        assert_eq!(batches.len(), 1);
+
+        let batch = &batches[0];
+        assert_eq!(batch.remaining_accounts.len(), 4);
+        for (entry, pair) in batch.entries.iter().zip(batch.remaining_accounts.chunks_exact(2)) {
+            let (recipient_account, bump) = find_direct_recipient_pda(&distribution, &pair[1].pubkey);
+            assert_eq!(pair[0], AccountMeta::new(recipient_account, false));
+            assert!(!pair[1].is_writable && !pair[1].is_signer);
+            assert_eq!(entry.bump, bump);
+            assert_eq!(entry.schedule, VestingSchedule::Linear { start_ts: 100, end_ts: 200 });
+        }
+    }
+
+    #[test]
+    fn test_recipient_batches_rejects_zero_batch_size() {
+        assert!(recipient_batches(&Pubkey::new_unique(), &allocations(1), 0).is_err());
+    }
+}
@@ -0,0 +1,79 @@
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use rewards_merkle_tree::hex;
+use rewards_program::{
+    state::CompressedDistribution as CompressedDistributionState, traits::RewardsAccountDiscriminators,
+};
+use rewards_program_client::{
+    accounts::{
+        BasketDistribution, BasketRecipient, ClaimDelegate, CompressedDistribution, DirectDistribution,
+        DirectRecipient, MerkleClaim, MerkleClaimBitmap, MerkleDistribution, MerkleRedirect, MerkleRevocation,
+    },
+    REWARDS_PROGRAM_ID,
+};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::config::Config;
+
+const DIRECT_DISTRIBUTION: u8 = RewardsAccountDiscriminators::DirectDistribution as u8;
+const DIRECT_RECIPIENT: u8 = RewardsAccountDiscriminators::DirectRecipient as u8;
+const MERKLE_DISTRIBUTION: u8 = RewardsAccountDiscriminators::MerkleDistribution as u8;
+const MERKLE_CLAIM: u8 = RewardsAccountDiscriminators::MerkleClaim as u8;
+const MERKLE_REVOCATION: u8 = RewardsAccountDiscriminators::MerkleRevocation as u8;
+const CLAIM_DELEGATE: u8 = RewardsAccountDiscriminators::ClaimDelegate as u8;
+const MERKLE_REDIRECT: u8 = RewardsAccountDiscriminators::MerkleRedirect as u8;
+const BASKET_DISTRIBUTION: u8 = RewardsAccountDiscriminators::BasketDistribution as u8;
+const BASKET_RECIPIENT: u8 = RewardsAccountDiscriminators::BasketRecipient as u8;
+const MERKLE_CLAIM_BITMAP: u8 = RewardsAccountDiscriminators::MerkleClaimBitmap as u8;
+const COMPRESSED_DISTRIBUTION: u8 = RewardsAccountDiscriminators::CompressedDistribution as u8;
+
+/// Fetch `address` and print it decoded according to its discriminator
+pub fn inspect(config: &Config, address: &Pubkey) -> Result<()> {
+    let account = config.rpc.get_account(address).with_context(|| format!("Failed to fetch account {address}"))?;
+    ensure!(
+        account.owner == REWARDS_PROGRAM_ID,
+        "Account {address} is owned by {}, not the Rewards Program",
+        account.owner
+    );
+
+    let data = account.data.as_slice();
+    let Some(&discriminator) = data.first() else {
+        bail!("Account {address} is empty");
+    };
+
+    println!("Address: {address}");
+    println!("Lamports: {}", account.lamports);
+    match discriminator {
+        DIRECT_DISTRIBUTION => println!("DirectDistribution {:#?}", DirectDistribution::from_bytes(data)?),
+        DIRECT_RECIPIENT => println!("DirectRecipient {:#?}", DirectRecipient::from_bytes(data)?),
+        MERKLE_DISTRIBUTION => {
+            let distribution = MerkleDistribution::from_bytes(data)?;
+            println!("Merkle root: {}", hex::encode(&distribution.merkle_root));
+            println!("MerkleDistribution {distribution:#?}");
+        }
+        MERKLE_CLAIM => println!("MerkleClaim {:#?}", MerkleClaim::from_bytes(data)?),
+        MERKLE_REVOCATION => println!("MerkleRevocation {:#?}", MerkleRevocation::from_bytes(data)?),
+        CLAIM_DELEGATE => println!("ClaimDelegate {:#?}", ClaimDelegate::from_bytes(data)?),
+        MERKLE_REDIRECT => println!("MerkleRedirect {:#?}", MerkleRedirect::from_bytes(data)?),
+        BASKET_DISTRIBUTION => println!("BasketDistribution {:#?}", BasketDistribution::from_bytes(data)?),
+        BASKET_RECIPIENT => println!("BasketRecipient {:#?}", BasketRecipient::from_bytes(data)?),
+        MERKLE_CLAIM_BITMAP => {
+            // The raw bits are 1 KiB; summarize them instead of dumping every byte
+            let bitmap = MerkleClaimBitmap::from_bytes(data)?;
+            let claimed: u32 = bitmap.bits.iter().map(|byte| byte.count_ones()).sum();
+            println!("MerkleClaimBitmap");
+            println!("  chunk_index: {}", bitmap.chunk_index);
+            println!("  payer: {}", bitmap.payer);
+            println!("  claimed_leaves: {claimed}");
+        }
+        COMPRESSED_DISTRIBUTION => {
+            // The header is followed by the recipient tree; print its summary, not the raw buffers
+            let tree = CompressedDistributionState::tree(data).map_err(|e| anyhow!("Invalid tree: {e:?}"))?;
+            println!("Tree root: {}", hex::encode(&tree.root()));
+            println!("Tree leaves: {}", tree.num_leaves());
+            println!("Tree sequence number: {}", tree.sequence_number());
+            println!("CompressedDistribution {:#?}", CompressedDistribution::from_bytes(data)?);
+        }
+        other => bail!("Unknown account discriminator {other}"),
+    }
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use std::path::Path;
+
+use anyhow::{ensure, Context, Result};
+use rewards_merkle_tree::{read_allocations_file, ClaimProof, MerkleTree, ProofsFile};
+use rewards_program_client::{
+    instructions::{
+        ClaimMerkleBuilder, CloseMerkleDistributionBuilder, CreateMerkleDistributionBuilder, RevokeMerkleClaimBuilder,
+    },
+    types::{MerkleClaimMode, RevokeMode, TransferFeeMode},
+};
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+use spl_associated_token_account_interface::{
+    address::get_associated_token_address_with_program_id, instruction::create_associated_token_account_idempotent,
+};
+
+use super::{fetch_merkle_distribution, seed_keypair};
+use crate::{
+    config::Config,
+    pda::{
+        find_distribution_vault, find_event_authority_pda, find_merkle_claim_pda, find_merkle_distribution_pda,
+        find_merkle_redirect_pda, find_merkle_revocation_pda,
+    },
+    schedule::from_input,
+    WindowArgs,
+};
+
+pub fn build_tree(input: &Path, output: &Path) -> Result<()> {
+    let tree = MerkleTree::from_inputs(&read_allocations_file(input)?)?;
+    let proofs = ProofsFile::from_tree(&tree);
+    proofs.write_file(output)?;
+
+    println!("Merkle root: {}", proofs.merkle_root);
+    println!("Total amount: {}", proofs.total_amount);
+    println!("Claims: {}", proofs.num_claims);
+    println!("Proofs written to {}", output.display());
+    Ok(())
+}
+
+pub fn create_merkle(
+    config: &Config,
+    mint: &Pubkey,
+    proofs: &Path,
+    amount: Option<u64>,
+    revocable: u8,
+    window: WindowArgs,
+    seed: Option<&Path>,
+) -> Result<()> {
+    let proofs = ProofsFile::read_file(proofs)?;
+    let authority = config.payer.pubkey();
+    let seed = seed_keypair(seed)?;
+    let token_program = config.token_program(mint)?;
+    let (distribution, bump) = find_merkle_distribution_pda(mint, &authority, &seed.pubkey());
+    let (event_authority, _) = find_event_authority_pda();
+
+    let mut builder = CreateMerkleDistributionBuilder::new();
+    builder
+        .payer(authority)
+        .authority(authority)
+        .seeds(seed.pubkey())
+        .distribution(distribution)
+        .mint(*mint)
+        .distribution_vault(find_distribution_vault(&distribution, mint, &token_program))
+        .authority_token_account(get_associated_token_address_with_program_id(&authority, mint, &token_program))
+        .token_program(token_program)
+        .event_authority(event_authority)
+        .bump(bump)
+        .revocable(revocable)
+        .amount(amount.unwrap_or(proofs.total_amount))
+        .merkle_root(proofs.root_bytes()?)
+        .total_amount(proofs.total_amount)
+        .clawback_ts(window.clawback_ts)
+        .claim_mode(MerkleClaimMode::ClaimAccount)
+        .claim_start_ts(window.claim_start_ts)
+        .claim_end_ts(window.claim_end_ts);
+
+    println!("Distribution: {distribution}");
+    println!("Seed: {}", seed.pubkey());
+    config.process(&[builder.instruction()], &[&seed])
+}
+
+pub fn claim_merkle(config: &Config, distribution: &Pubkey, proofs: &Path, amount: u64) -> Result<()> {
+    let claimant = config.payer.pubkey();
+    let (mint, claim) = load_claim(config, distribution, proofs, &claimant)?;
+    let token_program = config.token_program(&mint)?;
+    let (claim_account, claim_bump) = find_merkle_claim_pda(distribution, &claimant);
+    let (revocation_account, _) = find_merkle_revocation_pda(distribution, &claimant);
+    let (redirect_account, _) = find_merkle_redirect_pda(distribution, &claimant);
+    let (event_authority, _) = find_event_authority_pda();
+
+    let mut builder = ClaimMerkleBuilder::new();
+    builder
+        .payer(claimant)
+        .claimant(claimant)
+        .distribution(*distribution)
+        .claim_account(claim_account)
+        .revocation_account(revocation_account)
+        .redirect_account(redirect_account)
+        .mint(mint)
+        .distribution_vault(find_distribution_vault(distribution, &mint, &token_program))
+        .claimant_token_account(get_associated_token_address_with_program_id(&claimant, &mint, &token_program))
+        .token_program(token_program)
+        .event_authority(event_authority)
+        .claim_bump(claim_bump)
+        .total_amount(claim.amount)
+        .schedule(from_input(&claim.schedule)?)
+        .amount(amount)
+        .fee_mode(TransferFeeMode::Gross)
+        .proof(claim.proof_bytes()?);
+
+    let create_ata = create_associated_token_account_idempotent(&claimant, &claimant, &mint, &token_program);
+    config.process(&[create_ata, builder.instruction()], &[])
+}
+
+pub fn revoke_merkle(
+    config: &Config,
+    distribution: &Pubkey,
+    claimant: &Pubkey,
+    proofs: &Path,
+    revoke_mode: RevokeMode,
+) -> Result<()> {
+    let authority = config.payer.pubkey();
+    let (mint, claim) = load_claim(config, distribution, proofs, claimant)?;
+    let token_program = config.token_program(&mint)?;
+    let (claim_account, _) = find_merkle_claim_pda(distribution, claimant);
+    let (revocation_account, _) = find_merkle_revocation_pda(distribution, claimant);
+    let (event_authority, _) = find_event_authority_pda();
+
+    let mut builder = RevokeMerkleClaimBuilder::new();
+    builder
+        .authority(authority)
+        .payer(authority)
+        .distribution(*distribution)
+        .claim_account(claim_account)
+        .revocation_account(revocation_account)
+        .claimant(*claimant)
+        .mint(mint)
+        .distribution_vault(find_distribution_vault(distribution, &mint, &token_program))
+        .claimant_token_account(get_associated_token_address_with_program_id(claimant, &mint, &token_program))
+        .authority_token_account(get_associated_token_address_with_program_id(&authority, &mint, &token_program))
+        .token_program(token_program)
+        .event_authority(event_authority)
+        .revoke_mode(revoke_mode)
+        .total_amount(claim.amount)
+        .schedule(from_input(&claim.schedule)?)
+        .proof(claim.proof_bytes()?);
+
+    let create_ata = create_associated_token_account_idempotent(&authority, claimant, &mint, &token_program);
+    config.process(&[create_ata, builder.instruction()], &[])
+}
+
+pub fn close_merkle(config: &Config, distribution: &Pubkey) -> Result<()> {
+    let mint = fetch_merkle_distribution(config, distribution)?.mint;
+    let token_program = config.token_program(&mint)?;
+    let authority = config.payer.pubkey();
+    let authority_token_account = get_associated_token_address_with_program_id(&authority, &mint, &token_program);
+    let (event_authority, _) = find_event_authority_pda();
+
+    let mut builder = CloseMerkleDistributionBuilder::new();
+    builder
+        .authority(authority)
+        .distribution(*distribution)
+        .mint(mint)
+        .distribution_vault(find_distribution_vault(distribution, &mint, &token_program))
+        .authority_token_account(authority_token_account)
+        .token_program(token_program)
+        .event_authority(event_authority);
+
+    let create_ata = create_associated_token_account_idempotent(&authority, &authority, &mint, &token_program);
+    config.process(&[create_ata, builder.instruction()], &[])
+}
+
+/// Distribution mint and the proofs file entry for `claimant`, checked against the on-chain root
+fn load_claim(
+    config: &Config,
+    distribution: &Pubkey,
+    proofs: &Path,
+    claimant: &Pubkey,
+) -> Result<(Pubkey, ClaimProof)> {
+    let account = fetch_merkle_distribution(config, distribution)?;
+    let proofs = ProofsFile::read_file(proofs)?;
+    ensure!(proofs.root_bytes()? == account.merkle_root, "Proofs file root does not match distribution {distribution}");
+
+    let claim =
+        proofs.find(&claimant.to_string()).cloned().with_context(|| format!("{claimant} is not in the proofs file"))?;
+    Ok((account.mint, claim))
+}
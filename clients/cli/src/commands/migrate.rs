@@ -0,0 +1,18 @@
+use anyhow::{Context, Result};
+use rewards_program_client::instructions::MigrateAccountBuilder;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+use crate::config::Config;
+
+/// Rewrite a distribution or merkle claim stored at an older layout version at the current one
+pub fn migrate(config: &Config, address: &Pubkey) -> Result<()> {
+    let data = config.rpc.get_account_data(address).with_context(|| format!("Failed to fetch account {address}"))?;
+    if let Some(version) = data.get(1) {
+        println!("Stored version: {version}");
+    }
+
+    let mut builder = MigrateAccountBuilder::new();
+    builder.payer(config.payer.pubkey()).account(*address);
+
+    config.process(&[builder.instruction()], &[])
+}
@@ -0,0 +1,51 @@
+pub mod direct;
+pub mod inspect;
+pub mod merkle;
+pub mod migrate;
+
+pub use direct::*;
+pub use inspect::*;
+pub use merkle::*;
+pub use migrate::*;
+
+use std::path::Path;
+
+use anyhow::{ensure, Context, Result};
+use rewards_program::traits::RewardsAccountDiscriminators;
+use rewards_program_client::accounts::{DirectDistribution, DirectRecipient, MerkleDistribution};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair};
+
+use crate::config::{load_keypair, Config};
+
+/// Seed keypair from `path`, or a fresh one when none is given
+fn seed_keypair(path: Option<&Path>) -> Result<Keypair> {
+    match path {
+        Some(path) => load_keypair(path),
+        None => Ok(Keypair::new()),
+    }
+}
+
+fn fetch_rewards_account(
+    config: &Config,
+    address: &Pubkey,
+    discriminator: RewardsAccountDiscriminators,
+) -> Result<Vec<u8>> {
+    let data = config.rpc.get_account_data(address).with_context(|| format!("Failed to fetch account {address}"))?;
+    ensure!(data.first() == Some(&(discriminator as u8)), "Account {address} has an unexpected discriminator");
+    Ok(data)
+}
+
+fn fetch_direct_distribution(config: &Config, address: &Pubkey) -> Result<DirectDistribution> {
+    let data = fetch_rewards_account(config, address, RewardsAccountDiscriminators::DirectDistribution)?;
+    DirectDistribution::from_bytes(&data).with_context(|| format!("Failed to decode direct distribution {address}"))
+}
+
+fn fetch_direct_recipient(config: &Config, address: &Pubkey) -> Result<DirectRecipient> {
+    let data = fetch_rewards_account(config, address, RewardsAccountDiscriminators::DirectRecipient)?;
+    DirectRecipient::from_bytes(&data).with_context(|| format!("Failed to decode direct recipient {address}"))
+}
+
+fn fetch_merkle_distribution(config: &Config, address: &Pubkey) -> Result<MerkleDistribution> {
+    let data = fetch_rewards_account(config, address, RewardsAccountDiscriminators::MerkleDistribution)?;
+    MerkleDistribution::from_bytes(&data).with_context(|| format!("Failed to decode merkle distribution {address}"))
+}
@@ -0,0 +1,192 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use litesvm::LiteSVM;
+use solana_client::rpc_client::RpcClient;
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::{
+    account::Account,
+    instruction::Instruction,
+    message::Message,
+    pubkey,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    sysvar,
+    transaction::Transaction,
+};
+
+const BPF_LOADER_UPGRADEABLE_ID: Pubkey = pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// `getMultipleAccounts` accepts at most this many addresses per request
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// RPC connection, fee payer and send mode shared by every subcommand
+pub struct Config {
+    pub rpc: RpcClient,
+    pub payer: Keypair,
+    pub dry_run: bool,
+}
+
+impl Config {
+    pub fn new(url: &str, keypair: Option<&Path>, dry_run: bool) -> Result<Self> {
+        let path = match keypair {
+            Some(path) => path.to_path_buf(),
+            None => default_keypair_path()?,
+        };
+        let payer = load_keypair(&path)?;
+        let rpc = RpcClient::new_with_commitment(url.to_string(), CommitmentConfig::confirmed());
+
+        Ok(Self { rpc, payer, dry_run })
+    }
+
+    /// Owner program of `mint`, i.e. SPL Token or Token-2022
+    pub fn token_program(&self, mint: &Pubkey) -> Result<Pubkey> {
+        let account = self.rpc.get_account(mint).with_context(|| format!("Failed to fetch mint {mint}"))?;
+        Ok(account.owner)
+    }
+
+    /// Sign `instructions` with the payer and `signers`, then send them, or with `--dry-run`
+    /// print them and simulate them locally (see [`Config::simulate`])
+    pub fn process(&self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<()> {
+        let mut all_signers: Vec<&Keypair> = vec![&self.payer];
+        for signer in signers {
+            if all_signers.iter().all(|existing| existing.pubkey() != signer.pubkey()) {
+                all_signers.push(signer);
+            }
+        }
+
+        if self.dry_run {
+            print_instructions(instructions);
+            return self.simulate(instructions, &all_signers);
+        }
+
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let transaction =
+            Transaction::new_signed_with_payer(instructions, Some(&self.payer.pubkey()), &all_signers, blockhash);
+        let signature = self.rpc.send_and_confirm_transaction(&transaction)?;
+        println!("Signature: {signature}");
+        Ok(())
+    }
+
+    /// Run the transaction in an in-process LiteSVM instead of on the RPC node.
+    ///
+    /// Every account the transaction references, the cluster clock and any upgradeable
+    /// program's data are read from `--url` and loaded into the VM. LiteSVM's bundled
+    /// System, SPL Token, Token-2022 and associated token programs are kept. The
+    /// transaction is signed against the VM's own blockhash, so nothing can be sent.
+    fn simulate(&self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<()> {
+        let mut svm = LiteSVM::new().with_sysvars().with_default_programs();
+
+        // The clock goes first so programs are loaded at the cluster's slot
+        let message = Message::new(instructions, Some(&self.payer.pubkey()));
+        let mut addresses = vec![sysvar::clock::ID];
+        addresses.extend(message.account_keys.iter().copied());
+
+        for chunk in addresses.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let accounts = self.rpc.get_multiple_accounts(chunk).context("Failed to fetch accounts for simulation")?;
+            for (address, account) in chunk.iter().zip(accounts) {
+                let Some(account) = account else { continue };
+                if svm.get_account(address).is_some_and(|existing| existing.executable) {
+                    continue;
+                }
+                if account.executable && account.owner == BPF_LOADER_UPGRADEABLE_ID {
+                    let programdata_address = programdata_address(&account)
+                        .ok_or_else(|| anyhow!("Program {address} has no program data address"))?;
+                    let programdata = self
+                        .rpc
+                        .get_account(&programdata_address)
+                        .with_context(|| format!("Failed to fetch program data for {address}"))?;
+                    load_account(&mut svm, programdata_address, programdata)?;
+                }
+                load_account(&mut svm, *address, account)?;
+            }
+        }
+
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            signers,
+            svm.latest_blockhash(),
+        );
+        let (meta, err) = match svm.simulate_transaction(transaction) {
+            Ok(info) => (info.meta, None),
+            Err(failed) => (failed.meta, Some(failed.err)),
+        };
+
+        println!("Simulation logs:");
+        for log in &meta.logs {
+            println!("  {log}");
+        }
+        println!("Compute units: {}", meta.compute_units_consumed);
+        if let Some(err) = err {
+            bail!("Simulation failed: {err}");
+        }
+        println!("Simulation succeeded (not sent)");
+        Ok(())
+    }
+}
+
+fn load_account(svm: &mut LiteSVM, address: Pubkey, account: Account) -> Result<()> {
+    svm.set_account(address, account).map_err(|err| anyhow!("Failed to load account {address} for simulation: {err}"))
+}
+
+/// Program data address stored in an upgradeable loader `Program` account
+fn programdata_address(program: &Account) -> Option<Pubkey> {
+    // UpgradeableLoaderState::Program: u32 variant tag (2) followed by the program data address
+    let tag = u32::from_le_bytes(program.data.get(0..4)?.try_into().ok()?);
+    if tag != 2 {
+        return None;
+    }
+    Some(Pubkey::new_from_array(program.data.get(4..36)?.try_into().ok()?))
+}
+
+pub fn load_keypair(path: &Path) -> Result<Keypair> {
+    read_keypair_file(path).map_err(|err| anyhow!("Failed to read keypair {}: {err}", path.display()))
+}
+
+fn default_keypair_path() -> Result<PathBuf> {
+    let home = std::env::var_os("HOME").ok_or_else(|| anyhow!("HOME is not set; pass --keypair"))?;
+    Ok(PathBuf::from(home).join(".config/solana/id.json"))
+}
+
+fn print_instructions(instructions: &[Instruction]) {
+    for (index, instruction) in instructions.iter().enumerate() {
+        println!("Instruction #{index}");
+        println!("  Program: {}", instruction.program_id);
+        println!("  Accounts:");
+        for (account_index, meta) in instruction.accounts.iter().enumerate() {
+            let signer = if meta.is_signer { "signer" } else { "" };
+            let writable = if meta.is_writable { "writable" } else { "readonly" };
+            println!("    [{account_index:>2}] {} {writable} {signer}", meta.pubkey);
+        }
+        let data: String = instruction.data.iter().map(|byte| format!("{byte:02x}")).collect();
+        println!("  Data ({} bytes): {data}", instruction.data.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program_account(data: Vec<u8>) -> Account {
+        Account { lamports: 1, data, owner: BPF_LOADER_UPGRADEABLE_ID, executable: true, rent_epoch: 0 }
+    }
+
+    #[test]
+    fn test_programdata_address() {
+        let programdata = Pubkey::new_unique();
+        let mut data = 2u32.to_le_bytes().to_vec();
+        data.extend_from_slice(programdata.as_ref());
+
+        assert_eq!(programdata_address(&program_account(data)), Some(programdata));
+    }
+
+    #[test]
+    fn test_programdata_address_wrong_state() {
+        let mut data = 3u32.to_le_bytes().to_vec();
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+
+        assert_eq!(programdata_address(&program_account(data)), None);
+        assert_eq!(programdata_address(&program_account(vec![2, 0, 0, 0])), None);
+    }
+}
@@ -0,0 +1,246 @@
+//! # Rewards CLI
+//!
+//! Operate Rewards Program distributions from the command line.
+//!
+//! Every transaction-sending subcommand honours the global `--dry-run` flag, which prints
+//! the exact instructions and accounts and simulates the transaction instead of sending it.
+//! Simulation runs in an in-process LiteSVM loaded with the accounts and program fetched from
+//! `--url`, so the endpoint is only read from and no recent blockhash is needed.
+
+mod commands;
+mod config;
+mod pda;
+mod schedule;
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use rewards_program_client::types::RevokeMode;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::config::Config;
+
+#[derive(Parser)]
+#[command(name = "rewards-cli", version, about = "Operate Rewards Program distributions")]
+struct Cli {
+    /// RPC endpoint
+    #[arg(short = 'u', long, global = true, default_value = "http://localhost:8899")]
+    url: String,
+
+    /// Keypair paying fees and signing as authority, recipient or claimant
+    #[arg(short = 'k', long, global = true)]
+    keypair: Option<PathBuf>,
+
+    /// Print the instructions and simulate them in a local VM with accounts read from --url; nothing is sent
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create a direct distribution for a mint
+    CreateDirect {
+        #[arg(long)]
+        mint: Pubkey,
+        /// Bitmask of allowed revoke modes (bit 0 = NonVested, bit 1 = Full, bit 2 = Freeze)
+        #[arg(long, default_value_t = 0)]
+        revocable: u8,
+        #[command(flatten)]
+        window: WindowArgs,
+        /// Keep unclaimed allocations in the vault for recipients when the distribution is closed
+        #[arg(long)]
+        protect_recipients: bool,
+        /// Seed keypair for the distribution PDA (defaults to a fresh keypair)
+        #[arg(long)]
+        seed: Option<PathBuf>,
+    },
+    /// Add recipients to a direct distribution from a CSV or JSON allocations file
+    AddRecipients {
+        #[arg(long)]
+        distribution: Pubkey,
+        /// Allocations file; the `claimant` column is the recipient
+        #[arg(long)]
+        file: PathBuf,
+        /// Recipients per transaction
+        #[arg(long, default_value_t = 8)]
+        batch_size: usize,
+    },
+    /// Build a merkle tree from an allocations file and write the proofs file
+    BuildTree {
+        #[arg(long)]
+        input: PathBuf,
+        #[arg(long)]
+        output: PathBuf,
+    },
+    /// Create and fund a merkle distribution from a proofs file
+    CreateMerkle {
+        #[arg(long)]
+        mint: Pubkey,
+        #[arg(long)]
+        proofs: PathBuf,
+        /// Initial funding (defaults to the tree's total amount)
+        #[arg(long)]
+        amount: Option<u64>,
+        /// Bitmask of allowed revoke modes (bit 0 = NonVested, bit 1 = Full)
+        #[arg(long, default_value_t = 0)]
+        revocable: u8,
+        #[command(flatten)]
+        window: WindowArgs,
+        /// Seed keypair for the distribution PDA (defaults to a fresh keypair)
+        #[arg(long)]
+        seed: Option<PathBuf>,
+    },
+    /// Claim vested tokens from a direct distribution
+    ClaimDirect {
+        #[arg(long)]
+        distribution: Pubkey,
+        /// Amount to claim (0 = everything available)
+        #[arg(long, default_value_t = 0)]
+        amount: u64,
+    },
+    /// Claim vested tokens from a merkle distribution using a proofs file
+    ClaimMerkle {
+        #[arg(long)]
+        distribution: Pubkey,
+        #[arg(long)]
+        proofs: PathBuf,
+        /// Amount to claim (0 = everything available)
+        #[arg(long, default_value_t = 0)]
+        amount: u64,
+    },
+    /// Revoke a direct recipient
+    RevokeDirect {
+        #[arg(long)]
+        distribution: Pubkey,
+        #[arg(long)]
+        recipient: Pubkey,
+        #[arg(long, value_enum)]
+        mode: RevokeModeArg,
+    },
+    /// Add tokens to a direct recipient's allocation, vesting on its current schedule
+    IncreaseDirect {
+        #[arg(long)]
+        distribution: Pubkey,
+        #[arg(long)]
+        recipient: Pubkey,
+        #[arg(long)]
+        amount: u64,
+    },
+    /// Return part of a direct recipient's unvested allocation to the authority
+    DecreaseDirect {
+        #[arg(long)]
+        distribution: Pubkey,
+        #[arg(long)]
+        recipient: Pubkey,
+        #[arg(long)]
+        amount: u64,
+    },
+    /// Revoke a merkle claimant using a proofs file
+    RevokeMerkle {
+        #[arg(long)]
+        distribution: Pubkey,
+        #[arg(long)]
+        claimant: Pubkey,
+        #[arg(long)]
+        proofs: PathBuf,
+        #[arg(long, value_enum)]
+        mode: RevokeModeArg,
+    },
+    /// Close a direct distribution and return the remaining vault balance (or only the
+    /// unallocated part, if it protects recipients and allocations are unclaimed)
+    CloseDirect {
+        #[arg(long)]
+        distribution: Pubkey,
+    },
+    /// Close a merkle distribution and return the remaining vault balance
+    CloseMerkle {
+        #[arg(long)]
+        distribution: Pubkey,
+    },
+    /// Fetch and decode any Rewards Program account
+    Inspect { address: Pubkey },
+    /// Grow a distribution, direct recipient or merkle claim created by an older program version to the current layout
+    Migrate { address: Pubkey },
+}
+
+/// When recipients may claim from a new distribution, and when its authority may close it
+#[derive(Clone, Copy, Args)]
+pub struct WindowArgs {
+    /// Timestamp after which the authority may close the distribution (0 = once claims close)
+    #[arg(long, default_value_t = 0)]
+    pub clawback_ts: i64,
+    /// Timestamp claims open at (0 = immediately)
+    #[arg(long, default_value_t = 0)]
+    pub claim_start_ts: i64,
+    /// Timestamp claims close at (0 = never)
+    #[arg(long, default_value_t = 0)]
+    pub claim_end_ts: i64,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum RevokeModeArg {
+    /// Return only unvested tokens to the authority; vested tokens go to the recipient
+    NonVested,
+    /// Return all unclaimed tokens to the authority
+    Full,
+    /// Return unvested tokens to the authority and stop vesting; the recipient keeps claiming what had
+    /// vested (direct distributions only)
+    Freeze,
+}
+
+impl From<RevokeModeArg> for RevokeMode {
+    fn from(mode: RevokeModeArg) -> Self {
+        match mode {
+            RevokeModeArg::NonVested => RevokeMode::NonVested,
+            RevokeModeArg::Full => RevokeMode::Full,
+            RevokeModeArg::Freeze => RevokeMode::Freeze,
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    if let Command::BuildTree { input, output } = &cli.command {
+        return commands::build_tree(input, output);
+    }
+
+    let config = Config::new(&cli.url, cli.keypair.as_deref(), cli.dry_run)?;
+
+    match cli.command {
+        Command::CreateDirect { mint, revocable, window, protect_recipients, seed } => {
+            commands::create_direct(&config, &mint, revocable, window, protect_recipients, seed.as_deref())
+        }
+        Command::AddRecipients { distribution, file, batch_size } => {
+            commands::add_recipients(&config, &distribution, &file, batch_size)
+        }
+        Command::BuildTree { .. } => unreachable!("handled before connecting"),
+        Command::CreateMerkle { mint, proofs, amount, revocable, window, seed } => {
+            commands::create_merkle(&config, &mint, &proofs, amount, revocable, window, seed.as_deref())
+        }
+        Command::ClaimDirect { distribution, amount } => commands::claim_direct(&config, &distribution, amount),
+        Command::ClaimMerkle { distribution, proofs, amount } => {
+            commands::claim_merkle(&config, &distribution, &proofs, amount)
+        }
+        Command::RevokeDirect { distribution, recipient, mode } => {
+            commands::revoke_direct(&config, &distribution, &recipient, mode.into())
+        }
+        Command::IncreaseDirect { distribution, recipient, amount } => {
+            commands::increase_direct(&config, &distribution, &recipient, amount)
+        }
+        Command::DecreaseDirect { distribution, recipient, amount } => {
+            commands::decrease_direct(&config, &distribution, &recipient, amount)
+        }
+        Command::RevokeMerkle { distribution, claimant, proofs, mode } => {
+            commands::revoke_merkle(&config, &distribution, &claimant, &proofs, mode.into())
+        }
+        Command::CloseDirect { distribution } => commands::close_direct(&config, &distribution),
+        Command::CloseMerkle { distribution } => commands::close_merkle(&config, &distribution),
+        Command::Inspect { address } => commands::inspect(&config, &address),
+        Command::Migrate { address } => commands::migrate(&config, &address),
+    }
+}
@@ -0,0 +1,109 @@
+use rewards_program_client::REWARDS_PROGRAM_ID;
+use solana_sdk::pubkey::Pubkey;
+use spl_associated_token_account_interface::address::get_associated_token_address_with_program_id;
+
+const DIRECT_DISTRIBUTION_SEED: &[u8] = b"direct_distribution";
+const DIRECT_RECIPIENT_SEED: &[u8] = b"direct_recipient";
+const MERKLE_DISTRIBUTION_SEED: &[u8] = b"merkle_distribution";
+const MERKLE_CLAIM_SEED: &[u8] = b"merkle_claim";
+const MERKLE_REVOCATION_SEED: &[u8] = b"merkle_revocation";
+const MERKLE_REDIRECT_SEED: &[u8] = b"merkle_redirect";
+const EVENT_AUTHORITY_SEED: &[u8] = b"event_authority";
+
+pub fn find_direct_distribution_pda(mint: &Pubkey, authority: &Pubkey, seeds: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[DIRECT_DISTRIBUTION_SEED, mint.as_ref(), authority.as_ref(), seeds.as_ref()],
+        &REWARDS_PROGRAM_ID,
+    )
+}
+
+pub fn find_direct_recipient_pda(distribution: &Pubkey, recipient: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[DIRECT_RECIPIENT_SEED, distribution.as_ref(), recipient.as_ref()],
+        &REWARDS_PROGRAM_ID,
+    )
+}
+
+pub fn find_event_authority_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], &REWARDS_PROGRAM_ID)
+}
+
+pub fn find_merkle_distribution_pda(mint: &Pubkey, authority: &Pubkey, seeds: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MERKLE_DISTRIBUTION_SEED, mint.as_ref(), authority.as_ref(), seeds.as_ref()],
+        &REWARDS_PROGRAM_ID,
+    )
+}
+
+pub fn find_merkle_claim_pda(distribution: &Pubkey, claimant: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MERKLE_CLAIM_SEED, distribution.as_ref(), claimant.as_ref()], &REWARDS_PROGRAM_ID)
+}
+
+pub fn find_merkle_revocation_pda(distribution: &Pubkey, claimant: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MERKLE_REVOCATION_SEED, distribution.as_ref(), claimant.as_ref()],
+        &REWARDS_PROGRAM_ID,
+    )
+}
+
+pub fn find_merkle_redirect_pda(distribution: &Pubkey, claimant: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MERKLE_REDIRECT_SEED, distribution.as_ref(), claimant.as_ref()], &REWARDS_PROGRAM_ID)
+}
+
+pub fn find_distribution_vault(distribution: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(distribution, mint, token_program)
+}
+
+#[cfg(test)]
+mod tests {
+    use rewards_program::{
+        events::event_authority_pda,
+        state::{
+            DirectDistribution, DirectRecipient, MerkleClaim, MerkleDistribution, MerkleRedirect, MerkleRevocation,
+        },
+        traits::PdaSeeds,
+        utils::VestingSchedule,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_program_id_matches_program() {
+        assert_eq!(REWARDS_PROGRAM_ID.to_bytes(), rewards_program::ID.to_bytes());
+    }
+
+    #[test]
+    fn test_seeds_match_program() {
+        assert_eq!(DIRECT_DISTRIBUTION_SEED, DirectDistribution::PREFIX);
+        assert_eq!(DIRECT_RECIPIENT_SEED, DirectRecipient::PREFIX);
+        assert_eq!(MERKLE_DISTRIBUTION_SEED, MerkleDistribution::PREFIX);
+        assert_eq!(MERKLE_CLAIM_SEED, MerkleClaim::PREFIX);
+        assert_eq!(MERKLE_REVOCATION_SEED, MerkleRevocation::PREFIX);
+        assert_eq!(MERKLE_REDIRECT_SEED, MerkleRedirect::PREFIX);
+        assert_eq!(EVENT_AUTHORITY_SEED, rewards_program::events::EVENT_AUTHORITY_SEED);
+    }
+
+    #[test]
+    fn test_event_authority_matches_program() {
+        let (address, bump) = find_event_authority_pda();
+        assert_eq!(address.to_bytes(), event_authority_pda::ID.to_bytes());
+        assert_eq!(bump, event_authority_pda::BUMP);
+    }
+
+    #[test]
+    fn test_direct_recipient_matches_program_seeds() {
+        let distribution = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let state = DirectRecipient::new(
+            0,
+            distribution.to_bytes().into(),
+            recipient.to_bytes().into(),
+            [0u8; 32].into(),
+            1,
+            VestingSchedule::Immediate {},
+        );
+
+        let expected = Pubkey::find_program_address(&state.seeds(), &REWARDS_PROGRAM_ID);
+        assert_eq!(find_direct_recipient_pda(&distribution, &recipient), expected);
+    }
+}
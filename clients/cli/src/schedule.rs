@@ -0,0 +1,91 @@
+use anyhow::{Context, Result};
+use rewards_merkle_tree::ScheduleInput;
+use rewards_program::utils::VestingSchedule as ProgramVestingSchedule;
+use rewards_program_client::types::VestingSchedule;
+
+/// Convert a program schedule into the generated client type.
+///
+/// The program's `to_bytes` encoding is the borsh layout described by the IDL,
+/// so a round-trip through bytes keeps both types in lockstep.
+pub fn to_client_schedule(schedule: &ProgramVestingSchedule) -> Result<VestingSchedule> {
+    borsh::from_slice(&schedule.to_bytes()).context("Failed to encode vesting schedule")
+}
+
+pub fn from_input(schedule: &ScheduleInput) -> Result<VestingSchedule> {
+    to_client_schedule(&ProgramVestingSchedule::from(schedule))
+}
+
+#[cfg(test)]
+mod tests {
+    use rewards_merkle_tree::{InterpolationInput, TranchePointInput};
+    use rewards_program::utils::{TrancheInterpolation as ProgramInterpolation, TranchePoint as ProgramTranchePoint};
+    use rewards_program_client::types::{TrancheInterpolation, TranchePoint};
+
+    use super::*;
+
+    #[test]
+    fn test_to_client_schedule_fixed_variants() {
+        let cases = [
+            (ProgramVestingSchedule::Immediate {}, VestingSchedule::Immediate),
+            (
+                ProgramVestingSchedule::Linear { start_ts: 100, end_ts: 200 },
+                VestingSchedule::Linear { start_ts: 100, end_ts: 200 },
+            ),
+            (ProgramVestingSchedule::Cliff { cliff_ts: 150 }, VestingSchedule::Cliff { cliff_ts: 150 }),
+            (
+                ProgramVestingSchedule::CliffLinear { start_ts: 100, cliff_ts: 150, end_ts: 200 },
+                VestingSchedule::CliffLinear { start_ts: 100, cliff_ts: 150, end_ts: 200 },
+            ),
+            (
+                ProgramVestingSchedule::Stepped { start_ts: 100, period_secs: 30, num_periods: 4 },
+                VestingSchedule::Stepped { start_ts: 100, period_secs: 30, num_periods: 4 },
+            ),
+            (
+                ProgramVestingSchedule::CliffStepped { start_ts: 100, cliff_ts: 160, period_secs: 30, num_periods: 4 },
+                VestingSchedule::CliffStepped { start_ts: 100, cliff_ts: 160, period_secs: 30, num_periods: 4 },
+            ),
+        ];
+
+        for (program, client) in cases {
+            assert_eq!(to_client_schedule(&program).unwrap(), client);
+        }
+    }
+
+    #[test]
+    fn test_to_client_schedule_tranches() {
+        let program = ProgramVestingSchedule::Tranches {
+            interpolation: ProgramInterpolation::Linear {},
+            points: vec![
+                ProgramTranchePoint { timestamp: 100, cumulative_bps: 1_000 },
+                ProgramTranchePoint { timestamp: 200, cumulative_bps: 10_000 },
+            ],
+        };
+
+        assert_eq!(
+            to_client_schedule(&program).unwrap(),
+            VestingSchedule::Tranches {
+                interpolation: TrancheInterpolation::Linear,
+                points: vec![
+                    TranchePoint { timestamp: 100, cumulative_bps: 1_000 },
+                    TranchePoint { timestamp: 200, cumulative_bps: 10_000 },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_input() {
+        let input = ScheduleInput::Tranches {
+            interpolation: InterpolationInput::Step,
+            points: vec![TranchePointInput { timestamp: 100, cumulative_bps: 10_000 }],
+        };
+
+        assert_eq!(
+            from_input(&input).unwrap(),
+            VestingSchedule::Tranches {
+                interpolation: TrancheInterpolation::Step,
+                points: vec![TranchePoint { timestamp: 100, cumulative_bps: 10_000 }],
+            }
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use rewards_program_client::events::{is_event_instruction, RewardsEvent};
+use rewards_program_client::REWARDS_PROGRAM_ID;
+use solana_pubkey::Pubkey;
+
+use crate::IndexerError;
+
+/// Total order of events across the chain.
+///
+/// Fields compare in declaration order, so sorting entries by position replays them
+/// in exactly the order the program emitted them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EventPosition {
+    pub slot: u64,
+    /// Index of the transaction within its block
+    pub transaction_index: u32,
+    /// Index of the top-level instruction within its transaction
+    pub instruction_index: u16,
+    /// Index of the event among the events emitted by that instruction
+    pub event_index: u16,
+}
+
+/// One decoded event and the context it was emitted in
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LedgerEntry {
+    pub position: EventPosition,
+    pub signature: String,
+    /// Block time of the transaction (unix seconds)
+    pub block_time: i64,
+    /// Data of the top-level Rewards instruction that emitted the event, when known.
+    ///
+    /// Only needed for facts absent from events, such as the initial deposit of
+    /// `CreateMerkleDistribution`.
+    pub instruction_data: Option<Vec<u8>>,
+    /// Raw event bytes as emitted through `EmitEvent`
+    pub event_data: Vec<u8>,
+    pub event: RewardsEvent,
+}
+
+impl LedgerEntry {
+    /// Decode `event_data` into an entry
+    pub fn decode(
+        position: EventPosition,
+        signature: &str,
+        block_time: i64,
+        instruction_data: Option<Vec<u8>>,
+        event_data: Vec<u8>,
+    ) -> Result<Self, IndexerError> {
+        let event = RewardsEvent::decode(&event_data)?;
+        Ok(Self { position, signature: signature.to_string(), block_time, instruction_data, event_data, event })
+    }
+
+    /// Entries for every event emitted by one top-level instruction.
+    ///
+    /// `position` locates the instruction (its `event_index` is ignored); `inner_instructions`
+    /// yields `(program_id, data)` for each of its inner instructions. Inner instructions that
+    /// are not Rewards events are skipped.
+    pub fn from_instruction<'a, I>(
+        position: EventPosition,
+        signature: &str,
+        block_time: i64,
+        instruction_data: Option<&[u8]>,
+        inner_instructions: I,
+    ) -> Result<Vec<Self>, IndexerError>
+    where
+        I: IntoIterator<Item = (&'a Pubkey, &'a [u8])>,
+    {
+        inner_instructions
+            .into_iter()
+            .filter(|(program_id, data)| **program_id == REWARDS_PROGRAM_ID && is_event_instruction(data))
+            .enumerate()
+            .map(|(event_index, (_, data))| {
+                let position = EventPosition { event_index: event_index as u16, ..position };
+                Self::decode(position, signature, block_time, instruction_data.map(<[u8]>::to_vec), data.to_vec())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rewards_program_client::events::{EventDiscriminator, EVENT_IX_TAG_LE};
+    use rewards_program_client::types::ClaimedEvent;
+
+    use super::*;
+
+    fn claimed_bytes(amount: u64) -> Vec<u8> {
+        let event = ClaimedEvent {
+            distribution: Pubkey::new_from_array([1u8; 32]),
+            claimant: Pubkey::new_from_array([2u8; 32]),
+            amount,
+        };
+        let mut data = EVENT_IX_TAG_LE.to_vec();
+        data.push(EventDiscriminator::Claimed as u8);
+        data.extend_from_slice(&borsh::to_vec(&event).unwrap());
+        data
+    }
+
+    #[test]
+    fn test_position_ordering() {
+        let first = EventPosition { slot: 10, transaction_index: 3, instruction_index: 1, event_index: 5 };
+        let later_event = EventPosition { event_index: 6, ..first };
+        let later_instruction = EventPosition { instruction_index: 2, event_index: 0, ..first };
+        let later_slot = EventPosition { slot: 11, transaction_index: 0, instruction_index: 0, event_index: 0 };
+
+        assert!(first < later_event);
+        assert!(later_event < later_instruction);
+        assert!(later_instruction < later_slot);
+    }
+
+    #[test]
+    fn test_from_instruction_numbers_events() {
+        let other_program = Pubkey::new_from_array([9u8; 32]);
+        let first = claimed_bytes(10);
+        let second = claimed_bytes(20);
+        let inner = [
+            (&REWARDS_PROGRAM_ID, first.as_slice()),
+            (&other_program, first.as_slice()),
+            (&REWARDS_PROGRAM_ID, second.as_slice()),
+        ];
+        let position = EventPosition { slot: 5, transaction_index: 1, instruction_index: 2, event_index: 99 };
+
+        let entries = LedgerEntry::from_instruction(position, "sig", 1_000, None, inner).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].position, EventPosition { event_index: 0, ..position });
+        assert_eq!(entries[1].position, EventPosition { event_index: 1, ..position });
+        assert_eq!(entries[1].event_data, second);
+        assert!(matches!(&entries[1].event, RewardsEvent::Claimed(event) if event.amount == 20));
+    }
+
+    #[test]
+    fn test_decode_rejects_bad_event() {
+        let result = LedgerEntry::decode(EventPosition::default(), "sig", 0, None, vec![0u8; 3]);
+        assert!(matches!(result, Err(IndexerError::Decode(_))));
+    }
+}
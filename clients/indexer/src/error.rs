@@ -0,0 +1,44 @@
+use rewards_program_client::events::EventDecodeError;
+use solana_pubkey::Pubkey;
+use thiserror::Error;
+
+use crate::EventPosition;
+
+#[derive(Debug, Error)]
+pub enum IndexerError {
+    /// Raw event bytes could not be decoded
+    #[error(transparent)]
+    Decode(#[from] EventDecodeError),
+
+    /// An entry is not strictly after the last applied entry
+    #[error("Event at {position:?} is not after the last applied event at {last:?}")]
+    OutOfOrder { position: EventPosition, last: EventPosition },
+
+    /// An event references a distribution whose creation was never indexed
+    #[error("Unknown distribution {0}")]
+    UnknownDistribution(Pubkey),
+
+    /// An event references a position that was never indexed for its distribution
+    #[error("Unknown position {recipient} in distribution {distribution}")]
+    UnknownPosition { distribution: Pubkey, recipient: Pubkey },
+
+    /// A distribution is created twice
+    #[error("Distribution {0} already exists")]
+    DuplicateDistribution(Pubkey),
+
+    /// An event arrives for a distribution after it was closed
+    #[error("Distribution {0} is closed")]
+    DistributionClosed(Pubkey),
+
+    /// Replayed totals overflow or underflow, meaning the history is incomplete
+    #[error("Arithmetic overflow replaying {0}")]
+    MathOverflow(&'static str),
+
+    /// Instruction data attached to an entry does not match the event
+    #[error("Invalid instruction data for {0}")]
+    InvalidInstructionData(&'static str),
+
+    #[cfg(feature = "sqlite")]
+    #[error(transparent)]
+    Sqlite(#[from] rusqlite::Error),
+}
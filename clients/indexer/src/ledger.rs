@@ -0,0 +1,772 @@
+use std::collections::BTreeMap;
+
+use borsh::BorshDeserialize;
+use rewards_program_client::{
+    events::RewardsEvent,
+    instructions::{CreateMerkleDistributionInstructionArgs, CREATE_MERKLE_DISTRIBUTION_DISCRIMINATOR},
+    types::{
+        AllocationChangedEvent, ClaimDelegateUpdatedEvent, CompressedClaimedEvent, CompressedRecipientAddedEvent,
+        DistributionCreatedData, DistributionCreatedEvent, RecipientAddedEvent, RecipientRevokedEvent,
+        RecipientTransferredEvent, RevokeMode,
+    },
+    REWARDS_PROGRAM_ID,
+};
+use solana_pubkey::Pubkey;
+
+use crate::{
+    DistributionKind, DistributionView, EventPosition, IndexerError, LedgerEntry, PositionView, RevocationView,
+};
+
+const DIRECT_DISTRIBUTION_SEED: &[u8] = b"direct_distribution";
+const MERKLE_DISTRIBUTION_SEED: &[u8] = b"merkle_distribution";
+const COMPRESSED_DISTRIBUTION_SEED: &[u8] = b"compressed_distribution";
+
+/// Materialized view of every distribution and position, built by applying events in order.
+///
+/// The view is a pure function of the applied entries: replaying the same history always
+/// yields an equal `Ledger`, and closed accounts keep their final state.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ledger {
+    distributions: BTreeMap<Pubkey, DistributionView>,
+    positions: BTreeMap<(Pubkey, Pubkey), PositionView>,
+    last_position: Option<EventPosition>,
+}
+
+impl Ledger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a ledger from a full history, sorting entries by position first
+    pub fn replay<'a, I>(entries: I) -> Result<Self, IndexerError>
+    where
+        I: IntoIterator<Item = &'a LedgerEntry>,
+    {
+        let mut entries: Vec<&LedgerEntry> = entries.into_iter().collect();
+        entries.sort_by_key(|entry| entry.position);
+
+        let mut ledger = Self::new();
+        for entry in entries {
+            ledger.apply(entry)?;
+        }
+        Ok(ledger)
+    }
+
+    pub fn distribution(&self, address: &Pubkey) -> Option<&DistributionView> {
+        self.distributions.get(address)
+    }
+
+    pub fn distributions(&self) -> impl Iterator<Item = &DistributionView> {
+        self.distributions.values()
+    }
+
+    pub fn position(&self, distribution: &Pubkey, recipient: &Pubkey) -> Option<&PositionView> {
+        self.positions.get(&(*distribution, *recipient))
+    }
+
+    /// Positions of `distribution`, ordered by recipient
+    pub fn positions(&self, distribution: &Pubkey) -> impl Iterator<Item = &PositionView> {
+        let distribution = *distribution;
+        self.positions
+            .range(
+                (distribution, Pubkey::new_from_array([0u8; 32]))..=(distribution, Pubkey::new_from_array([0xff; 32])),
+            )
+            .map(|(_, position)| position)
+    }
+
+    pub fn all_positions(&self) -> impl Iterator<Item = &PositionView> {
+        self.positions.values()
+    }
+
+    pub fn last_position(&self) -> Option<EventPosition> {
+        self.last_position
+    }
+
+    /// Apply one entry. Entries must arrive in strictly increasing position order.
+    pub fn apply(&mut self, entry: &LedgerEntry) -> Result<(), IndexerError> {
+        if let Some(last) = self.last_position {
+            if entry.position <= last {
+                return Err(IndexerError::OutOfOrder { position: entry.position, last });
+            }
+        }
+
+        match &entry.event {
+            RewardsEvent::DistributionCreated(event) => self.apply_created(entry, event)?,
+            RewardsEvent::RecipientAdded(event) => self.apply_recipient_added(event)?,
+            RewardsEvent::Claimed(event) => {
+                let kind = self.distribution_mut(&event.distribution)?.kind;
+                let distribution = self.distribution_mut(&event.distribution)?;
+                distribution.total_claimed = add(distribution.total_claimed, event.amount, "total_claimed")?;
+
+                let position = self.position_for(kind, &event.distribution, &event.claimant)?;
+                position.claimed = add(position.claimed, event.amount, "claimed")?;
+            }
+            RewardsEvent::RecipientRevoked(event) => self.apply_revoked(entry, event)?,
+            RewardsEvent::RecipientTransferred(event) => self.apply_transferred(event)?,
+            RewardsEvent::ClaimDelegateUpdated(event) => self.apply_delegate(event)?,
+            RewardsEvent::ClaimClosed(event) => {
+                let kind = self.distribution_mut(&event.distribution)?.kind;
+                self.position_for(kind, &event.distribution, &event.claimant)?.closed_at = Some(entry.block_time);
+            }
+            RewardsEvent::DistributionClosed(event) => {
+                let distribution = self.distribution_mut(&event.distribution)?;
+                distribution.closed_at = Some(entry.block_time);
+                distribution.remaining_at_close = Some(event.remaining_amount);
+            }
+            RewardsEvent::AuthorityTransferred(event) => {
+                self.distribution_mut(&event.distribution)?.authority = event.new_authority;
+            }
+            RewardsEvent::MerkleRootUpdated(event) => {
+                let distribution = self.distribution_mut(&event.distribution)?;
+                distribution.merkle_root = event.merkle_root;
+                distribution.total_amount = event.total_amount;
+                distribution.epoch = event.epoch;
+            }
+            RewardsEvent::DistributionFunded(event) => {
+                self.distribution_mut(&event.distribution)?.total_funded = event.total_funded;
+            }
+            RewardsEvent::DistributionPaused(event) => {
+                self.distribution_mut(&event.distribution)?.paused = true;
+            }
+            RewardsEvent::DistributionUnpaused(event) => {
+                let distribution = self.distribution_mut(&event.distribution)?;
+                distribution.paused = false;
+                distribution.vesting_offset = distribution
+                    .vesting_offset
+                    .checked_add(event.vesting_shift)
+                    .ok_or(IndexerError::MathOverflow("vesting_offset"))?;
+            }
+            RewardsEvent::CompressedRecipientAdded(event) => self.apply_compressed_recipient_added(event)?,
+            RewardsEvent::CompressedClaimed(event) => self.apply_compressed_claimed(event)?,
+            RewardsEvent::AllocationChanged(event) => self.apply_allocation_changed(event)?,
+            RewardsEvent::DistributionSettled(event) => {
+                // A settled distribution can be closed again to sweep late deposits; keep the first time
+                self.distribution_mut(&event.distribution)?.settled_at.get_or_insert(entry.block_time);
+            }
+            // Basket distributions pay several mints and are not tracked by this single-mint ledger
+            RewardsEvent::BasketDistributionCreated(_)
+            | RewardsEvent::BasketRecipientAdded(_)
+            | RewardsEvent::BasketClaimed(_)
+            | RewardsEvent::BasketDistributionClosed(_) => {}
+        }
+
+        self.last_position = Some(entry.position);
+        Ok(())
+    }
+
+    fn apply_created(&mut self, entry: &LedgerEntry, event: &DistributionCreatedEvent) -> Result<(), IndexerError> {
+        let (kind, prefix) = match event.type_data {
+            DistributionCreatedData::Direct { .. } => (DistributionKind::Direct, DIRECT_DISTRIBUTION_SEED),
+            DistributionCreatedData::Merkle { .. } => (DistributionKind::Merkle, MERKLE_DISTRIBUTION_SEED),
+            DistributionCreatedData::Compressed { .. } => (DistributionKind::Compressed, COMPRESSED_DISTRIBUTION_SEED),
+        };
+        let (address, _) = Pubkey::find_program_address(
+            &[prefix, event.mint.as_ref(), event.authority.as_ref(), event.seed.as_ref()],
+            &REWARDS_PROGRAM_ID,
+        );
+        if self.distributions.contains_key(&address) {
+            return Err(IndexerError::DuplicateDistribution(address));
+        }
+
+        let mut view = DistributionView {
+            address,
+            kind,
+            authority: event.authority,
+            mint: event.mint,
+            seed: event.seed,
+            created_slot: entry.position.slot,
+            created_at: entry.block_time,
+            clawback_ts: 0,
+            merkle_root: [0u8; 32],
+            total_amount: 0,
+            epoch: 0,
+            total_allocated: 0,
+            total_funded: 0,
+            total_claimed: 0,
+            total_revoked: 0,
+            paused: false,
+            vesting_offset: 0,
+            settled_at: None,
+            closed_at: None,
+            remaining_at_close: None,
+        };
+
+        match &event.type_data {
+            DistributionCreatedData::Direct { clawback_ts } | DistributionCreatedData::Compressed { clawback_ts } => {
+                view.clawback_ts = *clawback_ts
+            }
+            DistributionCreatedData::Merkle { merkle_root, total_amount, clawback_ts } => {
+                view.merkle_root = *merkle_root;
+                view.total_amount = *total_amount;
+                view.clawback_ts = *clawback_ts;
+                view.total_funded = initial_merkle_deposit(entry.instruction_data.as_deref())?;
+            }
+        }
+
+        self.distributions.insert(address, view);
+        Ok(())
+    }
+
+    fn apply_recipient_added(&mut self, event: &RecipientAddedEvent) -> Result<(), IndexerError> {
+        let distribution = self.distribution_mut(&event.distribution)?;
+        distribution.total_allocated = add(distribution.total_allocated, event.amount, "total_allocated")?;
+        distribution.total_funded = add(distribution.total_funded, event.amount, "total_funded")?;
+
+        let mut position = PositionView::new(event.distribution, event.recipient);
+        position.allocated = event.amount;
+        self.positions.insert((event.distribution, event.recipient), position);
+        Ok(())
+    }
+
+    /// Increases are deposited like a new recipient; decreases return unvested tokens like a revoke
+    fn apply_allocation_changed(&mut self, event: &AllocationChangedEvent) -> Result<(), IndexerError> {
+        let distribution = self.distribution_mut(&event.distribution)?;
+        if event.new_amount >= event.previous_amount {
+            let delta = event.new_amount - event.previous_amount;
+            distribution.total_allocated = add(distribution.total_allocated, delta, "total_allocated")?;
+            distribution.total_funded = add(distribution.total_funded, delta, "total_funded")?;
+        } else {
+            let delta = event.previous_amount - event.new_amount;
+            distribution.total_allocated =
+                distribution.total_allocated.checked_sub(delta).ok_or(IndexerError::MathOverflow("total_allocated"))?;
+            distribution.total_revoked = add(distribution.total_revoked, delta, "total_revoked")?;
+        }
+
+        let position = self.position_for(DistributionKind::Direct, &event.distribution, &event.recipient)?;
+        position.allocated = event.new_amount;
+        Ok(())
+    }
+
+    /// A recipient may hold several leaves; their allocations add up in one position
+    fn apply_compressed_recipient_added(&mut self, event: &CompressedRecipientAddedEvent) -> Result<(), IndexerError> {
+        let distribution = self.distribution_mut(&event.distribution)?;
+        distribution.total_allocated = add(distribution.total_allocated, event.amount, "total_allocated")?;
+        distribution.total_funded = add(distribution.total_funded, event.amount, "total_funded")?;
+
+        let position = self.position_for(DistributionKind::Compressed, &event.distribution, &event.recipient)?;
+        position.allocated = add(position.allocated, event.amount, "allocated")?;
+        Ok(())
+    }
+
+    fn apply_compressed_claimed(&mut self, event: &CompressedClaimedEvent) -> Result<(), IndexerError> {
+        let distribution = self.distribution_mut(&event.distribution)?;
+        distribution.total_claimed = add(distribution.total_claimed, event.amount, "total_claimed")?;
+
+        let position = self.position_for(DistributionKind::Compressed, &event.distribution, &event.recipient)?;
+        position.claimed = add(position.claimed, event.amount, "claimed")?;
+        Ok(())
+    }
+
+    fn apply_revoked(&mut self, entry: &LedgerEntry, event: &RecipientRevokedEvent) -> Result<(), IndexerError> {
+        let distribution = self.distribution_mut(&event.distribution)?;
+        let kind = distribution.kind;
+        distribution.total_claimed = add(distribution.total_claimed, event.vested_transferred, "total_claimed")?;
+        distribution.total_revoked = add(distribution.total_revoked, event.unvested_returned, "total_revoked")?;
+        if kind == DistributionKind::Direct {
+            distribution.total_allocated = distribution
+                .total_allocated
+                .checked_sub(event.unvested_returned)
+                .ok_or(IndexerError::MathOverflow("total_allocated"))?;
+        }
+
+        let position = self.position_for(kind, &event.distribution, &event.recipient)?;
+        position.claimed = add(position.claimed, event.vested_transferred, "claimed")?;
+        position.revocation = Some(RevocationView {
+            mode: event.revoke_mode.clone(),
+            vested_transferred: event.vested_transferred,
+            unvested_returned: event.unvested_returned,
+            revoked_at: entry.block_time,
+        });
+        // Direct revocation closes the recipient account unless it froze it; merkle revocation leaves the claim account
+        if kind == DistributionKind::Direct && !matches!(event.revoke_mode, RevokeMode::Freeze) {
+            position.closed_at = Some(entry.block_time);
+        }
+        Ok(())
+    }
+
+    fn apply_transferred(&mut self, event: &RecipientTransferredEvent) -> Result<(), IndexerError> {
+        let kind = self.distribution_mut(&event.distribution)?.kind;
+        match kind {
+            // The recipient PDA moves to the new recipient with its allocation and progress
+            DistributionKind::Direct => {
+                let mut position = self.positions.remove(&(event.distribution, event.previous_recipient)).ok_or(
+                    IndexerError::UnknownPosition {
+                        distribution: event.distribution,
+                        recipient: event.previous_recipient,
+                    },
+                )?;
+                position.recipient = event.new_recipient;
+                position.owner = event.new_recipient;
+                self.positions.insert((event.distribution, event.new_recipient), position);
+            }
+            // The claim stays keyed by the leaf claimant; a redirect names the new owner
+            DistributionKind::Merkle | DistributionKind::Compressed => {
+                let owned = self
+                    .positions
+                    .iter()
+                    .find(|((distribution, _), position)| {
+                        *distribution == event.distribution && position.owner == event.previous_recipient
+                    })
+                    .map(|(key, _)| *key);
+                let key = owned.unwrap_or((event.distribution, event.previous_recipient));
+                self.positions.entry(key).or_insert_with(|| PositionView::new(key.0, key.1)).owner =
+                    event.new_recipient;
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_delegate(&mut self, event: &ClaimDelegateUpdatedEvent) -> Result<(), IndexerError> {
+        let kind = self.distribution_mut(&event.distribution)?.kind;
+        let position = self.position_for(kind, &event.distribution, &event.recipient)?;
+        // A removed delegate is reported as the default address
+        position.delegate = (event.delegate != Pubkey::default()).then_some(event.delegate);
+        Ok(())
+    }
+
+    fn distribution_mut(&mut self, address: &Pubkey) -> Result<&mut DistributionView, IndexerError> {
+        let distribution = self.distributions.get_mut(address).ok_or(IndexerError::UnknownDistribution(*address))?;
+        if distribution.closed_at.is_some() {
+            return Err(IndexerError::DistributionClosed(*address));
+        }
+        Ok(distribution)
+    }
+
+    /// Direct positions must have been added; merkle and compressed positions appear on first use
+    fn position_for(
+        &mut self,
+        kind: DistributionKind,
+        distribution: &Pubkey,
+        recipient: &Pubkey,
+    ) -> Result<&mut PositionView, IndexerError> {
+        let key = (*distribution, *recipient);
+        match kind {
+            DistributionKind::Direct => self
+                .positions
+                .get_mut(&key)
+                .ok_or(IndexerError::UnknownPosition { distribution: *distribution, recipient: *recipient }),
+            DistributionKind::Merkle | DistributionKind::Compressed => {
+                Ok(self.positions.entry(key).or_insert_with(|| PositionView::new(*distribution, *recipient)))
+            }
+        }
+    }
+}
+
+/// Requested deposit of `CreateMerkleDistribution`, which no event reports.
+///
+/// For transfer-fee mints the vault receives less; the next `DistributionFunded` event
+/// carries the authoritative `total_funded`.
+fn initial_merkle_deposit(instruction_data: Option<&[u8]>) -> Result<u64, IndexerError> {
+    let Some(data) = instruction_data else {
+        return Ok(0);
+    };
+    match data.split_first() {
+        Some((&CREATE_MERKLE_DISTRIBUTION_DISCRIMINATOR, args)) => {
+            CreateMerkleDistributionInstructionArgs::try_from_slice(args)
+                .map(|args| args.amount)
+                .map_err(|_| IndexerError::InvalidInstructionData("CreateMerkleDistribution"))
+        }
+        _ => Err(IndexerError::InvalidInstructionData("CreateMerkleDistribution")),
+    }
+}
+
+fn add(value: u64, amount: u64, field: &'static str) -> Result<u64, IndexerError> {
+    value.checked_add(amount).ok_or(IndexerError::MathOverflow(field))
+}
+
+#[cfg(test)]
+mod tests {
+    use rewards_program_client::{
+        events::{EventDiscriminator, EVENT_IX_TAG_LE},
+        types::{
+            ClaimedEvent, DistributionClosedEvent, DistributionPausedEvent, DistributionSettledEvent,
+            DistributionUnpausedEvent, VestingSchedule,
+        },
+    };
+
+    use super::*;
+
+    const AUTHORITY: Pubkey = Pubkey::new_from_array([1u8; 32]);
+    const MINT: Pubkey = Pubkey::new_from_array([2u8; 32]);
+    const SEED: Pubkey = Pubkey::new_from_array([3u8; 32]);
+    const ALICE: Pubkey = Pubkey::new_from_array([4u8; 32]);
+    const BOB: Pubkey = Pubkey::new_from_array([5u8; 32]);
+
+    struct History {
+        entries: Vec<LedgerEntry>,
+    }
+
+    impl History {
+        fn new() -> Self {
+            Self { entries: Vec::new() }
+        }
+
+        fn push<T: borsh::BorshSerialize>(&mut self, discriminator: EventDiscriminator, event: &T) -> &mut Self {
+            self.push_with(discriminator, event, None)
+        }
+
+        fn push_with<T: borsh::BorshSerialize>(
+            &mut self,
+            discriminator: EventDiscriminator,
+            event: &T,
+            instruction_data: Option<Vec<u8>>,
+        ) -> &mut Self {
+            let slot = self.entries.len() as u64 + 1;
+            let mut data = EVENT_IX_TAG_LE.to_vec();
+            data.push(discriminator as u8);
+            data.extend_from_slice(&borsh::to_vec(event).unwrap());
+
+            let position = EventPosition { slot, ..EventPosition::default() };
+            let entry = LedgerEntry::decode(position, "sig", slot as i64 * 100, instruction_data, data).unwrap();
+            self.entries.push(entry);
+            self
+        }
+    }
+
+    fn direct_address() -> Pubkey {
+        Pubkey::find_program_address(
+            &[DIRECT_DISTRIBUTION_SEED, MINT.as_ref(), AUTHORITY.as_ref(), SEED.as_ref()],
+            &REWARDS_PROGRAM_ID,
+        )
+        .0
+    }
+
+    fn direct_history() -> History {
+        let distribution = direct_address();
+        let mut history = History::new();
+        history
+            .push(
+                EventDiscriminator::DistributionCreated,
+                &DistributionCreatedEvent {
+                    authority: AUTHORITY,
+                    mint: MINT,
+                    seed: SEED,
+                    type_data: DistributionCreatedData::Direct { clawback_ts: 0 },
+                },
+            )
+            .push(
+                EventDiscriminator::RecipientAdded,
+                &RecipientAddedEvent {
+                    distribution,
+                    recipient: ALICE,
+                    amount: 1_000,
+                    schedule: VestingSchedule::Immediate,
+                },
+            )
+            .push(
+                EventDiscriminator::RecipientAdded,
+                &RecipientAddedEvent {
+                    distribution,
+                    recipient: BOB,
+                    amount: 500,
+                    schedule: VestingSchedule::Immediate,
+                },
+            )
+            .push(EventDiscriminator::Claimed, &ClaimedEvent { distribution, claimant: ALICE, amount: 300 })
+            .push(
+                EventDiscriminator::RecipientRevoked,
+                &RecipientRevokedEvent {
+                    distribution,
+                    recipient: BOB,
+                    revoke_mode: RevokeMode::NonVested,
+                    vested_transferred: 200,
+                    unvested_returned: 300,
+                },
+            );
+        history
+    }
+
+    #[test]
+    fn test_direct_totals() {
+        let history = direct_history();
+        let ledger = Ledger::replay(&history.entries).unwrap();
+
+        let distribution = ledger.distribution(&direct_address()).unwrap();
+        assert_eq!(distribution.kind, DistributionKind::Direct);
+        assert_eq!(distribution.total_allocated, 1_200);
+        assert_eq!(distribution.total_funded, 1_500);
+        assert_eq!(distribution.total_claimed, 500);
+        assert_eq!(distribution.total_revoked, 300);
+
+        let alice = ledger.position(&direct_address(), &ALICE).unwrap();
+        assert_eq!(alice.allocated, 1_000);
+        assert_eq!(alice.claimed, 300);
+        assert!(alice.revocation.is_none());
+
+        let bob = ledger.position(&direct_address(), &BOB).unwrap();
+        assert_eq!(bob.claimed, 200);
+        assert_eq!(bob.revocation.as_ref().unwrap().unvested_returned, 300);
+        assert_eq!(bob.closed_at, Some(500));
+    }
+
+    #[test]
+    fn test_frozen_recipient_stays_open() {
+        let distribution = direct_address();
+        let mut history = direct_history();
+        history
+            .push(
+                EventDiscriminator::RecipientRevoked,
+                &RecipientRevokedEvent {
+                    distribution,
+                    recipient: ALICE,
+                    revoke_mode: RevokeMode::Freeze,
+                    vested_transferred: 0,
+                    unvested_returned: 400,
+                },
+            )
+            .push(EventDiscriminator::Claimed, &ClaimedEvent { distribution, claimant: ALICE, amount: 300 });
+        let ledger = Ledger::replay(&history.entries).unwrap();
+
+        let view = ledger.distribution(&distribution).unwrap();
+        assert_eq!(view.total_allocated, 800);
+        assert_eq!(view.total_revoked, 700);
+
+        let alice = ledger.position(&distribution, &ALICE).unwrap();
+        assert_eq!(alice.claimed, 600);
+        assert!(alice.is_frozen());
+        assert_eq!(alice.closed_at, None);
+    }
+
+    #[test]
+    fn test_allocation_changes() {
+        let distribution = direct_address();
+        let mut history = direct_history();
+        history
+            .push(
+                EventDiscriminator::AllocationChanged,
+                &AllocationChangedEvent {
+                    distribution,
+                    recipient: ALICE,
+                    previous_amount: 1_000,
+                    new_amount: 1_500,
+                    schedule: VestingSchedule::Immediate,
+                },
+            )
+            .push(
+                EventDiscriminator::AllocationChanged,
+                &AllocationChangedEvent {
+                    distribution,
+                    recipient: ALICE,
+                    previous_amount: 1_500,
+                    new_amount: 1_100,
+                    schedule: VestingSchedule::Immediate,
+                },
+            );
+        let ledger = Ledger::replay(&history.entries).unwrap();
+
+        let view = ledger.distribution(&distribution).unwrap();
+        assert_eq!(view.total_allocated, 1_300);
+        assert_eq!(view.total_funded, 2_000);
+        assert_eq!(view.total_revoked, 700);
+        assert_eq!(ledger.position(&distribution, &ALICE).unwrap().allocated, 1_100);
+    }
+
+    #[test]
+    fn test_closed_distribution_keeps_history() {
+        let distribution = direct_address();
+        let mut history = direct_history();
+        history.push(
+            EventDiscriminator::DistributionClosed,
+            &DistributionClosedEvent { distribution, remaining_amount: 700 },
+        );
+        let ledger = Ledger::replay(&history.entries).unwrap();
+
+        let view = ledger.distribution(&distribution).unwrap();
+        assert_eq!(view.closed_at, Some(600));
+        assert_eq!(view.remaining_at_close, Some(700));
+        assert_eq!(ledger.positions(&distribution).count(), 2);
+    }
+
+    #[test]
+    fn test_settled_distribution_still_accepts_claims() {
+        let distribution = direct_address();
+        let mut history = direct_history();
+        history
+            .push(
+                EventDiscriminator::DistributionSettled,
+                &DistributionSettledEvent { distribution, swept_amount: 0, outstanding_amount: 700 },
+            )
+            .push(EventDiscriminator::Claimed, &ClaimedEvent { distribution, claimant: ALICE, amount: 100 });
+        let ledger = Ledger::replay(&history.entries).unwrap();
+
+        let view = ledger.distribution(&distribution).unwrap();
+        assert_eq!(view.settled_at, Some(600));
+        assert_eq!(view.closed_at, None);
+        assert_eq!(ledger.position(&distribution, &ALICE).unwrap().claimed, 400);
+    }
+
+    #[test]
+    fn test_event_after_close_is_rejected() {
+        let distribution = direct_address();
+        let mut history = direct_history();
+        history
+            .push(
+                EventDiscriminator::DistributionClosed,
+                &DistributionClosedEvent { distribution, remaining_amount: 0 },
+            )
+            .push(EventDiscriminator::Claimed, &ClaimedEvent { distribution, claimant: ALICE, amount: 1 });
+
+        let result = Ledger::replay(&history.entries);
+        assert!(matches!(result, Err(IndexerError::DistributionClosed(address)) if address == distribution));
+    }
+
+    #[test]
+    fn test_replay_is_order_independent() {
+        let history = direct_history();
+        let mut shuffled = history.entries.clone();
+        shuffled.reverse();
+
+        assert_eq!(Ledger::replay(&shuffled).unwrap(), Ledger::replay(&history.entries).unwrap());
+    }
+
+    #[test]
+    fn test_apply_rejects_out_of_order() {
+        let history = direct_history();
+        let mut ledger = Ledger::new();
+        ledger.apply(&history.entries[0]).unwrap();
+        ledger.apply(&history.entries[1]).unwrap();
+
+        let result = ledger.apply(&history.entries[1]);
+        assert!(matches!(result, Err(IndexerError::OutOfOrder { .. })));
+    }
+
+    #[test]
+    fn test_unknown_distribution() {
+        let mut history = History::new();
+        history.push(EventDiscriminator::Claimed, &ClaimedEvent { distribution: SEED, claimant: ALICE, amount: 1 });
+
+        let result = Ledger::replay(&history.entries);
+        assert!(matches!(result, Err(IndexerError::UnknownDistribution(address)) if address == SEED));
+    }
+
+    #[test]
+    fn test_direct_transfer_moves_position() {
+        let distribution = direct_address();
+        let mut history = direct_history();
+        history.push(
+            EventDiscriminator::RecipientTransferred,
+            &RecipientTransferredEvent { distribution, previous_recipient: ALICE, new_recipient: SEED },
+        );
+        let ledger = Ledger::replay(&history.entries).unwrap();
+
+        assert!(ledger.position(&distribution, &ALICE).is_none());
+        let moved = ledger.position(&distribution, &SEED).unwrap();
+        assert_eq!(moved.claimed, 300);
+        assert_eq!(moved.allocated, 1_000);
+    }
+
+    #[test]
+    fn test_merkle_claims_create_positions() {
+        let root = [9u8; 32];
+        let mut create_data = vec![CREATE_MERKLE_DISTRIBUTION_DISCRIMINATOR];
+        create_data.extend_from_slice(
+            &borsh::to_vec(&CreateMerkleDistributionInstructionArgs {
+                bump: 255,
+                revocable: 0,
+                amount: 5_000,
+                merkle_root: root,
+                total_amount: 5_000,
+                clawback_ts: 0,
+            })
+            .unwrap(),
+        );
+        let distribution = Pubkey::find_program_address(
+            &[MERKLE_DISTRIBUTION_SEED, MINT.as_ref(), AUTHORITY.as_ref(), SEED.as_ref()],
+            &REWARDS_PROGRAM_ID,
+        )
+        .0;
+
+        let mut history = History::new();
+        history
+            .push_with(
+                EventDiscriminator::DistributionCreated,
+                &DistributionCreatedEvent {
+                    authority: AUTHORITY,
+                    mint: MINT,
+                    seed: SEED,
+                    type_data: DistributionCreatedData::Merkle {
+                        merkle_root: root,
+                        total_amount: 5_000,
+                        clawback_ts: 0,
+                    },
+                },
+                Some(create_data),
+            )
+            .push(EventDiscriminator::Claimed, &ClaimedEvent { distribution, claimant: ALICE, amount: 400 })
+            .push(
+                EventDiscriminator::RecipientTransferred,
+                &RecipientTransferredEvent { distribution, previous_recipient: ALICE, new_recipient: BOB },
+            )
+            .push(EventDiscriminator::Claimed, &ClaimedEvent { distribution, claimant: ALICE, amount: 100 })
+            .push(EventDiscriminator::DistributionPaused, &DistributionPausedEvent { distribution, paused_at: 450 })
+            .push(
+                EventDiscriminator::DistributionUnpaused,
+                &DistributionUnpausedEvent { distribution, unpaused_at: 550, vesting_shift: 100 },
+            );
+        let ledger = Ledger::replay(&history.entries).unwrap();
+
+        let view = ledger.distribution(&distribution).unwrap();
+        assert_eq!(view.kind, DistributionKind::Merkle);
+        assert_eq!(view.total_funded, 5_000);
+        assert_eq!(view.total_claimed, 500);
+        assert!(!view.paused);
+        assert_eq!(view.vesting_offset, 100);
+
+        let alice = ledger.position(&distribution, &ALICE).unwrap();
+        assert_eq!(alice.claimed, 500);
+        assert_eq!(alice.owner, BOB);
+    }
+
+    #[test]
+    fn test_compressed_leaves_aggregate_per_recipient() {
+        let distribution = Pubkey::find_program_address(
+            &[COMPRESSED_DISTRIBUTION_SEED, MINT.as_ref(), AUTHORITY.as_ref(), SEED.as_ref()],
+            &REWARDS_PROGRAM_ID,
+        )
+        .0;
+        let added = |leaf_index, amount| CompressedRecipientAddedEvent {
+            distribution,
+            recipient: ALICE,
+            leaf_index,
+            amount,
+            base_vesting_offset: 0,
+            schedule: VestingSchedule::Immediate,
+        };
+
+        let mut history = History::new();
+        history
+            .push(
+                EventDiscriminator::DistributionCreated,
+                &DistributionCreatedEvent {
+                    authority: AUTHORITY,
+                    mint: MINT,
+                    seed: SEED,
+                    type_data: DistributionCreatedData::Compressed { clawback_ts: 900 },
+                },
+            )
+            .push(EventDiscriminator::CompressedRecipientAdded, &added(0, 1_000))
+            .push(EventDiscriminator::CompressedRecipientAdded, &added(1, 400))
+            .push(
+                EventDiscriminator::CompressedClaimed,
+                &CompressedClaimedEvent {
+                    distribution,
+                    recipient: ALICE,
+                    leaf_index: 1,
+                    amount: 250,
+                    claimed_amount: 250,
+                },
+            );
+        let ledger = Ledger::replay(&history.entries).unwrap();
+
+        let view = ledger.distribution(&distribution).unwrap();
+        assert_eq!(view.kind, DistributionKind::Compressed);
+        assert_eq!(view.clawback_ts, 900);
+        assert_eq!(view.total_allocated, 1_400);
+        assert_eq!(view.total_funded, 1_400);
+        assert_eq!(view.total_claimed, 250);
+
+        let alice = ledger.position(&distribution, &ALICE).unwrap();
+        assert_eq!(alice.allocated, 1_400);
+        assert_eq!(alice.claimed, 250);
+    }
+}
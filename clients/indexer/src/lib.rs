@@ -0,0 +1,24 @@
+//! Event-sourced ledger of Rewards Program distributions.
+//!
+//! Feed decoded [`LedgerEntry`] values (events plus the instruction that emitted them) into a
+//! [`Ledger`] to maintain per-distribution totals and per-recipient positions, including for
+//! accounts that have since been closed. Replaying the same history always produces the same
+//! ledger, and the `verify_*` functions compare it against live account state.
+//!
+//! With the `sqlite` feature, [`SqliteSink`] persists the raw history and view snapshots locally.
+
+mod entry;
+mod error;
+mod ledger;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+mod verify;
+mod view;
+
+pub use entry::*;
+pub use error::*;
+pub use ledger::*;
+#[cfg(feature = "sqlite")]
+pub use sqlite::*;
+pub use verify::*;
+pub use view::*;
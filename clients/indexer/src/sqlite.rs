@@ -0,0 +1,269 @@
+use std::path::Path;
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::{DistributionKind, EventPosition, IndexerError, Ledger, LedgerEntry};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS events (
+    slot INTEGER NOT NULL,
+    transaction_index INTEGER NOT NULL,
+    instruction_index INTEGER NOT NULL,
+    event_index INTEGER NOT NULL,
+    signature TEXT NOT NULL,
+    block_time INTEGER NOT NULL,
+    instruction_data BLOB,
+    event_data BLOB NOT NULL,
+    PRIMARY KEY (slot, transaction_index, instruction_index, event_index)
+);
+
+CREATE TABLE IF NOT EXISTS distributions (
+    address TEXT PRIMARY KEY,
+    kind TEXT NOT NULL,
+    authority TEXT NOT NULL,
+    mint TEXT NOT NULL,
+    seed TEXT NOT NULL,
+    created_slot INTEGER NOT NULL,
+    created_at INTEGER NOT NULL,
+    clawback_ts INTEGER NOT NULL,
+    merkle_root BLOB NOT NULL,
+    total_amount INTEGER NOT NULL,
+    epoch INTEGER NOT NULL,
+    total_allocated INTEGER NOT NULL,
+    total_funded INTEGER NOT NULL,
+    total_claimed INTEGER NOT NULL,
+    total_revoked INTEGER NOT NULL,
+    paused INTEGER NOT NULL,
+    vesting_offset INTEGER NOT NULL,
+    settled_at INTEGER,
+    closed_at INTEGER,
+    remaining_at_close INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS positions (
+    distribution TEXT NOT NULL,
+    recipient TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    allocated INTEGER NOT NULL,
+    claimed INTEGER NOT NULL,
+    delegate TEXT,
+    revoke_mode TEXT,
+    vested_transferred INTEGER,
+    unvested_returned INTEGER,
+    revoked_at INTEGER,
+    closed_at INTEGER,
+    PRIMARY KEY (distribution, recipient)
+);
+";
+
+/// Local SQLite store for raw event history and the materialized view.
+///
+/// The `events` table is the source of truth; `distributions` and `positions` are
+/// snapshots written by [`SqliteSink::save`] and can always be rebuilt with
+/// [`SqliteSink::replay`].
+pub struct SqliteSink {
+    conn: Connection,
+}
+
+impl SqliteSink {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, IndexerError> {
+        Self::init(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self, IndexerError> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self, IndexerError> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// Store an entry. Entries already stored at the same position are ignored,
+    /// so re-ingesting an overlapping range is safe.
+    pub fn append(&self, entry: &LedgerEntry) -> Result<(), IndexerError> {
+        let position = entry.position;
+        self.conn.execute(
+            "INSERT INTO events
+                (slot, transaction_index, instruction_index, event_index, signature, block_time, instruction_data, event_data)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+             ON CONFLICT DO NOTHING",
+            params![
+                position.slot,
+                position.transaction_index,
+                position.instruction_index,
+                position.event_index,
+                entry.signature,
+                entry.block_time,
+                entry.instruction_data,
+                entry.event_data,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Position of the latest stored entry, to resume ingestion from
+    pub fn last_position(&self) -> Result<Option<EventPosition>, IndexerError> {
+        let position = self
+            .conn
+            .query_row(
+                "SELECT slot, transaction_index, instruction_index, event_index FROM events
+                 ORDER BY slot DESC, transaction_index DESC, instruction_index DESC, event_index DESC
+                 LIMIT 1",
+                [],
+                |row| {
+                    Ok(EventPosition {
+                        slot: row.get(0)?,
+                        transaction_index: row.get(1)?,
+                        instruction_index: row.get(2)?,
+                        event_index: row.get(3)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(position)
+    }
+
+    /// All stored entries in position order
+    pub fn load_entries(&self) -> Result<Vec<LedgerEntry>, IndexerError> {
+        let mut statement = self.conn.prepare(
+            "SELECT slot, transaction_index, instruction_index, event_index, signature, block_time, instruction_data, event_data
+             FROM events
+             ORDER BY slot, transaction_index, instruction_index, event_index",
+        )?;
+        let rows = statement.query_map([], |row| {
+            let position = EventPosition {
+                slot: row.get(0)?,
+                transaction_index: row.get(1)?,
+                instruction_index: row.get(2)?,
+                event_index: row.get(3)?,
+            };
+            let signature: String = row.get(4)?;
+            let block_time: i64 = row.get(5)?;
+            let instruction_data: Option<Vec<u8>> = row.get(6)?;
+            let event_data: Vec<u8> = row.get(7)?;
+            Ok((position, signature, block_time, instruction_data, event_data))
+        })?;
+
+        let mut entries = Vec::new();
+        for row in rows {
+            let (position, signature, block_time, instruction_data, event_data) = row?;
+            entries.push(LedgerEntry::decode(position, &signature, block_time, instruction_data, event_data)?);
+        }
+        Ok(entries)
+    }
+
+    /// Rebuild the ledger from the stored history
+    pub fn replay(&self) -> Result<Ledger, IndexerError> {
+        Ledger::replay(&self.load_entries()?)
+    }
+
+    /// Replace the `distributions` and `positions` snapshots with `ledger`
+    pub fn save(&mut self, ledger: &Ledger) -> Result<(), IndexerError> {
+        let tx = self.conn.transaction()?;
+        tx.execute("DELETE FROM distributions", [])?;
+        tx.execute("DELETE FROM positions", [])?;
+
+        for view in ledger.distributions() {
+            let kind = match view.kind {
+                DistributionKind::Direct => "direct",
+                DistributionKind::Merkle => "merkle",
+                DistributionKind::Compressed => "compressed",
+            };
+            tx.execute(
+                "INSERT INTO distributions VALUES
+                    (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20)",
+                params![
+                    view.address.to_string(),
+                    kind,
+                    view.authority.to_string(),
+                    view.mint.to_string(),
+                    view.seed.to_string(),
+                    view.created_slot,
+                    view.created_at,
+                    view.clawback_ts,
+                    view.merkle_root.as_slice(),
+                    view.total_amount,
+                    view.epoch,
+                    view.total_allocated,
+                    view.total_funded,
+                    view.total_claimed,
+                    view.total_revoked,
+                    view.paused,
+                    view.vesting_offset,
+                    view.settled_at,
+                    view.closed_at,
+                    view.remaining_at_close,
+                ],
+            )?;
+        }
+
+        for position in ledger.all_positions() {
+            let revocation = position.revocation.as_ref();
+            tx.execute(
+                "INSERT INTO positions VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                params![
+                    position.distribution.to_string(),
+                    position.recipient.to_string(),
+                    position.owner.to_string(),
+                    position.allocated,
+                    position.claimed,
+                    position.delegate.map(|delegate| delegate.to_string()),
+                    revocation.map(|revocation| format!("{:?}", revocation.mode)),
+                    revocation.map(|revocation| revocation.vested_transferred),
+                    revocation.map(|revocation| revocation.unvested_returned),
+                    revocation.map(|revocation| revocation.revoked_at),
+                    position.closed_at,
+                ],
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rewards_program_client::events::{EventDiscriminator, EVENT_IX_TAG_LE};
+    use rewards_program_client::types::ClaimedEvent;
+    use solana_pubkey::Pubkey;
+
+    use super::*;
+
+    fn claimed_entry(slot: u64, amount: u64) -> LedgerEntry {
+        let event = ClaimedEvent {
+            distribution: Pubkey::new_from_array([1u8; 32]),
+            claimant: Pubkey::new_from_array([2u8; 32]),
+            amount,
+        };
+        let mut data = EVENT_IX_TAG_LE.to_vec();
+        data.push(EventDiscriminator::Claimed as u8);
+        data.extend_from_slice(&borsh::to_vec(&event).unwrap());
+
+        let position = EventPosition { slot, ..EventPosition::default() };
+        LedgerEntry::decode(position, "sig", slot as i64, Some(vec![7u8]), data).unwrap()
+    }
+
+    #[test]
+    fn test_append_and_load_round_trip() {
+        let sink = SqliteSink::open_in_memory().unwrap();
+        let first = claimed_entry(1, 10);
+        let second = claimed_entry(2, 20);
+        sink.append(&second).unwrap();
+        sink.append(&first).unwrap();
+        sink.append(&first).unwrap();
+
+        assert_eq!(sink.load_entries().unwrap(), vec![first, second.clone()]);
+        assert_eq!(sink.last_position().unwrap(), Some(second.position));
+    }
+
+    #[test]
+    fn test_save_empty_ledger() {
+        let mut sink = SqliteSink::open_in_memory().unwrap();
+        sink.save(&Ledger::new()).unwrap();
+
+        assert_eq!(sink.last_position().unwrap(), None);
+        assert_eq!(sink.replay().unwrap(), Ledger::new());
+    }
+}
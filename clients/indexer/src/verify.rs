@@ -0,0 +1,105 @@
+use std::fmt::Display;
+
+use rewards_program_client::accounts::{
+    CompressedDistribution, DirectDistribution, DirectRecipient, MerkleClaim, MerkleDistribution,
+};
+use solana_pubkey::Pubkey;
+
+use crate::{DistributionView, PositionView};
+
+/// One field where the indexed view disagrees with live account state
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Discrepancy {
+    pub account: Pubkey,
+    pub field: &'static str,
+    pub indexed: String,
+    pub on_chain: String,
+}
+
+struct Checker {
+    account: Pubkey,
+    discrepancies: Vec<Discrepancy>,
+}
+
+impl Checker {
+    fn new(account: Pubkey) -> Self {
+        Self { account, discrepancies: Vec::new() }
+    }
+
+    fn check<T: PartialEq + Display>(&mut self, field: &'static str, indexed: T, on_chain: T) -> &mut Self {
+        if indexed != on_chain {
+            self.discrepancies.push(Discrepancy {
+                account: self.account,
+                field,
+                indexed: indexed.to_string(),
+                on_chain: on_chain.to_string(),
+            });
+        }
+        self
+    }
+
+    fn finish(&mut self) -> Vec<Discrepancy> {
+        std::mem::take(&mut self.discrepancies)
+    }
+}
+
+/// Compare a direct distribution view with its account
+pub fn verify_direct_distribution(view: &DistributionView, account: &DirectDistribution) -> Vec<Discrepancy> {
+    Checker::new(view.address)
+        .check("authority", view.authority, account.authority)
+        .check("mint", view.mint, account.mint)
+        .check("total_allocated", view.total_allocated, account.total_allocated)
+        .check("total_funded", view.total_funded, account.total_funded)
+        .check("total_claimed", view.total_claimed, account.total_claimed)
+        .check("paused", view.paused, account.paused != 0)
+        .check("vesting_offset", view.vesting_offset, account.vesting_offset)
+        .check("settled", view.settled_at.is_some(), account.settled != 0)
+        .finish()
+}
+
+/// Compare a merkle distribution view with its account
+pub fn verify_merkle_distribution(view: &DistributionView, account: &MerkleDistribution) -> Vec<Discrepancy> {
+    Checker::new(view.address)
+        .check("authority", view.authority, account.authority)
+        .check("mint", view.mint, account.mint)
+        .check("merkle_root", Pubkey::new_from_array(view.merkle_root), Pubkey::new_from_array(account.merkle_root))
+        .check("total_amount", view.total_amount, account.total_amount)
+        .check("epoch", view.epoch, account.epoch)
+        .check("total_funded", view.total_funded, account.total_funded)
+        .check("total_claimed", view.total_claimed, account.total_claimed)
+        .check("paused", view.paused, account.paused != 0)
+        .check("vesting_offset", view.vesting_offset, account.vesting_offset)
+        .finish()
+}
+
+/// Compare a compressed distribution view with its account header
+pub fn verify_compressed_distribution(view: &DistributionView, account: &CompressedDistribution) -> Vec<Discrepancy> {
+    Checker::new(view.address)
+        .check("authority", view.authority, account.authority)
+        .check("mint", view.mint, account.mint)
+        .check("total_allocated", view.total_allocated, account.total_allocated)
+        .check("total_funded", view.total_funded, account.total_funded)
+        .check("total_claimed", view.total_claimed, account.total_claimed)
+        .check("paused", view.paused, account.paused != 0)
+        .check("vesting_offset", view.vesting_offset, account.vesting_offset)
+        .finish()
+}
+
+/// Compare a direct position view with its recipient account at `address`
+pub fn verify_direct_recipient(view: &PositionView, address: Pubkey, account: &DirectRecipient) -> Vec<Discrepancy> {
+    Checker::new(address)
+        .check("recipient", view.recipient, account.recipient)
+        .check("allocated", view.allocated, account.total_amount)
+        .check("claimed", view.claimed, account.claimed_amount)
+        .check("frozen", view.is_frozen(), account.revoked_at != 0)
+        .finish()
+}
+
+/// Compare a merkle position view with its claim account at `address`.
+///
+/// Vested tokens paid out by a revoke are counted in `view.claimed` but never written to
+/// the claim account, so they are excluded from the comparison.
+pub fn verify_merkle_claim(view: &PositionView, address: Pubkey, account: &MerkleClaim) -> Vec<Discrepancy> {
+    let revoked_payout = view.revocation.as_ref().map_or(0, |revocation| revocation.vested_transferred);
+    Checker::new(address).check("claimed", view.claimed.saturating_sub(revoked_payout), account.claimed_amount).finish()
+}
@@ -0,0 +1,84 @@
+use rewards_program_client::types::RevokeMode;
+use solana_pubkey::Pubkey;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DistributionKind {
+    Direct,
+    Merkle,
+    Compressed,
+}
+
+/// Materialized state of one distribution, including after its account is closed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DistributionView {
+    pub address: Pubkey,
+    pub kind: DistributionKind,
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub seed: Pubkey,
+    pub created_slot: u64,
+    pub created_at: i64,
+    pub clawback_ts: i64,
+    /// Merkle only: current root, its total amount and epoch
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub epoch: u64,
+    /// Direct only: sum of live allocations
+    pub total_allocated: u64,
+    pub total_funded: u64,
+    pub total_claimed: u64,
+    /// Tokens returned to the authority by revocations
+    pub total_revoked: u64,
+    pub paused: bool,
+    pub vesting_offset: i64,
+    /// Direct only: when a `ProtectRecipients` close left unclaimed allocations in the vault
+    pub settled_at: Option<i64>,
+    pub closed_at: Option<i64>,
+    /// Vault balance returned to the authority on close
+    pub remaining_at_close: Option<u64>,
+}
+
+/// Materialized state of one recipient (direct) or claimant (merkle) position
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PositionView {
+    pub distribution: Pubkey,
+    /// Direct: the recipient the position PDA is derived from. Merkle: the leaf claimant.
+    pub recipient: Pubkey,
+    /// Wallet that currently owns the position; differs from `recipient` after a merkle transfer
+    pub owner: Pubkey,
+    /// Direct only: allocated amount (merkle allocations live in the tree)
+    pub allocated: u64,
+    /// Claimed amount, including vested tokens sent out by a `NonVested` revoke
+    pub claimed: u64,
+    pub delegate: Option<Pubkey>,
+    pub revocation: Option<RevocationView>,
+    pub closed_at: Option<i64>,
+}
+
+impl PositionView {
+    pub(crate) fn new(distribution: Pubkey, recipient: Pubkey) -> Self {
+        Self {
+            distribution,
+            recipient,
+            owner: recipient,
+            allocated: 0,
+            claimed: 0,
+            delegate: None,
+            revocation: None,
+            closed_at: None,
+        }
+    }
+
+    /// Direct only: a `Freeze` revoke stopped vesting but left the position open
+    pub fn is_frozen(&self) -> bool {
+        self.revocation.as_ref().is_some_and(|revocation| matches!(revocation.mode, RevokeMode::Freeze))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RevocationView {
+    pub mode: RevokeMode,
+    pub vested_transferred: u64,
+    pub unvested_returned: u64,
+    pub revoked_at: i64,
+}
@@ -0,0 +1,177 @@
+use rewards_program::{
+    state::CompressedRecipient,
+    utils::{hash_tree_nodes, COMPRESSED_TREE_DEPTH, EMPTY_LEAF},
+};
+use solana_address::Address;
+
+/// Off-chain mirror of a compressed distribution's recipient tree.
+///
+/// Replay `CompressedRecipientAdded` events with [`push`](Self::push) and
+/// `CompressedClaimed` events with [`set_claimed`](Self::set_claimed) to get the
+/// current leaves, root and proofs for `ClaimCompressed`. Hashing uses the
+/// program's own functions, so roots match the on-chain `ConcurrentMerkleTree`.
+#[derive(Clone, Debug, Default)]
+pub struct CompressedTree {
+    recipients: Vec<CompressedRecipient>,
+}
+
+impl CompressedTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a recipient and return its leaf index
+    pub fn push(&mut self, recipient: CompressedRecipient) -> u32 {
+        self.recipients.push(recipient);
+        (self.recipients.len() - 1) as u32
+    }
+
+    /// Record a claim; returns false if `index` is out of range
+    pub fn set_claimed(&mut self, index: u32, claimed_amount: u64) -> bool {
+        match self.recipients.get_mut(index as usize) {
+            Some(recipient) => {
+                recipient.claimed_amount = claimed_amount;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn recipients(&self) -> &[CompressedRecipient] {
+        &self.recipients
+    }
+
+    pub fn get(&self, index: u32) -> Option<&CompressedRecipient> {
+        self.recipients.get(index as usize)
+    }
+
+    /// Leaf index of `recipient`'s first allocation
+    pub fn find(&self, recipient: &Address) -> Option<u32> {
+        self.recipients.iter().position(|r| &r.recipient == recipient).map(|index| index as u32)
+    }
+
+    pub fn len(&self) -> usize {
+        self.recipients.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.recipients.is_empty()
+    }
+
+    /// Current root, matching the on-chain tree after the same appends and claims
+    pub fn root(&self) -> [u8; 32] {
+        self.levels().last().expect("levels is never empty")[0]
+    }
+
+    /// `COMPRESSED_TREE_DEPTH` sibling hashes from the leaf at `index` up to the root
+    pub fn proof(&self, index: u32) -> Option<Vec<[u8; 32]>> {
+        if index as usize >= self.recipients.len() {
+            return None;
+        }
+
+        let levels = self.levels();
+        let mut empty = EMPTY_LEAF;
+        let mut proof = Vec::with_capacity(COMPRESSED_TREE_DEPTH);
+        for (level, nodes) in levels.iter().take(COMPRESSED_TREE_DEPTH).enumerate() {
+            let sibling = ((index as usize) >> level) ^ 1;
+            proof.push(nodes.get(sibling).copied().unwrap_or(empty));
+            empty = hash_tree_nodes(&empty, &empty);
+        }
+        Some(proof)
+    }
+
+    /// Every level from the leaves to the root; missing right nodes are empty subtrees
+    fn levels(&self) -> Vec<Vec<[u8; 32]>> {
+        let mut levels = vec![self.recipients.iter().map(CompressedRecipient::leaf_hash).collect::<Vec<_>>()];
+        let mut empty = EMPTY_LEAF;
+        for _ in 0..COMPRESSED_TREE_DEPTH {
+            let level = levels.last().expect("levels is never empty");
+            let next_level = if level.is_empty() {
+                vec![hash_tree_nodes(&empty, &empty)]
+            } else {
+                level.chunks(2).map(|pair| hash_tree_nodes(&pair[0], pair.get(1).unwrap_or(&empty))).collect()
+            };
+            levels.push(next_level);
+            empty = hash_tree_nodes(&empty, &empty);
+        }
+        levels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rewards_program::utils::{ConcurrentMerkleTree, VestingSchedule};
+
+    use super::*;
+
+    fn recipient(n: u8) -> CompressedRecipient {
+        CompressedRecipient::new(
+            Address::new_from_array([n; 32]),
+            1_000 * n as u64,
+            0,
+            0,
+            VestingSchedule::Linear { start_ts: 100, end_ts: 200 },
+        )
+    }
+
+    fn on_chain_tree() -> Vec<u8> {
+        let mut data = vec![0u8; ConcurrentMerkleTree::LEN];
+        ConcurrentMerkleTree::from_bytes_mut(&mut data).unwrap().initialize().unwrap();
+        data
+    }
+
+    #[test]
+    fn test_empty_root_matches_program() {
+        let data = on_chain_tree();
+        let on_chain = ConcurrentMerkleTree::from_bytes(&data).unwrap();
+        assert_eq!(CompressedTree::new().root(), on_chain.root());
+    }
+
+    #[test]
+    fn test_appends_match_program() {
+        let mut data = on_chain_tree();
+        let on_chain = ConcurrentMerkleTree::from_bytes_mut(&mut data).unwrap();
+        let mut tree = CompressedTree::new();
+
+        for n in 1..=5 {
+            let r = recipient(n);
+            let index = on_chain.append(&r.leaf_hash()).unwrap();
+            assert_eq!(tree.push(r), index);
+            assert_eq!(tree.root(), on_chain.root());
+        }
+    }
+
+    #[test]
+    fn test_proof_replaces_leaf_on_program() {
+        let mut data = on_chain_tree();
+        let on_chain = ConcurrentMerkleTree::from_bytes_mut(&mut data).unwrap();
+        let mut tree = CompressedTree::new();
+        for n in 1..=3 {
+            let r = recipient(n);
+            on_chain.append(&r.leaf_hash()).unwrap();
+            tree.push(r);
+        }
+
+        let previous = tree.get(1).unwrap().leaf_hash();
+        let proof = tree.proof(1).unwrap();
+        assert_eq!(proof.len(), COMPRESSED_TREE_DEPTH);
+
+        assert!(tree.set_claimed(1, 500));
+        let updated = tree.get(1).unwrap().leaf_hash();
+        on_chain.replace_leaf(&on_chain.root(), &previous, &updated, &proof, 1).unwrap();
+
+        assert_eq!(tree.root(), on_chain.root());
+    }
+
+    #[test]
+    fn test_find_and_bounds() {
+        let mut tree = CompressedTree::new();
+        tree.push(recipient(1));
+        tree.push(recipient(2));
+
+        assert_eq!(tree.find(&Address::new_from_array([2u8; 32])), Some(1));
+        assert_eq!(tree.find(&Address::new_from_array([9u8; 32])), None);
+        assert!(tree.proof(2).is_none());
+        assert!(!tree.set_claimed(2, 1));
+    }
+}
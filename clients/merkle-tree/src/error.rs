@@ -0,0 +1,53 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum MerkleTreeError {
+    /// No allocations were provided
+    #[error("At least one allocation is required")]
+    EmptyAllocations,
+
+    /// A claimant address could not be parsed
+    #[error("Invalid claimant address `{0}`")]
+    InvalidClaimant(String),
+
+    /// A claimant appears more than once
+    #[error("Duplicate claimant {0}")]
+    DuplicateClaimant(String),
+
+    /// An allocation has a zero amount
+    #[error("Allocation for {0} has a zero amount")]
+    ZeroAmount(String),
+
+    /// A schedule was rejected by `VestingSchedule::validate`
+    #[error("Invalid vesting schedule for {claimant}: {reason}")]
+    InvalidSchedule { claimant: String, reason: String },
+
+    /// A CSV row is missing a field required by its schedule type
+    #[error("Missing `{field}` for {claimant}")]
+    MissingField { claimant: String, field: &'static str },
+
+    /// A CSV row names a schedule type that does not exist
+    #[error("Unknown schedule type `{0}`")]
+    UnknownScheduleType(String),
+
+    /// A CSV tranche point is not `timestamp:cumulative_bps`
+    #[error("Invalid tranche point `{0}`, expected `timestamp:cumulative_bps`")]
+    InvalidTranchePoint(String),
+
+    /// The summed allocation amount overflows u64
+    #[error("Total allocation amount overflows u64")]
+    AmountOverflow,
+
+    /// A hex string is not a 32-byte hash
+    #[error("Invalid 32-byte hex string `{0}`")]
+    InvalidHex(String),
+
+    #[error(transparent)]
+    Csv(#[from] csv::Error),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
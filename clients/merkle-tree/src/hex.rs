@@ -0,0 +1,66 @@
+use crate::MerkleTreeError;
+
+const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+
+/// Encode a 32-byte hash as lowercase hex
+pub fn encode(bytes: &[u8; 32]) -> String {
+    let mut out = String::with_capacity(64);
+    for byte in bytes {
+        out.push(HEX_CHARS[(byte >> 4) as usize] as char);
+        out.push(HEX_CHARS[(byte & 0x0f) as usize] as char);
+    }
+    out
+}
+
+/// Decode a 64-character hex string (optionally `0x`-prefixed) into a 32-byte hash
+pub fn decode(value: &str) -> Result<[u8; 32], MerkleTreeError> {
+    let digits = value.strip_prefix("0x").unwrap_or(value).as_bytes();
+    if digits.len() != 64 {
+        return Err(MerkleTreeError::InvalidHex(value.to_string()));
+    }
+
+    let mut out = [0u8; 32];
+    for (i, pair) in digits.chunks_exact(2).enumerate() {
+        let high = nibble(pair[0]).ok_or_else(|| MerkleTreeError::InvalidHex(value.to_string()))?;
+        let low = nibble(pair[1]).ok_or_else(|| MerkleTreeError::InvalidHex(value.to_string()))?;
+        out[i] = (high << 4) | low;
+    }
+    Ok(out)
+}
+
+fn nibble(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (i * 8) as u8;
+        }
+        let encoded = encode(&bytes);
+        assert_eq!(encoded.len(), 64);
+        assert_eq!(decode(&encoded).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_decode_accepts_prefix_and_uppercase() {
+        let encoded = format!("0x{}", "AB".repeat(32));
+        assert_eq!(decode(&encoded).unwrap(), [0xab; 32]);
+    }
+
+    #[test]
+    fn test_decode_rejects_bad_input() {
+        assert!(decode("abcd").is_err());
+        assert!(decode(&"zz".repeat(32)).is_err());
+    }
+}
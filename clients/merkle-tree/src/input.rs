@@ -0,0 +1,389 @@
+use std::{collections::HashSet, fs::File, io::Read, path::Path, str::FromStr};
+
+use rewards_program::utils::{TrancheInterpolation, TranchePoint, VestingSchedule};
+use serde::{Deserialize, Serialize};
+use solana_address::Address;
+
+use crate::MerkleTreeError;
+
+/// Vesting schedule as written in allocation and proofs files.
+///
+/// Mirrors the program's `VestingSchedule`, tagged by a snake_case `type` field.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScheduleInput {
+    Immediate,
+    Linear { start_ts: i64, end_ts: i64 },
+    Cliff { cliff_ts: i64 },
+    CliffLinear { start_ts: i64, cliff_ts: i64, end_ts: i64 },
+    Stepped { start_ts: i64, period_secs: u64, num_periods: u32 },
+    CliffStepped { start_ts: i64, cliff_ts: i64, period_secs: u64, num_periods: u32 },
+    Tranches { interpolation: InterpolationInput, points: Vec<TranchePointInput> },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InterpolationInput {
+    Step,
+    Linear,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TranchePointInput {
+    pub timestamp: i64,
+    pub cumulative_bps: u16,
+}
+
+impl From<&ScheduleInput> for VestingSchedule {
+    fn from(schedule: &ScheduleInput) -> Self {
+        match schedule {
+            ScheduleInput::Immediate => VestingSchedule::Immediate {},
+            ScheduleInput::Linear { start_ts, end_ts } => {
+                VestingSchedule::Linear { start_ts: *start_ts, end_ts: *end_ts }
+            }
+            ScheduleInput::Cliff { cliff_ts } => VestingSchedule::Cliff { cliff_ts: *cliff_ts },
+            ScheduleInput::CliffLinear { start_ts, cliff_ts, end_ts } => {
+                VestingSchedule::CliffLinear { start_ts: *start_ts, cliff_ts: *cliff_ts, end_ts: *end_ts }
+            }
+            ScheduleInput::Stepped { start_ts, period_secs, num_periods } => {
+                VestingSchedule::Stepped { start_ts: *start_ts, period_secs: *period_secs, num_periods: *num_periods }
+            }
+            ScheduleInput::CliffStepped { start_ts, cliff_ts, period_secs, num_periods } => {
+                VestingSchedule::CliffStepped {
+                    start_ts: *start_ts,
+                    cliff_ts: *cliff_ts,
+                    period_secs: *period_secs,
+                    num_periods: *num_periods,
+                }
+            }
+            ScheduleInput::Tranches { interpolation, points } => VestingSchedule::Tranches {
+                interpolation: match interpolation {
+                    InterpolationInput::Step => TrancheInterpolation::Step {},
+                    InterpolationInput::Linear => TrancheInterpolation::Linear {},
+                },
+                points: points
+                    .iter()
+                    .map(|point| TranchePoint { timestamp: point.timestamp, cumulative_bps: point.cumulative_bps })
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl From<&VestingSchedule> for ScheduleInput {
+    fn from(schedule: &VestingSchedule) -> Self {
+        match schedule {
+            VestingSchedule::Immediate {} => ScheduleInput::Immediate,
+            VestingSchedule::Linear { start_ts, end_ts } => {
+                ScheduleInput::Linear { start_ts: *start_ts, end_ts: *end_ts }
+            }
+            VestingSchedule::Cliff { cliff_ts } => ScheduleInput::Cliff { cliff_ts: *cliff_ts },
+            VestingSchedule::CliffLinear { start_ts, cliff_ts, end_ts } => {
+                ScheduleInput::CliffLinear { start_ts: *start_ts, cliff_ts: *cliff_ts, end_ts: *end_ts }
+            }
+            VestingSchedule::Stepped { start_ts, period_secs, num_periods } => {
+                ScheduleInput::Stepped { start_ts: *start_ts, period_secs: *period_secs, num_periods: *num_periods }
+            }
+            VestingSchedule::CliffStepped { start_ts, cliff_ts, period_secs, num_periods } => {
+                ScheduleInput::CliffStepped {
+                    start_ts: *start_ts,
+                    cliff_ts: *cliff_ts,
+                    period_secs: *period_secs,
+                    num_periods: *num_periods,
+                }
+            }
+            VestingSchedule::Tranches { interpolation, points } => ScheduleInput::Tranches {
+                interpolation: match interpolation {
+                    TrancheInterpolation::Step {} => InterpolationInput::Step,
+                    TrancheInterpolation::Linear {} => InterpolationInput::Linear,
+                },
+                points: points
+                    .iter()
+                    .map(|point| TranchePointInput { timestamp: point.timestamp, cumulative_bps: point.cumulative_bps })
+                    .collect(),
+            },
+        }
+    }
+}
+
+/// One row of an allocations file, before validation
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocationInput {
+    pub claimant: String,
+    pub amount: u64,
+    pub schedule: ScheduleInput,
+}
+
+/// A validated allocation, ready to be hashed into a leaf
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Allocation {
+    pub claimant: Address,
+    pub amount: u64,
+    pub schedule: VestingSchedule,
+}
+
+impl TryFrom<&AllocationInput> for Allocation {
+    type Error = MerkleTreeError;
+
+    fn try_from(input: &AllocationInput) -> Result<Self, Self::Error> {
+        let claimant =
+            Address::from_str(&input.claimant).map_err(|_| MerkleTreeError::InvalidClaimant(input.claimant.clone()))?;
+
+        if input.amount == 0 {
+            return Err(MerkleTreeError::ZeroAmount(input.claimant.clone()));
+        }
+
+        let schedule = VestingSchedule::from(&input.schedule);
+        schedule.validate().map_err(|err| MerkleTreeError::InvalidSchedule {
+            claimant: input.claimant.clone(),
+            reason: format!("{err:?}"),
+        })?;
+
+        Ok(Self { claimant, amount: input.amount, schedule })
+    }
+}
+
+/// Validate allocation rows, rejecting bad addresses, zero amounts, invalid schedules
+/// and duplicate claimants. Input order is preserved.
+pub fn validate_allocations(inputs: &[AllocationInput]) -> Result<Vec<Allocation>, MerkleTreeError> {
+    if inputs.is_empty() {
+        return Err(MerkleTreeError::EmptyAllocations);
+    }
+
+    let mut seen = HashSet::with_capacity(inputs.len());
+    let mut allocations = Vec::with_capacity(inputs.len());
+
+    for input in inputs {
+        let allocation = Allocation::try_from(input)?;
+        if !seen.insert(allocation.claimant) {
+            return Err(MerkleTreeError::DuplicateClaimant(input.claimant.clone()));
+        }
+        allocations.push(allocation);
+    }
+
+    Ok(allocations)
+}
+
+/// Read allocations from a JSON array of `{ claimant, amount, schedule }` objects
+pub fn read_json<R: Read>(reader: R) -> Result<Vec<AllocationInput>, MerkleTreeError> {
+    Ok(serde_json::from_reader(reader)?)
+}
+
+/// Flat CSV row; schedule parameters that a schedule type does not use are left empty.
+#[derive(Debug, Deserialize)]
+struct CsvRecord {
+    claimant: String,
+    amount: u64,
+    schedule: String,
+    start_ts: Option<i64>,
+    cliff_ts: Option<i64>,
+    end_ts: Option<i64>,
+    period_secs: Option<u64>,
+    num_periods: Option<u32>,
+    interpolation: Option<InterpolationInput>,
+    points: Option<String>,
+}
+
+impl CsvRecord {
+    fn required<T>(&self, value: Option<T>, field: &'static str) -> Result<T, MerkleTreeError> {
+        value.ok_or_else(|| MerkleTreeError::MissingField { claimant: self.claimant.clone(), field })
+    }
+
+    fn into_input(self) -> Result<AllocationInput, MerkleTreeError> {
+        let schedule = match self.schedule.as_str() {
+            "immediate" => ScheduleInput::Immediate,
+            "linear" => ScheduleInput::Linear {
+                start_ts: self.required(self.start_ts, "start_ts")?,
+                end_ts: self.required(self.end_ts, "end_ts")?,
+            },
+            "cliff" => ScheduleInput::Cliff { cliff_ts: self.required(self.cliff_ts, "cliff_ts")? },
+            "cliff_linear" => ScheduleInput::CliffLinear {
+                start_ts: self.required(self.start_ts, "start_ts")?,
+                cliff_ts: self.required(self.cliff_ts, "cliff_ts")?,
+                end_ts: self.required(self.end_ts, "end_ts")?,
+            },
+            "stepped" => ScheduleInput::Stepped {
+                start_ts: self.required(self.start_ts, "start_ts")?,
+                period_secs: self.required(self.period_secs, "period_secs")?,
+                num_periods: self.required(self.num_periods, "num_periods")?,
+            },
+            "cliff_stepped" => ScheduleInput::CliffStepped {
+                start_ts: self.required(self.start_ts, "start_ts")?,
+                cliff_ts: self.required(self.cliff_ts, "cliff_ts")?,
+                period_secs: self.required(self.period_secs, "period_secs")?,
+                num_periods: self.required(self.num_periods, "num_periods")?,
+            },
+            "tranches" => ScheduleInput::Tranches {
+                interpolation: self.required(self.interpolation, "interpolation")?,
+                points: parse_tranche_points(&self.required(self.points.clone(), "points")?)?,
+            },
+            other => return Err(MerkleTreeError::UnknownScheduleType(other.to_string())),
+        };
+
+        Ok(AllocationInput { claimant: self.claimant, amount: self.amount, schedule })
+    }
+}
+
+/// Parse `timestamp:cumulative_bps` pairs separated by `;`
+fn parse_tranche_points(value: &str) -> Result<Vec<TranchePointInput>, MerkleTreeError> {
+    value
+        .split(';')
+        .map(str::trim)
+        .filter(|point| !point.is_empty())
+        .map(|point| {
+            let invalid = || MerkleTreeError::InvalidTranchePoint(point.to_string());
+            let (timestamp, cumulative_bps) = point.split_once(':').ok_or_else(invalid)?;
+            Ok(TranchePointInput {
+                timestamp: timestamp.trim().parse().map_err(|_| invalid())?,
+                cumulative_bps: cumulative_bps.trim().parse().map_err(|_| invalid())?,
+            })
+        })
+        .collect()
+}
+
+/// Read allocations from CSV with the header
+/// `claimant,amount,schedule,start_ts,cliff_ts,end_ts,period_secs,num_periods,interpolation,points`.
+///
+/// `schedule` is one of `immediate`, `linear`, `cliff`, `cliff_linear`, `stepped`,
+/// `cliff_stepped` or `tranches`. Tranche points are written as `ts:bps;ts:bps`.
+pub fn read_csv<R: Read>(reader: R) -> Result<Vec<AllocationInput>, MerkleTreeError> {
+    let mut csv_reader = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(reader);
+    csv_reader.deserialize::<CsvRecord>().map(|record| record?.into_input()).collect()
+}
+
+/// Read allocations from a `.csv` or `.json` file, chosen by extension
+pub fn read_allocations_file(path: &Path) -> Result<Vec<AllocationInput>, MerkleTreeError> {
+    let file = File::open(path)?;
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) if ext.eq_ignore_ascii_case("csv") => read_csv(file),
+        _ => read_json(file),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLAIMANT_A: &str = "11111111111111111111111111111112";
+    const CLAIMANT_B: &str = "SysvarC1ock11111111111111111111111111111111";
+
+    fn linear_input(claimant: &str, amount: u64) -> AllocationInput {
+        AllocationInput {
+            claimant: claimant.to_string(),
+            amount,
+            schedule: ScheduleInput::Linear { start_ts: 100, end_ts: 200 },
+        }
+    }
+
+    #[test]
+    fn test_read_json() {
+        let json = format!(
+            r#"[
+                {{"claimant": "{CLAIMANT_A}", "amount": 1000, "schedule": {{"type": "linear", "start_ts": 100, "end_ts": 200}}}},
+                {{"claimant": "{CLAIMANT_B}", "amount": 500, "schedule": {{"type": "tranches", "interpolation": "step",
+                    "points": [{{"timestamp": 100, "cumulative_bps": 5000}}, {{"timestamp": 200, "cumulative_bps": 10000}}]}}}}
+            ]"#
+        );
+        let inputs = read_json(json.as_bytes()).unwrap();
+        assert_eq!(inputs.len(), 2);
+        assert_eq!(inputs[0], linear_input(CLAIMANT_A, 1000));
+        assert!(matches!(inputs[1].schedule, ScheduleInput::Tranches { interpolation: InterpolationInput::Step, .. }));
+    }
+
+    #[test]
+    fn test_read_csv() {
+        let csv = format!(
+            "claimant,amount,schedule,start_ts,cliff_ts,end_ts,period_secs,num_periods,interpolation,points\n\
+             {CLAIMANT_A},1000,linear,100,,200,,,,\n\
+             {CLAIMANT_B},500,tranches,,,,,,linear,100:2500;200:10000\n"
+        );
+        let inputs = read_csv(csv.as_bytes()).unwrap();
+        assert_eq!(inputs[0], linear_input(CLAIMANT_A, 1000));
+        assert_eq!(
+            inputs[1].schedule,
+            ScheduleInput::Tranches {
+                interpolation: InterpolationInput::Linear,
+                points: vec![
+                    TranchePointInput { timestamp: 100, cumulative_bps: 2500 },
+                    TranchePointInput { timestamp: 200, cumulative_bps: 10000 },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_read_csv_missing_field() {
+        let csv = format!(
+            "claimant,amount,schedule,start_ts,cliff_ts,end_ts,period_secs,num_periods,interpolation,points\n\
+             {CLAIMANT_A},1000,stepped,100,,,60,,,\n"
+        );
+        let result = read_csv(csv.as_bytes());
+        assert!(matches!(result, Err(MerkleTreeError::MissingField { field: "num_periods", .. })));
+    }
+
+    #[test]
+    fn test_read_csv_unknown_schedule() {
+        let csv = format!(
+            "claimant,amount,schedule,start_ts,cliff_ts,end_ts,period_secs,num_periods,interpolation,points\n\
+             {CLAIMANT_A},1000,weekly,,,,,,,\n"
+        );
+        let result = read_csv(csv.as_bytes());
+        assert!(matches!(result, Err(MerkleTreeError::UnknownScheduleType(kind)) if kind == "weekly"));
+    }
+
+    #[test]
+    fn test_schedule_conversion_roundtrip() {
+        let schedules = [
+            ScheduleInput::Immediate,
+            ScheduleInput::Cliff { cliff_ts: 10 },
+            ScheduleInput::CliffStepped { start_ts: 0, cliff_ts: 60, period_secs: 30, num_periods: 4 },
+            ScheduleInput::Tranches {
+                interpolation: InterpolationInput::Step,
+                points: vec![TranchePointInput { timestamp: 1, cumulative_bps: 10000 }],
+            },
+        ];
+        for schedule in schedules {
+            assert_eq!(ScheduleInput::from(&VestingSchedule::from(&schedule)), schedule);
+        }
+    }
+
+    #[test]
+    fn test_validate_allocations_success() {
+        let allocations =
+            validate_allocations(&[linear_input(CLAIMANT_A, 1000), linear_input(CLAIMANT_B, 500)]).unwrap();
+        assert_eq!(allocations.len(), 2);
+        assert_eq!(allocations[0].claimant.to_string(), CLAIMANT_A);
+        assert_eq!(allocations[1].amount, 500);
+    }
+
+    #[test]
+    fn test_validate_allocations_rejects_empty() {
+        assert!(matches!(validate_allocations(&[]), Err(MerkleTreeError::EmptyAllocations)));
+    }
+
+    #[test]
+    fn test_validate_allocations_rejects_duplicate() {
+        let result = validate_allocations(&[linear_input(CLAIMANT_A, 1000), linear_input(CLAIMANT_A, 500)]);
+        assert!(matches!(result, Err(MerkleTreeError::DuplicateClaimant(_))));
+    }
+
+    #[test]
+    fn test_validate_allocations_rejects_zero_amount() {
+        let result = validate_allocations(&[linear_input(CLAIMANT_A, 0)]);
+        assert!(matches!(result, Err(MerkleTreeError::ZeroAmount(_))));
+    }
+
+    #[test]
+    fn test_validate_allocations_rejects_bad_address() {
+        let result = validate_allocations(&[linear_input("not-an-address", 1000)]);
+        assert!(matches!(result, Err(MerkleTreeError::InvalidClaimant(_))));
+    }
+
+    #[test]
+    fn test_validate_allocations_uses_program_schedule_rules() {
+        let mut input = linear_input(CLAIMANT_A, 1000);
+        input.schedule = ScheduleInput::Linear { start_ts: 200, end_ts: 100 };
+        let result = validate_allocations(&[input]);
+        assert!(matches!(result, Err(MerkleTreeError::InvalidSchedule { .. })));
+    }
+}
@@ -0,0 +1,27 @@
+//! # Rewards Merkle Tree
+//!
+//! Off-chain builder for Rewards Program merkle distributions.
+//!
+//! ## Features
+//! - Reads allocations (claimant, amount, vesting schedule) from CSV or JSON
+//! - Validates schedules with the same rules as the program
+//! - Builds the sorted-pair keccak tree used by `ClaimMerkle`
+//! - Exports a proofs file with the root, total and a proof per claimant
+//! - Mirrors a compressed distribution's recipient tree for `ClaimCompressed` proofs
+//!
+//! ## Compatibility
+//! Leaf hashes, schedule encoding and pair hashing are the program's own functions,
+//! so roots and proofs produced here always verify on-chain.
+
+pub mod compressed;
+pub mod error;
+pub mod hex;
+pub mod input;
+pub mod output;
+pub mod tree;
+
+pub use compressed::*;
+pub use error::*;
+pub use input::*;
+pub use output::*;
+pub use tree::*;
@@ -0,0 +1,156 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Read, Write},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{hex, MerkleTree, MerkleTreeError, ScheduleInput};
+
+/// Per-claimant entry of a proofs file.
+///
+/// `amount` and `schedule` are the leaf data the claimant passes to `ClaimMerkle`;
+/// `leaf` and `proof` are hex-encoded 32-byte hashes.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimProof {
+    pub claimant: String,
+    pub amount: u64,
+    pub schedule: ScheduleInput,
+    pub index: usize,
+    pub leaf: String,
+    pub proof: Vec<String>,
+}
+
+impl ClaimProof {
+    /// Decode the proof into the `Vec<[u8; 32]>` expected by `ClaimMerkle`
+    pub fn proof_bytes(&self) -> Result<Vec<[u8; 32]>, MerkleTreeError> {
+        self.proof.iter().map(|node| hex::decode(node)).collect()
+    }
+
+    pub fn leaf_bytes(&self) -> Result<[u8; 32], MerkleTreeError> {
+        hex::decode(&self.leaf)
+    }
+}
+
+/// Everything needed to create a merkle distribution and serve claims
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofsFile {
+    pub merkle_root: String,
+    pub total_amount: u64,
+    pub num_claims: usize,
+    pub claims: Vec<ClaimProof>,
+}
+
+impl ProofsFile {
+    pub fn from_tree(tree: &MerkleTree) -> Self {
+        let claims = tree
+            .leaves()
+            .iter()
+            .enumerate()
+            .map(|(index, leaf)| ClaimProof {
+                claimant: leaf.allocation.claimant.to_string(),
+                amount: leaf.allocation.amount,
+                schedule: ScheduleInput::from(&leaf.allocation.schedule),
+                index,
+                leaf: hex::encode(&leaf.hash),
+                proof: tree.proof(index).unwrap_or_default().iter().map(hex::encode).collect(),
+            })
+            .collect();
+
+        Self {
+            merkle_root: hex::encode(&tree.root()),
+            total_amount: tree.total_amount(),
+            num_claims: tree.len(),
+            claims,
+        }
+    }
+
+    pub fn root_bytes(&self) -> Result<[u8; 32], MerkleTreeError> {
+        hex::decode(&self.merkle_root)
+    }
+
+    /// Entry for `claimant` (base58)
+    pub fn find(&self, claimant: &str) -> Option<&ClaimProof> {
+        self.claims.iter().find(|claim| claim.claimant == claimant)
+    }
+
+    pub fn to_writer<W: Write>(&self, writer: W) -> Result<(), MerkleTreeError> {
+        serde_json::to_writer_pretty(writer, self)?;
+        Ok(())
+    }
+
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self, MerkleTreeError> {
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    pub fn write_file(&self, path: &Path) -> Result<(), MerkleTreeError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.to_writer(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    pub fn read_file(path: &Path) -> Result<Self, MerkleTreeError> {
+        Self::from_reader(File::open(path)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rewards_program::utils::{compute_leaf_hash, verify_proof, VestingSchedule};
+    use solana_address::Address;
+
+    use super::*;
+    use crate::Allocation;
+
+    fn tree() -> MerkleTree {
+        let allocations = (0..5u8)
+            .map(|i| Allocation {
+                claimant: Address::new_from_array([i + 1; 32]),
+                amount: 100 + i as u64,
+                schedule: VestingSchedule::Cliff { cliff_ts: 1_000 + i as i64 },
+            })
+            .collect();
+        MerkleTree::new(allocations).unwrap()
+    }
+
+    #[test]
+    fn test_from_tree() {
+        let tree = tree();
+        let file = ProofsFile::from_tree(&tree);
+
+        assert_eq!(file.root_bytes().unwrap(), tree.root());
+        assert_eq!(file.total_amount, 100 + 101 + 102 + 103 + 104);
+        assert_eq!(file.num_claims, 5);
+        assert_eq!(file.claims[3].index, 3);
+        assert_eq!(file.claims[3].claimant, Address::new_from_array([4u8; 32]).to_string());
+    }
+
+    #[test]
+    fn test_exported_proofs_verify_on_program() {
+        let file = ProofsFile::from_tree(&tree());
+        let root = file.root_bytes().unwrap();
+
+        for claim in &file.claims {
+            let claimant: Address = claim.claimant.parse().unwrap();
+            let schedule = VestingSchedule::from(&claim.schedule);
+            let leaf = compute_leaf_hash(&claimant, claim.amount, &schedule.to_bytes());
+            assert_eq!(claim.leaf_bytes().unwrap(), leaf);
+            assert!(verify_proof(&claim.proof_bytes().unwrap(), &root, &leaf));
+        }
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let file = ProofsFile::from_tree(&tree());
+        let mut bytes = Vec::new();
+        file.to_writer(&mut bytes).unwrap();
+
+        let parsed = ProofsFile::from_reader(bytes.as_slice()).unwrap();
+        assert_eq!(parsed, file);
+
+        let claimant = Address::new_from_array([2u8; 32]).to_string();
+        assert_eq!(parsed.find(&claimant).unwrap().index, 1);
+    }
+}
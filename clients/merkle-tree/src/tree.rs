@@ -0,0 +1,197 @@
+use rewards_program::utils::{compute_leaf_hash, hash_pair};
+use solana_address::Address;
+
+use crate::{validate_allocations, Allocation, AllocationInput, MerkleTreeError};
+
+/// A validated allocation and its leaf hash
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleLeaf {
+    pub allocation: Allocation,
+    pub hash: [u8; 32],
+}
+
+impl MerkleLeaf {
+    pub fn new(allocation: Allocation) -> Self {
+        let hash = compute_leaf_hash(&allocation.claimant, allocation.amount, &allocation.schedule.to_bytes());
+        Self { allocation, hash }
+    }
+}
+
+/// Sorted-pair keccak merkle tree matching the program's `verify_proof`.
+///
+/// Leaves keep their input order. Each level hashes adjacent pairs with `hash_pair`;
+/// an odd node at the end of a level is promoted unchanged.
+#[derive(Clone, Debug)]
+pub struct MerkleTree {
+    leaves: Vec<MerkleLeaf>,
+    levels: Vec<Vec<[u8; 32]>>,
+    total_amount: u64,
+}
+
+impl MerkleTree {
+    /// Build a tree from validated allocations
+    pub fn new(allocations: Vec<Allocation>) -> Result<Self, MerkleTreeError> {
+        if allocations.is_empty() {
+            return Err(MerkleTreeError::EmptyAllocations);
+        }
+
+        let total_amount = allocations
+            .iter()
+            .try_fold(0u64, |total, allocation| total.checked_add(allocation.amount))
+            .ok_or(MerkleTreeError::AmountOverflow)?;
+
+        let leaves: Vec<MerkleLeaf> = allocations.into_iter().map(MerkleLeaf::new).collect();
+
+        let mut levels = vec![leaves.iter().map(|leaf| leaf.hash).collect::<Vec<_>>()];
+        while levels.last().map_or(0, Vec::len) > 1 {
+            let level = levels.last().expect("levels is never empty");
+            let next_level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_pair(left, right),
+                    [single] => *single,
+                    _ => unreachable!("chunks(2) yields one or two nodes"),
+                })
+                .collect();
+            levels.push(next_level);
+        }
+
+        Ok(Self { leaves, levels, total_amount })
+    }
+
+    /// Validate raw allocation rows and build a tree from them
+    pub fn from_inputs(inputs: &[AllocationInput]) -> Result<Self, MerkleTreeError> {
+        Self::new(validate_allocations(inputs)?)
+    }
+
+    /// Root to pass to `CreateMerkleDistribution` / `UpdateMerkleRoot`
+    pub fn root(&self) -> [u8; 32] {
+        self.levels.last().expect("levels is never empty")[0]
+    }
+
+    /// Sum of all allocation amounts
+    pub fn total_amount(&self) -> u64 {
+        self.total_amount
+    }
+
+    pub fn leaves(&self) -> &[MerkleLeaf] {
+        &self.leaves
+    }
+
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Index of the leaf for `claimant`
+    pub fn find(&self, claimant: &Address) -> Option<usize> {
+        self.leaves.iter().position(|leaf| &leaf.allocation.claimant == claimant)
+    }
+
+    /// Sibling hashes from the leaf at `index` up to the root
+    pub fn proof(&self, index: usize) -> Option<Vec<[u8; 32]>> {
+        if index >= self.leaves.len() {
+            return None;
+        }
+
+        let mut proof = Vec::with_capacity(self.levels.len());
+        let mut position = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = position ^ 1;
+            if sibling < level.len() {
+                proof.push(level[sibling]);
+            }
+            position /= 2;
+        }
+        Some(proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rewards_program::utils::{verify_proof, VestingSchedule};
+
+    use super::*;
+
+    fn allocations(count: usize) -> Vec<Allocation> {
+        (0..count)
+            .map(|i| Allocation {
+                claimant: Address::new_from_array([i as u8 + 1; 32]),
+                amount: 1_000 * (i as u64 + 1),
+                schedule: VestingSchedule::Linear { start_ts: 100, end_ts: 200 + i as i64 },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_single_leaf_root_is_leaf() {
+        let tree = MerkleTree::new(allocations(1)).unwrap();
+        assert_eq!(tree.root(), tree.leaves()[0].hash);
+        assert!(tree.proof(0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_leaf_matches_program_hash() {
+        let tree = MerkleTree::new(allocations(3)).unwrap();
+        let leaf = &tree.leaves()[1];
+        let expected =
+            compute_leaf_hash(&leaf.allocation.claimant, leaf.allocation.amount, &leaf.allocation.schedule.to_bytes());
+        assert_eq!(leaf.hash, expected);
+    }
+
+    #[test]
+    fn test_two_leaf_root() {
+        let tree = MerkleTree::new(allocations(2)).unwrap();
+        let leaves = tree.leaves();
+        assert_eq!(tree.root(), hash_pair(&leaves[0].hash, &leaves[1].hash));
+    }
+
+    #[test]
+    fn test_odd_leaf_is_promoted() {
+        let tree = MerkleTree::new(allocations(3)).unwrap();
+        let leaves = tree.leaves();
+        let left = hash_pair(&leaves[0].hash, &leaves[1].hash);
+        assert_eq!(tree.root(), hash_pair(&left, &leaves[2].hash));
+        assert_eq!(tree.proof(2).unwrap(), vec![left]);
+    }
+
+    #[test]
+    fn test_every_proof_verifies_on_program() {
+        for count in 1..=17 {
+            let tree = MerkleTree::new(allocations(count)).unwrap();
+            for (index, leaf) in tree.leaves().iter().enumerate() {
+                let proof = tree.proof(index).unwrap();
+                assert!(verify_proof(&proof, &tree.root(), &leaf.hash), "count {count}, index {index}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_proof_out_of_bounds() {
+        let tree = MerkleTree::new(allocations(2)).unwrap();
+        assert!(tree.proof(2).is_none());
+    }
+
+    #[test]
+    fn test_find_and_total() {
+        let tree = MerkleTree::new(allocations(4)).unwrap();
+        assert_eq!(tree.find(&Address::new_from_array([3u8; 32])), Some(2));
+        assert_eq!(tree.find(&Address::new_from_array([9u8; 32])), None);
+        assert_eq!(tree.total_amount(), 1_000 + 2_000 + 3_000 + 4_000);
+    }
+
+    #[test]
+    fn test_total_overflow() {
+        let mut allocations = allocations(2);
+        allocations[0].amount = u64::MAX;
+        assert!(matches!(MerkleTree::new(allocations), Err(MerkleTreeError::AmountOverflow)));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(matches!(MerkleTree::new(vec![]), Err(MerkleTreeError::EmptyAllocations)));
+    }
+}
@@ -0,0 +1,351 @@
+//! Decoder for events emitted through the program's `EmitEvent` self-CPI.
+//!
+//! Each event is the data of an inner instruction invoked by the Rewards Program on itself:
+//! the Anchor-compatible `EVENT_IX_TAG` (8 bytes, little-endian), one `EventDiscriminator`
+//! byte, then the borsh-encoded event struct from [`crate::types`].
+
+use borsh::BorshDeserialize;
+use solana_pubkey::Pubkey;
+use thiserror::Error;
+
+use crate::{
+    types::{
+        AllocationChangedEvent, AuthorityTransferredEvent, BasketClaimedEvent, BasketDistributionClosedEvent,
+        BasketDistributionCreatedEvent, BasketRecipientAddedEvent, ClaimClosedEvent, ClaimDelegateUpdatedEvent,
+        ClaimedEvent, CompressedClaimedEvent, CompressedRecipientAddedEvent, DistributionClosedEvent,
+        DistributionCreatedEvent, DistributionFundedEvent, DistributionPausedEvent, DistributionSettledEvent,
+        DistributionUnpausedEvent, MerkleRootUpdatedEvent, RecipientAddedEvent, RecipientRevokedEvent,
+        RecipientTransferredEvent,
+    },
+    REWARDS_PROGRAM_ID,
+};
+
+/// Anchor-compatible event tag: `Sha256("anchor:event")[..8]`
+pub const EVENT_IX_TAG: u64 = 0x1d9acb512ea545e4;
+pub const EVENT_IX_TAG_LE: [u8; 8] = EVENT_IX_TAG.to_le_bytes();
+
+/// Length of the event prefix (`EVENT_IX_TAG_LE` + discriminator byte)
+pub const EVENT_DISCRIMINATOR_LEN: usize = 8 + 1;
+
+/// Event discriminator values, mirroring the program's `EventDiscriminators`
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventDiscriminator {
+    Claimed = 0,
+    DistributionClosed = 1,
+    DistributionCreated = 2,
+    RecipientAdded = 3,
+    ClaimClosed = 4,
+    RecipientRevoked = 5,
+    AuthorityTransferred = 6,
+    MerkleRootUpdated = 7,
+    DistributionFunded = 8,
+    ClaimDelegateUpdated = 9,
+    RecipientTransferred = 10,
+    DistributionPaused = 11,
+    DistributionUnpaused = 12,
+    BasketDistributionCreated = 13,
+    BasketRecipientAdded = 14,
+    BasketClaimed = 15,
+    BasketDistributionClosed = 16,
+    CompressedRecipientAdded = 17,
+    CompressedClaimed = 18,
+    DistributionSettled = 19,
+    AllocationChanged = 20,
+}
+
+impl TryFrom<u8> for EventDiscriminator {
+    type Error = EventDecodeError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Claimed),
+            1 => Ok(Self::DistributionClosed),
+            2 => Ok(Self::DistributionCreated),
+            3 => Ok(Self::RecipientAdded),
+            4 => Ok(Self::ClaimClosed),
+            5 => Ok(Self::RecipientRevoked),
+            6 => Ok(Self::AuthorityTransferred),
+            7 => Ok(Self::MerkleRootUpdated),
+            8 => Ok(Self::DistributionFunded),
+            9 => Ok(Self::ClaimDelegateUpdated),
+            10 => Ok(Self::RecipientTransferred),
+            11 => Ok(Self::DistributionPaused),
+            12 => Ok(Self::DistributionUnpaused),
+            13 => Ok(Self::BasketDistributionCreated),
+            14 => Ok(Self::BasketRecipientAdded),
+            15 => Ok(Self::BasketClaimed),
+            16 => Ok(Self::BasketDistributionClosed),
+            17 => Ok(Self::CompressedRecipientAdded),
+            18 => Ok(Self::CompressedClaimed),
+            19 => Ok(Self::DistributionSettled),
+            20 => Ok(Self::AllocationChanged),
+            other => Err(EventDecodeError::UnknownDiscriminator(other)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum EventDecodeError {
+    /// Data is shorter than the tag and discriminator
+    #[error("Event data is too short")]
+    TooShort,
+
+    /// Data does not start with `EVENT_IX_TAG_LE`
+    #[error("Event data does not start with the event instruction tag")]
+    InvalidTag,
+
+    /// Discriminator byte does not match any known event
+    #[error("Unknown event discriminator {0}")]
+    UnknownDiscriminator(u8),
+
+    /// Event body does not match the layout for its discriminator
+    #[error("Failed to deserialize {discriminator:?} event: {reason}")]
+    InvalidData { discriminator: EventDiscriminator, reason: String },
+}
+
+/// A decoded Rewards Program event
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RewardsEvent {
+    Claimed(ClaimedEvent),
+    DistributionClosed(DistributionClosedEvent),
+    DistributionCreated(DistributionCreatedEvent),
+    RecipientAdded(RecipientAddedEvent),
+    ClaimClosed(ClaimClosedEvent),
+    RecipientRevoked(RecipientRevokedEvent),
+    AuthorityTransferred(AuthorityTransferredEvent),
+    MerkleRootUpdated(MerkleRootUpdatedEvent),
+    DistributionFunded(DistributionFundedEvent),
+    ClaimDelegateUpdated(ClaimDelegateUpdatedEvent),
+    RecipientTransferred(RecipientTransferredEvent),
+    DistributionPaused(DistributionPausedEvent),
+    DistributionUnpaused(DistributionUnpausedEvent),
+    BasketDistributionCreated(BasketDistributionCreatedEvent),
+    BasketRecipientAdded(BasketRecipientAddedEvent),
+    BasketClaimed(BasketClaimedEvent),
+    BasketDistributionClosed(BasketDistributionClosedEvent),
+    CompressedRecipientAdded(CompressedRecipientAddedEvent),
+    CompressedClaimed(CompressedClaimedEvent),
+    DistributionSettled(DistributionSettledEvent),
+    AllocationChanged(AllocationChangedEvent),
+}
+
+impl RewardsEvent {
+    /// Decode raw event bytes (tag, discriminator and body)
+    pub fn decode(data: &[u8]) -> Result<Self, EventDecodeError> {
+        if data.len() < EVENT_DISCRIMINATOR_LEN {
+            return Err(EventDecodeError::TooShort);
+        }
+        if data[..8] != EVENT_IX_TAG_LE {
+            return Err(EventDecodeError::InvalidTag);
+        }
+
+        let discriminator = EventDiscriminator::try_from(data[8])?;
+        let body = &data[EVENT_DISCRIMINATOR_LEN..];
+
+        let event = match discriminator {
+            EventDiscriminator::Claimed => Self::Claimed(parse(discriminator, body)?),
+            EventDiscriminator::DistributionClosed => Self::DistributionClosed(parse(discriminator, body)?),
+            EventDiscriminator::DistributionCreated => Self::DistributionCreated(parse(discriminator, body)?),
+            EventDiscriminator::RecipientAdded => Self::RecipientAdded(parse(discriminator, body)?),
+            EventDiscriminator::ClaimClosed => Self::ClaimClosed(parse(discriminator, body)?),
+            EventDiscriminator::RecipientRevoked => Self::RecipientRevoked(parse(discriminator, body)?),
+            EventDiscriminator::AuthorityTransferred => Self::AuthorityTransferred(parse(discriminator, body)?),
+            EventDiscriminator::MerkleRootUpdated => Self::MerkleRootUpdated(parse(discriminator, body)?),
+            EventDiscriminator::DistributionFunded => Self::DistributionFunded(parse(discriminator, body)?),
+            EventDiscriminator::ClaimDelegateUpdated => Self::ClaimDelegateUpdated(parse(discriminator, body)?),
+            EventDiscriminator::RecipientTransferred => Self::RecipientTransferred(parse(discriminator, body)?),
+            EventDiscriminator::DistributionPaused => Self::DistributionPaused(parse(discriminator, body)?),
+            EventDiscriminator::DistributionUnpaused => Self::DistributionUnpaused(parse(discriminator, body)?),
+            EventDiscriminator::BasketDistributionCreated => {
+                Self::BasketDistributionCreated(parse(discriminator, body)?)
+            }
+            EventDiscriminator::BasketRecipientAdded => Self::BasketRecipientAdded(parse(discriminator, body)?),
+            EventDiscriminator::BasketClaimed => Self::BasketClaimed(parse(discriminator, body)?),
+            EventDiscriminator::BasketDistributionClosed => Self::BasketDistributionClosed(parse(discriminator, body)?),
+            EventDiscriminator::CompressedRecipientAdded => Self::CompressedRecipientAdded(parse(discriminator, body)?),
+            EventDiscriminator::CompressedClaimed => Self::CompressedClaimed(parse(discriminator, body)?),
+            EventDiscriminator::DistributionSettled => Self::DistributionSettled(parse(discriminator, body)?),
+            EventDiscriminator::AllocationChanged => Self::AllocationChanged(parse(discriminator, body)?),
+        };
+        Ok(event)
+    }
+
+    pub fn discriminator(&self) -> EventDiscriminator {
+        match self {
+            Self::Claimed(_) => EventDiscriminator::Claimed,
+            Self::DistributionClosed(_) => EventDiscriminator::DistributionClosed,
+            Self::DistributionCreated(_) => EventDiscriminator::DistributionCreated,
+            Self::RecipientAdded(_) => EventDiscriminator::RecipientAdded,
+            Self::ClaimClosed(_) => EventDiscriminator::ClaimClosed,
+            Self::RecipientRevoked(_) => EventDiscriminator::RecipientRevoked,
+            Self::AuthorityTransferred(_) => EventDiscriminator::AuthorityTransferred,
+            Self::MerkleRootUpdated(_) => EventDiscriminator::MerkleRootUpdated,
+            Self::DistributionFunded(_) => EventDiscriminator::DistributionFunded,
+            Self::ClaimDelegateUpdated(_) => EventDiscriminator::ClaimDelegateUpdated,
+            Self::RecipientTransferred(_) => EventDiscriminator::RecipientTransferred,
+            Self::DistributionPaused(_) => EventDiscriminator::DistributionPaused,
+            Self::DistributionUnpaused(_) => EventDiscriminator::DistributionUnpaused,
+            Self::BasketDistributionCreated(_) => EventDiscriminator::BasketDistributionCreated,
+            Self::BasketRecipientAdded(_) => EventDiscriminator::BasketRecipientAdded,
+            Self::BasketClaimed(_) => EventDiscriminator::BasketClaimed,
+            Self::BasketDistributionClosed(_) => EventDiscriminator::BasketDistributionClosed,
+            Self::CompressedRecipientAdded(_) => EventDiscriminator::CompressedRecipientAdded,
+            Self::CompressedClaimed(_) => EventDiscriminator::CompressedClaimed,
+            Self::DistributionSettled(_) => EventDiscriminator::DistributionSettled,
+            Self::AllocationChanged(_) => EventDiscriminator::AllocationChanged,
+        }
+    }
+}
+
+/// Whether instruction data carries the event tag
+pub fn is_event_instruction(data: &[u8]) -> bool {
+    data.starts_with(&EVENT_IX_TAG_LE)
+}
+
+/// Decode every Rewards event among a transaction's inner instructions.
+///
+/// `instructions` yields `(program_id, data)` per inner instruction, with the program id
+/// already resolved from the transaction's account keys. Instructions for other programs
+/// and Rewards instructions without the event tag are skipped.
+pub fn decode_events<'a, I>(instructions: I) -> Result<Vec<RewardsEvent>, EventDecodeError>
+where
+    I: IntoIterator<Item = (&'a Pubkey, &'a [u8])>,
+{
+    instructions
+        .into_iter()
+        .filter(|(program_id, data)| **program_id == REWARDS_PROGRAM_ID && is_event_instruction(data))
+        .map(|(_, data)| RewardsEvent::decode(data))
+        .collect()
+}
+
+fn parse<T: BorshDeserialize>(discriminator: EventDiscriminator, body: &[u8]) -> Result<T, EventDecodeError> {
+    borsh::from_slice(body).map_err(|err| EventDecodeError::InvalidData { discriminator, reason: err.to_string() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DistributionCreatedData, VestingSchedule};
+
+    fn encode<T: borsh::BorshSerialize>(discriminator: EventDiscriminator, event: &T) -> Vec<u8> {
+        let mut data = EVENT_IX_TAG_LE.to_vec();
+        data.push(discriminator as u8);
+        data.extend_from_slice(&borsh::to_vec(event).unwrap());
+        data
+    }
+
+    fn claimed() -> ClaimedEvent {
+        ClaimedEvent {
+            distribution: Pubkey::new_from_array([1u8; 32]),
+            claimant: Pubkey::new_from_array([2u8; 32]),
+            amount: 500,
+        }
+    }
+
+    #[test]
+    fn test_decode_claimed() {
+        let data = encode(EventDiscriminator::Claimed, &claimed());
+        let event = RewardsEvent::decode(&data).unwrap();
+        assert_eq!(event, RewardsEvent::Claimed(claimed()));
+        assert_eq!(event.discriminator(), EventDiscriminator::Claimed);
+    }
+
+    #[test]
+    fn test_decode_distribution_created_merkle() {
+        let created = DistributionCreatedEvent {
+            authority: Pubkey::new_from_array([1u8; 32]),
+            mint: Pubkey::new_from_array([2u8; 32]),
+            seed: Pubkey::new_from_array([3u8; 32]),
+            type_data: DistributionCreatedData::Merkle { merkle_root: [7u8; 32], total_amount: 1_000, clawback_ts: 99 },
+        };
+        let data = encode(EventDiscriminator::DistributionCreated, &created);
+        assert_eq!(RewardsEvent::decode(&data).unwrap(), RewardsEvent::DistributionCreated(created));
+    }
+
+    #[test]
+    fn test_decode_basket_claimed() {
+        let claimed = BasketClaimedEvent {
+            distribution: Pubkey::new_from_array([1u8; 32]),
+            claimant: Pubkey::new_from_array([2u8; 32]),
+            amounts: vec![100, 0, 25],
+        };
+        let data = encode(EventDiscriminator::BasketClaimed, &claimed);
+        assert_eq!(RewardsEvent::decode(&data).unwrap(), RewardsEvent::BasketClaimed(claimed));
+    }
+
+    #[test]
+    fn test_decode_compressed_claimed() {
+        let claimed = CompressedClaimedEvent {
+            distribution: Pubkey::new_from_array([1u8; 32]),
+            recipient: Pubkey::new_from_array([2u8; 32]),
+            leaf_index: 4,
+            amount: 250,
+            claimed_amount: 750,
+        };
+        let data = encode(EventDiscriminator::CompressedClaimed, &claimed);
+        assert_eq!(RewardsEvent::decode(&data).unwrap(), RewardsEvent::CompressedClaimed(claimed));
+    }
+
+    #[test]
+    fn test_decode_distribution_settled() {
+        let settled = DistributionSettledEvent {
+            distribution: Pubkey::new_from_array([1u8; 32]),
+            swept_amount: 300,
+            outstanding_amount: 700,
+        };
+        let data = encode(EventDiscriminator::DistributionSettled, &settled);
+        assert_eq!(RewardsEvent::decode(&data).unwrap(), RewardsEvent::DistributionSettled(settled));
+    }
+
+    #[test]
+    fn test_decode_allocation_changed() {
+        let changed = AllocationChangedEvent {
+            distribution: Pubkey::new_from_array([1u8; 32]),
+            recipient: Pubkey::new_from_array([2u8; 32]),
+            previous_amount: 1_000,
+            new_amount: 600,
+            schedule: VestingSchedule::Linear { start_ts: 100, end_ts: 200 },
+        };
+        let data = encode(EventDiscriminator::AllocationChanged, &changed);
+        assert_eq!(RewardsEvent::decode(&data).unwrap(), RewardsEvent::AllocationChanged(changed));
+    }
+
+    #[test]
+    fn test_decode_rejects_bad_prefix() {
+        assert_eq!(RewardsEvent::decode(&[0u8; 4]), Err(EventDecodeError::TooShort));
+
+        let mut data = encode(EventDiscriminator::Claimed, &claimed());
+        data[0] ^= 0xff;
+        assert_eq!(RewardsEvent::decode(&data), Err(EventDecodeError::InvalidTag));
+
+        let mut data = encode(EventDiscriminator::Claimed, &claimed());
+        data[8] = 200;
+        assert_eq!(RewardsEvent::decode(&data), Err(EventDecodeError::UnknownDiscriminator(200)));
+    }
+
+    #[test]
+    fn test_decode_rejects_truncated_body() {
+        let data = encode(EventDiscriminator::Claimed, &claimed());
+        let result = RewardsEvent::decode(&data[..data.len() - 1]);
+        assert!(matches!(
+            result,
+            Err(EventDecodeError::InvalidData { discriminator: EventDiscriminator::Claimed, .. })
+        ));
+    }
+
+    #[test]
+    fn test_decode_events_filters_program_and_tag() {
+        let other_program = Pubkey::new_from_array([9u8; 32]);
+        let event_data = encode(EventDiscriminator::Claimed, &claimed());
+        let non_event = vec![4u8, 0, 0];
+
+        let instructions = [
+            (&other_program, event_data.as_slice()),
+            (&REWARDS_PROGRAM_ID, non_event.as_slice()),
+            (&REWARDS_PROGRAM_ID, event_data.as_slice()),
+        ];
+        let events = decode_events(instructions).unwrap();
+        assert_eq!(events, vec![RewardsEvent::Claimed(claimed())]);
+    }
+}
@@ -2,17 +2,45 @@ use pinocchio::{account::AccountView, entrypoint, error::ProgramError, Address,
 
 use crate::{
     instructions::{
+        authority::{
+            accept_authority::process_accept_authority, cancel_authority_proposal::process_cancel_authority_proposal,
+            propose_authority::process_propose_authority,
+        },
+        basket::{
+            add_recipient::process_add_basket_recipient, claim::process_claim_basket,
+            close_distribution::process_close_basket_distribution, close_recipient::process_close_basket_recipient,
+            create_distribution::process_create_basket_distribution,
+        },
+        compressed::{
+            add_recipient::process_add_compressed_recipient, claim::process_claim_compressed,
+            close_distribution::process_close_compressed_distribution,
+            create_distribution::process_create_compressed_distribution,
+        },
+        delegate::{
+            remove_claim_delegate::process_remove_claim_delegate, set_claim_delegate::process_set_claim_delegate,
+        },
         direct::{
-            add_recipient::process_add_direct_recipient, claim::process_claim_direct,
+            add_recipient::process_add_direct_recipient, add_recipients::process_add_direct_recipients,
+            claim::process_claim_direct, claim_many::process_claim_direct_many,
             close_distribution::process_close_direct_distribution, close_recipient::process_close_direct_recipient,
-            create_distribution::process_create_direct_distribution, revoke_recipient::process_revoke_direct_recipient,
+            crank_claim::process_crank_claim_direct, create_distribution::process_create_direct_distribution,
+            decrease_allocation::process_decrease_direct_allocation,
+            increase_allocation::process_increase_direct_allocation, revoke_recipient::process_revoke_direct_recipient,
+            transfer_recipient::process_transfer_direct_recipient,
         },
         emit_event::process_emit_event,
+        funding::fund_distribution::process_fund_distribution,
         merkle::{
-            claim::process_claim_merkle, close_claim::process_close_merkle_claim,
-            close_distribution::process_close_merkle_distribution,
+            claim::process_claim_merkle, claim_bitmap::process_claim_merkle_bitmap,
+            close_claim::process_close_merkle_claim, close_claim_bitmap::process_close_merkle_claim_bitmap,
+            close_claim_early::process_close_merkle_claim_early, close_distribution::process_close_merkle_distribution,
+            crank_claim::process_crank_claim_merkle, create_claim_bitmap::process_create_merkle_claim_bitmap,
             create_distribution::process_create_merkle_distribution, revoke_claim::process_revoke_merkle_claim,
+            transfer_claim::process_transfer_merkle_claim, update_root::process_update_merkle_root,
         },
+        migration::migrate_account::process_migrate_account,
+        pause::{pause_distribution::process_pause_distribution, unpause_distribution::process_unpause_distribution},
+        transfer::set_transferable::process_set_transferable,
     },
     traits::RewardsInstructionDiscriminators,
 };
@@ -55,6 +83,100 @@ pub fn process_instruction(program_id: &Address, accounts: &[AccountView], instr
         RewardsInstructionDiscriminators::RevokeMerkleClaim => {
             process_revoke_merkle_claim(program_id, accounts, instruction_data)
         }
+        RewardsInstructionDiscriminators::ProposeAuthority => {
+            process_propose_authority(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::AcceptAuthority => {
+            process_accept_authority(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::CancelAuthorityProposal => {
+            process_cancel_authority_proposal(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::UpdateMerkleRoot => {
+            process_update_merkle_root(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::FundDistribution => {
+            process_fund_distribution(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::AddDirectRecipients => {
+            process_add_direct_recipients(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::SetClaimDelegate => {
+            process_set_claim_delegate(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::RemoveClaimDelegate => {
+            process_remove_claim_delegate(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::CrankClaimDirect => {
+            process_crank_claim_direct(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::CrankClaimMerkle => {
+            process_crank_claim_merkle(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::SetTransferable => {
+            process_set_transferable(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::TransferDirectRecipient => {
+            process_transfer_direct_recipient(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::TransferMerkleClaim => {
+            process_transfer_merkle_claim(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::PauseDistribution => {
+            process_pause_distribution(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::UnpauseDistribution => {
+            process_unpause_distribution(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::ClaimDirectMany => {
+            process_claim_direct_many(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::CreateBasketDistribution => {
+            process_create_basket_distribution(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::AddBasketRecipient => {
+            process_add_basket_recipient(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::ClaimBasket => process_claim_basket(program_id, accounts, instruction_data),
+        RewardsInstructionDiscriminators::CloseBasketDistribution => {
+            process_close_basket_distribution(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::CreateMerkleClaimBitmap => {
+            process_create_merkle_claim_bitmap(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::ClaimMerkleBitmap => {
+            process_claim_merkle_bitmap(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::CloseMerkleClaimBitmap => {
+            process_close_merkle_claim_bitmap(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::CreateCompressedDistribution => {
+            process_create_compressed_distribution(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::AddCompressedRecipient => {
+            process_add_compressed_recipient(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::ClaimCompressed => {
+            process_claim_compressed(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::CloseCompressedDistribution => {
+            process_close_compressed_distribution(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::MigrateAccount => {
+            process_migrate_account(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::IncreaseDirectAllocation => {
+            process_increase_direct_allocation(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::DecreaseDirectAllocation => {
+            process_decrease_direct_allocation(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::CloseBasketRecipient => {
+            process_close_basket_recipient(program_id, accounts, instruction_data)
+        }
+        RewardsInstructionDiscriminators::CloseMerkleClaimEarly => {
+            process_close_merkle_claim_early(program_id, accounts, instruction_data)
+        }
         RewardsInstructionDiscriminators::EmitEvent => process_emit_event(program_id, accounts),
     }
 }
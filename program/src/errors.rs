@@ -84,6 +84,142 @@ pub enum RewardsProgramError {
     /// (19) Claimant has already been revoked
     #[error("Claimant has already been revoked")]
     ClaimantAlreadyRevoked,
+
+    /// (20) No authority transfer is pending
+    #[error("No authority transfer is pending")]
+    NoPendingAuthority,
+
+    /// (21) Invalid new authority
+    #[error("Invalid new authority")]
+    InvalidNewAuthority,
+
+    /// (22) Merkle root epoch must increase
+    #[error("Merkle root epoch must increase")]
+    InvalidEpoch,
+
+    /// (23) Recipient accounts do not match recipient entries
+    #[error("Recipient accounts do not match recipient entries")]
+    InvalidRecipientCount,
+
+    /// (24) Mint has an extension that distributions do not support
+    #[error("Mint has an extension that distributions do not support")]
+    UnsupportedMintExtension,
+
+    /// (25) Invalid transfer fee mode
+    #[error("Invalid transfer fee mode")]
+    InvalidTransferFeeMode,
+
+    /// (26) Tranche points are empty, unordered or do not end at 100%
+    #[error("Tranche points are empty, unordered or do not end at 100%")]
+    InvalidTrancheSchedule,
+
+    /// (27) Claim destination must be the recipient's associated token account
+    #[error("Claim destination must be the recipient's associated token account")]
+    InvalidClaimDestination,
+
+    /// (28) Invalid claim delegate
+    #[error("Invalid claim delegate")]
+    InvalidClaimDelegate,
+
+    /// (29) Recipient transfers are disabled for this distribution
+    #[error("Recipient transfers are disabled for this distribution")]
+    RecipientTransfersDisabled,
+
+    /// (30) Invalid new recipient
+    #[error("Invalid new recipient")]
+    InvalidNewRecipient,
+
+    /// (31) Claimant has been redirected to another wallet
+    #[error("Claimant has been redirected to another wallet")]
+    ClaimantRedirected,
+
+    /// (32) Distribution is paused
+    #[error("Distribution is paused")]
+    DistributionPaused,
+
+    /// (33) Distribution is not paused
+    #[error("Distribution is not paused")]
+    DistributionNotPaused,
+
+    /// (34) Distribution accounts must be non-empty (distribution, recipient_account, distribution_vault) triples
+    #[error("Distribution accounts must be non-empty (distribution, recipient_account, distribution_vault) triples")]
+    InvalidDistributionCount,
+
+    /// (35) Basket must hold 1 to 4 distinct mints
+    #[error("Basket must hold 1 to 4 distinct mints")]
+    InvalidBasketMints,
+
+    /// (36) Basket accounts do not match the basket mints
+    #[error("Basket accounts do not match the basket mints")]
+    InvalidBasketAccounts,
+
+    /// (37) Invalid merkle claim mode
+    #[error("Invalid merkle claim mode")]
+    InvalidClaimMode,
+
+    /// (38) Instruction does not support the distribution's claim mode
+    #[error("Instruction does not support the distribution's claim mode")]
+    ClaimModeMismatch,
+
+    /// (39) Leaf has already been claimed
+    #[error("Leaf has already been claimed")]
+    LeafAlreadyClaimed,
+
+    /// (40) Compressed recipient tree is full
+    #[error("Compressed recipient tree is full")]
+    CompressedTreeFull,
+
+    /// (41) Proof root is no longer in the tree's changelog or the leaf changed since
+    #[error("Proof root is no longer in the tree's changelog or the leaf changed since")]
+    StaleCompressedProof,
+
+    /// (42) Invalid close policy
+    #[error("Invalid close policy")]
+    InvalidClosePolicy,
+
+    /// (43) Distribution is settled and only accepts claims
+    #[error("Distribution is settled and only accepts claims")]
+    DistributionSettled,
+
+    /// (44) Claim window has not opened yet
+    #[error("Claim window has not opened yet")]
+    ClaimWindowNotOpen,
+
+    /// (45) Claim window has closed
+    #[error("Claim window has closed")]
+    ClaimWindowClosed,
+
+    /// (46) Account version is not supported
+    #[error("Account version is not supported")]
+    InvalidAccountVersion,
+
+    /// (47) Account is already at the current version
+    #[error("Account is already at the current version")]
+    AccountAlreadyMigrated,
+
+    /// (48) New schedule would relock already vested tokens
+    #[error("New schedule would relock already vested tokens")]
+    ScheduleRelocksVestedTokens,
+
+    /// (49) Amount exceeds the recipient's unvested allocation
+    #[error("Amount exceeds the recipient's unvested allocation")]
+    InsufficientUnvestedAmount,
+
+    /// (50) Only the recipient or their claim delegate can choose a crank amount or fee mode
+    #[error("Only the recipient or their claim delegate can choose a crank amount or fee mode")]
+    CrankAmountRestricted,
+
+    /// (51) Account must be migrated to the current version before this update
+    #[error("Account must be migrated to the current version before this update")]
+    AccountNotMigrated,
+
+    /// (52) New schedule would unlock part of the existing allocation later
+    #[error("New schedule would unlock part of the existing allocation later")]
+    ScheduleDelaysUnlocks,
+
+    /// (53) Schedule has too many remaining steps to compare
+    #[error("Schedule has too many remaining steps to compare")]
+    TooManyScheduleSteps,
 }
 
 impl From<RewardsProgramError> for ProgramError {
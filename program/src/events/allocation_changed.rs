@@ -0,0 +1,89 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::{
+    traits::{EventDiscriminator, EventDiscriminators, EventSerialize},
+    utils::VestingSchedule,
+};
+
+/// Emitted when a direct recipient's allocation is increased or decreased.
+///
+/// `schedule` is the recipient's schedule after the change.
+#[derive(CodamaType)]
+pub struct AllocationChangedEvent {
+    pub distribution: Address,
+    pub recipient: Address,
+    pub previous_amount: u64,
+    pub new_amount: u64,
+    pub schedule: VestingSchedule,
+}
+
+impl EventDiscriminator for AllocationChangedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::AllocationChanged as u8;
+}
+
+impl EventSerialize for AllocationChangedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let schedule_bytes = self.schedule.to_bytes();
+        let mut data = Vec::with_capacity(Self::BASE_DATA_LEN + schedule_bytes.len());
+        data.extend_from_slice(self.distribution.as_ref());
+        data.extend_from_slice(self.recipient.as_ref());
+        data.extend_from_slice(&self.previous_amount.to_le_bytes());
+        data.extend_from_slice(&self.new_amount.to_le_bytes());
+        data.extend_from_slice(&schedule_bytes);
+        data
+    }
+}
+
+impl AllocationChangedEvent {
+    /// distribution(32) + recipient(32) + previous_amount(8) + new_amount(8)
+    pub const BASE_DATA_LEN: usize = 32 + 32 + 8 + 8;
+
+    #[inline(always)]
+    pub fn new(
+        distribution: Address,
+        recipient: Address,
+        previous_amount: u64,
+        new_amount: u64,
+        schedule: VestingSchedule,
+    ) -> Self {
+        Self { distribution, recipient, previous_amount, new_amount, schedule }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_allocation_changed_event_to_bytes_inner() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let recipient = Address::new_from_array([2u8; 32]);
+        let event = AllocationChangedEvent::new(distribution, recipient, 1000, 1500, VestingSchedule::Immediate {});
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), AllocationChangedEvent::BASE_DATA_LEN + 1); // schedule = 1 byte
+        assert_eq!(&bytes[..32], distribution.as_ref());
+        assert_eq!(&bytes[32..64], recipient.as_ref());
+        assert_eq!(&bytes[64..72], &1000u64.to_le_bytes());
+        assert_eq!(&bytes[72..80], &1500u64.to_le_bytes());
+        assert_eq!(bytes[80], 0); // Immediate discriminant
+    }
+
+    #[test]
+    fn test_allocation_changed_event_to_bytes() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let recipient = Address::new_from_array([2u8; 32]);
+        let schedule = VestingSchedule::Linear { start_ts: 100, end_ts: 200 };
+        let event = AllocationChangedEvent::new(distribution, recipient, 1000, 400, schedule);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + AllocationChangedEvent::BASE_DATA_LEN + 17);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::AllocationChanged as u8);
+    }
+}
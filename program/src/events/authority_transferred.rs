@@ -0,0 +1,84 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct AuthorityTransferredEvent {
+    pub distribution: Address,
+    pub previous_authority: Address,
+    pub new_authority: Address,
+}
+
+impl EventDiscriminator for AuthorityTransferredEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::AuthorityTransferred as u8;
+}
+
+impl EventSerialize for AuthorityTransferredEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.distribution.as_ref());
+        data.extend_from_slice(self.previous_authority.as_ref());
+        data.extend_from_slice(self.new_authority.as_ref());
+        data
+    }
+}
+
+impl AuthorityTransferredEvent {
+    pub const DATA_LEN: usize = 32 + 32 + 32; // distribution + previous_authority + new_authority
+
+    #[inline(always)]
+    pub fn new(distribution: Address, previous_authority: Address, new_authority: Address) -> Self {
+        Self { distribution, previous_authority, new_authority }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_authority_transferred_event_new() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let previous_authority = Address::new_from_array([2u8; 32]);
+        let new_authority = Address::new_from_array([3u8; 32]);
+
+        let event = AuthorityTransferredEvent::new(distribution, previous_authority, new_authority);
+
+        assert_eq!(event.distribution, distribution);
+        assert_eq!(event.previous_authority, previous_authority);
+        assert_eq!(event.new_authority, new_authority);
+    }
+
+    #[test]
+    fn test_authority_transferred_event_to_bytes_inner() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let previous_authority = Address::new_from_array([2u8; 32]);
+        let new_authority = Address::new_from_array([3u8; 32]);
+        let event = AuthorityTransferredEvent::new(distribution, previous_authority, new_authority);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), AuthorityTransferredEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], distribution.as_ref());
+        assert_eq!(&bytes[32..64], previous_authority.as_ref());
+        assert_eq!(&bytes[64..96], new_authority.as_ref());
+    }
+
+    #[test]
+    fn test_authority_transferred_event_to_bytes() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let previous_authority = Address::new_from_array([2u8; 32]);
+        let new_authority = Address::new_from_array([3u8; 32]);
+        let event = AuthorityTransferredEvent::new(distribution, previous_authority, new_authority);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + AuthorityTransferredEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::AuthorityTransferred as u8);
+        assert_eq!(&bytes[9..41], distribution.as_ref());
+    }
+}
@@ -0,0 +1,77 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct BasketClaimedEvent {
+    pub distribution: Address,
+    pub claimant: Address,
+    /// Amount claimed per basket mint, in slot order
+    pub amounts: Vec<u64>,
+}
+
+impl EventDiscriminator for BasketClaimedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::BasketClaimed as u8;
+}
+
+impl EventSerialize for BasketClaimedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::BASE_DATA_LEN + 8 * self.amounts.len());
+        data.extend_from_slice(self.distribution.as_ref());
+        data.extend_from_slice(self.claimant.as_ref());
+        data.extend_from_slice(&(self.amounts.len() as u32).to_le_bytes());
+        for amount in &self.amounts {
+            data.extend_from_slice(&amount.to_le_bytes());
+        }
+        data
+    }
+}
+
+impl BasketClaimedEvent {
+    /// distribution(32) + claimant(32) + amount count(4)
+    pub const BASE_DATA_LEN: usize = 32 + 32 + 4;
+
+    #[inline(always)]
+    pub fn new(distribution: Address, claimant: Address, amounts: Vec<u64>) -> Self {
+        Self { distribution, claimant, amounts }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_basket_claimed_event_to_bytes_inner() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let claimant = Address::new_from_array([2u8; 32]);
+        let event = BasketClaimedEvent::new(distribution, claimant, vec![10, 0, 30]);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), BasketClaimedEvent::BASE_DATA_LEN + 24);
+        assert_eq!(&bytes[..32], distribution.as_ref());
+        assert_eq!(&bytes[32..64], claimant.as_ref());
+        assert_eq!(&bytes[64..68], &3u32.to_le_bytes());
+        assert_eq!(&bytes[68..76], &10u64.to_le_bytes());
+        assert_eq!(&bytes[76..84], &0u64.to_le_bytes());
+        assert_eq!(&bytes[84..92], &30u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_basket_claimed_event_to_bytes() {
+        let event =
+            BasketClaimedEvent::new(Address::new_from_array([1u8; 32]), Address::new_from_array([2u8; 32]), vec![1]);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + BasketClaimedEvent::BASE_DATA_LEN + 8);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::BasketClaimed as u8);
+    }
+}
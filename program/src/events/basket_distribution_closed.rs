@@ -0,0 +1,71 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct BasketDistributionClosedEvent {
+    pub distribution: Address,
+    /// Amount returned to the authority per basket mint, in slot order
+    pub remaining_amounts: Vec<u64>,
+}
+
+impl EventDiscriminator for BasketDistributionClosedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::BasketDistributionClosed as u8;
+}
+
+impl EventSerialize for BasketDistributionClosedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::BASE_DATA_LEN + 8 * self.remaining_amounts.len());
+        data.extend_from_slice(self.distribution.as_ref());
+        data.extend_from_slice(&(self.remaining_amounts.len() as u32).to_le_bytes());
+        for amount in &self.remaining_amounts {
+            data.extend_from_slice(&amount.to_le_bytes());
+        }
+        data
+    }
+}
+
+impl BasketDistributionClosedEvent {
+    /// distribution(32) + amount count(4)
+    pub const BASE_DATA_LEN: usize = 32 + 4;
+
+    #[inline(always)]
+    pub fn new(distribution: Address, remaining_amounts: Vec<u64>) -> Self {
+        Self { distribution, remaining_amounts }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_basket_distribution_closed_event_to_bytes_inner() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let event = BasketDistributionClosedEvent::new(distribution, vec![100, 200]);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), BasketDistributionClosedEvent::BASE_DATA_LEN + 16);
+        assert_eq!(&bytes[..32], distribution.as_ref());
+        assert_eq!(&bytes[32..36], &2u32.to_le_bytes());
+        assert_eq!(&bytes[36..44], &100u64.to_le_bytes());
+        assert_eq!(&bytes[44..52], &200u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_basket_distribution_closed_event_to_bytes() {
+        let event = BasketDistributionClosedEvent::new(Address::new_from_array([1u8; 32]), vec![5]);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + BasketDistributionClosedEvent::BASE_DATA_LEN + 8);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::BasketDistributionClosed as u8);
+    }
+}
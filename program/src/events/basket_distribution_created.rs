@@ -0,0 +1,95 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct BasketDistributionCreatedEvent {
+    pub distribution: Address,
+    pub authority: Address,
+    pub seeds: Address,
+    pub mints: Vec<Address>,
+    pub clawback_ts: i64,
+}
+
+impl EventDiscriminator for BasketDistributionCreatedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::BasketDistributionCreated as u8;
+}
+
+impl EventSerialize for BasketDistributionCreatedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::BASE_DATA_LEN + 32 * self.mints.len());
+        data.extend_from_slice(self.distribution.as_ref());
+        data.extend_from_slice(self.authority.as_ref());
+        data.extend_from_slice(self.seeds.as_ref());
+        data.extend_from_slice(&(self.mints.len() as u32).to_le_bytes());
+        for mint in &self.mints {
+            data.extend_from_slice(mint.as_ref());
+        }
+        data.extend_from_slice(&self.clawback_ts.to_le_bytes());
+        data
+    }
+}
+
+impl BasketDistributionCreatedEvent {
+    /// distribution(32) + authority(32) + seeds(32) + mint count(4) + clawback_ts(8)
+    pub const BASE_DATA_LEN: usize = 32 + 32 + 32 + 4 + 8;
+
+    #[inline(always)]
+    pub fn new(
+        distribution: Address,
+        authority: Address,
+        seeds: Address,
+        mints: Vec<Address>,
+        clawback_ts: i64,
+    ) -> Self {
+        Self { distribution, authority, seeds, mints, clawback_ts }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_basket_distribution_created_event_to_bytes_inner() {
+        let distribution = Address::new_from_array([9u8; 32]);
+        let authority = Address::new_from_array([1u8; 32]);
+        let seeds = Address::new_from_array([2u8; 32]);
+        let mints = vec![Address::new_from_array([3u8; 32]), Address::new_from_array([4u8; 32])];
+        let event = BasketDistributionCreatedEvent::new(distribution, authority, seeds, mints, 1700000000);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), BasketDistributionCreatedEvent::BASE_DATA_LEN + 64);
+        assert_eq!(&bytes[..32], distribution.as_ref());
+        assert_eq!(&bytes[32..64], authority.as_ref());
+        assert_eq!(&bytes[64..96], seeds.as_ref());
+        assert_eq!(&bytes[96..100], &2u32.to_le_bytes());
+        assert_eq!(&bytes[100..132], &[3u8; 32]);
+        assert_eq!(&bytes[132..164], &[4u8; 32]);
+        assert_eq!(&bytes[164..172], &1700000000i64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_basket_distribution_created_event_to_bytes() {
+        let mints = vec![Address::new_from_array([3u8; 32])];
+        let event = BasketDistributionCreatedEvent::new(
+            Address::new_from_array([9u8; 32]),
+            Address::new_from_array([1u8; 32]),
+            Address::new_from_array([2u8; 32]),
+            mints,
+            0,
+        );
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + BasketDistributionCreatedEvent::BASE_DATA_LEN + 32);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::BasketDistributionCreated as u8);
+    }
+}
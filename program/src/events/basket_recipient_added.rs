@@ -0,0 +1,88 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::{
+    traits::{EventDiscriminator, EventDiscriminators, EventSerialize},
+    utils::VestingSchedule,
+};
+
+#[derive(CodamaType)]
+pub struct BasketRecipientAddedEvent {
+    pub distribution: Address,
+    pub recipient: Address,
+    pub amounts: Vec<u64>,
+    pub schedule: VestingSchedule,
+}
+
+impl EventDiscriminator for BasketRecipientAddedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::BasketRecipientAdded as u8;
+}
+
+impl EventSerialize for BasketRecipientAddedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let schedule_bytes = self.schedule.to_bytes();
+        let mut data = Vec::with_capacity(Self::BASE_DATA_LEN + 8 * self.amounts.len() + schedule_bytes.len());
+        data.extend_from_slice(self.distribution.as_ref());
+        data.extend_from_slice(self.recipient.as_ref());
+        data.extend_from_slice(&(self.amounts.len() as u32).to_le_bytes());
+        for amount in &self.amounts {
+            data.extend_from_slice(&amount.to_le_bytes());
+        }
+        data.extend_from_slice(&schedule_bytes);
+        data
+    }
+}
+
+impl BasketRecipientAddedEvent {
+    /// distribution(32) + recipient(32) + amount count(4)
+    pub const BASE_DATA_LEN: usize = 32 + 32 + 4;
+
+    #[inline(always)]
+    pub fn new(distribution: Address, recipient: Address, amounts: Vec<u64>, schedule: VestingSchedule) -> Self {
+        Self { distribution, recipient, amounts, schedule }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_basket_recipient_added_event_to_bytes_inner() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let recipient = Address::new_from_array([2u8; 32]);
+        let event =
+            BasketRecipientAddedEvent::new(distribution, recipient, vec![500, 7], VestingSchedule::Immediate {});
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), BasketRecipientAddedEvent::BASE_DATA_LEN + 16 + 1); // schedule = 1 byte
+        assert_eq!(&bytes[..32], distribution.as_ref());
+        assert_eq!(&bytes[32..64], recipient.as_ref());
+        assert_eq!(&bytes[64..68], &2u32.to_le_bytes());
+        assert_eq!(&bytes[68..76], &500u64.to_le_bytes());
+        assert_eq!(&bytes[76..84], &7u64.to_le_bytes());
+        assert_eq!(bytes[84], 0); // Immediate discriminant
+    }
+
+    #[test]
+    fn test_basket_recipient_added_event_to_bytes() {
+        let schedule = VestingSchedule::Linear { start_ts: 100, end_ts: 200 };
+        let event = BasketRecipientAddedEvent::new(
+            Address::new_from_array([1u8; 32]),
+            Address::new_from_array([2u8; 32]),
+            vec![1000],
+            schedule,
+        );
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + BasketRecipientAddedEvent::BASE_DATA_LEN + 8 + 17);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::BasketRecipientAdded as u8);
+    }
+}
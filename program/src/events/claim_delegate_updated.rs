@@ -0,0 +1,84 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct ClaimDelegateUpdatedEvent {
+    pub distribution: Address,
+    pub recipient: Address,
+    pub delegate: Address,
+}
+
+impl EventDiscriminator for ClaimDelegateUpdatedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::ClaimDelegateUpdated as u8;
+}
+
+impl EventSerialize for ClaimDelegateUpdatedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.distribution.as_ref());
+        data.extend_from_slice(self.recipient.as_ref());
+        data.extend_from_slice(self.delegate.as_ref());
+        data
+    }
+}
+
+impl ClaimDelegateUpdatedEvent {
+    pub const DATA_LEN: usize = 32 + 32 + 32; // distribution + recipient + delegate
+
+    #[inline(always)]
+    pub fn new(distribution: Address, recipient: Address, delegate: Address) -> Self {
+        Self { distribution, recipient, delegate }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_claim_delegate_updated_event_new() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let recipient = Address::new_from_array([2u8; 32]);
+        let delegate = Address::new_from_array([3u8; 32]);
+
+        let event = ClaimDelegateUpdatedEvent::new(distribution, recipient, delegate);
+
+        assert_eq!(event.distribution, distribution);
+        assert_eq!(event.recipient, recipient);
+        assert_eq!(event.delegate, delegate);
+    }
+
+    #[test]
+    fn test_claim_delegate_updated_event_to_bytes_inner() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let recipient = Address::new_from_array([2u8; 32]);
+        let delegate = Address::new_from_array([3u8; 32]);
+        let event = ClaimDelegateUpdatedEvent::new(distribution, recipient, delegate);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), ClaimDelegateUpdatedEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], distribution.as_ref());
+        assert_eq!(&bytes[32..64], recipient.as_ref());
+        assert_eq!(&bytes[64..96], delegate.as_ref());
+    }
+
+    #[test]
+    fn test_claim_delegate_updated_event_to_bytes() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let recipient = Address::new_from_array([2u8; 32]);
+        let delegate = Address::new_from_array([3u8; 32]);
+        let event = ClaimDelegateUpdatedEvent::new(distribution, recipient, delegate);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + ClaimDelegateUpdatedEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::ClaimDelegateUpdated as u8);
+        assert_eq!(&bytes[9..41], distribution.as_ref());
+    }
+}
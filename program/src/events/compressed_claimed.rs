@@ -0,0 +1,79 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+/// Emitted when a compressed recipient claims; `claimed_amount` is the leaf's new running total.
+#[derive(CodamaType)]
+pub struct CompressedClaimedEvent {
+    pub distribution: Address,
+    pub recipient: Address,
+    pub leaf_index: u32,
+    pub amount: u64,
+    pub claimed_amount: u64,
+}
+
+impl EventDiscriminator for CompressedClaimedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::CompressedClaimed as u8;
+}
+
+impl EventSerialize for CompressedClaimedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.distribution.as_ref());
+        data.extend_from_slice(self.recipient.as_ref());
+        data.extend_from_slice(&self.leaf_index.to_le_bytes());
+        data.extend_from_slice(&self.amount.to_le_bytes());
+        data.extend_from_slice(&self.claimed_amount.to_le_bytes());
+        data
+    }
+}
+
+impl CompressedClaimedEvent {
+    pub const DATA_LEN: usize = 32 + 32 + 4 + 8 + 8; // distribution + recipient + leaf_index + amount + claimed_amount
+
+    #[inline(always)]
+    pub fn new(distribution: Address, recipient: Address, leaf_index: u32, amount: u64, claimed_amount: u64) -> Self {
+        Self { distribution, recipient, leaf_index, amount, claimed_amount }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_compressed_claimed_event_to_bytes_inner() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let recipient = Address::new_from_array([2u8; 32]);
+        let event = CompressedClaimedEvent::new(distribution, recipient, 3, 400, 900);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), CompressedClaimedEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], distribution.as_ref());
+        assert_eq!(&bytes[32..64], recipient.as_ref());
+        assert_eq!(&bytes[64..68], &3u32.to_le_bytes());
+        assert_eq!(&bytes[68..76], &400u64.to_le_bytes());
+        assert_eq!(&bytes[76..84], &900u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_compressed_claimed_event_to_bytes() {
+        let event = CompressedClaimedEvent::new(
+            Address::new_from_array([1u8; 32]),
+            Address::new_from_array([2u8; 32]),
+            0,
+            1,
+            1,
+        );
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + CompressedClaimedEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::CompressedClaimed as u8);
+    }
+}
@@ -0,0 +1,94 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::{
+    traits::{EventDiscriminator, EventDiscriminators, EventSerialize},
+    utils::VestingSchedule,
+};
+
+/// Emitted when a recipient leaf is appended to a compressed distribution.
+///
+/// Carries every leaf field so indexers can rebuild the tree.
+#[derive(CodamaType)]
+pub struct CompressedRecipientAddedEvent {
+    pub distribution: Address,
+    pub recipient: Address,
+    pub leaf_index: u32,
+    pub amount: u64,
+    pub base_vesting_offset: i64,
+    pub schedule: VestingSchedule,
+}
+
+impl EventDiscriminator for CompressedRecipientAddedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::CompressedRecipientAdded as u8;
+}
+
+impl EventSerialize for CompressedRecipientAddedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let schedule_bytes = self.schedule.to_bytes();
+        let mut data = Vec::with_capacity(Self::BASE_DATA_LEN + schedule_bytes.len());
+        data.extend_from_slice(self.distribution.as_ref());
+        data.extend_from_slice(self.recipient.as_ref());
+        data.extend_from_slice(&self.leaf_index.to_le_bytes());
+        data.extend_from_slice(&self.amount.to_le_bytes());
+        data.extend_from_slice(&self.base_vesting_offset.to_le_bytes());
+        data.extend_from_slice(&schedule_bytes);
+        data
+    }
+}
+
+impl CompressedRecipientAddedEvent {
+    /// distribution(32) + recipient(32) + leaf_index(4) + amount(8) + base_vesting_offset(8)
+    pub const BASE_DATA_LEN: usize = 32 + 32 + 4 + 8 + 8;
+
+    #[inline(always)]
+    pub fn new(
+        distribution: Address,
+        recipient: Address,
+        leaf_index: u32,
+        amount: u64,
+        base_vesting_offset: i64,
+        schedule: VestingSchedule,
+    ) -> Self {
+        Self { distribution, recipient, leaf_index, amount, base_vesting_offset, schedule }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_compressed_recipient_added_event_to_bytes_inner() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let recipient = Address::new_from_array([2u8; 32]);
+        let event =
+            CompressedRecipientAddedEvent::new(distribution, recipient, 7, 5000, 600, VestingSchedule::Immediate {});
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), CompressedRecipientAddedEvent::BASE_DATA_LEN + 1);
+        assert_eq!(&bytes[..32], distribution.as_ref());
+        assert_eq!(&bytes[32..64], recipient.as_ref());
+        assert_eq!(&bytes[64..68], &7u32.to_le_bytes());
+        assert_eq!(&bytes[68..76], &5000u64.to_le_bytes());
+        assert_eq!(&bytes[76..84], &600i64.to_le_bytes());
+        assert_eq!(bytes[84], 0); // Immediate discriminant
+    }
+
+    #[test]
+    fn test_compressed_recipient_added_event_to_bytes() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let recipient = Address::new_from_array([2u8; 32]);
+        let schedule = VestingSchedule::Linear { start_ts: 100, end_ts: 200 };
+        let event = CompressedRecipientAddedEvent::new(distribution, recipient, 0, 1000, 0, schedule);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + CompressedRecipientAddedEvent::BASE_DATA_LEN + 17);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::CompressedRecipientAdded as u8);
+    }
+}
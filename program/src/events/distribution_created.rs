@@ -16,6 +16,7 @@ pub struct DistributionCreatedEvent {
 pub enum DistributionCreatedData {
     Direct { clawback_ts: i64 },
     Merkle { merkle_root: [u8; 32], total_amount: u64, clawback_ts: i64 },
+    Compressed { clawback_ts: i64 },
 }
 
 impl DistributionCreatedData {
@@ -35,6 +36,12 @@ impl DistributionCreatedData {
                 data.extend_from_slice(&clawback_ts.to_le_bytes());
                 data
             }
+            DistributionCreatedData::Compressed { clawback_ts } => {
+                let mut data = Vec::with_capacity(1 + 8);
+                data.push(2); // Compressed variant
+                data.extend_from_slice(&clawback_ts.to_le_bytes());
+                data
+            }
         }
     }
 }
@@ -58,6 +65,7 @@ impl EventSerialize for DistributionCreatedEvent {
 
 impl DistributionCreatedEvent {
     pub const DIRECT_DATA_LEN: usize = 32 + 32 + 32 + 1 + 8; // authority + mint + seed + variant + clawback_ts
+    pub const COMPRESSED_DATA_LEN: usize = 32 + 32 + 32 + 1 + 8; // authority + mint + seed + variant + clawback_ts
     pub const MERKLE_DATA_LEN: usize = 32 + 32 + 32 + 1 + 32 + 8 + 8; // authority + mint + seed + variant + merkle_root + total_amount + clawback_ts
 
     #[inline(always)]
@@ -65,6 +73,11 @@ impl DistributionCreatedEvent {
         Self { authority, mint, seed, type_data: DistributionCreatedData::Direct { clawback_ts } }
     }
 
+    #[inline(always)]
+    pub fn compressed(authority: Address, mint: Address, seed: Address, clawback_ts: i64) -> Self {
+        Self { authority, mint, seed, type_data: DistributionCreatedData::Compressed { clawback_ts } }
+    }
+
     #[inline(always)]
     pub fn merkle(
         authority: Address,
@@ -193,6 +206,19 @@ mod tests {
         assert_eq!(&bytes[137..145], &1700000000i64.to_le_bytes());
     }
 
+    #[test]
+    fn test_distribution_created_event_compressed_to_bytes_inner() {
+        let authority = Address::new_from_array([1u8; 32]);
+        let mint = Address::new_from_array([2u8; 32]);
+        let seeds = Address::new_from_array([3u8; 32]);
+        let event = DistributionCreatedEvent::compressed(authority, mint, seeds, 1700000000);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), DistributionCreatedEvent::COMPRESSED_DATA_LEN);
+        assert_eq!(bytes[96], 2); // Compressed variant
+        assert_eq!(&bytes[97..105], &1700000000i64.to_le_bytes());
+    }
+
     #[test]
     fn test_distribution_created_event_to_bytes() {
         let authority = Address::new_from_array([1u8; 32]);
@@ -0,0 +1,85 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct DistributionFundedEvent {
+    pub distribution: Address,
+    pub funder: Address,
+    pub amount: u64,
+    pub total_funded: u64,
+}
+
+impl EventDiscriminator for DistributionFundedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::DistributionFunded as u8;
+}
+
+impl EventSerialize for DistributionFundedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.distribution.as_ref());
+        data.extend_from_slice(self.funder.as_ref());
+        data.extend_from_slice(&self.amount.to_le_bytes());
+        data.extend_from_slice(&self.total_funded.to_le_bytes());
+        data
+    }
+}
+
+impl DistributionFundedEvent {
+    pub const DATA_LEN: usize = 32 + 32 + 8 + 8; // distribution + funder + amount + total_funded
+
+    #[inline(always)]
+    pub fn new(distribution: Address, funder: Address, amount: u64, total_funded: u64) -> Self {
+        Self { distribution, funder, amount, total_funded }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_distribution_funded_event_new() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let funder = Address::new_from_array([2u8; 32]);
+
+        let event = DistributionFundedEvent::new(distribution, funder, 1000, 3000);
+
+        assert_eq!(event.distribution, distribution);
+        assert_eq!(event.funder, funder);
+        assert_eq!(event.amount, 1000);
+        assert_eq!(event.total_funded, 3000);
+    }
+
+    #[test]
+    fn test_distribution_funded_event_to_bytes_inner() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let funder = Address::new_from_array([2u8; 32]);
+        let event = DistributionFundedEvent::new(distribution, funder, 1000, 3000);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), DistributionFundedEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], distribution.as_ref());
+        assert_eq!(&bytes[32..64], funder.as_ref());
+        assert_eq!(&bytes[64..72], &1000u64.to_le_bytes());
+        assert_eq!(&bytes[72..80], &3000u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_distribution_funded_event_to_bytes() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let funder = Address::new_from_array([2u8; 32]);
+        let event = DistributionFundedEvent::new(distribution, funder, 1000, 3000);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + DistributionFundedEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::DistributionFunded as u8);
+        assert_eq!(&bytes[9..41], distribution.as_ref());
+    }
+}
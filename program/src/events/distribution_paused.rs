@@ -0,0 +1,74 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct DistributionPausedEvent {
+    pub distribution: Address,
+    pub paused_at: i64,
+}
+
+impl EventDiscriminator for DistributionPausedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::DistributionPaused as u8;
+}
+
+impl EventSerialize for DistributionPausedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.distribution.as_ref());
+        data.extend_from_slice(&self.paused_at.to_le_bytes());
+        data
+    }
+}
+
+impl DistributionPausedEvent {
+    pub const DATA_LEN: usize = 32 + 8; // distribution + paused_at
+
+    #[inline(always)]
+    pub fn new(distribution: Address, paused_at: i64) -> Self {
+        Self { distribution, paused_at }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_distribution_paused_event_new() {
+        let distribution = Address::new_from_array([1u8; 32]);
+
+        let event = DistributionPausedEvent::new(distribution, 1_700_000_000);
+
+        assert_eq!(event.distribution, distribution);
+        assert_eq!(event.paused_at, 1_700_000_000);
+    }
+
+    #[test]
+    fn test_distribution_paused_event_to_bytes_inner() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let event = DistributionPausedEvent::new(distribution, 1_700_000_000);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), DistributionPausedEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], distribution.as_ref());
+        assert_eq!(&bytes[32..40], &1_700_000_000i64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_distribution_paused_event_to_bytes() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let event = DistributionPausedEvent::new(distribution, 1_700_000_000);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + DistributionPausedEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::DistributionPaused as u8);
+        assert_eq!(&bytes[9..41], distribution.as_ref());
+    }
+}
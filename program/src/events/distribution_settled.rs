@@ -0,0 +1,70 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+/// Emitted when a `ProtectRecipients` distribution is closed with allocations still unclaimed.
+///
+/// `swept_amount` went back to the authority; `outstanding_amount` stays in the vault for claims.
+#[derive(CodamaType)]
+pub struct DistributionSettledEvent {
+    pub distribution: Address,
+    pub swept_amount: u64,
+    pub outstanding_amount: u64,
+}
+
+impl EventDiscriminator for DistributionSettledEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::DistributionSettled as u8;
+}
+
+impl EventSerialize for DistributionSettledEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.distribution.as_ref());
+        data.extend_from_slice(&self.swept_amount.to_le_bytes());
+        data.extend_from_slice(&self.outstanding_amount.to_le_bytes());
+        data
+    }
+}
+
+impl DistributionSettledEvent {
+    pub const DATA_LEN: usize = 32 + 8 + 8; // distribution + swept_amount + outstanding_amount
+
+    #[inline(always)]
+    pub fn new(distribution: Address, swept_amount: u64, outstanding_amount: u64) -> Self {
+        Self { distribution, swept_amount, outstanding_amount }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_distribution_settled_event_to_bytes_inner() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let event = DistributionSettledEvent::new(distribution, 300, 700);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), DistributionSettledEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], distribution.as_ref());
+        assert_eq!(&bytes[32..40], &300u64.to_le_bytes());
+        assert_eq!(&bytes[40..48], &700u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_distribution_settled_event_to_bytes() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let event = DistributionSettledEvent::new(distribution, 0, 700);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + DistributionSettledEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::DistributionSettled as u8);
+        assert_eq!(&bytes[9..41], distribution.as_ref());
+    }
+}
@@ -0,0 +1,78 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct DistributionUnpausedEvent {
+    pub distribution: Address,
+    pub unpaused_at: i64,
+    pub vesting_shift: i64,
+}
+
+impl EventDiscriminator for DistributionUnpausedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::DistributionUnpaused as u8;
+}
+
+impl EventSerialize for DistributionUnpausedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.distribution.as_ref());
+        data.extend_from_slice(&self.unpaused_at.to_le_bytes());
+        data.extend_from_slice(&self.vesting_shift.to_le_bytes());
+        data
+    }
+}
+
+impl DistributionUnpausedEvent {
+    pub const DATA_LEN: usize = 32 + 8 + 8; // distribution + unpaused_at + vesting_shift
+
+    #[inline(always)]
+    pub fn new(distribution: Address, unpaused_at: i64, vesting_shift: i64) -> Self {
+        Self { distribution, unpaused_at, vesting_shift }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_distribution_unpaused_event_new() {
+        let distribution = Address::new_from_array([1u8; 32]);
+
+        let event = DistributionUnpausedEvent::new(distribution, 1_700_000_600, 600);
+
+        assert_eq!(event.distribution, distribution);
+        assert_eq!(event.unpaused_at, 1_700_000_600);
+        assert_eq!(event.vesting_shift, 600);
+    }
+
+    #[test]
+    fn test_distribution_unpaused_event_to_bytes_inner() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let event = DistributionUnpausedEvent::new(distribution, 1_700_000_600, 600);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), DistributionUnpausedEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], distribution.as_ref());
+        assert_eq!(&bytes[32..40], &1_700_000_600i64.to_le_bytes());
+        assert_eq!(&bytes[40..48], &600i64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_distribution_unpaused_event_to_bytes() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let event = DistributionUnpausedEvent::new(distribution, 1_700_000_600, 0);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + DistributionUnpausedEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::DistributionUnpaused as u8);
+        assert_eq!(&bytes[9..41], distribution.as_ref());
+    }
+}
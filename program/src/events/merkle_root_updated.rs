@@ -0,0 +1,82 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct MerkleRootUpdatedEvent {
+    pub distribution: Address,
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub epoch: u64,
+}
+
+impl EventDiscriminator for MerkleRootUpdatedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::MerkleRootUpdated as u8;
+}
+
+impl EventSerialize for MerkleRootUpdatedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.distribution.as_ref());
+        data.extend_from_slice(&self.merkle_root);
+        data.extend_from_slice(&self.total_amount.to_le_bytes());
+        data.extend_from_slice(&self.epoch.to_le_bytes());
+        data
+    }
+}
+
+impl MerkleRootUpdatedEvent {
+    pub const DATA_LEN: usize = 32 + 32 + 8 + 8; // distribution + merkle_root + total_amount + epoch
+
+    #[inline(always)]
+    pub fn new(distribution: Address, merkle_root: [u8; 32], total_amount: u64, epoch: u64) -> Self {
+        Self { distribution, merkle_root, total_amount, epoch }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_merkle_root_updated_event_new() {
+        let distribution = Address::new_from_array([1u8; 32]);
+
+        let event = MerkleRootUpdatedEvent::new(distribution, [2u8; 32], 5000, 3);
+
+        assert_eq!(event.distribution, distribution);
+        assert_eq!(event.merkle_root, [2u8; 32]);
+        assert_eq!(event.total_amount, 5000);
+        assert_eq!(event.epoch, 3);
+    }
+
+    #[test]
+    fn test_merkle_root_updated_event_to_bytes_inner() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let event = MerkleRootUpdatedEvent::new(distribution, [2u8; 32], 5000, 3);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), MerkleRootUpdatedEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], distribution.as_ref());
+        assert_eq!(&bytes[32..64], &[2u8; 32]);
+        assert_eq!(&bytes[64..72], &5000u64.to_le_bytes());
+        assert_eq!(&bytes[72..80], &3u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_merkle_root_updated_event_to_bytes() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let event = MerkleRootUpdatedEvent::new(distribution, [2u8; 32], 5000, 3);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + MerkleRootUpdatedEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::MerkleRootUpdated as u8);
+        assert_eq!(&bytes[9..41], distribution.as_ref());
+    }
+}
@@ -1,15 +1,45 @@
+pub mod allocation_changed;
+pub mod authority_transferred;
+pub mod basket_claimed;
+pub mod basket_distribution_closed;
+pub mod basket_distribution_created;
+pub mod basket_recipient_added;
 pub mod claim_closed;
+pub mod claim_delegate_updated;
 pub mod claimed;
+pub mod compressed_claimed;
+pub mod compressed_recipient_added;
 pub mod distribution_closed;
 pub mod distribution_created;
+pub mod distribution_funded;
+pub mod distribution_paused;
+pub mod distribution_settled;
+pub mod distribution_unpaused;
+pub mod merkle_root_updated;
 pub mod recipient_added;
 pub mod recipient_revoked;
+pub mod recipient_transferred;
 pub mod shared;
 
+pub use allocation_changed::*;
+pub use authority_transferred::*;
+pub use basket_claimed::*;
+pub use basket_distribution_closed::*;
+pub use basket_distribution_created::*;
+pub use basket_recipient_added::*;
 pub use claim_closed::*;
+pub use claim_delegate_updated::*;
 pub use claimed::*;
+pub use compressed_claimed::*;
+pub use compressed_recipient_added::*;
 pub use distribution_closed::*;
 pub use distribution_created::*;
+pub use distribution_funded::*;
+pub use distribution_paused::*;
+pub use distribution_settled::*;
+pub use distribution_unpaused::*;
+pub use merkle_root_updated::*;
 pub use recipient_added::*;
 pub use recipient_revoked::*;
+pub use recipient_transferred::*;
 pub use shared::*;
@@ -54,7 +54,7 @@ mod tests {
         let recipient = Address::new_from_array([2u8; 32]);
         let schedule = VestingSchedule::Linear { start_ts: 100, end_ts: 200 };
 
-        let event = RecipientAddedEvent::new(distribution, recipient, 1000, schedule);
+        let event = RecipientAddedEvent::new(distribution, recipient, 1000, schedule.clone());
 
         assert_eq!(event.distribution, distribution);
         assert_eq!(event.recipient, recipient);
@@ -92,7 +92,7 @@ mod tests {
         let distribution = Address::new_from_array([1u8; 32]);
         let recipient = Address::new_from_array([2u8; 32]);
         let schedule = VestingSchedule::Linear { start_ts: 100, end_ts: 200 };
-        let event = RecipientAddedEvent::new(distribution, recipient, 1000, schedule);
+        let event = RecipientAddedEvent::new(distribution, recipient, 1000, schedule.clone());
 
         let bytes = event.to_bytes();
         let expected_len = EVENT_DISCRIMINATOR_LEN + RecipientAddedEvent::BASE_DATA_LEN + 17;
@@ -106,7 +106,7 @@ mod tests {
         let distribution = Address::new_from_array([1u8; 32]);
         let recipient = Address::new_from_array([2u8; 32]);
         let schedule = VestingSchedule::CliffLinear { start_ts: 0, cliff_ts: 100, end_ts: 400 };
-        let event = RecipientAddedEvent::new(distribution, recipient, 1000, schedule);
+        let event = RecipientAddedEvent::new(distribution, recipient, 1000, schedule.clone());
 
         assert_eq!(event.schedule, schedule);
 
@@ -0,0 +1,84 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct RecipientTransferredEvent {
+    pub distribution: Address,
+    pub previous_recipient: Address,
+    pub new_recipient: Address,
+}
+
+impl EventDiscriminator for RecipientTransferredEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::RecipientTransferred as u8;
+}
+
+impl EventSerialize for RecipientTransferredEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.distribution.as_ref());
+        data.extend_from_slice(self.previous_recipient.as_ref());
+        data.extend_from_slice(self.new_recipient.as_ref());
+        data
+    }
+}
+
+impl RecipientTransferredEvent {
+    pub const DATA_LEN: usize = 32 + 32 + 32; // distribution + previous_recipient + new_recipient
+
+    #[inline(always)]
+    pub fn new(distribution: Address, previous_recipient: Address, new_recipient: Address) -> Self {
+        Self { distribution, previous_recipient, new_recipient }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_recipient_transferred_event_new() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let previous_recipient = Address::new_from_array([2u8; 32]);
+        let new_recipient = Address::new_from_array([3u8; 32]);
+
+        let event = RecipientTransferredEvent::new(distribution, previous_recipient, new_recipient);
+
+        assert_eq!(event.distribution, distribution);
+        assert_eq!(event.previous_recipient, previous_recipient);
+        assert_eq!(event.new_recipient, new_recipient);
+    }
+
+    #[test]
+    fn test_recipient_transferred_event_to_bytes_inner() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let previous_recipient = Address::new_from_array([2u8; 32]);
+        let new_recipient = Address::new_from_array([3u8; 32]);
+        let event = RecipientTransferredEvent::new(distribution, previous_recipient, new_recipient);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), RecipientTransferredEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], distribution.as_ref());
+        assert_eq!(&bytes[32..64], previous_recipient.as_ref());
+        assert_eq!(&bytes[64..96], new_recipient.as_ref());
+    }
+
+    #[test]
+    fn test_recipient_transferred_event_to_bytes() {
+        let distribution = Address::new_from_array([1u8; 32]);
+        let previous_recipient = Address::new_from_array([2u8; 32]);
+        let new_recipient = Address::new_from_array([3u8; 32]);
+        let event = RecipientTransferredEvent::new(distribution, previous_recipient, new_recipient);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + RecipientTransferredEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::RecipientTransferred as u8);
+        assert_eq!(&bytes[9..41], distribution.as_ref());
+    }
+}
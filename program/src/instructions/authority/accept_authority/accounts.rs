@@ -0,0 +1,43 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_signer, verify_writable,
+    },
+};
+
+pub struct AcceptAuthorityAccounts<'a> {
+    pub new_authority: &'a AccountView,
+    pub distribution: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for AcceptAuthorityAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [new_authority, distribution, event_authority, program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(new_authority, false)?;
+
+        // 2. Validate writable
+        verify_writable(distribution, true)?;
+
+        // 3. Validate program IDs
+        verify_current_program(program)?;
+        verify_event_authority(event_authority)?;
+
+        // 4. Validate accounts owned by current program
+        verify_current_program_account(distribution)?;
+
+        Ok(Self { new_authority, distribution, event_authority, program })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for AcceptAuthorityAccounts<'a> {}
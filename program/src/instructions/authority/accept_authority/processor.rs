@@ -0,0 +1,47 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    events::AuthorityTransferredEvent,
+    state::{CompressedDistribution, DirectDistribution, MerkleDistribution},
+    traits::{Distribution, EventSerialize},
+    utils::{emit_event, get_distribution_kind, load_distribution, save_distribution, DistributionKind},
+    ID,
+};
+
+use super::AcceptAuthority;
+
+pub fn process_accept_authority(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = AcceptAuthority::try_from((instruction_data, accounts))?;
+
+    let previous_authority = match get_distribution_kind(ix.accounts.distribution)? {
+        DistributionKind::Direct => accept_authority::<DirectDistribution>(&ix)?,
+        DistributionKind::Merkle => accept_authority::<MerkleDistribution>(&ix)?,
+        DistributionKind::Compressed => accept_authority::<CompressedDistribution>(&ix)?,
+    };
+
+    let event = AuthorityTransferredEvent::new(
+        *ix.accounts.distribution.address(),
+        previous_authority,
+        *ix.accounts.new_authority.address(),
+    );
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
+
+/// Moves the pending authority into place and returns the previous authority.
+fn accept_authority<D: Distribution>(ix: &AcceptAuthority) -> Result<Address, ProgramError> {
+    let mut distribution: D = load_distribution(ix.accounts.distribution)?;
+    distribution.validate_pending_authority(ix.accounts.new_authority.address())?;
+
+    let previous_authority = *distribution.authority();
+    distribution.set_authority(*ix.accounts.new_authority.address());
+    distribution.set_pending_authority(Address::default());
+    save_distribution(ix.accounts.distribution, &distribution)?;
+
+    Ok(previous_authority)
+}
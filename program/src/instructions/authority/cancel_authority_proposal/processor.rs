@@ -0,0 +1,36 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    state::{CompressedDistribution, DirectDistribution, MerkleDistribution},
+    traits::Distribution,
+    utils::{get_distribution_kind, load_distribution, save_distribution, DistributionKind},
+};
+
+use super::CancelAuthorityProposal;
+
+pub fn process_cancel_authority_proposal(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = CancelAuthorityProposal::try_from((instruction_data, accounts))?;
+
+    match get_distribution_kind(ix.accounts.distribution)? {
+        DistributionKind::Direct => cancel_authority_proposal::<DirectDistribution>(&ix),
+        DistributionKind::Merkle => cancel_authority_proposal::<MerkleDistribution>(&ix),
+        DistributionKind::Compressed => cancel_authority_proposal::<CompressedDistribution>(&ix),
+    }
+}
+
+fn cancel_authority_proposal<D: Distribution>(ix: &CancelAuthorityProposal) -> ProgramResult {
+    let mut distribution: D = load_distribution(ix.accounts.distribution)?;
+    distribution.validate_authority(ix.accounts.authority.address())?;
+
+    if !distribution.has_pending_authority() {
+        return Err(RewardsProgramError::NoPendingAuthority.into());
+    }
+
+    distribution.set_pending_authority(Address::default());
+    save_distribution(ix.accounts.distribution, &distribution)
+}
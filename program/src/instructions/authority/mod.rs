@@ -0,0 +1,3 @@
+pub mod accept_authority;
+pub mod cancel_authority_proposal;
+pub mod propose_authority;
@@ -0,0 +1,68 @@
+use pinocchio::{error::ProgramError, Address};
+
+use crate::{errors::RewardsProgramError, require_len, traits::InstructionData};
+
+/// Instruction data for ProposeAuthority
+///
+/// - `new_authority`: The address that may accept authority over the distribution.
+pub struct ProposeAuthorityData {
+    pub new_authority: Address,
+}
+
+impl<'a> TryFrom<&'a [u8]> for ProposeAuthorityData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let new_authority =
+            Address::new_from_array(data[..32].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        Ok(Self { new_authority })
+    }
+}
+
+impl<'a> InstructionData<'a> for ProposeAuthorityData {
+    const LEN: usize = 32;
+
+    fn validate(&self) -> Result<(), ProgramError> {
+        // The default address marks "no pending authority"; use CancelAuthorityProposal instead
+        if self.new_authority == Address::default() {
+            return Err(RewardsProgramError::InvalidNewAuthority.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_valid_data() {
+        let data = [7u8; 32];
+        let result = ProposeAuthorityData::try_from(&data[..]).unwrap();
+        assert_eq!(result.new_authority, Address::new_from_array([7u8; 32]));
+    }
+
+    #[test]
+    fn test_try_from_insufficient_data() {
+        let data = [1u8; 31];
+        let result = ProposeAuthorityData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_validate_rejects_default_address() {
+        let data = [0u8; 32];
+        let result = ProposeAuthorityData::try_from(&data[..]).unwrap();
+        assert_eq!(result.validate(), Err(RewardsProgramError::InvalidNewAuthority.into()));
+    }
+
+    #[test]
+    fn test_validate_accepts_non_default_address() {
+        let data = [7u8; 32];
+        let result = ProposeAuthorityData::try_from(&data[..]).unwrap();
+        assert!(result.validate().is_ok());
+    }
+}
@@ -0,0 +1,39 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    state::{CompressedDistribution, DirectDistribution, MerkleDistribution},
+    traits::{Distribution, InstructionData},
+    utils::{get_distribution_kind, load_distribution, save_distribution, DistributionKind},
+};
+
+use super::ProposeAuthority;
+
+pub fn process_propose_authority(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = ProposeAuthority::try_from((instruction_data, accounts))?;
+    ix.data.validate()?;
+
+    match get_distribution_kind(ix.accounts.distribution)? {
+        DistributionKind::Direct => propose_authority::<DirectDistribution>(&ix),
+        DistributionKind::Merkle => propose_authority::<MerkleDistribution>(&ix),
+        DistributionKind::Compressed => propose_authority::<CompressedDistribution>(&ix),
+    }
+}
+
+fn propose_authority<D: Distribution>(ix: &ProposeAuthority) -> ProgramResult {
+    let mut distribution: D = load_distribution(ix.accounts.distribution)?;
+    distribution.validate_authority(ix.accounts.authority.address())?;
+    distribution.validate_not_settled()?;
+
+    if distribution.authority() == &ix.data.new_authority {
+        return Err(RewardsProgramError::InvalidNewAuthority.into());
+    }
+
+    // Overwrites any previous proposal
+    distribution.set_pending_authority(ix.data.new_authority);
+    save_distribution(ix.accounts.distribution, &distribution)
+}
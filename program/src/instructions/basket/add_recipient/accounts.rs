@@ -0,0 +1,73 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    instructions::basket::legs::{parse_basket_legs, BasketLegAccounts},
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_readonly, verify_signer,
+        verify_system_program, verify_writable,
+    },
+};
+
+pub struct AddBasketRecipientAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub authority: &'a AccountView,
+    pub distribution: &'a AccountView,
+    pub recipient_account: &'a AccountView,
+    pub recipient: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub program: &'a AccountView,
+    /// One leg per basket mint; `token_account` is the authority's source account
+    pub legs: Vec<BasketLegAccounts<'a>>,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for AddBasketRecipientAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, authority, distribution, recipient_account, recipient, system_program, event_authority, program, legs @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(authority, false)?;
+
+        // 2. Validate writable
+        verify_writable(distribution, true)?;
+        verify_writable(recipient_account, true)?;
+
+        // 2b. Validate read-only accounts
+        verify_readonly(recipient)?;
+
+        // 3. Validate program IDs
+        verify_system_program(system_program)?;
+        verify_current_program(program)?;
+        verify_event_authority(event_authority)?;
+
+        // 4. Validate accounts owned by current program
+        verify_current_program_account(distribution)?;
+
+        // 5-6. Validate each leg's mint, token account and distribution_vault
+        let legs = parse_basket_legs(legs, distribution)?;
+
+        Ok(Self {
+            payer,
+            authority,
+            distribution,
+            recipient_account,
+            recipient,
+            system_program,
+            event_authority,
+            program,
+            legs,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for AddBasketRecipientAccounts<'a> {}
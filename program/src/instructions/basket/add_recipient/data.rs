@@ -0,0 +1,118 @@
+use alloc::vec::Vec;
+use pinocchio::error::ProgramError;
+
+use crate::{
+    errors::RewardsProgramError, require_len, state::MAX_BASKET_MINTS, traits::InstructionData, utils::VestingSchedule,
+};
+
+/// Instruction data for AddBasketRecipient.
+///
+/// A u32-prefixed amount list (one per basket mint, in slot order) followed by
+/// the shared vesting schedule.
+pub struct AddBasketRecipientData {
+    /// Bump for the recipient PDA
+    pub bump: u8,
+    /// Amount allocated per basket mint; zero skips that mint
+    pub amounts: Vec<u64>,
+    /// Vesting schedule shared by every mint
+    pub schedule: VestingSchedule,
+}
+
+impl<'a> TryFrom<&'a [u8]> for AddBasketRecipientData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let bump = data[0];
+        let count =
+            u32::from_le_bytes(data[1..5].try_into().map_err(|_| ProgramError::InvalidInstructionData)?) as usize;
+        if count > MAX_BASKET_MINTS {
+            return Err(RewardsProgramError::InvalidBasketAccounts.into());
+        }
+
+        // amounts + min schedule(1)
+        let schedule_offset = 5 + count * 8;
+        require_len!(data, schedule_offset + 1);
+
+        let amounts = data[5..schedule_offset]
+            .chunks_exact(8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap_or_default()))
+            .collect();
+        let (schedule, _) = VestingSchedule::from_bytes(&data[schedule_offset..])?;
+
+        Ok(Self { bump, amounts, schedule })
+    }
+}
+
+impl<'a> InstructionData<'a> for AddBasketRecipientData {
+    const LEN: usize = 1 + 4 + 1; // bump + amount count + min schedule (Immediate)
+
+    fn validate(&self) -> Result<(), ProgramError> {
+        if self.amounts.iter().all(|amount| *amount == 0) {
+            return Err(RewardsProgramError::InvalidAmount.into());
+        }
+        self.schedule.validate()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    fn make_data(amounts: &[u64], schedule: VestingSchedule) -> Vec<u8> {
+        let mut data = vec![255]; // bump
+        data.extend_from_slice(&(amounts.len() as u32).to_le_bytes());
+        for amount in amounts {
+            data.extend_from_slice(&amount.to_le_bytes());
+        }
+        data.extend_from_slice(&schedule.to_bytes());
+        data
+    }
+
+    #[test]
+    fn test_try_from_valid_data() {
+        let schedule = VestingSchedule::Linear { start_ts: 100, end_ts: 200 };
+        let data = make_data(&[1000, 0, 5], schedule.clone());
+
+        let parsed = AddBasketRecipientData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.bump, 255);
+        assert_eq!(parsed.amounts, vec![1000, 0, 5]);
+        assert_eq!(parsed.schedule, schedule);
+        assert!(parsed.validate().is_ok());
+    }
+
+    #[test]
+    fn test_try_from_truncated_amounts() {
+        let mut data = make_data(&[1000, 2000], VestingSchedule::Immediate {});
+        data.truncate(1 + 4 + 8);
+        assert_eq!(AddBasketRecipientData::try_from(&data[..]).err(), Some(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_try_from_too_many_amounts() {
+        let data = make_data(&[1; MAX_BASKET_MINTS + 1], VestingSchedule::Immediate {});
+        assert_eq!(
+            AddBasketRecipientData::try_from(&data[..]).err(),
+            Some(RewardsProgramError::InvalidBasketAccounts.into())
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_all_zero_amounts() {
+        let data = make_data(&[0, 0], VestingSchedule::Immediate {});
+        let parsed = AddBasketRecipientData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.validate().err(), Some(RewardsProgramError::InvalidAmount.into()));
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_schedule() {
+        let data = make_data(&[10], VestingSchedule::Linear { start_ts: 200, end_ts: 100 });
+        let parsed = AddBasketRecipientData::try_from(&data[..]).unwrap();
+        assert!(parsed.validate().is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    events::BasketRecipientAddedEvent,
+    state::{BasketDistribution, BasketRecipient, MAX_BASKET_MINTS},
+    traits::{AccountSerialize, EventSerialize, InstructionData, PdaSeeds},
+    utils::{create_pda_account, deposit_to_distribution_vault, emit_event, VaultDepositContext},
+    ID,
+};
+
+use super::AddBasketRecipient;
+
+pub fn process_add_basket_recipient(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = AddBasketRecipient::try_from((instruction_data, accounts))?;
+    ix.data.validate()?;
+
+    let distribution_data = ix.accounts.distribution.try_borrow()?;
+    let mut distribution = BasketDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    drop(distribution_data);
+
+    distribution.validate_authority(ix.accounts.authority.address())?;
+    distribution.validate_mints(ix.accounts.legs.iter().map(|leg| leg.mint.address()))?;
+    if ix.data.amounts.len() != distribution.mint_count as usize {
+        return Err(RewardsProgramError::InvalidBasketAccounts.into());
+    }
+
+    // Allocate what actually reached each vault so Token-2022 transfer fees never leave it short
+    let mut total_amounts = [0u64; MAX_BASKET_MINTS];
+    for (index, (leg, amount)) in ix.accounts.legs.iter().zip(&ix.data.amounts).enumerate() {
+        if *amount == 0 {
+            continue;
+        }
+
+        let received = deposit_to_distribution_vault(
+            &VaultDepositContext {
+                source: leg.token_account,
+                authority: ix.accounts.authority,
+                distribution_vault: leg.distribution_vault,
+                mint: leg.mint,
+                token_program: leg.token_program,
+            },
+            *amount,
+        )?;
+        if received == 0 {
+            return Err(RewardsProgramError::InvalidAmount.into());
+        }
+
+        distribution.add_allocated(index, received)?;
+        total_amounts[index] = received;
+    }
+
+    let recipient = BasketRecipient::new(
+        ix.data.bump,
+        *ix.accounts.distribution.address(),
+        *ix.accounts.recipient.address(),
+        *ix.accounts.payer.address(),
+        total_amounts,
+        ix.data.schedule.clone(),
+    );
+
+    recipient.validate_pda(ix.accounts.recipient_account, &ID, ix.data.bump)?;
+
+    let bump_seed = [ix.data.bump];
+    let recipient_seeds = recipient.seeds_with_bump(&bump_seed);
+    let recipient_seeds_array: [_; 4] = recipient_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    create_pda_account(
+        ix.accounts.payer,
+        BasketRecipient::calculate_account_size(&recipient.schedule),
+        &ID,
+        ix.accounts.recipient_account,
+        recipient_seeds_array,
+    )?;
+
+    let mut recipient_data = ix.accounts.recipient_account.try_borrow_mut()?;
+    recipient.write_to_slice(&mut recipient_data)?;
+    drop(recipient_data);
+
+    let mut distribution_data = ix.accounts.distribution.try_borrow_mut()?;
+    distribution.write_to_slice(&mut distribution_data)?;
+    drop(distribution_data);
+
+    let event = BasketRecipientAddedEvent::new(
+        *ix.accounts.distribution.address(),
+        *ix.accounts.recipient.address(),
+        total_amounts[..distribution.mint_count as usize].to_vec(),
+        ix.data.schedule,
+    );
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    instructions::basket::legs::{parse_basket_legs, BasketLegAccounts},
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_signer, verify_writable,
+    },
+};
+
+pub struct ClaimBasketAccounts<'a> {
+    pub recipient: &'a AccountView,
+    pub distribution: &'a AccountView,
+    pub recipient_account: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub program: &'a AccountView,
+    /// One leg per basket mint; `token_account` receives the claimed tokens
+    pub legs: Vec<BasketLegAccounts<'a>>,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for ClaimBasketAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [recipient, distribution, recipient_account, event_authority, program, legs @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(recipient, false)?;
+
+        // 2. Validate writable
+        verify_writable(distribution, true)?;
+        verify_writable(recipient_account, true)?;
+
+        // 3. Validate program IDs
+        verify_current_program(program)?;
+        verify_event_authority(event_authority)?;
+
+        // 4. Validate accounts owned by current program
+        verify_current_program_account(distribution)?;
+        verify_current_program_account(recipient_account)?;
+
+        // 5-6. Validate each leg's mint, token account and distribution_vault
+        let legs = parse_basket_legs(legs, distribution)?;
+
+        Ok(Self { recipient, distribution, recipient_account, event_authority, program, legs })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for ClaimBasketAccounts<'a> {}
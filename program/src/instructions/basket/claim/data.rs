@@ -0,0 +1,33 @@
+use pinocchio::error::ProgramError;
+
+use crate::traits::InstructionData;
+
+/// Instruction data for ClaimBasket
+///
+/// This instruction has no data - every basket mint pays out all available tokens.
+pub struct ClaimBasketData {}
+
+impl<'a> TryFrom<&'a [u8]> for ClaimBasketData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(_data: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(Self {})
+    }
+}
+
+impl<'a> InstructionData<'a> for ClaimBasketData {
+    const LEN: usize = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_empty_data() {
+        let data: [u8; 0] = [];
+        let result = ClaimBasketData::try_from(&data[..]);
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,88 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    events::BasketClaimedEvent,
+    state::{BasketDistribution, BasketRecipient, MAX_BASKET_MINTS},
+    traits::{AccountSerialize, ClaimTracker, EventSerialize},
+    utils::{
+        emit_event, get_current_timestamp, transfer_from_distribution_vault, verify_vault_balance, ClaimTransferContext,
+    },
+    ID,
+};
+
+use super::ClaimBasket;
+
+pub fn process_claim_basket(_program_id: &Address, accounts: &[AccountView], instruction_data: &[u8]) -> ProgramResult {
+    let ix = ClaimBasket::try_from((instruction_data, accounts))?;
+    let current_ts = get_current_timestamp()?;
+
+    let distribution_data = ix.accounts.distribution.try_borrow()?;
+    let mut distribution = BasketDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    drop(distribution_data);
+
+    distribution.validate_mints(ix.accounts.legs.iter().map(|leg| leg.mint.address()))?;
+
+    let recipient_data = ix.accounts.recipient_account.try_borrow()?;
+    let mut recipient = BasketRecipient::from_account(&recipient_data, ix.accounts.recipient_account, &ID)?;
+    drop(recipient_data);
+
+    recipient.validate_distribution(ix.accounts.distribution.address())?;
+    recipient.validate_recipient(ix.accounts.recipient.address())?;
+
+    // Every mint vests on the same schedule, but each tracks its claims separately
+    let mut claim_amounts = [0u64; MAX_BASKET_MINTS];
+    for (index, leg) in ix.accounts.legs.iter().enumerate() {
+        let claim_amount = recipient.claimable_amount(index, current_ts)?;
+        if claim_amount == 0 {
+            continue;
+        }
+
+        verify_vault_balance(leg.distribution_vault, claim_amount)?;
+
+        let mut basket_leg = recipient.leg(index);
+        basket_leg.add_claimed(claim_amount)?;
+        recipient.set_leg(index, basket_leg);
+        distribution.add_claimed(index, claim_amount)?;
+        claim_amounts[index] = claim_amount;
+    }
+
+    if claim_amounts.iter().all(|amount| *amount == 0) {
+        return Err(RewardsProgramError::NothingToClaim.into());
+    }
+
+    let mut recipient_data = ix.accounts.recipient_account.try_borrow_mut()?;
+    recipient.write_to_slice(&mut recipient_data)?;
+    drop(recipient_data);
+
+    let mut distribution_data = ix.accounts.distribution.try_borrow_mut()?;
+    distribution.write_to_slice(&mut distribution_data)?;
+    drop(distribution_data);
+
+    for (leg, claim_amount) in ix.accounts.legs.iter().zip(claim_amounts) {
+        if claim_amount == 0 {
+            continue;
+        }
+
+        transfer_from_distribution_vault(
+            &distribution,
+            &ClaimTransferContext {
+                distribution_vault: leg.distribution_vault,
+                mint: leg.mint,
+                destination: leg.token_account,
+                distribution_account: ix.accounts.distribution,
+                token_program: leg.token_program.address(),
+            },
+            claim_amount,
+        )?;
+    }
+
+    let event = BasketClaimedEvent::new(
+        *ix.accounts.distribution.address(),
+        *ix.accounts.recipient.address(),
+        claim_amounts[..distribution.mint_count as usize].to_vec(),
+    );
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    instructions::basket::legs::{parse_basket_legs, BasketLegAccounts},
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_signer, verify_writable,
+    },
+};
+
+pub struct CloseBasketDistributionAccounts<'a> {
+    pub authority: &'a AccountView,
+    pub distribution: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub program: &'a AccountView,
+    /// One leg per basket mint; `token_account` receives the remaining tokens
+    pub legs: Vec<BasketLegAccounts<'a>>,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for CloseBasketDistributionAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [authority, distribution, event_authority, program, legs @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(authority, true)?;
+
+        // 2. Validate writable
+        verify_writable(distribution, true)?;
+
+        // 3. Validate program IDs
+        verify_current_program(program)?;
+        verify_event_authority(event_authority)?;
+
+        // 4. Validate accounts owned by current program
+        verify_current_program_account(distribution)?;
+
+        // 5-6. Validate each leg's mint, token account and distribution_vault
+        let legs = parse_basket_legs(legs, distribution)?;
+
+        Ok(Self { authority, distribution, event_authority, program, legs })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for CloseBasketDistributionAccounts<'a> {}
@@ -0,0 +1,33 @@
+use pinocchio::error::ProgramError;
+
+use crate::traits::InstructionData;
+
+/// Instruction data for CloseBasketDistribution
+///
+/// This instruction has no data - all information comes from accounts.
+pub struct CloseBasketDistributionData {}
+
+impl<'a> TryFrom<&'a [u8]> for CloseBasketDistributionData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(_data: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(Self {})
+    }
+}
+
+impl<'a> InstructionData<'a> for CloseBasketDistributionData {
+    const LEN: usize = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_empty_data() {
+        let data: [u8; 0] = [];
+        let result = CloseBasketDistributionData::try_from(&data[..]);
+        assert!(result.is_ok());
+    }
+}
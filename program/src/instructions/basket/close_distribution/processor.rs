@@ -0,0 +1,58 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    events::BasketDistributionClosedEvent,
+    state::BasketDistribution,
+    traits::EventSerialize,
+    utils::{close_distribution_vault, close_pda_account, emit_event, get_current_timestamp, ClaimTransferContext},
+    ID,
+};
+
+use super::CloseBasketDistribution;
+
+pub fn process_close_basket_distribution(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = CloseBasketDistribution::try_from((instruction_data, accounts))?;
+
+    let distribution_data = ix.accounts.distribution.try_borrow()?;
+    let distribution = BasketDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    distribution.validate_authority(ix.accounts.authority.address())?;
+    distribution.validate_mints(ix.accounts.legs.iter().map(|leg| leg.mint.address()))?;
+
+    if distribution.clawback_ts != 0 {
+        let current_ts = get_current_timestamp()?;
+        if current_ts < distribution.clawback_ts {
+            return Err(RewardsProgramError::ClawbackNotReached.into());
+        }
+    }
+
+    let mut remaining_amounts = Vec::with_capacity(ix.accounts.legs.len());
+    for leg in &ix.accounts.legs {
+        let remaining_amount = close_distribution_vault(
+            &distribution,
+            &ClaimTransferContext {
+                distribution_vault: leg.distribution_vault,
+                mint: leg.mint,
+                destination: leg.token_account,
+                distribution_account: ix.accounts.distribution,
+                token_program: leg.token_program.address(),
+            },
+            ix.accounts.authority,
+        )?;
+        remaining_amounts.push(remaining_amount);
+    }
+
+    drop(distribution_data);
+
+    close_pda_account(ix.accounts.distribution, ix.accounts.authority)?;
+
+    let event = BasketDistributionClosedEvent::new(*ix.accounts.distribution.address(), remaining_amounts);
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,8 @@
+pub mod accounts;
+pub mod data;
+pub mod processor;
+
+pub use crate::instructions::impl_instructions::CloseBasketRecipient;
+pub use accounts::*;
+pub use data::*;
+pub use processor::*;
@@ -0,0 +1,42 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    events::ClaimClosedEvent,
+    state::BasketRecipient,
+    traits::EventSerialize,
+    utils::{close_pda_account, emit_event, verify_system_account},
+    ID,
+};
+
+use super::CloseBasketRecipient;
+
+pub fn process_close_basket_recipient(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = CloseBasketRecipient::try_from((instruction_data, accounts))?;
+
+    // Distribution must be closed (owner = system program means account was deleted)
+    verify_system_account(ix.accounts.distribution)?;
+
+    let recipient_data = ix.accounts.recipient_account.try_borrow()?;
+    let recipient = BasketRecipient::from_account(&recipient_data, ix.accounts.recipient_account, &ID)?;
+    drop(recipient_data);
+
+    recipient.validate_distribution(ix.accounts.distribution.address())?;
+    recipient.validate_recipient(ix.accounts.recipient.address())?;
+
+    // Validate that the payer account matches the one stored in the recipient
+    if &recipient.payer != ix.accounts.original_payer.address() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Return rent to the original payer who created this recipient account
+    close_pda_account(ix.accounts.recipient_account, ix.accounts.original_payer)?;
+
+    let event = ClaimClosedEvent::new(*ix.accounts.distribution.address(), *ix.accounts.recipient.address());
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
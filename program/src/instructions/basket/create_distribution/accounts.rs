@@ -0,0 +1,102 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    errors::RewardsProgramError,
+    state::MAX_BASKET_MINTS,
+    traits::InstructionAccounts,
+    utils::{
+        validate_distribution_vault_address, verify_associated_token_program, verify_current_program,
+        verify_event_authority, verify_mint_owner, verify_readonly, verify_signer, verify_system_program,
+        verify_token_program_for_mint, verify_writable,
+    },
+};
+
+pub struct CreateBasketDistributionAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub authority: &'a AccountView,
+    pub seed: &'a AccountView,
+    pub distribution: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub associated_token_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub program: &'a AccountView,
+    /// Remaining accounts as `[mint, distribution_vault, token_program]` triples, one per basket mint
+    pub legs: &'a [AccountView],
+}
+
+impl<'a> CreateBasketDistributionAccounts<'a> {
+    /// Each `[mint, distribution_vault, token_program]` triple, in basket slot order
+    #[inline(always)]
+    pub fn leg_accounts(&self) -> impl Iterator<Item = (&'a AccountView, &'a AccountView, &'a AccountView)> + '_ {
+        self.legs.chunks_exact(3).map(|triple| (&triple[0], &triple[1], &triple[2]))
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for CreateBasketDistributionAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, authority, seeds, distribution, system_program, associated_token_program, event_authority, program, legs @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if legs.is_empty() || legs.len() % 3 != 0 || legs.len() > MAX_BASKET_MINTS * 3 {
+            return Err(RewardsProgramError::InvalidBasketMints.into());
+        }
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(authority, false)?;
+        verify_signer(seeds, false)?;
+
+        // 2. Validate writable
+        verify_writable(distribution, true)?;
+        for triple in legs.chunks_exact(3) {
+            verify_writable(&triple[1], true)?;
+        }
+
+        // 2b. Validate read-only accounts
+        verify_readonly(seeds)?;
+        for triple in legs.chunks_exact(3) {
+            verify_readonly(&triple[0])?;
+        }
+
+        // 3. Validate program IDs
+        verify_system_program(system_program)?;
+        verify_associated_token_program(associated_token_program)?;
+        verify_current_program(program)?;
+        verify_event_authority(event_authority)?;
+        for triple in legs.chunks_exact(3) {
+            verify_token_program_for_mint(&triple[2], &triple[0])?;
+        }
+
+        // 4. (no accounts owned by current program for this instruction)
+
+        // 5. Validate token account ownership
+        for triple in legs.chunks_exact(3) {
+            verify_mint_owner(&triple[0], &triple[2])?;
+        }
+
+        // 6. Validate each distribution_vault address (they may not be initialized yet)
+        for triple in legs.chunks_exact(3) {
+            validate_distribution_vault_address(&triple[1], distribution.address(), &triple[0], &triple[2])?;
+        }
+
+        Ok(Self {
+            payer,
+            authority,
+            seed: seeds,
+            distribution,
+            system_program,
+            associated_token_program,
+            event_authority,
+            program,
+            legs,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for CreateBasketDistributionAccounts<'a> {}
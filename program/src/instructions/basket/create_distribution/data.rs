@@ -0,0 +1,54 @@
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, traits::InstructionData};
+
+pub struct CreateBasketDistributionData {
+    pub bump: u8,
+    pub clawback_ts: i64,
+}
+
+impl<'a> TryFrom<&'a [u8]> for CreateBasketDistributionData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let bump = data[0];
+        let clawback_ts = i64::from_le_bytes(data[1..9].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+
+        Ok(Self { bump, clawback_ts })
+    }
+}
+
+impl<'a> InstructionData<'a> for CreateBasketDistributionData {
+    const LEN: usize = 9; // bump(1) + clawback_ts(8)
+
+    fn validate(&self) -> Result<(), ProgramError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_valid_data() {
+        let mut data = [0u8; 9];
+        data[0] = 254;
+        data[1..9].copy_from_slice(&1700000000i64.to_le_bytes());
+
+        let parsed = CreateBasketDistributionData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.bump, 254);
+        assert_eq!(parsed.clawback_ts, 1700000000);
+        assert!(parsed.validate().is_ok());
+    }
+
+    #[test]
+    fn test_try_from_data_too_short() {
+        let data = [0u8; 8]; // need 9
+        let result = CreateBasketDistributionData::try_from(&data[..]);
+        assert_eq!(result.err(), Some(ProgramError::InvalidInstructionData));
+    }
+}
@@ -0,0 +1,74 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    events::BasketDistributionCreatedEvent,
+    state::BasketDistribution,
+    traits::{AccountSerialize, AccountSize, EventSerialize, InstructionData, PdaSeeds},
+    utils::{create_distribution_vault, create_pda_account, emit_event, verify_supported_mint_extensions},
+    ID,
+};
+
+use super::CreateBasketDistribution;
+
+pub fn process_create_basket_distribution(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = CreateBasketDistribution::try_from((instruction_data, accounts))?;
+    ix.data.validate()?;
+
+    let mints: Vec<Address> = ix.accounts.leg_accounts().map(|(mint, _, _)| *mint.address()).collect();
+    for (mint, _, _) in ix.accounts.leg_accounts() {
+        verify_supported_mint_extensions(mint)?;
+    }
+
+    let distribution = BasketDistribution::new(
+        ix.data.bump,
+        ix.data.clawback_ts,
+        *ix.accounts.authority.address(),
+        *ix.accounts.seed.address(),
+        &mints,
+    )?;
+
+    distribution.validate_pda(ix.accounts.distribution, &ID, ix.data.bump)?;
+
+    let bump_seed = [ix.data.bump];
+    let distribution_seeds = distribution.seeds_with_bump(&bump_seed);
+    let distribution_seeds_array: [_; 4] = distribution_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    create_pda_account(
+        ix.accounts.payer,
+        BasketDistribution::LEN,
+        &ID,
+        ix.accounts.distribution,
+        distribution_seeds_array,
+    )?;
+
+    let mut distribution_data = ix.accounts.distribution.try_borrow_mut()?;
+    distribution.write_to_slice(&mut distribution_data)?;
+    drop(distribution_data);
+
+    for (mint, distribution_vault, token_program) in ix.accounts.leg_accounts() {
+        create_distribution_vault(
+            ix.accounts.payer,
+            ix.accounts.distribution,
+            distribution_vault,
+            mint,
+            ix.accounts.system_program,
+            token_program,
+        )?;
+    }
+
+    let event = BasketDistributionCreatedEvent::new(
+        *ix.accounts.distribution.address(),
+        *ix.accounts.authority.address(),
+        *ix.accounts.seed.address(),
+        mints,
+        ix.data.clawback_ts,
+    );
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    errors::RewardsProgramError,
+    state::MAX_BASKET_MINTS,
+    utils::{
+        validate_distribution_vault, verify_mint_owner, verify_owned_by, verify_readonly,
+        verify_token_program_for_mint, verify_writable,
+    },
+};
+
+/// Number of accounts per basket leg
+pub const BASKET_LEG_ACCOUNTS_LEN: usize = 4;
+
+/// Per-mint accounts of a basket instruction, passed as remaining accounts.
+pub struct BasketLegAccounts<'a> {
+    pub mint: &'a AccountView,
+    pub distribution_vault: &'a AccountView,
+    /// Token account (or wallet, for native SOL) tokens move from or to
+    pub token_account: &'a AccountView,
+    pub token_program: &'a AccountView,
+}
+
+/// Validate `[mint, distribution_vault, token_account, token_program]` quads and return one leg per quad.
+///
+/// Callers still check that the leg mints match the basket's mints, in order.
+#[inline(always)]
+pub fn parse_basket_legs<'a>(
+    legs: &'a [AccountView],
+    distribution: &AccountView,
+) -> Result<Vec<BasketLegAccounts<'a>>, ProgramError> {
+    if legs.is_empty()
+        || !legs.len().is_multiple_of(BASKET_LEG_ACCOUNTS_LEN)
+        || legs.len() > MAX_BASKET_MINTS * BASKET_LEG_ACCOUNTS_LEN
+    {
+        return Err(RewardsProgramError::InvalidBasketAccounts.into());
+    }
+
+    legs.chunks_exact(BASKET_LEG_ACCOUNTS_LEN)
+        .map(|quad| {
+            let [mint, distribution_vault, token_account, token_program] = quad else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            verify_writable(distribution_vault, true)?;
+            verify_writable(token_account, true)?;
+            verify_readonly(mint)?;
+            verify_token_program_for_mint(token_program, mint)?;
+            verify_mint_owner(mint, token_program)?;
+            verify_owned_by(token_account, token_program.address())?;
+            validate_distribution_vault(distribution_vault, distribution.address(), mint, token_program)?;
+
+            Ok(BasketLegAccounts { mint, distribution_vault, token_account, token_program })
+        })
+        .collect()
+}
@@ -0,0 +1,6 @@
+pub mod add_recipient;
+pub mod claim;
+pub mod close_distribution;
+pub mod close_recipient;
+pub mod create_distribution;
+pub mod legs;
@@ -0,0 +1,90 @@
+use pinocchio::{error::ProgramError, Address};
+
+use crate::{errors::RewardsProgramError, require_len, traits::InstructionData, utils::VestingSchedule};
+
+/// Instruction data for AddCompressedRecipient.
+///
+/// Variable-length due to the VestingSchedule enum.
+pub struct AddCompressedRecipientData {
+    /// Wallet that will claim this allocation
+    pub recipient: Address,
+    /// Token amount allocated to this recipient
+    pub amount: u64,
+    /// Vesting schedule for this recipient's allocation
+    pub schedule: VestingSchedule,
+}
+
+impl<'a> TryFrom<&'a [u8]> for AddCompressedRecipientData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let recipient =
+            Address::new_from_array(data[..32].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        let amount = u64::from_le_bytes(data[32..40].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        let (schedule, _) = VestingSchedule::from_bytes(&data[40..])?;
+
+        Ok(Self { recipient, amount, schedule })
+    }
+}
+
+impl<'a> InstructionData<'a> for AddCompressedRecipientData {
+    const LEN: usize = 32 + 8 + 1; // recipient + amount + min schedule (Immediate)
+
+    fn validate(&self) -> Result<(), ProgramError> {
+        if self.amount == 0 {
+            return Err(RewardsProgramError::InvalidAmount.into());
+        }
+        self.schedule.validate()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn make_data(amount: u64, schedule: &VestingSchedule) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&[7u8; 32]); // recipient
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.extend_from_slice(&schedule.to_bytes());
+        data
+    }
+
+    #[test]
+    fn test_try_from_valid_linear() {
+        let schedule = VestingSchedule::Linear { start_ts: 100, end_ts: 200 };
+        let data = make_data(1000, &schedule);
+        let parsed = AddCompressedRecipientData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.recipient, Address::new_from_array([7u8; 32]));
+        assert_eq!(parsed.amount, 1000);
+        assert_eq!(parsed.schedule, schedule);
+        assert!(parsed.validate().is_ok());
+    }
+
+    #[test]
+    fn test_try_from_data_too_short() {
+        let data = [0u8; 40];
+        let result = AddCompressedRecipientData::try_from(&data[..]);
+        assert_eq!(result.err(), Some(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_validate_zero_amount() {
+        let data = make_data(0, &VestingSchedule::Immediate {});
+        let parsed = AddCompressedRecipientData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.validate().err(), Some(RewardsProgramError::InvalidAmount.into()));
+    }
+
+    #[test]
+    fn test_validate_invalid_time_window() {
+        let data = make_data(1000, &VestingSchedule::Linear { start_ts: 200, end_ts: 100 });
+        let parsed = AddCompressedRecipientData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.validate().err(), Some(RewardsProgramError::InvalidTimeWindow.into()));
+    }
+}
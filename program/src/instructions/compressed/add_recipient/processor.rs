@@ -0,0 +1,67 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    events::CompressedRecipientAddedEvent,
+    state::{CompressedDistribution, CompressedRecipient},
+    traits::{AccountSerialize, Distribution, EventSerialize, InstructionData},
+    utils::{deposit_to_distribution_vault, emit_event, VaultDepositContext},
+    ID,
+};
+
+use super::AddCompressedRecipient;
+
+pub fn process_add_compressed_recipient(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = AddCompressedRecipient::try_from((instruction_data, accounts))?;
+    ix.data.validate()?;
+
+    let distribution_data = ix.accounts.distribution.try_borrow()?;
+    let mut distribution = CompressedDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    drop(distribution_data);
+
+    Distribution::validate_authority(&distribution, ix.accounts.authority.address())?;
+
+    // Allocate what actually reached the vault so Token-2022 transfer fees never leave it short
+    let received = deposit_to_distribution_vault(
+        &VaultDepositContext {
+            source: ix.accounts.authority_token_account,
+            authority: ix.accounts.authority,
+            distribution_vault: ix.accounts.distribution_vault,
+            mint: ix.accounts.mint,
+            token_program: ix.accounts.token_program,
+        },
+        ix.data.amount,
+    )?;
+    if received == 0 {
+        return Err(RewardsProgramError::InvalidAmount.into());
+    }
+
+    distribution.total_allocated =
+        distribution.total_allocated.checked_add(received).ok_or(RewardsProgramError::MathOverflow)?;
+    distribution.add_funded(received)?;
+
+    // Pauses before the leaf existed never shift its schedule
+    let recipient =
+        CompressedRecipient::new(ix.data.recipient, received, 0, distribution.vesting_offset, ix.data.schedule);
+
+    let mut distribution_data = ix.accounts.distribution.try_borrow_mut()?;
+    let leaf_index = CompressedDistribution::tree_mut(&mut distribution_data)?.append(&recipient.leaf_hash())?;
+    distribution.write_to_slice(&mut distribution_data)?;
+    drop(distribution_data);
+
+    let event = CompressedRecipientAddedEvent::new(
+        *ix.accounts.distribution.address(),
+        recipient.recipient,
+        leaf_index,
+        received,
+        recipient.base_vesting_offset,
+        recipient.schedule,
+    );
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use alloc::vec::Vec;
+use pinocchio::error::ProgramError;
+
+use crate::{
+    require_len,
+    traits::InstructionData,
+    utils::{TransferFeeMode, VestingSchedule},
+};
+
+/// Instruction data for ClaimCompressed.
+///
+/// Fixed-size fields first, then variable-length schedule and proof.
+/// `total_amount`, `claimed_amount`, `base_vesting_offset` and `schedule` are
+/// the recipient's current leaf, proven against `root`.
+pub struct ClaimCompressedData {
+    /// Tree root the proof was built against; may be any root still in the changelog
+    pub root: [u8; 32],
+    /// Leaf index of the recipient
+    pub index: u32,
+    /// Total amount allocated to the recipient (from leaf)
+    pub total_amount: u64,
+    /// Amount already claimed (from leaf)
+    pub claimed_amount: u64,
+    /// Distribution vesting offset when the leaf was appended (from leaf)
+    pub base_vesting_offset: i64,
+    /// Amount to claim (0 = claim all available)
+    pub amount: u64,
+    /// Whether `amount` is sent from the vault (Gross) or received by the recipient (Net)
+    pub fee_mode: TransferFeeMode,
+    /// Vesting schedule (from leaf, variable length)
+    pub schedule: VestingSchedule,
+    /// Merkle proof from leaf to root (variable length)
+    pub proof: Vec<[u8; 32]>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for ClaimCompressedData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        // Fixed-size fields at known offsets
+        let root: [u8; 32] = data[0..32].try_into().map_err(|_| ProgramError::InvalidInstructionData)?;
+        let index = u32::from_le_bytes(data[32..36].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        let total_amount =
+            u64::from_le_bytes(data[36..44].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        let claimed_amount =
+            u64::from_le_bytes(data[44..52].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        let base_vesting_offset =
+            i64::from_le_bytes(data[52..60].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        let amount = u64::from_le_bytes(data[60..68].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        let fee_mode = TransferFeeMode::try_from(data[68])?;
+
+        // Variable-length fields
+        let (schedule, schedule_len) = VestingSchedule::from_bytes(&data[69..])?;
+
+        let proof_offset = 69 + schedule_len;
+        require_len!(data, proof_offset + 4); // proof_len(4)
+
+        let proof_len = u32::from_le_bytes(
+            data[proof_offset..proof_offset + 4].try_into().map_err(|_| ProgramError::InvalidInstructionData)?,
+        ) as usize;
+
+        let proof_start = proof_offset + 4;
+        let expected_len = proof_start + proof_len * 32;
+        require_len!(data, expected_len);
+
+        let mut proof = Vec::with_capacity(proof_len);
+        for i in 0..proof_len {
+            let start = proof_start + i * 32;
+            let end = start + 32;
+            let hash: [u8; 32] = data[start..end].try_into().map_err(|_| ProgramError::InvalidInstructionData)?;
+            proof.push(hash);
+        }
+
+        Ok(Self { root, index, total_amount, claimed_amount, base_vesting_offset, amount, fee_mode, schedule, proof })
+    }
+}
+
+impl<'a> InstructionData<'a> for ClaimCompressedData {
+    // root(32) + index(4) + total_amount(8) + claimed_amount(8) + base_vesting_offset(8) + amount(8) + fee_mode(1)
+    // + min_schedule(1) + proof_len(4) = 74
+    const LEN: usize = 74;
+
+    // No validate() override needed: the leaf fields are proven against the tree
+    // in the processor, so an invalid schedule fails proof verification.
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    fn build_data(index: u32, claimed_amount: u64, schedule: &VestingSchedule, proof: &[[u8; 32]]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&[9u8; 32]); // root
+        data.extend_from_slice(&index.to_le_bytes());
+        data.extend_from_slice(&1_000u64.to_le_bytes()); // total_amount
+        data.extend_from_slice(&claimed_amount.to_le_bytes());
+        data.extend_from_slice(&600i64.to_le_bytes()); // base_vesting_offset
+        data.extend_from_slice(&0u64.to_le_bytes()); // amount
+        data.push(0); // fee_mode
+        data.extend_from_slice(&schedule.to_bytes());
+        data.extend_from_slice(&(proof.len() as u32).to_le_bytes());
+        for node in proof {
+            data.extend_from_slice(node);
+        }
+        data
+    }
+
+    #[test]
+    fn test_try_from_valid_data() {
+        let schedule = VestingSchedule::Linear { start_ts: 100, end_ts: 200 };
+        let data = build_data(5, 250, &schedule, &[[1u8; 32], [2u8; 32]]);
+        let result = ClaimCompressedData::try_from(&data[..]).unwrap();
+        assert_eq!(result.root, [9u8; 32]);
+        assert_eq!(result.index, 5);
+        assert_eq!(result.total_amount, 1_000);
+        assert_eq!(result.claimed_amount, 250);
+        assert_eq!(result.base_vesting_offset, 600);
+        assert_eq!(result.amount, 0);
+        assert_eq!(result.fee_mode, TransferFeeMode::Gross {});
+        assert_eq!(result.schedule, schedule);
+        assert_eq!(result.proof, vec![[1u8; 32], [2u8; 32]]);
+    }
+
+    #[test]
+    fn test_try_from_min_length() {
+        let data = build_data(0, 0, &VestingSchedule::Immediate {}, &[]);
+        assert_eq!(data.len(), ClaimCompressedData::LEN);
+        assert!(ClaimCompressedData::try_from(&data[..]).is_ok());
+    }
+
+    #[test]
+    fn test_try_from_truncated_proof() {
+        let data = build_data(0, 0, &VestingSchedule::Immediate {}, &[[1u8; 32]]);
+        let result = ClaimCompressedData::try_from(&data[..data.len() - 1]);
+        assert_eq!(result.err(), Some(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_try_from_data_too_short() {
+        let data = [0u8; 73];
+        let result = ClaimCompressedData::try_from(&data[..]);
+        assert_eq!(result.err(), Some(ProgramError::InvalidInstructionData));
+    }
+}
@@ -0,0 +1,87 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    events::CompressedClaimedEvent,
+    state::{CompressedDistribution, CompressedRecipient},
+    traits::{AccountSerialize, ClaimTracker, Distribution, EventSerialize, InstructionData, VestingParams},
+    utils::{
+        emit_event, get_current_timestamp, resolve_claim_amount, resolve_gross_amount,
+        transfer_from_distribution_vault, verify_vault_balance, ClaimTransferContext,
+    },
+    ID,
+};
+
+use super::ClaimCompressed;
+
+pub fn process_claim_compressed(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = ClaimCompressed::try_from((instruction_data, accounts))?;
+    ix.data.validate()?;
+    let current_ts = get_current_timestamp()?;
+
+    let distribution_data = ix.accounts.distribution.try_borrow()?;
+    let mut distribution = CompressedDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    drop(distribution_data);
+
+    distribution.validate_not_paused()?;
+
+    // The signer must be the leaf's recipient; a mismatch would also fail the proof
+    let mut recipient = CompressedRecipient::new(
+        *ix.accounts.recipient.address(),
+        ix.data.total_amount,
+        ix.data.claimed_amount,
+        ix.data.base_vesting_offset,
+        ix.data.schedule,
+    );
+    let previous_leaf = recipient.leaf_hash();
+
+    let unlocked_amount = VestingParams::calculate_unlocked(
+        &recipient,
+        distribution.vesting_timestamp(current_ts, recipient.base_vesting_offset),
+    )?;
+    let claimable_amount = ClaimTracker::claimable_amount(&recipient, unlocked_amount)?;
+    let requested_amount = resolve_gross_amount(ix.accounts.mint, ix.data.amount, ix.data.fee_mode)?;
+    let claim_amount = resolve_claim_amount(requested_amount, claimable_amount)?;
+
+    verify_vault_balance(ix.accounts.distribution_vault, claim_amount)?;
+
+    ClaimTracker::add_claimed(&mut recipient, claim_amount)?;
+    Distribution::add_claimed(&mut distribution, claim_amount)?;
+
+    let mut distribution_data = ix.accounts.distribution.try_borrow_mut()?;
+    CompressedDistribution::tree_mut(&mut distribution_data)?.replace_leaf(
+        &ix.data.root,
+        &previous_leaf,
+        &recipient.leaf_hash(),
+        &ix.data.proof,
+        ix.data.index,
+    )?;
+    distribution.write_to_slice(&mut distribution_data)?;
+    drop(distribution_data);
+
+    transfer_from_distribution_vault(
+        &distribution,
+        &ClaimTransferContext {
+            distribution_vault: ix.accounts.distribution_vault,
+            mint: ix.accounts.mint,
+            destination: ix.accounts.recipient_token_account,
+            distribution_account: ix.accounts.distribution,
+            token_program: ix.accounts.token_program.address(),
+        },
+        claim_amount,
+    )?;
+
+    let event = CompressedClaimedEvent::new(
+        *ix.accounts.distribution.address(),
+        recipient.recipient,
+        ix.data.index,
+        claim_amount,
+        recipient.claimed_amount,
+    );
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    events::DistributionClosedEvent,
+    state::CompressedDistribution,
+    traits::{Distribution, EventSerialize},
+    utils::{close_distribution_vault, close_pda_account, emit_event, get_current_timestamp, ClaimTransferContext},
+    ID,
+};
+
+use super::CloseCompressedDistribution;
+
+pub fn process_close_compressed_distribution(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = CloseCompressedDistribution::try_from((instruction_data, accounts))?;
+
+    let distribution_data = ix.accounts.distribution.try_borrow()?;
+    let distribution = CompressedDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    distribution.validate_authority(ix.accounts.authority.address())?;
+
+    if distribution.clawback_ts != 0 {
+        let current_ts = get_current_timestamp()?;
+        if current_ts < distribution.clawback_ts {
+            return Err(RewardsProgramError::ClawbackNotReached.into());
+        }
+    }
+
+    let remaining_amount = close_distribution_vault(
+        &distribution,
+        &ClaimTransferContext {
+            distribution_vault: ix.accounts.distribution_vault,
+            mint: ix.accounts.mint,
+            destination: ix.accounts.authority_token_account,
+            distribution_account: ix.accounts.distribution,
+            token_program: ix.accounts.token_program.address(),
+        },
+        ix.accounts.authority,
+    )?;
+
+    drop(distribution_data);
+
+    close_pda_account(ix.accounts.distribution, ix.accounts.authority)?;
+
+    let event = DistributionClosedEvent::new(*ix.accounts.distribution.address(), remaining_amount);
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
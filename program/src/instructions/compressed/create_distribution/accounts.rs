@@ -0,0 +1,81 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        validate_distribution_vault_address, verify_associated_token_program, verify_current_program,
+        verify_event_authority, verify_mint_owner, verify_readonly, verify_signer, verify_system_program,
+        verify_token_program_for_mint, verify_writable,
+    },
+};
+
+pub struct CreateCompressedDistributionAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub authority: &'a AccountView,
+    pub seed: &'a AccountView,
+    pub distribution: &'a AccountView,
+    pub mint: &'a AccountView,
+    pub distribution_vault: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub token_program: &'a AccountView,
+    pub associated_token_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for CreateCompressedDistributionAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, authority, seeds, distribution, mint, distribution_vault, system_program, token_program, associated_token_program, event_authority, program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(authority, false)?;
+        verify_signer(seeds, false)?;
+
+        // 2. Validate writable
+        verify_writable(distribution, true)?;
+        verify_writable(distribution_vault, true)?;
+
+        // 2b. Validate read-only accounts
+        verify_readonly(mint)?;
+        verify_readonly(seeds)?;
+
+        // 3. Validate program IDs
+        verify_system_program(system_program)?;
+        verify_token_program_for_mint(token_program, mint)?;
+        verify_associated_token_program(associated_token_program)?;
+        verify_current_program(program)?;
+        verify_event_authority(event_authority)?;
+
+        // 4. (no accounts owned by current program for this instruction)
+
+        // 5. Validate token account ownership
+        verify_mint_owner(mint, token_program)?;
+
+        // 6. Validate distribution_vault address (it may not be initialized yet)
+        validate_distribution_vault_address(distribution_vault, distribution.address(), mint, token_program)?;
+
+        Ok(Self {
+            payer,
+            authority,
+            seed: seeds,
+            distribution,
+            mint,
+            distribution_vault,
+            system_program,
+            token_program,
+            associated_token_program,
+            event_authority,
+            program,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for CreateCompressedDistributionAccounts<'a> {}
@@ -0,0 +1,58 @@
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, traits::InstructionData};
+
+pub struct CreateCompressedDistributionData {
+    pub bump: u8,
+    pub clawback_ts: i64,
+}
+
+impl<'a> TryFrom<&'a [u8]> for CreateCompressedDistributionData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let bump = data[0];
+        let clawback_ts = i64::from_le_bytes(data[1..9].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+
+        Ok(Self { bump, clawback_ts })
+    }
+}
+
+impl<'a> InstructionData<'a> for CreateCompressedDistributionData {
+    const LEN: usize = 9; // bump(1) + clawback_ts(8)
+
+    fn validate(&self) -> Result<(), ProgramError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_data(bump: u8, clawback_ts: i64) -> [u8; 9] {
+        let mut data = [0u8; 9];
+        data[0] = bump;
+        data[1..9].copy_from_slice(&clawback_ts.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_try_from_valid_data() {
+        let data = make_data(255, 1700000000);
+        let parsed = CreateCompressedDistributionData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.bump, 255);
+        assert_eq!(parsed.clawback_ts, 1700000000);
+        assert!(parsed.validate().is_ok());
+    }
+
+    #[test]
+    fn test_try_from_data_too_short() {
+        let data = [0u8; 8];
+        let result = CreateCompressedDistributionData::try_from(&data[..]);
+        assert_eq!(result.err(), Some(ProgramError::InvalidInstructionData));
+    }
+}
@@ -0,0 +1,67 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    events::DistributionCreatedEvent,
+    state::CompressedDistribution,
+    traits::{AccountSerialize, EventSerialize, InstructionData, PdaSeeds},
+    utils::{create_distribution_vault, create_pda_account, emit_event, verify_supported_mint_extensions},
+    ID,
+};
+
+use super::CreateCompressedDistribution;
+
+pub fn process_create_compressed_distribution(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = CreateCompressedDistribution::try_from((instruction_data, accounts))?;
+    ix.data.validate()?;
+    verify_supported_mint_extensions(ix.accounts.mint)?;
+
+    let distribution = CompressedDistribution::new(
+        ix.data.bump,
+        ix.data.clawback_ts,
+        *ix.accounts.authority.address(),
+        *ix.accounts.mint.address(),
+        *ix.accounts.seed.address(),
+    );
+
+    distribution.validate_pda(ix.accounts.distribution, &ID, ix.data.bump)?;
+
+    let bump_seed = [ix.data.bump];
+    let distribution_seeds = distribution.seeds_with_bump(&bump_seed);
+    let distribution_seeds_array: [_; 5] = distribution_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    create_pda_account(
+        ix.accounts.payer,
+        CompressedDistribution::account_size(),
+        &ID,
+        ix.accounts.distribution,
+        distribution_seeds_array,
+    )?;
+
+    let mut distribution_data = ix.accounts.distribution.try_borrow_mut()?;
+    distribution.write_to_slice(&mut distribution_data)?;
+    CompressedDistribution::tree_mut(&mut distribution_data)?.initialize()?;
+    drop(distribution_data);
+
+    create_distribution_vault(
+        ix.accounts.payer,
+        ix.accounts.distribution,
+        ix.accounts.distribution_vault,
+        ix.accounts.mint,
+        ix.accounts.system_program,
+        ix.accounts.token_program,
+    )?;
+
+    let event = DistributionCreatedEvent::compressed(
+        *ix.accounts.authority.address(),
+        *ix.accounts.mint.address(),
+        *ix.accounts.seed.address(),
+        ix.data.clawback_ts,
+    );
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
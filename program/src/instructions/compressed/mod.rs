@@ -0,0 +1,4 @@
+pub mod add_recipient;
+pub mod claim;
+pub mod close_distribution;
+pub mod create_distribution;
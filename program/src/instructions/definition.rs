@@ -1,7 +1,11 @@
 use alloc::vec::Vec;
 use codama::CodamaInstructions;
+use pinocchio::Address;
 
-use crate::utils::{RevokeMode, VestingSchedule};
+use crate::{
+    instructions::direct::add_recipients::DirectRecipientEntry,
+    utils::{ClosePolicy, MerkleClaimMode, RevokeMode, TransferFeeMode, VestingSchedule},
+};
 
 /// Instructions for the Rewards Program.
 #[repr(C, u8)]
@@ -16,24 +20,33 @@ pub enum RewardsProgramInstruction {
         writable,
         docs = "PDA: [b\"direct_distribution\", mint, authority, seeds] (created)"
     ))]
-    #[codama(account(name = "mint", docs = "SPL token mint"))]
+    #[codama(account(name = "mint", docs = "SPL token mint, or the System program ID for native SOL"))]
     #[codama(account(
         name = "distribution_vault",
         writable,
-        docs = "ATA of distribution PDA for mint (created via CPI)"
+        docs = "ATA of distribution PDA for mint, or PDA: [b\"native_vault\", distribution] for native SOL (created via CPI)"
     ))]
     #[codama(account(name = "system_program", docs = "System program"))]
-    #[codama(account(name = "token_program", docs = "SPL Token or Token-2022 program"))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
     #[codama(account(name = "associated_token_program", docs = "Associated Token Account program"))]
     #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
     #[codama(account(name = "rewardsProgram", docs = "This program's ID (for event CPI)"))]
     CreateDirectDistribution {
         /// Bump for the distribution PDA
         bump: u8,
-        /// Bitmask of allowed revoke modes (0 = not revocable, bit 0 = NonVested, bit 1 = Full)
+        /// Bitmask of allowed revoke modes (0 = not revocable, bit 0 = NonVested, bit 1 = Full, bit 2 = Freeze)
         revocable: u8,
-        /// Timestamp after which authority can close the distribution (0 = no gate)
+        /// Timestamp after which authority can close the distribution (0 = no gate beyond claim_end_ts)
         clawback_ts: i64,
+        /// Close behaviour: sweep the whole vault, or keep unclaimed allocations for recipients
+        close_policy: ClosePolicy,
+        /// Timestamp claims open at (0 = open immediately)
+        claim_start_ts: i64,
+        /// Timestamp claims close at (0 = never); recipient schedules must end by then
+        claim_end_ts: i64,
     } = 0,
 
     /// Add a recipient to a direct distribution.
@@ -52,7 +65,7 @@ pub enum RewardsProgramInstruction {
     #[codama(account(
         name = "distribution_vault",
         writable,
-        docs = "ATA of distribution PDA for mint; receives transferred tokens"
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); receives transferred tokens"
     ))]
     #[codama(account(
         name = "authority_token_account",
@@ -60,7 +73,10 @@ pub enum RewardsProgramInstruction {
         docs = "Authority's token account; source of tokens for this recipient's allocation"
     ))]
     #[codama(account(name = "system_program", docs = "System program"))]
-    #[codama(account(name = "token_program", docs = "SPL Token or Token-2022 program"))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
     #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
     #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
     AddDirectRecipient {
@@ -88,19 +104,24 @@ pub enum RewardsProgramInstruction {
     #[codama(account(
         name = "distribution_vault",
         writable,
-        docs = "ATA of distribution PDA for mint; source of claimed tokens"
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); source of claimed tokens"
     ))]
     #[codama(account(
         name = "recipient_token_account",
         writable,
         docs = "Recipient's token account; destination for claimed tokens"
     ))]
-    #[codama(account(name = "token_program", docs = "SPL Token or Token-2022 program"))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
     #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
     #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
     ClaimDirect {
         /// Amount to claim. 0 = claim all available.
         amount: u64,
+        /// Whether `amount` is sent from the vault (Gross) or received by the recipient (Net)
+        fee_mode: TransferFeeMode,
     } = 2,
 
     /// Close a direct distribution and recover remaining tokens.
@@ -115,14 +136,17 @@ pub enum RewardsProgramInstruction {
     #[codama(account(
         name = "distribution_vault",
         writable,
-        docs = "ATA of distribution PDA for mint; remaining tokens returned to authority"
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); remaining tokens returned to authority"
     ))]
     #[codama(account(
         name = "authority_token_account",
         writable,
         docs = "Authority's token account; destination for remaining tokens"
     ))]
-    #[codama(account(name = "token_program", docs = "SPL Token or Token-2022 program"))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
     #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
     #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
     CloseDirectDistribution {} = 3,
@@ -160,11 +184,11 @@ pub enum RewardsProgramInstruction {
         writable,
         docs = "PDA: [b\"merkle_distribution\", mint, authority, seeds] (created)"
     ))]
-    #[codama(account(name = "mint", docs = "SPL token mint"))]
+    #[codama(account(name = "mint", docs = "SPL token mint, or the System program ID for native SOL"))]
     #[codama(account(
         name = "distribution_vault",
         writable,
-        docs = "ATA of distribution PDA for mint (created via CPI)"
+        docs = "ATA of distribution PDA for mint, or PDA: [b\"native_vault\", distribution] for native SOL (created via CPI)"
     ))]
     #[codama(account(
         name = "authority_token_account",
@@ -172,7 +196,10 @@ pub enum RewardsProgramInstruction {
         docs = "Authority's token account; source of initial funding"
     ))]
     #[codama(account(name = "system_program", docs = "System program"))]
-    #[codama(account(name = "token_program", docs = "SPL Token or Token-2022 program"))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
     #[codama(account(name = "associated_token_program", docs = "Associated Token Account program"))]
     #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
     #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
@@ -189,6 +216,13 @@ pub enum RewardsProgramInstruction {
         total_amount: u64,
         /// Timestamp after which authority can close the distribution
         clawback_ts: i64,
+        /// How claims are tracked: per-claimant ClaimAccount PDAs, or chunked bitmaps of leaf indices
+        /// (Bitmap rejects mints with a transfer fee)
+        claim_mode: MerkleClaimMode,
+        /// Timestamp claims open at (0 = open immediately)
+        claim_start_ts: i64,
+        /// Timestamp claims close at (0 = never)
+        claim_end_ts: i64,
     } = 5,
 
     /// Claim tokens from a merkle distribution.
@@ -204,11 +238,15 @@ pub enum RewardsProgramInstruction {
         name = "revocation_account",
         docs = "PDA: [b\"merkle_revocation\", distribution, claimant] (checked for existence)"
     ))]
+    #[codama(account(
+        name = "redirect_account",
+        docs = "PDA: [b\"merkle_redirect\", distribution, claimant] (checked for existence)"
+    ))]
     #[codama(account(name = "mint", docs = "SPL token mint"))]
     #[codama(account(
         name = "distribution_vault",
         writable,
-        docs = "ATA of distribution PDA for mint; source of claimed tokens"
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); source of claimed tokens"
     ))]
     #[codama(account(
         name = "claimant_token_account",
@@ -216,7 +254,10 @@ pub enum RewardsProgramInstruction {
         docs = "Claimant's token account; destination for claimed tokens"
     ))]
     #[codama(account(name = "system_program", docs = "System program"))]
-    #[codama(account(name = "token_program", docs = "SPL Token or Token-2022 program"))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
     #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
     #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
     ClaimMerkle {
@@ -226,6 +267,8 @@ pub enum RewardsProgramInstruction {
         total_amount: u64,
         /// Amount to claim (0 = claim all available)
         amount: u64,
+        /// Whether `amount` is sent from the vault (Gross) or received by the claimant (Net)
+        fee_mode: TransferFeeMode,
         /// Vesting schedule (from merkle leaf)
         schedule: VestingSchedule,
         /// Merkle proof
@@ -233,12 +276,8 @@ pub enum RewardsProgramInstruction {
     } = 6,
 
     /// Close a merkle claim after distribution is closed.
-    #[codama(account(
-        name = "claimant",
-        signer,
-        writable,
-        docs = "Wallet address of the claimant; receives rent refund"
-    ))]
+    #[codama(account(name = "claimant", signer, docs = "Wallet address of the claimant"))]
+    #[codama(account(name = "original_payer", writable, docs = "Original payer of claim PDA; receives rent refund"))]
     #[codama(account(
         name = "distribution",
         docs = "PDA: MerkleDistribution account; must be closed (owner = system program)"
@@ -264,14 +303,17 @@ pub enum RewardsProgramInstruction {
     #[codama(account(
         name = "distribution_vault",
         writable,
-        docs = "ATA of distribution PDA for mint; remaining tokens returned to authority"
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); remaining tokens returned to authority"
     ))]
     #[codama(account(
         name = "authority_token_account",
         writable,
         docs = "Authority's token account; destination for remaining tokens"
     ))]
-    #[codama(account(name = "token_program", docs = "SPL Token or Token-2022 program"))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
     #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
     #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
     CloseMerkleDistribution {} = 8,
@@ -279,12 +321,13 @@ pub enum RewardsProgramInstruction {
     /// Revoke a recipient from a revocable direct distribution.
     /// Mode 0 (NonVested): transfers vested-but-unclaimed tokens to recipient, returns unvested tokens to authority.
     /// Mode 1 (Full): returns all unclaimed tokens (unvested + vested-unclaimed) to authority, nothing transferred to recipient.
+    /// Mode 2 (Freeze): returns unvested tokens to authority and stops vesting; the recipient keeps claiming what had vested.
     #[codama(account(name = "authority", signer, docs = "Distribution authority; must match distribution.authority"))]
     #[codama(account(name = "distribution", writable, docs = "PDA: DirectDistribution account"))]
     #[codama(account(
         name = "recipient_account",
         writable,
-        docs = "PDA: [b\"direct_recipient\", distribution, recipient] (closed)"
+        docs = "PDA: [b\"direct_recipient\", distribution, recipient] (closed unless frozen)"
     ))]
     #[codama(account(name = "recipient", docs = "Wallet address of the recipient"))]
     #[codama(account(
@@ -296,7 +339,7 @@ pub enum RewardsProgramInstruction {
     #[codama(account(
         name = "distribution_vault",
         writable,
-        docs = "ATA of distribution PDA for mint; source of transferred tokens"
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); source of transferred tokens"
     ))]
     #[codama(account(
         name = "recipient_token_account",
@@ -308,11 +351,14 @@ pub enum RewardsProgramInstruction {
         writable,
         docs = "Authority's token account; destination for returned tokens"
     ))]
-    #[codama(account(name = "token_program", docs = "SPL Token or Token-2022 program"))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
     #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
     #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
     RevokeDirectRecipient {
-        /// Revoke mode: NonVested (fair) or Full (clawback all)
+        /// Revoke mode: NonVested (fair), Full (clawback all) or Freeze (stop vesting)
         revoke_mode: RevokeMode,
     } = 9,
 
@@ -337,7 +383,7 @@ pub enum RewardsProgramInstruction {
     #[codama(account(
         name = "distribution_vault",
         writable,
-        docs = "ATA of distribution PDA for mint; source of transferred tokens"
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); source of transferred tokens"
     ))]
     #[codama(account(
         name = "claimant_token_account",
@@ -350,7 +396,10 @@ pub enum RewardsProgramInstruction {
         docs = "Authority's token account; destination for returned tokens"
     ))]
     #[codama(account(name = "system_program", docs = "System program"))]
-    #[codama(account(name = "token_program", docs = "SPL Token or Token-2022 program"))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
     #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
     #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
     RevokeMerkleClaim {
@@ -364,6 +413,779 @@ pub enum RewardsProgramInstruction {
         proof: Vec<[u8; 32]>,
     } = 10,
 
+    /// Propose a new authority for a direct or merkle distribution.
+    /// The transfer only takes effect once the proposed authority calls AcceptAuthority.
+    /// Proposing again replaces any pending proposal.
+    #[codama(account(name = "authority", signer, docs = "Current distribution authority"))]
+    #[codama(account(
+        name = "distribution",
+        writable,
+        docs = "PDA: DirectDistribution or MerkleDistribution account"
+    ))]
+    ProposeAuthority {
+        /// Address that may accept authority over the distribution
+        new_authority: Address,
+    } = 11,
+
+    /// Accept a pending authority proposal, becoming the distribution's authority.
+    /// The distribution PDA address does not change.
+    #[codama(account(
+        name = "new_authority",
+        signer,
+        docs = "Proposed authority; must match distribution.pending_authority"
+    ))]
+    #[codama(account(
+        name = "distribution",
+        writable,
+        docs = "PDA: DirectDistribution or MerkleDistribution account"
+    ))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    AcceptAuthority {} = 12,
+
+    /// Cancel a pending authority proposal.
+    #[codama(account(name = "authority", signer, docs = "Current distribution authority"))]
+    #[codama(account(
+        name = "distribution",
+        writable,
+        docs = "PDA: DirectDistribution or MerkleDistribution account"
+    ))]
+    CancelAuthorityProposal {} = 13,
+
+    /// Replace the merkle root of a merkle distribution for a new epoch.
+    /// Leaves are cumulative: each claimant's claimed_amount carries across roots,
+    /// so a claimant can claim (new leaf amount - already claimed).
+    #[codama(account(name = "authority", signer, docs = "Distribution authority; must match distribution.authority"))]
+    #[codama(account(name = "distribution", writable, docs = "PDA: MerkleDistribution account"))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    UpdateMerkleRoot {
+        /// New merkle root hash
+        merkle_root: [u8; 32],
+        /// Total amount claimable by all recipients under the new root
+        total_amount: u64,
+        /// New epoch; must be greater than the current epoch
+        epoch: u64,
+    } = 14,
+
+    /// Deposit tokens into a distribution vault.
+    /// Anyone may fund a distribution; the deposit is added to distribution.total_funded.
+    #[codama(account(name = "funder", signer, docs = "Owner of the source token account"))]
+    #[codama(account(
+        name = "distribution",
+        writable,
+        docs = "PDA: DirectDistribution or MerkleDistribution account"
+    ))]
+    #[codama(account(name = "mint", docs = "SPL token mint; must match distribution.mint"))]
+    #[codama(account(
+        name = "distribution_vault",
+        writable,
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); receives transferred tokens"
+    ))]
+    #[codama(account(name = "funder_token_account", writable, docs = "Funder's token account; source of tokens"))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    FundDistribution {
+        /// Amount of tokens to deposit
+        amount: u64,
+    } = 15,
+
+    /// Add many recipients to a direct distribution in one instruction.
+    /// Remaining accounts are `[recipient_account (writable), recipient]` pairs in the same order as `recipients`.
+    /// The combined allocation is transferred into the distribution vault with a single transfer.
+    #[codama(account(name = "payer", signer, writable, docs = "Pays for recipient PDA creation"))]
+    #[codama(account(name = "authority", signer, docs = "Distribution authority; must match distribution.authority"))]
+    #[codama(account(name = "distribution", writable, docs = "PDA: DirectDistribution account"))]
+    #[codama(account(name = "mint", docs = "SPL token mint; must match distribution.mint"))]
+    #[codama(account(
+        name = "distribution_vault",
+        writable,
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); receives transferred tokens"
+    ))]
+    #[codama(account(
+        name = "authority_token_account",
+        writable,
+        docs = "Authority's token account; source of tokens for all allocations"
+    ))]
+    #[codama(account(name = "system_program", docs = "System program"))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    AddDirectRecipients {
+        /// Recipient allocations, one per remaining account pair
+        recipients: Vec<DirectRecipientEntry>,
+    } = 16,
+
+    /// Authorize a delegate to claim on the recipient's behalf and choose the destination.
+    /// Works for both distribution types; replaces any existing delegate.
+    #[codama(account(name = "payer", signer, writable, docs = "Pays for claim delegate PDA creation"))]
+    #[codama(account(
+        name = "recipient",
+        signer,
+        docs = "Direct recipient or merkle claimant granting the delegation"
+    ))]
+    #[codama(account(name = "distribution", docs = "PDA: DirectDistribution or MerkleDistribution account"))]
+    #[codama(account(
+        name = "claim_delegate_account",
+        writable,
+        docs = "PDA: [b\"claim_delegate\", distribution, recipient] (created or updated)"
+    ))]
+    #[codama(account(name = "system_program", docs = "System program"))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    SetClaimDelegate {
+        /// Address allowed to claim on the recipient's behalf
+        delegate: Address,
+    } = 17,
+
+    /// Remove the recipient's claim delegate and refund its rent to the recipient.
+    /// Emits `ClaimDelegateUpdated` with the default address as the delegate.
+    #[codama(account(
+        name = "recipient",
+        signer,
+        writable,
+        docs = "Recipient that set the delegate; receives rent from the closed account"
+    ))]
+    #[codama(account(name = "distribution", docs = "Distribution the delegate was set for (may be closed)"))]
+    #[codama(account(
+        name = "claim_delegate_account",
+        writable,
+        docs = "PDA: [b\"claim_delegate\", distribution, recipient] (closed)"
+    ))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    RemoveClaimDelegate {} = 18,
+
+    /// Claim vested tokens from a direct distribution on the recipient's behalf.
+    /// Anyone may crank into the recipient's associated token account; the recipient's
+    /// claim delegate may send to any token account. Other crankers must claim everything
+    /// claimable (amount 0) in Gross mode.
+    #[codama(account(name = "cranker", signer, docs = "Submits the claim; the claim delegate or any relayer"))]
+    #[codama(account(
+        name = "recipient",
+        docs = "Wallet address of the recipient; must match recipient_account.recipient"
+    ))]
+    #[codama(account(name = "distribution", writable, docs = "PDA: DirectDistribution account"))]
+    #[codama(account(
+        name = "recipient_account",
+        writable,
+        docs = "PDA: [b\"direct_recipient\", distribution, recipient]"
+    ))]
+    #[codama(account(
+        name = "claim_delegate_account",
+        docs = "PDA: [b\"claim_delegate\", distribution, recipient] (may be uninitialized)"
+    ))]
+    #[codama(account(name = "mint", docs = "SPL token mint"))]
+    #[codama(account(
+        name = "distribution_vault",
+        writable,
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); source of claimed tokens"
+    ))]
+    #[codama(account(
+        name = "destination_token_account",
+        writable,
+        docs = "Recipient's ATA, or any token account when cranked by the claim delegate"
+    ))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    CrankClaimDirect {
+        /// Amount to claim. 0 = claim all available.
+        amount: u64,
+        /// Whether `amount` is sent from the vault (Gross) or received by the destination (Net)
+        fee_mode: TransferFeeMode,
+    } = 19,
+
+    /// Claim vested tokens from a merkle distribution on the claimant's behalf.
+    /// Anyone may crank into the owner's associated token account; the owner and their
+    /// claim delegate may send to any token account. The owner is the claimant, or the
+    /// new claimant recorded by a MerkleRedirect. The cranker pays for the claim PDA.
+    /// Crankers other than the owner and delegate must claim everything claimable in Gross mode.
+    #[codama(account(
+        name = "cranker",
+        signer,
+        writable,
+        docs = "Submits the claim and pays for claim PDA creation; the claim delegate or any relayer"
+    ))]
+    #[codama(account(name = "claimant", docs = "Claimant whose merkle leaf is being claimed"))]
+    #[codama(account(name = "distribution", writable, docs = "PDA: MerkleDistribution account"))]
+    #[codama(account(
+        name = "claim_account",
+        writable,
+        docs = "PDA: [b\"merkle_claim\", distribution, claimant] (created or updated)"
+    ))]
+    #[codama(account(
+        name = "revocation_account",
+        docs = "PDA: [b\"merkle_revocation\", distribution, claimant] (checked for existence)"
+    ))]
+    #[codama(account(
+        name = "redirect_account",
+        docs = "PDA: [b\"merkle_redirect\", distribution, claimant]; its new_claimant owns the position if initialized"
+    ))]
+    #[codama(account(
+        name = "claim_delegate_account",
+        docs = "PDA: [b\"claim_delegate\", distribution, claimant] (may be uninitialized)"
+    ))]
+    #[codama(account(name = "mint", docs = "SPL token mint"))]
+    #[codama(account(
+        name = "distribution_vault",
+        writable,
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); source of claimed tokens"
+    ))]
+    #[codama(account(
+        name = "destination_token_account",
+        writable,
+        docs = "Claimant's ATA, or any token account when cranked by the claim delegate"
+    ))]
+    #[codama(account(name = "system_program", docs = "System program"))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    CrankClaimMerkle {
+        /// Bump for the claim PDA
+        claim_bump: u8,
+        /// Total amount allocated to claimant (from merkle leaf)
+        total_amount: u64,
+        /// Amount to claim (0 = claim all available)
+        amount: u64,
+        /// Whether `amount` is sent from the vault (Gross) or received by the destination (Net)
+        fee_mode: TransferFeeMode,
+        /// Vesting schedule (from merkle leaf)
+        schedule: VestingSchedule,
+        /// Merkle proof
+        proof: Vec<[u8; 32]>,
+    } = 20,
+
+    /// Allow or disallow recipient-initiated position transfers for a direct or merkle distribution.
+    #[codama(account(name = "authority", signer, docs = "Distribution authority; must match distribution.authority"))]
+    #[codama(account(
+        name = "distribution",
+        writable,
+        docs = "PDA: DirectDistribution or MerkleDistribution account"
+    ))]
+    SetTransferable {
+        /// 1 to allow transfers, 0 to disallow them
+        transferable: u8,
+    } = 21,
+
+    /// Move a direct recipient's position to a new wallet. The remaining allocation,
+    /// claimed amount and schedule are copied into a new DirectRecipient PDA and the old
+    /// one is closed. Requires the distribution to be transferable.
+    #[codama(account(name = "payer", signer, writable, docs = "Pays for the new recipient PDA"))]
+    #[codama(account(name = "recipient", signer, docs = "Current recipient; must match recipient_account.recipient"))]
+    #[codama(account(name = "new_recipient", docs = "Wallet receiving the position"))]
+    #[codama(account(name = "distribution", docs = "PDA: DirectDistribution account"))]
+    #[codama(account(
+        name = "recipient_account",
+        writable,
+        docs = "PDA: [b\"direct_recipient\", distribution, recipient] (closed)"
+    ))]
+    #[codama(account(
+        name = "new_recipient_account",
+        writable,
+        docs = "PDA: [b\"direct_recipient\", distribution, new_recipient] (created)"
+    ))]
+    #[codama(account(
+        name = "original_payer",
+        writable,
+        docs = "Receives rent from the closed recipient account; must match recipient_account.payer"
+    ))]
+    #[codama(account(name = "system_program", docs = "System program"))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    TransferDirectRecipient {
+        /// Bump for the new recipient PDA
+        bump: u8,
+    } = 22,
+
+    /// Move a merkle claimant's position to a new wallet. Leaves stay keyed by the original
+    /// claimant, so the new owner is recorded in a MerkleRedirect PDA and claims through
+    /// CrankClaimMerkle. The current owner may transfer again. Requires the distribution
+    /// to be transferable.
+    #[codama(account(name = "payer", signer, writable, docs = "Pays for the redirect PDA on the first transfer"))]
+    #[codama(account(
+        name = "owner",
+        signer,
+        docs = "Current owner: the claimant, or redirect_account.new_claimant after a transfer"
+    ))]
+    #[codama(account(name = "claimant", docs = "Original claimant named in the merkle leaf"))]
+    #[codama(account(name = "new_owner", docs = "Wallet receiving the position"))]
+    #[codama(account(name = "distribution", docs = "PDA: MerkleDistribution account"))]
+    #[codama(account(
+        name = "redirect_account",
+        writable,
+        docs = "PDA: [b\"merkle_redirect\", distribution, claimant] (created or updated)"
+    ))]
+    #[codama(account(
+        name = "revocation_account",
+        docs = "PDA: [b\"merkle_revocation\", distribution, claimant] (checked for existence)"
+    ))]
+    #[codama(account(name = "system_program", docs = "System program"))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    TransferMerkleClaim {} = 23,
+
+    /// Halt all claims from a direct or merkle distribution until UnpauseDistribution is called.
+    #[codama(account(name = "authority", signer, docs = "Distribution authority; must match distribution.authority"))]
+    #[codama(account(
+        name = "distribution",
+        writable,
+        docs = "PDA: DirectDistribution or MerkleDistribution account"
+    ))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    PauseDistribution {} = 24,
+
+    /// Resume claims from a paused distribution. With `shift_vesting` set, the paused
+    /// period is excluded from every vesting schedule in the distribution.
+    /// A claim window's `claim_end_ts` moves back by the same shift.
+    #[codama(account(name = "authority", signer, docs = "Distribution authority; must match distribution.authority"))]
+    #[codama(account(
+        name = "distribution",
+        writable,
+        docs = "PDA: DirectDistribution or MerkleDistribution account"
+    ))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    UnpauseDistribution {
+        /// 1 to push vesting back by the paused duration, 0 to let vesting catch up
+        shift_vesting: u8,
+    } = 25,
+
+    /// Claim all available tokens from several direct distributions of the same mint.
+    /// Remaining accounts are `[distribution (writable), recipient_account (writable), distribution_vault (writable)]`
+    /// triples. Each distribution must use `mint`. Paused distributions, distributions outside their claim
+    /// window and distributions with nothing claimable are skipped; at least one must pay out.
+    /// Emits one `Claimed` event per distribution claimed from.
+    #[codama(account(
+        name = "recipient",
+        signer,
+        docs = "Recipient wallet; must match every recipient_account.recipient"
+    ))]
+    #[codama(account(name = "mint", docs = "SPL token mint shared by every distribution"))]
+    #[codama(account(
+        name = "recipient_token_account",
+        writable,
+        docs = "Recipient's token account; destination for all claimed tokens"
+    ))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    ClaimDirectMany {} = 26,
+
+    /// Create a basket distribution paying up to 4 mints with one shared vesting schedule per recipient.
+    /// Remaining accounts are `[mint, distribution_vault (writable), token_program]` triples, one per mint.
+    /// Their order fixes the basket's mint slots; every later basket instruction passes mints in the same order.
+    #[codama(account(name = "payer", signer, writable, docs = "Pays for account creation"))]
+    #[codama(account(name = "authority", signer, docs = "Distribution authority; stored on-chain"))]
+    #[codama(account(name = "seeds", signer, docs = "Arbitrary signer used as PDA seed for uniqueness"))]
+    #[codama(account(
+        name = "distribution",
+        writable,
+        docs = "PDA: [b\"basket_distribution\", authority, seeds] (created)"
+    ))]
+    #[codama(account(name = "system_program", docs = "System program"))]
+    #[codama(account(name = "associated_token_program", docs = "Associated Token Account program"))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID (for event CPI)"))]
+    CreateBasketDistribution {
+        /// Bump for the distribution PDA
+        bump: u8,
+        /// Unix timestamp after which the authority may close the basket (0 = any time)
+        clawback_ts: i64,
+    } = 27,
+
+    /// Add a recipient to a basket distribution, depositing each mint's allocation into its vault.
+    /// Remaining accounts are `[mint, distribution_vault (writable), authority_token_account (writable), token_program]`
+    /// quads in basket slot order.
+    #[codama(account(name = "payer", signer, writable, docs = "Pays for recipient PDA creation"))]
+    #[codama(account(name = "authority", signer, docs = "Distribution authority; must match distribution.authority"))]
+    #[codama(account(name = "distribution", writable, docs = "PDA: BasketDistribution account"))]
+    #[codama(account(
+        name = "recipient_account",
+        writable,
+        docs = "PDA: [b\"basket_recipient\", distribution, recipient] (created)"
+    ))]
+    #[codama(account(name = "recipient", docs = "Recipient wallet"))]
+    #[codama(account(name = "system_program", docs = "System program"))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    AddBasketRecipient {
+        /// Bump for the recipient PDA
+        bump: u8,
+        /// Amount allocated per basket mint, in slot order; zero skips a mint
+        amounts: Vec<u64>,
+        /// Vesting schedule shared by every mint
+        schedule: VestingSchedule,
+    } = 28,
+
+    /// Claim every basket mint's vested tokens in one instruction.
+    /// Remaining accounts are `[mint, distribution_vault (writable), recipient_token_account (writable), token_program]`
+    /// quads in basket slot order. Mints with nothing claimable are skipped; at least one must pay out.
+    #[codama(account(name = "recipient", signer, docs = "Recipient wallet; must match recipient_account.recipient"))]
+    #[codama(account(name = "distribution", writable, docs = "PDA: BasketDistribution account"))]
+    #[codama(account(name = "recipient_account", writable, docs = "PDA: BasketRecipient account"))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    ClaimBasket {} = 29,
+
+    /// Close a basket distribution and recover every vault's remaining tokens.
+    /// Remaining accounts are `[mint, distribution_vault (writable), authority_token_account (writable), token_program]`
+    /// quads in basket slot order.
+    #[codama(account(
+        name = "authority",
+        signer,
+        writable,
+        docs = "Distribution authority; receives rent + remaining vault tokens"
+    ))]
+    #[codama(account(name = "distribution", writable, docs = "PDA: BasketDistribution account (closed)"))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    CloseBasketDistribution {} = 30,
+
+    /// Create one chunk of the claim bitmap for a Bitmap-mode merkle distribution (permissionless).
+    #[codama(account(name = "payer", signer, writable, docs = "Pays for the chunk; receives rent refund on close"))]
+    #[codama(account(name = "distribution", docs = "PDA: MerkleDistribution account in Bitmap claim mode"))]
+    #[codama(account(
+        name = "claim_bitmap",
+        writable,
+        docs = "PDA: [b\"merkle_claim_bitmap\", distribution, chunk_index (u32 LE)] (created)"
+    ))]
+    #[codama(account(name = "system_program", docs = "System program"))]
+    CreateMerkleClaimBitmap {
+        /// Chunk to create; chunk n tracks leaf indices n * 8192 .. (n + 1) * 8192
+        chunk_index: u32,
+    } = 31,
+
+    /// Claim a Bitmap-mode merkle leaf in full, marking its index in the claim bitmap.
+    #[codama(account(name = "claimant", signer, docs = "Wallet address of the claimant; proven via merkle proof"))]
+    #[codama(account(name = "distribution", writable, docs = "PDA: MerkleDistribution account in Bitmap claim mode"))]
+    #[codama(account(
+        name = "claim_bitmap",
+        writable,
+        docs = "PDA: [b\"merkle_claim_bitmap\", distribution, index / 8192 (u32 LE)]"
+    ))]
+    #[codama(account(name = "mint", docs = "SPL token mint"))]
+    #[codama(account(
+        name = "distribution_vault",
+        writable,
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); source of claimed tokens"
+    ))]
+    #[codama(account(
+        name = "claimant_token_account",
+        writable,
+        docs = "Claimant's token account; destination for claimed tokens"
+    ))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    ClaimMerkleBitmap {
+        /// Leaf index (from merkle leaf)
+        index: u32,
+        /// Amount allocated to claimant (from merkle leaf)
+        amount: u64,
+        /// Merkle proof
+        proof: Vec<[u8; 32]>,
+    } = 32,
+
+    /// Close a claim bitmap chunk after its distribution is closed.
+    #[codama(account(name = "original_payer", signer, writable, docs = "Payer of the chunk; receives rent refund"))]
+    #[codama(account(
+        name = "distribution",
+        docs = "PDA: MerkleDistribution account; must be closed (owner = system program)"
+    ))]
+    #[codama(account(name = "claim_bitmap", writable, docs = "PDA: MerkleClaimBitmap account (closed)"))]
+    CloseMerkleClaimBitmap {} = 33,
+
+    /// Create a compressed distribution whose recipients are leaves of an on-chain concurrent merkle tree.
+    #[codama(account(name = "payer", signer, writable, docs = "Pays for account creation"))]
+    #[codama(account(name = "authority", signer, docs = "Distribution authority; stored on-chain"))]
+    #[codama(account(name = "seeds", signer, docs = "Arbitrary signer used as PDA seed for uniqueness"))]
+    #[codama(account(
+        name = "distribution",
+        writable,
+        docs = "PDA: [b\"compressed_distribution\", mint, authority, seeds] (created with the recipient tree)"
+    ))]
+    #[codama(account(name = "mint", docs = "SPL token mint, or the System program ID for native SOL"))]
+    #[codama(account(
+        name = "distribution_vault",
+        writable,
+        docs = "ATA of distribution PDA for mint, or PDA: [b\"native_vault\", distribution] for native SOL (created via CPI)"
+    ))]
+    #[codama(account(name = "system_program", docs = "System program"))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
+    #[codama(account(name = "associated_token_program", docs = "Associated Token Account program"))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID (for event CPI)"))]
+    CreateCompressedDistribution {
+        /// Bump for the distribution PDA
+        bump: u8,
+        /// Timestamp after which authority can close the distribution (0 = no gate)
+        clawback_ts: i64,
+    } = 34,
+
+    /// Append a recipient leaf to a compressed distribution's tree.
+    /// Transfers the recipient's allocation amount into the distribution vault.
+    #[codama(account(name = "authority", signer, docs = "Distribution authority; must match distribution.authority"))]
+    #[codama(account(name = "distribution", writable, docs = "PDA: CompressedDistribution account"))]
+    #[codama(account(name = "mint", docs = "SPL token mint; must match distribution.mint"))]
+    #[codama(account(
+        name = "distribution_vault",
+        writable,
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); receives transferred tokens"
+    ))]
+    #[codama(account(
+        name = "authority_token_account",
+        writable,
+        docs = "Authority's token account; source of tokens for this recipient's allocation"
+    ))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    AddCompressedRecipient {
+        /// Wallet address of the recipient
+        recipient: Address,
+        /// Amount allocated to the recipient
+        amount: u64,
+        /// Vesting schedule
+        schedule: VestingSchedule,
+    } = 35,
+
+    /// Claim vested tokens from a compressed distribution, updating the recipient's leaf.
+    #[codama(account(name = "recipient", signer, docs = "Wallet address of the recipient; proven via the leaf"))]
+    #[codama(account(name = "distribution", writable, docs = "PDA: CompressedDistribution account"))]
+    #[codama(account(name = "mint", docs = "SPL token mint"))]
+    #[codama(account(
+        name = "distribution_vault",
+        writable,
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); source of claimed tokens"
+    ))]
+    #[codama(account(
+        name = "recipient_token_account",
+        writable,
+        docs = "Recipient's token account; destination for claimed tokens"
+    ))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    ClaimCompressed {
+        /// Tree root the proof was built against (any of the recent roots)
+        root: [u8; 32],
+        /// Leaf index of the recipient
+        index: u32,
+        /// Total amount allocated to the recipient (from leaf)
+        total_amount: u64,
+        /// Amount already claimed (from leaf)
+        claimed_amount: u64,
+        /// Distribution vesting offset when the leaf was appended (from leaf)
+        base_vesting_offset: i64,
+        /// Amount to claim. 0 = claim all available.
+        amount: u64,
+        /// Whether `amount` is sent from the vault (Gross) or received by the recipient (Net)
+        fee_mode: TransferFeeMode,
+        /// Vesting schedule (from leaf)
+        schedule: VestingSchedule,
+        /// Merkle proof from leaf to root
+        proof: Vec<[u8; 32]>,
+    } = 36,
+
+    /// Close a compressed distribution and recover remaining tokens.
+    #[codama(account(
+        name = "authority",
+        signer,
+        writable,
+        docs = "Distribution authority; receives rent + remaining distribution vault tokens"
+    ))]
+    #[codama(account(name = "distribution", writable, docs = "PDA: CompressedDistribution account (closed)"))]
+    #[codama(account(name = "mint", docs = "SPL token mint"))]
+    #[codama(account(
+        name = "distribution_vault",
+        writable,
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); remaining tokens returned to authority"
+    ))]
+    #[codama(account(
+        name = "authority_token_account",
+        writable,
+        docs = "Authority's token account; destination for remaining tokens"
+    ))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    CloseCompressedDistribution {} = 37,
+
+    /// Rewrite a program account at its current layout version (permissionless).
+    /// Grows the account to the current size; fields the stored version predates get their defaults.
+    #[codama(account(name = "payer", signer, writable, docs = "Pays any additional rent for the larger account"))]
+    #[codama(account(
+        name = "account",
+        writable,
+        docs = "DirectDistribution, MerkleDistribution, DirectRecipient or MerkleClaim stored at an older version"
+    ))]
+    #[codama(account(name = "system_program", docs = "System program"))]
+    MigrateAccount {} = 38,
+
+    /// Add tokens to an existing direct recipient's allocation.
+    /// Without a schedule the extra tokens follow the current curve; with one, the whole allocation
+    /// moves to the new schedule, including previously allocated tokens that have not vested yet.
+    /// Only revocable distributions may reschedule, and the new curve must unlock at least as much
+    /// as the old one at every future time.
+    /// Frozen recipients cannot be increased.
+    #[codama(account(
+        name = "payer",
+        signer,
+        writable,
+        docs = "Pays any additional rent if the recipient account grows"
+    ))]
+    #[codama(account(name = "authority", signer, docs = "Distribution authority; must match distribution.authority"))]
+    #[codama(account(name = "distribution", writable, docs = "PDA: DirectDistribution account"))]
+    #[codama(account(
+        name = "recipient_account",
+        writable,
+        docs = "PDA: [b\"direct_recipient\", distribution, recipient]"
+    ))]
+    #[codama(account(name = "recipient", docs = "Wallet address of the recipient"))]
+    #[codama(account(name = "mint", docs = "SPL token mint"))]
+    #[codama(account(
+        name = "distribution_vault",
+        writable,
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); receives the added tokens"
+    ))]
+    #[codama(account(
+        name = "authority_token_account",
+        writable,
+        docs = "Authority's token account; source of the added tokens"
+    ))]
+    #[codama(account(name = "system_program", docs = "System program"))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    IncreaseDirectAllocation {
+        /// Amount added to the allocation
+        amount: u64,
+        /// New schedule for the whole allocation, existing tokens included (None = keep the current schedule)
+        schedule: Option<VestingSchedule>,
+    } = 39,
+
+    /// Return part of a direct recipient's unvested allocation to the authority.
+    /// Requires the distribution to allow NonVested revocation. Vesting keeps its curve and
+    /// completes early at the reduced total, so nothing already vested is taken back.
+    #[codama(account(name = "authority", signer, docs = "Distribution authority; must match distribution.authority"))]
+    #[codama(account(name = "distribution", writable, docs = "PDA: DirectDistribution account"))]
+    #[codama(account(
+        name = "recipient_account",
+        writable,
+        docs = "PDA: [b\"direct_recipient\", distribution, recipient]"
+    ))]
+    #[codama(account(name = "recipient", docs = "Wallet address of the recipient"))]
+    #[codama(account(name = "mint", docs = "SPL token mint"))]
+    #[codama(account(
+        name = "distribution_vault",
+        writable,
+        docs = "ATA of distribution PDA for mint (or native SOL vault PDA); source of returned tokens"
+    ))]
+    #[codama(account(
+        name = "authority_token_account",
+        writable,
+        docs = "Authority's token account; destination for returned tokens"
+    ))]
+    #[codama(account(
+        name = "token_program",
+        docs = "SPL Token or Token-2022 program; System program for native SOL"
+    ))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    DecreaseDirectAllocation {
+        /// Unvested amount removed from the allocation
+        amount: u64,
+    } = 40,
+
+    /// Close a basket recipient after its basket distribution is closed.
+    #[codama(account(name = "recipient", signer, docs = "Wallet address of the recipient"))]
+    #[codama(account(
+        name = "original_payer",
+        writable,
+        docs = "Original payer of recipient PDA; receives rent refund"
+    ))]
+    #[codama(account(
+        name = "distribution",
+        docs = "PDA: BasketDistribution account; must be closed (owner = system program)"
+    ))]
+    #[codama(account(
+        name = "recipient_account",
+        writable,
+        docs = "PDA: [b\"basket_recipient\", distribution, recipient] (closed)"
+    ))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    CloseBasketRecipient {} = 41,
+
+    /// Close a fully claimed merkle claim while its distribution is still open.
+    /// The claim stays as a closed record that keeps its claimed amount, so the leaf cannot be
+    /// claimed again. The original payer gets all of its rent back and the claimant pays the
+    /// record's rent instead. A later claim reopens it.
+    #[codama(account(
+        name = "claimant",
+        signer,
+        writable,
+        docs = "Wallet address of the claimant; pays the closed record's rent"
+    ))]
+    #[codama(account(
+        name = "original_payer",
+        writable,
+        docs = "Original payer of claim PDA; receives its full rent"
+    ))]
+    #[codama(account(name = "distribution", docs = "PDA: MerkleDistribution account"))]
+    #[codama(account(
+        name = "claim_account",
+        writable,
+        docs = "PDA: [b\"merkle_claim\", distribution, claimant] (kept as a closed record)"
+    ))]
+    #[codama(account(name = "system_program", docs = "System program"))]
+    #[codama(account(name = "event_authority", docs = "PDA: [b\"__event_authority\"] for event CPI"))]
+    #[codama(account(name = "rewardsProgram", docs = "This program's ID"))]
+    CloseMerkleClaimEarly {
+        /// Total amount allocated to this claimant (from merkle leaf)
+        total_amount: u64,
+        /// Vesting schedule (from merkle leaf)
+        schedule: VestingSchedule,
+        /// Merkle proof
+        proof: Vec<[u8; 32]>,
+    } = 42,
+
     /// Emit event data via CPI (prevents log truncation).
     #[codama(account(name = "event_authority", signer, docs = "PDA: [b\"__event_authority\"]; validates CPI caller"))]
     EmitEvent {} = 228,
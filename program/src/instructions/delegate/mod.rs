@@ -0,0 +1,2 @@
+pub mod remove_claim_delegate;
+pub mod set_claim_delegate;
@@ -0,0 +1,49 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_readonly, verify_signer,
+        verify_writable,
+    },
+};
+
+pub struct RemoveClaimDelegateAccounts<'a> {
+    pub recipient: &'a AccountView,
+    pub distribution: &'a AccountView,
+    pub claim_delegate_account: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for RemoveClaimDelegateAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [recipient, distribution, claim_delegate_account, event_authority, program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(recipient, true)?;
+
+        // 2. Validate writable
+        verify_writable(claim_delegate_account, true)?;
+
+        // 2b. Validate read-only accounts
+        verify_readonly(distribution)?;
+
+        // 3. Validate program IDs
+        verify_current_program(program)?;
+        verify_event_authority(event_authority)?;
+
+        // 4. Validate accounts owned by current program
+        // Note: distribution may already be closed; the delegate PDA seeds bind it to the distribution
+        verify_current_program_account(claim_delegate_account)?;
+
+        Ok(Self { recipient, distribution, claim_delegate_account, event_authority, program })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for RemoveClaimDelegateAccounts<'a> {}
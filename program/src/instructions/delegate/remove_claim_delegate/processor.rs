@@ -0,0 +1,42 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    events::ClaimDelegateUpdatedEvent,
+    state::ClaimDelegate,
+    traits::EventSerialize,
+    utils::{close_pda_account, emit_event},
+    ID,
+};
+
+use super::RemoveClaimDelegate;
+
+pub fn process_remove_claim_delegate(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = RemoveClaimDelegate::try_from((instruction_data, accounts))?;
+
+    let delegate_data = ix.accounts.claim_delegate_account.try_borrow()?;
+    let _claim_delegate = ClaimDelegate::from_account(
+        &delegate_data,
+        ix.accounts.claim_delegate_account,
+        &ID,
+        ix.accounts.distribution.address(),
+        ix.accounts.recipient.address(),
+    )?;
+    drop(delegate_data);
+
+    // Close the delegate account and return rent to recipient
+    close_pda_account(ix.accounts.claim_delegate_account, ix.accounts.recipient)?;
+
+    // A removed delegate is reported as the default address
+    let event = ClaimDelegateUpdatedEvent::new(
+        *ix.accounts.distribution.address(),
+        *ix.accounts.recipient.address(),
+        Address::default(),
+    );
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_readonly, verify_signer,
+        verify_system_program, verify_writable,
+    },
+};
+
+pub struct SetClaimDelegateAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub recipient: &'a AccountView,
+    pub distribution: &'a AccountView,
+    pub claim_delegate_account: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for SetClaimDelegateAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, recipient, distribution, claim_delegate_account, system_program, event_authority, program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(recipient, false)?;
+
+        // 2. Validate writable
+        verify_writable(claim_delegate_account, true)?;
+
+        // 2b. Validate read-only accounts
+        verify_readonly(distribution)?;
+
+        // 3. Validate program IDs
+        verify_system_program(system_program)?;
+        verify_current_program(program)?;
+        verify_event_authority(event_authority)?;
+
+        // 4. Validate accounts owned by current program
+        verify_current_program_account(distribution)?;
+        // claim_delegate_account is created on first use
+
+        Ok(Self { payer, recipient, distribution, claim_delegate_account, system_program, event_authority, program })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for SetClaimDelegateAccounts<'a> {}
@@ -0,0 +1,68 @@
+use pinocchio::{error::ProgramError, Address};
+
+use crate::{errors::RewardsProgramError, require_len, traits::InstructionData};
+
+/// Instruction data for SetClaimDelegate
+///
+/// - `delegate`: The address allowed to claim on the recipient's behalf.
+pub struct SetClaimDelegateData {
+    pub delegate: Address,
+}
+
+impl<'a> TryFrom<&'a [u8]> for SetClaimDelegateData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let delegate =
+            Address::new_from_array(data[..32].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        Ok(Self { delegate })
+    }
+}
+
+impl<'a> InstructionData<'a> for SetClaimDelegateData {
+    const LEN: usize = 32;
+
+    fn validate(&self) -> Result<(), ProgramError> {
+        // The default address marks "no delegate"; use RemoveClaimDelegate instead
+        if self.delegate == Address::default() {
+            return Err(RewardsProgramError::InvalidClaimDelegate.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_valid_data() {
+        let data = [7u8; 32];
+        let result = SetClaimDelegateData::try_from(&data[..]).unwrap();
+        assert_eq!(result.delegate, Address::new_from_array([7u8; 32]));
+    }
+
+    #[test]
+    fn test_try_from_insufficient_data() {
+        let data = [1u8; 31];
+        let result = SetClaimDelegateData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_validate_rejects_default_address() {
+        let data = [0u8; 32];
+        let result = SetClaimDelegateData::try_from(&data[..]).unwrap();
+        assert_eq!(result.validate(), Err(RewardsProgramError::InvalidClaimDelegate.into()));
+    }
+
+    #[test]
+    fn test_validate_accepts_non_default_address() {
+        let data = [7u8; 32];
+        let result = SetClaimDelegateData::try_from(&data[..]).unwrap();
+        assert!(result.validate().is_ok());
+    }
+}
@@ -0,0 +1,64 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    events::ClaimDelegateUpdatedEvent,
+    state::{ClaimDelegate, ClaimDelegateSeeds},
+    traits::{AccountSerialize, AccountSize, EventSerialize, InstructionData, PdaSeeds},
+    utils::{create_pda_account, emit_event, get_distribution_kind, is_pda_uninitialized},
+    ID,
+};
+
+use super::SetClaimDelegate;
+
+pub fn process_set_claim_delegate(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = SetClaimDelegate::try_from((instruction_data, accounts))?;
+    ix.data.validate()?;
+
+    // Delegates apply to both distribution types; reject anything else owned by this program
+    get_distribution_kind(ix.accounts.distribution)?;
+
+    if &ix.data.delegate == ix.accounts.recipient.address() {
+        return Err(RewardsProgramError::InvalidClaimDelegate.into());
+    }
+
+    let delegate_seeds = ClaimDelegateSeeds {
+        distribution: *ix.accounts.distribution.address(),
+        recipient: *ix.accounts.recipient.address(),
+    };
+    let delegate_bump = delegate_seeds.validate_pda_address(ix.accounts.claim_delegate_account, &ID)?;
+
+    if is_pda_uninitialized(ix.accounts.claim_delegate_account) {
+        let delegate_bump_seed = [delegate_bump];
+        let delegate_pda_seeds = delegate_seeds.seeds_with_bump(&delegate_bump_seed);
+        let delegate_pda_seeds_array: [_; 4] =
+            delegate_pda_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+        create_pda_account(
+            ix.accounts.payer,
+            ClaimDelegate::LEN,
+            &ID,
+            ix.accounts.claim_delegate_account,
+            delegate_pda_seeds_array,
+        )?;
+    }
+
+    // Overwrites any previous delegate
+    let claim_delegate = ClaimDelegate::new(delegate_bump, ix.data.delegate);
+    let mut delegate_data = ix.accounts.claim_delegate_account.try_borrow_mut()?;
+    claim_delegate.write_to_slice(&mut delegate_data)?;
+    drop(delegate_data);
+
+    let event = ClaimDelegateUpdatedEvent::new(
+        *ix.accounts.distribution.address(),
+        *ix.accounts.recipient.address(),
+        ix.data.delegate,
+    );
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -3,9 +3,9 @@ use pinocchio::{account::AccountView, error::ProgramError};
 use crate::{
     traits::InstructionAccounts,
     utils::{
-        validate_associated_token_account, verify_current_program, verify_current_program_account,
-        verify_event_authority, verify_owned_by, verify_readonly, verify_signer, verify_system_program,
-        verify_token_program, verify_writable,
+        validate_distribution_vault, verify_current_program, verify_current_program_account, verify_event_authority,
+        verify_mint_owner, verify_owned_by, verify_readonly, verify_signer, verify_system_program,
+        verify_token_program_for_mint, verify_writable,
     },
 };
 
@@ -51,7 +51,7 @@ impl<'a> TryFrom<&'a [AccountView]> for AddDirectRecipientAccounts<'a> {
 
         // 3. Validate program IDs
         verify_system_program(system_program)?;
-        verify_token_program(token_program)?;
+        verify_token_program_for_mint(token_program, mint)?;
         verify_current_program(program)?;
         verify_event_authority(event_authority)?;
 
@@ -59,11 +59,11 @@ impl<'a> TryFrom<&'a [AccountView]> for AddDirectRecipientAccounts<'a> {
         verify_current_program_account(distribution)?;
 
         // 5. Validate token account ownership
-        verify_owned_by(mint, token_program.address())?;
+        verify_mint_owner(mint, token_program)?;
         verify_owned_by(authority_token_account, token_program.address())?;
 
-        // 6. Validate distribution_vault ATA
-        validate_associated_token_account(distribution_vault, distribution.address(), mint, token_program)?;
+        // 6. Validate distribution_vault (ATA, or native vault PDA)
+        validate_distribution_vault(distribution_vault, distribution.address(), mint, token_program)?;
 
         Ok(Self {
             payer,
@@ -1,12 +1,14 @@
 use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
-use pinocchio_token_2022::instructions::TransferChecked;
 
 use crate::{
     errors::RewardsProgramError,
     events::RecipientAddedEvent,
     state::{DirectDistribution, DirectRecipient},
     traits::{AccountSerialize, Distribution, EventSerialize, InstructionData, PdaSeeds},
-    utils::{create_pda_account, emit_event, get_mint_decimals},
+    utils::{
+        create_pda_account, deposit_to_distribution_vault, emit_event, save_account, VaultDepositContext,
+        VestingSchedule,
+    },
     ID,
 };
 
@@ -25,62 +27,84 @@ pub fn process_add_direct_recipient(
     drop(distribution_data);
 
     Distribution::validate_authority(&distribution, ix.accounts.authority.address())?;
-
-    let new_total_allocated =
-        distribution.total_allocated.checked_add(ix.data.amount).ok_or(RewardsProgramError::MathOverflow)?;
-
-    let recipient = DirectRecipient::new(
-        ix.data.bump,
-        *ix.accounts.distribution.address(),
-        *ix.accounts.recipient.address(),
-        *ix.accounts.payer.address(),
+    distribution.validate_not_settled()?;
+    distribution.validate_vesting_end(ix.data.schedule.end_ts()?, distribution.vesting_offset)?;
+
+    // Allocate what actually reached the vault so Token-2022 transfer fees never leave it short
+    let received = deposit_to_distribution_vault(
+        &VaultDepositContext {
+            source: ix.accounts.authority_token_account,
+            authority: ix.accounts.authority,
+            distribution_vault: ix.accounts.distribution_vault,
+            mint: ix.accounts.mint,
+            token_program: ix.accounts.token_program,
+        },
         ix.data.amount,
-        ix.data.schedule,
-    );
-
-    recipient.validate_pda(ix.accounts.recipient_account, &ID, ix.data.bump)?;
+    )?;
+    if received == 0 {
+        return Err(RewardsProgramError::InvalidAmount.into());
+    }
 
-    let bump_seed = [ix.data.bump];
-    let recipient_seeds = recipient.seeds_with_bump(&bump_seed);
-    let recipient_seeds_array: [_; 4] = recipient_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+    distribution.total_allocated =
+        distribution.total_allocated.checked_add(received).ok_or(RewardsProgramError::MathOverflow)?;
+    distribution.add_funded(received)?;
 
-    create_pda_account(
+    create_direct_recipient(
         ix.accounts.payer,
-        DirectRecipient::calculate_account_size(&ix.data.schedule),
-        &ID,
+        ix.accounts.distribution,
         ix.accounts.recipient_account,
-        recipient_seeds_array,
+        ix.accounts.recipient,
+        ix.data.bump,
+        received,
+        ix.data.schedule.clone(),
+        distribution.vesting_offset,
     )?;
 
-    let mut recipient_data = ix.accounts.recipient_account.try_borrow_mut()?;
-    recipient.write_to_slice(&mut recipient_data)?;
-    drop(recipient_data);
-
-    distribution.total_allocated = new_total_allocated;
-    let mut distribution_data = ix.accounts.distribution.try_borrow_mut()?;
-    distribution.write_to_slice(&mut distribution_data)?;
-    drop(distribution_data);
-
-    let decimals = get_mint_decimals(ix.accounts.mint)?;
-
-    TransferChecked {
-        from: ix.accounts.authority_token_account,
-        mint: ix.accounts.mint,
-        to: ix.accounts.distribution_vault,
-        authority: ix.accounts.authority,
-        amount: ix.data.amount,
-        decimals,
-        token_program: ix.accounts.token_program.address(),
-    }
-    .invoke()?;
+    save_account(ix.accounts.distribution, &distribution, Some(ix.accounts.payer))?;
 
     let event = RecipientAddedEvent::new(
         *ix.accounts.distribution.address(),
         *ix.accounts.recipient.address(),
-        ix.data.amount,
+        received,
         ix.data.schedule,
     );
     emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
 
     Ok(())
 }
+
+/// Creates and initializes a DirectRecipient PDA.
+///
+/// Shared by AddDirectRecipient and AddDirectRecipients; the caller handles
+/// distribution totals, the token transfer and the event. `base_vesting_offset`
+/// is the distribution's current vesting offset, so earlier pauses never shift
+/// the new schedule.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn create_direct_recipient(
+    payer: &AccountView,
+    distribution: &AccountView,
+    recipient_account: &AccountView,
+    recipient: &AccountView,
+    bump: u8,
+    amount: u64,
+    schedule: VestingSchedule,
+    base_vesting_offset: i64,
+) -> ProgramResult {
+    let account_size = DirectRecipient::calculate_account_size(&schedule);
+    let mut direct_recipient =
+        DirectRecipient::new(bump, *distribution.address(), *recipient.address(), *payer.address(), amount, schedule);
+    direct_recipient.base_vesting_offset = base_vesting_offset;
+
+    direct_recipient.validate_pda(recipient_account, &ID, bump)?;
+
+    let bump_seed = [bump];
+    let recipient_seeds = direct_recipient.seeds_with_bump(&bump_seed);
+    let recipient_seeds_array: [_; 4] = recipient_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    create_pda_account(payer, account_size, &ID, recipient_account, recipient_seeds_array)?;
+
+    let mut recipient_data = recipient_account.try_borrow_mut()?;
+    direct_recipient.write_to_slice(&mut recipient_data)?;
+
+    Ok(())
+}
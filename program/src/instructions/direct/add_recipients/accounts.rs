@@ -0,0 +1,101 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    errors::RewardsProgramError,
+    traits::InstructionAccounts,
+    utils::{
+        validate_distribution_vault, verify_current_program, verify_current_program_account, verify_event_authority,
+        verify_mint_owner, verify_owned_by, verify_readonly, verify_signer, verify_system_program,
+        verify_token_program_for_mint, verify_writable,
+    },
+};
+
+pub struct AddDirectRecipientsAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub authority: &'a AccountView,
+    pub distribution: &'a AccountView,
+    pub mint: &'a AccountView,
+    pub distribution_vault: &'a AccountView,
+    pub authority_token_account: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub token_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub program: &'a AccountView,
+    /// Remaining accounts as `[recipient_account, recipient]` pairs, one pair per entry
+    pub recipients: &'a [AccountView],
+}
+
+impl<'a> AddDirectRecipientsAccounts<'a> {
+    /// Number of `[recipient_account, recipient]` pairs
+    #[inline(always)]
+    pub fn recipient_count(&self) -> usize {
+        self.recipients.len() / 2
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for AddDirectRecipientsAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, authority, distribution, mint, distribution_vault, authority_token_account, system_program, token_program, event_authority, program, recipients @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if recipients.is_empty() || recipients.len() % 2 != 0 {
+            return Err(RewardsProgramError::InvalidRecipientCount.into());
+        }
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(authority, false)?;
+
+        // 2. Validate writable
+        verify_writable(distribution, true)?;
+        verify_writable(distribution_vault, true)?;
+        verify_writable(authority_token_account, true)?;
+        for pair in recipients.chunks_exact(2) {
+            verify_writable(&pair[0], true)?;
+        }
+
+        // 2b. Validate read-only accounts
+        verify_readonly(mint)?;
+        for pair in recipients.chunks_exact(2) {
+            verify_readonly(&pair[1])?;
+        }
+
+        // 3. Validate program IDs
+        verify_system_program(system_program)?;
+        verify_token_program_for_mint(token_program, mint)?;
+        verify_current_program(program)?;
+        verify_event_authority(event_authority)?;
+
+        // 4. Validate accounts owned by current program
+        verify_current_program_account(distribution)?;
+
+        // 5. Validate token account ownership
+        verify_mint_owner(mint, token_program)?;
+        verify_owned_by(authority_token_account, token_program.address())?;
+
+        // 6. Validate distribution_vault (ATA, or native vault PDA)
+        validate_distribution_vault(distribution_vault, distribution.address(), mint, token_program)?;
+
+        Ok(Self {
+            payer,
+            authority,
+            distribution,
+            mint,
+            distribution_vault,
+            authority_token_account,
+            system_program,
+            token_program,
+            event_authority,
+            program,
+            recipients,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for AddDirectRecipientsAccounts<'a> {}
@@ -0,0 +1,203 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::error::ProgramError;
+
+use crate::{errors::RewardsProgramError, require_len, traits::InstructionData, utils::VestingSchedule};
+
+/// A single recipient allocation within an AddDirectRecipients batch.
+#[derive(Clone, Debug, PartialEq, Eq, CodamaType)]
+pub struct DirectRecipientEntry {
+    /// Bump for the recipient PDA
+    pub bump: u8,
+    /// Token amount allocated to this recipient
+    pub amount: u64,
+    /// Vesting schedule for this recipient's allocation
+    pub schedule: VestingSchedule,
+}
+
+/// Instruction data for AddDirectRecipients.
+///
+/// A u32 entry count followed by the entries. Each entry is variable-length
+/// due to the VestingSchedule enum.
+pub struct AddDirectRecipientsData {
+    pub recipients: Vec<DirectRecipientEntry>,
+}
+
+impl AddDirectRecipientsData {
+    /// bump(1) + amount(8) + min schedule(1)
+    pub const MIN_ENTRY_LEN: usize = 10;
+
+    /// Sum of all entry amounts
+    #[inline(always)]
+    pub fn total_amount(&self) -> Result<u64, ProgramError> {
+        self.recipients.iter().try_fold(0u64, |total, entry| {
+            total.checked_add(entry.amount).ok_or(RewardsProgramError::MathOverflow.into())
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for AddDirectRecipientsData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let count =
+            u32::from_le_bytes(data[0..4].try_into().map_err(|_| ProgramError::InvalidInstructionData)?) as usize;
+
+        // Bound the count by the remaining bytes before allocating
+        if count > (data.len() - Self::LEN) / Self::MIN_ENTRY_LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mut recipients = Vec::with_capacity(count);
+        let mut offset = 4;
+        for _ in 0..count {
+            require_len!(data, offset + Self::MIN_ENTRY_LEN);
+
+            let bump = data[offset];
+            let amount = u64::from_le_bytes(
+                data[offset + 1..offset + 9].try_into().map_err(|_| ProgramError::InvalidInstructionData)?,
+            );
+            let (schedule, schedule_len) = VestingSchedule::from_bytes(&data[offset + 9..])?;
+
+            recipients.push(DirectRecipientEntry { bump, amount, schedule });
+            offset += 9 + schedule_len;
+        }
+
+        // Every byte must belong to a declared entry
+        if offset != data.len() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { recipients })
+    }
+}
+
+impl<'a> InstructionData<'a> for AddDirectRecipientsData {
+    const LEN: usize = 4; // entry count
+
+    fn validate(&self) -> Result<(), ProgramError> {
+        if self.recipients.is_empty() {
+            return Err(RewardsProgramError::InvalidRecipientCount.into());
+        }
+        for entry in &self.recipients {
+            if entry.amount == 0 {
+                return Err(RewardsProgramError::InvalidAmount.into());
+            }
+            entry.schedule.validate()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push_entry(data: &mut Vec<u8>, bump: u8, amount: u64, schedule: VestingSchedule) {
+        data.push(bump);
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.extend_from_slice(&schedule.to_bytes());
+    }
+
+    fn create_data(entries: &[(u8, u64, VestingSchedule)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+        for (bump, amount, schedule) in entries {
+            push_entry(&mut data, *bump, *amount, schedule.clone());
+        }
+        data
+    }
+
+    #[test]
+    fn test_try_from_mixed_schedules() {
+        let linear = VestingSchedule::Linear { start_ts: 100, end_ts: 200 };
+        let data = create_data(&[(255, 1000, linear.clone()), (254, 500, VestingSchedule::Immediate {})]);
+        let parsed = AddDirectRecipientsData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.recipients.len(), 2);
+        assert_eq!(parsed.recipients[0], DirectRecipientEntry { bump: 255, amount: 1000, schedule: linear });
+        assert_eq!(
+            parsed.recipients[1],
+            DirectRecipientEntry { bump: 254, amount: 500, schedule: VestingSchedule::Immediate {} }
+        );
+    }
+
+    #[test]
+    fn test_try_from_data_too_short() {
+        let data = [0u8; 3];
+        let result = AddDirectRecipientsData::try_from(&data[..]);
+        assert_eq!(result.err(), Some(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_try_from_truncated_entry() {
+        let mut data = create_data(&[(1, 1000, VestingSchedule::Linear { start_ts: 100, end_ts: 200 })]);
+        data.truncate(data.len() - 1);
+        assert!(AddDirectRecipientsData::try_from(&data[..]).is_err());
+    }
+
+    #[test]
+    fn test_try_from_count_exceeds_entries() {
+        let mut data = create_data(&[(1, 1000, VestingSchedule::Immediate {})]);
+        data[0..4].copy_from_slice(&2u32.to_le_bytes());
+        assert_eq!(AddDirectRecipientsData::try_from(&data[..]).err(), Some(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_try_from_trailing_bytes() {
+        let mut data = create_data(&[(1, 1000, VestingSchedule::Immediate {})]);
+        data.push(0);
+        assert_eq!(AddDirectRecipientsData::try_from(&data[..]).err(), Some(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_try_from_oversized_count() {
+        let mut data = create_data(&[(1, 1000, VestingSchedule::Immediate {})]);
+        data[0..4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(AddDirectRecipientsData::try_from(&data[..]).err(), Some(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_total_amount() {
+        let data = create_data(&[(1, 1000, VestingSchedule::Immediate {}), (2, 2500, VestingSchedule::Immediate {})]);
+        let parsed = AddDirectRecipientsData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.total_amount().unwrap(), 3500);
+    }
+
+    #[test]
+    fn test_total_amount_overflow() {
+        let data = create_data(&[(1, u64::MAX, VestingSchedule::Immediate {}), (2, 1, VestingSchedule::Immediate {})]);
+        let parsed = AddDirectRecipientsData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.total_amount().err(), Some(RewardsProgramError::MathOverflow.into()));
+    }
+
+    #[test]
+    fn test_validate_success() {
+        let data = create_data(&[(1, 1000, VestingSchedule::Linear { start_ts: 100, end_ts: 200 })]);
+        let parsed = AddDirectRecipientsData::try_from(&data[..]).unwrap();
+        assert!(parsed.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_empty() {
+        let data = create_data(&[]);
+        let parsed = AddDirectRecipientsData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.validate().err(), Some(RewardsProgramError::InvalidRecipientCount.into()));
+    }
+
+    #[test]
+    fn test_validate_zero_amount() {
+        let data = create_data(&[(1, 1000, VestingSchedule::Immediate {}), (2, 0, VestingSchedule::Immediate {})]);
+        let parsed = AddDirectRecipientsData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.validate().err(), Some(RewardsProgramError::InvalidAmount.into()));
+    }
+
+    #[test]
+    fn test_validate_invalid_time_window() {
+        let data = create_data(&[(1, 1000, VestingSchedule::Linear { start_ts: 200, end_ts: 100 })]);
+        let parsed = AddDirectRecipientsData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.validate().err(), Some(RewardsProgramError::InvalidTimeWindow.into()));
+    }
+}
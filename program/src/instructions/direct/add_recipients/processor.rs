@@ -0,0 +1,112 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    events::RecipientAddedEvent,
+    instructions::direct::add_recipient::create_direct_recipient,
+    state::DirectDistribution,
+    traits::{Distribution, EventSerialize, InstructionData},
+    utils::{
+        deposit_to_distribution_vault, emit_event, get_current_epoch, get_transfer_fee, save_account,
+        VaultDepositContext,
+    },
+    ID,
+};
+
+use super::AddDirectRecipients;
+
+pub fn process_add_direct_recipients(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = AddDirectRecipients::try_from((instruction_data, accounts))?;
+    ix.data.validate()?;
+
+    if ix.accounts.recipient_count() != ix.data.recipients.len() {
+        return Err(RewardsProgramError::InvalidRecipientCount.into());
+    }
+
+    let distribution_data = ix.accounts.distribution.try_borrow()?;
+    let mut distribution = DirectDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    drop(distribution_data);
+
+    Distribution::validate_authority(&distribution, ix.accounts.authority.address())?;
+    distribution.validate_not_settled()?;
+    for entry in &ix.data.recipients {
+        distribution.validate_vesting_end(entry.schedule.end_ts()?, distribution.vesting_offset)?;
+    }
+
+    let batch_amount = ix.data.total_amount()?;
+    // One transfer covers every allocation in the batch
+    let received = deposit_to_distribution_vault(
+        &VaultDepositContext {
+            source: ix.accounts.authority_token_account,
+            authority: ix.accounts.authority,
+            distribution_vault: ix.accounts.distribution_vault,
+            mint: ix.accounts.mint,
+            token_program: ix.accounts.token_program,
+        },
+        batch_amount,
+    )?;
+
+    // Each allocation is its amount net of the transfer fee it would have paid on its own.
+    // The fee on one aggregated transfer is never larger than the sum of per-entry fees,
+    // so the allocations always fit within what the vault received.
+    let transfer_fee = get_transfer_fee(ix.accounts.mint, get_current_epoch()?)?;
+    let mut net_amounts = Vec::with_capacity(ix.data.recipients.len());
+    for entry in &ix.data.recipients {
+        let net_amount = match transfer_fee {
+            Some(fee) => fee.calculate_post_fee_amount(entry.amount).ok_or(RewardsProgramError::MathOverflow)?,
+            None => entry.amount,
+        };
+        if net_amount == 0 {
+            return Err(RewardsProgramError::InvalidAmount.into());
+        }
+        net_amounts.push(net_amount);
+    }
+
+    let total_net = net_amounts
+        .iter()
+        .try_fold(0u64, |total, amount| total.checked_add(*amount))
+        .ok_or(RewardsProgramError::MathOverflow)?;
+    if total_net > received {
+        return Err(RewardsProgramError::InsufficientFunds.into());
+    }
+
+    distribution.total_allocated =
+        distribution.total_allocated.checked_add(total_net).ok_or(RewardsProgramError::MathOverflow)?;
+    distribution.add_funded(received)?;
+
+    for ((entry, pair), net_amount) in
+        ix.data.recipients.iter().zip(ix.accounts.recipients.chunks_exact(2)).zip(&net_amounts)
+    {
+        create_direct_recipient(
+            ix.accounts.payer,
+            ix.accounts.distribution,
+            &pair[0],
+            &pair[1],
+            entry.bump,
+            *net_amount,
+            entry.schedule.clone(),
+            distribution.vesting_offset,
+        )?;
+    }
+
+    save_account(ix.accounts.distribution, &distribution, Some(ix.accounts.payer))?;
+
+    for ((entry, pair), net_amount) in
+        ix.data.recipients.iter().zip(ix.accounts.recipients.chunks_exact(2)).zip(&net_amounts)
+    {
+        let event = RecipientAddedEvent::new(
+            *ix.accounts.distribution.address(),
+            *pair[1].address(),
+            *net_amount,
+            entry.schedule.clone(),
+        );
+        emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+    }
+
+    Ok(())
+}
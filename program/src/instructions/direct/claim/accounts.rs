@@ -3,8 +3,9 @@ use pinocchio::{account::AccountView, error::ProgramError};
 use crate::{
     traits::InstructionAccounts,
     utils::{
-        validate_associated_token_account, verify_current_program, verify_current_program_account,
-        verify_event_authority, verify_owned_by, verify_readonly, verify_signer, verify_token_program, verify_writable,
+        validate_distribution_vault, verify_current_program, verify_current_program_account, verify_event_authority,
+        verify_mint_owner, verify_owned_by, verify_readonly, verify_signer, verify_token_program_for_mint,
+        verify_writable,
     },
 };
 
@@ -44,7 +45,7 @@ impl<'a> TryFrom<&'a [AccountView]> for ClaimDirectAccounts<'a> {
         verify_readonly(mint)?;
 
         // 3. Validate program IDs
-        verify_token_program(token_program)?;
+        verify_token_program_for_mint(token_program, mint)?;
         verify_current_program(program)?;
         verify_event_authority(event_authority)?;
 
@@ -53,11 +54,11 @@ impl<'a> TryFrom<&'a [AccountView]> for ClaimDirectAccounts<'a> {
         verify_current_program_account(recipient_account)?;
 
         // 5. Validate token account ownership
-        verify_owned_by(mint, token_program.address())?;
+        verify_mint_owner(mint, token_program)?;
         verify_owned_by(recipient_token_account, token_program.address())?;
 
-        // 6. Validate distribution_vault ATA
-        validate_associated_token_account(distribution_vault, distribution.address(), mint, token_program)?;
+        // 6. Validate distribution_vault (ATA, or native vault PDA)
+        validate_distribution_vault(distribution_vault, distribution.address(), mint, token_program)?;
 
         Ok(Self {
             recipient,
@@ -1,12 +1,14 @@
 use pinocchio::error::ProgramError;
 
-use crate::{require_len, traits::InstructionData};
+use crate::{require_len, traits::InstructionData, utils::TransferFeeMode};
 
 /// Instruction data for ClaimDirect
 ///
 /// - `amount`: The amount to claim. If 0, claims all available.
+/// - `fee_mode`: Whether `amount` is sent from the vault or received by the recipient.
 pub struct ClaimDirectData {
     pub amount: u64,
+    pub fee_mode: TransferFeeMode,
 }
 
 impl<'a> TryFrom<&'a [u8]> for ClaimDirectData {
@@ -17,37 +19,60 @@ impl<'a> TryFrom<&'a [u8]> for ClaimDirectData {
         require_len!(data, Self::LEN);
 
         let amount = u64::from_le_bytes(data[..8].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
-        Ok(Self { amount })
+        let fee_mode = TransferFeeMode::try_from(data[8])?;
+        Ok(Self { amount, fee_mode })
     }
 }
 
 impl<'a> InstructionData<'a> for ClaimDirectData {
-    const LEN: usize = 8;
+    const LEN: usize = 8 + 1; // amount + fee_mode
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     use super::*;
+    use crate::errors::RewardsProgramError;
+
+    fn build_data(amount: u64, fee_mode: u8) -> Vec<u8> {
+        let mut data = amount.to_le_bytes().to_vec();
+        data.push(fee_mode);
+        data
+    }
 
     #[test]
     fn test_try_from_valid_data() {
-        let amount: u64 = 1000;
-        let data = amount.to_le_bytes();
+        let data = build_data(1000, 0);
         let result = ClaimDirectData::try_from(&data[..]).unwrap();
         assert_eq!(result.amount, 1000);
+        assert_eq!(result.fee_mode, TransferFeeMode::Gross {});
     }
 
     #[test]
     fn test_try_from_zero_amount() {
-        let amount: u64 = 0;
-        let data = amount.to_le_bytes();
+        let data = build_data(0, 0);
         let result = ClaimDirectData::try_from(&data[..]).unwrap();
         assert_eq!(result.amount, 0);
     }
 
+    #[test]
+    fn test_try_from_net_fee_mode() {
+        let data = build_data(1000, 1);
+        let result = ClaimDirectData::try_from(&data[..]).unwrap();
+        assert_eq!(result.fee_mode, TransferFeeMode::Net {});
+    }
+
+    #[test]
+    fn test_try_from_invalid_fee_mode() {
+        let data = build_data(1000, 2);
+        let result = ClaimDirectData::try_from(&data[..]);
+        assert_eq!(result.err(), Some(RewardsProgramError::InvalidTransferFeeMode.into()));
+    }
+
     #[test]
     fn test_try_from_extra_data() {
-        let mut data = 500u64.to_le_bytes().to_vec();
+        let mut data = build_data(500, 0);
         data.extend_from_slice(&[1, 2, 3]);
         let result = ClaimDirectData::try_from(&data[..]).unwrap();
         assert_eq!(result.amount, 500);
@@ -55,7 +80,7 @@ mod tests {
 
     #[test]
     fn test_try_from_insufficient_data() {
-        let data = [1, 2, 3];
+        let data = 500u64.to_le_bytes();
         let result = ClaimDirectData::try_from(&data[..]);
         assert!(result.is_err());
     }
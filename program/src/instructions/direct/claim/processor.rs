@@ -1,64 +1,121 @@
-use pinocchio::{account::AccountView, Address, ProgramResult};
-use pinocchio_token_2022::instructions::TransferChecked;
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
 
 use crate::{
     events::ClaimedEvent,
     state::{DirectDistribution, DirectRecipient},
-    traits::{AccountSerialize, ClaimTracker, Distribution, DistributionSigner, EventSerialize, VestingParams},
-    utils::{emit_event, get_current_timestamp, get_mint_decimals, resolve_claim_amount},
+    traits::{ClaimTracker, Distribution, EventSerialize, VestingParams},
+    utils::{
+        emit_event, get_current_timestamp, resolve_claim_amount, resolve_gross_amount, save_account,
+        transfer_from_distribution_vault, verify_vault_balance, ClaimTransferContext,
+    },
     ID,
 };
 
-use super::ClaimDirect;
+use super::{ClaimDirect, ClaimDirectAccounts, ClaimDirectData};
 
 pub fn process_claim_direct(_program_id: &Address, accounts: &[AccountView], instruction_data: &[u8]) -> ProgramResult {
     let ix = ClaimDirect::try_from((instruction_data, accounts))?;
+    execute_claim_direct(&ix.accounts, &ix.data)
+}
 
+/// Claim vested tokens for `accounts.recipient` into `accounts.recipient_token_account`.
+///
+/// Shared by `ClaimDirect` and `CrankClaimDirect`; callers authorize the claim and its destination.
+pub(crate) fn execute_claim_direct(accounts: &ClaimDirectAccounts, data: &ClaimDirectData) -> ProgramResult {
     let current_ts = get_current_timestamp()?;
 
-    let distribution_data = ix.accounts.distribution.try_borrow()?;
-    let mut distribution = DirectDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    let (mut distribution, mut recipient, claimable_amount) = load_direct_claim(accounts, current_ts)?;
+    let requested_amount = resolve_gross_amount(accounts.mint, data.amount, data.fee_mode)?;
+    let claim_amount = resolve_claim_amount(requested_amount, claimable_amount)?;
+
+    settle_direct_claim(accounts, &mut distribution, &mut recipient, claim_amount)
+}
+
+/// Load and validate the distribution and recipient accounts of a direct claim.
+///
+/// Returns both accounts and the amount the recipient can claim at `current_ts`.
+pub(crate) fn load_direct_claim(
+    accounts: &ClaimDirectAccounts,
+    current_ts: i64,
+) -> Result<(DirectDistribution, DirectRecipient, u64), ProgramError> {
+    let (distribution, recipient) = load_direct_claim_accounts(accounts)?;
+
+    distribution.validate_not_paused()?;
+    distribution.validate_claim_window(current_ts)?;
+
+    let claimable_amount = direct_claimable_amount(&distribution, &recipient, current_ts)?;
+
+    Ok((distribution, recipient, claimable_amount))
+}
+
+/// Load the distribution and recipient accounts of a direct claim and check that they belong
+/// together, to `accounts.recipient` and to `accounts.mint`.
+///
+/// Does not check the pause state or the claim window.
+pub(crate) fn load_direct_claim_accounts(
+    accounts: &ClaimDirectAccounts,
+) -> Result<(DirectDistribution, DirectRecipient), ProgramError> {
+    let distribution_data = accounts.distribution.try_borrow()?;
+    let distribution = DirectDistribution::from_account(&distribution_data, accounts.distribution, &ID)?;
     drop(distribution_data);
 
-    let recipient_data = ix.accounts.recipient_account.try_borrow()?;
-    let mut recipient = DirectRecipient::from_account(&recipient_data, ix.accounts.recipient_account, &ID)?;
+    if distribution.mint() != accounts.mint.address() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let recipient_data = accounts.recipient_account.try_borrow()?;
+    let recipient = DirectRecipient::from_account(&recipient_data, accounts.recipient_account, &ID)?;
     drop(recipient_data);
 
-    recipient.validate_distribution(ix.accounts.distribution.address())?;
-    recipient.validate_recipient(ix.accounts.recipient.address())?;
+    recipient.validate_distribution(accounts.distribution.address())?;
+    recipient.validate_recipient(accounts.recipient.address())?;
 
-    let unlocked_amount = VestingParams::calculate_unlocked(&recipient, current_ts)?;
-    let claimable_amount = ClaimTracker::claimable_amount(&recipient, unlocked_amount)?;
-    let claim_amount = resolve_claim_amount(ix.data.amount, claimable_amount)?;
+    Ok((distribution, recipient))
+}
 
-    ClaimTracker::add_claimed(&mut recipient, claim_amount)?;
-    Distribution::add_claimed(&mut distribution, claim_amount)?;
+/// Amount `recipient` can claim from `distribution` at `current_ts`.
+pub(crate) fn direct_claimable_amount(
+    distribution: &DirectDistribution,
+    recipient: &DirectRecipient,
+    current_ts: i64,
+) -> Result<u64, ProgramError> {
+    let unlocked_amount = VestingParams::calculate_unlocked(
+        recipient,
+        distribution.vesting_timestamp(current_ts, recipient.base_vesting_offset),
+    )?;
+    Ok(ClaimTracker::claimable_amount(recipient, unlocked_amount)?)
+}
 
-    let mut recipient_data = ix.accounts.recipient_account.try_borrow_mut()?;
-    recipient.write_to_slice(&mut recipient_data)?;
-    drop(recipient_data);
+/// Record `claim_amount` as claimed, transfer it from the vault and emit `Claimed`.
+pub(crate) fn settle_direct_claim(
+    accounts: &ClaimDirectAccounts,
+    distribution: &mut DirectDistribution,
+    recipient: &mut DirectRecipient,
+    claim_amount: u64,
+) -> ProgramResult {
+    verify_vault_balance(accounts.distribution_vault, claim_amount)?;
 
-    let mut distribution_data = ix.accounts.distribution.try_borrow_mut()?;
-    distribution.write_to_slice(&mut distribution_data)?;
-    drop(distribution_data);
+    ClaimTracker::add_claimed(recipient, claim_amount)?;
+    Distribution::add_claimed(distribution, claim_amount)?;
+
+    save_account(accounts.recipient_account, recipient, None)?;
+
+    save_account(accounts.distribution, distribution, None)?;
+
+    transfer_from_distribution_vault(
+        distribution,
+        &ClaimTransferContext {
+            distribution_vault: accounts.distribution_vault,
+            mint: accounts.mint,
+            destination: accounts.recipient_token_account,
+            distribution_account: accounts.distribution,
+            token_program: accounts.token_program.address(),
+        },
+        claim_amount,
+    )?;
 
-    let decimals = get_mint_decimals(ix.accounts.mint)?;
-
-    distribution.with_signer(|signers| {
-        TransferChecked {
-            from: ix.accounts.distribution_vault,
-            mint: ix.accounts.mint,
-            to: ix.accounts.recipient_token_account,
-            authority: ix.accounts.distribution,
-            amount: claim_amount,
-            decimals,
-            token_program: ix.accounts.token_program.address(),
-        }
-        .invoke_signed(signers)
-    })?;
-
-    let event = ClaimedEvent::new(*ix.accounts.distribution.address(), *ix.accounts.recipient.address(), claim_amount);
-    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+    let event = ClaimedEvent::new(*accounts.distribution.address(), *accounts.recipient.address(), claim_amount);
+    emit_event(&ID, accounts.event_authority, accounts.program, &event.to_bytes())?;
 
     Ok(())
 }
@@ -0,0 +1,95 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    errors::RewardsProgramError,
+    instructions::direct::claim::ClaimDirectAccounts,
+    traits::InstructionAccounts,
+    utils::{
+        validate_distribution_vault, verify_current_program, verify_current_program_account, verify_event_authority,
+        verify_mint_owner, verify_owned_by, verify_readonly, verify_signer, verify_token_program_for_mint,
+        verify_writable,
+    },
+};
+
+pub struct ClaimDirectManyAccounts<'a> {
+    pub recipient: &'a AccountView,
+    pub mint: &'a AccountView,
+    pub recipient_token_account: &'a AccountView,
+    pub token_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub program: &'a AccountView,
+    /// Remaining accounts as `[distribution, recipient_account, distribution_vault]` triples
+    pub claims: &'a [AccountView],
+}
+
+impl<'a> ClaimDirectManyAccounts<'a> {
+    /// View each `[distribution, recipient_account, distribution_vault]` triple as a regular claim
+    #[inline(always)]
+    pub fn claim_accounts(&self) -> impl Iterator<Item = ClaimDirectAccounts<'a>> + '_ {
+        self.claims.chunks_exact(3).map(|triple| ClaimDirectAccounts {
+            recipient: self.recipient,
+            distribution: &triple[0],
+            recipient_account: &triple[1],
+            mint: self.mint,
+            distribution_vault: &triple[2],
+            recipient_token_account: self.recipient_token_account,
+            token_program: self.token_program,
+            event_authority: self.event_authority,
+            program: self.program,
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for ClaimDirectManyAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [recipient, mint, recipient_token_account, token_program, event_authority, program, claims @ ..] = accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if claims.is_empty() || claims.len() % 3 != 0 {
+            return Err(RewardsProgramError::InvalidDistributionCount.into());
+        }
+
+        // 1. Validate signers
+        verify_signer(recipient, false)?;
+
+        // 2. Validate writable
+        verify_writable(recipient_token_account, true)?;
+        for triple in claims.chunks_exact(3) {
+            verify_writable(&triple[0], true)?;
+            verify_writable(&triple[1], true)?;
+            verify_writable(&triple[2], true)?;
+        }
+
+        // 2b. Validate read-only accounts
+        verify_readonly(mint)?;
+
+        // 3. Validate program IDs
+        verify_token_program_for_mint(token_program, mint)?;
+        verify_current_program(program)?;
+        verify_event_authority(event_authority)?;
+
+        // 4. Validate accounts owned by current program
+        for triple in claims.chunks_exact(3) {
+            verify_current_program_account(&triple[0])?;
+            verify_current_program_account(&triple[1])?;
+        }
+
+        // 5. Validate token account ownership
+        verify_mint_owner(mint, token_program)?;
+        verify_owned_by(recipient_token_account, token_program.address())?;
+
+        // 6. Validate each distribution_vault (ATA, or native vault PDA)
+        for triple in claims.chunks_exact(3) {
+            validate_distribution_vault(&triple[2], triple[0].address(), mint, token_program)?;
+        }
+
+        Ok(Self { recipient, mint, recipient_token_account, token_program, event_authority, program, claims })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for ClaimDirectManyAccounts<'a> {}
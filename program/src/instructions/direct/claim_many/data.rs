@@ -0,0 +1,33 @@
+use pinocchio::error::ProgramError;
+
+use crate::traits::InstructionData;
+
+/// Instruction data for ClaimDirectMany
+///
+/// This instruction has no data - every distribution claims all available tokens.
+pub struct ClaimDirectManyData {}
+
+impl<'a> TryFrom<&'a [u8]> for ClaimDirectManyData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(_data: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(Self {})
+    }
+}
+
+impl<'a> InstructionData<'a> for ClaimDirectManyData {
+    const LEN: usize = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_empty_data() {
+        let data: [u8; 0] = [];
+        let result = ClaimDirectManyData::try_from(&data[..]);
+        assert!(result.is_ok());
+    }
+}
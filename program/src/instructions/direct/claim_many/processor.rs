@@ -0,0 +1,43 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    instructions::direct::claim::{direct_claimable_amount, load_direct_claim_accounts, settle_direct_claim},
+    traits::Distribution,
+    utils::get_current_timestamp,
+};
+
+use super::ClaimDirectMany;
+
+pub fn process_claim_direct_many(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = ClaimDirectMany::try_from((instruction_data, accounts))?;
+    let current_ts = get_current_timestamp()?;
+
+    // Paused distributions, closed claim windows and grants with nothing vested yet are skipped so one
+    // blocked grant does not fail the rest. Accounts that fail validation still fail the whole batch.
+    let mut claimed_any = false;
+    for claim_accounts in ix.accounts.claim_accounts() {
+        let (mut distribution, mut recipient) = load_direct_claim_accounts(&claim_accounts)?;
+        if distribution.validate_not_paused().is_err() || distribution.validate_claim_window(current_ts).is_err() {
+            continue;
+        }
+
+        let claimable_amount = direct_claimable_amount(&distribution, &recipient, current_ts)?;
+        if claimable_amount == 0 {
+            continue;
+        }
+
+        settle_direct_claim(&claim_accounts, &mut distribution, &mut recipient, claimable_amount)?;
+        claimed_any = true;
+    }
+
+    if !claimed_any {
+        return Err(RewardsProgramError::NothingToClaim.into());
+    }
+
+    Ok(())
+}
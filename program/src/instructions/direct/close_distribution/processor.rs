@@ -1,12 +1,14 @@
 use pinocchio::{account::AccountView, Address, ProgramResult};
-use pinocchio_token_2022::instructions::{CloseAccount, TransferChecked};
 
 use crate::{
     errors::RewardsProgramError,
-    events::DistributionClosedEvent,
+    events::{DistributionClosedEvent, DistributionSettledEvent},
     state::DirectDistribution,
-    traits::{Distribution, DistributionSigner, EventSerialize},
-    utils::{close_pda_account, emit_event, get_current_timestamp, get_mint_decimals, get_token_account_balance},
+    traits::{Distribution, EventSerialize},
+    utils::{
+        close_distribution_vault, close_pda_account, emit_event, get_current_timestamp, get_vault_balance,
+        save_account, transfer_from_distribution_vault, ClaimTransferContext, ClosePolicy,
+    },
     ID,
 };
 
@@ -20,45 +22,49 @@ pub fn process_close_direct_distribution(
     let ix = CloseDirectDistribution::try_from((instruction_data, accounts))?;
 
     let distribution_data = ix.accounts.distribution.try_borrow()?;
-    let distribution = DirectDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    let mut distribution = DirectDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    drop(distribution_data);
     distribution.validate_authority(ix.accounts.authority.address())?;
 
-    if distribution.clawback_ts != 0 {
-        let current_ts = get_current_timestamp()?;
-        if current_ts < distribution.clawback_ts {
-            return Err(RewardsProgramError::ClawbackNotReached.into());
-        }
+    // Both gates are optional; the authority may not close while the claim window is open
+    let current_ts = get_current_timestamp()?;
+    if current_ts < distribution.clawback_ts.max(distribution.claim_end_ts) {
+        return Err(RewardsProgramError::ClawbackNotReached.into());
     }
 
-    let remaining_amount = get_token_account_balance(ix.accounts.distribution_vault)?;
-    let decimals = get_mint_decimals(ix.accounts.mint)?;
+    let transfer_ctx = ClaimTransferContext {
+        distribution_vault: ix.accounts.distribution_vault,
+        mint: ix.accounts.mint,
+        destination: ix.accounts.authority_token_account,
+        distribution_account: ix.accounts.distribution,
+        token_program: ix.accounts.token_program.address(),
+    };
 
-    if remaining_amount > 0 {
-        distribution.with_signer(|signers| {
-            TransferChecked {
-                from: ix.accounts.distribution_vault,
-                mint: ix.accounts.mint,
-                to: ix.accounts.authority_token_account,
-                authority: ix.accounts.distribution,
-                amount: remaining_amount,
-                decimals,
-                token_program: ix.accounts.token_program.address(),
-            }
-            .invoke_signed(signers)
-        })?;
-    }
+    let outstanding = distribution.outstanding()?;
+    // Once the claim window has closed nobody can claim the outstanding amount, so sweep it all
+    if outstanding > 0
+        && distribution.close_policy()? == (ClosePolicy::ProtectRecipients {})
+        && !distribution.is_claim_window_closed(current_ts)
+    {
+        // Recipients could never claim from a settled distribution that is still paused
+        distribution.validate_not_paused()?;
 
-    distribution.with_signer(|signers| {
-        CloseAccount {
-            account: ix.accounts.distribution_vault,
-            destination: ix.accounts.authority,
-            authority: ix.accounts.distribution,
-            token_program: ix.accounts.token_program.address(),
+        let vault_balance = get_vault_balance(ix.accounts.distribution_vault)?;
+        let swept_amount = distribution.remaining_unallocated(vault_balance)?;
+        if swept_amount > 0 {
+            transfer_from_distribution_vault(&distribution, &transfer_ctx, swept_amount)?;
         }
-        .invoke_signed(signers)
-    })?;
 
-    drop(distribution_data);
+        distribution.settled = 1;
+        save_account(ix.accounts.distribution, &distribution, Some(ix.accounts.authority))?;
+
+        let event = DistributionSettledEvent::new(*ix.accounts.distribution.address(), swept_amount, outstanding);
+        emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+        return Ok(());
+    }
+
+    let remaining_amount = close_distribution_vault(&distribution, &transfer_ctx, ix.accounts.authority)?;
 
     close_pda_account(ix.accounts.distribution, ix.accounts.authority)?;
 
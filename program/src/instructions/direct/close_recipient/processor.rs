@@ -34,7 +34,7 @@ pub fn process_close_direct_recipient(
         return Err(ProgramError::InvalidAccountData);
     }
 
-    if recipient.claimed_amount < recipient.total_amount {
+    if recipient.claimed_amount < recipient.entitled_amount()? {
         return Err(RewardsProgramError::ClaimNotFullyVested.into());
     }
 
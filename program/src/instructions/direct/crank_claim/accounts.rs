@@ -0,0 +1,103 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    instructions::direct::claim::ClaimDirectAccounts,
+    traits::InstructionAccounts,
+    utils::{
+        validate_distribution_vault, verify_current_program, verify_current_program_account, verify_event_authority,
+        verify_mint_owner, verify_owned_by, verify_readonly, verify_signer, verify_token_program_for_mint,
+        verify_writable,
+    },
+};
+
+pub struct CrankClaimDirectAccounts<'a> {
+    pub cranker: &'a AccountView,
+    pub recipient: &'a AccountView,
+    pub distribution: &'a AccountView,
+    pub recipient_account: &'a AccountView,
+    pub claim_delegate_account: &'a AccountView,
+    pub mint: &'a AccountView,
+    pub distribution_vault: &'a AccountView,
+    pub destination_token_account: &'a AccountView,
+    pub token_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for CrankClaimDirectAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [cranker, recipient, distribution, recipient_account, claim_delegate_account, mint, distribution_vault, destination_token_account, token_program, event_authority, program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(cranker, false)?;
+
+        // 2. Validate writable
+        verify_writable(distribution, true)?;
+        verify_writable(recipient_account, true)?;
+        verify_writable(distribution_vault, true)?;
+        verify_writable(destination_token_account, true)?;
+
+        // 2b. Validate read-only accounts
+        verify_readonly(recipient)?;
+        verify_readonly(claim_delegate_account)?;
+        verify_readonly(mint)?;
+
+        // 3. Validate program IDs
+        verify_token_program_for_mint(token_program, mint)?;
+        verify_current_program(program)?;
+        verify_event_authority(event_authority)?;
+
+        // 4. Validate accounts owned by current program
+        verify_current_program_account(distribution)?;
+        verify_current_program_account(recipient_account)?;
+        // claim_delegate_account may be uninitialized; validated in processor
+
+        // 5. Validate token account ownership
+        verify_mint_owner(mint, token_program)?;
+        verify_owned_by(destination_token_account, token_program.address())?;
+
+        // 6. Validate distribution_vault (ATA, or native vault PDA)
+        validate_distribution_vault(distribution_vault, distribution.address(), mint, token_program)?;
+
+        Ok(Self {
+            cranker,
+            recipient,
+            distribution,
+            recipient_account,
+            claim_delegate_account,
+            mint,
+            distribution_vault,
+            destination_token_account,
+            token_program,
+            event_authority,
+            program,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for CrankClaimDirectAccounts<'a> {}
+
+impl<'a> CrankClaimDirectAccounts<'a> {
+    /// View these accounts as a regular claim paying into `destination_token_account`.
+    #[inline(always)]
+    pub fn as_claim_accounts(&self) -> ClaimDirectAccounts<'a> {
+        ClaimDirectAccounts {
+            recipient: self.recipient,
+            distribution: self.distribution,
+            recipient_account: self.recipient_account,
+            mint: self.mint,
+            distribution_vault: self.distribution_vault,
+            recipient_token_account: self.destination_token_account,
+            token_program: self.token_program,
+            event_authority: self.event_authority,
+            program: self.program,
+        }
+    }
+}
@@ -0,0 +1,6 @@
+use crate::instructions::direct::claim::ClaimDirectData;
+
+/// Instruction data for CrankClaimDirect
+///
+/// Identical to `ClaimDirectData`: `amount` (0 = claim all available) and `fee_mode`.
+pub type CrankClaimDirectData = ClaimDirectData;
@@ -0,0 +1,36 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    instructions::direct::claim::execute_claim_direct,
+    utils::{is_recipient_or_delegate, verify_permissionless_crank},
+};
+
+use super::CrankClaimDirect;
+
+pub fn process_crank_claim_direct(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = CrankClaimDirect::try_from((instruction_data, accounts))?;
+
+    let recipient = ix.accounts.recipient.address();
+    if !is_recipient_or_delegate(
+        ix.accounts.cranker.address(),
+        recipient,
+        ix.accounts.distribution.address(),
+        ix.accounts.claim_delegate_account,
+    )? {
+        verify_permissionless_crank(
+            recipient,
+            ix.accounts.destination_token_account,
+            ix.accounts.mint,
+            ix.accounts.token_program,
+            ix.data.amount,
+            ix.data.fee_mode,
+        )?;
+    }
+
+    // Recipient account PDA, recipient match, vesting and vault balance are validated by the shared claim path
+    execute_claim_direct(&ix.accounts.as_claim_accounts(), &ix.data)
+}
@@ -3,9 +3,9 @@ use pinocchio::{account::AccountView, error::ProgramError};
 use crate::{
     traits::InstructionAccounts,
     utils::{
-        validate_associated_token_account_address, verify_associated_token_program, verify_current_program,
-        verify_event_authority, verify_owned_by, verify_readonly, verify_signer, verify_system_program,
-        verify_token_program, verify_writable,
+        validate_distribution_vault_address, verify_associated_token_program, verify_current_program,
+        verify_event_authority, verify_mint_owner, verify_readonly, verify_signer, verify_system_program,
+        verify_token_program_for_mint, verify_writable,
     },
 };
 
@@ -49,7 +49,7 @@ impl<'a> TryFrom<&'a [AccountView]> for CreateDirectDistributionAccounts<'a> {
 
         // 3. Validate program IDs
         verify_system_program(system_program)?;
-        verify_token_program(token_program)?;
+        verify_token_program_for_mint(token_program, mint)?;
         verify_associated_token_program(associated_token_program)?;
         verify_current_program(program)?;
         verify_event_authority(event_authority)?;
@@ -57,10 +57,10 @@ impl<'a> TryFrom<&'a [AccountView]> for CreateDirectDistributionAccounts<'a> {
         // 4. (no accounts owned by current program for this instruction)
 
         // 5. Validate token account ownership
-        verify_owned_by(mint, token_program.address())?;
+        verify_mint_owner(mint, token_program)?;
 
-        // 6. Validate ATA (distribution_vault may not be initialized yet, so just validate the address)
-        validate_associated_token_account_address(distribution_vault, distribution.address(), mint, token_program)?;
+        // 6. Validate distribution_vault address (it may not be initialized yet)
+        validate_distribution_vault_address(distribution_vault, distribution.address(), mint, token_program)?;
 
         Ok(Self {
             payer,
@@ -1,11 +1,18 @@
 use pinocchio::error::ProgramError;
 
-use crate::{require_len, traits::InstructionData};
+use crate::{
+    require_len,
+    traits::InstructionData,
+    utils::{validate_claim_window_config, ClosePolicy},
+};
 
 pub struct CreateDirectDistributionData {
     pub bump: u8,
     pub revocable: u8,
     pub clawback_ts: i64,
+    pub close_policy: ClosePolicy,
+    pub claim_start_ts: i64,
+    pub claim_end_ts: i64,
 }
 
 impl<'a> TryFrom<&'a [u8]> for CreateDirectDistributionData {
@@ -19,15 +26,21 @@ impl<'a> TryFrom<&'a [u8]> for CreateDirectDistributionData {
         let revocable = data[1];
         let clawback_ts = i64::from_le_bytes(data[2..10].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
 
-        Ok(Self { bump, revocable, clawback_ts })
+        let close_policy = ClosePolicy::try_from(data[10])?;
+        let claim_start_ts =
+            i64::from_le_bytes(data[11..19].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        let claim_end_ts =
+            i64::from_le_bytes(data[19..27].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+
+        Ok(Self { bump, revocable, clawback_ts, close_policy, claim_start_ts, claim_end_ts })
     }
 }
 
 impl<'a> InstructionData<'a> for CreateDirectDistributionData {
-    const LEN: usize = 10; // bump(1) + revocable(1) + clawback_ts(8)
+    const LEN: usize = 27; // bump(1) + revocable(1) + clawback_ts(8) + close_policy(1) + claim_start_ts(8) + claim_end_ts(8)
 
     fn validate(&self) -> Result<(), ProgramError> {
-        Ok(())
+        validate_claim_window_config(self.claim_start_ts, self.claim_end_ts, self.clawback_ts)
     }
 }
 
@@ -35,11 +48,14 @@ impl<'a> InstructionData<'a> for CreateDirectDistributionData {
 mod tests {
     use super::*;
 
-    fn make_data(bump: u8, revocable: u8, clawback_ts: i64) -> [u8; 10] {
-        let mut data = [0u8; 10];
+    use crate::errors::RewardsProgramError;
+
+    fn make_data(bump: u8, revocable: u8, clawback_ts: i64) -> [u8; 27] {
+        let mut data = [0u8; 27];
         data[0] = bump;
         data[1] = revocable;
         data[2..10].copy_from_slice(&clawback_ts.to_le_bytes());
+        data[10] = 0; // close_policy (Sweep)
         data
     }
 
@@ -53,6 +69,23 @@ mod tests {
         assert_eq!(parsed.bump, 255);
         assert_eq!(parsed.revocable, 0);
         assert_eq!(parsed.clawback_ts, 0);
+        assert_eq!(parsed.close_policy, ClosePolicy::Sweep {});
+    }
+
+    #[test]
+    fn test_try_from_protect_recipients() {
+        let mut data = make_data(255, 0, 0);
+        data[10] = 1;
+        let parsed = CreateDirectDistributionData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.close_policy, ClosePolicy::ProtectRecipients {});
+    }
+
+    #[test]
+    fn test_try_from_invalid_close_policy() {
+        let mut data = make_data(255, 0, 0);
+        data[10] = 2;
+        let result = CreateDirectDistributionData::try_from(&data[..]);
+        assert_eq!(result.err(), Some(RewardsProgramError::InvalidClosePolicy.into()));
     }
 
     #[test]
@@ -77,7 +110,7 @@ mod tests {
 
     #[test]
     fn test_try_from_data_too_short() {
-        let data = [0u8; 9]; // need 10
+        let data = [0u8; 26]; // need 27
         let result = CreateDirectDistributionData::try_from(&data[..]);
         assert_eq!(result.err(), Some(ProgramError::InvalidInstructionData));
     }
@@ -98,4 +131,20 @@ mod tests {
             assert_eq!(parsed.revocable, revocable);
         }
     }
+
+    #[test]
+    fn test_validate_claim_window() {
+        let mut data = make_data(255, 0, 0);
+        data[11..19].copy_from_slice(&100i64.to_le_bytes());
+        data[19..27].copy_from_slice(&200i64.to_le_bytes());
+        let parsed = CreateDirectDistributionData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.claim_start_ts, 100);
+        assert_eq!(parsed.claim_end_ts, 200);
+        assert!(parsed.validate().is_ok());
+
+        // Window closes before it opens
+        data[19..27].copy_from_slice(&50i64.to_le_bytes());
+        let parsed = CreateDirectDistributionData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.validate().err(), Some(RewardsProgramError::InvalidTimeWindow.into()));
+    }
 }
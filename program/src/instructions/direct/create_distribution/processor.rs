@@ -1,11 +1,10 @@
 use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
-use pinocchio_associated_token_account::instructions::CreateIdempotent;
 
 use crate::{
     events::DistributionCreatedEvent,
     state::DirectDistribution,
     traits::{AccountSerialize, AccountSize, EventSerialize, InstructionData, PdaSeeds},
-    utils::{create_pda_account, emit_event},
+    utils::{create_distribution_vault, create_pda_account, emit_event, verify_supported_mint_extensions},
     ID,
 };
 
@@ -18,8 +17,9 @@ pub fn process_create_direct_distribution(
 ) -> ProgramResult {
     let ix = CreateDirectDistribution::try_from((instruction_data, accounts))?;
     ix.data.validate()?;
+    verify_supported_mint_extensions(ix.accounts.mint)?;
 
-    let distribution = DirectDistribution::new(
+    let mut distribution = DirectDistribution::new(
         ix.data.bump,
         ix.data.revocable,
         ix.data.clawback_ts,
@@ -27,6 +27,9 @@ pub fn process_create_direct_distribution(
         *ix.accounts.mint.address(),
         *ix.accounts.seed.address(),
     );
+    distribution.close_policy = ix.data.close_policy.to_byte();
+    distribution.claim_start_ts = ix.data.claim_start_ts;
+    distribution.claim_end_ts = ix.data.claim_end_ts;
 
     distribution.validate_pda(ix.accounts.distribution, &ID, ix.data.bump)?;
 
@@ -46,15 +49,14 @@ pub fn process_create_direct_distribution(
     distribution.write_to_slice(&mut distribution_data)?;
     drop(distribution_data);
 
-    CreateIdempotent {
-        funding_account: ix.accounts.payer,
-        account: ix.accounts.distribution_vault,
-        wallet: ix.accounts.distribution,
-        mint: ix.accounts.mint,
-        system_program: ix.accounts.system_program,
-        token_program: ix.accounts.token_program,
-    }
-    .invoke()?;
+    create_distribution_vault(
+        ix.accounts.payer,
+        ix.accounts.distribution,
+        ix.accounts.distribution_vault,
+        ix.accounts.mint,
+        ix.accounts.system_program,
+        ix.accounts.token_program,
+    )?;
 
     let event = DistributionCreatedEvent::direct(
         *ix.accounts.authority.address(),
@@ -0,0 +1,59 @@
+use pinocchio::error::ProgramError;
+
+use crate::{errors::RewardsProgramError, require_len, traits::InstructionData};
+
+pub struct DecreaseDirectAllocationData {
+    /// Unvested token amount removed from the recipient's allocation
+    pub amount: u64,
+}
+
+impl<'a> TryFrom<&'a [u8]> for DecreaseDirectAllocationData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let amount = u64::from_le_bytes(data[0..8].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+
+        Ok(Self { amount })
+    }
+}
+
+impl<'a> InstructionData<'a> for DecreaseDirectAllocationData {
+    const LEN: usize = 8; // amount
+
+    fn validate(&self) -> Result<(), ProgramError> {
+        if self.amount == 0 {
+            return Err(RewardsProgramError::InvalidAmount.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_valid() {
+        let data = 400u64.to_le_bytes();
+        let parsed = DecreaseDirectAllocationData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.amount, 400);
+        assert!(parsed.validate().is_ok());
+    }
+
+    #[test]
+    fn test_try_from_data_too_short() {
+        let data = [0u8; 7];
+        let result = DecreaseDirectAllocationData::try_from(&data[..]);
+        assert_eq!(result.err(), Some(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_validate_zero_amount() {
+        let data = 0u64.to_le_bytes();
+        let parsed = DecreaseDirectAllocationData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.validate().err(), Some(RewardsProgramError::InvalidAmount.into()));
+    }
+}
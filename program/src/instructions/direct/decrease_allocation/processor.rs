@@ -0,0 +1,88 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    events::AllocationChangedEvent,
+    state::{DirectDistribution, DirectRecipient},
+    traits::{Distribution, EventSerialize, InstructionData, VestingParams},
+    utils::{
+        emit_event, get_current_timestamp, save_account, transfer_from_distribution_vault, ClaimTransferContext,
+        RevokeMode,
+    },
+    ID,
+};
+
+use super::DecreaseDirectAllocation;
+
+pub fn process_decrease_direct_allocation(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = DecreaseDirectAllocation::try_from((instruction_data, accounts))?;
+    ix.data.validate()?;
+
+    let distribution_data = ix.accounts.distribution.try_borrow()?;
+    let mut distribution = DirectDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    drop(distribution_data);
+
+    distribution.validate_authority(ix.accounts.authority.address())?;
+    distribution.validate_not_settled()?;
+
+    // A decrease is a partial NonVested revoke, so it needs the same opt-in
+    if (RevokeMode::NonVested {}).is_disabled_by(distribution.revocable) {
+        return Err(RewardsProgramError::DistributionNotRevocable.into());
+    }
+
+    let recipient_data = ix.accounts.recipient_account.try_borrow()?;
+    let mut recipient = DirectRecipient::from_account(&recipient_data, ix.accounts.recipient_account, &ID)?;
+    drop(recipient_data);
+
+    recipient.validate_distribution(ix.accounts.distribution.address())?;
+    recipient.validate_recipient(ix.accounts.recipient.address())?;
+
+    if recipient.is_frozen() {
+        return Err(RewardsProgramError::ClaimantAlreadyRevoked.into());
+    }
+
+    let current_ts = get_current_timestamp()?;
+    let vesting_ts = distribution.vesting_timestamp(current_ts, recipient.base_vesting_offset);
+    let vested_amount = VestingParams::calculate_unlocked(&recipient, vesting_ts)?;
+    let unvested = recipient.total_amount.checked_sub(vested_amount).ok_or(RewardsProgramError::MathOverflow)?;
+    if ix.data.amount > unvested {
+        return Err(RewardsProgramError::InsufficientUnvestedAmount.into());
+    }
+
+    // schedule_amount is left alone: the curve keeps its shape and vesting stops early at the new total
+    let previous_amount = recipient.total_amount;
+    recipient.total_amount -= ix.data.amount;
+    distribution.total_allocated =
+        distribution.total_allocated.checked_sub(ix.data.amount).ok_or(RewardsProgramError::MathOverflow)?;
+
+    transfer_from_distribution_vault(
+        &distribution,
+        &ClaimTransferContext {
+            distribution_vault: ix.accounts.distribution_vault,
+            mint: ix.accounts.mint,
+            destination: ix.accounts.authority_token_account,
+            distribution_account: ix.accounts.distribution,
+            token_program: ix.accounts.token_program.address(),
+        },
+        ix.data.amount,
+    )?;
+
+    save_account(ix.accounts.recipient_account, &recipient, None)?;
+
+    save_account(ix.accounts.distribution, &distribution, None)?;
+
+    let event = AllocationChangedEvent::new(
+        *ix.accounts.distribution.address(),
+        *ix.accounts.recipient.address(),
+        previous_amount,
+        recipient.total_amount,
+        recipient.schedule,
+    );
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
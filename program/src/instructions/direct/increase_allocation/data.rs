@@ -0,0 +1,119 @@
+use pinocchio::error::ProgramError;
+
+use crate::{errors::RewardsProgramError, require_len, traits::InstructionData, utils::VestingSchedule};
+
+/// Instruction data for IncreaseDirectAllocation.
+///
+/// Variable-length due to the optional VestingSchedule.
+pub struct IncreaseDirectAllocationData {
+    /// Token amount added to the recipient's allocation
+    pub amount: u64,
+    /// Replacement schedule for the whole allocation, existing tokens included (None = keep the current schedule)
+    pub schedule: Option<VestingSchedule>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for IncreaseDirectAllocationData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let amount = u64::from_le_bytes(data[0..8].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        let schedule = match data[8] {
+            0 => None,
+            1 => Some(VestingSchedule::from_bytes(&data[9..])?.0),
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        Ok(Self { amount, schedule })
+    }
+}
+
+impl<'a> InstructionData<'a> for IncreaseDirectAllocationData {
+    const LEN: usize = 8 + 1; // amount + schedule option flag
+
+    fn validate(&self) -> Result<(), ProgramError> {
+        if self.amount == 0 {
+            return Err(RewardsProgramError::InvalidAmount.into());
+        }
+        if let Some(schedule) = &self.schedule {
+            schedule.validate()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn create_data(amount: u64, schedule: Option<VestingSchedule>) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&amount.to_le_bytes());
+        match schedule {
+            Some(schedule) => {
+                data.push(1);
+                data.extend_from_slice(&schedule.to_bytes());
+            }
+            None => data.push(0),
+        }
+        data
+    }
+
+    #[test]
+    fn test_try_from_valid_without_schedule() {
+        let data = create_data(500, None);
+        let parsed = IncreaseDirectAllocationData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.amount, 500);
+        assert_eq!(parsed.schedule, None);
+    }
+
+    #[test]
+    fn test_try_from_valid_with_schedule() {
+        let schedule = VestingSchedule::Linear { start_ts: 100, end_ts: 200 };
+        let data = create_data(500, Some(schedule.clone()));
+        let parsed = IncreaseDirectAllocationData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.amount, 500);
+        assert_eq!(parsed.schedule, Some(schedule));
+    }
+
+    #[test]
+    fn test_try_from_data_too_short() {
+        let data = [0u8; 8];
+        let result = IncreaseDirectAllocationData::try_from(&data[..]);
+        assert_eq!(result.err(), Some(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_try_from_invalid_option_flag() {
+        let mut data = create_data(500, None);
+        data[8] = 2;
+        let result = IncreaseDirectAllocationData::try_from(&data[..]);
+        assert_eq!(result.err(), Some(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_try_from_missing_schedule() {
+        let mut data = create_data(500, None);
+        data[8] = 1;
+        let result = IncreaseDirectAllocationData::try_from(&data[..]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_zero_amount() {
+        let data = create_data(0, None);
+        let parsed = IncreaseDirectAllocationData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.validate().err(), Some(RewardsProgramError::InvalidAmount.into()));
+    }
+
+    #[test]
+    fn test_validate_invalid_schedule() {
+        let data = create_data(500, Some(VestingSchedule::Linear { start_ts: 200, end_ts: 100 }));
+        let parsed = IncreaseDirectAllocationData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.validate().err(), Some(RewardsProgramError::InvalidTimeWindow.into()));
+    }
+}
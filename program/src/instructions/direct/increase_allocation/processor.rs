@@ -0,0 +1,112 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    events::AllocationChangedEvent,
+    state::{DirectDistribution, DirectRecipient},
+    traits::{Distribution, EventSerialize, InstructionData, VestingParams},
+    utils::{
+        deposit_to_distribution_vault, emit_event, get_current_timestamp, resize_pda_account, save_account,
+        VaultDepositContext,
+    },
+    ID,
+};
+
+use super::IncreaseDirectAllocation;
+
+pub fn process_increase_direct_allocation(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = IncreaseDirectAllocation::try_from((instruction_data, accounts))?;
+    ix.data.validate()?;
+
+    let distribution_data = ix.accounts.distribution.try_borrow()?;
+    let mut distribution = DirectDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    drop(distribution_data);
+
+    Distribution::validate_authority(&distribution, ix.accounts.authority.address())?;
+    distribution.validate_not_settled()?;
+
+    let recipient_data = ix.accounts.recipient_account.try_borrow()?;
+    let mut recipient = DirectRecipient::from_account(&recipient_data, ix.accounts.recipient_account, &ID)?;
+    drop(recipient_data);
+
+    recipient.validate_distribution(ix.accounts.distribution.address())?;
+    recipient.validate_recipient(ix.accounts.recipient.address())?;
+
+    if recipient.is_frozen() {
+        return Err(RewardsProgramError::ClaimantAlreadyRevoked.into());
+    }
+    if let Some(schedule) = &ix.data.schedule {
+        // Without revocation rights the authority must not be able to push back a granted curve
+        if distribution.revocable == 0 {
+            return Err(RewardsProgramError::DistributionNotRevocable.into());
+        }
+        distribution.validate_vesting_end(schedule.end_ts()?, recipient.base_vesting_offset)?;
+    }
+
+    // Allocate what actually reached the vault so Token-2022 transfer fees never leave it short
+    let received = deposit_to_distribution_vault(
+        &VaultDepositContext {
+            source: ix.accounts.authority_token_account,
+            authority: ix.accounts.authority,
+            distribution_vault: ix.accounts.distribution_vault,
+            mint: ix.accounts.mint,
+            token_program: ix.accounts.token_program,
+        },
+        ix.data.amount,
+    )?;
+    if received == 0 {
+        return Err(RewardsProgramError::InvalidAmount.into());
+    }
+
+    let current_ts = get_current_timestamp()?;
+    let vesting_ts = distribution.vesting_timestamp(current_ts, recipient.base_vesting_offset);
+    let previous_unlocked = VestingParams::calculate_unlocked(&recipient, vesting_ts)?;
+    let previous = recipient.clone();
+
+    recipient.total_amount = recipient.total_amount.checked_add(received).ok_or(RewardsProgramError::MathOverflow)?;
+    match ix.data.schedule {
+        Some(schedule) => {
+            // The whole allocation moves to the new schedule, not just the added tokens. It must not
+            // take back what has vested, nor unlock any of the old grant later than it would have.
+            recipient.schedule = schedule;
+            recipient.schedule_amount = recipient.total_amount;
+            if VestingParams::calculate_unlocked(&recipient, vesting_ts)? < previous_unlocked {
+                return Err(RewardsProgramError::ScheduleRelocksVestedTokens.into());
+            }
+            recipient.validate_unlocks_no_later(&previous, vesting_ts)?;
+        }
+        None => {
+            recipient.schedule_amount =
+                recipient.schedule_amount.checked_add(received).ok_or(RewardsProgramError::MathOverflow)?;
+        }
+    }
+
+    distribution.total_allocated =
+        distribution.total_allocated.checked_add(received).ok_or(RewardsProgramError::MathOverflow)?;
+    distribution.add_funded(received)?;
+
+    // A new schedule variant or an older stored layout changes the account size
+    let account_size = DirectRecipient::calculate_account_size(&recipient.schedule);
+    if account_size != ix.accounts.recipient_account.data_len() {
+        resize_pda_account(ix.accounts.payer, ix.accounts.recipient_account, account_size)?;
+    }
+
+    save_account(ix.accounts.recipient_account, &recipient, Some(ix.accounts.payer))?;
+
+    save_account(ix.accounts.distribution, &distribution, Some(ix.accounts.payer))?;
+
+    let event = AllocationChangedEvent::new(
+        *ix.accounts.distribution.address(),
+        *ix.accounts.recipient.address(),
+        previous.total_amount,
+        recipient.total_amount,
+        recipient.schedule,
+    );
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -1,6 +1,12 @@
 pub mod add_recipient;
+pub mod add_recipients;
 pub mod claim;
+pub mod claim_many;
 pub mod close_distribution;
 pub mod close_recipient;
+pub mod crank_claim;
 pub mod create_distribution;
+pub mod decrease_allocation;
+pub mod increase_allocation;
 pub mod revoke_recipient;
+pub mod transfer_recipient;
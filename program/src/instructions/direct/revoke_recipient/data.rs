@@ -48,6 +48,14 @@ mod tests {
         assert_eq!(result.unwrap().revoke_mode, RevokeMode::Full {});
     }
 
+    #[test]
+    fn test_try_from_valid_freeze() {
+        let data = [2u8];
+        let result = RevokeDirectRecipientData::try_from(&data[..]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().revoke_mode, RevokeMode::Freeze {});
+    }
+
     #[test]
     fn test_try_from_data_too_short() {
         let data: [u8; 0] = [];
@@ -57,7 +65,7 @@ mod tests {
 
     #[test]
     fn test_try_from_invalid_mode() {
-        let data = [2u8];
+        let data = [3u8];
         let result = RevokeDirectRecipientData::try_from(&data[..]);
         assert_eq!(result.err(), Some(ProgramError::Custom(RewardsProgramError::InvalidRevokeMode as u32)));
     }
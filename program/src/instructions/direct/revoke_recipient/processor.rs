@@ -1,12 +1,14 @@
 use pinocchio::{account::AccountView, Address, ProgramResult};
-use pinocchio_token_2022::instructions::TransferChecked;
 
 use crate::{
     errors::RewardsProgramError,
     events::RecipientRevokedEvent,
     state::{DirectDistribution, DirectRecipient},
-    traits::{AccountSerialize, Distribution, DistributionSigner, EventSerialize, InstructionData, VestingParams},
-    utils::{close_pda_account, emit_event, get_current_timestamp, get_mint_decimals, RevokeMode},
+    traits::{Distribution, EventSerialize, InstructionData, VestingParams},
+    utils::{
+        close_pda_account, emit_event, get_current_timestamp, save_account, transfer_from_distribution_vault,
+        ClaimTransferContext, RevokeMode,
+    },
     ID,
 };
 
@@ -25,13 +27,14 @@ pub fn process_revoke_direct_recipient(
     drop(distribution_data);
 
     distribution.validate_authority(ix.accounts.authority.address())?;
+    distribution.validate_not_settled()?;
 
     if ix.data.revoke_mode.is_disabled_by(distribution.revocable) {
         return Err(RewardsProgramError::DistributionNotRevocable.into());
     }
 
     let recipient_data = ix.accounts.recipient_account.try_borrow()?;
-    let recipient = DirectRecipient::from_account(&recipient_data, ix.accounts.recipient_account, &ID)?;
+    let mut recipient = DirectRecipient::from_account(&recipient_data, ix.accounts.recipient_account, &ID)?;
     drop(recipient_data);
 
     recipient.validate_distribution(ix.accounts.distribution.address())?;
@@ -42,28 +45,27 @@ pub fn process_revoke_direct_recipient(
     }
 
     let current_ts = get_current_timestamp()?;
-    let vested_amount = VestingParams::calculate_unlocked(&recipient, current_ts)?;
+    let vesting_ts = distribution.vesting_timestamp(current_ts, recipient.base_vesting_offset);
+    let vested_amount = VestingParams::calculate_unlocked(&recipient, vesting_ts)?;
     let vested_unclaimed =
         vested_amount.checked_sub(recipient.claimed_amount).ok_or(RewardsProgramError::MathOverflow)?;
-    let unvested = recipient.total_amount.checked_sub(vested_amount).ok_or(RewardsProgramError::MathOverflow)?;
-
-    let decimals = get_mint_decimals(ix.accounts.mint)?;
+    // A frozen recipient's unvested tokens already went back to the authority
+    let unvested = recipient.entitled_amount()?.checked_sub(vested_amount).ok_or(RewardsProgramError::MathOverflow)?;
 
     let (vested_transferred, total_freed) = match ix.data.revoke_mode {
         RevokeMode::NonVested {} => {
             if vested_unclaimed > 0 {
-                distribution.with_signer(|signers| {
-                    TransferChecked {
-                        from: ix.accounts.distribution_vault,
+                transfer_from_distribution_vault(
+                    &distribution,
+                    &ClaimTransferContext {
+                        distribution_vault: ix.accounts.distribution_vault,
                         mint: ix.accounts.mint,
-                        to: ix.accounts.recipient_token_account,
-                        authority: ix.accounts.distribution,
-                        amount: vested_unclaimed,
-                        decimals,
+                        destination: ix.accounts.recipient_token_account,
+                        distribution_account: ix.accounts.distribution,
                         token_program: ix.accounts.token_program.address(),
-                    }
-                    .invoke_signed(signers)
-                })?;
+                    },
+                    vested_unclaimed,
+                )?;
             }
 
             distribution.total_allocated =
@@ -81,28 +83,41 @@ pub fn process_revoke_direct_recipient(
 
             (0, total_freed)
         }
+        RevokeMode::Freeze {} => {
+            if recipient.is_frozen() {
+                return Err(RewardsProgramError::ClaimantAlreadyRevoked.into());
+            }
+
+            distribution.total_allocated =
+                distribution.total_allocated.checked_sub(unvested).ok_or(RewardsProgramError::MathOverflow)?;
+            recipient.revoked_at = vesting_ts;
+
+            (0, unvested)
+        }
     };
 
     if total_freed > 0 {
-        distribution.with_signer(|signers| {
-            TransferChecked {
-                from: ix.accounts.distribution_vault,
+        transfer_from_distribution_vault(
+            &distribution,
+            &ClaimTransferContext {
+                distribution_vault: ix.accounts.distribution_vault,
                 mint: ix.accounts.mint,
-                to: ix.accounts.authority_token_account,
-                authority: ix.accounts.distribution,
-                amount: total_freed,
-                decimals,
+                destination: ix.accounts.authority_token_account,
+                distribution_account: ix.accounts.distribution,
                 token_program: ix.accounts.token_program.address(),
-            }
-            .invoke_signed(signers)
-        })?;
+            },
+            total_freed,
+        )?;
     }
 
-    let mut distribution_data = ix.accounts.distribution.try_borrow_mut()?;
-    distribution.write_to_slice(&mut distribution_data)?;
-    drop(distribution_data);
+    save_account(ix.accounts.distribution, &distribution, None)?;
 
-    close_pda_account(ix.accounts.recipient_account, ix.accounts.original_payer)?;
+    // A frozen recipient keeps its account to claim what had vested
+    if ix.data.revoke_mode == (RevokeMode::Freeze {}) {
+        save_account(ix.accounts.recipient_account, &recipient, None)?;
+    } else {
+        close_pda_account(ix.accounts.recipient_account, ix.accounts.original_payer)?;
+    }
 
     let event = RecipientRevokedEvent::new(
         *ix.accounts.distribution.address(),
@@ -0,0 +1,73 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_readonly, verify_signer,
+        verify_system_program, verify_writable,
+    },
+};
+
+pub struct TransferDirectRecipientAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub recipient: &'a AccountView,
+    pub new_recipient: &'a AccountView,
+    pub distribution: &'a AccountView,
+    pub recipient_account: &'a AccountView,
+    pub new_recipient_account: &'a AccountView,
+    pub original_payer: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for TransferDirectRecipientAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, recipient, new_recipient, distribution, recipient_account, new_recipient_account, original_payer, system_program, event_authority, program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(recipient, false)?;
+
+        // 2. Validate writable
+        verify_writable(recipient_account, true)?;
+        verify_writable(new_recipient_account, true)?;
+        verify_writable(original_payer, true)?;
+
+        // 2b. Validate read-only accounts
+        verify_readonly(new_recipient)?;
+        verify_readonly(distribution)?;
+
+        // 3. Validate program IDs
+        verify_system_program(system_program)?;
+        verify_current_program(program)?;
+        verify_event_authority(event_authority)?;
+
+        // 4. Validate accounts owned by current program
+        // new_recipient_account is created by this instruction and must not exist yet
+        verify_current_program_account(distribution)?;
+        verify_current_program_account(recipient_account)?;
+
+        Ok(Self {
+            payer,
+            recipient,
+            new_recipient,
+            distribution,
+            recipient_account,
+            new_recipient_account,
+            original_payer,
+            system_program,
+            event_authority,
+            program,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for TransferDirectRecipientAccounts<'a> {}
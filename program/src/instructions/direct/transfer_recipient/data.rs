@@ -0,0 +1,42 @@
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, traits::InstructionData};
+
+/// Instruction data for TransferDirectRecipient
+///
+/// - `bump`: Bump for the new recipient's DirectRecipient PDA.
+pub struct TransferDirectRecipientData {
+    pub bump: u8,
+}
+
+impl<'a> TryFrom<&'a [u8]> for TransferDirectRecipientData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        Ok(Self { bump: data[0] })
+    }
+}
+
+impl<'a> InstructionData<'a> for TransferDirectRecipientData {
+    const LEN: usize = 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_valid_data() {
+        let result = TransferDirectRecipientData::try_from(&[254u8][..]).unwrap();
+        assert_eq!(result.bump, 254);
+    }
+
+    #[test]
+    fn test_try_from_insufficient_data() {
+        let result = TransferDirectRecipientData::try_from(&[][..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
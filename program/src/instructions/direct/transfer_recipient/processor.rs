@@ -0,0 +1,89 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    events::RecipientTransferredEvent,
+    state::{DirectDistribution, DirectRecipient},
+    traits::{AccountSerialize, Distribution, EventSerialize, PdaSeeds},
+    utils::{close_pda_account, create_pda_account, emit_event},
+    ID,
+};
+
+use super::TransferDirectRecipient;
+
+pub fn process_transfer_direct_recipient(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = TransferDirectRecipient::try_from((instruction_data, accounts))?;
+
+    let distribution_data = ix.accounts.distribution.try_borrow()?;
+    let distribution = DirectDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    drop(distribution_data);
+
+    distribution.validate_transferable()?;
+
+    let recipient_data = ix.accounts.recipient_account.try_borrow()?;
+    let recipient = DirectRecipient::from_account(&recipient_data, ix.accounts.recipient_account, &ID)?;
+    drop(recipient_data);
+
+    recipient.validate_distribution(ix.accounts.distribution.address())?;
+    recipient.validate_recipient(ix.accounts.recipient.address())?;
+
+    // Validate that the payer account matches the one stored in the recipient
+    if &recipient.payer != ix.accounts.original_payer.address() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let new_recipient_address = ix.accounts.new_recipient.address();
+    if new_recipient_address == &Address::default() || new_recipient_address == ix.accounts.recipient.address() {
+        return Err(RewardsProgramError::InvalidNewRecipient.into());
+    }
+
+    // The new position carries over the full allocation, claimed progress, schedule curve, freeze and pause shifts
+    let account_size = DirectRecipient::calculate_account_size(&recipient.schedule);
+    let mut new_recipient = DirectRecipient::new(
+        ix.data.bump,
+        *ix.accounts.distribution.address(),
+        *new_recipient_address,
+        *ix.accounts.payer.address(),
+        recipient.total_amount,
+        recipient.schedule,
+    );
+    new_recipient.claimed_amount = recipient.claimed_amount;
+    new_recipient.revoked_at = recipient.revoked_at;
+    new_recipient.schedule_amount = recipient.schedule_amount;
+    new_recipient.base_vesting_offset = recipient.base_vesting_offset;
+
+    new_recipient.validate_pda(ix.accounts.new_recipient_account, &ID, ix.data.bump)?;
+
+    let bump_seed = [ix.data.bump];
+    let new_recipient_seeds = new_recipient.seeds_with_bump(&bump_seed);
+    let new_recipient_seeds_array: [_; 4] =
+        new_recipient_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    create_pda_account(
+        ix.accounts.payer,
+        account_size,
+        &ID,
+        ix.accounts.new_recipient_account,
+        new_recipient_seeds_array,
+    )?;
+
+    let mut new_recipient_data = ix.accounts.new_recipient_account.try_borrow_mut()?;
+    new_recipient.write_to_slice(&mut new_recipient_data)?;
+    drop(new_recipient_data);
+
+    // Return rent to the original payer who created the old recipient account
+    close_pda_account(ix.accounts.recipient_account, ix.accounts.original_payer)?;
+
+    let event = RecipientTransferredEvent::new(
+        *ix.accounts.distribution.address(),
+        *ix.accounts.recipient.address(),
+        *new_recipient_address,
+    );
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use pinocchio::error::ProgramError;
+
+use crate::{errors::RewardsProgramError, require_len, traits::InstructionData};
+
+/// Instruction data for FundDistribution
+///
+/// - `amount`: Number of tokens to transfer from the funder into the distribution vault.
+pub struct FundDistributionData {
+    pub amount: u64,
+}
+
+impl<'a> TryFrom<&'a [u8]> for FundDistributionData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let amount = u64::from_le_bytes(data[0..8].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        Ok(Self { amount })
+    }
+}
+
+impl<'a> InstructionData<'a> for FundDistributionData {
+    const LEN: usize = 8;
+
+    fn validate(&self) -> Result<(), ProgramError> {
+        if self.amount == 0 {
+            return Err(RewardsProgramError::InvalidAmount.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_valid_data() {
+        let data = 1000u64.to_le_bytes();
+        let result = FundDistributionData::try_from(&data[..]).unwrap();
+        assert_eq!(result.amount, 1000);
+    }
+
+    #[test]
+    fn test_try_from_data_too_short() {
+        let data = [0u8; 7];
+        let result = FundDistributionData::try_from(&data[..]);
+        assert_eq!(result.err(), Some(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_validate_success() {
+        let data = FundDistributionData { amount: 1 };
+        assert!(data.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_zero_amount() {
+        let data = FundDistributionData { amount: 0 };
+        assert_eq!(data.validate().err(), Some(RewardsProgramError::InvalidAmount.into()));
+    }
+}
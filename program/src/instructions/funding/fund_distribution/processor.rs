@@ -0,0 +1,70 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    events::DistributionFundedEvent,
+    state::{CompressedDistribution, DirectDistribution, MerkleDistribution},
+    traits::{Distribution, EventSerialize, InstructionData},
+    utils::{
+        deposit_to_distribution_vault, emit_event, get_distribution_kind, load_distribution, save_distribution,
+        DistributionKind, VaultDepositContext,
+    },
+    ID,
+};
+
+use super::FundDistribution;
+
+pub fn process_fund_distribution(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = FundDistribution::try_from((instruction_data, accounts))?;
+    ix.data.validate()?;
+
+    let kind = get_distribution_kind(ix.accounts.distribution)?;
+    // Transfer fees are withheld in the vault, so record what actually arrived
+    let received = deposit_to_distribution_vault(
+        &VaultDepositContext {
+            source: ix.accounts.funder_token_account,
+            authority: ix.accounts.funder,
+            distribution_vault: ix.accounts.distribution_vault,
+            mint: ix.accounts.mint,
+            token_program: ix.accounts.token_program,
+        },
+        ix.data.amount,
+    )?;
+    if received == 0 {
+        return Err(RewardsProgramError::InvalidAmount.into());
+    }
+
+    let total_funded = match kind {
+        DistributionKind::Direct => record_funding::<DirectDistribution>(&ix, received)?,
+        DistributionKind::Merkle => record_funding::<MerkleDistribution>(&ix, received)?,
+        DistributionKind::Compressed => record_funding::<CompressedDistribution>(&ix, received)?,
+    };
+
+    let event = DistributionFundedEvent::new(
+        *ix.accounts.distribution.address(),
+        *ix.accounts.funder.address(),
+        received,
+        total_funded,
+    );
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
+
+/// Adds the deposit to the distribution's funded total and returns the new total.
+fn record_funding<D: Distribution>(ix: &FundDistribution, received: u64) -> Result<u64, ProgramError> {
+    let mut distribution: D = load_distribution(ix.accounts.distribution)?;
+    if distribution.mint() != ix.accounts.mint.address() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    distribution.validate_not_settled()?;
+
+    distribution.add_funded(received)?;
+    save_distribution(ix.accounts.distribution, &distribution)?;
+
+    Ok(distribution.total_funded())
+}
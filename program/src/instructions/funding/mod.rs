@@ -0,0 +1 @@
+pub mod fund_distribution;
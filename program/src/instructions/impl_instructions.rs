@@ -1,32 +1,128 @@
 use crate::define_instruction;
 
+use super::authority::{
+    accept_authority::{AcceptAuthorityAccounts, AcceptAuthorityData},
+    cancel_authority_proposal::{CancelAuthorityProposalAccounts, CancelAuthorityProposalData},
+    propose_authority::{ProposeAuthorityAccounts, ProposeAuthorityData},
+};
+use super::basket::{
+    add_recipient::{AddBasketRecipientAccounts, AddBasketRecipientData},
+    claim::{ClaimBasketAccounts, ClaimBasketData},
+    close_distribution::{CloseBasketDistributionAccounts, CloseBasketDistributionData},
+    close_recipient::{CloseBasketRecipientAccounts, CloseBasketRecipientData},
+    create_distribution::{CreateBasketDistributionAccounts, CreateBasketDistributionData},
+};
+use super::compressed::{
+    add_recipient::{AddCompressedRecipientAccounts, AddCompressedRecipientData},
+    claim::{ClaimCompressedAccounts, ClaimCompressedData},
+    close_distribution::{CloseCompressedDistributionAccounts, CloseCompressedDistributionData},
+    create_distribution::{CreateCompressedDistributionAccounts, CreateCompressedDistributionData},
+};
+use super::delegate::{
+    remove_claim_delegate::{RemoveClaimDelegateAccounts, RemoveClaimDelegateData},
+    set_claim_delegate::{SetClaimDelegateAccounts, SetClaimDelegateData},
+};
 use super::direct::{
     add_recipient::{AddDirectRecipientAccounts, AddDirectRecipientData},
+    add_recipients::{AddDirectRecipientsAccounts, AddDirectRecipientsData},
     claim::{ClaimDirectAccounts, ClaimDirectData},
+    claim_many::{ClaimDirectManyAccounts, ClaimDirectManyData},
     close_distribution::{CloseDirectDistributionAccounts, CloseDirectDistributionData},
     close_recipient::{CloseDirectRecipientAccounts, CloseDirectRecipientData},
+    crank_claim::{CrankClaimDirectAccounts, CrankClaimDirectData},
     create_distribution::{CreateDirectDistributionAccounts, CreateDirectDistributionData},
+    decrease_allocation::{DecreaseDirectAllocationAccounts, DecreaseDirectAllocationData},
+    increase_allocation::{IncreaseDirectAllocationAccounts, IncreaseDirectAllocationData},
     revoke_recipient::{RevokeDirectRecipientAccounts, RevokeDirectRecipientData},
+    transfer_recipient::{TransferDirectRecipientAccounts, TransferDirectRecipientData},
 };
+use super::funding::fund_distribution::{FundDistributionAccounts, FundDistributionData};
 use super::merkle::{
     claim::{ClaimMerkleAccounts, ClaimMerkleData},
+    claim_bitmap::{ClaimMerkleBitmapAccounts, ClaimMerkleBitmapData},
     close_claim::{CloseMerkleClaimAccounts, CloseMerkleClaimData},
+    close_claim_bitmap::{CloseMerkleClaimBitmapAccounts, CloseMerkleClaimBitmapData},
+    close_claim_early::{CloseMerkleClaimEarlyAccounts, CloseMerkleClaimEarlyData},
     close_distribution::{CloseMerkleDistributionAccounts, CloseMerkleDistributionData},
+    crank_claim::{CrankClaimMerkleAccounts, CrankClaimMerkleData},
+    create_claim_bitmap::{CreateMerkleClaimBitmapAccounts, CreateMerkleClaimBitmapData},
     create_distribution::{CreateMerkleDistributionAccounts, CreateMerkleDistributionData},
     revoke_claim::{RevokeMerkleClaimAccounts, RevokeMerkleClaimData},
+    transfer_claim::{TransferMerkleClaimAccounts, TransferMerkleClaimData},
+    update_root::{UpdateMerkleRootAccounts, UpdateMerkleRootData},
 };
+use super::migration::migrate_account::{MigrateAccountAccounts, MigrateAccountData};
+use super::pause::{
+    pause_distribution::{PauseDistributionAccounts, PauseDistributionData},
+    unpause_distribution::{UnpauseDistributionAccounts, UnpauseDistributionData},
+};
+use super::transfer::set_transferable::{SetTransferableAccounts, SetTransferableData};
 
 // Direct Distribution
 define_instruction!(AddDirectRecipient, AddDirectRecipientAccounts, AddDirectRecipientData);
+define_instruction!(AddDirectRecipients, AddDirectRecipientsAccounts, AddDirectRecipientsData);
 define_instruction!(ClaimDirect, ClaimDirectAccounts, ClaimDirectData);
+define_instruction!(ClaimDirectMany, ClaimDirectManyAccounts, ClaimDirectManyData);
 define_instruction!(CloseDirectDistribution, CloseDirectDistributionAccounts, CloseDirectDistributionData);
 define_instruction!(CloseDirectRecipient, CloseDirectRecipientAccounts, CloseDirectRecipientData);
+define_instruction!(CrankClaimDirect, CrankClaimDirectAccounts, CrankClaimDirectData);
 define_instruction!(CreateDirectDistribution, CreateDirectDistributionAccounts, CreateDirectDistributionData);
+define_instruction!(DecreaseDirectAllocation, DecreaseDirectAllocationAccounts, DecreaseDirectAllocationData);
+define_instruction!(IncreaseDirectAllocation, IncreaseDirectAllocationAccounts, IncreaseDirectAllocationData);
 define_instruction!(RevokeDirectRecipient, RevokeDirectRecipientAccounts, RevokeDirectRecipientData);
+define_instruction!(TransferDirectRecipient, TransferDirectRecipientAccounts, TransferDirectRecipientData);
 
 // Merkle Distribution
 define_instruction!(ClaimMerkle, ClaimMerkleAccounts, ClaimMerkleData);
 define_instruction!(CloseMerkleClaim, CloseMerkleClaimAccounts, CloseMerkleClaimData);
+define_instruction!(CloseMerkleClaimEarly, CloseMerkleClaimEarlyAccounts, CloseMerkleClaimEarlyData);
 define_instruction!(CloseMerkleDistribution, CloseMerkleDistributionAccounts, CloseMerkleDistributionData);
+define_instruction!(CrankClaimMerkle, CrankClaimMerkleAccounts, CrankClaimMerkleData);
 define_instruction!(CreateMerkleDistribution, CreateMerkleDistributionAccounts, CreateMerkleDistributionData);
 define_instruction!(RevokeMerkleClaim, RevokeMerkleClaimAccounts, RevokeMerkleClaimData);
+define_instruction!(TransferMerkleClaim, TransferMerkleClaimAccounts, TransferMerkleClaimData);
+define_instruction!(UpdateMerkleRoot, UpdateMerkleRootAccounts, UpdateMerkleRootData);
+
+// Authority
+define_instruction!(AcceptAuthority, AcceptAuthorityAccounts, AcceptAuthorityData);
+define_instruction!(CancelAuthorityProposal, CancelAuthorityProposalAccounts, CancelAuthorityProposalData);
+define_instruction!(ProposeAuthority, ProposeAuthorityAccounts, ProposeAuthorityData);
+
+// Funding
+define_instruction!(FundDistribution, FundDistributionAccounts, FundDistributionData);
+
+// Claim Delegate
+define_instruction!(RemoveClaimDelegate, RemoveClaimDelegateAccounts, RemoveClaimDelegateData);
+define_instruction!(SetClaimDelegate, SetClaimDelegateAccounts, SetClaimDelegateData);
+
+// Position Transfer
+define_instruction!(SetTransferable, SetTransferableAccounts, SetTransferableData);
+
+// Pause
+define_instruction!(PauseDistribution, PauseDistributionAccounts, PauseDistributionData);
+define_instruction!(UnpauseDistribution, UnpauseDistributionAccounts, UnpauseDistributionData);
+
+// Basket Distribution
+define_instruction!(AddBasketRecipient, AddBasketRecipientAccounts, AddBasketRecipientData);
+define_instruction!(ClaimBasket, ClaimBasketAccounts, ClaimBasketData);
+define_instruction!(CloseBasketDistribution, CloseBasketDistributionAccounts, CloseBasketDistributionData);
+define_instruction!(CloseBasketRecipient, CloseBasketRecipientAccounts, CloseBasketRecipientData);
+define_instruction!(CreateBasketDistribution, CreateBasketDistributionAccounts, CreateBasketDistributionData);
+
+// Merkle Bitmap Claims
+define_instruction!(ClaimMerkleBitmap, ClaimMerkleBitmapAccounts, ClaimMerkleBitmapData);
+define_instruction!(CloseMerkleClaimBitmap, CloseMerkleClaimBitmapAccounts, CloseMerkleClaimBitmapData);
+define_instruction!(CreateMerkleClaimBitmap, CreateMerkleClaimBitmapAccounts, CreateMerkleClaimBitmapData);
+
+// Compressed Distribution
+define_instruction!(AddCompressedRecipient, AddCompressedRecipientAccounts, AddCompressedRecipientData);
+define_instruction!(ClaimCompressed, ClaimCompressedAccounts, ClaimCompressedData);
+define_instruction!(CloseCompressedDistribution, CloseCompressedDistributionAccounts, CloseCompressedDistributionData);
+define_instruction!(
+    CreateCompressedDistribution,
+    CreateCompressedDistributionAccounts,
+    CreateCompressedDistributionData
+);
+
+// Migration
+define_instruction!(MigrateAccount, MigrateAccountAccounts, MigrateAccountData);
@@ -3,9 +3,9 @@ use pinocchio::{account::AccountView, error::ProgramError};
 use crate::{
     traits::InstructionAccounts,
     utils::{
-        validate_associated_token_account, verify_current_program, verify_current_program_account,
-        verify_event_authority, verify_owned_by, verify_readonly, verify_signer, verify_system_program,
-        verify_token_program, verify_writable,
+        validate_distribution_vault, verify_current_program, verify_current_program_account, verify_event_authority,
+        verify_mint_owner, verify_owned_by, verify_readonly, verify_signer, verify_system_program,
+        verify_token_program_for_mint, verify_writable,
     },
 };
 
@@ -15,6 +15,7 @@ pub struct ClaimMerkleAccounts<'a> {
     pub distribution: &'a AccountView,
     pub claim_account: &'a AccountView,
     pub revocation_account: &'a AccountView,
+    pub redirect_account: &'a AccountView,
     pub mint: &'a AccountView,
     pub distribution_vault: &'a AccountView,
     pub claimant_token_account: &'a AccountView,
@@ -29,7 +30,7 @@ impl<'a> TryFrom<&'a [AccountView]> for ClaimMerkleAccounts<'a> {
 
     #[inline(always)]
     fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
-        let [payer, claimant, distribution, claim_account, revocation_account, mint, distribution_vault, claimant_token_account, system_program, token_program, event_authority, program] =
+        let [payer, claimant, distribution, claim_account, revocation_account, redirect_account, mint, distribution_vault, claimant_token_account, system_program, token_program, event_authority, program] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
@@ -47,11 +48,12 @@ impl<'a> TryFrom<&'a [AccountView]> for ClaimMerkleAccounts<'a> {
 
         // 2b. Validate read-only accounts
         verify_readonly(revocation_account)?;
+        verify_readonly(redirect_account)?;
         verify_readonly(mint)?;
 
         // 3. Validate program IDs
         verify_system_program(system_program)?;
-        verify_token_program(token_program)?;
+        verify_token_program_for_mint(token_program, mint)?;
         verify_current_program(program)?;
         verify_event_authority(event_authority)?;
 
@@ -60,11 +62,11 @@ impl<'a> TryFrom<&'a [AccountView]> for ClaimMerkleAccounts<'a> {
         // claim_account may not exist yet (will be created idempotently)
 
         // 5. Validate token account ownership
-        verify_owned_by(mint, token_program.address())?;
+        verify_mint_owner(mint, token_program)?;
         verify_owned_by(claimant_token_account, token_program.address())?;
 
-        // 6. Validate distribution_vault ATA
-        validate_associated_token_account(distribution_vault, distribution.address(), mint, token_program)?;
+        // 6. Validate distribution_vault (ATA, or native vault PDA)
+        validate_distribution_vault(distribution_vault, distribution.address(), mint, token_program)?;
 
         Ok(Self {
             payer,
@@ -72,6 +74,7 @@ impl<'a> TryFrom<&'a [AccountView]> for ClaimMerkleAccounts<'a> {
             distribution,
             claim_account,
             revocation_account,
+            redirect_account,
             mint,
             distribution_vault,
             claimant_token_account,
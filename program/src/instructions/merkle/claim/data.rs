@@ -4,7 +4,7 @@ use pinocchio::error::ProgramError;
 use crate::{
     require_len,
     traits::{InstructionData, VestingParams},
-    utils::VestingSchedule,
+    utils::{TransferFeeMode, VestingSchedule},
 };
 
 /// Instruction data for ClaimMerkle.
@@ -17,6 +17,8 @@ pub struct ClaimMerkleData {
     pub total_amount: u64,
     /// Amount to claim (0 = claim all available)
     pub amount: u64,
+    /// Whether `amount` is sent from the vault (Gross) or received by the claimant (Net)
+    pub fee_mode: TransferFeeMode,
     /// Vesting schedule (from merkle leaf, variable length)
     pub schedule: VestingSchedule,
     /// Merkle proof (variable length)
@@ -28,18 +30,19 @@ impl<'a> TryFrom<&'a [u8]> for ClaimMerkleData {
 
     #[inline(always)]
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        // Minimum length: claim_bump(1) + total_amount(8) + amount(8) + schedule(1) + proof_len(4) = 22
+        // Minimum length: claim_bump(1) + total_amount(8) + amount(8) + fee_mode(1) + schedule(1) + proof_len(4) = 23
         require_len!(data, Self::LEN);
 
         // Fixed-size fields at known offsets
         let claim_bump = data[0];
         let total_amount = u64::from_le_bytes(data[1..9].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
         let amount = u64::from_le_bytes(data[9..17].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        let fee_mode = TransferFeeMode::try_from(data[17])?;
 
         // Variable-length fields
-        let (schedule, schedule_len) = VestingSchedule::from_bytes(&data[17..])?;
+        let (schedule, schedule_len) = VestingSchedule::from_bytes(&data[18..])?;
 
-        let proof_offset = 17 + schedule_len;
+        let proof_offset = 18 + schedule_len;
         require_len!(data, proof_offset + 4); // proof_len(4)
 
         let proof_len = u32::from_le_bytes(
@@ -58,13 +61,13 @@ impl<'a> TryFrom<&'a [u8]> for ClaimMerkleData {
             proof.push(hash);
         }
 
-        Ok(Self { claim_bump, total_amount, amount, schedule, proof })
+        Ok(Self { claim_bump, total_amount, amount, fee_mode, schedule, proof })
     }
 }
 
 impl<'a> InstructionData<'a> for ClaimMerkleData {
-    // claim_bump(1) + total_amount(8) + amount(8) + min_schedule(1) + proof_len(4) = 22
-    const LEN: usize = 22;
+    // claim_bump(1) + total_amount(8) + amount(8) + fee_mode(1) + min_schedule(1) + proof_len(4) = 23
+    const LEN: usize = 23;
 
     // No validate() override needed: the schedule is part of the merkle leaf
     // verified against the on-chain root in the processor. An invalid schedule
@@ -78,8 +81,8 @@ impl VestingParams for ClaimMerkleData {
     }
 
     #[inline(always)]
-    fn vesting_schedule(&self) -> VestingSchedule {
-        self.schedule
+    fn vesting_schedule(&self) -> &VestingSchedule {
+        &self.schedule
     }
 }
 
@@ -94,7 +97,8 @@ mod tests {
         data.push(255); // claim_bump
         data.extend_from_slice(&1000u64.to_le_bytes()); // total_amount
         data.extend_from_slice(&amount.to_le_bytes()); // amount
-                                                       // Variable-length fields
+        data.push(0); // fee_mode
+                      // Variable-length fields
         data.extend_from_slice(&schedule_bytes); // schedule
         data.extend_from_slice(&(proof.len() as u32).to_le_bytes()); // proof_len
         for p in proof {
@@ -118,7 +122,7 @@ mod tests {
     fn test_try_from_linear_with_proof() {
         let schedule = VestingSchedule::Linear { start_ts: 100, end_ts: 200 };
         let proof = [[1u8; 32], [2u8; 32]];
-        let data = build_data(schedule, 0, &proof);
+        let data = build_data(schedule.clone(), 0, &proof);
         let parsed = ClaimMerkleData::try_from(&data[..]).unwrap();
         assert_eq!(parsed.schedule, schedule);
         assert_eq!(parsed.amount, 0);
@@ -130,12 +134,24 @@ mod tests {
     #[test]
     fn test_try_from_cliff_linear() {
         let schedule = VestingSchedule::CliffLinear { start_ts: 0, cliff_ts: 100, end_ts: 400 };
-        let data = build_data(schedule, 250, &[]);
+        let data = build_data(schedule.clone(), 250, &[]);
         let parsed = ClaimMerkleData::try_from(&data[..]).unwrap();
         assert_eq!(parsed.schedule, schedule);
         assert_eq!(parsed.amount, 250);
     }
 
+    #[test]
+    fn test_try_from_fee_mode() {
+        let mut data = build_data(VestingSchedule::Immediate {}, 500, &[]);
+        assert_eq!(ClaimMerkleData::try_from(&data[..]).unwrap().fee_mode, TransferFeeMode::Gross {});
+
+        data[17] = 1;
+        assert_eq!(ClaimMerkleData::try_from(&data[..]).unwrap().fee_mode, TransferFeeMode::Net {});
+
+        data[17] = 2;
+        assert!(ClaimMerkleData::try_from(&data[..]).is_err());
+    }
+
     #[test]
     fn test_try_from_data_too_short() {
         let data = [0u8; 10];
@@ -151,6 +167,7 @@ mod tests {
         data.push(255); // claim_bump
         data.extend_from_slice(&1000u64.to_le_bytes()); // total_amount
         data.extend_from_slice(&0u64.to_le_bytes()); // amount
+        data.push(0); // fee_mode
         data.extend_from_slice(&schedule_bytes);
         data.extend_from_slice(&2u32.to_le_bytes()); // claim 2 proofs but don't include them
         let result = ClaimMerkleData::try_from(&data[..]);
@@ -168,13 +185,13 @@ mod tests {
     fn test_vesting_params_linear() {
         let data = build_data(VestingSchedule::Linear { start_ts: 100, end_ts: 200 }, 500, &[]);
         let parsed = ClaimMerkleData::try_from(&data[..]).unwrap();
-        assert_eq!(parsed.vesting_schedule(), VestingSchedule::Linear { start_ts: 100, end_ts: 200 });
+        assert_eq!(parsed.vesting_schedule(), &VestingSchedule::Linear { start_ts: 100, end_ts: 200 });
     }
 
     #[test]
     fn test_vesting_params_immediate() {
         let data = build_data(VestingSchedule::Immediate {}, 0, &[]);
         let parsed = ClaimMerkleData::try_from(&data[..]).unwrap();
-        assert_eq!(parsed.vesting_schedule(), VestingSchedule::Immediate {});
+        assert_eq!(parsed.vesting_schedule(), &VestingSchedule::Immediate {});
     }
 }
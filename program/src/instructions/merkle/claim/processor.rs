@@ -1,120 +1,141 @@
 use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
-use pinocchio_token_2022::instructions::TransferChecked;
 
 use crate::{
     errors::RewardsProgramError,
     events::ClaimedEvent,
-    state::{MerkleClaim, MerkleClaimSeeds, MerkleDistribution, MerkleRevocationSeeds},
-    traits::{
-        AccountParse, AccountSerialize, AccountSize, ClaimTracker, Distribution, DistributionSigner, EventSerialize,
-        PdaSeeds, VestingParams,
-    },
+    state::{MerkleClaim, MerkleClaimSeeds, MerkleDistribution, MerkleRedirectSeeds, MerkleRevocationSeeds},
+    traits::{AccountParse, AccountSize, ClaimTracker, Distribution, EventSerialize, PdaSeeds, VestingParams},
     utils::{
-        compute_leaf_hash, create_pda_account_idempotent, emit_event, get_current_timestamp, get_mint_decimals,
-        is_pda_uninitialized, resolve_claim_amount, verify_proof_or_error,
+        compute_leaf_hash, create_pda_account_idempotent, emit_event, get_current_timestamp, is_pda_uninitialized,
+        resolve_claim_amount, resolve_gross_amount, save_account, transfer_from_distribution_vault,
+        verify_proof_or_error, verify_vault_balance, ClaimTransferContext, MerkleClaimMode,
     },
     ID,
 };
 
-use super::ClaimMerkle;
+use super::{ClaimMerkle, ClaimMerkleAccounts, ClaimMerkleData};
 
 pub fn process_claim_merkle(_program_id: &Address, accounts: &[AccountView], instruction_data: &[u8]) -> ProgramResult {
     let ix = ClaimMerkle::try_from((instruction_data, accounts))?;
 
+    // A transferred position can only be claimed by its new owner via CrankClaimMerkle
+    let redirect_seeds = MerkleRedirectSeeds {
+        distribution: *ix.accounts.distribution.address(),
+        claimant: *ix.accounts.claimant.address(),
+    };
+    redirect_seeds.validate_pda_address(ix.accounts.redirect_account, &ID)?;
+
+    if !is_pda_uninitialized(ix.accounts.redirect_account) {
+        return Err(RewardsProgramError::ClaimantRedirected.into());
+    }
+
+    execute_claim_merkle(&ix.accounts, &ix.data)
+}
+
+/// Claim vested tokens for `accounts.claimant` into `accounts.claimant_token_account`.
+///
+/// Shared by `ClaimMerkle` and `CrankClaimMerkle`; callers authorize the claim and its destination.
+pub(crate) fn execute_claim_merkle(accounts: &ClaimMerkleAccounts, data: &ClaimMerkleData) -> ProgramResult {
     let current_ts = get_current_timestamp()?;
 
-    let distribution_data = ix.accounts.distribution.try_borrow()?;
-    let mut distribution = MerkleDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    let distribution_data = accounts.distribution.try_borrow()?;
+    let mut distribution = MerkleDistribution::from_account(&distribution_data, accounts.distribution, &ID)?;
     drop(distribution_data);
 
-    let schedule_bytes = ix.data.schedule.to_bytes();
-    let leaf = compute_leaf_hash(ix.accounts.claimant.address(), ix.data.total_amount, &schedule_bytes);
-    verify_proof_or_error(&ix.data.proof, &distribution.merkle_root, &leaf)?;
+    distribution.validate_claim_mode(MerkleClaimMode::ClaimAccount {})?;
+    distribution.validate_not_paused()?;
+    distribution.validate_claim_window(current_ts)?;
+
+    let schedule_bytes = data.schedule.to_bytes();
+    let leaf = compute_leaf_hash(accounts.claimant.address(), data.total_amount, &schedule_bytes);
+    verify_proof_or_error(&data.proof, &distribution.merkle_root, &leaf)?;
 
     // Check if claimant has been revoked
     let revocation_seeds = MerkleRevocationSeeds {
-        distribution: *ix.accounts.distribution.address(),
-        claimant: *ix.accounts.claimant.address(),
+        distribution: *accounts.distribution.address(),
+        claimant: *accounts.claimant.address(),
     };
-    revocation_seeds.validate_pda_address(ix.accounts.revocation_account, &ID)?;
+    revocation_seeds.validate_pda_address(accounts.revocation_account, &ID)?;
 
-    if !is_pda_uninitialized(ix.accounts.revocation_account) {
+    if !is_pda_uninitialized(accounts.revocation_account) {
         return Err(RewardsProgramError::ClaimantAlreadyRevoked.into());
     }
 
-    let claim_seeds = MerkleClaimSeeds {
-        distribution: *ix.accounts.distribution.address(),
-        claimant: *ix.accounts.claimant.address(),
-    };
+    let claim_seeds =
+        MerkleClaimSeeds { distribution: *accounts.distribution.address(), claimant: *accounts.claimant.address() };
 
-    claim_seeds.validate_pda(ix.accounts.claim_account, &ID, ix.data.claim_bump)?;
+    claim_seeds.validate_pda(accounts.claim_account, &ID, data.claim_bump)?;
 
-    let claim_bump_seed = [ix.data.claim_bump];
+    let claim_bump_seed = [data.claim_bump];
     let claim_pda_seeds = claim_seeds.seeds_with_bump(&claim_bump_seed);
     let claim_pda_seeds_array: [_; 4] = claim_pda_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
 
     // Unlike direct distributions where the authority creates recipient accounts upfront,
     // merkle claim accounts are created on the claimant's first claim. Check if this PDA
     // is uninitialized (owned by the system program) to determine if it needs creation.
-    let is_new_claim = is_pda_uninitialized(ix.accounts.claim_account);
+    let is_new_claim = is_pda_uninitialized(accounts.claim_account);
 
     let mut claim = if is_new_claim {
         create_pda_account_idempotent(
-            ix.accounts.payer,
+            accounts.payer,
             MerkleClaim::LEN,
             &ID,
-            ix.accounts.claim_account,
+            accounts.claim_account,
             claim_pda_seeds_array,
         )?;
 
-        let claim = MerkleClaim::new(ix.data.claim_bump);
-        let mut claim_data = ix.accounts.claim_account.try_borrow_mut()?;
-        claim.write_to_slice(&mut claim_data)?;
-        drop(claim_data);
+        let claim = MerkleClaim::new(data.claim_bump, *accounts.payer.address());
+        save_account(accounts.claim_account, &claim, Some(accounts.payer))?;
         claim
     } else {
-        let claim_data = ix.accounts.claim_account.try_borrow()?;
-        let claim = MerkleClaim::parse_from_bytes(&claim_data)?;
+        let claim_data = accounts.claim_account.try_borrow()?;
+        let mut claim = MerkleClaim::parse_from_bytes(&claim_data)?;
         drop(claim_data);
+
+        // A claim closed early keeps its claimed amount, and the claimant keeps paying its rent
+        if claim.is_closed() {
+            claim.reopen();
+        }
         claim
     };
 
     // Calculate how much the claimant can claim right now:
     // 1. calculate_unlocked: total tokens unlocked by the vesting schedule at current_ts
-    // 2. claimable_amount: unlocked minus already claimed (via ClaimTracker)
-    // 3. resolve_claim_amount: if amount == 0 claim everything available, else validate request
-    let unlocked_amount = VestingParams::calculate_unlocked(&ix.data, current_ts)?;
-    let claimable_amount = ClaimTracker::claimable_amount(&claim, unlocked_amount)?;
-    let claim_amount = resolve_claim_amount(ix.data.amount, claimable_amount)?;
+    //    (shifted back by any paused periods the authority excluded from vesting)
+    // 2. claimable_amount: unlocked minus already claimed. Saturates at zero because a root
+    //    update may lower a cumulative leaf below what the claimant already claimed.
+    // 3. resolve_gross_amount: in Net mode, gross up the request to cover the mint's transfer fee
+    // 4. resolve_claim_amount: if amount == 0 claim everything available, else validate request
+    let unlocked_amount = VestingParams::calculate_unlocked(
+        data,
+        distribution.vesting_timestamp(current_ts, data.base_vesting_offset()),
+    )?;
+    let claimable_amount = unlocked_amount.saturating_sub(ClaimTracker::claimed_amount(&claim));
+    let requested_amount = resolve_gross_amount(accounts.mint, data.amount, data.fee_mode)?;
+    let claim_amount = resolve_claim_amount(requested_amount, claimable_amount)?;
+    verify_vault_balance(accounts.distribution_vault, claim_amount)?;
 
     ClaimTracker::add_claimed(&mut claim, claim_amount)?;
     Distribution::add_claimed(&mut distribution, claim_amount)?;
 
-    let mut claim_data = ix.accounts.claim_account.try_borrow_mut()?;
-    claim.write_to_slice(&mut claim_data)?;
-    drop(claim_data);
+    save_account(accounts.claim_account, &claim, Some(accounts.payer))?;
 
-    let mut distribution_data = ix.accounts.distribution.try_borrow_mut()?;
-    distribution.write_to_slice(&mut distribution_data)?;
-    drop(distribution_data);
+    save_account(accounts.distribution, &distribution, Some(accounts.payer))?;
 
-    let decimals = get_mint_decimals(ix.accounts.mint)?;
-
-    distribution.with_signer(|signers| {
-        TransferChecked {
-            from: ix.accounts.distribution_vault,
-            mint: ix.accounts.mint,
-            to: ix.accounts.claimant_token_account,
-            authority: ix.accounts.distribution,
-            amount: claim_amount,
-            decimals,
-            token_program: ix.accounts.token_program.address(),
-        }
-        .invoke_signed(signers)
-    })?;
+    transfer_from_distribution_vault(
+        &distribution,
+        &ClaimTransferContext {
+            distribution_vault: accounts.distribution_vault,
+            mint: accounts.mint,
+            destination: accounts.claimant_token_account,
+            distribution_account: accounts.distribution,
+            token_program: accounts.token_program.address(),
+        },
+        claim_amount,
+    )?;
 
-    let event = ClaimedEvent::new(*ix.accounts.distribution.address(), *ix.accounts.claimant.address(), claim_amount);
-    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+    let event = ClaimedEvent::new(*accounts.distribution.address(), *accounts.claimant.address(), claim_amount);
+    emit_event(&ID, accounts.event_authority, accounts.program, &event.to_bytes())?;
 
     Ok(())
 }
@@ -0,0 +1,112 @@
+use alloc::vec::Vec;
+use pinocchio::error::ProgramError;
+
+use crate::{errors::RewardsProgramError, require_len, traits::InstructionData};
+
+/// Instruction data for ClaimMerkleBitmap.
+///
+/// Fixed-size fields first, then the variable-length proof.
+pub struct ClaimMerkleBitmapData {
+    /// Leaf index (from merkle leaf); selects the bit that records the claim
+    pub index: u32,
+    /// Amount allocated to this claimant (from merkle leaf); always claimed in full
+    pub amount: u64,
+    /// Merkle proof (variable length)
+    pub proof: Vec<[u8; 32]>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for ClaimMerkleBitmapData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let index = u32::from_le_bytes(data[0..4].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        let amount = u64::from_le_bytes(data[4..12].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        let proof_len =
+            u32::from_le_bytes(data[12..16].try_into().map_err(|_| ProgramError::InvalidInstructionData)?) as usize;
+
+        let proof_start = Self::LEN;
+        let expected_len = proof_start + proof_len * 32;
+        require_len!(data, expected_len);
+
+        let mut proof = Vec::with_capacity(proof_len);
+        for i in 0..proof_len {
+            let start = proof_start + i * 32;
+            let end = start + 32;
+            let hash: [u8; 32] = data[start..end].try_into().map_err(|_| ProgramError::InvalidInstructionData)?;
+            proof.push(hash);
+        }
+
+        Ok(Self { index, amount, proof })
+    }
+}
+
+impl<'a> InstructionData<'a> for ClaimMerkleBitmapData {
+    // index(4) + amount(8) + proof_len(4) = 16
+    const LEN: usize = 16;
+
+    fn validate(&self) -> Result<(), ProgramError> {
+        if self.amount == 0 {
+            return Err(RewardsProgramError::InvalidAmount.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    fn build_data(index: u32, amount: u64, proof: &[[u8; 32]]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&index.to_le_bytes());
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.extend_from_slice(&(proof.len() as u32).to_le_bytes());
+        for node in proof {
+            data.extend_from_slice(node);
+        }
+        data
+    }
+
+    #[test]
+    fn test_try_from_valid_data() {
+        let data = build_data(42, 1_000, &[[1u8; 32], [2u8; 32]]);
+        let result = ClaimMerkleBitmapData::try_from(&data[..]).unwrap();
+        assert_eq!(result.index, 42);
+        assert_eq!(result.amount, 1_000);
+        assert_eq!(result.proof, vec![[1u8; 32], [2u8; 32]]);
+    }
+
+    #[test]
+    fn test_try_from_empty_proof() {
+        let data = build_data(0, 1_000, &[]);
+        let result = ClaimMerkleBitmapData::try_from(&data[..]).unwrap();
+        assert!(result.proof.is_empty());
+    }
+
+    #[test]
+    fn test_try_from_truncated_proof() {
+        let mut data = build_data(0, 1_000, &[[1u8; 32]]);
+        data.pop();
+        let result = ClaimMerkleBitmapData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_validate_zero_amount() {
+        let data = build_data(0, 0, &[]);
+        let result = ClaimMerkleBitmapData::try_from(&data[..]).unwrap();
+        assert_eq!(result.validate().err(), Some(RewardsProgramError::InvalidAmount.into()));
+    }
+
+    #[test]
+    fn test_try_from_insufficient_data() {
+        let data = [0u8; 15];
+        let result = ClaimMerkleBitmapData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
@@ -0,0 +1,72 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    events::ClaimedEvent,
+    state::{MerkleClaimBitmap, MerkleDistribution},
+    traits::{AccountSerialize, Distribution, EventSerialize},
+    utils::{
+        compute_bitmap_leaf_hash, emit_event, get_current_timestamp, transfer_from_distribution_vault,
+        verify_proof_or_error, verify_vault_balance, ClaimTransferContext, MerkleClaimMode,
+    },
+    ID,
+};
+
+use super::ClaimMerkleBitmap;
+
+pub fn process_claim_merkle_bitmap(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = ClaimMerkleBitmap::try_from((instruction_data, accounts))?;
+
+    let distribution_data = ix.accounts.distribution.try_borrow()?;
+    let mut distribution = MerkleDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    drop(distribution_data);
+
+    distribution.validate_claim_mode(MerkleClaimMode::Bitmap {})?;
+    distribution.validate_not_paused()?;
+    distribution.validate_claim_window(get_current_timestamp()?)?;
+
+    let leaf = compute_bitmap_leaf_hash(ix.accounts.claimant.address(), ix.data.index, ix.data.amount);
+    verify_proof_or_error(&ix.data.proof, &distribution.merkle_root, &leaf)?;
+
+    let bitmap_data = ix.accounts.claim_bitmap.try_borrow()?;
+    let mut bitmap = MerkleClaimBitmap::from_account(
+        &bitmap_data,
+        ix.accounts.claim_bitmap,
+        &ID,
+        ix.accounts.distribution.address(),
+    )?;
+    drop(bitmap_data);
+
+    // Bitmap leaves vest immediately and are claimed in full, so one bit is the whole claim state
+    bitmap.set_claimed(ix.data.index)?;
+    verify_vault_balance(ix.accounts.distribution_vault, ix.data.amount)?;
+    Distribution::add_claimed(&mut distribution, ix.data.amount)?;
+
+    let mut bitmap_data = ix.accounts.claim_bitmap.try_borrow_mut()?;
+    bitmap.write_to_slice(&mut bitmap_data)?;
+    drop(bitmap_data);
+
+    let mut distribution_data = ix.accounts.distribution.try_borrow_mut()?;
+    distribution.write_to_slice(&mut distribution_data)?;
+    drop(distribution_data);
+
+    transfer_from_distribution_vault(
+        &distribution,
+        &ClaimTransferContext {
+            distribution_vault: ix.accounts.distribution_vault,
+            mint: ix.accounts.mint,
+            destination: ix.accounts.claimant_token_account,
+            distribution_account: ix.accounts.distribution,
+            token_program: ix.accounts.token_program.address(),
+        },
+        ix.data.amount,
+    )?;
+
+    let event = ClaimedEvent::new(*ix.accounts.distribution.address(), *ix.accounts.claimant.address(), ix.data.amount);
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
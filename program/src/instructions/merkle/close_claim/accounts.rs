@@ -9,6 +9,7 @@ use crate::{
 
 pub struct CloseMerkleClaimAccounts<'a> {
     pub claimant: &'a AccountView,
+    pub original_payer: &'a AccountView,
     pub distribution: &'a AccountView,
     pub claim_account: &'a AccountView,
     pub event_authority: &'a AccountView,
@@ -20,14 +21,15 @@ impl<'a> TryFrom<&'a [AccountView]> for CloseMerkleClaimAccounts<'a> {
 
     #[inline(always)]
     fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
-        let [claimant, distribution, claim_account, event_authority, program] = accounts else {
+        let [claimant, original_payer, distribution, claim_account, event_authority, program] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
         // 1. Validate signers
-        verify_signer(claimant, true)?;
+        verify_signer(claimant, false)?;
 
         // 2. Validate writable
+        verify_writable(original_payer, true)?;
         verify_writable(claim_account, true)?;
 
         // 3. Validate program IDs
@@ -38,7 +40,7 @@ impl<'a> TryFrom<&'a [AccountView]> for CloseMerkleClaimAccounts<'a> {
         // Note: distribution owner is validated in processor (must be system program = closed)
         verify_current_program_account(claim_account)?;
 
-        Ok(Self { claimant, distribution, claim_account, event_authority, program })
+        Ok(Self { claimant, original_payer, distribution, claim_account, event_authority, program })
     }
 }
 
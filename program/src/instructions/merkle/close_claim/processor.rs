@@ -1,4 +1,4 @@
-use pinocchio::{account::AccountView, Address, ProgramResult};
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
 
 use crate::{
     events::ClaimClosedEvent,
@@ -21,7 +21,7 @@ pub fn process_close_merkle_claim(
     verify_system_account(ix.accounts.distribution)?;
 
     let claim_data = ix.accounts.claim_account.try_borrow()?;
-    let _claim = MerkleClaim::from_account(
+    let claim = MerkleClaim::from_account(
         &claim_data,
         ix.accounts.claim_account,
         &ID,
@@ -30,8 +30,13 @@ pub fn process_close_merkle_claim(
     )?;
     drop(claim_data);
 
-    // Close the claim account and return rent to claimant
-    close_pda_account(ix.accounts.claim_account, ix.accounts.claimant)?;
+    // Validate that the payer account matches the one stored in the claim
+    if claim.rent_payer(ix.accounts.claimant.address()) != ix.accounts.original_payer.address() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Return rent to the original payer who created this claim account
+    close_pda_account(ix.accounts.claim_account, ix.accounts.original_payer)?;
 
     let event = ClaimClosedEvent::new(*ix.accounts.distribution.address(), *ix.accounts.claimant.address());
     emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
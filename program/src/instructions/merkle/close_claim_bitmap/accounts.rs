@@ -0,0 +1,37 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{verify_current_program_account, verify_signer, verify_writable},
+};
+
+pub struct CloseMerkleClaimBitmapAccounts<'a> {
+    pub original_payer: &'a AccountView,
+    pub distribution: &'a AccountView,
+    pub claim_bitmap: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for CloseMerkleClaimBitmapAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [original_payer, distribution, claim_bitmap] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(original_payer, true)?;
+
+        // 2. Validate writable
+        verify_writable(claim_bitmap, true)?;
+
+        // 4. Validate accounts owned by current program
+        // Note: distribution owner is validated in processor (must be system program = closed)
+        verify_current_program_account(claim_bitmap)?;
+
+        Ok(Self { original_payer, distribution, claim_bitmap })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for CloseMerkleClaimBitmapAccounts<'a> {}
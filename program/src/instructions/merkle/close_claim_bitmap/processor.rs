@@ -0,0 +1,38 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    state::MerkleClaimBitmap,
+    utils::{close_pda_account, verify_system_account},
+    ID,
+};
+
+use super::CloseMerkleClaimBitmap;
+
+pub fn process_close_merkle_claim_bitmap(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = CloseMerkleClaimBitmap::try_from((instruction_data, accounts))?;
+
+    // Distribution must be closed (owner = system program means account was deleted)
+    verify_system_account(ix.accounts.distribution)?;
+
+    let bitmap_data = ix.accounts.claim_bitmap.try_borrow()?;
+    let bitmap = MerkleClaimBitmap::from_account(
+        &bitmap_data,
+        ix.accounts.claim_bitmap,
+        &ID,
+        ix.accounts.distribution.address(),
+    )?;
+    drop(bitmap_data);
+
+    // Only the account that funded the chunk can reclaim its rent
+    if &bitmap.payer != ix.accounts.original_payer.address() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    close_pda_account(ix.accounts.claim_bitmap, ix.accounts.original_payer)?;
+
+    Ok(())
+}
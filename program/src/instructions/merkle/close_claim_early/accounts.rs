@@ -0,0 +1,52 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_signer,
+        verify_system_program, verify_writable,
+    },
+};
+
+pub struct CloseMerkleClaimEarlyAccounts<'a> {
+    pub claimant: &'a AccountView,
+    pub original_payer: &'a AccountView,
+    pub distribution: &'a AccountView,
+    pub claim_account: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for CloseMerkleClaimEarlyAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [claimant, original_payer, distribution, claim_account, system_program, event_authority, program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(claimant, true)?;
+
+        // 2. Validate writable
+        verify_writable(original_payer, true)?;
+        verify_writable(claim_account, true)?;
+
+        // 3. Validate program IDs
+        verify_system_program(system_program)?;
+        verify_current_program(program)?;
+        verify_event_authority(event_authority)?;
+
+        // 4. Validate accounts owned by current program
+        verify_current_program_account(distribution)?;
+        verify_current_program_account(claim_account)?;
+
+        Ok(Self { claimant, original_payer, distribution, claim_account, system_program, event_authority, program })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for CloseMerkleClaimEarlyAccounts<'a> {}
@@ -0,0 +1,106 @@
+use alloc::vec::Vec;
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, traits::InstructionData, utils::VestingSchedule};
+
+/// Instruction data for CloseMerkleClaimEarly.
+///
+/// The claimant provides their merkle leaf data (total_amount, schedule, proof)
+/// so the program can check the claim is fully claimed against the on-chain root.
+pub struct CloseMerkleClaimEarlyData {
+    /// Total amount allocated to this claimant (from merkle leaf)
+    pub total_amount: u64,
+    /// Vesting schedule (from merkle leaf, variable length)
+    pub schedule: VestingSchedule,
+    /// Merkle proof (variable length)
+    pub proof: Vec<[u8; 32]>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for CloseMerkleClaimEarlyData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        // Minimum: total_amount(8) + schedule(1) + proof_len(4) = 13
+        require_len!(data, Self::LEN);
+
+        let total_amount = u64::from_le_bytes(data[0..8].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+
+        // Variable-length fields
+        let (schedule, schedule_len) = VestingSchedule::from_bytes(&data[8..])?;
+
+        let proof_offset = 8 + schedule_len;
+        require_len!(data, proof_offset + 4);
+
+        let proof_len = u32::from_le_bytes(
+            data[proof_offset..proof_offset + 4].try_into().map_err(|_| ProgramError::InvalidInstructionData)?,
+        ) as usize;
+
+        let proof_start = proof_offset + 4;
+        let expected_len = proof_start + proof_len * 32;
+        require_len!(data, expected_len);
+
+        let mut proof = Vec::with_capacity(proof_len);
+        for i in 0..proof_len {
+            let start = proof_start + i * 32;
+            let end = start + 32;
+            let hash: [u8; 32] = data[start..end].try_into().map_err(|_| ProgramError::InvalidInstructionData)?;
+            proof.push(hash);
+        }
+
+        Ok(Self { total_amount, schedule, proof })
+    }
+}
+
+impl<'a> InstructionData<'a> for CloseMerkleClaimEarlyData {
+    // total_amount(8) + min_schedule(1) + proof_len(4) = 13
+    const LEN: usize = 13;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_data(schedule: VestingSchedule, proof: &[[u8; 32]]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&1000u64.to_le_bytes()); // total_amount
+        data.extend_from_slice(&schedule.to_bytes()); // schedule
+        data.extend_from_slice(&(proof.len() as u32).to_le_bytes()); // proof_len
+        for p in proof {
+            data.extend_from_slice(p);
+        }
+        data
+    }
+
+    #[test]
+    fn test_try_from_immediate_no_proof() {
+        let data = build_data(VestingSchedule::Immediate {}, &[]);
+        let parsed = CloseMerkleClaimEarlyData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.total_amount, 1000);
+        assert_eq!(parsed.schedule, VestingSchedule::Immediate {});
+        assert!(parsed.proof.is_empty());
+    }
+
+    #[test]
+    fn test_try_from_linear_with_proof() {
+        let schedule = VestingSchedule::Linear { start_ts: 100, end_ts: 200 };
+        let proof = [[1u8; 32], [2u8; 32]];
+        let data = build_data(schedule.clone(), &proof);
+        let parsed = CloseMerkleClaimEarlyData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.schedule, schedule);
+        assert_eq!(parsed.proof, proof.to_vec());
+    }
+
+    #[test]
+    fn test_try_from_too_short() {
+        let data = [0u8; 12];
+        assert!(matches!(CloseMerkleClaimEarlyData::try_from(&data[..]), Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_try_from_truncated_proof() {
+        let mut data = build_data(VestingSchedule::Immediate {}, &[[1u8; 32]]);
+        data.pop();
+        assert!(matches!(CloseMerkleClaimEarlyData::try_from(&data[..]), Err(ProgramError::InvalidInstructionData)));
+    }
+}
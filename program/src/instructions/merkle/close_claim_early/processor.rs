@@ -0,0 +1,67 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    events::ClaimClosedEvent,
+    state::{MerkleClaim, MerkleDistribution},
+    traits::{AccountSize, EventSerialize},
+    utils::{compute_leaf_hash, emit_event, save_account, transfer_rent_payer, verify_proof_or_error},
+    ID,
+};
+
+use super::CloseMerkleClaimEarly;
+
+/// Close a fully claimed merkle claim while its distribution is still open.
+///
+/// The claim account is not deleted: it stays as a closed record keeping its claimed amount,
+/// so `ClaimMerkle` cannot pay the same leaf again. The original payer gets all of its rent
+/// back and the claimant pays the record's rent instead, which `CloseMerkleClaim` refunds.
+pub fn process_close_merkle_claim_early(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = CloseMerkleClaimEarly::try_from((instruction_data, accounts))?;
+
+    let distribution_data = ix.accounts.distribution.try_borrow()?;
+    let distribution = MerkleDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    drop(distribution_data);
+
+    let schedule_bytes = ix.data.schedule.to_bytes();
+    let leaf = compute_leaf_hash(ix.accounts.claimant.address(), ix.data.total_amount, &schedule_bytes);
+    verify_proof_or_error(&ix.data.proof, &distribution.merkle_root, &leaf)?;
+
+    let claim_data = ix.accounts.claim_account.try_borrow()?;
+    let mut claim = MerkleClaim::from_account(
+        &claim_data,
+        ix.accounts.claim_account,
+        &ID,
+        ix.accounts.distribution.address(),
+        ix.accounts.claimant.address(),
+    )?;
+    drop(claim_data);
+
+    if claim.is_closed() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Validate that the payer account matches the one stored in the claim
+    if claim.rent_payer(ix.accounts.claimant.address()) != ix.accounts.original_payer.address() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if claim.claimed_amount < ix.data.total_amount {
+        return Err(RewardsProgramError::ClaimNotFullyVested.into());
+    }
+
+    // Refund the original payer in full; the claimant funds the closed record from now on
+    transfer_rent_payer(ix.accounts.claimant, ix.accounts.original_payer, ix.accounts.claim_account, MerkleClaim::LEN)?;
+    claim.close(*ix.accounts.claimant.address());
+
+    save_account(ix.accounts.claim_account, &claim, Some(ix.accounts.claimant))?;
+
+    let event = ClaimClosedEvent::new(*ix.accounts.distribution.address(), *ix.accounts.claimant.address());
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -1,12 +1,11 @@
 use pinocchio::{account::AccountView, Address, ProgramResult};
-use pinocchio_token_2022::instructions::{CloseAccount, TransferChecked};
 
 use crate::{
     errors::RewardsProgramError,
     events::DistributionClosedEvent,
     state::MerkleDistribution,
-    traits::{Distribution, DistributionSigner, EventSerialize},
-    utils::{close_pda_account, emit_event, get_current_timestamp, get_mint_decimals, get_token_account_balance},
+    traits::{Distribution, EventSerialize},
+    utils::{close_distribution_vault, close_pda_account, emit_event, get_current_timestamp, ClaimTransferContext},
     ID,
 };
 
@@ -29,41 +28,23 @@ pub fn process_close_merkle_distribution(
     // Validate authority
     distribution.validate_authority(ix.accounts.authority.address())?;
 
-    // Validate clawback timestamp has been reached
-    if current_ts < distribution.clawback_ts {
+    // Validate clawback timestamp has been reached and the claim window has closed
+    if current_ts < distribution.clawback_ts.max(distribution.claim_end_ts) {
         return Err(RewardsProgramError::ClawbackNotReached.into());
     }
 
-    // Get remaining tokens in distribution vault
-    let remaining_amount = get_token_account_balance(ix.accounts.distribution_vault)?;
-    let decimals = get_mint_decimals(ix.accounts.mint)?;
-
-    // Transfer remaining tokens back to authority
-    if remaining_amount > 0 {
-        distribution.with_signer(|signers| {
-            TransferChecked {
-                from: ix.accounts.distribution_vault,
-                mint: ix.accounts.mint,
-                to: ix.accounts.authority_token_account,
-                authority: ix.accounts.distribution,
-                amount: remaining_amount,
-                decimals,
-                token_program: ix.accounts.token_program.address(),
-            }
-            .invoke_signed(signers)
-        })?;
-    }
-
-    // Close distribution vault ATA
-    distribution.with_signer(|signers| {
-        CloseAccount {
-            account: ix.accounts.distribution_vault,
-            destination: ix.accounts.authority,
-            authority: ix.accounts.distribution,
+    // Return remaining tokens to the authority and close the vault
+    let remaining_amount = close_distribution_vault(
+        &distribution,
+        &ClaimTransferContext {
+            distribution_vault: ix.accounts.distribution_vault,
+            mint: ix.accounts.mint,
+            destination: ix.accounts.authority_token_account,
+            distribution_account: ix.accounts.distribution,
             token_program: ix.accounts.token_program.address(),
-        }
-        .invoke_signed(signers)
-    })?;
+        },
+        ix.accounts.authority,
+    )?;
 
     let event = DistributionClosedEvent::new(*ix.accounts.distribution.address(), remaining_amount);
     emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
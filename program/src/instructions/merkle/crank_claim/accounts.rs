@@ -0,0 +1,117 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    instructions::merkle::claim::ClaimMerkleAccounts,
+    traits::InstructionAccounts,
+    utils::{
+        validate_distribution_vault, verify_current_program, verify_current_program_account, verify_event_authority,
+        verify_mint_owner, verify_owned_by, verify_readonly, verify_signer, verify_system_program,
+        verify_token_program_for_mint, verify_writable,
+    },
+};
+
+pub struct CrankClaimMerkleAccounts<'a> {
+    pub cranker: &'a AccountView,
+    pub claimant: &'a AccountView,
+    pub distribution: &'a AccountView,
+    pub claim_account: &'a AccountView,
+    pub revocation_account: &'a AccountView,
+    pub redirect_account: &'a AccountView,
+    pub claim_delegate_account: &'a AccountView,
+    pub mint: &'a AccountView,
+    pub distribution_vault: &'a AccountView,
+    pub destination_token_account: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub token_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for CrankClaimMerkleAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [cranker, claimant, distribution, claim_account, revocation_account, redirect_account, claim_delegate_account, mint, distribution_vault, destination_token_account, system_program, token_program, event_authority, program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(cranker, true)?;
+
+        // 2. Validate writable
+        verify_writable(distribution, true)?;
+        verify_writable(claim_account, true)?;
+        verify_writable(distribution_vault, true)?;
+        verify_writable(destination_token_account, true)?;
+
+        // 2b. Validate read-only accounts
+        verify_readonly(claimant)?;
+        verify_readonly(revocation_account)?;
+        verify_readonly(redirect_account)?;
+        verify_readonly(claim_delegate_account)?;
+        verify_readonly(mint)?;
+
+        // 3. Validate program IDs
+        verify_system_program(system_program)?;
+        verify_token_program_for_mint(token_program, mint)?;
+        verify_current_program(program)?;
+        verify_event_authority(event_authority)?;
+
+        // 4. Validate accounts owned by current program
+        verify_current_program_account(distribution)?;
+        // claim_account may not exist yet (will be created idempotently)
+        // redirect_account and claim_delegate_account may be uninitialized; validated in processor
+
+        // 5. Validate token account ownership
+        verify_mint_owner(mint, token_program)?;
+        verify_owned_by(destination_token_account, token_program.address())?;
+
+        // 6. Validate distribution_vault (ATA, or native vault PDA)
+        validate_distribution_vault(distribution_vault, distribution.address(), mint, token_program)?;
+
+        Ok(Self {
+            cranker,
+            claimant,
+            distribution,
+            claim_account,
+            revocation_account,
+            redirect_account,
+            claim_delegate_account,
+            mint,
+            distribution_vault,
+            destination_token_account,
+            system_program,
+            token_program,
+            event_authority,
+            program,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for CrankClaimMerkleAccounts<'a> {}
+
+impl<'a> CrankClaimMerkleAccounts<'a> {
+    /// View these accounts as a regular claim paying into `destination_token_account`.
+    /// The cranker pays for the claim account if this is the claimant's first claim.
+    #[inline(always)]
+    pub fn as_claim_accounts(&self) -> ClaimMerkleAccounts<'a> {
+        ClaimMerkleAccounts {
+            payer: self.cranker,
+            claimant: self.claimant,
+            distribution: self.distribution,
+            claim_account: self.claim_account,
+            revocation_account: self.revocation_account,
+            redirect_account: self.redirect_account,
+            mint: self.mint,
+            distribution_vault: self.distribution_vault,
+            claimant_token_account: self.destination_token_account,
+            system_program: self.system_program,
+            token_program: self.token_program,
+            event_authority: self.event_authority,
+            program: self.program,
+        }
+    }
+}
@@ -0,0 +1,6 @@
+use crate::instructions::merkle::claim::ClaimMerkleData;
+
+/// Instruction data for CrankClaimMerkle
+///
+/// Identical to `ClaimMerkleData`: the claimant's leaf data, proof, `amount` and `fee_mode`.
+pub type CrankClaimMerkleData = ClaimMerkleData;
@@ -0,0 +1,55 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    instructions::merkle::claim::execute_claim_merkle,
+    state::{MerkleRedirect, MerkleRedirectSeeds},
+    traits::PdaSeeds,
+    utils::{is_pda_uninitialized, is_recipient_or_delegate, verify_permissionless_crank},
+    ID,
+};
+
+use super::CrankClaimMerkle;
+
+pub fn process_crank_claim_merkle(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = CrankClaimMerkle::try_from((instruction_data, accounts))?;
+
+    let distribution = ix.accounts.distribution.address();
+    let claimant = ix.accounts.claimant.address();
+
+    let redirect_seeds = MerkleRedirectSeeds { distribution: *distribution, claimant: *claimant };
+    redirect_seeds.validate_pda_address(ix.accounts.redirect_account, &ID)?;
+
+    // A transferred position belongs to the redirect's new claimant; the leaf still names the original
+    let owner = if is_pda_uninitialized(ix.accounts.redirect_account) {
+        *claimant
+    } else {
+        let redirect_data = ix.accounts.redirect_account.try_borrow()?;
+        let redirect =
+            MerkleRedirect::from_account(&redirect_data, ix.accounts.redirect_account, &ID, distribution, claimant)?;
+        drop(redirect_data);
+        redirect.new_claimant
+    };
+
+    if !is_recipient_or_delegate(
+        ix.accounts.cranker.address(),
+        &owner,
+        distribution,
+        ix.accounts.claim_delegate_account,
+    )? {
+        verify_permissionless_crank(
+            &owner,
+            ix.accounts.destination_token_account,
+            ix.accounts.mint,
+            ix.accounts.token_program,
+            ix.data.amount,
+            ix.data.fee_mode,
+        )?;
+    }
+
+    // Merkle proof, revocation and vesting are validated by the shared claim path
+    execute_claim_merkle(&ix.accounts.as_claim_accounts(), &ix.data)
+}
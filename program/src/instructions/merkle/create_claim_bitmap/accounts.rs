@@ -0,0 +1,44 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{verify_current_program_account, verify_readonly, verify_signer, verify_system_program, verify_writable},
+};
+
+pub struct CreateMerkleClaimBitmapAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub distribution: &'a AccountView,
+    pub claim_bitmap: &'a AccountView,
+    pub system_program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for CreateMerkleClaimBitmapAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, distribution, claim_bitmap, system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+
+        // 2. Validate writable
+        verify_writable(claim_bitmap, true)?;
+
+        // 2b. Validate read-only accounts
+        verify_readonly(distribution)?;
+
+        // 3. Validate program IDs
+        verify_system_program(system_program)?;
+
+        // 4. Validate accounts owned by current program
+        verify_current_program_account(distribution)?;
+        // claim_bitmap is created by this instruction
+
+        Ok(Self { payer, distribution, claim_bitmap, system_program })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for CreateMerkleClaimBitmapAccounts<'a> {}
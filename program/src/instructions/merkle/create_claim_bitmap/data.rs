@@ -0,0 +1,46 @@
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, traits::InstructionData};
+
+/// Instruction data for CreateMerkleClaimBitmap
+///
+/// - `chunk_index`: Which chunk to create; chunk `n` tracks leaves `n * 8192..(n + 1) * 8192`.
+pub struct CreateMerkleClaimBitmapData {
+    pub chunk_index: u32,
+}
+
+impl<'a> TryFrom<&'a [u8]> for CreateMerkleClaimBitmapData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let chunk_index = u32::from_le_bytes(data[0..4].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+
+        Ok(Self { chunk_index })
+    }
+}
+
+impl<'a> InstructionData<'a> for CreateMerkleClaimBitmapData {
+    const LEN: usize = 4; // chunk_index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_valid_data() {
+        let data = 3u32.to_le_bytes();
+        let result = CreateMerkleClaimBitmapData::try_from(&data[..]).unwrap();
+        assert_eq!(result.chunk_index, 3);
+    }
+
+    #[test]
+    fn test_try_from_insufficient_data() {
+        let data = [0u8; 3];
+        let result = CreateMerkleClaimBitmapData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
@@ -0,0 +1,47 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    state::{MerkleClaimBitmap, MerkleClaimBitmapSeeds, MerkleDistribution},
+    traits::{AccountSerialize, AccountSize, PdaSeeds},
+    utils::{create_pda_account, MerkleClaimMode},
+    ID,
+};
+
+use super::CreateMerkleClaimBitmap;
+
+pub fn process_create_merkle_claim_bitmap(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = CreateMerkleClaimBitmap::try_from((instruction_data, accounts))?;
+
+    let distribution_data = ix.accounts.distribution.try_borrow()?;
+    let distribution = MerkleDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    drop(distribution_data);
+
+    distribution.validate_claim_mode(MerkleClaimMode::Bitmap {})?;
+
+    // Anyone may pay for a chunk; the rent is refunded to them once the distribution is closed
+    let bitmap_seeds = MerkleClaimBitmapSeeds::new(*ix.accounts.distribution.address(), ix.data.chunk_index);
+    let bitmap_bump = bitmap_seeds.validate_pda_address(ix.accounts.claim_bitmap, &ID)?;
+
+    let bitmap_bump_seed = [bitmap_bump];
+    let bitmap_pda_seeds = bitmap_seeds.seeds_with_bump(&bitmap_bump_seed);
+    let bitmap_pda_seeds_array: [_; 4] = bitmap_pda_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    create_pda_account(
+        ix.accounts.payer,
+        MerkleClaimBitmap::LEN,
+        &ID,
+        ix.accounts.claim_bitmap,
+        bitmap_pda_seeds_array,
+    )?;
+
+    let bitmap = MerkleClaimBitmap::new(bitmap_bump, ix.data.chunk_index, *ix.accounts.payer.address());
+    let mut bitmap_data = ix.accounts.claim_bitmap.try_borrow_mut()?;
+    bitmap.write_to_slice(&mut bitmap_data)?;
+    drop(bitmap_data);
+
+    Ok(())
+}
@@ -1,6 +1,11 @@
 use pinocchio::error::ProgramError;
 
-use crate::{errors::RewardsProgramError, require_len, traits::InstructionData};
+use crate::{
+    errors::RewardsProgramError,
+    require_len,
+    traits::InstructionData,
+    utils::{validate_claim_window_config, MerkleClaimMode},
+};
 
 pub struct CreateMerkleDistributionData {
     pub bump: u8,
@@ -9,6 +14,9 @@ pub struct CreateMerkleDistributionData {
     pub merkle_root: [u8; 32],
     pub total_amount: u64,
     pub clawback_ts: i64,
+    pub claim_mode: MerkleClaimMode,
+    pub claim_start_ts: i64,
+    pub claim_end_ts: i64,
 }
 
 impl<'a> TryFrom<&'a [u8]> for CreateMerkleDistributionData {
@@ -26,13 +34,28 @@ impl<'a> TryFrom<&'a [u8]> for CreateMerkleDistributionData {
             u64::from_le_bytes(data[42..50].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
         let clawback_ts =
             i64::from_le_bytes(data[50..58].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
-
-        Ok(Self { bump, revocable, amount, merkle_root, total_amount, clawback_ts })
+        let claim_mode = MerkleClaimMode::try_from(data[58])?;
+        let claim_start_ts =
+            i64::from_le_bytes(data[59..67].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        let claim_end_ts =
+            i64::from_le_bytes(data[67..75].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+
+        Ok(Self {
+            bump,
+            revocable,
+            amount,
+            merkle_root,
+            total_amount,
+            clawback_ts,
+            claim_mode,
+            claim_start_ts,
+            claim_end_ts,
+        })
     }
 }
 
 impl<'a> InstructionData<'a> for CreateMerkleDistributionData {
-    const LEN: usize = 1 + 1 + 8 + 32 + 8 + 8; // bump + revocable + amount + merkle_root + total_amount + clawback_ts = 58
+    const LEN: usize = 1 + 1 + 8 + 32 + 8 + 8 + 1 + 8 + 8; // bump + revocable + amount + merkle_root + total_amount + clawback_ts + claim_mode + claim_start_ts + claim_end_ts = 75
 
     fn validate(&self) -> Result<(), ProgramError> {
         if self.amount == 0 {
@@ -41,7 +64,11 @@ impl<'a> InstructionData<'a> for CreateMerkleDistributionData {
         if self.total_amount == 0 {
             return Err(RewardsProgramError::InvalidAmount.into());
         }
-        Ok(())
+        // Bitmap claims keep no per-claimant account to record a revocation against
+        if self.claim_mode == (MerkleClaimMode::Bitmap {}) && self.revocable != 0 {
+            return Err(RewardsProgramError::InvalidClaimMode.into());
+        }
+        validate_claim_window_config(self.claim_start_ts, self.claim_end_ts, self.clawback_ts)
     }
 }
 
@@ -49,14 +76,17 @@ impl<'a> InstructionData<'a> for CreateMerkleDistributionData {
 mod tests {
     use super::*;
 
-    fn create_valid_data() -> [u8; 58] {
-        let mut data = [0u8; 58];
+    fn create_valid_data() -> [u8; 75] {
+        let mut data = [0u8; 75];
         data[0] = 255; // bump
         data[1] = 3; // revocable (both modes)
         data[2..10].copy_from_slice(&1000u64.to_le_bytes()); // amount
         data[10..42].copy_from_slice(&[1u8; 32]); // merkle_root
         data[42..50].copy_from_slice(&5000u64.to_le_bytes()); // total_amount
         data[50..58].copy_from_slice(&1700000000i64.to_le_bytes()); // clawback_ts
+        data[58] = 0; // claim_mode (ClaimAccount)
+        data[59..67].copy_from_slice(&0i64.to_le_bytes()); // claim_start_ts
+        data[67..75].copy_from_slice(&0i64.to_le_bytes()); // claim_end_ts
         data
     }
 
@@ -73,11 +103,20 @@ mod tests {
         assert_eq!(parsed.merkle_root, [1u8; 32]);
         assert_eq!(parsed.total_amount, 5000);
         assert_eq!(parsed.clawback_ts, 1700000000);
+        assert_eq!(parsed.claim_mode, MerkleClaimMode::ClaimAccount {});
+    }
+
+    #[test]
+    fn test_try_from_invalid_claim_mode() {
+        let mut data = create_valid_data();
+        data[58] = 2;
+        let result = CreateMerkleDistributionData::try_from(&data[..]);
+        assert_eq!(result.err(), Some(RewardsProgramError::InvalidClaimMode.into()));
     }
 
     #[test]
     fn test_try_from_data_too_short() {
-        let data = [0u8; 50];
+        let data = [0u8; 74];
         let result = CreateMerkleDistributionData::try_from(&data[..]);
         assert_eq!(result.err(), Some(ProgramError::InvalidInstructionData));
     }
@@ -104,4 +143,32 @@ mod tests {
         let parsed = CreateMerkleDistributionData::try_from(&data[..]).unwrap();
         assert_eq!(parsed.validate().err(), Some(RewardsProgramError::InvalidAmount.into()));
     }
+
+    #[test]
+    fn test_validate_bitmap_not_revocable() {
+        let mut data = create_valid_data();
+        data[58] = 1; // Bitmap
+        let parsed = CreateMerkleDistributionData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.validate().err(), Some(RewardsProgramError::InvalidClaimMode.into()));
+
+        data[1] = 0; // not revocable
+        let parsed = CreateMerkleDistributionData::try_from(&data[..]).unwrap();
+        assert!(parsed.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_claim_window() {
+        let mut data = create_valid_data();
+        data[59..67].copy_from_slice(&1600000000i64.to_le_bytes());
+        data[67..75].copy_from_slice(&1700000000i64.to_le_bytes());
+        let parsed = CreateMerkleDistributionData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.claim_start_ts, 1600000000);
+        assert_eq!(parsed.claim_end_ts, 1700000000);
+        assert!(parsed.validate().is_ok());
+
+        // Clawback before the window closes
+        data[67..75].copy_from_slice(&1800000000i64.to_le_bytes());
+        let parsed = CreateMerkleDistributionData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.validate().err(), Some(RewardsProgramError::InvalidTimeWindow.into()));
+    }
 }
@@ -1,12 +1,14 @@
 use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
-use pinocchio_associated_token_account::instructions::CreateIdempotent;
-use pinocchio_token_2022::instructions::TransferChecked;
 
 use crate::{
+    errors::RewardsProgramError,
     events::DistributionCreatedEvent,
     state::MerkleDistribution,
     traits::{AccountSerialize, AccountSize, EventSerialize, InstructionData, PdaSeeds},
-    utils::{create_pda_account, emit_event, get_mint_decimals},
+    utils::{
+        create_distribution_vault, create_pda_account, deposit_to_distribution_vault, emit_event,
+        has_transfer_fee_config, verify_supported_mint_extensions, MerkleClaimMode, VaultDepositContext,
+    },
     ID,
 };
 
@@ -19,8 +21,14 @@ pub fn process_create_merkle_distribution(
 ) -> ProgramResult {
     let ix = CreateMerkleDistribution::try_from((instruction_data, accounts))?;
     ix.data.validate()?;
+    verify_supported_mint_extensions(ix.accounts.mint)?;
 
-    let distribution = MerkleDistribution::new(
+    // Bitmap claims pay the whole leaf in one transfer with no fee mode, so a fee would silently shrink every leaf
+    if ix.data.claim_mode == (MerkleClaimMode::Bitmap {}) && has_transfer_fee_config(ix.accounts.mint)? {
+        return Err(RewardsProgramError::UnsupportedMintExtension.into());
+    }
+
+    let mut distribution = MerkleDistribution::new(
         ix.data.bump,
         ix.data.revocable,
         *ix.accounts.authority.address(),
@@ -30,6 +38,9 @@ pub fn process_create_merkle_distribution(
         ix.data.total_amount,
         ix.data.clawback_ts,
     );
+    distribution.claim_mode = ix.data.claim_mode.to_byte();
+    distribution.claim_start_ts = ix.data.claim_start_ts;
+    distribution.claim_end_ts = ix.data.claim_end_ts;
 
     distribution.validate_pda(ix.accounts.distribution, &ID, ix.data.bump)?;
 
@@ -45,33 +56,31 @@ pub fn process_create_merkle_distribution(
         distribution_seeds_array,
     )?;
 
+    create_distribution_vault(
+        ix.accounts.payer,
+        ix.accounts.distribution,
+        ix.accounts.distribution_vault,
+        ix.accounts.mint,
+        ix.accounts.system_program,
+        ix.accounts.token_program,
+    )?;
+
+    // Transfer fees are withheld in the vault, so record what actually arrived
+    distribution.total_funded = deposit_to_distribution_vault(
+        &VaultDepositContext {
+            source: ix.accounts.authority_token_account,
+            authority: ix.accounts.authority,
+            distribution_vault: ix.accounts.distribution_vault,
+            mint: ix.accounts.mint,
+            token_program: ix.accounts.token_program,
+        },
+        ix.data.amount,
+    )?;
+
     let mut distribution_data = ix.accounts.distribution.try_borrow_mut()?;
     distribution.write_to_slice(&mut distribution_data)?;
     drop(distribution_data);
 
-    CreateIdempotent {
-        funding_account: ix.accounts.payer,
-        account: ix.accounts.distribution_vault,
-        wallet: ix.accounts.distribution,
-        mint: ix.accounts.mint,
-        system_program: ix.accounts.system_program,
-        token_program: ix.accounts.token_program,
-    }
-    .invoke()?;
-
-    let decimals = get_mint_decimals(ix.accounts.mint)?;
-
-    TransferChecked {
-        from: ix.accounts.authority_token_account,
-        mint: ix.accounts.mint,
-        to: ix.accounts.distribution_vault,
-        authority: ix.accounts.authority,
-        amount: ix.data.amount,
-        decimals,
-        token_program: ix.accounts.token_program.address(),
-    }
-    .invoke()?;
-
     let event = DistributionCreatedEvent::merkle(
         *ix.accounts.authority.address(),
         *ix.accounts.mint.address(),
@@ -1,5 +1,12 @@
 pub mod claim;
+pub mod claim_bitmap;
 pub mod close_claim;
+pub mod close_claim_bitmap;
+pub mod close_claim_early;
 pub mod close_distribution;
+pub mod crank_claim;
+pub mod create_claim_bitmap;
 pub mod create_distribution;
 pub mod revoke_claim;
+pub mod transfer_claim;
+pub mod update_root;
@@ -2,6 +2,7 @@ use alloc::vec::Vec;
 use pinocchio::error::ProgramError;
 
 use crate::{
+    errors::RewardsProgramError,
     require_len,
     traits::{InstructionData, VestingParams},
     utils::{RevokeMode, VestingSchedule},
@@ -12,7 +13,7 @@ use crate::{
 /// The authority must provide the claimant's merkle leaf data (total_amount,
 /// schedule, proof) so the program can verify it against the on-chain root.
 pub struct RevokeMerkleClaimData {
-    /// Revocation mode: NonVested (transfer vested) or Full (no transfer)
+    /// Revocation mode: NonVested (transfer vested) or Full (no transfer); Freeze is direct-only
     pub revoke_mode: RevokeMode,
     /// Total amount allocated to this claimant (from merkle leaf)
     pub total_amount: u64,
@@ -64,6 +65,11 @@ impl<'a> InstructionData<'a> for RevokeMerkleClaimData {
     const LEN: usize = 14;
 
     fn validate(&self) -> Result<(), ProgramError> {
+        // Freezing keeps the allocation claimable, which needs a per-claimant account; merkle
+        // claimants only get a MerkleRevocation marker that blocks every later claim
+        if self.revoke_mode == (RevokeMode::Freeze {}) {
+            return Err(RewardsProgramError::InvalidRevokeMode.into());
+        }
         Ok(())
     }
 }
@@ -75,15 +81,14 @@ impl VestingParams for RevokeMerkleClaimData {
     }
 
     #[inline(always)]
-    fn vesting_schedule(&self) -> VestingSchedule {
-        self.schedule
+    fn vesting_schedule(&self) -> &VestingSchedule {
+        &self.schedule
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::errors::RewardsProgramError;
 
     fn build_data(revoke_mode: u8, schedule: VestingSchedule, proof: &[[u8; 32]]) -> Vec<u8> {
         let schedule_bytes = schedule.to_bytes();
@@ -112,7 +117,7 @@ mod tests {
     fn test_try_from_full_linear_with_proof() {
         let schedule = VestingSchedule::Linear { start_ts: 100, end_ts: 200 };
         let proof = [[1u8; 32], [2u8; 32]];
-        let data = build_data(1, schedule, &proof);
+        let data = build_data(1, schedule.clone(), &proof);
         let parsed = RevokeMerkleClaimData::try_from(&data[..]).unwrap();
         assert_eq!(parsed.revoke_mode, RevokeMode::Full {});
         assert_eq!(parsed.schedule, schedule);
@@ -124,7 +129,7 @@ mod tests {
     #[test]
     fn test_try_from_cliff_linear() {
         let schedule = VestingSchedule::CliffLinear { start_ts: 0, cliff_ts: 100, end_ts: 400 };
-        let data = build_data(0, schedule, &[]);
+        let data = build_data(0, schedule.clone(), &[]);
         let parsed = RevokeMerkleClaimData::try_from(&data[..]).unwrap();
         assert_eq!(parsed.schedule, schedule);
     }
@@ -138,11 +143,18 @@ mod tests {
 
     #[test]
     fn test_try_from_invalid_mode() {
-        let data = build_data(2, VestingSchedule::Immediate {}, &[]);
+        let data = build_data(3, VestingSchedule::Immediate {}, &[]);
         let result = RevokeMerkleClaimData::try_from(&data[..]);
         assert_eq!(result.err(), Some(ProgramError::Custom(RewardsProgramError::InvalidRevokeMode as u32)));
     }
 
+    #[test]
+    fn test_validate_rejects_freeze() {
+        let data = build_data(2, VestingSchedule::Immediate {}, &[]);
+        let parsed = RevokeMerkleClaimData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.validate(), Err(RewardsProgramError::InvalidRevokeMode.into()));
+    }
+
     #[test]
     fn test_try_from_proof_too_short() {
         let schedule = VestingSchedule::Immediate {};
@@ -168,6 +180,6 @@ mod tests {
         let data = build_data(0, VestingSchedule::Linear { start_ts: 100, end_ts: 200 }, &[]);
         let parsed = RevokeMerkleClaimData::try_from(&data[..]).unwrap();
         assert_eq!(parsed.total_amount(), 1000);
-        assert_eq!(parsed.vesting_schedule(), VestingSchedule::Linear { start_ts: 100, end_ts: 200 });
+        assert_eq!(parsed.vesting_schedule(), &VestingSchedule::Linear { start_ts: 100, end_ts: 200 });
     }
 }
@@ -1,17 +1,16 @@
 use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
-use pinocchio_token_2022::instructions::TransferChecked;
 
 use crate::{
     errors::RewardsProgramError,
     events::RecipientRevokedEvent,
     state::{MerkleClaim, MerkleClaimSeeds, MerkleDistribution, MerkleRevocation, MerkleRevocationSeeds},
     traits::{
-        AccountParse, AccountSerialize, AccountSize, Distribution, DistributionSigner, EventSerialize, InstructionData,
-        PdaSeeds, VestingParams,
+        AccountParse, AccountSerialize, AccountSize, Distribution, EventSerialize, InstructionData, PdaSeeds,
+        VestingParams,
     },
     utils::{
-        compute_leaf_hash, create_pda_account, emit_event, get_current_timestamp, get_mint_decimals,
-        is_pda_uninitialized, verify_proof_or_error, RevokeMode,
+        compute_leaf_hash, create_pda_account, emit_event, get_current_timestamp, is_pda_uninitialized, save_account,
+        transfer_from_distribution_vault, verify_proof_or_error, ClaimTransferContext, MerkleClaimMode, RevokeMode,
     },
     ID,
 };
@@ -34,6 +33,7 @@ pub fn process_revoke_merkle_claim(
     drop(distribution_data);
 
     distribution.validate_authority(ix.accounts.authority.address())?;
+    distribution.validate_claim_mode(MerkleClaimMode::ClaimAccount {})?;
 
     if ix.data.revoke_mode.is_disabled_by(distribution.revocable) {
         return Err(RewardsProgramError::DistributionNotRevocable.into());
@@ -72,28 +72,30 @@ pub fn process_revoke_merkle_claim(
     };
 
     // Calculate vesting
-    let vested_amount = VestingParams::calculate_unlocked(&ix.data, current_ts)?;
-    let vested_unclaimed = vested_amount.checked_sub(claimed_amount).ok_or(RewardsProgramError::MathOverflow)?;
-    let unvested = ix.data.total_amount.checked_sub(vested_amount).ok_or(RewardsProgramError::MathOverflow)?;
+    let vested_amount = VestingParams::calculate_unlocked(
+        &ix.data,
+        distribution.vesting_timestamp(current_ts, ix.data.base_vesting_offset()),
+    )?;
+    // Saturating: after a root update the claimant may have already claimed more than this leaf vests
+    let vested_unclaimed = vested_amount.saturating_sub(claimed_amount);
+    let unvested = ix.data.total_amount.saturating_sub(vested_amount.max(claimed_amount));
 
     // Apply revoke mode
-    let decimals = get_mint_decimals(ix.accounts.mint)?;
 
     let (vested_transferred, total_freed) = match ix.data.revoke_mode {
         RevokeMode::NonVested {} => {
             if vested_unclaimed > 0 {
-                distribution.with_signer(|signers| {
-                    TransferChecked {
-                        from: ix.accounts.distribution_vault,
+                transfer_from_distribution_vault(
+                    &distribution,
+                    &ClaimTransferContext {
+                        distribution_vault: ix.accounts.distribution_vault,
                         mint: ix.accounts.mint,
-                        to: ix.accounts.claimant_token_account,
-                        authority: ix.accounts.distribution,
-                        amount: vested_unclaimed,
-                        decimals,
+                        destination: ix.accounts.claimant_token_account,
+                        distribution_account: ix.accounts.distribution,
                         token_program: ix.accounts.token_program.address(),
-                    }
-                    .invoke_signed(signers)
-                })?;
+                    },
+                    vested_unclaimed,
+                )?;
             }
 
             Distribution::add_claimed(&mut distribution, vested_unclaimed)?;
@@ -104,27 +106,26 @@ pub fn process_revoke_merkle_claim(
             let total_freed = unvested.checked_add(vested_unclaimed).ok_or(RewardsProgramError::MathOverflow)?;
             (0, total_freed)
         }
+        // Rejected by RevokeMerkleClaimData::validate
+        RevokeMode::Freeze {} => return Err(RewardsProgramError::InvalidRevokeMode.into()),
     };
 
     if total_freed > 0 {
-        distribution.with_signer(|signers| {
-            TransferChecked {
-                from: ix.accounts.distribution_vault,
+        transfer_from_distribution_vault(
+            &distribution,
+            &ClaimTransferContext {
+                distribution_vault: ix.accounts.distribution_vault,
                 mint: ix.accounts.mint,
-                to: ix.accounts.authority_token_account,
-                authority: ix.accounts.distribution,
-                amount: total_freed,
-                decimals,
+                destination: ix.accounts.authority_token_account,
+                distribution_account: ix.accounts.distribution,
                 token_program: ix.accounts.token_program.address(),
-            }
-            .invoke_signed(signers)
-        })?;
+            },
+            total_freed,
+        )?;
     }
 
     // Write updated distribution
-    let mut distribution_data = ix.accounts.distribution.try_borrow_mut()?;
-    distribution.write_to_slice(&mut distribution_data)?;
-    drop(distribution_data);
+    save_account(ix.accounts.distribution, &distribution, Some(ix.accounts.payer))?;
 
     // Create revocation PDA
     let revocation_bump_seed = [revocation_bump];
@@ -0,0 +1,72 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_readonly, verify_signer,
+        verify_system_program, verify_writable,
+    },
+};
+
+pub struct TransferMerkleClaimAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub owner: &'a AccountView,
+    pub claimant: &'a AccountView,
+    pub new_owner: &'a AccountView,
+    pub distribution: &'a AccountView,
+    pub redirect_account: &'a AccountView,
+    pub revocation_account: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for TransferMerkleClaimAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, owner, claimant, new_owner, distribution, redirect_account, revocation_account, system_program, event_authority, program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(owner, false)?;
+
+        // 2. Validate writable
+        verify_writable(redirect_account, true)?;
+
+        // 2b. Validate read-only accounts
+        verify_readonly(claimant)?;
+        verify_readonly(new_owner)?;
+        verify_readonly(distribution)?;
+        verify_readonly(revocation_account)?;
+
+        // 3. Validate program IDs
+        verify_system_program(system_program)?;
+        verify_current_program(program)?;
+        verify_event_authority(event_authority)?;
+
+        // 4. Validate accounts owned by current program
+        verify_current_program_account(distribution)?;
+        // redirect_account is created on the first transfer
+
+        Ok(Self {
+            payer,
+            owner,
+            claimant,
+            new_owner,
+            distribution,
+            redirect_account,
+            revocation_account,
+            system_program,
+            event_authority,
+            program,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for TransferMerkleClaimAccounts<'a> {}
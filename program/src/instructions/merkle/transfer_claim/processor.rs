@@ -0,0 +1,90 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    events::RecipientTransferredEvent,
+    state::{MerkleDistribution, MerkleRedirect, MerkleRedirectSeeds, MerkleRevocationSeeds},
+    traits::{AccountSerialize, AccountSize, Distribution, EventSerialize, PdaSeeds},
+    utils::{create_pda_account, emit_event, is_pda_uninitialized, MerkleClaimMode},
+    ID,
+};
+
+use super::TransferMerkleClaim;
+
+pub fn process_transfer_merkle_claim(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = TransferMerkleClaim::try_from((instruction_data, accounts))?;
+
+    let distribution_data = ix.accounts.distribution.try_borrow()?;
+    let distribution = MerkleDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    drop(distribution_data);
+
+    distribution.validate_claim_mode(MerkleClaimMode::ClaimAccount {})?;
+    distribution.validate_transferable()?;
+
+    let distribution_address = ix.accounts.distribution.address();
+    let claimant = ix.accounts.claimant.address();
+    let owner = ix.accounts.owner.address();
+    let new_owner = ix.accounts.new_owner.address();
+
+    let revocation_seeds = MerkleRevocationSeeds { distribution: *distribution_address, claimant: *claimant };
+    revocation_seeds.validate_pda_address(ix.accounts.revocation_account, &ID)?;
+
+    if !is_pda_uninitialized(ix.accounts.revocation_account) {
+        return Err(RewardsProgramError::ClaimantAlreadyRevoked.into());
+    }
+
+    if new_owner == &Address::default() || new_owner == owner || new_owner == claimant {
+        return Err(RewardsProgramError::InvalidNewRecipient.into());
+    }
+
+    let redirect_seeds = MerkleRedirectSeeds { distribution: *distribution_address, claimant: *claimant };
+    let redirect_bump = redirect_seeds.validate_pda_address(ix.accounts.redirect_account, &ID)?;
+
+    // The original claimant starts the first transfer; after that only the current owner can move it
+    if is_pda_uninitialized(ix.accounts.redirect_account) {
+        if owner != claimant {
+            return Err(RewardsProgramError::UnauthorizedRecipient.into());
+        }
+
+        let redirect_bump_seed = [redirect_bump];
+        let redirect_pda_seeds = redirect_seeds.seeds_with_bump(&redirect_bump_seed);
+        let redirect_pda_seeds_array: [_; 4] =
+            redirect_pda_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+        create_pda_account(
+            ix.accounts.payer,
+            MerkleRedirect::LEN,
+            &ID,
+            ix.accounts.redirect_account,
+            redirect_pda_seeds_array,
+        )?;
+    } else {
+        let redirect_data = ix.accounts.redirect_account.try_borrow()?;
+        let redirect = MerkleRedirect::from_account(
+            &redirect_data,
+            ix.accounts.redirect_account,
+            &ID,
+            distribution_address,
+            claimant,
+        )?;
+        drop(redirect_data);
+
+        if &redirect.new_claimant != owner {
+            return Err(RewardsProgramError::UnauthorizedRecipient.into());
+        }
+    }
+
+    let redirect = MerkleRedirect::new(redirect_bump, *new_owner);
+    let mut redirect_data = ix.accounts.redirect_account.try_borrow_mut()?;
+    redirect.write_to_slice(&mut redirect_data)?;
+    drop(redirect_data);
+
+    let event = RecipientTransferredEvent::new(*distribution_address, *owner, *new_owner);
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
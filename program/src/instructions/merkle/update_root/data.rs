@@ -0,0 +1,85 @@
+use pinocchio::error::ProgramError;
+
+use crate::{errors::RewardsProgramError, require_len, traits::InstructionData};
+
+/// Instruction data for UpdateMerkleRoot
+///
+/// - `merkle_root`: The new merkle root (leaves carry cumulative allocations).
+/// - `total_amount`: Total amount claimable by all recipients under the new root.
+/// - `epoch`: The new epoch; must be greater than the distribution's current epoch.
+pub struct UpdateMerkleRootData {
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub epoch: u64,
+}
+
+impl<'a> TryFrom<&'a [u8]> for UpdateMerkleRootData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let merkle_root: [u8; 32] = data[..32].try_into().map_err(|_| ProgramError::InvalidInstructionData)?;
+        let total_amount =
+            u64::from_le_bytes(data[32..40].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        let epoch = u64::from_le_bytes(data[40..48].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+
+        Ok(Self { merkle_root, total_amount, epoch })
+    }
+}
+
+impl<'a> InstructionData<'a> for UpdateMerkleRootData {
+    const LEN: usize = 32 + 8 + 8; // merkle_root + total_amount + epoch = 48
+
+    fn validate(&self) -> Result<(), ProgramError> {
+        if self.total_amount == 0 {
+            return Err(RewardsProgramError::InvalidAmount.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_valid_data() -> [u8; 48] {
+        let mut data = [0u8; 48];
+        data[..32].copy_from_slice(&[1u8; 32]); // merkle_root
+        data[32..40].copy_from_slice(&5000u64.to_le_bytes()); // total_amount
+        data[40..48].copy_from_slice(&2u64.to_le_bytes()); // epoch
+        data
+    }
+
+    #[test]
+    fn test_try_from_valid_data() {
+        let data = create_valid_data();
+        let parsed = UpdateMerkleRootData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.merkle_root, [1u8; 32]);
+        assert_eq!(parsed.total_amount, 5000);
+        assert_eq!(parsed.epoch, 2);
+    }
+
+    #[test]
+    fn test_try_from_data_too_short() {
+        let data = [0u8; 47];
+        let result = UpdateMerkleRootData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_validate_zero_total_amount() {
+        let mut data = create_valid_data();
+        data[32..40].copy_from_slice(&0u64.to_le_bytes());
+        let parsed = UpdateMerkleRootData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.validate(), Err(RewardsProgramError::InvalidAmount.into()));
+    }
+
+    #[test]
+    fn test_validate_success() {
+        let data = create_valid_data();
+        let parsed = UpdateMerkleRootData::try_from(&data[..]).unwrap();
+        assert!(parsed.validate().is_ok());
+    }
+}
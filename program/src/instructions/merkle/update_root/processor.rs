@@ -0,0 +1,44 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    events::MerkleRootUpdatedEvent,
+    state::MerkleDistribution,
+    traits::{Distribution, EventSerialize, InstructionData},
+    utils::{emit_event, save_account, MerkleClaimMode},
+    ID,
+};
+
+use super::UpdateMerkleRoot;
+
+pub fn process_update_merkle_root(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = UpdateMerkleRoot::try_from((instruction_data, accounts))?;
+    ix.data.validate()?;
+
+    let distribution_data = ix.accounts.distribution.try_borrow()?;
+    let mut distribution = MerkleDistribution::from_account(&distribution_data, ix.accounts.distribution, &ID)?;
+    drop(distribution_data);
+
+    distribution.validate_authority(ix.accounts.authority.address())?;
+    // Bitmap claim state is keyed by leaf index, which a new root may reassign
+    distribution.validate_claim_mode(MerkleClaimMode::ClaimAccount {})?;
+
+    // Claim accounts are keyed by (distribution, claimant) only, so each claimant's
+    // claimed_amount carries over and is netted against their cumulative leaf amount.
+    distribution.update_root(ix.data.merkle_root, ix.data.total_amount, ix.data.epoch)?;
+
+    save_account(ix.accounts.distribution, &distribution, None)?;
+
+    let event = MerkleRootUpdatedEvent::new(
+        *ix.accounts.distribution.address(),
+        distribution.merkle_root,
+        distribution.total_amount,
+        distribution.epoch,
+    );
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{verify_current_program_account, verify_signer, verify_system_program, verify_writable},
+};
+
+pub struct MigrateAccountAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub account: &'a AccountView,
+    pub system_program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for MigrateAccountAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, account, system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+
+        // 2. Validate writable
+        verify_writable(account, true)?;
+
+        // 3. Validate program IDs
+        verify_system_program(system_program)?;
+
+        // 4. Validate accounts owned by current program
+        verify_current_program_account(account)?;
+
+        Ok(Self { payer, account, system_program })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for MigrateAccountAccounts<'a> {}
@@ -0,0 +1,53 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    state::{DirectDistribution, DirectRecipient, MerkleClaim, MerkleDistribution},
+    traits::{Discriminator, Migrate},
+    utils::resize_pda_account,
+};
+
+use super::MigrateAccount;
+
+pub fn process_migrate_account(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = MigrateAccount::try_from((instruction_data, accounts))?;
+
+    let data = ix.accounts.account.try_borrow()?;
+    let discriminator = *data.first().ok_or(ProgramError::InvalidAccountData)?;
+    drop(data);
+
+    match discriminator {
+        DirectDistribution::DISCRIMINATOR => {
+            migrate_account::<DirectDistribution>(ix.accounts.payer, ix.accounts.account)
+        }
+        MerkleDistribution::DISCRIMINATOR => {
+            migrate_account::<MerkleDistribution>(ix.accounts.payer, ix.accounts.account)
+        }
+        DirectRecipient::DISCRIMINATOR => migrate_account::<DirectRecipient>(ix.accounts.payer, ix.accounts.account),
+        MerkleClaim::DISCRIMINATOR => migrate_account::<MerkleClaim>(ix.accounts.payer, ix.accounts.account),
+        // Every other account type still has its original layout
+        _ => Err(RewardsProgramError::AccountAlreadyMigrated.into()),
+    }
+}
+
+/// Rewrites `account` at `T::VERSION`, growing it first so the current layout fits.
+fn migrate_account<T: Migrate>(payer: &AccountView, account: &AccountView) -> ProgramResult {
+    let data = account.try_borrow()?;
+    if T::stored_version(&data)? == T::VERSION {
+        return Err(RewardsProgramError::AccountAlreadyMigrated.into());
+    }
+    let state = T::parse_from_bytes(&data)?;
+    drop(data);
+
+    // Sized from the state, since some layouts end in a variable-length schedule
+    let bytes = state.to_bytes();
+    resize_pda_account(payer, account, bytes.len())?;
+
+    let mut data = account.try_borrow_mut()?;
+    data[..bytes.len()].copy_from_slice(&bytes);
+    Ok(())
+}
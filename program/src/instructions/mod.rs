@@ -1,8 +1,16 @@
+pub mod authority;
+pub mod basket;
+pub mod compressed;
 pub mod definition;
+pub mod delegate;
 pub mod direct;
 pub mod emit_event;
+pub mod funding;
 pub mod impl_instructions;
 pub mod merkle;
+pub mod migration;
+pub mod pause;
+pub mod transfer;
 
 pub use definition::*;
 pub use impl_instructions::*;
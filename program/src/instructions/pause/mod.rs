@@ -0,0 +1,2 @@
+pub mod pause_distribution;
+pub mod unpause_distribution;
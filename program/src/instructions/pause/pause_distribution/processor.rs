@@ -0,0 +1,43 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    events::DistributionPausedEvent,
+    state::{CompressedDistribution, DirectDistribution, MerkleDistribution},
+    traits::{Distribution, EventSerialize},
+    utils::{
+        emit_event, get_current_timestamp, get_distribution_kind, load_distribution, save_distribution,
+        DistributionKind,
+    },
+    ID,
+};
+
+use super::PauseDistribution;
+
+pub fn process_pause_distribution(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = PauseDistribution::try_from((instruction_data, accounts))?;
+    let current_ts = get_current_timestamp()?;
+
+    match get_distribution_kind(ix.accounts.distribution)? {
+        DistributionKind::Direct => pause_distribution::<DirectDistribution>(&ix, current_ts)?,
+        DistributionKind::Merkle => pause_distribution::<MerkleDistribution>(&ix, current_ts)?,
+        DistributionKind::Compressed => pause_distribution::<CompressedDistribution>(&ix, current_ts)?,
+    }
+
+    let event = DistributionPausedEvent::new(*ix.accounts.distribution.address(), current_ts);
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
+
+fn pause_distribution<D: Distribution>(ix: &PauseDistribution, current_ts: i64) -> ProgramResult {
+    let mut distribution: D = load_distribution(ix.accounts.distribution)?;
+    distribution.validate_authority(ix.accounts.authority.address())?;
+    distribution.validate_not_settled()?;
+
+    distribution.pause(current_ts)?;
+    save_distribution(ix.accounts.distribution, &distribution)
+}
@@ -0,0 +1,56 @@
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, traits::InstructionData};
+
+/// Instruction data for UnpauseDistribution
+///
+/// - `shift_vesting`: 1 to exclude the paused period from vesting, 0 to let vesting catch up.
+pub struct UnpauseDistributionData {
+    pub shift_vesting: u8,
+}
+
+impl<'a> TryFrom<&'a [u8]> for UnpauseDistributionData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        Ok(Self { shift_vesting: data[0] })
+    }
+}
+
+impl<'a> InstructionData<'a> for UnpauseDistributionData {
+    const LEN: usize = 1;
+
+    fn validate(&self) -> Result<(), ProgramError> {
+        if self.shift_vesting > 1 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_valid_data() {
+        let result = UnpauseDistributionData::try_from(&[1u8][..]).unwrap();
+        assert_eq!(result.shift_vesting, 1);
+        assert!(result.validate().is_ok());
+    }
+
+    #[test]
+    fn test_try_from_insufficient_data() {
+        let result = UnpauseDistributionData::try_from(&[][..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_validate_rejects_non_boolean_value() {
+        let result = UnpauseDistributionData::try_from(&[2u8][..]).unwrap();
+        assert_eq!(result.validate(), Err(ProgramError::InvalidInstructionData));
+    }
+}
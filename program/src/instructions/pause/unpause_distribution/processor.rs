@@ -0,0 +1,46 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    events::DistributionUnpausedEvent,
+    state::{CompressedDistribution, DirectDistribution, MerkleDistribution},
+    traits::{Distribution, EventSerialize, InstructionData},
+    utils::{
+        emit_event, get_current_timestamp, get_distribution_kind, load_distribution, save_distribution,
+        DistributionKind,
+    },
+    ID,
+};
+
+use super::UnpauseDistribution;
+
+pub fn process_unpause_distribution(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = UnpauseDistribution::try_from((instruction_data, accounts))?;
+    ix.data.validate()?;
+    let current_ts = get_current_timestamp()?;
+
+    let vesting_shift = match get_distribution_kind(ix.accounts.distribution)? {
+        DistributionKind::Direct => unpause_distribution::<DirectDistribution>(&ix, current_ts)?,
+        DistributionKind::Merkle => unpause_distribution::<MerkleDistribution>(&ix, current_ts)?,
+        DistributionKind::Compressed => unpause_distribution::<CompressedDistribution>(&ix, current_ts)?,
+    };
+
+    let event = DistributionUnpausedEvent::new(*ix.accounts.distribution.address(), current_ts, vesting_shift);
+    emit_event(&ID, ix.accounts.event_authority, ix.accounts.program, &event.to_bytes())?;
+
+    Ok(())
+}
+
+/// Clears the paused flag and returns the number of seconds vesting was shifted by.
+fn unpause_distribution<D: Distribution>(ix: &UnpauseDistribution, current_ts: i64) -> Result<i64, ProgramError> {
+    let mut distribution: D = load_distribution(ix.accounts.distribution)?;
+    distribution.validate_authority(ix.accounts.authority.address())?;
+
+    let vesting_shift = distribution.unpause(current_ts, ix.data.shift_vesting == 1)?;
+    save_distribution(ix.accounts.distribution, &distribution)?;
+
+    Ok(vesting_shift)
+}
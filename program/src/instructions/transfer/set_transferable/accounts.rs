@@ -0,0 +1,35 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{verify_current_program_account, verify_signer, verify_writable},
+};
+
+pub struct SetTransferableAccounts<'a> {
+    pub authority: &'a AccountView,
+    pub distribution: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for SetTransferableAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [authority, distribution] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(authority, false)?;
+
+        // 2. Validate writable
+        verify_writable(distribution, true)?;
+
+        // 3. Validate accounts owned by current program
+        verify_current_program_account(distribution)?;
+
+        Ok(Self { authority, distribution })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for SetTransferableAccounts<'a> {}
@@ -0,0 +1,56 @@
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, traits::InstructionData};
+
+/// Instruction data for SetTransferable
+///
+/// - `transferable`: 1 to allow recipient-initiated position transfers, 0 to disallow them.
+pub struct SetTransferableData {
+    pub transferable: u8,
+}
+
+impl<'a> TryFrom<&'a [u8]> for SetTransferableData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        Ok(Self { transferable: data[0] })
+    }
+}
+
+impl<'a> InstructionData<'a> for SetTransferableData {
+    const LEN: usize = 1;
+
+    fn validate(&self) -> Result<(), ProgramError> {
+        if self.transferable > 1 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_valid_data() {
+        let result = SetTransferableData::try_from(&[1u8][..]).unwrap();
+        assert_eq!(result.transferable, 1);
+        assert!(result.validate().is_ok());
+    }
+
+    #[test]
+    fn test_try_from_insufficient_data() {
+        let result = SetTransferableData::try_from(&[][..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_validate_rejects_non_boolean_value() {
+        let result = SetTransferableData::try_from(&[2u8][..]).unwrap();
+        assert_eq!(result.validate(), Err(ProgramError::InvalidInstructionData));
+    }
+}
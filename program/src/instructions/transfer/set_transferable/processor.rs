@@ -0,0 +1,34 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    state::{DirectDistribution, MerkleDistribution},
+    traits::{Distribution, InstructionData},
+    utils::{get_distribution_kind, load_distribution, save_distribution, DistributionKind},
+};
+
+use super::SetTransferable;
+
+pub fn process_set_transferable(
+    _program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = SetTransferable::try_from((instruction_data, accounts))?;
+    ix.data.validate()?;
+
+    match get_distribution_kind(ix.accounts.distribution)? {
+        DistributionKind::Direct => set_transferable::<DirectDistribution>(&ix),
+        DistributionKind::Merkle => set_transferable::<MerkleDistribution>(&ix),
+        // Compressed recipients have no transfer instruction
+        DistributionKind::Compressed => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+fn set_transferable<D: Distribution>(ix: &SetTransferable) -> ProgramResult {
+    let mut distribution: D = load_distribution(ix.accounts.distribution)?;
+    distribution.validate_authority(ix.accounts.authority.address())?;
+    distribution.validate_not_settled()?;
+
+    distribution.set_transferable(ix.data.transferable == 1);
+    save_distribution(ix.accounts.distribution, &distribution)
+}
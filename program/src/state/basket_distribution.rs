@@ -0,0 +1,368 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use codama::CodamaAccount;
+use pinocchio::{
+    account::AccountView,
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    Address,
+};
+
+use crate::errors::RewardsProgramError;
+use crate::traits::{
+    AccountParse, AccountSerialize, AccountSize, AccountValidation, Discriminator, DistributionSigner, PdaAccount,
+    PdaSeeds, RewardsAccountDiscriminators, Versioned,
+};
+use crate::{assert_no_padding, require_account_len, validate_discriminator, validate_version};
+
+/// Maximum number of mints a basket distribution can pay out
+pub const MAX_BASKET_MINTS: usize = 4;
+
+/// BasketDistribution account state
+///
+/// A direct distribution paying up to `MAX_BASKET_MINTS` mints at once. Each
+/// mint has its own vault (the distribution PDA's ATA, or a native vault), and
+/// every recipient's amounts share one vesting schedule. Unused mint slots are
+/// zeroed; slot order is fixed at creation and instructions pass their per-mint
+/// accounts in the same order.
+///
+/// Array lengths are spelled out as `MAX_BASKET_MINTS` literals so codama can
+/// size them in the IDL.
+///
+/// # PDA Seeds
+/// `[b"basket_distribution", authority.as_ref(), seeds.as_ref()]`
+#[derive(Clone, Debug, PartialEq, CodamaAccount)]
+#[repr(C)]
+pub struct BasketDistribution {
+    pub bump: u8,
+    pub mint_count: u8,
+    _padding: [u8; 6],
+    pub authority: Address,
+    pub seed: Address,
+    pub clawback_ts: i64,
+    pub mints: [Address; 4],
+    pub total_allocated: [u64; 4],
+    pub total_claimed: [u64; 4],
+}
+
+assert_no_padding!(BasketDistribution, 1 + 1 + 6 + 32 + 32 + 8 + 32 * 4 + 8 * 4 + 8 * 4);
+
+impl Discriminator for BasketDistribution {
+    const DISCRIMINATOR: u8 = RewardsAccountDiscriminators::BasketDistribution as u8;
+}
+
+impl Versioned for BasketDistribution {
+    const VERSION: u8 = 1;
+}
+
+impl AccountSize for BasketDistribution {
+    const DATA_LEN: usize = 1 + 1 + 6 + 32 + 32 + 8 + 32 * 4 + 8 * 4 + 8 * 4; // 272
+}
+
+impl AccountParse for BasketDistribution {
+    fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_account_len!(data, Self::LEN);
+        validate_discriminator!(data, Self::DISCRIMINATOR);
+        validate_version!(data, Self::VERSION);
+
+        // Skip discriminator (byte 0) and version (byte 1)
+        let data = &data[2..];
+
+        let bump = data[0];
+        let mint_count = data[1];
+        // Skip padding bytes [2..8]
+        let authority =
+            Address::new_from_array(data[8..40].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        let seed =
+            Address::new_from_array(data[40..72].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        let clawback_ts =
+            i64::from_le_bytes(data[72..80].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+
+        let mut mints = [Address::default(); MAX_BASKET_MINTS];
+        let mut total_allocated = [0u64; MAX_BASKET_MINTS];
+        let mut total_claimed = [0u64; MAX_BASKET_MINTS];
+        for i in 0..MAX_BASKET_MINTS {
+            let mint_offset = 80 + i * 32;
+            mints[i] = Address::new_from_array(
+                data[mint_offset..mint_offset + 32].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?,
+            );
+            let allocated_offset = 208 + i * 8;
+            total_allocated[i] = u64::from_le_bytes(
+                data[allocated_offset..allocated_offset + 8]
+                    .try_into()
+                    .map_err(|_| RewardsProgramError::InvalidAccountData)?,
+            );
+            let claimed_offset = 240 + i * 8;
+            total_claimed[i] = u64::from_le_bytes(
+                data[claimed_offset..claimed_offset + 8]
+                    .try_into()
+                    .map_err(|_| RewardsProgramError::InvalidAccountData)?,
+            );
+        }
+
+        Ok(Self {
+            bump,
+            mint_count,
+            _padding: [0u8; 6],
+            authority,
+            seed,
+            clawback_ts,
+            mints,
+            total_allocated,
+            total_claimed,
+        })
+    }
+}
+
+impl AccountSerialize for BasketDistribution {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.push(self.bump);
+        data.push(self.mint_count);
+        data.extend_from_slice(&[0u8; 6]); // padding
+        data.extend_from_slice(self.authority.as_ref());
+        data.extend_from_slice(self.seed.as_ref());
+        data.extend_from_slice(&self.clawback_ts.to_le_bytes());
+        for mint in &self.mints {
+            data.extend_from_slice(mint.as_ref());
+        }
+        for allocated in &self.total_allocated {
+            data.extend_from_slice(&allocated.to_le_bytes());
+        }
+        for claimed in &self.total_claimed {
+            data.extend_from_slice(&claimed.to_le_bytes());
+        }
+        data
+    }
+}
+
+impl AccountValidation for BasketDistribution {}
+
+impl PdaSeeds for BasketDistribution {
+    const PREFIX: &'static [u8] = b"basket_distribution";
+
+    fn seeds(&self) -> Vec<&[u8]> {
+        vec![Self::PREFIX, self.authority.as_ref(), self.seed.as_ref()]
+    }
+
+    fn seeds_with_bump<'a>(&'a self, bump: &'a [u8; 1]) -> Vec<Seed<'a>> {
+        vec![
+            Seed::from(Self::PREFIX),
+            Seed::from(self.authority.as_ref()),
+            Seed::from(self.seed.as_ref()),
+            Seed::from(bump.as_slice()),
+        ]
+    }
+}
+
+impl PdaAccount for BasketDistribution {
+    #[inline(always)]
+    fn bump(&self) -> u8 {
+        self.bump
+    }
+}
+
+impl DistributionSigner for BasketDistribution {
+    #[inline(always)]
+    fn with_signer<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&[Signer<'_, '_>]) -> R,
+    {
+        let bump_seed = [self.bump];
+        let pda_seeds = [
+            Seed::from(Self::PREFIX),
+            Seed::from(self.authority.as_ref()),
+            Seed::from(self.seed.as_ref()),
+            Seed::from(bump_seed.as_slice()),
+        ];
+        let signers = [Signer::from(&pda_seeds)];
+        f(&signers)
+    }
+}
+
+impl BasketDistribution {
+    /// Create a basket paying `mints`, which must be 1 to `MAX_BASKET_MINTS` distinct addresses.
+    #[inline(always)]
+    pub fn new(
+        bump: u8,
+        clawback_ts: i64,
+        authority: Address,
+        seed: Address,
+        mints: &[Address],
+    ) -> Result<Self, ProgramError> {
+        if mints.is_empty() || mints.len() > MAX_BASKET_MINTS {
+            return Err(RewardsProgramError::InvalidBasketMints.into());
+        }
+        for (i, mint) in mints.iter().enumerate() {
+            if mints[..i].contains(mint) {
+                return Err(RewardsProgramError::InvalidBasketMints.into());
+            }
+        }
+
+        let mut basket_mints = [Address::default(); MAX_BASKET_MINTS];
+        basket_mints[..mints.len()].copy_from_slice(mints);
+
+        Ok(Self {
+            bump,
+            mint_count: mints.len() as u8,
+            _padding: [0u8; 6],
+            authority,
+            seed,
+            clawback_ts,
+            mints: basket_mints,
+            total_allocated: [0u64; MAX_BASKET_MINTS],
+            total_claimed: [0u64; MAX_BASKET_MINTS],
+        })
+    }
+
+    #[inline(always)]
+    pub fn from_account(data: &[u8], account: &AccountView, program_id: &Address) -> Result<Self, ProgramError> {
+        let state = Self::parse_from_bytes(data)?;
+        state.validate_self(account, program_id)?;
+        Ok(state)
+    }
+
+    /// Mints in use, in slot order
+    #[inline(always)]
+    pub fn active_mints(&self) -> &[Address] {
+        &self.mints[..self.mint_count as usize]
+    }
+
+    #[inline(always)]
+    pub fn validate_authority(&self, authority: &Address) -> Result<(), ProgramError> {
+        if &self.authority != authority {
+            return Err(RewardsProgramError::UnauthorizedAuthority.into());
+        }
+        Ok(())
+    }
+
+    /// Verify the mint accounts passed to an instruction match the basket's mints, in order
+    #[inline(always)]
+    pub fn validate_mints<'a>(&self, mints: impl ExactSizeIterator<Item = &'a Address>) -> Result<(), ProgramError> {
+        if mints.len() != self.mint_count as usize {
+            return Err(RewardsProgramError::InvalidBasketAccounts.into());
+        }
+        for (mint, expected) in mints.zip(self.active_mints()) {
+            if mint != expected {
+                return Err(RewardsProgramError::InvalidBasketAccounts.into());
+            }
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn add_allocated(&mut self, index: usize, amount: u64) -> Result<(), ProgramError> {
+        self.total_allocated[index] =
+            self.total_allocated[index].checked_add(amount).ok_or(RewardsProgramError::MathOverflow)?;
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn add_claimed(&mut self, index: usize, amount: u64) -> Result<(), ProgramError> {
+        self.total_claimed[index] =
+            self.total_claimed[index].checked_add(amount).ok_or(RewardsProgramError::MathOverflow)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mint(byte: u8) -> Address {
+        Address::new_from_array([byte; 32])
+    }
+
+    fn create_test_basket() -> BasketDistribution {
+        BasketDistribution::new(255, 0, mint(1), mint(2), &[mint(10), mint(11)]).unwrap()
+    }
+
+    #[test]
+    fn test_basket_distribution_new() {
+        let basket = create_test_basket();
+        assert_eq!(basket.bump, 255);
+        assert_eq!(basket.mint_count, 2);
+        assert_eq!(basket.active_mints(), &[mint(10), mint(11)]);
+        assert_eq!(basket.mints[2], Address::default());
+        assert_eq!(basket.total_allocated, [0; MAX_BASKET_MINTS]);
+        assert_eq!(basket.total_claimed, [0; MAX_BASKET_MINTS]);
+    }
+
+    #[test]
+    fn test_basket_distribution_new_rejects_empty() {
+        let result = BasketDistribution::new(255, 0, mint(1), mint(2), &[]);
+        assert_eq!(result.err(), Some(RewardsProgramError::InvalidBasketMints.into()));
+    }
+
+    #[test]
+    fn test_basket_distribution_new_rejects_too_many() {
+        let mints = [mint(10), mint(11), mint(12), mint(13), mint(14)];
+        let result = BasketDistribution::new(255, 0, mint(1), mint(2), &mints);
+        assert_eq!(result.err(), Some(RewardsProgramError::InvalidBasketMints.into()));
+    }
+
+    #[test]
+    fn test_basket_distribution_new_rejects_duplicates() {
+        let result = BasketDistribution::new(255, 0, mint(1), mint(2), &[mint(10), mint(11), mint(10)]);
+        assert_eq!(result.err(), Some(RewardsProgramError::InvalidBasketMints.into()));
+    }
+
+    #[test]
+    fn test_basket_distribution_to_bytes() {
+        let basket = create_test_basket();
+        let bytes = basket.to_bytes();
+
+        assert_eq!(bytes.len(), BasketDistribution::LEN);
+        assert_eq!(bytes[0], BasketDistribution::DISCRIMINATOR);
+        assert_eq!(bytes[1], BasketDistribution::VERSION);
+        assert_eq!(bytes[2], 255); // bump
+        assert_eq!(bytes[3], 2); // mint_count
+    }
+
+    #[test]
+    fn test_roundtrip_serialization() {
+        let mut basket = create_test_basket();
+        basket.add_allocated(0, 1000).unwrap();
+        basket.add_allocated(1, 50).unwrap();
+        basket.add_claimed(1, 20).unwrap();
+
+        let bytes = basket.to_bytes();
+        let deserialized = BasketDistribution::parse_from_bytes(&bytes).unwrap();
+
+        assert_eq!(deserialized, basket);
+    }
+
+    #[test]
+    fn test_pda_seeds() {
+        let basket = create_test_basket();
+        let seeds = basket.seeds();
+
+        assert_eq!(seeds.len(), 3);
+        assert_eq!(seeds[0], BasketDistribution::PREFIX);
+        assert_eq!(seeds[1], basket.authority.as_ref());
+        assert_eq!(seeds[2], basket.seed.as_ref());
+    }
+
+    #[test]
+    fn test_validate_mints() {
+        let basket = create_test_basket();
+        assert!(basket.validate_mints([mint(10), mint(11)].iter()).is_ok());
+        assert!(basket.validate_mints([mint(11), mint(10)].iter()).is_err());
+        assert!(basket.validate_mints([mint(10)].iter()).is_err());
+    }
+
+    #[test]
+    fn test_validate_authority() {
+        let basket = create_test_basket();
+        assert!(basket.validate_authority(&mint(1)).is_ok());
+        assert!(basket.validate_authority(&mint(99)).is_err());
+    }
+
+    #[test]
+    fn test_add_claimed_overflow() {
+        let mut basket = create_test_basket();
+        basket.add_claimed(0, u64::MAX).unwrap();
+        assert!(basket.add_claimed(0, 1).is_err());
+    }
+}
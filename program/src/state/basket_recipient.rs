@@ -0,0 +1,326 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use codama::CodamaAccount;
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address};
+
+use crate::errors::RewardsProgramError;
+use crate::state::MAX_BASKET_MINTS;
+use crate::traits::{
+    AccountParse, AccountSerialize, AccountSize, AccountValidation, ClaimTracker, Discriminator, PdaAccount, PdaSeeds,
+    RewardsAccountDiscriminators, Versioned, ACCOUNT_HEADER_SIZE,
+};
+use crate::utils::VestingSchedule;
+use crate::{require_account_len, validate_discriminator, validate_version};
+
+/// BasketRecipient account state
+///
+/// A recipient's allocation within a basket distribution: one amount per
+/// basket mint, all vesting on the same schedule. Slot `i` of the amount
+/// arrays belongs to `BasketDistribution::mints[i]`.
+///
+/// Fixed fields first, variable-length schedule last.
+///
+/// # PDA Seeds
+/// `[b"basket_recipient", distribution.as_ref(), recipient.as_ref()]`
+#[derive(Clone, Debug, PartialEq, CodamaAccount)]
+pub struct BasketRecipient {
+    pub bump: u8,
+    pub distribution: Address,
+    pub recipient: Address,
+    pub payer: Address,
+    pub total_amounts: [u64; 4],
+    pub claimed_amounts: [u64; 4],
+    pub schedule: VestingSchedule,
+}
+
+/// Fixed fields size: bump(1) + distribution(32) + recipient(32) + payer(32) + total_amounts(32) + claimed_amounts(32)
+const FIXED_DATA_LEN: usize = 1 + 32 + 32 + 32 + 8 * MAX_BASKET_MINTS + 8 * MAX_BASKET_MINTS;
+
+/// One mint's slice of a basket allocation.
+///
+/// Claims are tracked per mint through `ClaimTracker`, exactly like a single-mint recipient.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BasketLeg {
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+}
+
+impl ClaimTracker for BasketLeg {
+    #[inline(always)]
+    fn claimed_amount(&self) -> u64 {
+        self.claimed_amount
+    }
+
+    #[inline(always)]
+    fn set_claimed_amount(&mut self, amount: u64) -> Result<(), ProgramError> {
+        if amount < self.claimed_amount {
+            return Err(RewardsProgramError::ClaimedAmountDecreased.into());
+        }
+        self.claimed_amount = amount;
+        Ok(())
+    }
+}
+
+impl Discriminator for BasketRecipient {
+    const DISCRIMINATOR: u8 = RewardsAccountDiscriminators::BasketRecipient as u8;
+}
+
+impl Versioned for BasketRecipient {
+    const VERSION: u8 = 1;
+}
+
+impl AccountSize for BasketRecipient {
+    /// Minimum DATA_LEN: fixed fields (161) + smallest schedule variant (Immediate = 1 byte) = 162
+    const DATA_LEN: usize = FIXED_DATA_LEN + 1;
+}
+
+impl AccountParse for BasketRecipient {
+    fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_account_len!(data, Self::LEN);
+        validate_discriminator!(data, Self::DISCRIMINATOR);
+        validate_version!(data, Self::VERSION);
+
+        // Skip discriminator (byte 0) and version (byte 1)
+        let data = &data[2..];
+
+        let bump = data[0];
+        let distribution =
+            Address::new_from_array(data[1..33].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        let recipient =
+            Address::new_from_array(data[33..65].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        let payer =
+            Address::new_from_array(data[65..97].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+
+        let mut total_amounts = [0u64; MAX_BASKET_MINTS];
+        let mut claimed_amounts = [0u64; MAX_BASKET_MINTS];
+        for i in 0..MAX_BASKET_MINTS {
+            let total_offset = 97 + i * 8;
+            total_amounts[i] = u64::from_le_bytes(
+                data[total_offset..total_offset + 8].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?,
+            );
+            let claimed_offset = 129 + i * 8;
+            claimed_amounts[i] = u64::from_le_bytes(
+                data[claimed_offset..claimed_offset + 8]
+                    .try_into()
+                    .map_err(|_| RewardsProgramError::InvalidAccountData)?,
+            );
+        }
+        let (schedule, _) = VestingSchedule::from_bytes(&data[FIXED_DATA_LEN..])?;
+
+        Ok(Self { bump, distribution, recipient, payer, total_amounts, claimed_amounts, schedule })
+    }
+}
+
+impl AccountSerialize for BasketRecipient {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(FIXED_DATA_LEN + self.schedule.byte_len());
+        data.push(self.bump);
+        data.extend_from_slice(self.distribution.as_ref());
+        data.extend_from_slice(self.recipient.as_ref());
+        data.extend_from_slice(self.payer.as_ref());
+        for amount in &self.total_amounts {
+            data.extend_from_slice(&amount.to_le_bytes());
+        }
+        for amount in &self.claimed_amounts {
+            data.extend_from_slice(&amount.to_le_bytes());
+        }
+        data.extend_from_slice(&self.schedule.to_bytes());
+        data
+    }
+}
+
+impl AccountValidation for BasketRecipient {}
+
+impl PdaSeeds for BasketRecipient {
+    const PREFIX: &'static [u8] = b"basket_recipient";
+
+    #[inline(always)]
+    fn seeds(&self) -> Vec<&[u8]> {
+        vec![Self::PREFIX, self.distribution.as_ref(), self.recipient.as_ref()]
+    }
+
+    #[inline(always)]
+    fn seeds_with_bump<'a>(&'a self, bump: &'a [u8; 1]) -> Vec<Seed<'a>> {
+        vec![
+            Seed::from(Self::PREFIX),
+            Seed::from(self.distribution.as_ref()),
+            Seed::from(self.recipient.as_ref()),
+            Seed::from(bump.as_slice()),
+        ]
+    }
+}
+
+impl PdaAccount for BasketRecipient {
+    #[inline(always)]
+    fn bump(&self) -> u8 {
+        self.bump
+    }
+}
+
+impl BasketRecipient {
+    pub fn calculate_account_size(schedule: &VestingSchedule) -> usize {
+        ACCOUNT_HEADER_SIZE + FIXED_DATA_LEN + schedule.byte_len()
+    }
+
+    #[inline(always)]
+    pub fn new(
+        bump: u8,
+        distribution: Address,
+        recipient: Address,
+        payer: Address,
+        total_amounts: [u64; MAX_BASKET_MINTS],
+        schedule: VestingSchedule,
+    ) -> Self {
+        Self {
+            bump,
+            distribution,
+            recipient,
+            payer,
+            total_amounts,
+            claimed_amounts: [0u64; MAX_BASKET_MINTS],
+            schedule,
+        }
+    }
+
+    #[inline(always)]
+    pub fn from_account(data: &[u8], account: &AccountView, program_id: &Address) -> Result<Self, ProgramError> {
+        let state = Self::parse_from_bytes(data)?;
+        state.validate_self(account, program_id)?;
+        Ok(state)
+    }
+
+    /// Allocation and claim progress for the mint in slot `index`
+    #[inline(always)]
+    pub fn leg(&self, index: usize) -> BasketLeg {
+        BasketLeg { total_amount: self.total_amounts[index], claimed_amount: self.claimed_amounts[index] }
+    }
+
+    #[inline(always)]
+    pub fn set_leg(&mut self, index: usize, leg: BasketLeg) {
+        self.total_amounts[index] = leg.total_amount;
+        self.claimed_amounts[index] = leg.claimed_amount;
+    }
+
+    /// Amount of the mint in slot `index` claimable at `vesting_ts`
+    #[inline(always)]
+    pub fn claimable_amount(&self, index: usize, vesting_ts: i64) -> Result<u64, ProgramError> {
+        let leg = self.leg(index);
+        let unlocked = self.schedule.calculate_unlocked(leg.total_amount, vesting_ts)?;
+        Ok(leg.claimable_amount(unlocked)?)
+    }
+
+    #[inline(always)]
+    pub fn validate_distribution(&self, distribution: &Address) -> Result<(), ProgramError> {
+        if &self.distribution != distribution {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn validate_recipient(&self, recipient: &Address) -> Result<(), ProgramError> {
+        if &self.recipient != recipient {
+            return Err(RewardsProgramError::UnauthorizedRecipient.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_recipient() -> BasketRecipient {
+        BasketRecipient::new(
+            255,
+            Address::new_from_array([1u8; 32]),
+            Address::new_from_array([2u8; 32]),
+            Address::new_from_array([3u8; 32]),
+            [1000, 200, 0, 0],
+            VestingSchedule::Linear { start_ts: 100, end_ts: 200 },
+        )
+    }
+
+    #[test]
+    fn test_basket_recipient_new() {
+        let recipient = create_test_recipient();
+        assert_eq!(recipient.bump, 255);
+        assert_eq!(recipient.total_amounts, [1000, 200, 0, 0]);
+        assert_eq!(recipient.claimed_amounts, [0; MAX_BASKET_MINTS]);
+    }
+
+    #[test]
+    fn test_basket_recipient_to_bytes() {
+        let recipient = create_test_recipient();
+        let bytes = recipient.to_bytes();
+
+        assert_eq!(bytes.len(), BasketRecipient::calculate_account_size(&recipient.schedule));
+        assert_eq!(bytes[0], BasketRecipient::DISCRIMINATOR);
+        assert_eq!(bytes[1], BasketRecipient::VERSION);
+        assert_eq!(bytes[2], 255); // bump
+    }
+
+    #[test]
+    fn test_roundtrip_serialization() {
+        let mut recipient = create_test_recipient();
+        recipient.claimed_amounts = [500, 100, 0, 0];
+
+        let bytes = recipient.to_bytes();
+        let deserialized = BasketRecipient::parse_from_bytes(&bytes).unwrap();
+
+        assert_eq!(deserialized, recipient);
+    }
+
+    #[test]
+    fn test_pda_seeds() {
+        let recipient = create_test_recipient();
+        let seeds = recipient.seeds();
+
+        assert_eq!(seeds.len(), 3);
+        assert_eq!(seeds[0], BasketRecipient::PREFIX);
+        assert_eq!(seeds[1], recipient.distribution.as_ref());
+        assert_eq!(seeds[2], recipient.recipient.as_ref());
+    }
+
+    #[test]
+    fn test_claimable_amount_per_leg() {
+        let recipient = create_test_recipient();
+        // Midpoint of the schedule unlocks half of every leg
+        assert_eq!(recipient.claimable_amount(0, 150).unwrap(), 500);
+        assert_eq!(recipient.claimable_amount(1, 150).unwrap(), 100);
+        assert_eq!(recipient.claimable_amount(2, 150).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_leg_claim_tracking() {
+        let mut recipient = create_test_recipient();
+        let mut leg = recipient.leg(1);
+        leg.add_claimed(150).unwrap();
+        recipient.set_leg(1, leg);
+
+        assert_eq!(recipient.claimed_amounts, [0, 150, 0, 0]);
+        assert_eq!(recipient.claimable_amount(1, 250).unwrap(), 50);
+    }
+
+    #[test]
+    fn test_leg_rejects_claimed_decrease() {
+        let mut leg = BasketLeg { total_amount: 100, claimed_amount: 50 };
+        assert!(leg.set_claimed_amount(40).is_err());
+        assert_eq!(leg.claimed_amount, 50);
+    }
+
+    #[test]
+    fn test_validate_recipient() {
+        let recipient = create_test_recipient();
+        assert!(recipient.validate_recipient(&Address::new_from_array([2u8; 32])).is_ok());
+        assert!(recipient.validate_recipient(&Address::new_from_array([99u8; 32])).is_err());
+    }
+
+    #[test]
+    fn test_validate_distribution() {
+        let recipient = create_test_recipient();
+        assert!(recipient.validate_distribution(&Address::new_from_array([1u8; 32])).is_ok());
+        assert!(recipient.validate_distribution(&Address::new_from_array([99u8; 32])).is_err());
+    }
+}
@@ -0,0 +1,186 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use codama::CodamaAccount;
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address};
+
+use crate::errors::RewardsProgramError;
+use crate::traits::{
+    AccountParse, AccountSerialize, AccountSize, AccountValidation, Discriminator, PdaSeeds,
+    RewardsAccountDiscriminators, Versioned,
+};
+use crate::{require_account_len, validate_discriminator, validate_version};
+
+/// ClaimDelegate account state
+///
+/// Records the address a recipient (direct) or claimant (merkle) has authorized
+/// to claim on their behalf. The delegate may send claimed tokens to any token
+/// account; without a delegate, cranked claims can only pay the recipient's ATA.
+/// Rent is refunded to the recipient when the delegate is removed.
+///
+/// # PDA Seeds
+/// `[b"claim_delegate", distribution.as_ref(), recipient.as_ref()]`
+#[derive(Clone, Debug, PartialEq, CodamaAccount)]
+pub struct ClaimDelegate {
+    pub bump: u8,
+    pub delegate: Address,
+}
+
+impl Discriminator for ClaimDelegate {
+    const DISCRIMINATOR: u8 = RewardsAccountDiscriminators::ClaimDelegate as u8;
+}
+
+impl Versioned for ClaimDelegate {
+    const VERSION: u8 = 1;
+}
+
+impl AccountSize for ClaimDelegate {
+    const DATA_LEN: usize = 1 + 32; // bump + delegate
+}
+
+impl AccountParse for ClaimDelegate {
+    fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_account_len!(data, Self::LEN);
+        validate_discriminator!(data, Self::DISCRIMINATOR);
+        validate_version!(data, Self::VERSION);
+
+        // Skip discriminator (byte 0) and version (byte 1)
+        let data = &data[2..];
+
+        let bump = data[0];
+        let delegate =
+            Address::new_from_array(data[1..33].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+
+        Ok(Self { bump, delegate })
+    }
+}
+
+impl AccountSerialize for ClaimDelegate {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.push(self.bump);
+        data.extend_from_slice(self.delegate.as_ref());
+        data
+    }
+}
+
+impl AccountValidation for ClaimDelegate {}
+
+/// Seed helper for deriving ClaimDelegate PDA without having the full state
+pub struct ClaimDelegateSeeds {
+    pub distribution: Address,
+    pub recipient: Address,
+}
+
+impl PdaSeeds for ClaimDelegateSeeds {
+    const PREFIX: &'static [u8] = b"claim_delegate";
+
+    #[inline(always)]
+    fn seeds(&self) -> Vec<&[u8]> {
+        vec![Self::PREFIX, self.distribution.as_ref(), self.recipient.as_ref()]
+    }
+
+    #[inline(always)]
+    fn seeds_with_bump<'a>(&'a self, bump: &'a [u8; 1]) -> Vec<Seed<'a>> {
+        vec![
+            Seed::from(Self::PREFIX),
+            Seed::from(self.distribution.as_ref()),
+            Seed::from(self.recipient.as_ref()),
+            Seed::from(bump.as_slice()),
+        ]
+    }
+}
+
+impl ClaimDelegate {
+    #[inline(always)]
+    pub fn new(bump: u8, delegate: Address) -> Self {
+        Self { bump, delegate }
+    }
+
+    #[inline(always)]
+    pub fn from_account(
+        data: &[u8],
+        account: &AccountView,
+        program_id: &Address,
+        distribution: &Address,
+        recipient: &Address,
+    ) -> Result<Self, ProgramError> {
+        let state = Self::parse_from_bytes(data)?;
+        let seeds = ClaimDelegateSeeds { distribution: *distribution, recipient: *recipient };
+        seeds.validate_pda(account, program_id, state.bump)?;
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_delegate() -> ClaimDelegate {
+        ClaimDelegate::new(255, Address::new_from_array([3u8; 32]))
+    }
+
+    #[test]
+    fn test_claim_delegate_new() {
+        let delegate = create_test_delegate();
+        assert_eq!(delegate.bump, 255);
+        assert_eq!(delegate.delegate, Address::new_from_array([3u8; 32]));
+    }
+
+    #[test]
+    fn test_claim_delegate_to_bytes_inner() {
+        let delegate = create_test_delegate();
+        let bytes = delegate.to_bytes_inner();
+
+        assert_eq!(bytes.len(), ClaimDelegate::DATA_LEN);
+        assert_eq!(bytes[0], 255); // bump
+        assert_eq!(&bytes[1..33], &[3u8; 32]); // delegate
+    }
+
+    #[test]
+    fn test_claim_delegate_to_bytes() {
+        let delegate = create_test_delegate();
+        let bytes = delegate.to_bytes();
+
+        assert_eq!(bytes.len(), ClaimDelegate::LEN);
+        assert_eq!(bytes[0], ClaimDelegate::DISCRIMINATOR);
+        assert_eq!(bytes[1], ClaimDelegate::VERSION);
+        assert_eq!(bytes[2], 255); // bump
+    }
+
+    #[test]
+    fn test_roundtrip_serialization() {
+        let delegate = create_test_delegate();
+
+        let bytes = delegate.to_bytes();
+        let deserialized = ClaimDelegate::parse_from_bytes(&bytes).unwrap();
+
+        assert_eq!(deserialized, delegate);
+    }
+
+    #[test]
+    fn test_parse_rejects_wrong_discriminator() {
+        let mut bytes = create_test_delegate().to_bytes();
+        bytes[0] = RewardsAccountDiscriminators::MerkleRevocation as u8;
+        assert!(ClaimDelegate::parse_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_claim_delegate_seeds_pda_seeds() {
+        let seeds = ClaimDelegateSeeds {
+            distribution: Address::new_from_array([1u8; 32]),
+            recipient: Address::new_from_array([2u8; 32]),
+        };
+        let pda_seeds = seeds.seeds();
+        assert_eq!(pda_seeds.len(), 3);
+        assert_eq!(pda_seeds[0], ClaimDelegateSeeds::PREFIX);
+        assert_eq!(pda_seeds[1], seeds.distribution.as_ref());
+        assert_eq!(pda_seeds[2], seeds.recipient.as_ref());
+    }
+
+    #[test]
+    fn test_account_size() {
+        assert_eq!(ClaimDelegate::DATA_LEN, 33);
+        assert_eq!(ClaimDelegate::LEN, 35);
+    }
+}
@@ -0,0 +1,461 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use codama::CodamaAccount;
+use pinocchio::{
+    account::AccountView,
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    Address,
+};
+
+use crate::errors::RewardsProgramError;
+use crate::traits::{
+    AccountParse, AccountSerialize, AccountSize, AccountValidation, Discriminator, Distribution, DistributionSigner,
+    PdaAccount, PdaSeeds, RewardsAccountDiscriminators, Versioned,
+};
+use crate::utils::ConcurrentMerkleTree;
+use crate::{assert_no_padding, require_account_len, validate_discriminator, validate_version};
+
+/// CompressedDistribution account state
+///
+/// A distribution whose recipients are leaves of a concurrent merkle tree
+/// instead of one account each. The tree (`ConcurrentMerkleTree`) is stored in
+/// the same account directly after this header; each leaf commits to a
+/// `CompressedRecipient` (recipient, total_amount, claimed_amount, schedule).
+/// Recipient data itself lives only in events, so clients rebuild the leaves
+/// from the event log to produce proofs.
+///
+/// Recipients cannot be revoked or transferred.
+///
+/// # PDA Seeds
+/// `[b"compressed_distribution", mint.as_ref(), seed_authority.as_ref(), seeds.as_ref()]`
+///
+/// `seed_authority` is the authority at creation time and never changes, so the
+/// PDA address stays stable. `authority` is the current authority and can be
+/// rotated via the two-step `ProposeAuthority` / `AcceptAuthority` flow.
+#[derive(Clone, Debug, PartialEq, CodamaAccount)]
+#[repr(C)]
+pub struct CompressedDistribution {
+    pub bump: u8,
+    pub paused: u8,
+    _padding: [u8; 6],
+    pub authority: Address,
+    pub mint: Address,
+    pub seed: Address,
+    pub total_allocated: u64,
+    pub total_claimed: u64,
+    pub clawback_ts: i64,
+    pub seed_authority: Address,
+    pub pending_authority: Address,
+    pub total_funded: u64,
+    pub paused_at: i64,
+    pub vesting_offset: i64,
+}
+
+assert_no_padding!(CompressedDistribution, 1 + 1 + 6 + 32 + 32 + 32 + 8 + 8 + 8 + 32 + 32 + 8 + 8 + 8);
+
+impl Discriminator for CompressedDistribution {
+    const DISCRIMINATOR: u8 = RewardsAccountDiscriminators::CompressedDistribution as u8;
+}
+
+impl Versioned for CompressedDistribution {
+    const VERSION: u8 = 1;
+}
+
+impl AccountSize for CompressedDistribution {
+    const DATA_LEN: usize = 1 + 1 + 6 + 32 + 32 + 32 + 8 + 8 + 8 + 32 + 32 + 8 + 8 + 8; // 216
+}
+
+impl AccountParse for CompressedDistribution {
+    fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_account_len!(data, Self::LEN);
+        validate_discriminator!(data, Self::DISCRIMINATOR);
+        validate_version!(data, Self::VERSION);
+
+        // Skip discriminator (byte 0) and version (byte 1)
+        let data = &data[2..];
+
+        let bump = data[0];
+        let paused = data[1];
+        // Skip padding bytes [2..8]
+        let authority =
+            Address::new_from_array(data[8..40].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        let mint =
+            Address::new_from_array(data[40..72].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        let seeds =
+            Address::new_from_array(data[72..104].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        let total_allocated =
+            u64::from_le_bytes(data[104..112].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        let total_claimed =
+            u64::from_le_bytes(data[112..120].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        let clawback_ts =
+            i64::from_le_bytes(data[120..128].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        let seed_authority =
+            Address::new_from_array(data[128..160].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        let pending_authority =
+            Address::new_from_array(data[160..192].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        let total_funded =
+            u64::from_le_bytes(data[192..200].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        let paused_at =
+            i64::from_le_bytes(data[200..208].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        let vesting_offset =
+            i64::from_le_bytes(data[208..216].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+
+        Ok(Self {
+            bump,
+            paused,
+            _padding: [0u8; 6],
+            authority,
+            mint,
+            seed: seeds,
+            total_allocated,
+            total_claimed,
+            clawback_ts,
+            seed_authority,
+            pending_authority,
+            total_funded,
+            paused_at,
+            vesting_offset,
+        })
+    }
+}
+
+impl AccountSerialize for CompressedDistribution {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.push(self.bump);
+        data.push(self.paused);
+        data.extend_from_slice(&[0u8; 6]); // padding
+        data.extend_from_slice(self.authority.as_ref());
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.seed.as_ref());
+        data.extend_from_slice(&self.total_allocated.to_le_bytes());
+        data.extend_from_slice(&self.total_claimed.to_le_bytes());
+        data.extend_from_slice(&self.clawback_ts.to_le_bytes());
+        data.extend_from_slice(self.seed_authority.as_ref());
+        data.extend_from_slice(self.pending_authority.as_ref());
+        data.extend_from_slice(&self.total_funded.to_le_bytes());
+        data.extend_from_slice(&self.paused_at.to_le_bytes());
+        data.extend_from_slice(&self.vesting_offset.to_le_bytes());
+        data
+    }
+}
+
+impl AccountValidation for CompressedDistribution {}
+
+impl PdaSeeds for CompressedDistribution {
+    const PREFIX: &'static [u8] = b"compressed_distribution";
+
+    fn seeds(&self) -> Vec<&[u8]> {
+        vec![Self::PREFIX, self.mint.as_ref(), self.seed_authority.as_ref(), self.seed.as_ref()]
+    }
+
+    fn seeds_with_bump<'a>(&'a self, bump: &'a [u8; 1]) -> Vec<Seed<'a>> {
+        vec![
+            Seed::from(Self::PREFIX),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(self.seed_authority.as_ref()),
+            Seed::from(self.seed.as_ref()),
+            Seed::from(bump.as_slice()),
+        ]
+    }
+}
+
+impl PdaAccount for CompressedDistribution {
+    #[inline(always)]
+    fn bump(&self) -> u8 {
+        self.bump
+    }
+}
+
+impl Distribution for CompressedDistribution {
+    #[inline(always)]
+    fn mint(&self) -> &Address {
+        &self.mint
+    }
+
+    #[inline(always)]
+    fn authority(&self) -> &Address {
+        &self.authority
+    }
+
+    #[inline(always)]
+    fn set_authority(&mut self, authority: Address) {
+        self.authority = authority;
+    }
+
+    #[inline(always)]
+    fn pending_authority(&self) -> &Address {
+        &self.pending_authority
+    }
+
+    #[inline(always)]
+    fn set_pending_authority(&mut self, pending_authority: Address) {
+        self.pending_authority = pending_authority;
+    }
+
+    #[inline(always)]
+    fn seeds_key(&self) -> &Address {
+        &self.seed
+    }
+
+    #[inline(always)]
+    fn total_claimed(&self) -> u64 {
+        self.total_claimed
+    }
+
+    #[inline(always)]
+    fn set_total_claimed(&mut self, amount: u64) -> Result<(), ProgramError> {
+        if amount < self.total_claimed {
+            return Err(RewardsProgramError::ClaimedAmountDecreased.into());
+        }
+        self.total_claimed = amount;
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn total_funded(&self) -> u64 {
+        self.total_funded
+    }
+
+    #[inline(always)]
+    fn set_total_funded(&mut self, amount: u64) {
+        self.total_funded = amount;
+    }
+
+    /// Compressed recipients cannot be transferred
+    #[inline(always)]
+    fn is_transferable(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn set_transferable(&mut self, _transferable: bool) {}
+
+    #[inline(always)]
+    fn is_paused(&self) -> bool {
+        self.paused != 0
+    }
+
+    #[inline(always)]
+    fn set_paused(&mut self, paused: bool) {
+        self.paused = paused as u8;
+    }
+
+    #[inline(always)]
+    fn paused_at(&self) -> i64 {
+        self.paused_at
+    }
+
+    #[inline(always)]
+    fn set_paused_at(&mut self, paused_at: i64) {
+        self.paused_at = paused_at;
+    }
+
+    #[inline(always)]
+    fn vesting_offset(&self) -> i64 {
+        self.vesting_offset
+    }
+
+    #[inline(always)]
+    fn set_vesting_offset(&mut self, vesting_offset: i64) {
+        self.vesting_offset = vesting_offset;
+    }
+
+    /// Only direct distributions can be settled
+    #[inline(always)]
+    fn is_settled(&self) -> bool {
+        false
+    }
+
+    /// Compressed recipients can claim for as long as the distribution is open
+    #[inline(always)]
+    fn claim_start_ts(&self) -> i64 {
+        0
+    }
+
+    #[inline(always)]
+    fn claim_end_ts(&self) -> i64 {
+        0
+    }
+
+    /// Compressed distributions have no claim window to move
+    #[inline(always)]
+    fn set_claim_end_ts(&mut self, _claim_end_ts: i64) {}
+}
+
+impl DistributionSigner for CompressedDistribution {
+    #[inline(always)]
+    fn with_signer<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&[Signer<'_, '_>]) -> R,
+    {
+        let bump_seed = [self.bump];
+        let pda_seeds = [
+            Seed::from(Self::PREFIX),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(self.seed_authority.as_ref()),
+            Seed::from(self.seed.as_ref()),
+            Seed::from(bump_seed.as_slice()),
+        ];
+        let signers = [Signer::from(&pda_seeds)];
+        f(&signers)
+    }
+}
+
+impl CompressedDistribution {
+    #[inline(always)]
+    pub fn new(bump: u8, clawback_ts: i64, authority: Address, mint: Address, seeds: Address) -> Self {
+        Self {
+            bump,
+            paused: 0,
+            _padding: [0u8; 6],
+            authority,
+            mint,
+            seed: seeds,
+            total_allocated: 0,
+            total_claimed: 0,
+            clawback_ts,
+            seed_authority: authority,
+            pending_authority: Address::default(),
+            total_funded: 0,
+            paused_at: 0,
+            vesting_offset: 0,
+        }
+    }
+
+    #[inline(always)]
+    pub fn from_account(data: &[u8], account: &AccountView, program_id: &Address) -> Result<Self, ProgramError> {
+        let state = Self::parse_from_bytes(data)?;
+        state.validate_self(account, program_id)?;
+        Ok(state)
+    }
+
+    /// Total account size: header followed by the recipient tree
+    #[inline(always)]
+    pub fn account_size() -> usize {
+        Self::LEN + ConcurrentMerkleTree::LEN
+    }
+
+    /// Recipient tree stored after the header
+    #[inline(always)]
+    pub fn tree(data: &[u8]) -> Result<&ConcurrentMerkleTree, ProgramError> {
+        require_account_len!(data, Self::account_size());
+        ConcurrentMerkleTree::from_bytes(&data[Self::LEN..])
+    }
+
+    /// Mutable recipient tree stored after the header
+    #[inline(always)]
+    pub fn tree_mut(data: &mut [u8]) -> Result<&mut ConcurrentMerkleTree, ProgramError> {
+        require_account_len!(data, Self::account_size());
+        ConcurrentMerkleTree::from_bytes_mut(&mut data[Self::LEN..])
+    }
+
+    pub fn remaining_unallocated(&self, vault_balance: u64) -> Result<u64, RewardsProgramError> {
+        let outstanding =
+            self.total_allocated.checked_sub(self.total_claimed).ok_or(RewardsProgramError::MathOverflow)?;
+        vault_balance.checked_sub(outstanding).ok_or(RewardsProgramError::MathOverflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::{Distribution, PdaAccount};
+
+    fn create_test_distribution() -> CompressedDistribution {
+        CompressedDistribution::new(
+            255,
+            0,
+            Address::new_from_array([1u8; 32]),
+            Address::new_from_array([2u8; 32]),
+            Address::new_from_array([3u8; 32]),
+        )
+    }
+
+    #[test]
+    fn test_compressed_distribution_new() {
+        let dist = create_test_distribution();
+        assert_eq!(dist.bump, 255);
+        assert_eq!(dist.total_allocated, 0);
+        assert_eq!(dist.total_claimed, 0);
+        assert_eq!(dist.seed_authority, dist.authority);
+        assert!(!dist.is_transferable());
+    }
+
+    #[test]
+    fn test_compressed_distribution_to_bytes() {
+        let dist = create_test_distribution();
+        let bytes = dist.to_bytes();
+
+        assert_eq!(bytes.len(), CompressedDistribution::LEN);
+        assert_eq!(bytes[0], CompressedDistribution::DISCRIMINATOR);
+        assert_eq!(bytes[1], CompressedDistribution::VERSION);
+        assert_eq!(bytes[2], 255); // bump
+    }
+
+    #[test]
+    fn test_roundtrip_serialization() {
+        let mut dist = CompressedDistribution::new(
+            200,
+            1700000000,
+            Address::new_from_array([1u8; 32]),
+            Address::new_from_array([2u8; 32]),
+            Address::new_from_array([3u8; 32]),
+        );
+        dist.total_allocated = 500;
+        Distribution::add_claimed(&mut dist, 100).unwrap();
+        Distribution::pause(&mut dist, 1_700_000_100).unwrap();
+
+        let bytes = dist.to_bytes();
+        let deserialized = CompressedDistribution::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized, dist);
+    }
+
+    #[test]
+    fn test_pda_seeds() {
+        let dist = create_test_distribution();
+        let seeds = dist.seeds();
+        assert_eq!(seeds.len(), 4);
+        assert_eq!(seeds[0], CompressedDistribution::PREFIX);
+        assert_eq!(seeds[1], dist.mint.as_ref());
+        assert_eq!(seeds[2], dist.seed_authority.as_ref());
+        assert_eq!(seeds[3], dist.seed.as_ref());
+        assert_eq!(PdaAccount::bump(&dist), dist.bump);
+    }
+
+    #[test]
+    fn test_write_header_preserves_tree() {
+        let mut dist = create_test_distribution();
+        let mut data = vec![0u8; CompressedDistribution::account_size()];
+        dist.write_to_slice(&mut data).unwrap();
+        let tree = CompressedDistribution::tree_mut(&mut data).unwrap();
+        tree.initialize().unwrap();
+        tree.append(&[7u8; 32]).unwrap();
+        let root = tree.root();
+
+        dist.total_allocated = 1000;
+        dist.write_to_slice(&mut data).unwrap();
+
+        let tree = CompressedDistribution::tree(&data).unwrap();
+        assert_eq!(tree.root(), root);
+        assert_eq!(tree.num_leaves(), 1);
+        assert_eq!(CompressedDistribution::parse_from_bytes(&data).unwrap().total_allocated, 1000);
+    }
+
+    #[test]
+    fn test_tree_requires_full_account() {
+        let dist = create_test_distribution();
+        let bytes = dist.to_bytes();
+        assert_eq!(CompressedDistribution::tree(&bytes).err(), Some(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_remaining_unallocated() {
+        let mut dist = create_test_distribution();
+        dist.total_allocated = 500;
+        dist.total_claimed = 100;
+        assert_eq!(dist.remaining_unallocated(1000).unwrap(), 600);
+    }
+}
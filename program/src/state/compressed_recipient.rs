@@ -0,0 +1,128 @@
+use pinocchio::{error::ProgramError, Address};
+
+use crate::errors::RewardsProgramError;
+use crate::traits::{ClaimTracker, VestingParams};
+use crate::utils::{compute_compressed_leaf_hash, VestingSchedule};
+
+/// A recipient of a compressed distribution.
+///
+/// Not an account: only the leaf hash is stored, in the distribution's
+/// `ConcurrentMerkleTree`. Claimants resubmit these fields with each claim
+/// and the program swaps the old leaf for one with the new `claimed_amount`.
+///
+/// `base_vesting_offset` is the distribution's `vesting_offset` when the leaf
+/// was appended, so only pauses after that shift its schedule.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompressedRecipient {
+    pub recipient: Address,
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub base_vesting_offset: i64,
+    pub schedule: VestingSchedule,
+}
+
+impl ClaimTracker for CompressedRecipient {
+    #[inline(always)]
+    fn claimed_amount(&self) -> u64 {
+        self.claimed_amount
+    }
+
+    #[inline(always)]
+    fn set_claimed_amount(&mut self, amount: u64) -> Result<(), ProgramError> {
+        if amount < self.claimed_amount {
+            return Err(RewardsProgramError::ClaimedAmountDecreased.into());
+        }
+        self.claimed_amount = amount;
+        Ok(())
+    }
+}
+
+impl VestingParams for CompressedRecipient {
+    #[inline(always)]
+    fn total_amount(&self) -> u64 {
+        self.total_amount
+    }
+
+    #[inline(always)]
+    fn vesting_schedule(&self) -> &VestingSchedule {
+        &self.schedule
+    }
+
+    #[inline(always)]
+    fn base_vesting_offset(&self) -> i64 {
+        self.base_vesting_offset
+    }
+}
+
+impl CompressedRecipient {
+    #[inline(always)]
+    pub fn new(
+        recipient: Address,
+        total_amount: u64,
+        claimed_amount: u64,
+        base_vesting_offset: i64,
+        schedule: VestingSchedule,
+    ) -> Self {
+        Self { recipient, total_amount, claimed_amount, base_vesting_offset, schedule }
+    }
+
+    /// Leaf hash committing to every field
+    pub fn leaf_hash(&self) -> [u8; 32] {
+        let mut schedule_bytes = [0u8; VestingSchedule::MAX_BYTE_LEN];
+        let schedule_len = self.schedule.write_bytes(&mut schedule_bytes);
+        compute_compressed_leaf_hash(
+            &self.recipient,
+            self.total_amount,
+            self.claimed_amount,
+            self.base_vesting_offset,
+            &schedule_bytes[..schedule_len],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_recipient() -> CompressedRecipient {
+        CompressedRecipient::new(
+            Address::new_from_array([1u8; 32]),
+            1000,
+            0,
+            0,
+            VestingSchedule::Linear { start_ts: 0, end_ts: 100 },
+        )
+    }
+
+    #[test]
+    fn test_leaf_hash_matches_schedule_bytes() {
+        let recipient = create_test_recipient();
+        let expected = compute_compressed_leaf_hash(&recipient.recipient, 1000, 0, 0, &recipient.schedule.to_bytes());
+        assert_eq!(recipient.leaf_hash(), expected);
+    }
+
+    #[test]
+    fn test_leaf_hash_changes_after_claim() {
+        let mut recipient = create_test_recipient();
+        let before = recipient.leaf_hash();
+        recipient.add_claimed(250).unwrap();
+        assert_ne!(recipient.leaf_hash(), before);
+        assert_eq!(recipient.claimed_amount, 250);
+    }
+
+    #[test]
+    fn test_claimable_amount() {
+        let mut recipient = create_test_recipient();
+        let unlocked = recipient.calculate_unlocked(50).unwrap();
+        assert_eq!(unlocked, 500);
+        recipient.add_claimed(200).unwrap();
+        assert_eq!(recipient.claimable_amount(unlocked).unwrap(), 300);
+    }
+
+    #[test]
+    fn test_set_claimed_rejects_decrease() {
+        let mut recipient = create_test_recipient();
+        recipient.add_claimed(300).unwrap();
+        assert_eq!(recipient.set_claimed_amount(100), Err(RewardsProgramError::ClaimedAmountDecreased.into()));
+    }
+}
@@ -11,9 +11,10 @@ use pinocchio::{
 use crate::errors::RewardsProgramError;
 use crate::traits::{
     AccountParse, AccountSerialize, AccountSize, AccountValidation, Discriminator, Distribution, DistributionSigner,
-    PdaAccount, PdaSeeds, RewardsAccountDiscriminators, Versioned,
+    Migrate, PdaAccount, PdaSeeds, RewardsAccountDiscriminators, Versioned, ACCOUNT_HEADER_SIZE,
 };
-use crate::{assert_no_padding, require_account_len, validate_discriminator};
+use crate::utils::ClosePolicy;
+use crate::{assert_no_padding, validate_discriminator};
 
 /// DirectDistribution account state
 ///
@@ -22,46 +23,75 @@ use crate::{assert_no_padding, require_account_len, validate_discriminator};
 /// are per-recipient (stored in DirectRecipient accounts).
 ///
 /// # PDA Seeds
-/// `[b"direct_distribution", mint.as_ref(), authority.as_ref(), seeds.as_ref()]`
+/// `[b"direct_distribution", mint.as_ref(), seed_authority.as_ref(), seeds.as_ref()]`
+///
+/// `seed_authority` is the authority at creation time and never changes, so the
+/// PDA address stays stable. `authority` is the current authority and can be
+/// rotated via the two-step `ProposeAuthority` / `AcceptAuthority` flow.
+///
+/// `close_policy` (a `ClosePolicy`) is fixed at creation. Under `ProtectRecipients`,
+/// closing while allocations are unclaimed sets `settled` instead of closing the account.
+///
+/// Recipients can claim from `claim_start_ts` until `claim_end_ts` (0 = unbounded). The
+/// authority can close once both `clawback_ts` and `claim_end_ts` have passed.
 #[derive(Clone, Debug, PartialEq, CodamaAccount)]
 #[repr(C)]
 pub struct DirectDistribution {
     pub bump: u8,
     pub revocable: u8,
-    _padding: [u8; 6],
+    pub transferable: u8,
+    pub paused: u8,
+    pub close_policy: u8,
+    pub settled: u8,
+    _padding: [u8; 2],
     pub authority: Address,
     pub mint: Address,
     pub seed: Address,
     pub total_allocated: u64,
     pub total_claimed: u64,
     pub clawback_ts: i64,
+    pub seed_authority: Address,
+    pub pending_authority: Address,
+    pub total_funded: u64,
+    pub paused_at: i64,
+    pub vesting_offset: i64,
+    pub claim_start_ts: i64,
+    pub claim_end_ts: i64,
 }
 
-assert_no_padding!(DirectDistribution, 1 + 1 + 6 + 32 + 32 + 32 + 8 + 8 + 8);
+assert_no_padding!(
+    DirectDistribution,
+    1 + 1 + 1 + 1 + 1 + 1 + 2 + 32 + 32 + 32 + 8 + 8 + 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8
+);
 
 impl Discriminator for DirectDistribution {
     const DISCRIMINATOR: u8 = RewardsAccountDiscriminators::DirectDistribution as u8;
 }
 
 impl Versioned for DirectDistribution {
-    const VERSION: u8 = 1;
+    const VERSION: u8 = 5;
 }
 
 impl AccountSize for DirectDistribution {
-    const DATA_LEN: usize = 1 + 1 + 6 + 32 + 32 + 32 + 8 + 8 + 8; // 128
+    const DATA_LEN: usize = 1 + 1 + 1 + 1 + 1 + 1 + 2 + 32 + 32 + 32 + 8 + 8 + 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8; // 232
 }
 
 impl AccountParse for DirectDistribution {
     fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
-        require_account_len!(data, Self::LEN);
         validate_discriminator!(data, Self::DISCRIMINATOR);
+        let version = Self::stored_version(data)?;
 
         // Skip discriminator (byte 0) and version (byte 1)
         let data = &data[2..];
 
+        // Flags written into padding bytes are zero in older versions
         let bump = data[0];
         let revocable = data[1];
-        // Skip padding bytes [2..8]
+        let transferable = data[2];
+        let paused = data[3];
+        let close_policy = data[4];
+        let settled = data[5];
+        // Skip padding bytes [6..8]
         let authority =
             Address::new_from_array(data[8..40].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
         let mint =
@@ -75,33 +105,106 @@ impl AccountParse for DirectDistribution {
         let clawback_ts =
             i64::from_le_bytes(data[120..128].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
 
+        // v2: authority could not be rotated before, so it is still the seed authority
+        let (seed_authority, pending_authority) = if version >= 2 {
+            (
+                Address::new_from_array(
+                    data[128..160].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?,
+                ),
+                Address::new_from_array(
+                    data[160..192].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?,
+                ),
+            )
+        } else {
+            (authority, Address::default())
+        };
+
+        // v3: everything deposited before funding was tracked went to allocations
+        let total_funded = if version >= 3 {
+            u64::from_le_bytes(data[192..200].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?)
+        } else {
+            total_allocated
+        };
+
+        // v4: pausing
+        let (paused_at, vesting_offset) = if version >= 4 {
+            (
+                i64::from_le_bytes(data[200..208].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?),
+                i64::from_le_bytes(data[208..216].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?),
+            )
+        } else {
+            (0, 0)
+        };
+
+        // v5: claim window
+        let (claim_start_ts, claim_end_ts) = if version >= 5 {
+            (
+                i64::from_le_bytes(data[216..224].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?),
+                i64::from_le_bytes(data[224..232].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?),
+            )
+        } else {
+            (0, 0)
+        };
+
         Ok(Self {
             bump,
             revocable,
-            _padding: [0u8; 6],
+            transferable,
+            paused,
+            close_policy,
+            settled,
+            _padding: [0u8; 2],
             authority,
             mint,
             seed: seeds,
             total_allocated,
             total_claimed,
             clawback_ts,
+            seed_authority,
+            pending_authority,
+            total_funded,
+            paused_at,
+            vesting_offset,
+            claim_start_ts,
+            claim_end_ts,
         })
     }
 }
 
+impl Migrate for DirectDistribution {
+    const VERSION_LENS: &'static [usize] = &[
+        ACCOUNT_HEADER_SIZE + 128, // v1
+        ACCOUNT_HEADER_SIZE + 192, // v2: seed_authority, pending_authority
+        ACCOUNT_HEADER_SIZE + 200, // v3: total_funded
+        ACCOUNT_HEADER_SIZE + 216, // v4: paused_at, vesting_offset
+        ACCOUNT_HEADER_SIZE + 232, // v5: claim_start_ts, claim_end_ts
+    ];
+}
+
 impl AccountSerialize for DirectDistribution {
     #[inline(always)]
     fn to_bytes_inner(&self) -> Vec<u8> {
         let mut data = Vec::with_capacity(Self::DATA_LEN);
         data.push(self.bump);
         data.push(self.revocable);
-        data.extend_from_slice(&[0u8; 6]); // padding
+        data.push(self.transferable);
+        data.push(self.paused);
+        data.push(self.close_policy);
+        data.push(self.settled);
+        data.extend_from_slice(&[0u8; 2]); // padding
         data.extend_from_slice(self.authority.as_ref());
         data.extend_from_slice(self.mint.as_ref());
         data.extend_from_slice(self.seed.as_ref());
         data.extend_from_slice(&self.total_allocated.to_le_bytes());
         data.extend_from_slice(&self.total_claimed.to_le_bytes());
         data.extend_from_slice(&self.clawback_ts.to_le_bytes());
+        data.extend_from_slice(self.seed_authority.as_ref());
+        data.extend_from_slice(self.pending_authority.as_ref());
+        data.extend_from_slice(&self.total_funded.to_le_bytes());
+        data.extend_from_slice(&self.paused_at.to_le_bytes());
+        data.extend_from_slice(&self.vesting_offset.to_le_bytes());
+        data.extend_from_slice(&self.claim_start_ts.to_le_bytes());
+        data.extend_from_slice(&self.claim_end_ts.to_le_bytes());
         data
     }
 }
@@ -112,14 +215,14 @@ impl PdaSeeds for DirectDistribution {
     const PREFIX: &'static [u8] = b"direct_distribution";
 
     fn seeds(&self) -> Vec<&[u8]> {
-        vec![Self::PREFIX, self.mint.as_ref(), self.authority.as_ref(), self.seed.as_ref()]
+        vec![Self::PREFIX, self.mint.as_ref(), self.seed_authority.as_ref(), self.seed.as_ref()]
     }
 
     fn seeds_with_bump<'a>(&'a self, bump: &'a [u8; 1]) -> Vec<Seed<'a>> {
         vec![
             Seed::from(Self::PREFIX),
             Seed::from(self.mint.as_ref()),
-            Seed::from(self.authority.as_ref()),
+            Seed::from(self.seed_authority.as_ref()),
             Seed::from(self.seed.as_ref()),
             Seed::from(bump.as_slice()),
         ]
@@ -144,6 +247,21 @@ impl Distribution for DirectDistribution {
         &self.authority
     }
 
+    #[inline(always)]
+    fn set_authority(&mut self, authority: Address) {
+        self.authority = authority;
+    }
+
+    #[inline(always)]
+    fn pending_authority(&self) -> &Address {
+        &self.pending_authority
+    }
+
+    #[inline(always)]
+    fn set_pending_authority(&mut self, pending_authority: Address) {
+        self.pending_authority = pending_authority;
+    }
+
     #[inline(always)]
     fn seeds_key(&self) -> &Address {
         &self.seed
@@ -162,6 +280,76 @@ impl Distribution for DirectDistribution {
         self.total_claimed = amount;
         Ok(())
     }
+
+    #[inline(always)]
+    fn total_funded(&self) -> u64 {
+        self.total_funded
+    }
+
+    #[inline(always)]
+    fn set_total_funded(&mut self, amount: u64) {
+        self.total_funded = amount;
+    }
+
+    #[inline(always)]
+    fn is_transferable(&self) -> bool {
+        self.transferable != 0
+    }
+
+    #[inline(always)]
+    fn set_transferable(&mut self, transferable: bool) {
+        self.transferable = transferable as u8;
+    }
+
+    #[inline(always)]
+    fn is_paused(&self) -> bool {
+        self.paused != 0
+    }
+
+    #[inline(always)]
+    fn set_paused(&mut self, paused: bool) {
+        self.paused = paused as u8;
+    }
+
+    #[inline(always)]
+    fn paused_at(&self) -> i64 {
+        self.paused_at
+    }
+
+    #[inline(always)]
+    fn set_paused_at(&mut self, paused_at: i64) {
+        self.paused_at = paused_at;
+    }
+
+    #[inline(always)]
+    fn vesting_offset(&self) -> i64 {
+        self.vesting_offset
+    }
+
+    #[inline(always)]
+    fn set_vesting_offset(&mut self, vesting_offset: i64) {
+        self.vesting_offset = vesting_offset;
+    }
+
+    #[inline(always)]
+    fn is_settled(&self) -> bool {
+        self.settled != 0
+    }
+
+    #[inline(always)]
+    fn claim_start_ts(&self) -> i64 {
+        self.claim_start_ts
+    }
+
+    #[inline(always)]
+    fn claim_end_ts(&self) -> i64 {
+        self.claim_end_ts
+    }
+
+    #[inline(always)]
+    fn set_claim_end_ts(&mut self, claim_end_ts: i64) {
+        self.claim_end_ts = claim_end_ts;
+    }
 }
 
 impl DistributionSigner for DirectDistribution {
@@ -174,7 +362,7 @@ impl DistributionSigner for DirectDistribution {
         let pda_seeds = [
             Seed::from(Self::PREFIX),
             Seed::from(self.mint.as_ref()),
-            Seed::from(self.authority.as_ref()),
+            Seed::from(self.seed_authority.as_ref()),
             Seed::from(self.seed.as_ref()),
             Seed::from(bump_seed.as_slice()),
         ];
@@ -189,13 +377,24 @@ impl DirectDistribution {
         Self {
             bump,
             revocable,
-            _padding: [0u8; 6],
+            transferable: 0,
+            paused: 0,
+            close_policy: ClosePolicy::Sweep {}.to_byte(),
+            settled: 0,
+            _padding: [0u8; 2],
             authority,
             mint,
             seed: seeds,
             total_allocated: 0,
             total_claimed: 0,
             clawback_ts,
+            seed_authority: authority,
+            pending_authority: Address::default(),
+            total_funded: 0,
+            paused_at: 0,
+            vesting_offset: 0,
+            claim_start_ts: 0,
+            claim_end_ts: 0,
         }
     }
 
@@ -206,10 +405,18 @@ impl DirectDistribution {
         Ok(state)
     }
 
+    #[inline(always)]
+    pub fn close_policy(&self) -> Result<ClosePolicy, ProgramError> {
+        ClosePolicy::try_from(self.close_policy)
+    }
+
+    /// Allocated tokens not yet claimed; revocations already reduce `total_allocated`
+    pub fn outstanding(&self) -> Result<u64, RewardsProgramError> {
+        self.total_allocated.checked_sub(self.total_claimed).ok_or(RewardsProgramError::MathOverflow)
+    }
+
     pub fn remaining_unallocated(&self, vault_balance: u64) -> Result<u64, RewardsProgramError> {
-        let outstanding =
-            self.total_allocated.checked_sub(self.total_claimed).ok_or(RewardsProgramError::MathOverflow)?;
-        vault_balance.checked_sub(outstanding).ok_or(RewardsProgramError::MathOverflow)
+        vault_balance.checked_sub(self.outstanding()?).ok_or(RewardsProgramError::MathOverflow)
     }
 }
 
@@ -313,6 +520,27 @@ mod tests {
         assert_eq!(deserialized.revocable, 1);
     }
 
+    #[test]
+    fn test_roundtrip_serialization_transferable() {
+        let mut dist = create_test_distribution();
+        dist.set_transferable(true);
+        let bytes = dist.to_bytes();
+        let deserialized = DirectDistribution::parse_from_bytes(&bytes).unwrap();
+        assert!(deserialized.is_transferable());
+        assert_eq!(deserialized.revocable, dist.revocable);
+    }
+
+    #[test]
+    fn test_backward_compat_old_bytes_parse_as_non_transferable() {
+        let dist = create_test_distribution();
+        let bytes = dist.to_bytes();
+        // Old accounts have 0x00 at the transferable offset (was padding)
+        assert_eq!(bytes[4], 0);
+        let deserialized = DirectDistribution::parse_from_bytes(&bytes).unwrap();
+        assert!(!deserialized.is_transferable());
+        assert!(deserialized.validate_transferable().is_err());
+    }
+
     #[test]
     fn test_backward_compat_old_bytes_parse_as_non_revocable() {
         let dist = create_test_distribution();
@@ -322,6 +550,37 @@ mod tests {
         assert_eq!(deserialized.revocable, 0);
     }
 
+    #[test]
+    fn test_backward_compat_old_bytes_parse_as_sweep() {
+        let dist = create_test_distribution();
+        let bytes = dist.to_bytes();
+        // Old accounts have 0x00 at the close_policy and settled offsets (was padding)
+        assert_eq!(bytes[6..8], [0, 0]);
+        let deserialized = DirectDistribution::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.close_policy().unwrap(), ClosePolicy::Sweep {});
+        assert!(!deserialized.is_settled());
+    }
+
+    #[test]
+    fn test_roundtrip_serialization_settled() {
+        let mut dist = create_test_distribution();
+        dist.close_policy = ClosePolicy::ProtectRecipients {}.to_byte();
+        dist.settled = 1;
+        let bytes = dist.to_bytes();
+        let deserialized = DirectDistribution::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.close_policy().unwrap(), ClosePolicy::ProtectRecipients {});
+        assert!(deserialized.is_settled());
+        assert_eq!(deserialized.validate_not_settled(), Err(RewardsProgramError::DistributionSettled.into()));
+    }
+
+    #[test]
+    fn test_outstanding() {
+        let mut dist = create_test_distribution();
+        dist.total_allocated = 500;
+        dist.total_claimed = 120;
+        assert_eq!(dist.outstanding().unwrap(), 380);
+    }
+
     #[test]
     fn test_roundtrip_serialization_with_clawback_ts() {
         let dist = DirectDistribution::new(
@@ -410,4 +669,335 @@ mod tests {
         assert!(Distribution::set_total_claimed(&mut dist, 400).is_err());
         assert_eq!(Distribution::total_claimed(&dist), 500);
     }
+
+    #[test]
+    fn test_new_sets_seed_authority_and_no_pending() {
+        let dist = create_test_distribution();
+        assert_eq!(dist.seed_authority, dist.authority);
+        assert_eq!(dist.pending_authority, Address::default());
+        assert!(!Distribution::has_pending_authority(&dist));
+    }
+
+    #[test]
+    fn test_roundtrip_serialization_with_pending_authority() {
+        let mut dist = create_test_distribution();
+        Distribution::set_pending_authority(&mut dist, Address::new_from_array([9u8; 32]));
+        let bytes = dist.to_bytes();
+        let deserialized = DirectDistribution::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.seed_authority, dist.seed_authority);
+        assert_eq!(deserialized.pending_authority, Address::new_from_array([9u8; 32]));
+    }
+
+    #[test]
+    fn test_seeds_use_seed_authority_after_transfer() {
+        let mut dist = create_test_distribution();
+        let original_authority = dist.authority;
+        Distribution::set_authority(&mut dist, Address::new_from_array([9u8; 32]));
+        let seeds = dist.seeds();
+        assert_eq!(seeds[2], original_authority.as_ref());
+    }
+
+    #[test]
+    fn test_validate_authority_uses_current_authority() {
+        let mut dist = create_test_distribution();
+        let original_authority = dist.authority;
+        let new_authority = Address::new_from_array([9u8; 32]);
+        Distribution::set_authority(&mut dist, new_authority);
+        assert!(Distribution::validate_authority(&dist, &new_authority).is_ok());
+        assert!(Distribution::validate_authority(&dist, &original_authority).is_err());
+    }
+
+    #[test]
+    fn test_validate_pending_authority() {
+        let mut dist = create_test_distribution();
+        let pending = Address::new_from_array([9u8; 32]);
+        assert_eq!(
+            Distribution::validate_pending_authority(&dist, &pending),
+            Err(RewardsProgramError::NoPendingAuthority.into())
+        );
+
+        Distribution::set_pending_authority(&mut dist, pending);
+        assert!(Distribution::validate_pending_authority(&dist, &pending).is_ok());
+        assert_eq!(
+            Distribution::validate_pending_authority(&dist, &Address::new_from_array([8u8; 32])),
+            Err(RewardsProgramError::UnauthorizedAuthority.into())
+        );
+    }
+
+    #[test]
+    fn test_roundtrip_serialization_with_total_funded() {
+        let mut dist = create_test_distribution();
+        assert_eq!(dist.total_funded, 0);
+        Distribution::add_funded(&mut dist, 1500).unwrap();
+        let bytes = dist.to_bytes();
+        let deserialized = DirectDistribution::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.total_funded, 1500);
+    }
+
+    #[test]
+    fn test_distribution_add_funded_overflow() {
+        let mut dist = create_test_distribution();
+        Distribution::add_funded(&mut dist, u64::MAX).unwrap();
+        assert_eq!(Distribution::add_funded(&mut dist, 1), Err(RewardsProgramError::MathOverflow.into()));
+        assert_eq!(Distribution::total_funded(&dist), u64::MAX);
+    }
+    #[test]
+    fn test_roundtrip_serialization_paused() {
+        let mut dist = create_test_distribution();
+        Distribution::pause(&mut dist, 1_700_000_000).unwrap();
+        dist.vesting_offset = 3600;
+        let bytes = dist.to_bytes();
+        let deserialized = DirectDistribution::parse_from_bytes(&bytes).unwrap();
+        assert!(deserialized.is_paused());
+        assert_eq!(deserialized.paused_at, 1_700_000_000);
+        assert_eq!(deserialized.vesting_offset, 3600);
+    }
+
+    #[test]
+    fn test_roundtrip_serialization_claim_window() {
+        let mut dist = create_test_distribution();
+        dist.claim_start_ts = 1_700_000_000;
+        dist.claim_end_ts = 1_800_000_000;
+        let bytes = dist.to_bytes();
+        let deserialized = DirectDistribution::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.claim_start_ts, 1_700_000_000);
+        assert_eq!(deserialized.claim_end_ts, 1_800_000_000);
+    }
+
+    #[test]
+    fn test_validate_claim_window() {
+        let mut dist = create_test_distribution();
+        assert!(dist.validate_claim_window(0).is_ok());
+
+        dist.claim_start_ts = 100;
+        dist.claim_end_ts = 200;
+        assert_eq!(dist.validate_claim_window(99), Err(RewardsProgramError::ClaimWindowNotOpen.into()));
+        assert!(dist.validate_claim_window(100).is_ok());
+        assert!(dist.validate_claim_window(199).is_ok());
+        assert_eq!(dist.validate_claim_window(200), Err(RewardsProgramError::ClaimWindowClosed.into()));
+        assert!(dist.is_claim_window_closed(200));
+    }
+
+    #[test]
+    fn test_validate_vesting_end() {
+        let mut dist = create_test_distribution();
+        assert!(dist.validate_vesting_end(i64::MAX, 0).is_ok());
+
+        dist.claim_end_ts = 1_000;
+        assert!(dist.validate_vesting_end(1_000, 0).is_ok());
+        assert_eq!(dist.validate_vesting_end(1_001, 0), Err(RewardsProgramError::InvalidTimeWindow.into()));
+
+        // Pauses since the recipient was added push its schedule's end later
+        dist.vesting_offset = 100;
+        assert!(dist.validate_vesting_end(900, 0).is_ok());
+        assert_eq!(dist.validate_vesting_end(901, 0), Err(RewardsProgramError::InvalidTimeWindow.into()));
+
+        // Pauses before it was added do not
+        assert!(dist.validate_vesting_end(1_000, 100).is_ok());
+        assert_eq!(dist.validate_vesting_end(951, 50), Err(RewardsProgramError::InvalidTimeWindow.into()));
+    }
+
+    #[test]
+    fn test_pause_twice_fails() {
+        let mut dist = create_test_distribution();
+        Distribution::pause(&mut dist, 100).unwrap();
+        assert_eq!(Distribution::pause(&mut dist, 200), Err(RewardsProgramError::DistributionPaused.into()));
+        assert_eq!(dist.paused_at, 100);
+        assert_eq!(dist.validate_not_paused(), Err(RewardsProgramError::DistributionPaused.into()));
+    }
+
+    #[test]
+    fn test_unpause_not_paused_fails() {
+        let mut dist = create_test_distribution();
+        assert_eq!(Distribution::unpause(&mut dist, 100, true), Err(RewardsProgramError::DistributionNotPaused.into()));
+    }
+
+    #[test]
+    fn test_unpause_without_shift_keeps_offset() {
+        let mut dist = create_test_distribution();
+        Distribution::pause(&mut dist, 100).unwrap();
+        assert_eq!(Distribution::unpause(&mut dist, 400, false).unwrap(), 0);
+        assert!(!dist.is_paused());
+        assert_eq!(dist.vesting_offset, 0);
+        assert_eq!(dist.vesting_timestamp(500, 0), 500);
+    }
+
+    #[test]
+    fn test_unpause_with_shift_accumulates_offset() {
+        let mut dist = create_test_distribution();
+        Distribution::pause(&mut dist, 100).unwrap();
+        assert_eq!(Distribution::unpause(&mut dist, 400, true).unwrap(), 300);
+        Distribution::pause(&mut dist, 1000).unwrap();
+        assert_eq!(Distribution::unpause(&mut dist, 1050, true).unwrap(), 50);
+        assert_eq!(dist.vesting_offset, 350);
+        assert_eq!(dist.vesting_timestamp(2000, 0), 1650);
+    }
+
+    #[test]
+    fn test_unpause_with_shift_extends_claim_window() {
+        let mut dist = create_test_distribution();
+        dist.claim_end_ts = 10_000;
+        Distribution::pause(&mut dist, 100).unwrap();
+        assert_eq!(Distribution::unpause(&mut dist, 400, true).unwrap(), 300);
+        assert_eq!(dist.vesting_offset, 300);
+        assert_eq!(dist.claim_end_ts, 10_300);
+
+        // A schedule that ended at the old window end still vests inside the extended window
+        assert!(dist.validate_vesting_end(10_000, 0).is_ok());
+
+        // Resuming without a shift leaves the window alone
+        Distribution::pause(&mut dist, 500).unwrap();
+        assert_eq!(Distribution::unpause(&mut dist, 900, false).unwrap(), 0);
+        assert_eq!(dist.claim_end_ts, 10_300);
+    }
+
+    #[test]
+    fn test_unpause_with_shift_keeps_open_ended_window() {
+        let mut dist = create_test_distribution();
+        Distribution::pause(&mut dist, 100).unwrap();
+        assert_eq!(Distribution::unpause(&mut dist, 400, true).unwrap(), 300);
+        assert_eq!(dist.claim_end_ts, 0);
+    }
+
+    #[test]
+    fn test_vesting_timestamp_skips_pauses_before_recipient() {
+        let mut dist = create_test_distribution();
+        Distribution::pause(&mut dist, 100).unwrap();
+        Distribution::unpause(&mut dist, 400, true).unwrap();
+
+        // Added after the first pause, so only the second one shifts it
+        let base_vesting_offset = dist.vesting_offset;
+        assert_eq!(dist.vesting_timestamp(500, base_vesting_offset), 500);
+
+        Distribution::pause(&mut dist, 1000).unwrap();
+        Distribution::unpause(&mut dist, 1050, true).unwrap();
+        assert_eq!(dist.vesting_timestamp(2000, base_vesting_offset), 1950);
+        assert_eq!(dist.vesting_timestamp(2000, 0), 1650);
+    }
+
+    #[test]
+    fn test_vesting_timestamp_frozen_while_paused() {
+        let mut dist = create_test_distribution();
+        Distribution::pause(&mut dist, 100).unwrap();
+        assert_eq!(dist.vesting_timestamp(50, 0), 50);
+        assert_eq!(dist.vesting_timestamp(400, 0), 100);
+    }
+
+    fn legacy_bytes(dist: &DirectDistribution, version: u8) -> Vec<u8> {
+        // Layouts only ever appended fields, so older versions are prefixes of the current one
+        let mut bytes = dist.to_bytes();
+        bytes[1] = version;
+        bytes.truncate(DirectDistribution::len_for_version(version).unwrap());
+        bytes
+    }
+
+    fn populated_distribution() -> DirectDistribution {
+        let mut dist = create_test_distribution();
+        dist.total_allocated = 1_000;
+        dist.total_claimed = 400;
+        dist.clawback_ts = 1_900_000_000;
+        dist.authority = Address::new_from_array([8u8; 32]);
+        dist.pending_authority = Address::new_from_array([9u8; 32]);
+        dist.total_funded = 5_000;
+        dist.paused_at = 1_700_000_000;
+        dist.vesting_offset = 600;
+        dist.claim_start_ts = 1_700_000_000;
+        dist.claim_end_ts = 1_800_000_000;
+        dist
+    }
+
+    #[test]
+    fn test_version_lens_match_current_layout() {
+        assert_eq!(DirectDistribution::VERSION_LENS.len(), DirectDistribution::VERSION as usize);
+        assert_eq!(DirectDistribution::len_for_version(DirectDistribution::VERSION).unwrap(), DirectDistribution::LEN);
+    }
+
+    #[test]
+    fn test_parse_v1_layout() {
+        let mut dist = populated_distribution();
+        dist.seed_authority = dist.authority;
+        let parsed = DirectDistribution::parse_from_bytes(&legacy_bytes(&dist, 1)).unwrap();
+
+        assert_eq!(parsed.authority, dist.authority);
+        assert_eq!(parsed.mint, dist.mint);
+        assert_eq!(parsed.seed, dist.seed);
+        assert_eq!(parsed.total_allocated, 1_000);
+        assert_eq!(parsed.total_claimed, 400);
+        assert_eq!(parsed.clawback_ts, 1_900_000_000);
+        assert_eq!(parsed.seed_authority, dist.authority);
+        assert_eq!(parsed.pending_authority, Address::default());
+        assert_eq!(parsed.total_funded, 1_000);
+        assert_eq!(parsed.paused_at, 0);
+        assert_eq!(parsed.vesting_offset, 0);
+        assert_eq!(parsed.claim_start_ts, 0);
+        assert_eq!(parsed.claim_end_ts, 0);
+    }
+
+    #[test]
+    fn test_parse_v2_layout() {
+        let dist = populated_distribution();
+        let parsed = DirectDistribution::parse_from_bytes(&legacy_bytes(&dist, 2)).unwrap();
+
+        assert_eq!(parsed.seed_authority, dist.seed_authority);
+        assert_eq!(parsed.pending_authority, dist.pending_authority);
+        assert_eq!(parsed.total_funded, 1_000);
+        assert_eq!(parsed.paused_at, 0);
+        assert_eq!(parsed.claim_end_ts, 0);
+    }
+
+    #[test]
+    fn test_parse_v3_layout() {
+        let dist = populated_distribution();
+        let parsed = DirectDistribution::parse_from_bytes(&legacy_bytes(&dist, 3)).unwrap();
+
+        assert_eq!(parsed.pending_authority, dist.pending_authority);
+        assert_eq!(parsed.total_funded, 5_000);
+        assert_eq!(parsed.paused_at, 0);
+        assert_eq!(parsed.vesting_offset, 0);
+        assert_eq!(parsed.claim_end_ts, 0);
+    }
+
+    #[test]
+    fn test_parse_v4_layout() {
+        let dist = populated_distribution();
+        let parsed = DirectDistribution::parse_from_bytes(&legacy_bytes(&dist, 4)).unwrap();
+
+        assert_eq!(parsed.total_funded, 5_000);
+        assert_eq!(parsed.paused_at, 1_700_000_000);
+        assert_eq!(parsed.vesting_offset, 600);
+        assert_eq!(parsed.claim_start_ts, 0);
+        assert_eq!(parsed.claim_end_ts, 0);
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_version() {
+        let mut bytes = create_test_distribution().to_bytes();
+        bytes[1] = 0;
+        assert_eq!(
+            DirectDistribution::parse_from_bytes(&bytes),
+            Err(RewardsProgramError::InvalidAccountVersion.into())
+        );
+        bytes[1] = DirectDistribution::VERSION + 1;
+        assert_eq!(
+            DirectDistribution::parse_from_bytes(&bytes),
+            Err(RewardsProgramError::InvalidAccountVersion.into())
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_truncated_legacy_layout() {
+        let mut bytes = legacy_bytes(&populated_distribution(), 2);
+        bytes.pop();
+        assert_eq!(DirectDistribution::parse_from_bytes(&bytes), Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_migrated_bytes_use_current_layout() {
+        let parsed = DirectDistribution::parse_from_bytes(&legacy_bytes(&populated_distribution(), 1)).unwrap();
+        let bytes = parsed.to_bytes();
+
+        assert_eq!(bytes.len(), DirectDistribution::LEN);
+        assert_eq!(bytes[1], DirectDistribution::VERSION);
+        assert_eq!(DirectDistribution::parse_from_bytes(&bytes).unwrap(), parsed);
+    }
 }
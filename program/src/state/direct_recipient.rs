@@ -10,8 +10,8 @@ use pinocchio::{
 
 use crate::errors::RewardsProgramError;
 use crate::traits::{
-    AccountParse, AccountSerialize, AccountSize, AccountValidation, ClaimTracker, Discriminator, PdaAccount, PdaSeeds,
-    RewardsAccountDiscriminators, Versioned, VestingParams, ACCOUNT_HEADER_SIZE,
+    AccountParse, AccountSerialize, AccountSize, AccountValidation, ClaimTracker, Discriminator, Migrate, PdaAccount,
+    PdaSeeds, RewardsAccountDiscriminators, Versioned, VestingParams, ACCOUNT_HEADER_SIZE,
 };
 use crate::utils::VestingSchedule;
 use crate::{require_account_len, validate_discriminator};
@@ -21,8 +21,19 @@ use crate::{require_account_len, validate_discriminator};
 /// Represents a recipient's allocation within a direct distribution.
 /// Each recipient has their own vesting schedule.
 ///
-/// Fixed fields first, variable-length schedule last. Account size
-/// depends on the schedule variant (116–140 bytes total).
+/// Fixed fields first, then the variable-length schedule, then fields added
+/// by later versions. Account size depends on the schedule variant
+/// (140–305 bytes total).
+///
+/// `revoked_at` is the vesting timestamp a `Freeze` revoke stopped vesting at
+/// (0 = not frozen).
+///
+/// `schedule_amount` is the amount the schedule's curve vests over. It starts
+/// equal to `total_amount`; a `DecreaseDirectAllocation` lowers only
+/// `total_amount`, trimming the tail of the curve without relocking vested tokens.
+///
+/// `base_vesting_offset` is the distribution's `vesting_offset` when the recipient
+/// was added, so only pauses after that shift its schedule.
 ///
 /// # PDA Seeds
 /// `[b"direct_recipient", distribution.as_ref(), recipient.as_ref()]`
@@ -35,28 +46,34 @@ pub struct DirectRecipient {
     pub total_amount: u64,
     pub claimed_amount: u64,
     pub schedule: VestingSchedule,
+    pub revoked_at: i64,
+    pub schedule_amount: u64,
+    pub base_vesting_offset: i64,
 }
 
 /// Fixed fields size: bump(1) + distribution(32) + recipient(32) + payer(32) + total_amount(8) + claimed_amount(8)
 const FIXED_DATA_LEN: usize = 1 + 32 + 32 + 32 + 8 + 8;
 
+/// Fields after the schedule: revoked_at(8) + schedule_amount(8) + base_vesting_offset(8)
+const TRAILING_DATA_LEN: usize = 8 + 8 + 8;
+
 impl Discriminator for DirectRecipient {
     const DISCRIMINATOR: u8 = RewardsAccountDiscriminators::DirectRecipient as u8;
 }
 
 impl Versioned for DirectRecipient {
-    const VERSION: u8 = 1;
+    const VERSION: u8 = 4;
 }
 
 impl AccountSize for DirectRecipient {
-    /// Minimum DATA_LEN: fixed fields (113) + smallest schedule variant (Immediate = 1 byte) = 114
-    const DATA_LEN: usize = FIXED_DATA_LEN + 1;
+    /// Minimum DATA_LEN: fixed fields (113) + smallest schedule variant (Immediate = 1 byte) + trailing fields (24) = 138
+    const DATA_LEN: usize = FIXED_DATA_LEN + 1 + TRAILING_DATA_LEN;
 }
 
 impl AccountParse for DirectRecipient {
     fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
-        require_account_len!(data, Self::LEN);
         validate_discriminator!(data, Self::DISCRIMINATOR);
+        let version = Self::stored_version(data)?;
 
         // Skip discriminator (byte 0) and version (byte 1)
         let data = &data[2..];
@@ -72,16 +89,62 @@ impl AccountParse for DirectRecipient {
             u64::from_le_bytes(data[97..105].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
         let claimed_amount =
             u64::from_le_bytes(data[105..113].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
-        let (schedule, _) = VestingSchedule::from_bytes(&data[113..])?;
-
-        Ok(Self { bump, distribution, recipient, payer, total_amount, claimed_amount, schedule })
+        let (schedule, schedule_len) = VestingSchedule::from_bytes(&data[113..])?;
+
+        // v2: revoked_at
+        let data = &data[113 + schedule_len..];
+        let revoked_at = if version >= 2 {
+            require_account_len!(data, 8);
+            i64::from_le_bytes(data[0..8].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?)
+        } else {
+            0
+        };
+
+        // v3: schedule_amount
+        let schedule_amount = if version >= 3 {
+            require_account_len!(data, 16);
+            u64::from_le_bytes(data[8..16].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?)
+        } else {
+            total_amount
+        };
+
+        // v4: base_vesting_offset
+        let base_vesting_offset = if version >= 4 {
+            require_account_len!(data, 24);
+            i64::from_le_bytes(data[16..24].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?)
+        } else {
+            0
+        };
+
+        Ok(Self {
+            bump,
+            distribution,
+            recipient,
+            payer,
+            total_amount,
+            claimed_amount,
+            schedule,
+            revoked_at,
+            schedule_amount,
+            base_vesting_offset,
+        })
     }
 }
 
+impl Migrate for DirectRecipient {
+    // Lengths with the smallest schedule; parsing checks the rest once the schedule is known
+    const VERSION_LENS: &'static [usize] = &[
+        ACCOUNT_HEADER_SIZE + FIXED_DATA_LEN + 1,                     // v1
+        ACCOUNT_HEADER_SIZE + FIXED_DATA_LEN + 1 + 8,                 // v2: revoked_at
+        ACCOUNT_HEADER_SIZE + FIXED_DATA_LEN + 1 + 16,                // v3: schedule_amount
+        ACCOUNT_HEADER_SIZE + FIXED_DATA_LEN + 1 + TRAILING_DATA_LEN, // v4: base_vesting_offset
+    ];
+}
+
 impl AccountSerialize for DirectRecipient {
     #[inline(always)]
     fn to_bytes_inner(&self) -> Vec<u8> {
-        let mut data = Vec::with_capacity(FIXED_DATA_LEN + self.schedule.byte_len());
+        let mut data = Vec::with_capacity(FIXED_DATA_LEN + self.schedule.byte_len() + TRAILING_DATA_LEN);
         data.push(self.bump);
         data.extend_from_slice(self.distribution.as_ref());
         data.extend_from_slice(self.recipient.as_ref());
@@ -89,6 +152,9 @@ impl AccountSerialize for DirectRecipient {
         data.extend_from_slice(&self.total_amount.to_le_bytes());
         data.extend_from_slice(&self.claimed_amount.to_le_bytes());
         data.extend_from_slice(&self.schedule.to_bytes());
+        data.extend_from_slice(&self.revoked_at.to_le_bytes());
+        data.extend_from_slice(&self.schedule_amount.to_le_bytes());
+        data.extend_from_slice(&self.base_vesting_offset.to_le_bytes());
         data
     }
 }
@@ -144,14 +210,29 @@ impl VestingParams for DirectRecipient {
     }
 
     #[inline(always)]
-    fn vesting_schedule(&self) -> VestingSchedule {
-        self.schedule
+    fn vesting_schedule(&self) -> &VestingSchedule {
+        &self.schedule
+    }
+
+    #[inline(always)]
+    fn schedule_amount(&self) -> u64 {
+        self.schedule_amount
+    }
+
+    #[inline(always)]
+    fn revoked_at(&self) -> i64 {
+        self.revoked_at
+    }
+
+    #[inline(always)]
+    fn base_vesting_offset(&self) -> i64 {
+        self.base_vesting_offset
     }
 }
 
 impl DirectRecipient {
     pub fn calculate_account_size(schedule: &VestingSchedule) -> usize {
-        ACCOUNT_HEADER_SIZE + FIXED_DATA_LEN + schedule.byte_len()
+        ACCOUNT_HEADER_SIZE + FIXED_DATA_LEN + schedule.byte_len() + TRAILING_DATA_LEN
     }
 
     #[inline(always)]
@@ -163,7 +244,18 @@ impl DirectRecipient {
         total_amount: u64,
         schedule: VestingSchedule,
     ) -> Self {
-        Self { bump, distribution, recipient, payer, total_amount, claimed_amount: 0, schedule }
+        Self {
+            bump,
+            distribution,
+            recipient,
+            payer,
+            total_amount,
+            claimed_amount: 0,
+            schedule,
+            revoked_at: 0,
+            schedule_amount: total_amount,
+            base_vesting_offset: 0,
+        }
     }
 
     #[inline(always)]
@@ -190,6 +282,21 @@ impl DirectRecipient {
         f(&signers)
     }
 
+    #[inline(always)]
+    pub fn is_frozen(&self) -> bool {
+        self.revoked_at != 0
+    }
+
+    /// Total the recipient can ever claim: the whole allocation, or what had vested when it was frozen
+    #[inline(always)]
+    pub fn entitled_amount(&self) -> Result<u64, ProgramError> {
+        if self.is_frozen() {
+            VestingParams::calculate_unlocked(self, self.revoked_at)
+        } else {
+            Ok(self.total_amount)
+        }
+    }
+
     pub fn remaining_amount(&self) -> Result<u64, RewardsProgramError> {
         self.total_amount.checked_sub(self.claimed_amount).ok_or(RewardsProgramError::MathOverflow)
     }
@@ -248,7 +355,7 @@ mod tests {
             Address::new_from_array([2u8; 32]),
             Address::new_from_array([3u8; 32]),
             1000,
-            schedule,
+            schedule.clone(),
         );
         assert_eq!(recipient.schedule, schedule);
     }
@@ -258,8 +365,8 @@ mod tests {
         let recipient = create_test_recipient();
         let bytes = recipient.to_bytes_inner();
 
-        // Linear schedule = 17 bytes, so inner = 113 + 17 = 130
-        assert_eq!(bytes.len(), FIXED_DATA_LEN + recipient.schedule.byte_len());
+        // Linear schedule = 17 bytes, so inner = 113 + 17 + 24 = 154
+        assert_eq!(bytes.len(), FIXED_DATA_LEN + recipient.schedule.byte_len() + TRAILING_DATA_LEN);
         assert_eq!(bytes[0], 255); // bump
         assert_eq!(&bytes[1..33], &[1u8; 32]); // distribution
         assert_eq!(&bytes[33..65], &[2u8; 32]); // recipient
@@ -347,6 +454,134 @@ mod tests {
         assert_eq!(deserialized.schedule, recipient.schedule);
     }
 
+    #[test]
+    fn test_roundtrip_serialization_frozen() {
+        let mut recipient = create_test_recipient();
+        recipient.revoked_at = 150;
+
+        let bytes = recipient.to_bytes();
+        let deserialized = DirectRecipient::parse_from_bytes(&bytes).unwrap();
+
+        assert_eq!(deserialized.revoked_at, 150);
+        assert!(deserialized.is_frozen());
+    }
+
+    #[test]
+    fn test_roundtrip_serialization_trimmed() {
+        let mut recipient = create_test_recipient();
+        recipient.total_amount = 600;
+
+        let bytes = recipient.to_bytes();
+        let deserialized = DirectRecipient::parse_from_bytes(&bytes).unwrap();
+
+        assert_eq!(deserialized.total_amount, 600);
+        assert_eq!(deserialized.schedule_amount, 1000);
+    }
+
+    #[test]
+    fn test_parse_v1_defaults_revoked_at() {
+        let mut recipient = create_test_recipient();
+        recipient.claimed_amount = 300;
+
+        // v1 ended at the schedule
+        let mut bytes = recipient.to_bytes();
+        bytes[1] = 1;
+        bytes.truncate(bytes.len() - TRAILING_DATA_LEN);
+
+        let parsed = DirectRecipient::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, recipient);
+        assert!(!parsed.is_frozen());
+        assert_eq!(parsed.to_bytes(), recipient.to_bytes());
+    }
+
+    #[test]
+    fn test_parse_v2_defaults_schedule_amount() {
+        let mut recipient = create_test_recipient();
+        recipient.revoked_at = 150;
+
+        // v2 ended at revoked_at
+        let mut bytes = recipient.to_bytes();
+        bytes[1] = 2;
+        bytes.truncate(bytes.len() - 16);
+
+        let parsed = DirectRecipient::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, recipient);
+        assert_eq!(parsed.schedule_amount, parsed.total_amount);
+    }
+
+    #[test]
+    fn test_parse_v3_defaults_base_vesting_offset() {
+        let mut recipient = create_test_recipient();
+        recipient.total_amount = 600;
+
+        // v3 ended at schedule_amount
+        let mut bytes = recipient.to_bytes();
+        bytes[1] = 3;
+        bytes.truncate(bytes.len() - 8);
+
+        let parsed = DirectRecipient::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, recipient);
+        assert_eq!(parsed.base_vesting_offset, 0);
+    }
+
+    #[test]
+    fn test_roundtrip_serialization_base_vesting_offset() {
+        let mut recipient = create_test_recipient();
+        recipient.base_vesting_offset = 3600;
+
+        let bytes = recipient.to_bytes();
+        let deserialized = DirectRecipient::parse_from_bytes(&bytes).unwrap();
+
+        assert_eq!(deserialized.base_vesting_offset, 3600);
+        assert_eq!(VestingParams::base_vesting_offset(&deserialized), 3600);
+    }
+
+    #[test]
+    fn test_parse_rejects_truncated_revoked_at() {
+        let bytes = create_test_recipient().to_bytes();
+        assert_eq!(DirectRecipient::parse_from_bytes(&bytes[..bytes.len() - 1]), Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_version_lens() {
+        let immediate = VestingSchedule::Immediate {};
+        assert_eq!(DirectRecipient::len_for_version(1).unwrap(), 116);
+        assert_eq!(DirectRecipient::len_for_version(2).unwrap(), 124);
+        assert_eq!(DirectRecipient::len_for_version(3).unwrap(), 132);
+        assert_eq!(DirectRecipient::len_for_version(4).unwrap(), DirectRecipient::calculate_account_size(&immediate));
+        assert_eq!(DirectRecipient::len_for_version(DirectRecipient::VERSION).unwrap(), DirectRecipient::LEN);
+    }
+
+    #[test]
+    fn test_entitled_amount() {
+        let mut recipient = create_test_recipient();
+        assert_eq!(recipient.entitled_amount().unwrap(), 1000);
+
+        // start=100, end=200, frozen at the midpoint
+        recipient.revoked_at = 150;
+        assert_eq!(recipient.entitled_amount().unwrap(), 500);
+        assert_eq!(VestingParams::calculate_unlocked(&recipient, 250).unwrap(), 500);
+        assert_eq!(VestingParams::calculate_unlocked(&recipient, 120).unwrap(), 200);
+    }
+
+    #[test]
+    fn test_trimmed_allocation_keeps_vested_curve() {
+        let mut recipient = create_test_recipient();
+        // Decreased by 400 while 500 had vested
+        recipient.total_amount = 600;
+
+        assert_eq!(VestingParams::calculate_unlocked(&recipient, 150).unwrap(), 500);
+        assert_eq!(VestingParams::calculate_unlocked(&recipient, 170).unwrap(), 600);
+        assert_eq!(recipient.entitled_amount().unwrap(), 600);
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_version() {
+        let mut bytes = create_test_recipient().to_bytes();
+        bytes[1] = DirectRecipient::VERSION + 1;
+        assert_eq!(DirectRecipient::parse_from_bytes(&bytes), Err(RewardsProgramError::InvalidAccountVersion.into()));
+    }
+
     #[test]
     fn test_roundtrip_serialization_cliff_linear() {
         let schedule = VestingSchedule::CliffLinear { start_ts: 0, cliff_ts: 100, end_ts: 400 };
@@ -356,7 +591,7 @@ mod tests {
             Address::new_from_array([2u8; 32]),
             Address::new_from_array([3u8; 32]),
             5000,
-            schedule,
+            schedule.clone(),
         );
 
         let bytes = recipient.to_bytes();
@@ -435,7 +670,10 @@ mod tests {
     fn test_vesting_params_trait() {
         let recipient = create_test_recipient();
         assert_eq!(VestingParams::total_amount(&recipient), 1000);
-        assert_eq!(VestingParams::vesting_schedule(&recipient), VestingSchedule::Linear { start_ts: 100, end_ts: 200 });
+        assert_eq!(
+            VestingParams::vesting_schedule(&recipient),
+            &VestingSchedule::Linear { start_ts: 100, end_ts: 200 }
+        );
     }
 
     #[test]
@@ -10,15 +10,23 @@ use pinocchio::{
 
 use crate::errors::RewardsProgramError;
 use crate::traits::{
-    AccountParse, AccountSerialize, AccountSize, AccountValidation, ClaimTracker, Discriminator, PdaSeeds,
-    RewardsAccountDiscriminators, Versioned,
+    AccountParse, AccountSerialize, AccountSize, AccountValidation, ClaimTracker, Discriminator, Migrate, PdaSeeds,
+    RewardsAccountDiscriminators, Versioned, ACCOUNT_HEADER_SIZE,
 };
-use crate::{assert_no_padding, require_account_len, validate_discriminator};
+use crate::{assert_no_padding, validate_discriminator};
 
 /// MerkleClaim account state
 ///
 /// Minimal PDA tracking how much a user has claimed from a merkle distribution.
-/// Rent is paid by claimant and refundable when claim is closed.
+/// Rent is paid by whoever sends the first claim (the claimant or a sponsor/cranker)
+/// and refunded to that payer when the claim is closed.
+///
+/// v1 claims did not record `payer`; they parse with a default payer and refund the claimant.
+///
+/// A fully claimed claim can be closed while its distribution is still open. The original payer
+/// is refunded in full and the claimant takes over the rent, becoming `payer`. The account stays
+/// as a closed record keeping `claimed_amount`, so `ClaimMerkle` cannot pay the same leaf again.
+/// Claiming later (e.g. after a root update raises the leaf) reopens it.
 ///
 /// # PDA Seeds
 /// `[b"merkle_claim", distribution.as_ref(), claimant.as_ref()]`
@@ -26,48 +34,69 @@ use crate::{assert_no_padding, require_account_len, validate_discriminator};
 #[repr(C)]
 pub struct MerkleClaim {
     pub bump: u8,
-    _padding: [u8; 7],
+    /// 1 once closed early; `payer` is then the claimant
+    pub closed: u8,
+    _padding: [u8; 6],
     pub claimed_amount: u64,
+    pub payer: Address,
 }
 
-assert_no_padding!(MerkleClaim, 1 + 7 + 8);
+assert_no_padding!(MerkleClaim, 1 + 1 + 6 + 8 + 32);
 
 impl Discriminator for MerkleClaim {
     const DISCRIMINATOR: u8 = RewardsAccountDiscriminators::MerkleClaim as u8;
 }
 
 impl Versioned for MerkleClaim {
-    const VERSION: u8 = 1;
+    const VERSION: u8 = 2;
 }
 
 impl AccountSize for MerkleClaim {
-    const DATA_LEN: usize = 1 + 7 + 8; // 16
+    const DATA_LEN: usize = 1 + 7 + 8 + 32; // 48
 }
 
 impl AccountParse for MerkleClaim {
     fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
-        require_account_len!(data, Self::LEN);
         validate_discriminator!(data, Self::DISCRIMINATOR);
+        let version = Self::stored_version(data)?;
 
         // Skip discriminator (byte 0) and version (byte 1)
         let data = &data[2..];
 
         let bump = data[0];
-        // Skip padding bytes [1..8]
+        // Was padding before v2; always 0 in v1 claims
+        let closed = data[1];
+        // Skip padding bytes [2..8]
         let claimed_amount =
             u64::from_le_bytes(data[8..16].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
 
-        Ok(Self { bump, _padding: [0u8; 7], claimed_amount })
+        // v2: claims created before the payer was recorded were paid for by the claimant
+        let payer = if version >= 2 {
+            Address::new_from_array(data[16..48].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?)
+        } else {
+            Address::default()
+        };
+
+        Ok(Self { bump, closed, _padding: [0u8; 6], claimed_amount, payer })
     }
 }
 
+impl Migrate for MerkleClaim {
+    const VERSION_LENS: &'static [usize] = &[
+        ACCOUNT_HEADER_SIZE + 16, // v1
+        ACCOUNT_HEADER_SIZE + 48, // v2: payer
+    ];
+}
+
 impl AccountSerialize for MerkleClaim {
     #[inline(always)]
     fn to_bytes_inner(&self) -> Vec<u8> {
         let mut data = Vec::with_capacity(Self::DATA_LEN);
         data.push(self.bump);
-        data.extend_from_slice(&[0u8; 7]); // padding
+        data.push(self.closed);
+        data.extend_from_slice(&[0u8; 6]); // padding
         data.extend_from_slice(&self.claimed_amount.to_le_bytes());
+        data.extend_from_slice(self.payer.as_ref());
         data
     }
 }
@@ -117,8 +146,26 @@ impl ClaimTracker for MerkleClaim {
 
 impl MerkleClaim {
     #[inline(always)]
-    pub fn new(bump: u8) -> Self {
-        Self { bump, _padding: [0u8; 7], claimed_amount: 0 }
+    pub fn new(bump: u8, payer: Address) -> Self {
+        Self { bump, closed: 0, _padding: [0u8; 6], claimed_amount: 0, payer }
+    }
+
+    #[inline(always)]
+    pub fn is_closed(&self) -> bool {
+        self.closed != 0
+    }
+
+    /// Mark the claim closed early, with `claimant` now paying its rent.
+    #[inline(always)]
+    pub fn close(&mut self, claimant: Address) {
+        self.closed = 1;
+        self.payer = claimant;
+    }
+
+    /// Reopen a claim closed early; the claimant keeps paying its rent.
+    #[inline(always)]
+    pub fn reopen(&mut self) {
+        self.closed = 0;
     }
 
     #[inline(always)]
@@ -151,6 +198,16 @@ impl MerkleClaim {
         f(&signers)
     }
 
+    /// Returns who paid the rent; claims migrated from v1 never recorded it and were paid by the claimant
+    #[inline(always)]
+    pub fn rent_payer<'a>(&'a self, claimant: &'a Address) -> &'a Address {
+        if self.payer == Address::default() {
+            claimant
+        } else {
+            &self.payer
+        }
+    }
+
     pub fn remaining_amount(&self, total_amount: u64) -> Result<u64, RewardsProgramError> {
         total_amount.checked_sub(self.claimed_amount).ok_or(RewardsProgramError::MathOverflow)
     }
@@ -162,7 +219,7 @@ mod tests {
     use crate::traits::ClaimTracker;
 
     fn create_test_claim() -> MerkleClaim {
-        MerkleClaim::new(255)
+        MerkleClaim::new(255, Address::new_from_array([3u8; 32]))
     }
 
     #[test]
@@ -170,6 +227,7 @@ mod tests {
         let claim = create_test_claim();
         assert_eq!(claim.bump, 255);
         assert_eq!(claim.claimed_amount, 0);
+        assert_eq!(claim.payer, Address::new_from_array([3u8; 32]));
     }
 
     #[test]
@@ -179,7 +237,9 @@ mod tests {
 
         assert_eq!(bytes.len(), MerkleClaim::DATA_LEN);
         assert_eq!(bytes[0], 255); // bump
-        assert_eq!(&bytes[1..8], &[0u8; 7]); // padding
+        assert_eq!(bytes[1], 0); // closed
+        assert_eq!(&bytes[2..8], &[0u8; 6]); // padding
+        assert_eq!(&bytes[16..48], &[3u8; 32]); // payer
     }
 
     #[test]
@@ -203,6 +263,36 @@ mod tests {
 
         assert_eq!(deserialized.bump, claim.bump);
         assert_eq!(deserialized.claimed_amount, claim.claimed_amount);
+        assert_eq!(deserialized.payer, claim.payer);
+    }
+
+    #[test]
+    fn test_closed_claim_roundtrip() {
+        let mut claim = create_test_claim();
+        claim.claimed_amount = 1_000;
+        let claimant = Address::new_from_array([7u8; 32]);
+        claim.close(claimant);
+
+        let bytes = claim.to_bytes();
+        assert_eq!(bytes.len(), MerkleClaim::LEN);
+
+        let parsed = MerkleClaim::parse_from_bytes(&bytes).unwrap();
+        assert!(parsed.is_closed());
+        assert_eq!(parsed.claimed_amount, 1_000);
+        assert_eq!(parsed.rent_payer(&claimant), &claimant);
+    }
+
+    #[test]
+    fn test_reopen_keeps_claimed_amount_and_payer() {
+        let mut claim = create_test_claim();
+        claim.claimed_amount = 1_000;
+        let claimant = Address::new_from_array([7u8; 32]);
+        claim.close(claimant);
+        claim.reopen();
+
+        assert!(!claim.is_closed());
+        assert_eq!(claim.claimed_amount, 1_000);
+        assert_eq!(claim.payer, claimant);
     }
 
     #[test]
@@ -273,4 +363,41 @@ mod tests {
         claim.claimed_amount = 1500;
         assert!(claim.remaining_amount(1000).is_err());
     }
+
+    #[test]
+    fn test_parse_v1_layout() {
+        let mut claim = create_test_claim();
+        claim.claimed_amount = 700;
+        let mut bytes = claim.to_bytes();
+        bytes[1] = 1;
+        bytes.truncate(MerkleClaim::len_for_version(1).unwrap());
+
+        let parsed = MerkleClaim::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(parsed.bump, 255);
+        assert_eq!(parsed.claimed_amount, 700);
+        assert_eq!(parsed.payer, Address::default());
+
+        let claimant = Address::new_from_array([7u8; 32]);
+        assert_eq!(parsed.rent_payer(&claimant), &claimant);
+    }
+
+    #[test]
+    fn test_rent_payer_uses_recorded_payer() {
+        let claim = create_test_claim();
+        let claimant = Address::new_from_array([7u8; 32]);
+        assert_eq!(claim.rent_payer(&claimant), &Address::new_from_array([3u8; 32]));
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_version() {
+        let mut bytes = create_test_claim().to_bytes();
+        bytes[1] = MerkleClaim::VERSION + 1;
+        assert_eq!(MerkleClaim::parse_from_bytes(&bytes), Err(RewardsProgramError::InvalidAccountVersion.into()));
+    }
+
+    #[test]
+    fn test_version_lens_match_current_layout() {
+        assert_eq!(MerkleClaim::VERSION_LENS.len(), MerkleClaim::VERSION as usize);
+        assert_eq!(MerkleClaim::len_for_version(MerkleClaim::VERSION).unwrap(), MerkleClaim::LEN);
+    }
 }
@@ -0,0 +1,259 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use codama::CodamaAccount;
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address};
+
+use crate::errors::RewardsProgramError;
+use crate::traits::{
+    AccountParse, AccountSerialize, AccountSize, AccountValidation, Discriminator, PdaSeeds,
+    RewardsAccountDiscriminators, Versioned,
+};
+use crate::{assert_no_padding, require_account_len, validate_discriminator, validate_version};
+
+/// Bytes of claim bits held by one bitmap chunk
+pub const CLAIM_BITMAP_CHUNK_BYTES: usize = 1024;
+
+/// Leaves tracked by one bitmap chunk
+pub const CLAIM_BITMAP_CHUNK_BITS: u32 = (CLAIM_BITMAP_CHUNK_BYTES * 8) as u32;
+
+/// MerkleClaimBitmap account state
+///
+/// One chunk of the claim-status bitmap of a `Bitmap`-mode merkle distribution.
+/// Leaf `index` lives in chunk `index / CLAIM_BITMAP_CHUNK_BITS`; its bit is set once
+/// the leaf has been claimed. Rent is paid by `payer` and refunded to it on close.
+///
+/// `bits` is spelled with a literal length because codama cannot resolve constant array lengths.
+///
+/// # PDA Seeds
+/// `[b"merkle_claim_bitmap", distribution.as_ref(), chunk_index.to_le_bytes()]`
+#[derive(Clone, Debug, PartialEq, CodamaAccount)]
+#[repr(C)]
+pub struct MerkleClaimBitmap {
+    pub bump: u8,
+    _padding: [u8; 3],
+    pub chunk_index: u32,
+    pub payer: Address,
+    pub bits: [u8; 1024],
+}
+
+assert_no_padding!(MerkleClaimBitmap, 1 + 3 + 4 + 32 + CLAIM_BITMAP_CHUNK_BYTES);
+
+impl Discriminator for MerkleClaimBitmap {
+    const DISCRIMINATOR: u8 = RewardsAccountDiscriminators::MerkleClaimBitmap as u8;
+}
+
+impl Versioned for MerkleClaimBitmap {
+    const VERSION: u8 = 1;
+}
+
+impl AccountSize for MerkleClaimBitmap {
+    const DATA_LEN: usize = 1 + 3 + 4 + 32 + CLAIM_BITMAP_CHUNK_BYTES; // 1064
+}
+
+impl AccountParse for MerkleClaimBitmap {
+    fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_account_len!(data, Self::LEN);
+        validate_discriminator!(data, Self::DISCRIMINATOR);
+        validate_version!(data, Self::VERSION);
+
+        // Skip discriminator (byte 0) and version (byte 1)
+        let data = &data[2..];
+
+        let bump = data[0];
+        // Skip padding bytes [1..4]
+        let chunk_index =
+            u32::from_le_bytes(data[4..8].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        let payer =
+            Address::new_from_array(data[8..40].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        let bits: [u8; CLAIM_BITMAP_CHUNK_BYTES] =
+            data[40..40 + CLAIM_BITMAP_CHUNK_BYTES].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?;
+
+        Ok(Self { bump, _padding: [0u8; 3], chunk_index, payer, bits })
+    }
+}
+
+impl AccountSerialize for MerkleClaimBitmap {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.push(self.bump);
+        data.extend_from_slice(&[0u8; 3]); // padding
+        data.extend_from_slice(&self.chunk_index.to_le_bytes());
+        data.extend_from_slice(self.payer.as_ref());
+        data.extend_from_slice(&self.bits);
+        data
+    }
+}
+
+impl AccountValidation for MerkleClaimBitmap {}
+
+/// Seed helper for deriving MerkleClaimBitmap PDA without having the full state
+pub struct MerkleClaimBitmapSeeds {
+    pub distribution: Address,
+    pub chunk_index: [u8; 4],
+}
+
+impl MerkleClaimBitmapSeeds {
+    #[inline(always)]
+    pub fn new(distribution: Address, chunk_index: u32) -> Self {
+        Self { distribution, chunk_index: chunk_index.to_le_bytes() }
+    }
+}
+
+impl PdaSeeds for MerkleClaimBitmapSeeds {
+    const PREFIX: &'static [u8] = b"merkle_claim_bitmap";
+
+    #[inline(always)]
+    fn seeds(&self) -> Vec<&[u8]> {
+        vec![Self::PREFIX, self.distribution.as_ref(), self.chunk_index.as_ref()]
+    }
+
+    #[inline(always)]
+    fn seeds_with_bump<'a>(&'a self, bump: &'a [u8; 1]) -> Vec<Seed<'a>> {
+        vec![
+            Seed::from(Self::PREFIX),
+            Seed::from(self.distribution.as_ref()),
+            Seed::from(self.chunk_index.as_ref()),
+            Seed::from(bump.as_slice()),
+        ]
+    }
+}
+
+impl MerkleClaimBitmap {
+    #[inline(always)]
+    pub fn new(bump: u8, chunk_index: u32, payer: Address) -> Self {
+        Self { bump, _padding: [0u8; 3], chunk_index, payer, bits: [0u8; CLAIM_BITMAP_CHUNK_BYTES] }
+    }
+
+    #[inline(always)]
+    pub fn from_account(
+        data: &[u8],
+        account: &AccountView,
+        program_id: &Address,
+        distribution: &Address,
+    ) -> Result<Self, ProgramError> {
+        let state = Self::parse_from_bytes(data)?;
+        let seeds = MerkleClaimBitmapSeeds::new(*distribution, state.chunk_index);
+        seeds.validate_pda(account, program_id, state.bump)?;
+        Ok(state)
+    }
+
+    /// Chunk holding leaf `index`
+    #[inline(always)]
+    pub fn chunk_for(index: u32) -> u32 {
+        index / CLAIM_BITMAP_CHUNK_BITS
+    }
+
+    /// Byte offset and bit mask of leaf `index` within its chunk
+    #[inline(always)]
+    fn bit_position(index: u32) -> (usize, u8) {
+        let bit = index % CLAIM_BITMAP_CHUNK_BITS;
+        ((bit / 8) as usize, 1u8 << (bit % 8))
+    }
+
+    #[inline(always)]
+    pub fn is_claimed(&self, index: u32) -> bool {
+        let (byte, mask) = Self::bit_position(index);
+        self.bits[byte] & mask != 0
+    }
+
+    /// Mark leaf `index` as claimed; fails if this chunk does not hold it or it is already claimed
+    #[inline(always)]
+    pub fn set_claimed(&mut self, index: u32) -> Result<(), ProgramError> {
+        if Self::chunk_for(index) != self.chunk_index {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if self.is_claimed(index) {
+            return Err(RewardsProgramError::LeafAlreadyClaimed.into());
+        }
+        let (byte, mask) = Self::bit_position(index);
+        self.bits[byte] |= mask;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_bitmap(chunk_index: u32) -> MerkleClaimBitmap {
+        MerkleClaimBitmap::new(254, chunk_index, Address::new_from_array([5u8; 32]))
+    }
+
+    #[test]
+    fn test_merkle_claim_bitmap_new() {
+        let bitmap = create_test_bitmap(2);
+        assert_eq!(bitmap.bump, 254);
+        assert_eq!(bitmap.chunk_index, 2);
+        assert_eq!(bitmap.payer, Address::new_from_array([5u8; 32]));
+        assert!(bitmap.bits.iter().all(|byte| *byte == 0));
+    }
+
+    #[test]
+    fn test_merkle_claim_bitmap_to_bytes() {
+        let bitmap = create_test_bitmap(2);
+        let bytes = bitmap.to_bytes();
+
+        assert_eq!(bytes.len(), MerkleClaimBitmap::LEN);
+        assert_eq!(bytes[0], MerkleClaimBitmap::DISCRIMINATOR);
+        assert_eq!(bytes[1], MerkleClaimBitmap::VERSION);
+        assert_eq!(bytes[2], 254); // bump
+        assert_eq!(&bytes[6..10], &2u32.to_le_bytes()); // chunk_index
+    }
+
+    #[test]
+    fn test_roundtrip_serialization() {
+        let mut bitmap = create_test_bitmap(1);
+        bitmap.set_claimed(CLAIM_BITMAP_CHUNK_BITS + 9).unwrap();
+
+        let bytes = bitmap.to_bytes();
+        let deserialized = MerkleClaimBitmap::parse_from_bytes(&bytes).unwrap();
+
+        assert_eq!(deserialized, bitmap);
+    }
+
+    #[test]
+    fn test_chunk_for() {
+        assert_eq!(MerkleClaimBitmap::chunk_for(0), 0);
+        assert_eq!(MerkleClaimBitmap::chunk_for(CLAIM_BITMAP_CHUNK_BITS - 1), 0);
+        assert_eq!(MerkleClaimBitmap::chunk_for(CLAIM_BITMAP_CHUNK_BITS), 1);
+    }
+
+    #[test]
+    fn test_set_claimed() {
+        let mut bitmap = create_test_bitmap(0);
+        assert!(!bitmap.is_claimed(10));
+
+        bitmap.set_claimed(10).unwrap();
+
+        assert!(bitmap.is_claimed(10));
+        assert!(!bitmap.is_claimed(9));
+        assert!(!bitmap.is_claimed(11));
+        assert_eq!(bitmap.bits[1], 1 << 2);
+    }
+
+    #[test]
+    fn test_set_claimed_twice() {
+        let mut bitmap = create_test_bitmap(0);
+        bitmap.set_claimed(3).unwrap();
+        let result = bitmap.set_claimed(3);
+        assert_eq!(result.err(), Some(ProgramError::Custom(RewardsProgramError::LeafAlreadyClaimed as u32)));
+    }
+
+    #[test]
+    fn test_set_claimed_wrong_chunk() {
+        let mut bitmap = create_test_bitmap(0);
+        let result = bitmap.set_claimed(CLAIM_BITMAP_CHUNK_BITS);
+        assert_eq!(result.err(), Some(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_pda_seeds() {
+        let seeds = MerkleClaimBitmapSeeds::new(Address::new_from_array([1u8; 32]), 7);
+        let pda_seeds = seeds.seeds();
+        assert_eq!(pda_seeds.len(), 3);
+        assert_eq!(pda_seeds[0], MerkleClaimBitmapSeeds::PREFIX);
+        assert_eq!(pda_seeds[1], seeds.distribution.as_ref());
+        assert_eq!(pda_seeds[2], &7u32.to_le_bytes());
+    }
+}
@@ -11,9 +11,10 @@ use pinocchio::{
 use crate::errors::RewardsProgramError;
 use crate::traits::{
     AccountParse, AccountSerialize, AccountSize, AccountValidation, Discriminator, Distribution, DistributionSigner,
-    PdaAccount, PdaSeeds, RewardsAccountDiscriminators, Versioned,
+    Migrate, PdaAccount, PdaSeeds, RewardsAccountDiscriminators, Versioned, ACCOUNT_HEADER_SIZE,
 };
-use crate::{assert_no_padding, require_account_len, validate_discriminator};
+use crate::utils::MerkleClaimMode;
+use crate::{assert_no_padding, validate_discriminator};
 
 /// MerkleDistribution account state
 ///
@@ -21,14 +22,30 @@ use crate::{assert_no_padding, require_account_len, validate_discriminator};
 /// their allocation via merkle proofs. Each user has per-user vesting
 /// parameters encoded in their merkle leaf.
 ///
+/// The authority can replace the root via `UpdateMerkleRoot`. Each update bumps
+/// `epoch`; leaves are cumulative, so `MerkleClaim.claimed_amount` carries across roots.
+///
+/// `claim_mode` (a `MerkleClaimMode`) is fixed at creation. `Bitmap` distributions track
+/// claims in `MerkleClaimBitmap` chunks keyed by leaf index, so their root cannot be replaced.
+///
+/// Claimants can claim from `claim_start_ts` until `claim_end_ts` (0 = unbounded). The
+/// authority can close once both `clawback_ts` and `claim_end_ts` have passed.
+///
 /// # PDA Seeds
-/// `[b"merkle_distribution", mint.as_ref(), authority.as_ref(), seeds.as_ref()]`
+/// `[b"merkle_distribution", mint.as_ref(), seed_authority.as_ref(), seeds.as_ref()]`
+///
+/// `seed_authority` is the authority at creation time and never changes, so the
+/// PDA address stays stable. `authority` is the current authority and can be
+/// rotated via the two-step `ProposeAuthority` / `AcceptAuthority` flow.
 #[derive(Clone, Debug, PartialEq, CodamaAccount)]
 #[repr(C)]
 pub struct MerkleDistribution {
     pub bump: u8,
     pub revocable: u8,
-    _padding: [u8; 6],
+    pub transferable: u8,
+    pub paused: u8,
+    pub claim_mode: u8,
+    _padding: [u8; 3],
     pub authority: Address,
     pub mint: Address,
     pub seed: Address,
@@ -36,33 +53,48 @@ pub struct MerkleDistribution {
     pub total_amount: u64,
     pub total_claimed: u64,
     pub clawback_ts: i64,
+    pub seed_authority: Address,
+    pub pending_authority: Address,
+    pub epoch: u64,
+    pub total_funded: u64,
+    pub paused_at: i64,
+    pub vesting_offset: i64,
+    pub claim_start_ts: i64,
+    pub claim_end_ts: i64,
 }
 
-assert_no_padding!(MerkleDistribution, 1 + 1 + 6 + 32 + 32 + 32 + 32 + 8 + 8 + 8);
+assert_no_padding!(
+    MerkleDistribution,
+    1 + 1 + 1 + 1 + 1 + 3 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8
+);
 
 impl Discriminator for MerkleDistribution {
     const DISCRIMINATOR: u8 = RewardsAccountDiscriminators::MerkleDistribution as u8;
 }
 
 impl Versioned for MerkleDistribution {
-    const VERSION: u8 = 1;
+    const VERSION: u8 = 6;
 }
 
 impl AccountSize for MerkleDistribution {
-    const DATA_LEN: usize = 1 + 1 + 6 + 32 + 32 + 32 + 32 + 8 + 8 + 8; // 160
+    const DATA_LEN: usize = 1 + 1 + 1 + 1 + 1 + 3 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8; // 272
 }
 
 impl AccountParse for MerkleDistribution {
     fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
-        require_account_len!(data, Self::LEN);
         validate_discriminator!(data, Self::DISCRIMINATOR);
+        let version = Self::stored_version(data)?;
 
         // Skip discriminator (byte 0) and version (byte 1)
         let data = &data[2..];
 
+        // Flags written into padding bytes are zero in older versions
         let bump = data[0];
         let revocable = data[1];
-        // Skip padding bytes [2..8]
+        let transferable = data[2];
+        let paused = data[3];
+        let claim_mode = data[4];
+        // Skip padding bytes [5..8]
         let authority =
             Address::new_from_array(data[8..40].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
         let mint =
@@ -76,11 +108,61 @@ impl AccountParse for MerkleDistribution {
             u64::from_le_bytes(data[144..152].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
         let clawback_ts =
             i64::from_le_bytes(data[152..160].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+        // v2: authority could not be rotated before, so it is still the seed authority
+        let (seed_authority, pending_authority) = if version >= 2 {
+            (
+                Address::new_from_array(
+                    data[160..192].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?,
+                ),
+                Address::new_from_array(
+                    data[192..224].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?,
+                ),
+            )
+        } else {
+            (authority, Address::default())
+        };
+
+        // v3: root updates
+        let epoch = if version >= 3 {
+            u64::from_le_bytes(data[224..232].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?)
+        } else {
+            0
+        };
+
+        // v4: the initial deposit was not recorded, so fall back to what the tree pays out
+        let total_funded = if version >= 4 {
+            u64::from_le_bytes(data[232..240].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?)
+        } else {
+            total_amount
+        };
+
+        // v5: pausing
+        let (paused_at, vesting_offset) = if version >= 5 {
+            (
+                i64::from_le_bytes(data[240..248].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?),
+                i64::from_le_bytes(data[248..256].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?),
+            )
+        } else {
+            (0, 0)
+        };
+
+        // v6: claim window
+        let (claim_start_ts, claim_end_ts) = if version >= 6 {
+            (
+                i64::from_le_bytes(data[256..264].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?),
+                i64::from_le_bytes(data[264..272].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?),
+            )
+        } else {
+            (0, 0)
+        };
 
         Ok(Self {
             bump,
             revocable,
-            _padding: [0u8; 6],
+            transferable,
+            paused,
+            claim_mode,
+            _padding: [0u8; 3],
             authority,
             mint,
             seed: seeds,
@@ -88,17 +170,39 @@ impl AccountParse for MerkleDistribution {
             total_amount,
             total_claimed,
             clawback_ts,
+            seed_authority,
+            pending_authority,
+            epoch,
+            total_funded,
+            paused_at,
+            vesting_offset,
+            claim_start_ts,
+            claim_end_ts,
         })
     }
 }
 
+impl Migrate for MerkleDistribution {
+    const VERSION_LENS: &'static [usize] = &[
+        ACCOUNT_HEADER_SIZE + 160, // v1
+        ACCOUNT_HEADER_SIZE + 224, // v2: seed_authority, pending_authority
+        ACCOUNT_HEADER_SIZE + 232, // v3: epoch
+        ACCOUNT_HEADER_SIZE + 240, // v4: total_funded
+        ACCOUNT_HEADER_SIZE + 256, // v5: paused_at, vesting_offset
+        ACCOUNT_HEADER_SIZE + 272, // v6: claim_start_ts, claim_end_ts
+    ];
+}
+
 impl AccountSerialize for MerkleDistribution {
     #[inline(always)]
     fn to_bytes_inner(&self) -> Vec<u8> {
         let mut data = Vec::with_capacity(Self::DATA_LEN);
         data.push(self.bump);
         data.push(self.revocable);
-        data.extend_from_slice(&[0u8; 6]); // padding
+        data.push(self.transferable);
+        data.push(self.paused);
+        data.push(self.claim_mode);
+        data.extend_from_slice(&[0u8; 3]); // padding
         data.extend_from_slice(self.authority.as_ref());
         data.extend_from_slice(self.mint.as_ref());
         data.extend_from_slice(self.seed.as_ref());
@@ -106,6 +210,14 @@ impl AccountSerialize for MerkleDistribution {
         data.extend_from_slice(&self.total_amount.to_le_bytes());
         data.extend_from_slice(&self.total_claimed.to_le_bytes());
         data.extend_from_slice(&self.clawback_ts.to_le_bytes());
+        data.extend_from_slice(self.seed_authority.as_ref());
+        data.extend_from_slice(self.pending_authority.as_ref());
+        data.extend_from_slice(&self.epoch.to_le_bytes());
+        data.extend_from_slice(&self.total_funded.to_le_bytes());
+        data.extend_from_slice(&self.paused_at.to_le_bytes());
+        data.extend_from_slice(&self.vesting_offset.to_le_bytes());
+        data.extend_from_slice(&self.claim_start_ts.to_le_bytes());
+        data.extend_from_slice(&self.claim_end_ts.to_le_bytes());
         data
     }
 }
@@ -116,14 +228,14 @@ impl PdaSeeds for MerkleDistribution {
     const PREFIX: &'static [u8] = b"merkle_distribution";
 
     fn seeds(&self) -> Vec<&[u8]> {
-        vec![Self::PREFIX, self.mint.as_ref(), self.authority.as_ref(), self.seed.as_ref()]
+        vec![Self::PREFIX, self.mint.as_ref(), self.seed_authority.as_ref(), self.seed.as_ref()]
     }
 
     fn seeds_with_bump<'a>(&'a self, bump: &'a [u8; 1]) -> Vec<Seed<'a>> {
         vec![
             Seed::from(Self::PREFIX),
             Seed::from(self.mint.as_ref()),
-            Seed::from(self.authority.as_ref()),
+            Seed::from(self.seed_authority.as_ref()),
             Seed::from(self.seed.as_ref()),
             Seed::from(bump.as_slice()),
         ]
@@ -148,6 +260,21 @@ impl Distribution for MerkleDistribution {
         &self.authority
     }
 
+    #[inline(always)]
+    fn set_authority(&mut self, authority: Address) {
+        self.authority = authority;
+    }
+
+    #[inline(always)]
+    fn pending_authority(&self) -> &Address {
+        &self.pending_authority
+    }
+
+    #[inline(always)]
+    fn set_pending_authority(&mut self, pending_authority: Address) {
+        self.pending_authority = pending_authority;
+    }
+
     #[inline(always)]
     fn seeds_key(&self) -> &Address {
         &self.seed
@@ -166,6 +293,77 @@ impl Distribution for MerkleDistribution {
         self.total_claimed = amount;
         Ok(())
     }
+
+    #[inline(always)]
+    fn total_funded(&self) -> u64 {
+        self.total_funded
+    }
+
+    #[inline(always)]
+    fn set_total_funded(&mut self, amount: u64) {
+        self.total_funded = amount;
+    }
+
+    #[inline(always)]
+    fn is_transferable(&self) -> bool {
+        self.transferable != 0
+    }
+
+    #[inline(always)]
+    fn set_transferable(&mut self, transferable: bool) {
+        self.transferable = transferable as u8;
+    }
+
+    #[inline(always)]
+    fn is_paused(&self) -> bool {
+        self.paused != 0
+    }
+
+    #[inline(always)]
+    fn set_paused(&mut self, paused: bool) {
+        self.paused = paused as u8;
+    }
+
+    #[inline(always)]
+    fn paused_at(&self) -> i64 {
+        self.paused_at
+    }
+
+    #[inline(always)]
+    fn set_paused_at(&mut self, paused_at: i64) {
+        self.paused_at = paused_at;
+    }
+
+    #[inline(always)]
+    fn vesting_offset(&self) -> i64 {
+        self.vesting_offset
+    }
+
+    #[inline(always)]
+    fn set_vesting_offset(&mut self, vesting_offset: i64) {
+        self.vesting_offset = vesting_offset;
+    }
+
+    /// Only direct distributions can be settled
+    #[inline(always)]
+    fn is_settled(&self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn claim_start_ts(&self) -> i64 {
+        self.claim_start_ts
+    }
+
+    #[inline(always)]
+    fn claim_end_ts(&self) -> i64 {
+        self.claim_end_ts
+    }
+
+    #[inline(always)]
+    fn set_claim_end_ts(&mut self, claim_end_ts: i64) {
+        self.claim_end_ts = claim_end_ts;
+    }
 }
 
 impl DistributionSigner for MerkleDistribution {
@@ -178,7 +376,7 @@ impl DistributionSigner for MerkleDistribution {
         let pda_seeds = [
             Seed::from(Self::PREFIX),
             Seed::from(self.mint.as_ref()),
-            Seed::from(self.authority.as_ref()),
+            Seed::from(self.seed_authority.as_ref()),
             Seed::from(self.seed.as_ref()),
             Seed::from(bump_seed.as_slice()),
         ];
@@ -203,7 +401,10 @@ impl MerkleDistribution {
         Self {
             bump,
             revocable,
-            _padding: [0u8; 6],
+            transferable: 0,
+            paused: 0,
+            claim_mode: MerkleClaimMode::ClaimAccount {}.to_byte(),
+            _padding: [0u8; 3],
             authority,
             mint,
             seed: seeds,
@@ -211,6 +412,14 @@ impl MerkleDistribution {
             total_amount,
             total_claimed: 0,
             clawback_ts,
+            seed_authority: authority,
+            pending_authority: Address::default(),
+            epoch: 0,
+            total_funded: 0,
+            paused_at: 0,
+            vesting_offset: 0,
+            claim_start_ts: 0,
+            claim_end_ts: 0,
         }
     }
 
@@ -220,6 +429,37 @@ impl MerkleDistribution {
         state.validate_self(account, program_id)?;
         Ok(state)
     }
+
+    #[inline(always)]
+    pub fn claim_mode(&self) -> Result<MerkleClaimMode, ProgramError> {
+        MerkleClaimMode::try_from(self.claim_mode)
+    }
+
+    /// Fails with `ClaimModeMismatch` unless the distribution tracks claims with `mode`
+    #[inline(always)]
+    pub fn validate_claim_mode(&self, mode: MerkleClaimMode) -> Result<(), ProgramError> {
+        if self.claim_mode()? != mode {
+            return Err(RewardsProgramError::ClaimModeMismatch.into());
+        }
+        Ok(())
+    }
+
+    /// Replace the merkle root for a new epoch.
+    ///
+    /// `epoch` must be strictly greater than the current epoch, and `total_amount`
+    /// cannot drop below what has already been claimed.
+    pub fn update_root(&mut self, merkle_root: [u8; 32], total_amount: u64, epoch: u64) -> Result<(), ProgramError> {
+        if epoch <= self.epoch {
+            return Err(RewardsProgramError::InvalidEpoch.into());
+        }
+        if total_amount < self.total_claimed {
+            return Err(RewardsProgramError::InvalidAmount.into());
+        }
+        self.merkle_root = merkle_root;
+        self.total_amount = total_amount;
+        self.epoch = epoch;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -310,6 +550,27 @@ mod tests {
         assert_eq!(deserialized.revocable, 3);
     }
 
+    #[test]
+    fn test_roundtrip_serialization_transferable() {
+        let mut dist = create_test_distribution();
+        dist.set_transferable(true);
+        let bytes = dist.to_bytes();
+        let deserialized = MerkleDistribution::parse_from_bytes(&bytes).unwrap();
+        assert!(deserialized.is_transferable());
+        assert_eq!(deserialized.revocable, dist.revocable);
+    }
+
+    #[test]
+    fn test_backward_compat_old_bytes_parse_as_non_transferable() {
+        let dist = create_test_distribution();
+        let bytes = dist.to_bytes();
+        // Old accounts have 0x00 at the transferable offset (was padding)
+        assert_eq!(bytes[4], 0);
+        let deserialized = MerkleDistribution::parse_from_bytes(&bytes).unwrap();
+        assert!(!deserialized.is_transferable());
+        assert!(deserialized.validate_transferable().is_err());
+    }
+
     #[test]
     fn test_backward_compat_old_bytes_parse_as_non_revocable() {
         let dist = create_test_distribution();
@@ -318,6 +579,34 @@ mod tests {
         assert_eq!(deserialized.revocable, 0);
     }
 
+    #[test]
+    fn test_backward_compat_old_bytes_parse_as_claim_account_mode() {
+        let dist = create_test_distribution();
+        let bytes = dist.to_bytes();
+        // Old accounts have 0x00 at the claim_mode offset (was padding)
+        assert_eq!(bytes[6], 0);
+        let deserialized = MerkleDistribution::parse_from_bytes(&bytes).unwrap();
+        assert!(deserialized.validate_claim_mode(MerkleClaimMode::ClaimAccount {}).is_ok());
+    }
+
+    #[test]
+    fn test_roundtrip_serialization_bitmap_mode() {
+        let mut dist = create_test_distribution();
+        dist.claim_mode = MerkleClaimMode::Bitmap {}.to_byte();
+        let bytes = dist.to_bytes();
+        let deserialized = MerkleDistribution::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.claim_mode().unwrap(), MerkleClaimMode::Bitmap {});
+        assert!(deserialized.validate_claim_mode(MerkleClaimMode::Bitmap {}).is_ok());
+        assert!(deserialized.validate_claim_mode(MerkleClaimMode::ClaimAccount {}).is_err());
+    }
+
+    #[test]
+    fn test_validate_claim_mode_invalid_byte() {
+        let mut dist = create_test_distribution();
+        dist.claim_mode = 7;
+        assert!(dist.claim_mode().is_err());
+    }
+
     #[test]
     fn test_pda_seeds() {
         let dist = create_test_distribution();
@@ -367,4 +656,266 @@ mod tests {
         assert!(Distribution::set_total_claimed(&mut dist, 400).is_err());
         assert_eq!(Distribution::total_claimed(&dist), 500);
     }
+
+    #[test]
+    fn test_new_sets_seed_authority_and_no_pending() {
+        let dist = create_test_distribution();
+        assert_eq!(dist.seed_authority, dist.authority);
+        assert_eq!(dist.pending_authority, Address::default());
+        assert!(!Distribution::has_pending_authority(&dist));
+    }
+
+    #[test]
+    fn test_roundtrip_serialization_with_pending_authority() {
+        let mut dist = create_test_distribution();
+        Distribution::set_pending_authority(&mut dist, Address::new_from_array([9u8; 32]));
+        let bytes = dist.to_bytes();
+        let deserialized = MerkleDistribution::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.seed_authority, dist.seed_authority);
+        assert_eq!(deserialized.pending_authority, Address::new_from_array([9u8; 32]));
+    }
+
+    #[test]
+    fn test_seeds_use_seed_authority_after_transfer() {
+        let mut dist = create_test_distribution();
+        let original_authority = dist.authority;
+        Distribution::set_authority(&mut dist, Address::new_from_array([9u8; 32]));
+        let seeds = dist.seeds();
+        assert_eq!(seeds[2], original_authority.as_ref());
+    }
+
+    #[test]
+    fn test_validate_authority_uses_current_authority() {
+        let mut dist = create_test_distribution();
+        let original_authority = dist.authority;
+        let new_authority = Address::new_from_array([9u8; 32]);
+        Distribution::set_authority(&mut dist, new_authority);
+        assert!(Distribution::validate_authority(&dist, &new_authority).is_ok());
+        assert!(Distribution::validate_authority(&dist, &original_authority).is_err());
+    }
+
+    #[test]
+    fn test_validate_pending_authority() {
+        let mut dist = create_test_distribution();
+        let pending = Address::new_from_array([9u8; 32]);
+        assert_eq!(
+            Distribution::validate_pending_authority(&dist, &pending),
+            Err(RewardsProgramError::NoPendingAuthority.into())
+        );
+
+        Distribution::set_pending_authority(&mut dist, pending);
+        assert!(Distribution::validate_pending_authority(&dist, &pending).is_ok());
+        assert_eq!(
+            Distribution::validate_pending_authority(&dist, &Address::new_from_array([8u8; 32])),
+            Err(RewardsProgramError::UnauthorizedAuthority.into())
+        );
+    }
+
+    #[test]
+    fn test_new_starts_at_epoch_zero() {
+        let dist = create_test_distribution();
+        assert_eq!(dist.epoch, 0);
+    }
+
+    #[test]
+    fn test_roundtrip_serialization_with_epoch() {
+        let mut dist = create_test_distribution();
+        dist.update_root([5u8; 32], 2_000_000, 7).unwrap();
+        let bytes = dist.to_bytes();
+        let deserialized = MerkleDistribution::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.epoch, 7);
+        assert_eq!(deserialized.merkle_root, [5u8; 32]);
+        assert_eq!(deserialized.total_amount, 2_000_000);
+    }
+
+    #[test]
+    fn test_update_root_success() {
+        let mut dist = create_test_distribution();
+        dist.total_claimed = 400;
+        dist.update_root([5u8; 32], 500, 1).unwrap();
+        assert_eq!(dist.merkle_root, [5u8; 32]);
+        assert_eq!(dist.total_amount, 500);
+        assert_eq!(dist.epoch, 1);
+        assert_eq!(dist.total_claimed, 400);
+    }
+
+    #[test]
+    fn test_update_root_rejects_non_increasing_epoch() {
+        let mut dist = create_test_distribution();
+        dist.update_root([5u8; 32], 500, 2).unwrap();
+        assert_eq!(dist.update_root([6u8; 32], 500, 2), Err(RewardsProgramError::InvalidEpoch.into()));
+        assert_eq!(dist.update_root([6u8; 32], 500, 1), Err(RewardsProgramError::InvalidEpoch.into()));
+        assert_eq!(dist.merkle_root, [5u8; 32]);
+    }
+
+    #[test]
+    fn test_update_root_rejects_total_below_claimed() {
+        let mut dist = create_test_distribution();
+        dist.total_claimed = 400;
+        assert_eq!(dist.update_root([5u8; 32], 399, 1), Err(RewardsProgramError::InvalidAmount.into()));
+    }
+
+    #[test]
+    fn test_roundtrip_serialization_with_total_funded() {
+        let mut dist = create_test_distribution();
+        assert_eq!(dist.total_funded, 0);
+        Distribution::add_funded(&mut dist, 1500).unwrap();
+        let bytes = dist.to_bytes();
+        let deserialized = MerkleDistribution::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.total_funded, 1500);
+    }
+
+    #[test]
+    fn test_distribution_add_funded_overflow() {
+        let mut dist = create_test_distribution();
+        Distribution::add_funded(&mut dist, u64::MAX).unwrap();
+        assert_eq!(Distribution::add_funded(&mut dist, 1), Err(RewardsProgramError::MathOverflow.into()));
+        assert_eq!(Distribution::total_funded(&dist), u64::MAX);
+    }
+    #[test]
+    fn test_roundtrip_serialization_paused() {
+        let mut dist = create_test_distribution();
+        Distribution::pause(&mut dist, 1_700_000_000).unwrap();
+        Distribution::unpause(&mut dist, 1_700_000_600, true).unwrap();
+        Distribution::pause(&mut dist, 1_700_001_000).unwrap();
+        let bytes = dist.to_bytes();
+        let deserialized = MerkleDistribution::parse_from_bytes(&bytes).unwrap();
+        assert!(deserialized.is_paused());
+        assert_eq!(deserialized.paused_at, 1_700_001_000);
+        assert_eq!(deserialized.vesting_offset, 600);
+        assert_eq!(deserialized.validate_not_paused(), Err(RewardsProgramError::DistributionPaused.into()));
+    }
+
+    #[test]
+    fn test_roundtrip_serialization_claim_window() {
+        let mut dist = create_test_distribution();
+        dist.claim_start_ts = 1_700_000_000;
+        dist.claim_end_ts = 1_800_000_000;
+        let bytes = dist.to_bytes();
+        let deserialized = MerkleDistribution::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized.claim_start_ts, 1_700_000_000);
+        assert_eq!(deserialized.claim_end_ts, 1_800_000_000);
+        assert_eq!(
+            deserialized.validate_claim_window(1_800_000_000),
+            Err(RewardsProgramError::ClaimWindowClosed.into())
+        );
+    }
+
+    fn legacy_bytes(dist: &MerkleDistribution, version: u8) -> Vec<u8> {
+        // Layouts only ever appended fields, so older versions are prefixes of the current one
+        let mut bytes = dist.to_bytes();
+        bytes[1] = version;
+        bytes.truncate(MerkleDistribution::len_for_version(version).unwrap());
+        bytes
+    }
+
+    fn populated_distribution() -> MerkleDistribution {
+        let mut dist = create_test_distribution();
+        dist.total_claimed = 400;
+        dist.authority = Address::new_from_array([8u8; 32]);
+        dist.pending_authority = Address::new_from_array([9u8; 32]);
+        dist.epoch = 3;
+        dist.total_funded = 5_000_000;
+        dist.paused_at = 1_700_000_000;
+        dist.vesting_offset = 600;
+        dist.claim_start_ts = 1_700_000_000;
+        dist.claim_end_ts = 1_800_000_000;
+        dist
+    }
+
+    #[test]
+    fn test_version_lens_match_current_layout() {
+        assert_eq!(MerkleDistribution::VERSION_LENS.len(), MerkleDistribution::VERSION as usize);
+        assert_eq!(MerkleDistribution::len_for_version(MerkleDistribution::VERSION).unwrap(), MerkleDistribution::LEN);
+    }
+
+    #[test]
+    fn test_parse_v1_layout() {
+        let mut dist = populated_distribution();
+        dist.seed_authority = dist.authority;
+        let parsed = MerkleDistribution::parse_from_bytes(&legacy_bytes(&dist, 1)).unwrap();
+
+        assert_eq!(parsed.authority, dist.authority);
+        assert_eq!(parsed.merkle_root, dist.merkle_root);
+        assert_eq!(parsed.total_amount, 1_000_000);
+        assert_eq!(parsed.total_claimed, 400);
+        assert_eq!(parsed.clawback_ts, dist.clawback_ts);
+        assert_eq!(parsed.seed_authority, dist.authority);
+        assert_eq!(parsed.pending_authority, Address::default());
+        assert_eq!(parsed.epoch, 0);
+        assert_eq!(parsed.total_funded, 1_000_000);
+        assert_eq!(parsed.paused_at, 0);
+        assert_eq!(parsed.vesting_offset, 0);
+        assert_eq!(parsed.claim_start_ts, 0);
+        assert_eq!(parsed.claim_end_ts, 0);
+    }
+
+    #[test]
+    fn test_parse_v2_layout() {
+        let dist = populated_distribution();
+        let parsed = MerkleDistribution::parse_from_bytes(&legacy_bytes(&dist, 2)).unwrap();
+
+        assert_eq!(parsed.seed_authority, dist.seed_authority);
+        assert_eq!(parsed.pending_authority, dist.pending_authority);
+        assert_eq!(parsed.epoch, 0);
+        assert_eq!(parsed.total_funded, 1_000_000);
+    }
+
+    #[test]
+    fn test_parse_v3_layout() {
+        let dist = populated_distribution();
+        let parsed = MerkleDistribution::parse_from_bytes(&legacy_bytes(&dist, 3)).unwrap();
+
+        assert_eq!(parsed.epoch, 3);
+        assert_eq!(parsed.total_funded, 1_000_000);
+        assert_eq!(parsed.paused_at, 0);
+    }
+
+    #[test]
+    fn test_parse_v4_layout() {
+        let dist = populated_distribution();
+        let parsed = MerkleDistribution::parse_from_bytes(&legacy_bytes(&dist, 4)).unwrap();
+
+        assert_eq!(parsed.epoch, 3);
+        assert_eq!(parsed.total_funded, 5_000_000);
+        assert_eq!(parsed.paused_at, 0);
+        assert_eq!(parsed.vesting_offset, 0);
+    }
+
+    #[test]
+    fn test_parse_v5_layout() {
+        let dist = populated_distribution();
+        let parsed = MerkleDistribution::parse_from_bytes(&legacy_bytes(&dist, 5)).unwrap();
+
+        assert_eq!(parsed.total_funded, 5_000_000);
+        assert_eq!(parsed.paused_at, 1_700_000_000);
+        assert_eq!(parsed.vesting_offset, 600);
+        assert_eq!(parsed.claim_start_ts, 0);
+        assert_eq!(parsed.claim_end_ts, 0);
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_version() {
+        let mut bytes = create_test_distribution().to_bytes();
+        bytes[1] = 0;
+        assert_eq!(
+            MerkleDistribution::parse_from_bytes(&bytes),
+            Err(RewardsProgramError::InvalidAccountVersion.into())
+        );
+        bytes[1] = MerkleDistribution::VERSION + 1;
+        assert_eq!(
+            MerkleDistribution::parse_from_bytes(&bytes),
+            Err(RewardsProgramError::InvalidAccountVersion.into())
+        );
+    }
+
+    #[test]
+    fn test_migrated_bytes_use_current_layout() {
+        let parsed = MerkleDistribution::parse_from_bytes(&legacy_bytes(&populated_distribution(), 1)).unwrap();
+        let bytes = parsed.to_bytes();
+
+        assert_eq!(bytes.len(), MerkleDistribution::LEN);
+        assert_eq!(bytes[1], MerkleDistribution::VERSION);
+        assert_eq!(MerkleDistribution::parse_from_bytes(&bytes).unwrap(), parsed);
+    }
 }
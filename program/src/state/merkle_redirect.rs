@@ -0,0 +1,186 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use codama::CodamaAccount;
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address};
+
+use crate::errors::RewardsProgramError;
+use crate::traits::{
+    AccountParse, AccountSerialize, AccountSize, AccountValidation, Discriminator, PdaSeeds,
+    RewardsAccountDiscriminators, Versioned,
+};
+use crate::{require_account_len, validate_discriminator, validate_version};
+
+/// MerkleRedirect account state
+///
+/// Records the wallet a merkle claimant has transferred their position to.
+/// Merkle leaves are fixed to the original claimant address, so the redirect
+/// lets the new owner claim against the original leaf. Its existence blocks
+/// claims signed by the original claimant.
+///
+/// # PDA Seeds
+/// `[b"merkle_redirect", distribution.as_ref(), claimant.as_ref()]`
+#[derive(Clone, Debug, PartialEq, CodamaAccount)]
+pub struct MerkleRedirect {
+    pub bump: u8,
+    pub new_claimant: Address,
+}
+
+impl Discriminator for MerkleRedirect {
+    const DISCRIMINATOR: u8 = RewardsAccountDiscriminators::MerkleRedirect as u8;
+}
+
+impl Versioned for MerkleRedirect {
+    const VERSION: u8 = 1;
+}
+
+impl AccountSize for MerkleRedirect {
+    const DATA_LEN: usize = 1 + 32; // bump + new_claimant
+}
+
+impl AccountParse for MerkleRedirect {
+    fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_account_len!(data, Self::LEN);
+        validate_discriminator!(data, Self::DISCRIMINATOR);
+        validate_version!(data, Self::VERSION);
+
+        // Skip discriminator (byte 0) and version (byte 1)
+        let data = &data[2..];
+
+        let bump = data[0];
+        let new_claimant =
+            Address::new_from_array(data[1..33].try_into().map_err(|_| RewardsProgramError::InvalidAccountData)?);
+
+        Ok(Self { bump, new_claimant })
+    }
+}
+
+impl AccountSerialize for MerkleRedirect {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.push(self.bump);
+        data.extend_from_slice(self.new_claimant.as_ref());
+        data
+    }
+}
+
+impl AccountValidation for MerkleRedirect {}
+
+/// Seed helper for deriving MerkleRedirect PDA without having the full state
+pub struct MerkleRedirectSeeds {
+    pub distribution: Address,
+    pub claimant: Address,
+}
+
+impl PdaSeeds for MerkleRedirectSeeds {
+    const PREFIX: &'static [u8] = b"merkle_redirect";
+
+    #[inline(always)]
+    fn seeds(&self) -> Vec<&[u8]> {
+        vec![Self::PREFIX, self.distribution.as_ref(), self.claimant.as_ref()]
+    }
+
+    #[inline(always)]
+    fn seeds_with_bump<'a>(&'a self, bump: &'a [u8; 1]) -> Vec<Seed<'a>> {
+        vec![
+            Seed::from(Self::PREFIX),
+            Seed::from(self.distribution.as_ref()),
+            Seed::from(self.claimant.as_ref()),
+            Seed::from(bump.as_slice()),
+        ]
+    }
+}
+
+impl MerkleRedirect {
+    #[inline(always)]
+    pub fn new(bump: u8, new_claimant: Address) -> Self {
+        Self { bump, new_claimant }
+    }
+
+    #[inline(always)]
+    pub fn from_account(
+        data: &[u8],
+        account: &AccountView,
+        program_id: &Address,
+        distribution: &Address,
+        claimant: &Address,
+    ) -> Result<Self, ProgramError> {
+        let state = Self::parse_from_bytes(data)?;
+        let seeds = MerkleRedirectSeeds { distribution: *distribution, claimant: *claimant };
+        seeds.validate_pda(account, program_id, state.bump)?;
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_redirect() -> MerkleRedirect {
+        MerkleRedirect::new(255, Address::new_from_array([3u8; 32]))
+    }
+
+    #[test]
+    fn test_merkle_redirect_new() {
+        let redirect = create_test_redirect();
+        assert_eq!(redirect.bump, 255);
+        assert_eq!(redirect.new_claimant, Address::new_from_array([3u8; 32]));
+    }
+
+    #[test]
+    fn test_merkle_redirect_to_bytes_inner() {
+        let redirect = create_test_redirect();
+        let bytes = redirect.to_bytes_inner();
+
+        assert_eq!(bytes.len(), MerkleRedirect::DATA_LEN);
+        assert_eq!(bytes[0], 255); // bump
+        assert_eq!(&bytes[1..33], &[3u8; 32]); // new_claimant
+    }
+
+    #[test]
+    fn test_merkle_redirect_to_bytes() {
+        let redirect = create_test_redirect();
+        let bytes = redirect.to_bytes();
+
+        assert_eq!(bytes.len(), MerkleRedirect::LEN);
+        assert_eq!(bytes[0], MerkleRedirect::DISCRIMINATOR);
+        assert_eq!(bytes[1], MerkleRedirect::VERSION);
+        assert_eq!(bytes[2], 255); // bump
+    }
+
+    #[test]
+    fn test_roundtrip_serialization() {
+        let redirect = create_test_redirect();
+
+        let bytes = redirect.to_bytes();
+        let deserialized = MerkleRedirect::parse_from_bytes(&bytes).unwrap();
+
+        assert_eq!(deserialized, redirect);
+    }
+
+    #[test]
+    fn test_parse_rejects_wrong_discriminator() {
+        let mut bytes = create_test_redirect().to_bytes();
+        bytes[0] = RewardsAccountDiscriminators::MerkleRevocation as u8;
+        assert!(MerkleRedirect::parse_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_merkle_redirect_seeds_pda_seeds() {
+        let seeds = MerkleRedirectSeeds {
+            distribution: Address::new_from_array([1u8; 32]),
+            claimant: Address::new_from_array([2u8; 32]),
+        };
+        let pda_seeds = seeds.seeds();
+        assert_eq!(pda_seeds.len(), 3);
+        assert_eq!(pda_seeds[0], MerkleRedirectSeeds::PREFIX);
+        assert_eq!(pda_seeds[1], seeds.distribution.as_ref());
+        assert_eq!(pda_seeds[2], seeds.claimant.as_ref());
+    }
+
+    #[test]
+    fn test_account_size() {
+        assert_eq!(MerkleRedirect::DATA_LEN, 33);
+        assert_eq!(MerkleRedirect::LEN, 35);
+    }
+}
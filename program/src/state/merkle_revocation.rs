@@ -7,7 +7,7 @@ use crate::traits::{
     AccountParse, AccountSerialize, AccountSize, AccountValidation, Discriminator, PdaSeeds,
     RewardsAccountDiscriminators, Versioned,
 };
-use crate::{require_account_len, validate_discriminator};
+use crate::{require_account_len, validate_discriminator, validate_version};
 
 /// MerkleRevocation account state
 ///
@@ -37,6 +37,7 @@ impl AccountParse for MerkleRevocation {
     fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
         require_account_len!(data, Self::LEN);
         validate_discriminator!(data, Self::DISCRIMINATOR);
+        validate_version!(data, Self::VERSION);
 
         // Skip discriminator (byte 0) and version (byte 1)
         let data = &data[2..];
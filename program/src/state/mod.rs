@@ -1,11 +1,25 @@
+pub mod basket_distribution;
+pub mod basket_recipient;
+pub mod claim_delegate;
+pub mod compressed_distribution;
+pub mod compressed_recipient;
 pub mod direct_distribution;
 pub mod direct_recipient;
 pub mod merkle_claim;
+pub mod merkle_claim_bitmap;
 pub mod merkle_distribution;
+pub mod merkle_redirect;
 pub mod merkle_revocation;
 
+pub use basket_distribution::*;
+pub use basket_recipient::*;
+pub use claim_delegate::*;
+pub use compressed_distribution::*;
+pub use compressed_recipient::*;
 pub use direct_distribution::*;
 pub use direct_recipient::*;
 pub use merkle_claim::*;
+pub use merkle_claim_bitmap::*;
 pub use merkle_distribution::*;
+pub use merkle_redirect::*;
 pub use merkle_revocation::*;
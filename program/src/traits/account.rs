@@ -1,7 +1,8 @@
 use alloc::vec::Vec;
 use pinocchio::{account::AccountView, error::ProgramError, Address};
 
-use crate::{require_len, validate_discriminator};
+use crate::errors::RewardsProgramError;
+use crate::{require_account_len, require_len, validate_discriminator};
 
 pub const ACCOUNT_DISCRIMINATOR_SIZE: usize = 1;
 pub const ACCOUNT_VERSION_SIZE: usize = 1;
@@ -86,6 +87,12 @@ pub enum RewardsAccountDiscriminators {
     MerkleDistribution = 2,
     MerkleClaim = 3,
     MerkleRevocation = 4,
+    ClaimDelegate = 5,
+    MerkleRedirect = 6,
+    BasketDistribution = 7,
+    BasketRecipient = 8,
+    MerkleClaimBitmap = 9,
+    CompressedDistribution = 10,
 }
 
 /// Manual account deserialization (non-zero-copy)
@@ -97,6 +104,33 @@ pub trait AccountParse: AccountSize {
     fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError>;
 }
 
+/// Upgrade path for accounts whose layout has changed since launch.
+///
+/// Layouts only grow, by appending fields or claiming zeroed padding bytes. `parse_from_bytes`
+/// reads every historical version and gives fields the stored version predates their defaults;
+/// `MigrateAccount` then rewrites the account at `VERSION`, and `save_account` does the same
+/// whenever an instruction with a paying signer writes to an older account.
+pub trait Migrate: AccountParse + AccountSerialize {
+    /// Minimum total account length of each layout version, starting at version 1
+    const VERSION_LENS: &'static [usize];
+
+    /// Returns the minimum total account length of a stored layout version
+    #[inline(always)]
+    fn len_for_version(version: u8) -> Result<usize, ProgramError> {
+        let index = (version as usize).checked_sub(1).ok_or(RewardsProgramError::InvalidAccountVersion)?;
+        Self::VERSION_LENS.get(index).copied().ok_or_else(|| RewardsProgramError::InvalidAccountVersion.into())
+    }
+
+    /// Returns the stored version once the data is long enough for that version's layout
+    #[inline(always)]
+    fn stored_version(data: &[u8]) -> Result<u8, ProgramError> {
+        require_account_len!(data, ACCOUNT_HEADER_SIZE);
+        let version = data[ACCOUNT_DISCRIMINATOR_SIZE];
+        require_account_len!(data, Self::len_for_version(version)?);
+        Ok(version)
+    }
+}
+
 /// Account serialization with discriminator and version prefix
 pub trait AccountSerialize: Discriminator + Versioned {
     /// Serialize account data without discriminator/version
@@ -12,9 +12,18 @@ pub trait Distribution: AccountParse + AccountSerialize + PdaAccount {
     /// Returns the mint address for this distribution
     fn mint(&self) -> &Address;
 
-    /// Returns the authority address for this distribution
+    /// Returns the current authority address for this distribution
     fn authority(&self) -> &Address;
 
+    /// Replaces the current authority
+    fn set_authority(&mut self, authority: Address);
+
+    /// Returns the proposed authority (default address when no proposal is pending)
+    fn pending_authority(&self) -> &Address;
+
+    /// Sets the proposed authority (default address clears the proposal)
+    fn set_pending_authority(&mut self, pending_authority: Address);
+
     /// Returns the seeds key used for PDA derivation
     fn seeds_key(&self) -> &Address;
 
@@ -24,7 +33,51 @@ pub trait Distribution: AccountParse + AccountSerialize + PdaAccount {
     /// Sets the total claimed amount
     fn set_total_claimed(&mut self, amount: u64) -> Result<(), ProgramError>;
 
-    /// Validates that the provided authority matches the distribution's authority
+    /// Returns the total amount deposited into the vault for this distribution
+    fn total_funded(&self) -> u64;
+
+    /// Sets the total funded amount
+    fn set_total_funded(&mut self, amount: u64);
+
+    /// Returns true if recipients may transfer their positions to another wallet
+    fn is_transferable(&self) -> bool;
+
+    /// Enables or disables recipient-initiated position transfers
+    fn set_transferable(&mut self, transferable: bool);
+
+    /// Returns true if claims are currently halted
+    fn is_paused(&self) -> bool;
+
+    /// Sets or clears the paused flag
+    fn set_paused(&mut self, paused: bool);
+
+    /// Returns the timestamp of the most recent pause (0 if never paused)
+    fn paused_at(&self) -> i64;
+
+    /// Sets the timestamp of the most recent pause
+    fn set_paused_at(&mut self, paused_at: i64);
+
+    /// Returns the number of seconds subtracted from the clock when computing vesting
+    fn vesting_offset(&self) -> i64;
+
+    /// Sets the vesting offset
+    fn set_vesting_offset(&mut self, vesting_offset: i64);
+
+    /// Returns true once the authority has closed the distribution but recipients are still owed tokens
+    fn is_settled(&self) -> bool;
+
+    /// Returns the timestamp claims open at (0 = open from creation)
+    fn claim_start_ts(&self) -> i64;
+
+    /// Returns the timestamp claims close at (0 = never)
+    fn claim_end_ts(&self) -> i64;
+
+    /// Sets the timestamp claims close at
+    fn set_claim_end_ts(&mut self, claim_end_ts: i64);
+
+    /// Validates that the provided authority matches the distribution's current authority.
+    ///
+    /// This checks the stored authority, not the authority used as a PDA seed.
     #[inline(always)]
     fn validate_authority(&self, authority: &Address) -> Result<(), ProgramError> {
         if self.authority() != authority {
@@ -33,6 +86,132 @@ pub trait Distribution: AccountParse + AccountSerialize + PdaAccount {
         Ok(())
     }
 
+    /// Returns true if an authority transfer has been proposed
+    #[inline(always)]
+    fn has_pending_authority(&self) -> bool {
+        self.pending_authority() != &Address::default()
+    }
+
+    /// Validates that the provided address is the proposed authority
+    #[inline(always)]
+    fn validate_pending_authority(&self, pending_authority: &Address) -> Result<(), ProgramError> {
+        if !self.has_pending_authority() {
+            return Err(RewardsProgramError::NoPendingAuthority.into());
+        }
+        if self.pending_authority() != pending_authority {
+            return Err(RewardsProgramError::UnauthorizedAuthority.into());
+        }
+        Ok(())
+    }
+
+    /// Validates that recipients are allowed to transfer their positions
+    #[inline(always)]
+    fn validate_transferable(&self) -> Result<(), ProgramError> {
+        if !self.is_transferable() {
+            return Err(RewardsProgramError::RecipientTransfersDisabled.into());
+        }
+        Ok(())
+    }
+
+    /// Validates that claims are not halted
+    #[inline(always)]
+    fn validate_not_paused(&self) -> Result<(), ProgramError> {
+        if self.is_paused() {
+            return Err(RewardsProgramError::DistributionPaused.into());
+        }
+        Ok(())
+    }
+
+    /// Validates that the authority has not closed the distribution into its claim-only state.
+    ///
+    /// A settled distribution only serves claims for what is already allocated.
+    #[inline(always)]
+    fn validate_not_settled(&self) -> Result<(), ProgramError> {
+        if self.is_settled() {
+            return Err(RewardsProgramError::DistributionSettled.into());
+        }
+        Ok(())
+    }
+
+    /// Returns true once `claim_end_ts` has passed and recipients can no longer claim
+    #[inline(always)]
+    fn is_claim_window_closed(&self, current_ts: i64) -> bool {
+        self.claim_end_ts() != 0 && current_ts >= self.claim_end_ts()
+    }
+
+    /// Validates that `current_ts` falls inside the claim window
+    #[inline(always)]
+    fn validate_claim_window(&self, current_ts: i64) -> Result<(), ProgramError> {
+        if current_ts < self.claim_start_ts() {
+            return Err(RewardsProgramError::ClaimWindowNotOpen.into());
+        }
+        if self.is_claim_window_closed(current_ts) {
+            return Err(RewardsProgramError::ClaimWindowClosed.into());
+        }
+        Ok(())
+    }
+
+    /// Validates that a schedule ending at `vesting_end_ts` fully vests before the claim window closes.
+    ///
+    /// Includes the pause shifts accumulated since the recipient's `base_vesting_offset`.
+    #[inline(always)]
+    fn validate_vesting_end(&self, vesting_end_ts: i64, base_vesting_offset: i64) -> Result<(), ProgramError> {
+        if self.claim_end_ts() == 0 {
+            return Ok(());
+        }
+        let shift = self.vesting_offset().saturating_sub(base_vesting_offset).max(0);
+        let shifted_end_ts = vesting_end_ts.checked_add(shift).ok_or(RewardsProgramError::MathOverflow)?;
+        if shifted_end_ts > self.claim_end_ts() {
+            return Err(RewardsProgramError::InvalidTimeWindow.into());
+        }
+        Ok(())
+    }
+
+    /// Halts claims as of `current_ts`
+    #[inline(always)]
+    fn pause(&mut self, current_ts: i64) -> Result<(), ProgramError> {
+        self.validate_not_paused()?;
+        self.set_paused(true);
+        self.set_paused_at(current_ts);
+        Ok(())
+    }
+
+    /// Resumes claims as of `current_ts`.
+    ///
+    /// When `shift_vesting` is set, the paused period is added to the vesting offset so
+    /// schedules resume where they left off. Returns the number of seconds added.
+    ///
+    /// A claim window is extended by the same shift: schedules may end right at `claim_end_ts`,
+    /// and delaying them alone would leave tokens vesting after claims close.
+    #[inline(always)]
+    fn unpause(&mut self, current_ts: i64, shift_vesting: bool) -> Result<i64, ProgramError> {
+        if !self.is_paused() {
+            return Err(RewardsProgramError::DistributionNotPaused.into());
+        }
+
+        let shift = if shift_vesting { current_ts.saturating_sub(self.paused_at()).max(0) } else { 0 };
+        let vesting_offset = self.vesting_offset().checked_add(shift).ok_or(RewardsProgramError::MathOverflow)?;
+        if self.claim_end_ts() != 0 {
+            let claim_end_ts = self.claim_end_ts().checked_add(shift).ok_or(RewardsProgramError::MathOverflow)?;
+            self.set_claim_end_ts(claim_end_ts);
+        }
+
+        self.set_vesting_offset(vesting_offset);
+        self.set_paused(false);
+        Ok(shift)
+    }
+
+    /// Returns the timestamp a recipient's vesting schedule should be evaluated at.
+    ///
+    /// Vesting is frozen at `paused_at` while paused, and shifted back by the vesting offset
+    /// accumulated since the recipient was added (`base_vesting_offset`), so a recipient is
+    /// never delayed by a pause that ended before it existed.
+    #[inline(always)]
+    fn vesting_timestamp(&self, current_ts: i64, base_vesting_offset: i64) -> i64 {
+        let ts = if self.is_paused() { current_ts.min(self.paused_at()) } else { current_ts };
+        ts.saturating_sub(self.vesting_offset().saturating_sub(base_vesting_offset).max(0))
+    }
+
     /// Adds to the total claimed amount with overflow checking
     #[inline(always)]
     fn add_claimed(&mut self, amount: u64) -> Result<(), ProgramError> {
@@ -40,13 +219,22 @@ pub trait Distribution: AccountParse + AccountSerialize + PdaAccount {
         self.set_total_claimed(new_total)?;
         Ok(())
     }
+
+    /// Adds to the total funded amount with overflow checking
+    #[inline(always)]
+    fn add_funded(&mut self, amount: u64) -> Result<(), ProgramError> {
+        let new_total = self.total_funded().checked_add(amount).ok_or(RewardsProgramError::MathOverflow)?;
+        self.set_total_funded(new_total);
+        Ok(())
+    }
 }
 
-/// Extension trait for distributions that can sign CPIs.
+/// Trait for distribution accounts that can sign CPIs.
 ///
 /// Distributions are PDAs that can sign cross-program invocations
-/// (e.g., token transfers from the distribution vault).
-pub trait DistributionSigner: Distribution {
+/// (e.g., token transfers from the distribution vault). Basket distributions
+/// sign for several vaults but are not single-mint `Distribution`s.
+pub trait DistributionSigner {
     /// Executes a closure with the distribution's PDA signer seeds.
     fn with_signer<F, R>(&self, f: F) -> R
     where
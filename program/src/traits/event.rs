@@ -14,6 +14,21 @@ pub enum EventDiscriminators {
     RecipientAdded = 3,
     ClaimClosed = 4,
     RecipientRevoked = 5,
+    AuthorityTransferred = 6,
+    MerkleRootUpdated = 7,
+    DistributionFunded = 8,
+    ClaimDelegateUpdated = 9,
+    RecipientTransferred = 10,
+    DistributionPaused = 11,
+    DistributionUnpaused = 12,
+    BasketDistributionCreated = 13,
+    BasketRecipientAdded = 14,
+    BasketClaimed = 15,
+    BasketDistributionClosed = 16,
+    CompressedRecipientAdded = 17,
+    CompressedClaimed = 18,
+    DistributionSettled = 19,
+    AllocationChanged = 20,
 }
 
 /// Event discriminator with Anchor-compatible prefix
@@ -20,6 +20,62 @@ pub enum RewardsInstructionDiscriminators {
     RevokeDirectRecipient = 9,
     RevokeMerkleClaim = 10,
 
+    // Authority
+    ProposeAuthority = 11,
+    AcceptAuthority = 12,
+    CancelAuthorityProposal = 13,
+
+    // Merkle Root
+    UpdateMerkleRoot = 14,
+
+    // Funding
+    FundDistribution = 15,
+
+    // Batch
+    AddDirectRecipients = 16,
+
+    // Claim Delegate
+    SetClaimDelegate = 17,
+    RemoveClaimDelegate = 18,
+    CrankClaimDirect = 19,
+    CrankClaimMerkle = 20,
+
+    // Position Transfer
+    SetTransferable = 21,
+    TransferDirectRecipient = 22,
+    TransferMerkleClaim = 23,
+
+    // Pause
+    PauseDistribution = 24,
+    UnpauseDistribution = 25,
+
+    // Batch Claim
+    ClaimDirectMany = 26,
+
+    // Basket Distribution
+    CreateBasketDistribution = 27,
+    AddBasketRecipient = 28,
+    ClaimBasket = 29,
+    CloseBasketDistribution = 30,
+
+    // Merkle Bitmap Claims
+    CreateMerkleClaimBitmap = 31,
+    ClaimMerkleBitmap = 32,
+    CloseMerkleClaimBitmap = 33,
+
+    // Compressed Distribution
+    CreateCompressedDistribution = 34,
+    AddCompressedRecipient = 35,
+    ClaimCompressed = 36,
+    CloseCompressedDistribution = 37,
+
+    // Migration
+    MigrateAccount = 38,
+    IncreaseDirectAllocation = 39,
+    DecreaseDirectAllocation = 40,
+    CloseBasketRecipient = 41,
+    CloseMerkleClaimEarly = 42,
+
     // Shared
     EmitEvent = 228,
 }
@@ -43,6 +99,50 @@ impl TryFrom<u8> for RewardsInstructionDiscriminators {
             // Revoke
             9 => Ok(Self::RevokeDirectRecipient),
             10 => Ok(Self::RevokeMerkleClaim),
+            // Authority
+            11 => Ok(Self::ProposeAuthority),
+            12 => Ok(Self::AcceptAuthority),
+            13 => Ok(Self::CancelAuthorityProposal),
+            // Merkle Root
+            14 => Ok(Self::UpdateMerkleRoot),
+            // Funding
+            15 => Ok(Self::FundDistribution),
+            // Batch
+            16 => Ok(Self::AddDirectRecipients),
+            // Claim Delegate
+            17 => Ok(Self::SetClaimDelegate),
+            18 => Ok(Self::RemoveClaimDelegate),
+            19 => Ok(Self::CrankClaimDirect),
+            20 => Ok(Self::CrankClaimMerkle),
+            // Position Transfer
+            21 => Ok(Self::SetTransferable),
+            22 => Ok(Self::TransferDirectRecipient),
+            23 => Ok(Self::TransferMerkleClaim),
+            // Pause
+            24 => Ok(Self::PauseDistribution),
+            25 => Ok(Self::UnpauseDistribution),
+            // Batch Claim
+            26 => Ok(Self::ClaimDirectMany),
+            // Basket Distribution
+            27 => Ok(Self::CreateBasketDistribution),
+            28 => Ok(Self::AddBasketRecipient),
+            29 => Ok(Self::ClaimBasket),
+            30 => Ok(Self::CloseBasketDistribution),
+            // Merkle Bitmap Claims
+            31 => Ok(Self::CreateMerkleClaimBitmap),
+            32 => Ok(Self::ClaimMerkleBitmap),
+            33 => Ok(Self::CloseMerkleClaimBitmap),
+            // Compressed Distribution
+            34 => Ok(Self::CreateCompressedDistribution),
+            35 => Ok(Self::AddCompressedRecipient),
+            36 => Ok(Self::ClaimCompressed),
+            37 => Ok(Self::CloseCompressedDistribution),
+            // Migration
+            38 => Ok(Self::MigrateAccount),
+            39 => Ok(Self::IncreaseDirectAllocation),
+            40 => Ok(Self::DecreaseDirectAllocation),
+            41 => Ok(Self::CloseBasketRecipient),
+            42 => Ok(Self::CloseMerkleClaimEarly),
             // Shared
             228 => Ok(Self::EmitEvent),
             _ => Err(ProgramError::InvalidInstructionData),
@@ -170,8 +270,226 @@ mod tests {
     }
 
     #[test]
-    fn test_discriminator_try_from_invalid() {
+    fn test_discriminator_try_from_authority_instructions() {
         let result = RewardsInstructionDiscriminators::try_from(11u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::ProposeAuthority));
+
+        let result = RewardsInstructionDiscriminators::try_from(12u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::AcceptAuthority));
+
+        let result = RewardsInstructionDiscriminators::try_from(13u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::CancelAuthorityProposal));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_update_merkle_root() {
+        let result = RewardsInstructionDiscriminators::try_from(14u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::UpdateMerkleRoot));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_fund_distribution() {
+        let result = RewardsInstructionDiscriminators::try_from(15u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::FundDistribution));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_add_direct_recipients() {
+        let result = RewardsInstructionDiscriminators::try_from(16u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::AddDirectRecipients));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_set_claim_delegate() {
+        let result = RewardsInstructionDiscriminators::try_from(17u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::SetClaimDelegate));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_remove_claim_delegate() {
+        let result = RewardsInstructionDiscriminators::try_from(18u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::RemoveClaimDelegate));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_crank_claim_direct() {
+        let result = RewardsInstructionDiscriminators::try_from(19u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::CrankClaimDirect));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_crank_claim_merkle() {
+        let result = RewardsInstructionDiscriminators::try_from(20u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::CrankClaimMerkle));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_set_transferable() {
+        let result = RewardsInstructionDiscriminators::try_from(21u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::SetTransferable));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_transfer_direct_recipient() {
+        let result = RewardsInstructionDiscriminators::try_from(22u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::TransferDirectRecipient));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_transfer_merkle_claim() {
+        let result = RewardsInstructionDiscriminators::try_from(23u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::TransferMerkleClaim));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_pause_distribution() {
+        let result = RewardsInstructionDiscriminators::try_from(24u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::PauseDistribution));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_unpause_distribution() {
+        let result = RewardsInstructionDiscriminators::try_from(25u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::UnpauseDistribution));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_claim_direct_many() {
+        let result = RewardsInstructionDiscriminators::try_from(26u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::ClaimDirectMany));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_create_basket_distribution() {
+        let result = RewardsInstructionDiscriminators::try_from(27u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::CreateBasketDistribution));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_add_basket_recipient() {
+        let result = RewardsInstructionDiscriminators::try_from(28u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::AddBasketRecipient));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_claim_basket() {
+        let result = RewardsInstructionDiscriminators::try_from(29u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::ClaimBasket));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_close_basket_distribution() {
+        let result = RewardsInstructionDiscriminators::try_from(30u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::CloseBasketDistribution));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_create_merkle_claim_bitmap() {
+        let result = RewardsInstructionDiscriminators::try_from(31u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::CreateMerkleClaimBitmap));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_claim_merkle_bitmap() {
+        let result = RewardsInstructionDiscriminators::try_from(32u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::ClaimMerkleBitmap));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_close_merkle_claim_bitmap() {
+        let result = RewardsInstructionDiscriminators::try_from(33u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::CloseMerkleClaimBitmap));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_create_compressed_distribution() {
+        let result = RewardsInstructionDiscriminators::try_from(34u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::CreateCompressedDistribution));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_add_compressed_recipient() {
+        let result = RewardsInstructionDiscriminators::try_from(35u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::AddCompressedRecipient));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_claim_compressed() {
+        let result = RewardsInstructionDiscriminators::try_from(36u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::ClaimCompressed));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_close_compressed_distribution() {
+        let result = RewardsInstructionDiscriminators::try_from(37u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::CloseCompressedDistribution));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_migrate_account() {
+        let result = RewardsInstructionDiscriminators::try_from(38u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::MigrateAccount));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_increase_direct_allocation() {
+        let result = RewardsInstructionDiscriminators::try_from(39u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::IncreaseDirectAllocation));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_decrease_direct_allocation() {
+        let result = RewardsInstructionDiscriminators::try_from(40u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::DecreaseDirectAllocation));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_close_basket_recipient() {
+        let result = RewardsInstructionDiscriminators::try_from(41u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::CloseBasketRecipient));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_close_merkle_claim_early() {
+        let result = RewardsInstructionDiscriminators::try_from(42u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), RewardsInstructionDiscriminators::CloseMerkleClaimEarly));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_invalid() {
+        let result = RewardsInstructionDiscriminators::try_from(43u8);
         assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
 
         let result = RewardsInstructionDiscriminators::try_from(255u8);
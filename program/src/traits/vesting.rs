@@ -1,6 +1,7 @@
+use alloc::vec;
 use pinocchio::error::ProgramError;
 
-use crate::utils::VestingSchedule;
+use crate::{errors::RewardsProgramError, utils::VestingSchedule};
 
 /// Interface for types that provide vesting schedule parameters.
 ///
@@ -12,12 +13,70 @@ pub trait VestingParams {
     fn total_amount(&self) -> u64;
 
     /// The vesting schedule for this allocation
-    fn vesting_schedule(&self) -> VestingSchedule;
+    fn vesting_schedule(&self) -> &VestingSchedule;
 
-    /// Calculates the unlocked amount at the given timestamp based on schedule
+    /// Amount the schedule's curve vests over. Once an allocation is decreased this
+    /// exceeds `total_amount`, so the curve keeps its shape and the tail is trimmed.
+    #[inline(always)]
+    fn schedule_amount(&self) -> u64 {
+        self.total_amount()
+    }
+
+    /// Vesting timestamp the allocation was frozen at (0 = never frozen)
+    #[inline(always)]
+    fn revoked_at(&self) -> i64 {
+        0
+    }
+
+    /// Distribution vesting offset when the allocation was added. Only pause shifts
+    /// accumulated after it delay the schedule (0 = every shift since creation).
+    #[inline(always)]
+    fn base_vesting_offset(&self) -> i64 {
+        0
+    }
+
+    /// Calculates the unlocked amount at the given timestamp based on schedule,
+    /// capped at `revoked_at` once the allocation is frozen and at `total_amount`
     #[inline(always)]
     fn calculate_unlocked(&self, current_ts: i64) -> Result<u64, ProgramError> {
-        self.vesting_schedule().calculate_unlocked(self.total_amount(), current_ts)
+        let revoked_at = self.revoked_at();
+        let vesting_ts = if revoked_at != 0 { current_ts.min(revoked_at) } else { current_ts };
+        let unlocked = self.vesting_schedule().calculate_unlocked(self.schedule_amount(), vesting_ts)?;
+        Ok(unlocked.min(self.total_amount()))
+    }
+
+    /// Errors unless this allocation has unlocked at least as much as `previous` at every
+    /// vesting timestamp from `from_ts` on, so a new schedule never delays granted tokens.
+    fn validate_unlocks_no_later(&self, previous: &impl VestingParams, from_ts: i64) -> Result<(), ProgramError> {
+        let mut points = vec![from_ts];
+        self.vesting_schedule().unlock_points(from_ts, &mut points)?;
+        previous.vesting_schedule().unlock_points(from_ts, &mut points)?;
+
+        // A decreased allocation stops at `total_amount` part way along a sloped segment
+        let (mut low, mut high) = (from_ts, previous.vesting_schedule().end_ts()?);
+        if previous.schedule_amount() > previous.total_amount()
+            && high > low
+            && previous.calculate_unlocked(low)? < previous.total_amount()
+        {
+            while high - low > 1 {
+                let mid = low + (high - low) / 2;
+                if previous.calculate_unlocked(mid)? < previous.total_amount() {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            points.push(high);
+        }
+
+        for point in points {
+            for ts in [point.saturating_sub(1).max(from_ts), point] {
+                if self.calculate_unlocked(ts)? < previous.calculate_unlocked(ts)? {
+                    return Err(RewardsProgramError::ScheduleDelaysUnlocks.into());
+                }
+            }
+        }
+        Ok(())
     }
 }
 
@@ -35,8 +94,8 @@ mod tests {
             self.total_amount
         }
 
-        fn vesting_schedule(&self) -> VestingSchedule {
-            self.schedule
+        fn vesting_schedule(&self) -> &VestingSchedule {
+            &self.schedule
         }
     }
 
@@ -138,4 +197,136 @@ mod tests {
         };
         assert_eq!(vesting.calculate_unlocked(400).unwrap(), 1000);
     }
+
+    struct FrozenVesting {
+        total_amount: u64,
+        schedule: VestingSchedule,
+        revoked_at: i64,
+    }
+
+    impl VestingParams for FrozenVesting {
+        fn total_amount(&self) -> u64 {
+            self.total_amount
+        }
+
+        fn vesting_schedule(&self) -> &VestingSchedule {
+            &self.schedule
+        }
+
+        fn revoked_at(&self) -> i64 {
+            self.revoked_at
+        }
+    }
+
+    #[test]
+    fn test_calculate_unlocked_frozen_caps_at_revoked_at() {
+        let vesting = FrozenVesting {
+            total_amount: 1000,
+            schedule: VestingSchedule::Linear { start_ts: 100, end_ts: 200 },
+            revoked_at: 150,
+        };
+        assert_eq!(vesting.calculate_unlocked(120).unwrap(), 200);
+        assert_eq!(vesting.calculate_unlocked(150).unwrap(), 500);
+        assert_eq!(vesting.calculate_unlocked(250).unwrap(), 500);
+    }
+
+    struct TrimmedVesting {
+        total_amount: u64,
+        schedule_amount: u64,
+        schedule: VestingSchedule,
+    }
+
+    impl VestingParams for TrimmedVesting {
+        fn total_amount(&self) -> u64 {
+            self.total_amount
+        }
+
+        fn schedule_amount(&self) -> u64 {
+            self.schedule_amount
+        }
+
+        fn vesting_schedule(&self) -> &VestingSchedule {
+            &self.schedule
+        }
+    }
+
+    #[test]
+    fn test_calculate_unlocked_trimmed_caps_at_total() {
+        let vesting = TrimmedVesting {
+            total_amount: 600,
+            schedule_amount: 1000,
+            schedule: VestingSchedule::Linear { start_ts: 100, end_ts: 200 },
+        };
+        assert_eq!(vesting.calculate_unlocked(150).unwrap(), 500);
+        assert_eq!(vesting.calculate_unlocked(160).unwrap(), 600);
+        assert_eq!(vesting.calculate_unlocked(250).unwrap(), 600);
+    }
+
+    #[test]
+    fn test_validate_unlocks_no_later_accepts_faster_schedule() {
+        let previous =
+            MockVesting { total_amount: 1000, schedule: VestingSchedule::Linear { start_ts: 100, end_ts: 200 } };
+        let updated =
+            MockVesting { total_amount: 1500, schedule: VestingSchedule::Linear { start_ts: 100, end_ts: 150 } };
+        assert!(updated.validate_unlocks_no_later(&previous, 120).is_ok());
+    }
+
+    #[test]
+    fn test_validate_unlocks_no_later_rejects_longer_schedule() {
+        let previous =
+            MockVesting { total_amount: 1000, schedule: VestingSchedule::Linear { start_ts: 100, end_ts: 200 } };
+        let updated =
+            MockVesting { total_amount: 1500, schedule: VestingSchedule::Linear { start_ts: 100, end_ts: 400 } };
+        let err = updated.validate_unlocks_no_later(&previous, 120).unwrap_err();
+        assert_eq!(err, RewardsProgramError::ScheduleDelaysUnlocks.into());
+    }
+
+    #[test]
+    fn test_validate_unlocks_no_later_checks_second_before_step() {
+        // The cliff is level or ahead at every breakpoint; only the second before it shows the delay
+        let previous =
+            MockVesting { total_amount: 1000, schedule: VestingSchedule::Linear { start_ts: 100, end_ts: 200 } };
+        let updated = MockVesting { total_amount: 2000, schedule: VestingSchedule::Cliff { cliff_ts: 200 } };
+        let err = updated.validate_unlocks_no_later(&previous, 100).unwrap_err();
+        assert_eq!(err, RewardsProgramError::ScheduleDelaysUnlocks.into());
+
+        let stepped = MockVesting {
+            total_amount: 1000,
+            schedule: VestingSchedule::Stepped { start_ts: 100, period_secs: 50, num_periods: 2 },
+        };
+        let cliff = MockVesting { total_amount: 2000, schedule: VestingSchedule::Cliff { cliff_ts: 150 } };
+        assert!(cliff.validate_unlocks_no_later(&stepped, 100).is_ok());
+    }
+
+    #[test]
+    fn test_validate_unlocks_no_later_checks_trimmed_cap() {
+        // The trimmed curve reaches its 600 cap at 160, where the replacement only has 480
+        let previous = TrimmedVesting {
+            total_amount: 600,
+            schedule_amount: 1000,
+            schedule: VestingSchedule::Linear { start_ts: 100, end_ts: 200 },
+        };
+        let updated =
+            MockVesting { total_amount: 800, schedule: VestingSchedule::Linear { start_ts: 100, end_ts: 200 } };
+        let err = updated.validate_unlocks_no_later(&previous, 100).unwrap_err();
+        assert_eq!(err, RewardsProgramError::ScheduleDelaysUnlocks.into());
+
+        let faster =
+            MockVesting { total_amount: 800, schedule: VestingSchedule::Linear { start_ts: 100, end_ts: 160 } };
+        assert!(faster.validate_unlocks_no_later(&previous, 100).is_ok());
+    }
+
+    #[test]
+    fn test_validate_unlocks_no_later_too_many_steps() {
+        let previous = MockVesting {
+            total_amount: 1000,
+            schedule: VestingSchedule::Stepped { start_ts: 0, period_secs: 1, num_periods: 1000 },
+        };
+        let updated = MockVesting { total_amount: 1500, schedule: VestingSchedule::Immediate {} };
+        let err = updated.validate_unlocks_no_later(&previous, 10).unwrap_err();
+        assert_eq!(err, RewardsProgramError::TooManyScheduleSteps.into());
+
+        // Only the periods still ahead of `from_ts` count
+        assert!(updated.validate_unlocks_no_later(&previous, 990).is_ok());
+    }
 }
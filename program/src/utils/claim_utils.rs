@@ -1,9 +1,17 @@
 use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
 use pinocchio_token_2022::instructions::TransferChecked;
 
-use crate::{errors::RewardsProgramError, traits::DistributionSigner};
+use crate::{
+    errors::RewardsProgramError,
+    state::{ClaimDelegate, ClaimDelegateSeeds},
+    traits::{DistributionSigner, PdaSeeds},
+    ID,
+};
 
-use super::get_mint_decimals;
+use super::{
+    get_mint_decimals, is_native_token_program, is_pda_uninitialized, validate_associated_token_account_address,
+    withdraw_from_native_vault, TransferFeeMode,
+};
 
 /// Context for claim transfer operations.
 ///
@@ -42,6 +50,8 @@ pub fn resolve_claim_amount(requested: u64, claimable: u64) -> Result<u64, Progr
 
 /// Transfers tokens from the distribution vault to recipient using the distribution as signer.
 ///
+/// Native SOL vaults pay lamports directly to `ctx.destination`.
+///
 /// # Arguments
 /// * `distribution` - The distribution that owns the vault (implements DistributionSigner)
 /// * `ctx` - Transfer context containing all required accounts
@@ -52,6 +62,10 @@ pub fn transfer_from_distribution_vault<D: DistributionSigner>(
     ctx: &ClaimTransferContext,
     amount: u64,
 ) -> ProgramResult {
+    if is_native_token_program(ctx.token_program) {
+        return withdraw_from_native_vault(ctx.distribution_vault, ctx.destination, amount);
+    }
+
     let decimals = get_mint_decimals(ctx.mint)?;
 
     distribution.with_signer(|signers| {
@@ -68,6 +82,75 @@ pub fn transfer_from_distribution_vault<D: DistributionSigner>(
     })
 }
 
+/// Returns true if `cranker` may direct a cranked claim: the recipient or their claim delegate.
+///
+/// # Arguments
+/// * `cranker` - The signer submitting the claim
+/// * `recipient` - The recipient (direct) or claimant (merkle) whose tokens are claimed
+/// * `distribution` - The distribution being claimed from
+/// * `claim_delegate_account` - PDA: `[b"claim_delegate", distribution, recipient]`; may be uninitialized
+pub fn is_recipient_or_delegate(
+    cranker: &Address,
+    recipient: &Address,
+    distribution: &Address,
+    claim_delegate_account: &AccountView,
+) -> Result<bool, ProgramError> {
+    if cranker == recipient {
+        return Ok(true);
+    }
+
+    let delegate_seeds = ClaimDelegateSeeds { distribution: *distribution, recipient: *recipient };
+    delegate_seeds.validate_pda_address(claim_delegate_account, &ID)?;
+
+    if is_pda_uninitialized(claim_delegate_account) {
+        return Ok(false);
+    }
+
+    let delegate_data = claim_delegate_account.try_borrow()?;
+    let claim_delegate =
+        ClaimDelegate::from_account(&delegate_data, claim_delegate_account, &ID, distribution, recipient)?;
+    drop(delegate_data);
+
+    Ok(&claim_delegate.delegate == cranker)
+}
+
+/// Verifies a claim cranked by someone other than the recipient or their claim delegate.
+///
+/// Such a cranker can only claim everything claimable in `Gross` mode, so repeated small
+/// claims cannot hand the allocation to rounded-up transfer fees. The tokens must go to
+/// the recipient's associated token account for `mint`, or the recipient's wallet for
+/// native SOL distributions.
+///
+/// # Arguments
+/// * `recipient` - The recipient (direct) or claimant (merkle) whose tokens are claimed
+/// * `destination` - Token account receiving the claimed tokens
+/// * `mint` - The distribution mint
+/// * `token_program` - The token program for `mint`
+/// * `amount` - Requested claim amount (0 = everything claimable)
+/// * `fee_mode` - Requested transfer fee mode
+pub fn verify_permissionless_crank(
+    recipient: &Address,
+    destination: &AccountView,
+    mint: &AccountView,
+    token_program: &AccountView,
+    amount: u64,
+    fee_mode: TransferFeeMode,
+) -> ProgramResult {
+    if amount != 0 || !matches!(fee_mode, TransferFeeMode::Gross {}) {
+        return Err(RewardsProgramError::CrankAmountRestricted.into());
+    }
+
+    if is_native_token_program(token_program.address()) {
+        if destination.address() != recipient {
+            return Err(RewardsProgramError::InvalidClaimDestination.into());
+        }
+        return Ok(());
+    }
+
+    validate_associated_token_account_address(destination, recipient, mint, token_program)
+        .map_err(|_| RewardsProgramError::InvalidClaimDestination.into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,487 @@
+use core::mem::size_of;
+
+use pinocchio::error::ProgramError;
+
+use crate::{assert_no_padding, errors::RewardsProgramError, utils::merkle_utils::keccak256};
+
+/// Depth of a compressed distribution's recipient tree (65,536 leaves)
+pub const COMPRESSED_TREE_DEPTH: usize = 16;
+
+/// Number of recent roots a proof may be built against
+pub const COMPRESSED_TREE_BUFFER_SIZE: usize = 8;
+
+/// Maximum number of leaves a compressed distribution's tree can hold
+pub const COMPRESSED_TREE_CAPACITY: u32 = 1 << COMPRESSED_TREE_DEPTH;
+
+/// Value of a leaf that has never been appended
+pub const EMPTY_LEAF: [u8; 32] = [0u8; 32];
+
+/// Hash two sibling nodes in positional order (left first).
+///
+/// Unlike `hash_pair`, the order is not sorted: a leaf's position is part of the
+/// tree, which lets the changelog fast-forward proofs by index.
+#[inline(always)]
+pub fn hash_tree_nodes(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut data = [0u8; 64];
+    data[0..32].copy_from_slice(left);
+    data[32..64].copy_from_slice(right);
+    keccak256(&data)
+}
+
+/// Level at which the paths of two leaves meet, i.e. the proof level of `a` that holds a node of `b`'s path.
+#[inline(always)]
+fn critbit(a: u32, b: u32) -> usize {
+    (31 - (a ^ b).leading_zeros()) as usize
+}
+
+/// One change to the tree: the root after the change and the new path from leaf to root.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct ChangeLogEntry {
+    root: [u8; 32],
+    path: [[u8; 32]; COMPRESSED_TREE_DEPTH],
+    index: [u8; 4],
+}
+
+assert_no_padding!(ChangeLogEntry, 32 + 32 * COMPRESSED_TREE_DEPTH + 4);
+
+const CHANGE_LOGS_LEN: usize = COMPRESSED_TREE_BUFFER_SIZE * size_of::<ChangeLogEntry>();
+
+impl ChangeLogEntry {
+    #[inline(always)]
+    fn index(&self) -> u32 {
+        u32::from_le_bytes(self.index)
+    }
+}
+
+/// Concurrent merkle tree stored zero-copy in account data.
+///
+/// Appends only need the rightmost proof kept in the account, so new leaves never
+/// require a proof. Leaf replacements take a proof against any of the last
+/// `COMPRESSED_TREE_BUFFER_SIZE` roots; the proof is fast-forwarded through the
+/// newer changelog entries so concurrent updates to different leaves do not
+/// invalidate each other.
+///
+/// All fields are byte arrays so the struct has alignment 1 and can be cast from any offset.
+#[repr(C)]
+pub struct ConcurrentMerkleTree {
+    sequence_number: [u8; 8],
+    active_index: [u8; 4],
+    buffer_size: [u8; 4],
+    rightmost_index: [u8; 4],
+    rightmost_leaf: [u8; 32],
+    rightmost_proof: [[u8; 32]; COMPRESSED_TREE_DEPTH],
+    change_logs: [ChangeLogEntry; COMPRESSED_TREE_BUFFER_SIZE],
+}
+
+assert_no_padding!(ConcurrentMerkleTree, 8 + 4 + 4 + 4 + 32 + 32 * COMPRESSED_TREE_DEPTH + CHANGE_LOGS_LEN);
+
+impl ConcurrentMerkleTree {
+    pub const LEN: usize = size_of::<Self>();
+
+    /// Zero-copy read over the start of `data`
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<&Self, ProgramError> {
+        if data.len() < Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // SAFETY: length checked above; all fields are byte arrays so alignment is 1
+        Ok(unsafe { &*(data.as_ptr() as *const Self) })
+    }
+
+    /// Mutable zero-copy access over the start of `data`
+    #[inline(always)]
+    pub fn from_bytes_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if data.len() < Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // SAFETY: length checked above; all fields are byte arrays so alignment is 1
+        Ok(unsafe { &mut *(data.as_mut_ptr() as *mut Self) })
+    }
+
+    /// Set up an empty tree; fails if the tree already holds a root.
+    pub fn initialize(&mut self) -> Result<(), ProgramError> {
+        if self.buffer_size() != 0 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        let mut path = [EMPTY_LEAF; COMPRESSED_TREE_DEPTH];
+        let mut node = EMPTY_LEAF;
+        for level in path.iter_mut() {
+            *level = node;
+            node = hash_tree_nodes(&node, &node);
+        }
+
+        self.rightmost_proof = path;
+        self.change_logs[0] = ChangeLogEntry { root: node, path, index: [0u8; 4] };
+        self.buffer_size = 1u32.to_le_bytes();
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn sequence_number(&self) -> u64 {
+        u64::from_le_bytes(self.sequence_number)
+    }
+
+    #[inline(always)]
+    fn active_index(&self) -> usize {
+        u32::from_le_bytes(self.active_index) as usize
+    }
+
+    #[inline(always)]
+    fn buffer_size(&self) -> usize {
+        u32::from_le_bytes(self.buffer_size) as usize
+    }
+
+    /// Number of leaves appended so far
+    #[inline(always)]
+    pub fn num_leaves(&self) -> u32 {
+        u32::from_le_bytes(self.rightmost_index)
+    }
+
+    /// Current root
+    #[inline(always)]
+    pub fn root(&self) -> [u8; 32] {
+        self.change_logs[self.active_index()].root
+    }
+
+    /// Append a leaf and return its index.
+    pub fn append(&mut self, leaf: &[u8; 32]) -> Result<u32, ProgramError> {
+        let index = self.num_leaves();
+        if index >= COMPRESSED_TREE_CAPACITY {
+            return Err(RewardsProgramError::CompressedTreeFull.into());
+        }
+
+        let mut path = [EMPTY_LEAF; COMPRESSED_TREE_DEPTH];
+        let mut node = *leaf;
+
+        if index == 0 {
+            // Every sibling of the first leaf is an empty subtree
+            for (level, sibling) in self.rightmost_proof.iter().enumerate() {
+                path[level] = node;
+                node = hash_tree_nodes(&node, sibling);
+            }
+        } else {
+            // Below the intersection the new leaf's siblings are empty subtrees, and the
+            // previous rightmost leaf's subtree is rebuilt to become the sibling at the intersection.
+            let intersection = index.trailing_zeros() as usize;
+            let mut intersection_node = self.rightmost_leaf;
+            let mut empty = EMPTY_LEAF;
+
+            for (level, path_node) in path.iter_mut().enumerate() {
+                *path_node = node;
+                if level < intersection {
+                    intersection_node = hash_tree_nodes(&self.rightmost_proof[level], &intersection_node);
+                    node = hash_tree_nodes(&node, &empty);
+                    self.rightmost_proof[level] = empty;
+                    empty = hash_tree_nodes(&empty, &empty);
+                } else if level == intersection {
+                    node = hash_tree_nodes(&intersection_node, &node);
+                    self.rightmost_proof[level] = intersection_node;
+                } else if (index >> level) & 1 == 0 {
+                    node = hash_tree_nodes(&node, &self.rightmost_proof[level]);
+                } else {
+                    node = hash_tree_nodes(&self.rightmost_proof[level], &node);
+                }
+            }
+        }
+
+        self.push_change_log(node, path, index);
+        self.rightmost_leaf = *leaf;
+        self.rightmost_index = (index + 1).to_le_bytes();
+        Ok(index)
+    }
+
+    /// Replace the leaf at `index`, proving `previous_leaf` against `root`.
+    ///
+    /// `root` may be any root still in the changelog; the proof is fast-forwarded through
+    /// every later change. Fails with `StaleCompressedProof` if `root` has been rotated out
+    /// or the same leaf was changed since, and with `InvalidMerkleProof` if the proof is wrong.
+    pub fn replace_leaf(
+        &mut self,
+        root: &[u8; 32],
+        previous_leaf: &[u8; 32],
+        new_leaf: &[u8; 32],
+        proof: &[[u8; 32]],
+        index: u32,
+    ) -> Result<(), ProgramError> {
+        let num_leaves = self.num_leaves();
+        if index >= num_leaves {
+            return Err(RewardsProgramError::InvalidMerkleProof.into());
+        }
+        let mut proof: [[u8; 32]; COMPRESSED_TREE_DEPTH] =
+            proof.try_into().map_err(|_| RewardsProgramError::InvalidMerkleProof)?;
+
+        let active = self.active_index();
+        let entry_at = |age: usize| (active + COMPRESSED_TREE_BUFFER_SIZE - age) % COMPRESSED_TREE_BUFFER_SIZE;
+
+        let age = (0..self.buffer_size())
+            .find(|age| self.change_logs[entry_at(*age)].root == *root)
+            .ok_or(RewardsProgramError::StaleCompressedProof)?;
+
+        // Replay every change made after `root`, oldest first
+        for newer in (0..age).rev() {
+            let entry = &self.change_logs[entry_at(newer)];
+            let changed = entry.index();
+            if changed == index {
+                return Err(RewardsProgramError::StaleCompressedProof.into());
+            }
+            let level = critbit(index, changed);
+            proof[level] = entry.path[level];
+        }
+
+        let (_, current_root) = Self::compute_path(previous_leaf, &proof, index);
+        if current_root != self.root() {
+            return Err(RewardsProgramError::InvalidMerkleProof.into());
+        }
+
+        let (path, new_root) = Self::compute_path(new_leaf, &proof, index);
+        self.push_change_log(new_root, path, index);
+
+        let last = num_leaves - 1;
+        if index == last {
+            self.rightmost_leaf = *new_leaf;
+        } else {
+            let level = critbit(index, last);
+            self.rightmost_proof[level] = path[level];
+        }
+        Ok(())
+    }
+
+    /// Path from `leaf` up to (excluding) the root, and the root itself
+    fn compute_path(
+        leaf: &[u8; 32],
+        proof: &[[u8; 32]; COMPRESSED_TREE_DEPTH],
+        index: u32,
+    ) -> ([[u8; 32]; COMPRESSED_TREE_DEPTH], [u8; 32]) {
+        let mut path = [EMPTY_LEAF; COMPRESSED_TREE_DEPTH];
+        let mut node = *leaf;
+        for (level, sibling) in proof.iter().enumerate() {
+            path[level] = node;
+            node = if (index >> level) & 1 == 0 {
+                hash_tree_nodes(&node, sibling)
+            } else {
+                hash_tree_nodes(sibling, &node)
+            };
+        }
+        (path, node)
+    }
+
+    fn push_change_log(&mut self, root: [u8; 32], path: [[u8; 32]; COMPRESSED_TREE_DEPTH], index: u32) {
+        let active = (self.active_index() + 1) % COMPRESSED_TREE_BUFFER_SIZE;
+        self.change_logs[active] = ChangeLogEntry { root, path, index: index.to_le_bytes() };
+        self.active_index = (active as u32).to_le_bytes();
+        let buffer_size = (self.buffer_size() + 1).min(COMPRESSED_TREE_BUFFER_SIZE);
+        self.buffer_size = (buffer_size as u32).to_le_bytes();
+        self.sequence_number = (self.sequence_number() + 1).to_le_bytes();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use super::*;
+
+    fn leaf(n: u8) -> [u8; 32] {
+        [n; 32]
+    }
+
+    fn new_tree() -> Vec<u8> {
+        let mut data = vec![0u8; ConcurrentMerkleTree::LEN];
+        ConcurrentMerkleTree::from_bytes_mut(&mut data).unwrap().initialize().unwrap();
+        data
+    }
+
+    /// Every level of a naive tree over `leaves`, padded with empty subtrees
+    fn naive_levels(leaves: &[[u8; 32]]) -> Vec<Vec<[u8; 32]>> {
+        let mut levels = vec![leaves.to_vec()];
+        let mut empty = EMPTY_LEAF;
+        for _ in 0..COMPRESSED_TREE_DEPTH {
+            let current = levels.last().unwrap();
+            let next: Vec<[u8; 32]> =
+                current.chunks(2).map(|pair| hash_tree_nodes(&pair[0], pair.get(1).unwrap_or(&empty))).collect();
+            levels.push(if next.is_empty() { vec![hash_tree_nodes(&empty, &empty)] } else { next });
+            empty = hash_tree_nodes(&empty, &empty);
+        }
+        levels
+    }
+
+    fn naive_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+        naive_levels(leaves).last().unwrap()[0]
+    }
+
+    fn naive_proof(leaves: &[[u8; 32]], index: usize) -> Vec<[u8; 32]> {
+        let levels = naive_levels(leaves);
+        let mut empty = EMPTY_LEAF;
+        let mut proof = Vec::new();
+        for (level, nodes) in levels.iter().take(COMPRESSED_TREE_DEPTH).enumerate() {
+            proof.push(*nodes.get((index >> level) ^ 1).unwrap_or(&empty));
+            empty = hash_tree_nodes(&empty, &empty);
+        }
+        proof
+    }
+
+    #[test]
+    fn test_initialize_empty_root() {
+        let data = new_tree();
+        let tree = ConcurrentMerkleTree::from_bytes(&data).unwrap();
+        assert_eq!(tree.root(), naive_root(&[]));
+        assert_eq!(tree.num_leaves(), 0);
+        assert_eq!(tree.sequence_number(), 0);
+    }
+
+    #[test]
+    fn test_initialize_twice_fails() {
+        let mut data = new_tree();
+        let result = ConcurrentMerkleTree::from_bytes_mut(&mut data).unwrap().initialize();
+        assert_eq!(result.err(), Some(ProgramError::AccountAlreadyInitialized));
+    }
+
+    #[test]
+    fn test_from_bytes_too_short() {
+        let data = vec![0u8; ConcurrentMerkleTree::LEN - 1];
+        assert!(ConcurrentMerkleTree::from_bytes(&data).is_err());
+    }
+
+    #[test]
+    fn test_append_matches_naive_root() {
+        let mut data = new_tree();
+        let tree = ConcurrentMerkleTree::from_bytes_mut(&mut data).unwrap();
+        let mut leaves = Vec::new();
+
+        for n in 1..=17u8 {
+            let index = tree.append(&leaf(n)).unwrap();
+            leaves.push(leaf(n));
+            assert_eq!(index as usize, leaves.len() - 1);
+            assert_eq!(tree.root(), naive_root(&leaves));
+        }
+        assert_eq!(tree.num_leaves(), 17);
+        assert_eq!(tree.sequence_number(), 17);
+    }
+
+    #[test]
+    fn test_replace_leaf_with_current_root() {
+        let mut data = new_tree();
+        let tree = ConcurrentMerkleTree::from_bytes_mut(&mut data).unwrap();
+        let mut leaves: Vec<[u8; 32]> = (1..=5u8).map(leaf).collect();
+        for l in &leaves {
+            tree.append(l).unwrap();
+        }
+
+        let proof = naive_proof(&leaves, 2);
+        tree.replace_leaf(&tree.root(), &leaves[2], &leaf(42), &proof, 2).unwrap();
+        leaves[2] = leaf(42);
+
+        assert_eq!(tree.root(), naive_root(&leaves));
+    }
+
+    #[test]
+    fn test_replace_leaf_fast_forwards_stale_proof() {
+        let mut data = new_tree();
+        let tree = ConcurrentMerkleTree::from_bytes_mut(&mut data).unwrap();
+        let mut leaves: Vec<[u8; 32]> = (1..=6u8).map(leaf).collect();
+        for l in &leaves {
+            tree.append(l).unwrap();
+        }
+
+        let root = tree.root();
+        let proof_1 = naive_proof(&leaves, 1);
+        let proof_4 = naive_proof(&leaves, 4);
+
+        tree.replace_leaf(&root, &leaves[1], &leaf(50), &proof_1, 1).unwrap();
+        leaves[1] = leaf(50);
+        tree.append(&leaf(7)).unwrap();
+        leaves.push(leaf(7));
+
+        // Proof and root are from before both changes
+        tree.replace_leaf(&root, &leaves[4], &leaf(60), &proof_4, 4).unwrap();
+        leaves[4] = leaf(60);
+
+        assert_eq!(tree.root(), naive_root(&leaves));
+    }
+
+    #[test]
+    fn test_replace_same_leaf_twice_with_old_root_fails() {
+        let mut data = new_tree();
+        let tree = ConcurrentMerkleTree::from_bytes_mut(&mut data).unwrap();
+        let leaves: Vec<[u8; 32]> = (1..=4u8).map(leaf).collect();
+        for l in &leaves {
+            tree.append(l).unwrap();
+        }
+
+        let root = tree.root();
+        let proof = naive_proof(&leaves, 3);
+        tree.replace_leaf(&root, &leaves[3], &leaf(9), &proof, 3).unwrap();
+
+        let result = tree.replace_leaf(&root, &leaves[3], &leaf(10), &proof, 3);
+        assert_eq!(result.err(), Some(RewardsProgramError::StaleCompressedProof.into()));
+    }
+
+    #[test]
+    fn test_replace_leaf_root_rotated_out_fails() {
+        let mut data = new_tree();
+        let tree = ConcurrentMerkleTree::from_bytes_mut(&mut data).unwrap();
+        let mut leaves = vec![leaf(1)];
+        tree.append(&leaf(1)).unwrap();
+
+        let root = tree.root();
+        let proof = naive_proof(&leaves, 0);
+        for n in 2..=(COMPRESSED_TREE_BUFFER_SIZE as u8 + 1) {
+            tree.append(&leaf(n)).unwrap();
+            leaves.push(leaf(n));
+        }
+
+        let result = tree.replace_leaf(&root, &leaves[0], &leaf(99), &proof, 0);
+        assert_eq!(result.err(), Some(RewardsProgramError::StaleCompressedProof.into()));
+    }
+
+    #[test]
+    fn test_replace_leaf_wrong_previous_leaf_fails() {
+        let mut data = new_tree();
+        let tree = ConcurrentMerkleTree::from_bytes_mut(&mut data).unwrap();
+        let leaves: Vec<[u8; 32]> = (1..=3u8).map(leaf).collect();
+        for l in &leaves {
+            tree.append(l).unwrap();
+        }
+
+        let proof = naive_proof(&leaves, 1);
+        let result = tree.replace_leaf(&tree.root(), &leaf(77), &leaf(9), &proof, 1);
+        assert_eq!(result.err(), Some(RewardsProgramError::InvalidMerkleProof.into()));
+    }
+
+    #[test]
+    fn test_replace_leaf_out_of_range_fails() {
+        let mut data = new_tree();
+        let tree = ConcurrentMerkleTree::from_bytes_mut(&mut data).unwrap();
+        tree.append(&leaf(1)).unwrap();
+
+        let proof = naive_proof(&[leaf(1)], 1);
+        let result = tree.replace_leaf(&tree.root(), &EMPTY_LEAF, &leaf(9), &proof, 1);
+        assert_eq!(result.err(), Some(RewardsProgramError::InvalidMerkleProof.into()));
+    }
+
+    #[test]
+    fn test_append_after_replacing_keeps_rightmost_proof() {
+        let mut data = new_tree();
+        let tree = ConcurrentMerkleTree::from_bytes_mut(&mut data).unwrap();
+        let mut leaves: Vec<[u8; 32]> = (1..=3u8).map(leaf).collect();
+        for l in &leaves {
+            tree.append(l).unwrap();
+        }
+
+        // Replace both an inner leaf and the rightmost leaf, then keep appending
+        let proof = naive_proof(&leaves, 0);
+        tree.replace_leaf(&tree.root(), &leaves[0], &leaf(30), &proof, 0).unwrap();
+        leaves[0] = leaf(30);
+        let proof = naive_proof(&leaves, 2);
+        tree.replace_leaf(&tree.root(), &leaves[2], &leaf(31), &proof, 2).unwrap();
+        leaves[2] = leaf(31);
+
+        for n in 4..=9u8 {
+            tree.append(&leaf(n)).unwrap();
+            leaves.push(leaf(n));
+            assert_eq!(tree.root(), naive_root(&leaves));
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use codama::CodamaType;
+use pinocchio::{account::AccountView, error::ProgramError, ProgramResult};
+
+use crate::{
+    errors::RewardsProgramError,
+    state::{CompressedDistribution, DirectDistribution, MerkleDistribution},
+    traits::{Discriminator, Distribution},
+    ID,
+};
+
+use super::save_account;
+
+/// Distribution account types handled by instructions that accept either kind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DistributionKind {
+    Direct,
+    Merkle,
+    Compressed,
+}
+
+impl TryFrom<u8> for DistributionKind {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(discriminator: u8) -> Result<Self, Self::Error> {
+        match discriminator {
+            DirectDistribution::DISCRIMINATOR => Ok(Self::Direct),
+            MerkleDistribution::DISCRIMINATOR => Ok(Self::Merkle),
+            CompressedDistribution::DISCRIMINATOR => Ok(Self::Compressed),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// What closing a direct distribution does with tokens still owed to recipients.
+///
+/// - `Sweep`: the whole vault goes back to the authority and the distribution is closed.
+/// - `ProtectRecipients`: only the unallocated surplus goes back. While allocations are
+///   still unclaimed the distribution stays open as a settled, claim-only account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, CodamaType)]
+pub enum ClosePolicy {
+    Sweep {},
+    ProtectRecipients {},
+}
+
+impl TryFrom<u8> for ClosePolicy {
+    type Error = ProgramError;
+
+    fn try_from(byte: u8) -> Result<Self, ProgramError> {
+        match byte {
+            0 => Ok(ClosePolicy::Sweep {}),
+            1 => Ok(ClosePolicy::ProtectRecipients {}),
+            _ => Err(RewardsProgramError::InvalidClosePolicy.into()),
+        }
+    }
+}
+
+impl ClosePolicy {
+    pub fn to_byte(&self) -> u8 {
+        match self {
+            ClosePolicy::Sweep {} => 0,
+            ClosePolicy::ProtectRecipients {} => 1,
+        }
+    }
+}
+
+/// Validate a distribution's claim window against its clawback timestamp.
+///
+/// Zero leaves a bound open. When both are set, claims must open before they close,
+/// and the authority may not claw back before the window closes.
+///
+/// # Arguments
+/// * `claim_start_ts` - Timestamp claims open at
+/// * `claim_end_ts` - Timestamp claims close at
+/// * `clawback_ts` - Timestamp after which the authority may close the distribution
+///
+/// # Returns
+/// * `Result<(), ProgramError>` - `InvalidTimeWindow` if the timestamps are inconsistent
+#[inline(always)]
+pub fn validate_claim_window_config(
+    claim_start_ts: i64,
+    claim_end_ts: i64,
+    clawback_ts: i64,
+) -> Result<(), ProgramError> {
+    if claim_start_ts < 0 || claim_end_ts < 0 {
+        return Err(RewardsProgramError::InvalidTimeWindow.into());
+    }
+    if claim_end_ts != 0 && (claim_start_ts >= claim_end_ts || (clawback_ts != 0 && clawback_ts < claim_end_ts)) {
+        return Err(RewardsProgramError::InvalidTimeWindow.into());
+    }
+    Ok(())
+}
+
+/// Determine which distribution type an account holds from its discriminator.
+///
+/// # Arguments
+/// * `account` - The distribution account (must already be verified as owned by this program).
+///
+/// # Returns
+/// * `Result<DistributionKind, ProgramError>` - The distribution kind
+#[inline(always)]
+pub fn get_distribution_kind(account: &AccountView) -> Result<DistributionKind, ProgramError> {
+    let data = account.try_borrow()?;
+    let discriminator = *data.first().ok_or(ProgramError::InvalidAccountData)?;
+    DistributionKind::try_from(discriminator)
+}
+
+/// Load and validate a distribution of type `D` from an account.
+///
+/// # Arguments
+/// * `account` - The distribution account
+///
+/// # Returns
+/// * `Result<D, ProgramError>` - The parsed distribution, with its PDA verified
+#[inline(always)]
+pub fn load_distribution<D: Distribution>(account: &AccountView) -> Result<D, ProgramError> {
+    let data = account.try_borrow()?;
+    let distribution = D::parse_from_bytes(&data)?;
+    drop(data);
+    distribution.validate_self(account, &ID)?;
+    Ok(distribution)
+}
+
+/// Write a distribution of type `D` back to its account.
+///
+/// # Arguments
+/// * `account` - The distribution account
+/// * `distribution` - The updated distribution state
+///
+/// # Returns
+/// * `ProgramResult` - The result of the operation
+#[inline(always)]
+pub fn save_distribution<D: Distribution>(account: &AccountView, distribution: &D) -> ProgramResult {
+    save_account(account, distribution, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distribution_kind_direct() {
+        let kind = DistributionKind::try_from(DirectDistribution::DISCRIMINATOR).unwrap();
+        assert_eq!(kind, DistributionKind::Direct);
+    }
+
+    #[test]
+    fn test_distribution_kind_merkle() {
+        let kind = DistributionKind::try_from(MerkleDistribution::DISCRIMINATOR).unwrap();
+        assert_eq!(kind, DistributionKind::Merkle);
+    }
+
+    #[test]
+    fn test_distribution_kind_compressed() {
+        let kind = DistributionKind::try_from(CompressedDistribution::DISCRIMINATOR).unwrap();
+        assert_eq!(kind, DistributionKind::Compressed);
+    }
+
+    #[test]
+    fn test_close_policy_roundtrip() {
+        for policy in [ClosePolicy::Sweep {}, ClosePolicy::ProtectRecipients {}] {
+            assert_eq!(ClosePolicy::try_from(policy.to_byte()).unwrap(), policy);
+        }
+    }
+
+    #[test]
+    fn test_close_policy_invalid() {
+        let result = ClosePolicy::try_from(2);
+        assert_eq!(result.err(), Some(RewardsProgramError::InvalidClosePolicy.into()));
+    }
+
+    #[test]
+    fn test_distribution_kind_invalid() {
+        let result = DistributionKind::try_from(99u8);
+        assert_eq!(result, Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_validate_claim_window_config() {
+        assert!(validate_claim_window_config(0, 0, 0).is_ok());
+        assert!(validate_claim_window_config(100, 0, 0).is_ok());
+        assert!(validate_claim_window_config(100, 200, 0).is_ok());
+        assert!(validate_claim_window_config(100, 200, 200).is_ok());
+        assert!(validate_claim_window_config(0, 200, 300).is_ok());
+    }
+
+    #[test]
+    fn test_validate_claim_window_config_invalid() {
+        let invalid = Err(RewardsProgramError::InvalidTimeWindow.into());
+        assert_eq!(validate_claim_window_config(200, 200, 0), invalid);
+        assert_eq!(validate_claim_window_config(300, 200, 0), invalid);
+        assert_eq!(validate_claim_window_config(100, 200, 150), invalid);
+        assert_eq!(validate_claim_window_config(-1, 0, 0), invalid);
+    }
+}
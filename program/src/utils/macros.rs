@@ -49,6 +49,23 @@ macro_rules! validate_discriminator {
     };
 }
 
+/// Validate the version byte of an account that has only ever had one layout.
+///
+/// # Arguments
+/// * `data` - The account's data to validate.
+/// * `version` - The expected version.
+///
+/// # Returns
+/// * `Result<(), ProgramError>` - The result of the operation
+#[macro_export]
+macro_rules! validate_version {
+    ($data:expr, $version:expr) => {
+        if $data.len() < 2 || $data[1] != $version {
+            return Err($crate::errors::RewardsProgramError::InvalidAccountVersion.into());
+        }
+    };
+}
+
 /// Compile-time assertion that a struct has no implicit padding.
 /// Use this for zero-copy structs to ensure memory layout matches serialized format.
 ///
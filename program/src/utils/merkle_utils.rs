@@ -1,16 +1,57 @@
+use codama::CodamaType;
 use const_crypto::sha3::Keccak256;
 use pinocchio::{error::ProgramError, Address};
 
-use crate::errors::RewardsProgramError;
+use crate::{errors::RewardsProgramError, utils::VestingSchedule};
 
 /// Leaf prefix to prevent second preimage attacks.
 pub const LEAF_PREFIX: &[u8] = &[0];
 
 /// Maximum byte length of a leaf's inner hash input:
-/// 32 (claimant) + 8 (total_amount) + 25 (max schedule = CliffLinear)
-const MAX_LEAF_DATA_LEN: usize = 65;
+/// 32 (claimant) + 8 (total_amount) + max schedule (Tranches with MAX_TRANCHE_POINTS points)
+const MAX_LEAF_DATA_LEN: usize = 32 + 8 + VestingSchedule::MAX_BYTE_LEN;
 
-fn keccak256(data: &[u8]) -> [u8; 32] {
+/// Maximum byte length of a compressed recipient leaf's inner hash input:
+/// 32 (recipient) + 8 (total_amount) + 8 (claimed_amount) + max schedule
+const MAX_COMPRESSED_LEAF_DATA_LEN: usize = 32 + 8 + 8 + 8 + VestingSchedule::MAX_BYTE_LEN;
+
+/// Byte length of a bitmap leaf's inner hash input: 32 (claimant) + 4 (index) + 8 (amount)
+const BITMAP_LEAF_DATA_LEN: usize = 32 + 4 + 8;
+
+/// How a merkle distribution records what each leaf has claimed.
+///
+/// - `ClaimAccount`: one `MerkleClaim` PDA per claimant; leaves carry a vesting schedule
+///   and can be claimed in parts.
+/// - `Bitmap`: leaves carry an index instead of a schedule, vest immediately and are
+///   claimed in full by flipping their bit in a `MerkleClaimBitmap` chunk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, CodamaType)]
+pub enum MerkleClaimMode {
+    ClaimAccount {},
+    Bitmap {},
+}
+
+impl TryFrom<u8> for MerkleClaimMode {
+    type Error = ProgramError;
+
+    fn try_from(byte: u8) -> Result<Self, ProgramError> {
+        match byte {
+            0 => Ok(MerkleClaimMode::ClaimAccount {}),
+            1 => Ok(MerkleClaimMode::Bitmap {}),
+            _ => Err(RewardsProgramError::InvalidClaimMode.into()),
+        }
+    }
+}
+
+impl MerkleClaimMode {
+    pub fn to_byte(&self) -> u8 {
+        match self {
+            MerkleClaimMode::ClaimAccount {} => 0,
+            MerkleClaimMode::Bitmap {} => 1,
+        }
+    }
+}
+
+pub(crate) fn keccak256(data: &[u8]) -> [u8; 32] {
     Keccak256::new().update(data).finalize()
 }
 
@@ -36,6 +77,54 @@ pub fn compute_leaf_hash(claimant: &Address, total_amount: u64, schedule_bytes:
     keccak256(&outer_data)
 }
 
+/// Compute the merkle leaf hash for a bitmap-mode claim.
+///
+/// The leaf format is:
+/// `hash(LEAF_PREFIX || hash(claimant || index || amount))`
+pub fn compute_bitmap_leaf_hash(claimant: &Address, index: u32, amount: u64) -> [u8; 32] {
+    let mut inner_data = [0u8; BITMAP_LEAF_DATA_LEN];
+    inner_data[0..32].copy_from_slice(claimant.as_ref());
+    inner_data[32..36].copy_from_slice(&index.to_le_bytes());
+    inner_data[36..44].copy_from_slice(&amount.to_le_bytes());
+
+    let inner_hash = keccak256(&inner_data);
+
+    let mut outer_data = [0u8; 1 + 32];
+    outer_data[0..1].copy_from_slice(LEAF_PREFIX);
+    outer_data[1..33].copy_from_slice(&inner_hash);
+
+    keccak256(&outer_data)
+}
+
+/// Compute the leaf hash of a recipient in a compressed distribution.
+///
+/// The leaf format is:
+/// `hash(LEAF_PREFIX || hash(recipient || total_amount || claimed_amount || base_vesting_offset || schedule_bytes))`
+pub fn compute_compressed_leaf_hash(
+    recipient: &Address,
+    total_amount: u64,
+    claimed_amount: u64,
+    base_vesting_offset: i64,
+    schedule_bytes: &[u8],
+) -> [u8; 32] {
+    let schedule_len = schedule_bytes.len();
+    let inner_len = 32 + 8 + 8 + 8 + schedule_len;
+    let mut inner_data = [0u8; MAX_COMPRESSED_LEAF_DATA_LEN];
+    inner_data[0..32].copy_from_slice(recipient.as_ref());
+    inner_data[32..40].copy_from_slice(&total_amount.to_le_bytes());
+    inner_data[40..48].copy_from_slice(&claimed_amount.to_le_bytes());
+    inner_data[48..56].copy_from_slice(&base_vesting_offset.to_le_bytes());
+    inner_data[56..56 + schedule_len].copy_from_slice(schedule_bytes);
+
+    let inner_hash = keccak256(&inner_data[..inner_len]);
+
+    let mut outer_data = [0u8; 1 + 32];
+    outer_data[0..1].copy_from_slice(LEAF_PREFIX);
+    outer_data[1..33].copy_from_slice(&inner_hash);
+
+    keccak256(&outer_data)
+}
+
 /// Verify a merkle proof against a root.
 ///
 /// The proof is an array of sibling hashes from leaf to root.
@@ -54,7 +143,7 @@ pub fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: &[u8; 32]) -> boo
 
 /// Hash two nodes together in sorted order (smaller first).
 /// This ensures deterministic tree construction regardless of proof ordering.
-fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
     let mut data = [0u8; 64];
     if a < b {
         data[0..32].copy_from_slice(a);
@@ -80,7 +169,7 @@ mod tests {
     use alloc::{vec, vec::Vec};
 
     use super::*;
-    use crate::utils::VestingSchedule;
+    use crate::utils::{TrancheInterpolation, TranchePoint, MAX_TRANCHE_POINTS, TRANCHE_FULL_BPS};
 
     fn schedule_bytes(schedule: VestingSchedule) -> Vec<u8> {
         schedule.to_bytes()
@@ -120,6 +209,21 @@ mod tests {
         assert_ne!(hash1, hash2);
     }
 
+    #[test]
+    fn test_compute_leaf_hash_max_schedule_len() {
+        let claimant = Address::new_from_array([1u8; 32]);
+        let points = (1..=MAX_TRANCHE_POINTS as u16)
+            .map(|i| TranchePoint { timestamp: i as i64 * 100, cumulative_bps: i * (TRANCHE_FULL_BPS / 16) })
+            .collect();
+        let sb = schedule_bytes(VestingSchedule::Tranches { interpolation: TrancheInterpolation::Step {}, points });
+        assert_eq!(sb.len(), VestingSchedule::MAX_BYTE_LEN);
+
+        let hash1 = compute_leaf_hash(&claimant, 1000, &sb);
+        let hash2 = compute_leaf_hash(&claimant, 1000, &schedule_bytes(VestingSchedule::Immediate {}));
+
+        assert_ne!(hash1, hash2);
+    }
+
     #[test]
     fn test_compute_leaf_hash_different_timestamps() {
         let claimant = Address::new_from_array([1u8; 32]);
@@ -144,6 +248,61 @@ mod tests {
         assert_ne!(hash1, hash2);
     }
 
+    #[test]
+    fn test_compute_bitmap_leaf_hash_different_indexes() {
+        let claimant = Address::new_from_array([1u8; 32]);
+
+        let hash1 = compute_bitmap_leaf_hash(&claimant, 0, 1000);
+        let hash2 = compute_bitmap_leaf_hash(&claimant, 1, 1000);
+
+        assert_ne!(hash1, hash2);
+        assert_eq!(hash1, compute_bitmap_leaf_hash(&claimant, 0, 1000));
+    }
+
+    #[test]
+    fn test_compute_bitmap_leaf_hash_differs_from_schedule_leaf() {
+        let claimant = Address::new_from_array([1u8; 32]);
+        let sb = schedule_bytes(VestingSchedule::Immediate {});
+
+        assert_ne!(compute_bitmap_leaf_hash(&claimant, 0, 1000), compute_leaf_hash(&claimant, 1000, &sb));
+    }
+
+    #[test]
+    fn test_compute_compressed_leaf_hash_claimed_amount_changes_hash() {
+        let recipient = Address::new_from_array([1u8; 32]);
+        let schedule = schedule_bytes(VestingSchedule::Immediate {});
+
+        let unclaimed = compute_compressed_leaf_hash(&recipient, 1000, 0, 0, &schedule);
+        let claimed = compute_compressed_leaf_hash(&recipient, 1000, 400, 0, &schedule);
+
+        assert_ne!(unclaimed, claimed);
+        assert_ne!(unclaimed, compute_leaf_hash(&recipient, 1000, &schedule));
+    }
+
+    #[test]
+    fn test_compute_compressed_leaf_hash_base_vesting_offset_changes_hash() {
+        let recipient = Address::new_from_array([1u8; 32]);
+        let schedule = schedule_bytes(VestingSchedule::Immediate {});
+
+        assert_ne!(
+            compute_compressed_leaf_hash(&recipient, 1000, 0, 0, &schedule),
+            compute_compressed_leaf_hash(&recipient, 1000, 0, 600, &schedule)
+        );
+    }
+
+    #[test]
+    fn test_merkle_claim_mode_roundtrip() {
+        for mode in [MerkleClaimMode::ClaimAccount {}, MerkleClaimMode::Bitmap {}] {
+            assert_eq!(MerkleClaimMode::try_from(mode.to_byte()).unwrap(), mode);
+        }
+    }
+
+    #[test]
+    fn test_merkle_claim_mode_invalid() {
+        let result = MerkleClaimMode::try_from(2);
+        assert_eq!(result.err(), Some(ProgramError::Custom(RewardsProgramError::InvalidClaimMode as u32)));
+    }
+
     #[test]
     fn test_hash_pair_commutative() {
         let a = [1u8; 32];
@@ -1,5 +1,7 @@
 pub mod account_utils;
 pub mod claim_utils;
+pub mod concurrent_merkle_tree;
+pub mod distribution_utils;
 pub mod event_utils;
 pub mod macros;
 pub mod merkle_utils;
@@ -7,16 +9,22 @@ pub mod pda_utils;
 pub mod program_utils;
 pub mod revoke_utils;
 pub mod time_utils;
+pub mod token_extension_utils;
 pub mod token_utils;
+pub mod vault_utils;
 pub mod vesting_utils;
 
 pub use account_utils::*;
 pub use claim_utils::*;
+pub use concurrent_merkle_tree::*;
+pub use distribution_utils::*;
 pub use event_utils::*;
 pub use merkle_utils::*;
 pub use pda_utils::*;
 pub use program_utils::*;
 pub use revoke_utils::*;
 pub use time_utils::*;
+pub use token_extension_utils::*;
 pub use token_utils::*;
+pub use vault_utils::*;
 pub use vesting_utils::*;
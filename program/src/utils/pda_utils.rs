@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use pinocchio::{account::AccountView, address::Address, error::ProgramError};
 use pinocchio::{
     cpi::{Seed, Signer},
@@ -6,7 +7,10 @@ use pinocchio::{
 };
 use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
 
-use crate::errors::RewardsProgramError;
+use crate::{
+    errors::RewardsProgramError,
+    traits::{AccountParse, AccountSerialize, ACCOUNT_DISCRIMINATOR_SIZE},
+};
 
 /// Check if a PDA account is uninitialized (owned by system program).
 /// Safe alternative to checking lamports == 0, which can be manipulated.
@@ -26,6 +30,95 @@ pub fn close_pda_account(pda_account: &AccountView, recipient: &AccountView) ->
     Ok(())
 }
 
+/// Grow a program-owned account to `space`, topping up rent from `payer`.
+pub fn resize_pda_account(payer: &AccountView, pda_account: &AccountView, space: usize) -> ProgramResult {
+    let rent = Rent::get()?;
+
+    let required_lamports =
+        rent.try_minimum_balance(space).map_err(|_| RewardsProgramError::RentCalculationFailed)?.max(1);
+
+    let additional_lamports = required_lamports.saturating_sub(pda_account.lamports());
+    if additional_lamports > 0 {
+        Transfer { from: payer, to: pda_account, lamports: additional_lamports }.invoke()?;
+    }
+    pda_account.resize(space)?;
+
+    Ok(())
+}
+
+/// Write `state` to a program-owned account, migrating it if it still has an older, shorter layout.
+///
+/// With a `payer`, the account grows to the current layout as `MigrateAccount` would. Without one,
+/// the state is written back at the stored version when that layout holds it unchanged, which
+/// covers updates to fields every version has. Anything else needs `MigrateAccount` first.
+pub fn save_account<T: AccountParse + AccountSerialize>(
+    account: &AccountView,
+    state: &T,
+    payer: Option<&AccountView>,
+) -> ProgramResult {
+    let bytes = state.to_bytes();
+    if account.data_len() < bytes.len() {
+        let Some(payer) = payer else {
+            let mut data = account.try_borrow_mut()?;
+            let version = *data.get(ACCOUNT_DISCRIMINATOR_SIZE).ok_or(ProgramError::InvalidAccountData)?;
+            let stored = encode_at_version::<T>(&bytes, data.len(), version)?;
+            data.copy_from_slice(&stored);
+            return Ok(());
+        };
+        resize_pda_account(payer, account, bytes.len())?;
+    }
+
+    let mut data = account.try_borrow_mut()?;
+    data[..bytes.len()].copy_from_slice(&bytes);
+    Ok(())
+}
+
+/// Re-encode current-layout `bytes` as `len` bytes of layout `version`, failing unless that
+/// layout parses back to the same state.
+fn encode_at_version<T: AccountParse + AccountSerialize>(
+    bytes: &[u8],
+    len: usize,
+    version: u8,
+) -> Result<Vec<u8>, ProgramError> {
+    let mut stored = bytes.get(..len).ok_or(ProgramError::InvalidAccountData)?.to_vec();
+    if let Some(stored_version) = stored.get_mut(ACCOUNT_DISCRIMINATOR_SIZE) {
+        *stored_version = version;
+    }
+    match T::parse_from_bytes(&stored) {
+        Ok(parsed) if parsed.to_bytes() == bytes => Ok(stored),
+        _ => Err(RewardsProgramError::AccountNotMigrated.into()),
+    }
+}
+
+/// Hand a program-owned account's rent over to `new_payer`, sized for `space`.
+///
+/// `new_payer` funds the rent for `space` bytes and every lamport the account held before
+/// goes to `previous_payer`.
+pub fn transfer_rent_payer(
+    new_payer: &AccountView,
+    previous_payer: &AccountView,
+    pda_account: &AccountView,
+    space: usize,
+) -> ProgramResult {
+    let rent = Rent::get()?;
+
+    let required_lamports =
+        rent.try_minimum_balance(space).map_err(|_| RewardsProgramError::RentCalculationFailed)?.max(1);
+
+    let refund_lamports = pda_account.lamports();
+    Transfer { from: new_payer, to: pda_account, lamports: required_lamports }.invoke()?;
+
+    pda_account.set_lamports(required_lamports);
+    previous_payer
+        .set_lamports(previous_payer.lamports().checked_add(refund_lamports).ok_or(RewardsProgramError::MathOverflow)?);
+
+    if pda_account.data_len() < space {
+        pda_account.resize(space)?;
+    }
+
+    Ok(())
+}
+
 /// Create a PDA account for the given seeds.
 ///
 /// Will return an error if the account already exists (has lamports).
@@ -103,3 +196,85 @@ pub fn create_pda_account_idempotent<const N: usize>(
             .invoke_signed(&signers)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        state::{DirectDistribution, DirectRecipient},
+        traits::{Migrate, ACCOUNT_HEADER_SIZE},
+        utils::VestingSchedule,
+    };
+
+    fn v1_distribution_bytes(distribution: &DirectDistribution) -> Vec<u8> {
+        let mut bytes = distribution.to_bytes();
+        bytes.truncate(DirectDistribution::len_for_version(1).unwrap());
+        bytes[ACCOUNT_DISCRIMINATOR_SIZE] = 1;
+        bytes
+    }
+
+    fn v1_distribution() -> DirectDistribution {
+        let authority = Address::new_from_array([1u8; 32]);
+        let mut distribution = DirectDistribution::new(
+            255,
+            1,
+            0,
+            authority,
+            Address::new_from_array([2u8; 32]),
+            Address::new_from_array([3u8; 32]),
+        );
+        distribution.total_allocated = 1_000;
+        distribution.total_funded = 1_000;
+        distribution
+    }
+
+    #[test]
+    fn test_encode_at_version_keeps_old_layout() {
+        let mut distribution = v1_distribution();
+        let stored = v1_distribution_bytes(&distribution);
+        assert_eq!(DirectDistribution::parse_from_bytes(&stored).unwrap().to_bytes(), distribution.to_bytes());
+
+        distribution.total_claimed = 400;
+        let encoded = encode_at_version::<DirectDistribution>(&distribution.to_bytes(), stored.len(), 1).unwrap();
+        assert_eq!(encoded.len(), stored.len());
+        assert_eq!(encoded[ACCOUNT_DISCRIMINATOR_SIZE], 1);
+        assert_eq!(DirectDistribution::parse_from_bytes(&encoded).unwrap().total_claimed, 400);
+    }
+
+    #[test]
+    fn test_encode_at_version_rejects_fields_the_layout_lacks() {
+        let mut distribution = v1_distribution();
+        let len = v1_distribution_bytes(&distribution).len();
+        distribution.paused_at = 100;
+        assert_eq!(
+            encode_at_version::<DirectDistribution>(&distribution.to_bytes(), len, 1),
+            Err(RewardsProgramError::AccountNotMigrated.into())
+        );
+    }
+
+    #[test]
+    fn test_encode_at_version_recipient_schedule() {
+        let schedule = VestingSchedule::Linear { start_ts: 100, end_ts: 200 };
+        let mut recipient = DirectRecipient::new(
+            255,
+            Address::new_from_array([1u8; 32]),
+            Address::new_from_array([2u8; 32]),
+            Address::new_from_array([3u8; 32]),
+            1_000,
+            schedule.clone(),
+        );
+        let len = ACCOUNT_HEADER_SIZE + 113 + schedule.byte_len();
+
+        recipient.claimed_amount = 250;
+        let encoded = encode_at_version::<DirectRecipient>(&recipient.to_bytes(), len, 1).unwrap();
+        let parsed = DirectRecipient::parse_from_bytes(&encoded).unwrap();
+        assert_eq!(parsed.claimed_amount, 250);
+        assert_eq!(parsed.schedule, schedule);
+
+        recipient.schedule_amount = 500;
+        assert_eq!(
+            encode_at_version::<DirectRecipient>(&recipient.to_bytes(), len, 1),
+            Err(RewardsProgramError::AccountNotMigrated.into())
+        );
+    }
+}
@@ -7,6 +7,7 @@ use crate::errors::RewardsProgramError;
 pub enum RevokeMode {
     NonVested {},
     Full {},
+    Freeze {},
 }
 
 impl TryFrom<u8> for RevokeMode {
@@ -16,6 +17,7 @@ impl TryFrom<u8> for RevokeMode {
         match byte {
             0 => Ok(RevokeMode::NonVested {}),
             1 => Ok(RevokeMode::Full {}),
+            2 => Ok(RevokeMode::Freeze {}),
             _ => Err(RewardsProgramError::InvalidRevokeMode.into()),
         }
     }
@@ -26,6 +28,7 @@ impl RevokeMode {
         match self {
             RevokeMode::NonVested {} => 0,
             RevokeMode::Full {} => 1,
+            RevokeMode::Freeze {} => 2,
         }
     }
 
@@ -54,9 +57,15 @@ mod tests {
         assert_eq!(mode, RevokeMode::Full {});
     }
 
+    #[test]
+    fn test_revoke_mode_try_from_freeze() {
+        let mode = RevokeMode::try_from(2).unwrap();
+        assert_eq!(mode, RevokeMode::Freeze {});
+    }
+
     #[test]
     fn test_revoke_mode_try_from_invalid() {
-        let result = RevokeMode::try_from(2);
+        let result = RevokeMode::try_from(3);
         assert_eq!(result.err(), Some(ProgramError::Custom(RewardsProgramError::InvalidRevokeMode as u32)));
     }
 
@@ -64,11 +73,12 @@ mod tests {
     fn test_revoke_mode_to_byte() {
         assert_eq!(RevokeMode::NonVested {}.to_byte(), 0);
         assert_eq!(RevokeMode::Full {}.to_byte(), 1);
+        assert_eq!(RevokeMode::Freeze {}.to_byte(), 2);
     }
 
     #[test]
     fn test_revoke_mode_roundtrip() {
-        for byte in 0..=1 {
+        for byte in 0..=2 {
             let mode = RevokeMode::try_from(byte).unwrap();
             assert_eq!(mode.to_byte(), byte);
         }
@@ -78,6 +88,7 @@ mod tests {
     fn test_revoke_mode_to_bit() {
         assert_eq!(RevokeMode::NonVested {}.to_bit(), 0b01);
         assert_eq!(RevokeMode::Full {}.to_bit(), 0b10);
+        assert_eq!(RevokeMode::Freeze {}.to_bit(), 0b100);
     }
 
     #[test]
@@ -93,6 +104,10 @@ mod tests {
 
         assert!(!RevokeMode::NonVested {}.is_disabled_by(3));
         assert!(!RevokeMode::Full {}.is_disabled_by(3));
+        assert!(RevokeMode::Freeze {}.is_disabled_by(3));
+
+        assert!(!RevokeMode::Freeze {}.is_disabled_by(4));
+        assert!(RevokeMode::NonVested {}.is_disabled_by(4));
     }
 
     #[test]
@@ -8,3 +8,9 @@ use pinocchio::{
 pub fn get_current_timestamp() -> Result<i64, ProgramError> {
     Ok(Clock::get()?.unix_timestamp)
 }
+
+/// Get the current epoch from the Clock sysvar.
+#[inline(always)]
+pub fn get_current_epoch() -> Result<u64, ProgramError> {
+    Ok(Clock::get()?.epoch)
+}
@@ -0,0 +1,339 @@
+use codama::CodamaType;
+use pinocchio::{account::AccountView, error::ProgramError, ProgramResult};
+use pinocchio_token_2022::state::TokenAccount;
+
+use crate::errors::RewardsProgramError;
+
+use super::{get_current_epoch, is_native_mint, verify_token_program_account};
+
+/// Token-2022 extension type IDs (spl-token-2022 `ExtensionType`)
+const EXTENSION_UNINITIALIZED: u16 = 0;
+const EXTENSION_TRANSFER_FEE_CONFIG: u16 = 1;
+const EXTENSION_NON_TRANSFERABLE: u16 = 9;
+const EXTENSION_PERMANENT_DELEGATE: u16 = 12;
+const EXTENSION_PAUSABLE: u16 = 26;
+
+/// Mint extensions that make a vault unsafe or unusable:
+/// - NonTransferable: claims could never leave the vault
+/// - PermanentDelegate: the delegate can move vault tokens at any time
+/// - Pausable: the mint authority can block every claim
+const UNSUPPORTED_MINT_EXTENSIONS: [u16; 3] =
+    [EXTENSION_NON_TRANSFERABLE, EXTENSION_PERMANENT_DELEGATE, EXTENSION_PAUSABLE];
+
+/// Mints with extensions are padded to the token account length, followed by
+/// a one-byte account type and then the TLV entries.
+const ACCOUNT_TYPE_OFFSET: usize = TokenAccount::BASE_LEN;
+const ACCOUNT_TYPE_MINT: u8 = 1;
+const TLV_START: usize = ACCOUNT_TYPE_OFFSET + 1;
+const TLV_HEADER_LEN: usize = 2 + 2; // type(u16) + length(u16)
+
+/// TransferFeeConfig value: two 32-byte authorities, withheld_amount(8), then
+/// older and newer TransferFee entries.
+const TRANSFER_FEE_CONFIG_LEN: usize = 32 + 32 + 8 + TransferFee::LEN + TransferFee::LEN;
+const OLDER_TRANSFER_FEE_OFFSET: usize = 32 + 32 + 8;
+const NEWER_TRANSFER_FEE_OFFSET: usize = OLDER_TRANSFER_FEE_OFFSET + TransferFee::LEN;
+
+const ONE_IN_BASIS_POINTS: u128 = 10_000;
+
+/// How a claim amount is interpreted when the mint charges a transfer fee.
+///
+/// - `Gross`: the amount leaves the vault and the recipient receives it minus the fee.
+/// - `Net`: the recipient receives the amount; the fee is added on top and also
+///   counted against the recipient's allocation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, CodamaType)]
+pub enum TransferFeeMode {
+    Gross {},
+    Net {},
+}
+
+impl TryFrom<u8> for TransferFeeMode {
+    type Error = ProgramError;
+
+    fn try_from(byte: u8) -> Result<Self, ProgramError> {
+        match byte {
+            0 => Ok(TransferFeeMode::Gross {}),
+            1 => Ok(TransferFeeMode::Net {}),
+            _ => Err(RewardsProgramError::InvalidTransferFeeMode.into()),
+        }
+    }
+}
+
+impl TransferFeeMode {
+    pub fn to_byte(&self) -> u8 {
+        match self {
+            TransferFeeMode::Gross {} => 0,
+            TransferFeeMode::Net {} => 1,
+        }
+    }
+}
+
+/// A single Token-2022 transfer fee schedule entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransferFee {
+    /// First epoch where this fee takes effect
+    pub epoch: u64,
+    /// Maximum fee charged on a single transfer
+    pub maximum_fee: u64,
+    /// Fee rate in basis points
+    pub basis_points: u16,
+}
+
+impl TransferFee {
+    pub const LEN: usize = 8 + 8 + 2;
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let epoch = u64::from_le_bytes(data[0..8].try_into().map_err(|_| ProgramError::InvalidAccountData)?);
+        let maximum_fee = u64::from_le_bytes(data[8..16].try_into().map_err(|_| ProgramError::InvalidAccountData)?);
+        let basis_points = u16::from_le_bytes(data[16..18].try_into().map_err(|_| ProgramError::InvalidAccountData)?);
+        Ok(Self { epoch, maximum_fee, basis_points })
+    }
+
+    /// Fee withheld when transferring `amount` (matches spl-token-2022 rounding).
+    pub fn calculate_fee(&self, amount: u64) -> Option<u64> {
+        if self.basis_points == 0 || amount == 0 {
+            return Some(0);
+        }
+        let numerator = (amount as u128).checked_mul(self.basis_points as u128)?;
+        let raw_fee = numerator.checked_add(ONE_IN_BASIS_POINTS - 1)? / ONE_IN_BASIS_POINTS;
+        Some(u64::try_from(raw_fee).ok()?.min(self.maximum_fee))
+    }
+
+    /// Amount received after transferring `amount`.
+    pub fn calculate_post_fee_amount(&self, amount: u64) -> Option<u64> {
+        amount.checked_sub(self.calculate_fee(amount)?)
+    }
+
+    /// Smallest transfer amount that delivers `post_fee_amount` to the destination.
+    pub fn calculate_pre_fee_amount(&self, post_fee_amount: u64) -> Option<u64> {
+        let basis_points = self.basis_points as u128;
+        match (basis_points, post_fee_amount) {
+            (0, _) => Some(post_fee_amount),
+            (_, 0) => Some(0),
+            (ONE_IN_BASIS_POINTS, _) => self.maximum_fee.checked_add(post_fee_amount),
+            _ => {
+                let numerator = (post_fee_amount as u128).checked_mul(ONE_IN_BASIS_POINTS)?;
+                let denominator = ONE_IN_BASIS_POINTS.checked_sub(basis_points)?;
+                let raw_pre_fee_amount = numerator.checked_add(denominator - 1)? / denominator;
+
+                if raw_pre_fee_amount.checked_sub(post_fee_amount as u128)? >= self.maximum_fee as u128 {
+                    post_fee_amount.checked_add(self.maximum_fee)
+                } else {
+                    u64::try_from(raw_pre_fee_amount).ok()
+                }
+            }
+        }
+    }
+}
+
+/// Finds the value of a Token-2022 mint extension in raw mint data.
+///
+/// Returns `None` for SPL Token mints and Token-2022 mints without the extension.
+fn find_mint_extension(data: &[u8], extension_type: u16) -> Result<Option<&[u8]>, ProgramError> {
+    if data.len() <= ACCOUNT_TYPE_OFFSET {
+        return Ok(None);
+    }
+    if data[ACCOUNT_TYPE_OFFSET] != ACCOUNT_TYPE_MINT {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let mut offset = TLV_START;
+    while offset + TLV_HEADER_LEN <= data.len() {
+        let entry_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+        let entry_len = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+        if entry_type == EXTENSION_UNINITIALIZED {
+            break;
+        }
+
+        let value_start = offset + TLV_HEADER_LEN;
+        let value_end = value_start.checked_add(entry_len).ok_or(ProgramError::InvalidAccountData)?;
+        if value_end > data.len() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if entry_type == extension_type {
+            return Ok(Some(&data[value_start..value_end]));
+        }
+        offset = value_end;
+    }
+
+    Ok(None)
+}
+
+/// Rejects mints carrying extensions that distributions cannot safely hold.
+#[inline(always)]
+pub fn verify_supported_mint_extensions(mint: &AccountView) -> ProgramResult {
+    if is_native_mint(mint) {
+        return Ok(());
+    }
+    verify_token_program_account(mint)?;
+
+    let data = mint.try_borrow()?;
+    for extension_type in UNSUPPORTED_MINT_EXTENSIONS {
+        if find_mint_extension(&data, extension_type)?.is_some() {
+            return Err(RewardsProgramError::UnsupportedMintExtension.into());
+        }
+    }
+    Ok(())
+}
+
+/// Returns whether the mint has a TransferFeeConfig extension, whatever its current rate.
+#[inline(always)]
+pub fn has_transfer_fee_config(mint: &AccountView) -> Result<bool, ProgramError> {
+    if is_native_mint(mint) {
+        return Ok(false);
+    }
+    verify_token_program_account(mint)?;
+
+    let data = mint.try_borrow()?;
+    Ok(find_mint_extension(&data, EXTENSION_TRANSFER_FEE_CONFIG)?.is_some())
+}
+
+/// Returns the transfer fee in effect at `epoch`, or `None` if the mint has no TransferFeeConfig
+/// (always the case for native SOL).
+#[inline(always)]
+pub fn get_transfer_fee(mint: &AccountView, epoch: u64) -> Result<Option<TransferFee>, ProgramError> {
+    if is_native_mint(mint) {
+        return Ok(None);
+    }
+    verify_token_program_account(mint)?;
+
+    let data = mint.try_borrow()?;
+    let Some(config) = find_mint_extension(&data, EXTENSION_TRANSFER_FEE_CONFIG)? else {
+        return Ok(None);
+    };
+    if config.len() < TRANSFER_FEE_CONFIG_LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let newer = TransferFee::from_bytes(&config[NEWER_TRANSFER_FEE_OFFSET..])?;
+    if epoch >= newer.epoch {
+        return Ok(Some(newer));
+    }
+    Ok(Some(TransferFee::from_bytes(&config[OLDER_TRANSFER_FEE_OFFSET..])?))
+}
+
+/// Converts a requested claim amount into the amount that must leave the vault.
+///
+/// `Gross` amounts are returned unchanged. `Net` amounts are grossed up by the
+/// current epoch's transfer fee. Zero means "claim all" and is returned unchanged.
+#[inline(always)]
+pub fn resolve_gross_amount(mint: &AccountView, amount: u64, mode: TransferFeeMode) -> Result<u64, ProgramError> {
+    if amount == 0 || mode == (TransferFeeMode::Gross {}) {
+        return Ok(amount);
+    }
+    match get_transfer_fee(mint, get_current_epoch()?)? {
+        Some(fee) => Ok(fee.calculate_pre_fee_amount(amount).ok_or(RewardsProgramError::MathOverflow)?),
+        None => Ok(amount),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn fee(basis_points: u16, maximum_fee: u64) -> TransferFee {
+        TransferFee { epoch: 0, maximum_fee, basis_points }
+    }
+
+    fn mint_data_with_extensions(extensions: &[(u16, &[u8])]) -> Vec<u8> {
+        let mut data = alloc::vec![0u8; TLV_START];
+        data[ACCOUNT_TYPE_OFFSET] = ACCOUNT_TYPE_MINT;
+        for (extension_type, value) in extensions {
+            data.extend_from_slice(&extension_type.to_le_bytes());
+            data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+            data.extend_from_slice(value);
+        }
+        data
+    }
+
+    #[test]
+    fn test_account_type_offset() {
+        assert_eq!(ACCOUNT_TYPE_OFFSET, 165);
+    }
+
+    #[test]
+    fn test_transfer_fee_mode_try_from() {
+        assert_eq!(TransferFeeMode::try_from(0).unwrap(), TransferFeeMode::Gross {});
+        assert_eq!(TransferFeeMode::try_from(1).unwrap(), TransferFeeMode::Net {});
+        assert_eq!(TransferFeeMode::try_from(2).err(), Some(RewardsProgramError::InvalidTransferFeeMode.into()));
+    }
+
+    #[test]
+    fn test_transfer_fee_mode_roundtrip() {
+        for mode in [TransferFeeMode::Gross {}, TransferFeeMode::Net {}] {
+            assert_eq!(TransferFeeMode::try_from(mode.to_byte()).unwrap(), mode);
+        }
+    }
+
+    #[test]
+    fn test_calculate_fee_rounds_up() {
+        // 1% of 150 = 1.5 -> 2
+        assert_eq!(fee(100, u64::MAX).calculate_fee(150), Some(2));
+    }
+
+    #[test]
+    fn test_calculate_fee_capped_at_maximum() {
+        assert_eq!(fee(500, 10).calculate_fee(1_000_000), Some(10));
+    }
+
+    #[test]
+    fn test_calculate_fee_zero() {
+        assert_eq!(fee(0, 10).calculate_fee(1_000), Some(0));
+        assert_eq!(fee(100, 10).calculate_fee(0), Some(0));
+    }
+
+    #[test]
+    fn test_calculate_post_fee_amount() {
+        assert_eq!(fee(100, u64::MAX).calculate_post_fee_amount(1_000), Some(990));
+    }
+
+    #[test]
+    fn test_calculate_pre_fee_amount_inverts_fee() {
+        let transfer_fee = fee(100, u64::MAX);
+        let gross = transfer_fee.calculate_pre_fee_amount(990).unwrap();
+        assert_eq!(gross, 1_000);
+        assert_eq!(transfer_fee.calculate_post_fee_amount(gross), Some(990));
+    }
+
+    #[test]
+    fn test_calculate_pre_fee_amount_capped_at_maximum() {
+        assert_eq!(fee(500, 10).calculate_pre_fee_amount(1_000_000), Some(1_000_010));
+    }
+
+    #[test]
+    fn test_calculate_pre_fee_amount_full_rate() {
+        assert_eq!(fee(10_000, 50).calculate_pre_fee_amount(100), Some(150));
+    }
+
+    #[test]
+    fn test_find_mint_extension_spl_token_mint() {
+        let data = [0u8; 82];
+        assert_eq!(find_mint_extension(&data, EXTENSION_TRANSFER_FEE_CONFIG).unwrap(), None);
+    }
+
+    #[test]
+    fn test_find_mint_extension_found() {
+        let data = mint_data_with_extensions(&[(EXTENSION_NON_TRANSFERABLE, &[]), (EXTENSION_PAUSABLE, &[7u8; 33])]);
+        assert_eq!(find_mint_extension(&data, EXTENSION_NON_TRANSFERABLE).unwrap(), Some(&[][..]));
+        assert_eq!(find_mint_extension(&data, EXTENSION_PAUSABLE).unwrap(), Some(&[7u8; 33][..]));
+        assert_eq!(find_mint_extension(&data, EXTENSION_PERMANENT_DELEGATE).unwrap(), None);
+    }
+
+    #[test]
+    fn test_find_mint_extension_truncated_entry() {
+        let mut data = mint_data_with_extensions(&[(EXTENSION_PERMANENT_DELEGATE, &[1u8; 32])]);
+        data.truncate(data.len() - 1);
+        assert_eq!(find_mint_extension(&data, EXTENSION_PERMANENT_DELEGATE), Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_find_mint_extension_wrong_account_type() {
+        let mut data = mint_data_with_extensions(&[]);
+        data[ACCOUNT_TYPE_OFFSET] = 2;
+        assert_eq!(find_mint_extension(&data, EXTENSION_PAUSABLE), Err(ProgramError::InvalidAccountData));
+    }
+}
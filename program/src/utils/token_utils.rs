@@ -3,7 +3,10 @@ use pinocchio::{account::AccountView, address::Address, error::ProgramError};
 use pinocchio_associated_token_account::ID as ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID;
 use pinocchio_token_2022::state::{Mint, TokenAccount};
 
-use crate::utils::verify_token_program_account;
+use crate::{
+    errors::RewardsProgramError,
+    utils::{get_vault_balance, verify_token_program_account},
+};
 
 /// Validates an Associated Token Account address.
 ///
@@ -101,3 +104,30 @@ pub fn get_token_account_balance(token_account: &AccountView) -> Result<u64, Pro
     let account = unsafe { TokenAccount::from_bytes_unchecked(&data) };
     Ok(account.amount())
 }
+
+/// Verifies that a vault (token or native) holds at least `amount` tokens.
+///
+/// Lets claims fail with a program error instead of a token-program transfer error
+/// when a distribution is underfunded.
+#[inline(always)]
+pub fn verify_vault_balance(vault: &AccountView, amount: u64) -> ProgramResult {
+    if get_vault_balance(vault)? < amount {
+        return Err(RewardsProgramError::InsufficientFunds.into());
+    }
+    Ok(())
+}
+
+/// Runs a transfer into `vault` and returns how many tokens actually arrived.
+///
+/// Token-2022 transfer fees are withheld in the destination account, so the
+/// balance delta is the only reliable measure of what a distribution received.
+#[inline(always)]
+pub fn measure_vault_deposit<F>(vault: &AccountView, transfer: F) -> Result<u64, ProgramError>
+where
+    F: FnOnce() -> ProgramResult,
+{
+    let balance_before = get_vault_balance(vault)?;
+    transfer()?;
+    let balance_after = get_vault_balance(vault)?;
+    Ok(balance_after.checked_sub(balance_before).ok_or(RewardsProgramError::MathOverflow)?)
+}
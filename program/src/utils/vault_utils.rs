@@ -0,0 +1,271 @@
+//! Distribution vault utilities.
+//!
+//! A distribution holds its tokens in one of two kinds of vault:
+//!
+//! - **Token vault**: the distribution PDA's associated token account for an SPL Token or
+//!   Token-2022 mint.
+//! - **Native vault**: a program-owned lamport account at `[b"native_vault", distribution]`.
+//!   Native SOL distributions use [`NATIVE_SOL_MINT`] as their mint and the System program as
+//!   their token program, so claims pay lamports straight to the recipient's wallet.
+//!
+//! The token program account passed to an instruction selects the vault kind. The helpers here
+//! branch on it so processors can move funds without caring which kind they hold.
+
+use pinocchio::{
+    account::AccountView,
+    cpi::Seed,
+    error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+    Address, ProgramResult,
+};
+use pinocchio_associated_token_account::instructions::CreateIdempotent;
+use pinocchio_system::instructions::Transfer;
+use pinocchio_token_2022::instructions::{CloseAccount, TransferChecked};
+
+use crate::{errors::RewardsProgramError, traits::DistributionSigner, ID};
+
+use super::{
+    close_pda_account, create_pda_account_idempotent, get_mint_decimals, get_token_account_balance,
+    measure_vault_deposit, transfer_from_distribution_vault, validate_associated_token_account,
+    validate_associated_token_account_address, verify_owned_by, verify_system_program, verify_token_program,
+    ClaimTransferContext,
+};
+
+/// Mint address of native SOL distributions.
+///
+/// This is the System program ID, which also serves as their token program. It can never be
+/// a real mint, so a native distribution cannot be confused with a wrapped SOL distribution.
+pub const NATIVE_SOL_MINT: Address = pinocchio_system::ID;
+
+/// Decimals of native SOL (lamports per SOL = 10^9)
+pub const NATIVE_SOL_DECIMALS: u8 = 9;
+
+/// Seed prefix for the lamport vault of a native SOL distribution
+pub const NATIVE_VAULT_SEED: &[u8] = b"native_vault";
+
+/// Whether `token_program` selects a native SOL vault.
+///
+/// Only meaningful after `verify_token_program_for_mint` has accepted the pair.
+#[inline(always)]
+pub fn is_native_token_program(token_program: &Address) -> bool {
+    token_program == &pinocchio_system::ID
+}
+
+/// Whether `mint` is the native SOL marker
+#[inline(always)]
+pub fn is_native_mint(mint: &AccountView) -> bool {
+    mint.address() == &NATIVE_SOL_MINT
+}
+
+/// Derive the lamport vault of a native SOL distribution.
+#[inline(always)]
+pub fn find_native_vault_address(distribution: &Address) -> (Address, u8) {
+    Address::find_program_address(&[NATIVE_VAULT_SEED, distribution.as_ref()], &ID)
+}
+
+/// Verify the token program matches the mint.
+///
+/// Native SOL distributions must pass the System program; every other mint must pass
+/// SPL Token or Token-2022.
+#[inline(always)]
+pub fn verify_token_program_for_mint(token_program: &AccountView, mint: &AccountView) -> ProgramResult {
+    if is_native_mint(mint) {
+        verify_system_program(token_program)
+    } else {
+        verify_token_program(token_program)
+    }
+}
+
+/// Verify the mint is owned by its token program. The native SOL marker has no mint account.
+#[inline(always)]
+pub fn verify_mint_owner(mint: &AccountView, token_program: &AccountView) -> ProgramResult {
+    if is_native_mint(mint) {
+        return Ok(());
+    }
+    verify_owned_by(mint, token_program.address())
+}
+
+/// Validate the distribution vault address without requiring it to exist.
+#[inline(always)]
+pub fn validate_distribution_vault_address(
+    vault: &AccountView,
+    distribution: &Address,
+    mint: &AccountView,
+    token_program: &AccountView,
+) -> ProgramResult {
+    if !is_native_token_program(token_program.address()) {
+        return validate_associated_token_account_address(vault, distribution, mint, token_program);
+    }
+
+    if vault.address() != &find_native_vault_address(distribution).0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(())
+}
+
+/// Validate an existing distribution vault: the distribution's ATA, or its native vault PDA.
+#[inline(always)]
+pub fn validate_distribution_vault(
+    vault: &AccountView,
+    distribution: &Address,
+    mint: &AccountView,
+    token_program: &AccountView,
+) -> ProgramResult {
+    if !is_native_token_program(token_program.address()) {
+        return validate_associated_token_account(vault, distribution, mint, token_program);
+    }
+
+    validate_distribution_vault_address(vault, distribution, mint, token_program)?;
+    verify_owned_by(vault, &ID)
+}
+
+/// Create the vault of a new distribution.
+///
+/// Token vaults are created through the associated token program. Native vaults are empty
+/// program-owned accounts holding the rent-exempt minimum. Both are created idempotently, so
+/// lamports sent to the vault address ahead of time cannot block the distribution.
+pub fn create_distribution_vault(
+    payer: &AccountView,
+    distribution: &AccountView,
+    vault: &AccountView,
+    mint: &AccountView,
+    system_program: &AccountView,
+    token_program: &AccountView,
+) -> ProgramResult {
+    if !is_native_token_program(token_program.address()) {
+        return CreateIdempotent {
+            funding_account: payer,
+            account: vault,
+            wallet: distribution,
+            mint,
+            system_program,
+            token_program,
+        }
+        .invoke();
+    }
+
+    let (_, bump) = find_native_vault_address(distribution.address());
+    let bump_seed = [bump];
+    let seeds = [Seed::from(NATIVE_VAULT_SEED), Seed::from(distribution.address().as_ref()), Seed::from(&bump_seed)];
+    create_pda_account_idempotent(payer, 0, &ID, vault, seeds)
+}
+
+/// Spendable balance of a distribution vault.
+///
+/// For native vaults this excludes the rent-exempt minimum, which stays in the vault until
+/// it is closed.
+#[inline(always)]
+pub fn get_vault_balance(vault: &AccountView) -> Result<u64, ProgramError> {
+    if !vault.owned_by(&ID) {
+        return get_token_account_balance(vault);
+    }
+
+    let reserve = Rent::get()?.try_minimum_balance(0).map_err(|_| RewardsProgramError::RentCalculationFailed)?;
+    Ok(vault.lamports().saturating_sub(reserve))
+}
+
+/// Accounts for moving funds into a distribution vault.
+pub struct VaultDepositContext<'a> {
+    /// Token account (or wallet, for native SOL) the funds come from
+    pub source: &'a AccountView,
+    /// Owner of `source`; must sign
+    pub authority: &'a AccountView,
+    pub distribution_vault: &'a AccountView,
+    pub mint: &'a AccountView,
+    pub token_program: &'a AccountView,
+}
+
+/// Transfer `amount` into the vault and return how much actually arrived.
+///
+/// Native deposits are System program transfers from `source`, which must be the signing
+/// wallet itself.
+#[inline(always)]
+pub fn deposit_to_distribution_vault(ctx: &VaultDepositContext, amount: u64) -> Result<u64, ProgramError> {
+    if is_native_token_program(ctx.token_program.address()) {
+        if ctx.source.address() != ctx.authority.address() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        return measure_vault_deposit(ctx.distribution_vault, || {
+            Transfer { from: ctx.source, to: ctx.distribution_vault, lamports: amount }.invoke()
+        });
+    }
+
+    let decimals = get_mint_decimals(ctx.mint)?;
+    measure_vault_deposit(ctx.distribution_vault, || {
+        TransferChecked {
+            from: ctx.source,
+            mint: ctx.mint,
+            to: ctx.distribution_vault,
+            authority: ctx.authority,
+            amount,
+            decimals,
+            token_program: ctx.token_program.address(),
+        }
+        .invoke()
+    })
+}
+
+/// Move `amount` lamports out of a native vault, keeping its rent-exempt reserve.
+///
+/// The vault is owned by this program, so no CPI is needed.
+#[inline(always)]
+pub fn withdraw_from_native_vault(vault: &AccountView, destination: &AccountView, amount: u64) -> ProgramResult {
+    if get_vault_balance(vault)? < amount {
+        return Err(RewardsProgramError::InsufficientFunds.into());
+    }
+
+    vault.set_lamports(vault.lamports() - amount);
+    destination.set_lamports(destination.lamports().checked_add(amount).ok_or(RewardsProgramError::MathOverflow)?);
+    Ok(())
+}
+
+/// Empty and close a distribution vault, returning the spendable balance it held.
+///
+/// The balance goes to `ctx.destination`; the vault's rent goes to `rent_destination`.
+pub fn close_distribution_vault<D: DistributionSigner>(
+    distribution: &D,
+    ctx: &ClaimTransferContext,
+    rent_destination: &AccountView,
+) -> Result<u64, ProgramError> {
+    let remaining_amount = get_vault_balance(ctx.distribution_vault)?;
+
+    if remaining_amount > 0 {
+        transfer_from_distribution_vault(distribution, ctx, remaining_amount)?;
+    }
+
+    if is_native_token_program(ctx.token_program) {
+        close_pda_account(ctx.distribution_vault, rent_destination)?;
+    } else {
+        distribution.with_signer(|signers| {
+            CloseAccount {
+                account: ctx.distribution_vault,
+                destination: rent_destination,
+                authority: ctx.distribution_account,
+                token_program: ctx.token_program,
+            }
+            .invoke_signed(signers)
+        })?;
+    }
+
+    Ok(remaining_amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_native_sol_mint_is_system_program() {
+        assert_eq!(NATIVE_SOL_MINT, pinocchio_system::ID);
+        assert!(is_native_token_program(&pinocchio_system::ID));
+        assert!(!is_native_token_program(&pinocchio_token::ID));
+        assert!(!is_native_token_program(&pinocchio_token_2022::ID));
+    }
+
+    #[test]
+    fn test_native_vault_address_is_per_distribution() {
+        let first = find_native_vault_address(&Address::new_from_array([1u8; 32])).0;
+        let second = find_native_vault_address(&Address::new_from_array([2u8; 32])).0;
+        assert_ne!(first, second);
+    }
+}
@@ -4,15 +4,76 @@ use pinocchio::error::ProgramError;
 
 use crate::errors::RewardsProgramError;
 
+/// Maximum number of points in a `Tranches` schedule
+pub const MAX_TRANCHE_POINTS: usize = 16;
+
+/// Basis points representing the full allocation
+pub const TRANCHE_FULL_BPS: u16 = 10_000;
+
+/// Maximum number of remaining stepped periods `unlock_points` lists
+pub const MAX_COMPARED_PERIODS: u64 = 64;
+
+/// A single point of a `Tranches` schedule: by `timestamp`, `cumulative_bps`
+/// of the total allocation has unlocked.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, CodamaType)]
+pub struct TranchePoint {
+    pub timestamp: i64,
+    pub cumulative_bps: u16,
+}
+
+impl TranchePoint {
+    pub const LEN: usize = 8 + 2;
+}
+
+/// How a `Tranches` schedule unlocks between two points.
+///
+/// - `Step`: the unlocked share jumps to each point's `cumulative_bps` at its timestamp.
+/// - `Linear`: the unlocked share moves linearly from one point to the next.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, CodamaType)]
+pub enum TrancheInterpolation {
+    Step {},
+    Linear {},
+}
+
+impl TryFrom<u8> for TrancheInterpolation {
+    type Error = ProgramError;
+
+    fn try_from(byte: u8) -> Result<Self, ProgramError> {
+        match byte {
+            0 => Ok(TrancheInterpolation::Step {}),
+            1 => Ok(TrancheInterpolation::Linear {}),
+            _ => Err(RewardsProgramError::InvalidTrancheSchedule.into()),
+        }
+    }
+}
+
+impl TrancheInterpolation {
+    pub fn to_byte(&self) -> u8 {
+        match self {
+            TrancheInterpolation::Step {} => 0,
+            TrancheInterpolation::Linear {} => 1,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, CodamaType)]
 pub enum VestingSchedule {
     Immediate {},
     Linear { start_ts: i64, end_ts: i64 },
     Cliff { cliff_ts: i64 },
     CliffLinear { start_ts: i64, cliff_ts: i64, end_ts: i64 },
+    Stepped { start_ts: i64, period_secs: u64, num_periods: u32 },
+    CliffStepped { start_ts: i64, cliff_ts: i64, period_secs: u64, num_periods: u32 },
+    Tranches { interpolation: TrancheInterpolation, points: Vec<TranchePoint> },
 }
 
+/// Tranches header: type(1) + interpolation(1) + points_len(4)
+const TRANCHES_HEADER_LEN: usize = 1 + 1 + 4;
+
 impl VestingSchedule {
+    /// Byte length of the largest variant (Tranches with MAX_TRANCHE_POINTS points)
+    pub const MAX_BYTE_LEN: usize = TRANCHES_HEADER_LEN + MAX_TRANCHE_POINTS * TranchePoint::LEN;
+
     pub fn validate(&self) -> Result<(), ProgramError> {
         match self {
             VestingSchedule::Immediate {} => Ok(()),
@@ -37,7 +98,72 @@ impl VestingSchedule {
                 }
                 Ok(())
             }
+            VestingSchedule::Stepped { start_ts, period_secs, num_periods } => {
+                calculate_stepped_end(*start_ts, *period_secs, *num_periods)?;
+                Ok(())
+            }
+            VestingSchedule::CliffStepped { start_ts, cliff_ts, period_secs, num_periods } => {
+                let end_ts = calculate_stepped_end(*start_ts, *period_secs, *num_periods)?;
+                if *cliff_ts < *start_ts || *cliff_ts > end_ts {
+                    return Err(RewardsProgramError::InvalidCliffTimestamp.into());
+                }
+                Ok(())
+            }
+            VestingSchedule::Tranches { points, .. } => validate_tranche_points(points),
+        }
+    }
+
+    /// Timestamp at which the schedule is fully vested (0 for `Immediate`).
+    pub fn end_ts(&self) -> Result<i64, ProgramError> {
+        match self {
+            VestingSchedule::Immediate {} => Ok(0),
+            VestingSchedule::Linear { end_ts, .. } | VestingSchedule::CliffLinear { end_ts, .. } => Ok(*end_ts),
+            VestingSchedule::Cliff { cliff_ts } => Ok(*cliff_ts),
+            VestingSchedule::Stepped { start_ts, period_secs, num_periods }
+            | VestingSchedule::CliffStepped { start_ts, period_secs, num_periods, .. } => {
+                calculate_stepped_end(*start_ts, *period_secs, *num_periods)
+            }
+            VestingSchedule::Tranches { points, .. } => {
+                Ok(points.last().ok_or(RewardsProgramError::InvalidTrancheSchedule)?.timestamp)
+            }
+        }
+    }
+
+    /// Appends each timestamp after `after_ts` where the unlock curve jumps or changes slope.
+    /// Between two such points the curve is flat or linear, so comparing two schedules only
+    /// needs these points and the second before each.
+    pub fn unlock_points(&self, after_ts: i64, points: &mut Vec<i64>) -> Result<(), ProgramError> {
+        let mut push = |ts: i64| {
+            if ts > after_ts {
+                points.push(ts);
+            }
+        };
+        match self {
+            VestingSchedule::Immediate {} => {}
+            VestingSchedule::Linear { start_ts, end_ts } => {
+                push(*start_ts);
+                push(*end_ts);
+            }
+            VestingSchedule::Cliff { cliff_ts } => push(*cliff_ts),
+            VestingSchedule::CliffLinear { start_ts, cliff_ts, end_ts } => {
+                push(*start_ts);
+                push(*cliff_ts);
+                push(*end_ts);
+            }
+            VestingSchedule::Stepped { start_ts, period_secs, num_periods } => {
+                push_stepped_points(*start_ts, *period_secs, *num_periods, after_ts, &mut push)?;
+            }
+            VestingSchedule::CliffStepped { start_ts, cliff_ts, period_secs, num_periods } => {
+                push(*cliff_ts);
+                // Steps before the cliff all land on it
+                let after_ts = after_ts.max(*cliff_ts);
+                push_stepped_points(*start_ts, *period_secs, *num_periods, after_ts, &mut push)?;
+            }
+            VestingSchedule::Tranches { points: tranche_points, .. } => {
+                tranche_points.iter().for_each(|point| push(point.timestamp));
+            }
         }
+        Ok(())
     }
 
     pub fn calculate_unlocked(&self, total_amount: u64, current_ts: i64) -> Result<u64, ProgramError> {
@@ -59,6 +185,18 @@ impl VestingSchedule {
                 }
                 calculate_linear_unlock(total_amount, *start_ts, *end_ts, current_ts)
             }
+            VestingSchedule::Stepped { start_ts, period_secs, num_periods } => {
+                calculate_stepped_unlock(total_amount, *start_ts, *period_secs, *num_periods, current_ts)
+            }
+            VestingSchedule::CliffStepped { start_ts, cliff_ts, period_secs, num_periods } => {
+                if current_ts < *cliff_ts {
+                    return Ok(0);
+                }
+                calculate_stepped_unlock(total_amount, *start_ts, *period_secs, *num_periods, current_ts)
+            }
+            VestingSchedule::Tranches { interpolation, points } => {
+                calculate_tranche_unlock(total_amount, *interpolation, points, current_ts)
+            }
         }
     }
 
@@ -68,6 +206,9 @@ impl VestingSchedule {
             VestingSchedule::Linear { .. } => 17,
             VestingSchedule::Cliff { .. } => 9,
             VestingSchedule::CliffLinear { .. } => 25,
+            VestingSchedule::Stepped { .. } => 21,
+            VestingSchedule::CliffStepped { .. } => 29,
+            VestingSchedule::Tranches { points, .. } => TRANCHES_HEADER_LEN + points.len() * TranchePoint::LEN,
         }
     }
 
@@ -97,6 +238,34 @@ impl VestingSchedule {
                 data.extend_from_slice(&end_ts.to_le_bytes());
                 data
             }
+            VestingSchedule::Stepped { start_ts, period_secs, num_periods } => {
+                let mut data = Vec::with_capacity(21);
+                data.push(4);
+                data.extend_from_slice(&start_ts.to_le_bytes());
+                data.extend_from_slice(&period_secs.to_le_bytes());
+                data.extend_from_slice(&num_periods.to_le_bytes());
+                data
+            }
+            VestingSchedule::CliffStepped { start_ts, cliff_ts, period_secs, num_periods } => {
+                let mut data = Vec::with_capacity(29);
+                data.push(5);
+                data.extend_from_slice(&start_ts.to_le_bytes());
+                data.extend_from_slice(&cliff_ts.to_le_bytes());
+                data.extend_from_slice(&period_secs.to_le_bytes());
+                data.extend_from_slice(&num_periods.to_le_bytes());
+                data
+            }
+            VestingSchedule::Tranches { interpolation, points } => {
+                let mut data = Vec::with_capacity(self.byte_len());
+                data.push(6);
+                data.push(interpolation.to_byte());
+                data.extend_from_slice(&(points.len() as u32).to_le_bytes());
+                for point in points {
+                    data.extend_from_slice(&point.timestamp.to_le_bytes());
+                    data.extend_from_slice(&point.cumulative_bps.to_le_bytes());
+                }
+                data
+            }
         }
     }
 
@@ -125,6 +294,33 @@ impl VestingSchedule {
                 buf[17..25].copy_from_slice(&end_ts.to_le_bytes());
                 25
             }
+            VestingSchedule::Stepped { start_ts, period_secs, num_periods } => {
+                buf[0] = 4;
+                buf[1..9].copy_from_slice(&start_ts.to_le_bytes());
+                buf[9..17].copy_from_slice(&period_secs.to_le_bytes());
+                buf[17..21].copy_from_slice(&num_periods.to_le_bytes());
+                21
+            }
+            VestingSchedule::CliffStepped { start_ts, cliff_ts, period_secs, num_periods } => {
+                buf[0] = 5;
+                buf[1..9].copy_from_slice(&start_ts.to_le_bytes());
+                buf[9..17].copy_from_slice(&cliff_ts.to_le_bytes());
+                buf[17..25].copy_from_slice(&period_secs.to_le_bytes());
+                buf[25..29].copy_from_slice(&num_periods.to_le_bytes());
+                29
+            }
+            VestingSchedule::Tranches { interpolation, points } => {
+                buf[0] = 6;
+                buf[1] = interpolation.to_byte();
+                buf[2..6].copy_from_slice(&(points.len() as u32).to_le_bytes());
+                let mut offset = TRANCHES_HEADER_LEN;
+                for point in points {
+                    buf[offset..offset + 8].copy_from_slice(&point.timestamp.to_le_bytes());
+                    buf[offset + 8..offset + 10].copy_from_slice(&point.cumulative_bps.to_le_bytes());
+                    offset += TranchePoint::LEN;
+                }
+                offset
+            }
         }
     }
 
@@ -164,6 +360,57 @@ impl VestingSchedule {
                     i64::from_le_bytes(data[17..25].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
                 Ok((VestingSchedule::CliffLinear { start_ts, cliff_ts, end_ts }, 25))
             }
+            4 => {
+                if data.len() < 21 {
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+                let start_ts =
+                    i64::from_le_bytes(data[1..9].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+                let period_secs =
+                    u64::from_le_bytes(data[9..17].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+                let num_periods =
+                    u32::from_le_bytes(data[17..21].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+                Ok((VestingSchedule::Stepped { start_ts, period_secs, num_periods }, 21))
+            }
+            5 => {
+                if data.len() < 29 {
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+                let start_ts =
+                    i64::from_le_bytes(data[1..9].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+                let cliff_ts =
+                    i64::from_le_bytes(data[9..17].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+                let period_secs =
+                    u64::from_le_bytes(data[17..25].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+                let num_periods =
+                    u32::from_le_bytes(data[25..29].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+                Ok((VestingSchedule::CliffStepped { start_ts, cliff_ts, period_secs, num_periods }, 29))
+            }
+            6 => {
+                if data.len() < TRANCHES_HEADER_LEN {
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+                let interpolation = TrancheInterpolation::try_from(data[1])?;
+                let points_len =
+                    u32::from_le_bytes(data[2..6].try_into().map_err(|_| ProgramError::InvalidInstructionData)?)
+                        as usize;
+                if points_len > MAX_TRANCHE_POINTS {
+                    return Err(RewardsProgramError::InvalidTrancheSchedule.into());
+                }
+                let total_len = TRANCHES_HEADER_LEN + points_len * TranchePoint::LEN;
+                if data.len() < total_len {
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+                let mut points = Vec::with_capacity(points_len);
+                for chunk in data[TRANCHES_HEADER_LEN..total_len].chunks_exact(TranchePoint::LEN) {
+                    let timestamp =
+                        i64::from_le_bytes(chunk[0..8].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+                    let cumulative_bps =
+                        u16::from_le_bytes(chunk[8..10].try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+                    points.push(TranchePoint { timestamp, cumulative_bps });
+                }
+                Ok((VestingSchedule::Tranches { interpolation, points }, total_len))
+            }
             _ => Err(RewardsProgramError::InvalidScheduleType.into()),
         }
     }
@@ -204,8 +451,141 @@ fn calculate_linear_unlock(
     Ok(u64::try_from(result).map_err(|_| RewardsProgramError::MathOverflow)?)
 }
 
+/// Timestamp at which the final step of a stepped schedule unlocks.
+fn calculate_stepped_end(start_ts: i64, period_secs: u64, num_periods: u32) -> Result<i64, ProgramError> {
+    if period_secs == 0 || num_periods == 0 {
+        return Err(RewardsProgramError::InvalidTimeWindow.into());
+    }
+    let duration = period_secs.checked_mul(num_periods as u64).ok_or(RewardsProgramError::MathOverflow)?;
+    let duration = i64::try_from(duration).map_err(|_| RewardsProgramError::MathOverflow)?;
+    Ok(start_ts.checked_add(duration).ok_or(RewardsProgramError::MathOverflow)?)
+}
+
+/// Passes every period boundary after `after_ts` to `push`, erroring past `MAX_COMPARED_PERIODS`
+fn push_stepped_points(
+    start_ts: i64,
+    period_secs: u64,
+    num_periods: u32,
+    after_ts: i64,
+    push: &mut impl FnMut(i64),
+) -> Result<(), ProgramError> {
+    if period_secs == 0 || num_periods == 0 {
+        return Ok(());
+    }
+    let first_period = if after_ts < start_ts {
+        1
+    } else {
+        let elapsed = after_ts.checked_sub(start_ts).ok_or(RewardsProgramError::MathOverflow)? as u64;
+        elapsed / period_secs + 1
+    };
+    let num_periods = num_periods as u64;
+    if first_period > num_periods {
+        return Ok(());
+    }
+    if num_periods - first_period + 1 > MAX_COMPARED_PERIODS {
+        return Err(RewardsProgramError::TooManyScheduleSteps.into());
+    }
+    for period in first_period..=num_periods {
+        let offset = period.checked_mul(period_secs).ok_or(RewardsProgramError::MathOverflow)?;
+        let offset = i64::try_from(offset).map_err(|_| RewardsProgramError::MathOverflow)?;
+        push(start_ts.checked_add(offset).ok_or(RewardsProgramError::MathOverflow)?);
+    }
+    Ok(())
+}
+
+/// Unlocks `total_amount / num_periods` at the end of each completed period.
+/// The final step releases any rounding remainder.
+fn calculate_stepped_unlock(
+    total_amount: u64,
+    start_ts: i64,
+    period_secs: u64,
+    num_periods: u32,
+    current_ts: i64,
+) -> Result<u64, ProgramError> {
+    if current_ts <= start_ts {
+        return Ok(0);
+    }
+    if period_secs == 0 || num_periods == 0 {
+        return Ok(total_amount);
+    }
+
+    let elapsed = u64::try_from(current_ts.checked_sub(start_ts).ok_or(RewardsProgramError::MathOverflow)?)
+        .map_err(|_| RewardsProgramError::MathOverflow)?;
+    let completed_periods = (elapsed / period_secs).min(num_periods as u64);
+
+    let result = (total_amount as u128)
+        .checked_mul(completed_periods as u128)
+        .ok_or(RewardsProgramError::MathOverflow)?
+        .checked_div(num_periods as u128)
+        .ok_or(RewardsProgramError::MathOverflow)?;
+
+    Ok(u64::try_from(result).map_err(|_| RewardsProgramError::MathOverflow)?)
+}
+
+/// Points must be non-empty, strictly increasing in time, non-decreasing in
+/// `cumulative_bps`, and end at `TRANCHE_FULL_BPS`.
+fn validate_tranche_points(points: &[TranchePoint]) -> Result<(), ProgramError> {
+    let last = points.last().ok_or(RewardsProgramError::InvalidTrancheSchedule)?;
+    if points.len() > MAX_TRANCHE_POINTS || last.cumulative_bps != TRANCHE_FULL_BPS {
+        return Err(RewardsProgramError::InvalidTrancheSchedule.into());
+    }
+    for pair in points.windows(2) {
+        if pair[1].timestamp <= pair[0].timestamp || pair[1].cumulative_bps < pair[0].cumulative_bps {
+            return Err(RewardsProgramError::InvalidTrancheSchedule.into());
+        }
+    }
+    Ok(())
+}
+
+/// Unlocked amount for a tranche table. Nothing unlocks before the first point.
+///
+/// Merkle leaves are not validated on claim, so malformed points must not panic:
+/// out-of-order points fall back to the earlier point's share.
+fn calculate_tranche_unlock(
+    total_amount: u64,
+    interpolation: TrancheInterpolation,
+    points: &[TranchePoint],
+    current_ts: i64,
+) -> Result<u64, ProgramError> {
+    let reached = points.iter().rposition(|point| point.timestamp <= current_ts);
+    let Some(index) = reached else {
+        return Ok(0);
+    };
+
+    let current = &points[index];
+    let full_bps = TRANCHE_FULL_BPS as u128;
+    let current_bps = (current.cumulative_bps as u128).min(full_bps);
+
+    // Unlocked share scaled by full_bps * span, so linear interpolation stays exact until the final division
+    let (scaled_bps, span) = match (interpolation, points.get(index + 1)) {
+        (TrancheInterpolation::Linear {}, Some(next))
+            if next.timestamp > current.timestamp && next.cumulative_bps as u128 > current_bps =>
+        {
+            let next_bps = (next.cumulative_bps as u128).min(full_bps);
+            let span = next.timestamp.checked_sub(current.timestamp).ok_or(RewardsProgramError::MathOverflow)? as u128;
+            let elapsed = current_ts.checked_sub(current.timestamp).ok_or(RewardsProgramError::MathOverflow)? as u128;
+            let scaled = current_bps
+                .checked_mul(span)
+                .and_then(|v| v.checked_add((next_bps - current_bps).checked_mul(elapsed)?))
+                .ok_or(RewardsProgramError::MathOverflow)?;
+            (scaled, span)
+        }
+        _ => (current_bps, 1),
+    };
+
+    let result = (total_amount as u128)
+        .checked_mul(scaled_bps)
+        .ok_or(RewardsProgramError::MathOverflow)?
+        .checked_div(full_bps.checked_mul(span).ok_or(RewardsProgramError::MathOverflow)?)
+        .ok_or(RewardsProgramError::MathOverflow)?;
+
+    Ok(u64::try_from(result).map_err(|_| RewardsProgramError::MathOverflow)?)
+}
+
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
+
     use super::*;
 
     // --- Linear unlock helper tests ---
@@ -258,6 +638,132 @@ mod tests {
         assert_eq!(result, u64::MAX / 2);
     }
 
+    // --- Stepped unlock helper tests ---
+
+    #[test]
+    fn test_stepped_unlock_before_start() {
+        assert_eq!(calculate_stepped_unlock(1200, 100, 30, 12, 50).unwrap(), 0);
+        assert_eq!(calculate_stepped_unlock(1200, 100, 30, 12, 100).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_stepped_unlock_within_first_period() {
+        assert_eq!(calculate_stepped_unlock(1200, 100, 30, 12, 129).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_stepped_unlock_at_period_boundaries() {
+        assert_eq!(calculate_stepped_unlock(1200, 100, 30, 12, 130).unwrap(), 100);
+        assert_eq!(calculate_stepped_unlock(1200, 100, 30, 12, 159).unwrap(), 100);
+        assert_eq!(calculate_stepped_unlock(1200, 100, 30, 12, 160).unwrap(), 200);
+    }
+
+    #[test]
+    fn test_stepped_unlock_after_end() {
+        assert_eq!(calculate_stepped_unlock(1200, 100, 30, 12, 460).unwrap(), 1200);
+        assert_eq!(calculate_stepped_unlock(1200, 100, 30, 12, 9999).unwrap(), 1200);
+    }
+
+    #[test]
+    fn test_stepped_unlock_rounding_remainder_on_last_step() {
+        // 1000 / 3 = 333 per step, last step releases the remainder
+        assert_eq!(calculate_stepped_unlock(1000, 0, 10, 3, 10).unwrap(), 333);
+        assert_eq!(calculate_stepped_unlock(1000, 0, 10, 3, 20).unwrap(), 666);
+        assert_eq!(calculate_stepped_unlock(1000, 0, 10, 3, 30).unwrap(), 1000);
+    }
+
+    #[test]
+    fn test_stepped_unlock_large_amounts() {
+        assert_eq!(calculate_stepped_unlock(u64::MAX, 0, 10, 2, 10).unwrap(), u64::MAX / 2);
+    }
+
+    #[test]
+    fn test_stepped_end_overflow() {
+        assert!(calculate_stepped_end(i64::MAX - 10, 10, 2).is_err());
+        assert!(calculate_stepped_end(0, u64::MAX, 2).is_err());
+    }
+
+    // --- Tranche unlock helper tests ---
+
+    fn tge_tranches(interpolation: TrancheInterpolation) -> VestingSchedule {
+        // 10% at TGE (100), 25% at 400, 100% at 1000
+        VestingSchedule::Tranches {
+            interpolation,
+            points: vec![
+                TranchePoint { timestamp: 100, cumulative_bps: 1_000 },
+                TranchePoint { timestamp: 400, cumulative_bps: 2_500 },
+                TranchePoint { timestamp: 1000, cumulative_bps: 10_000 },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_tranche_unlock_before_first_point() {
+        let s = tge_tranches(TrancheInterpolation::Step {});
+        assert_eq!(s.calculate_unlocked(1000, 99).unwrap(), 0);
+        let s = tge_tranches(TrancheInterpolation::Linear {});
+        assert_eq!(s.calculate_unlocked(1000, 99).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_tranche_unlock_step() {
+        let s = tge_tranches(TrancheInterpolation::Step {});
+        assert_eq!(s.calculate_unlocked(1000, 100).unwrap(), 100);
+        assert_eq!(s.calculate_unlocked(1000, 399).unwrap(), 100);
+        assert_eq!(s.calculate_unlocked(1000, 400).unwrap(), 250);
+        assert_eq!(s.calculate_unlocked(1000, 999).unwrap(), 250);
+        assert_eq!(s.calculate_unlocked(1000, 1000).unwrap(), 1000);
+        assert_eq!(s.calculate_unlocked(1000, 5000).unwrap(), 1000);
+    }
+
+    #[test]
+    fn test_tranche_unlock_linear() {
+        let s = tge_tranches(TrancheInterpolation::Linear {});
+        assert_eq!(s.calculate_unlocked(1000, 100).unwrap(), 100);
+        // Halfway between 10% and 25%
+        assert_eq!(s.calculate_unlocked(1000, 250).unwrap(), 175);
+        assert_eq!(s.calculate_unlocked(1000, 400).unwrap(), 250);
+        // Halfway between 25% and 100%
+        assert_eq!(s.calculate_unlocked(1000, 700).unwrap(), 625);
+        assert_eq!(s.calculate_unlocked(1000, 1000).unwrap(), 1000);
+    }
+
+    #[test]
+    fn test_tranche_unlock_linear_flat_segment() {
+        // A flat segment followed by a jump expresses a step inside a linear schedule
+        let s = VestingSchedule::Tranches {
+            interpolation: TrancheInterpolation::Linear {},
+            points: vec![
+                TranchePoint { timestamp: 100, cumulative_bps: 1_000 },
+                TranchePoint { timestamp: 399, cumulative_bps: 1_000 },
+                TranchePoint { timestamp: 400, cumulative_bps: 2_500 },
+                TranchePoint { timestamp: 1000, cumulative_bps: 10_000 },
+            ],
+        };
+        assert_eq!(s.calculate_unlocked(1000, 250).unwrap(), 100);
+        assert_eq!(s.calculate_unlocked(1000, 400).unwrap(), 250);
+    }
+
+    #[test]
+    fn test_tranche_unlock_large_amounts() {
+        let s = tge_tranches(TrancheInterpolation::Linear {});
+        assert_eq!(s.calculate_unlocked(u64::MAX, 1000).unwrap(), u64::MAX);
+        assert_eq!(s.calculate_unlocked(u64::MAX, 400).unwrap(), u64::MAX / 4);
+    }
+
+    #[test]
+    fn test_tranche_unlock_unordered_points_does_not_panic() {
+        let s = VestingSchedule::Tranches {
+            interpolation: TrancheInterpolation::Linear {},
+            points: vec![
+                TranchePoint { timestamp: 500, cumulative_bps: 5_000 },
+                TranchePoint { timestamp: 100, cumulative_bps: 20_000 },
+            ],
+        };
+        assert!(s.calculate_unlocked(1000, 300).is_ok());
+        assert!(s.calculate_unlocked(1000, 600).unwrap() <= 1000);
+    }
+
     // --- validate ---
 
     #[test]
@@ -320,6 +826,127 @@ mod tests {
         assert!(VestingSchedule::CliffLinear { start_ts: 400, cliff_ts: 200, end_ts: 100 }.validate().is_err());
     }
 
+    #[test]
+    fn test_end_ts() {
+        assert_eq!(VestingSchedule::Immediate {}.end_ts().unwrap(), 0);
+        assert_eq!(VestingSchedule::Linear { start_ts: 100, end_ts: 200 }.end_ts().unwrap(), 200);
+        assert_eq!(VestingSchedule::Cliff { cliff_ts: 150 }.end_ts().unwrap(), 150);
+        assert_eq!(VestingSchedule::CliffLinear { start_ts: 100, cliff_ts: 150, end_ts: 300 }.end_ts().unwrap(), 300);
+        assert_eq!(VestingSchedule::Stepped { start_ts: 100, period_secs: 30, num_periods: 4 }.end_ts().unwrap(), 220);
+        assert_eq!(
+            VestingSchedule::CliffStepped { start_ts: 100, cliff_ts: 130, period_secs: 30, num_periods: 4 }
+                .end_ts()
+                .unwrap(),
+            220
+        );
+        let points = vec![
+            TranchePoint { timestamp: 100, cumulative_bps: 5_000 },
+            TranchePoint { timestamp: 400, cumulative_bps: TRANCHE_FULL_BPS },
+        ];
+        assert_eq!(
+            VestingSchedule::Tranches { interpolation: TrancheInterpolation::Step {}, points }.end_ts().unwrap(),
+            400
+        );
+    }
+
+    #[test]
+    fn test_validate_stepped_valid() {
+        assert!(VestingSchedule::Stepped { start_ts: 100, period_secs: 30, num_periods: 12 }.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_stepped_zero_period() {
+        assert!(VestingSchedule::Stepped { start_ts: 100, period_secs: 0, num_periods: 12 }.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_stepped_zero_periods() {
+        assert!(VestingSchedule::Stepped { start_ts: 100, period_secs: 30, num_periods: 0 }.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_stepped_overflow() {
+        assert!(VestingSchedule::Stepped { start_ts: 0, period_secs: u64::MAX, num_periods: 2 }.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_cliff_stepped_valid() {
+        let s = VestingSchedule::CliffStepped { start_ts: 0, cliff_ts: 90, period_secs: 30, num_periods: 12 };
+        assert!(s.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_cliff_stepped_cliff_at_end() {
+        let s = VestingSchedule::CliffStepped { start_ts: 0, cliff_ts: 360, period_secs: 30, num_periods: 12 };
+        assert!(s.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_cliff_stepped_cliff_before_start() {
+        let s = VestingSchedule::CliffStepped { start_ts: 100, cliff_ts: 50, period_secs: 30, num_periods: 12 };
+        assert!(s.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_cliff_stepped_cliff_after_end() {
+        let s = VestingSchedule::CliffStepped { start_ts: 0, cliff_ts: 361, period_secs: 30, num_periods: 12 };
+        assert!(s.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_tranches_valid() {
+        assert!(tge_tranches(TrancheInterpolation::Step {}).validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_tranches_empty() {
+        let s = VestingSchedule::Tranches { interpolation: TrancheInterpolation::Step {}, points: vec![] };
+        assert!(s.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_tranches_not_ending_at_full() {
+        let s = VestingSchedule::Tranches {
+            interpolation: TrancheInterpolation::Step {},
+            points: vec![TranchePoint { timestamp: 100, cumulative_bps: 9_999 }],
+        };
+        assert!(s.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_tranches_non_increasing_timestamps() {
+        let s = VestingSchedule::Tranches {
+            interpolation: TrancheInterpolation::Step {},
+            points: vec![
+                TranchePoint { timestamp: 100, cumulative_bps: 1_000 },
+                TranchePoint { timestamp: 100, cumulative_bps: 10_000 },
+            ],
+        };
+        assert!(s.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_tranches_decreasing_bps() {
+        let s = VestingSchedule::Tranches {
+            interpolation: TrancheInterpolation::Step {},
+            points: vec![
+                TranchePoint { timestamp: 100, cumulative_bps: 5_000 },
+                TranchePoint { timestamp: 200, cumulative_bps: 4_000 },
+                TranchePoint { timestamp: 300, cumulative_bps: 10_000 },
+            ],
+        };
+        assert!(s.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_tranches_too_many_points() {
+        let mut points: Vec<TranchePoint> =
+            (0..MAX_TRANCHE_POINTS as i64).map(|i| TranchePoint { timestamp: i + 1, cumulative_bps: 100 }).collect();
+        points.push(TranchePoint { timestamp: 1000, cumulative_bps: 10_000 });
+        let s = VestingSchedule::Tranches { interpolation: TrancheInterpolation::Step {}, points };
+        assert!(s.validate().is_err());
+    }
+
     // --- calculate_unlocked: Immediate ---
 
     #[test]
@@ -427,6 +1054,44 @@ mod tests {
         assert_eq!(s.calculate_unlocked(1000, 200).unwrap(), 1000);
     }
 
+    // --- calculate_unlocked: Stepped ---
+
+    #[test]
+    fn test_unlocked_stepped_quarterly() {
+        // 4 quarterly steps of 90s starting at 100
+        let s = VestingSchedule::Stepped { start_ts: 100, period_secs: 90, num_periods: 4 };
+        assert_eq!(s.calculate_unlocked(1000, 100).unwrap(), 0);
+        assert_eq!(s.calculate_unlocked(1000, 189).unwrap(), 0);
+        assert_eq!(s.calculate_unlocked(1000, 190).unwrap(), 250);
+        assert_eq!(s.calculate_unlocked(1000, 370).unwrap(), 750);
+        assert_eq!(s.calculate_unlocked(1000, 460).unwrap(), 1000);
+    }
+
+    // --- calculate_unlocked: CliffStepped ---
+
+    #[test]
+    fn test_unlocked_cliff_stepped_before_cliff() {
+        // Monthly steps of 30s, cliff at 90 (3 steps accrued)
+        let s = VestingSchedule::CliffStepped { start_ts: 0, cliff_ts: 90, period_secs: 30, num_periods: 12 };
+        assert_eq!(s.calculate_unlocked(1200, 30).unwrap(), 0);
+        assert_eq!(s.calculate_unlocked(1200, 89).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_unlocked_cliff_stepped_at_cliff() {
+        // Steps accrued before the cliff become claimable at the cliff
+        let s = VestingSchedule::CliffStepped { start_ts: 0, cliff_ts: 90, period_secs: 30, num_periods: 12 };
+        assert_eq!(s.calculate_unlocked(1200, 90).unwrap(), 300);
+    }
+
+    #[test]
+    fn test_unlocked_cliff_stepped_after_cliff() {
+        let s = VestingSchedule::CliffStepped { start_ts: 0, cliff_ts: 90, period_secs: 30, num_periods: 12 };
+        assert_eq!(s.calculate_unlocked(1200, 119).unwrap(), 300);
+        assert_eq!(s.calculate_unlocked(1200, 120).unwrap(), 400);
+        assert_eq!(s.calculate_unlocked(1200, 360).unwrap(), 1200);
+    }
+
     // --- to_bytes / from_bytes roundtrip ---
 
     #[test]
@@ -450,6 +1115,18 @@ mod tests {
         assert_eq!(consumed, 17);
     }
 
+    #[test]
+    fn test_unlock_points_cliff_stepped_skips_steps_before_cliff() {
+        let schedule = VestingSchedule::CliffStepped { start_ts: 0, cliff_ts: 250, period_secs: 100, num_periods: 5 };
+        let mut points = Vec::new();
+        schedule.unlock_points(0, &mut points).unwrap();
+        assert_eq!(points, vec![250, 300, 400, 500]);
+
+        let mut points = Vec::new();
+        schedule.unlock_points(400, &mut points).unwrap();
+        assert_eq!(points, vec![500]);
+    }
+
     #[test]
     fn test_bytes_roundtrip_cliff() {
         let s = VestingSchedule::Cliff { cliff_ts: 150 };
@@ -472,6 +1149,63 @@ mod tests {
         assert_eq!(consumed, 25);
     }
 
+    #[test]
+    fn test_bytes_roundtrip_stepped() {
+        let s = VestingSchedule::Stepped { start_ts: 100, period_secs: 2_592_000, num_periods: 12 };
+        let bytes = s.to_bytes();
+        assert_eq!(bytes.len(), 21);
+        assert_eq!(bytes[0], 4);
+        let (parsed, consumed) = VestingSchedule::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, s);
+        assert_eq!(consumed, 21);
+    }
+
+    #[test]
+    fn test_bytes_roundtrip_cliff_stepped() {
+        let s = VestingSchedule::CliffStepped { start_ts: 100, cliff_ts: 200, period_secs: 50, num_periods: 8 };
+        let bytes = s.to_bytes();
+        assert_eq!(bytes.len(), 29);
+        assert_eq!(bytes[0], 5);
+        let (parsed, consumed) = VestingSchedule::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, s);
+        assert_eq!(consumed, 29);
+    }
+
+    #[test]
+    fn test_bytes_roundtrip_tranches() {
+        let s = tge_tranches(TrancheInterpolation::Linear {});
+        let bytes = s.to_bytes();
+        assert_eq!(bytes.len(), TRANCHES_HEADER_LEN + 3 * TranchePoint::LEN);
+        assert_eq!(bytes[0], 6);
+        assert_eq!(bytes[1], 1);
+        assert_eq!(&bytes[2..6], &3u32.to_le_bytes());
+        let (parsed, consumed) = VestingSchedule::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, s);
+        assert_eq!(consumed, bytes.len());
+    }
+
+    #[test]
+    fn test_from_bytes_tranches_too_many_points() {
+        let mut bytes = vec![6, 0];
+        bytes.extend_from_slice(&(MAX_TRANCHE_POINTS as u32 + 1).to_le_bytes());
+        bytes.extend_from_slice(&[0u8; (MAX_TRANCHE_POINTS + 1) * TranchePoint::LEN]);
+        assert!(VestingSchedule::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_from_bytes_tranches_invalid_interpolation() {
+        let mut bytes = tge_tranches(TrancheInterpolation::Step {}).to_bytes();
+        bytes[1] = 2;
+        assert!(VestingSchedule::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_from_bytes_truncated_tranches() {
+        let mut bytes = tge_tranches(TrancheInterpolation::Step {}).to_bytes();
+        bytes.truncate(bytes.len() - 1);
+        assert!(VestingSchedule::from_bytes(&bytes).is_err());
+    }
+
     #[test]
     fn test_from_bytes_empty() {
         assert!(VestingSchedule::from_bytes(&[]).is_err());
@@ -479,7 +1213,7 @@ mod tests {
 
     #[test]
     fn test_from_bytes_invalid_discriminant() {
-        assert!(VestingSchedule::from_bytes(&[4]).is_err());
+        assert!(VestingSchedule::from_bytes(&[7]).is_err());
         assert!(VestingSchedule::from_bytes(&[255]).is_err());
     }
 
@@ -497,6 +1231,14 @@ mod tests {
         assert!(VestingSchedule::from_bytes(&bytes).is_err());
     }
 
+    #[test]
+    fn test_from_bytes_truncated_cliff_stepped() {
+        let mut bytes =
+            VestingSchedule::CliffStepped { start_ts: 0, cliff_ts: 100, period_secs: 50, num_periods: 8 }.to_bytes();
+        bytes.truncate(26); // cut off num_periods
+        assert!(VestingSchedule::from_bytes(&bytes).is_err());
+    }
+
     #[test]
     fn test_write_bytes_matches_to_bytes() {
         let schedules = [
@@ -504,10 +1246,14 @@ mod tests {
             VestingSchedule::Linear { start_ts: 100, end_ts: 200 },
             VestingSchedule::Cliff { cliff_ts: 150 },
             VestingSchedule::CliffLinear { start_ts: 100, cliff_ts: 200, end_ts: 400 },
+            VestingSchedule::Stepped { start_ts: 100, period_secs: 50, num_periods: 8 },
+            VestingSchedule::CliffStepped { start_ts: 100, cliff_ts: 200, period_secs: 50, num_periods: 8 },
+            tge_tranches(TrancheInterpolation::Linear {}),
         ];
         for s in schedules {
             let vec_bytes = s.to_bytes();
-            let mut buf = [0u8; 25];
+            assert_eq!(vec_bytes.len(), s.byte_len());
+            let mut buf = [0u8; VestingSchedule::MAX_BYTE_LEN];
             let written = s.write_bytes(&mut buf);
             assert_eq!(&buf[..written], &vec_bytes[..]);
         }
@@ -0,0 +1,78 @@
+use rewards_program_client::instructions::AcceptAuthorityBuilder;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::ProposeAuthoritySetup;
+use crate::utils::{find_event_authority_pda, InstructionTestFixture, TestContext, TestInstruction};
+
+pub struct AcceptAuthoritySetup {
+    pub propose_setup: ProposeAuthoritySetup,
+}
+
+impl AcceptAuthoritySetup {
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let propose_setup = ProposeAuthoritySetup::new(ctx);
+        Self::from_propose_setup(ctx, propose_setup)
+    }
+
+    pub fn new_merkle(ctx: &mut TestContext) -> Self {
+        let propose_setup = ProposeAuthoritySetup::new_merkle(ctx);
+        Self::from_propose_setup(ctx, propose_setup)
+    }
+
+    pub fn from_propose_setup(ctx: &mut TestContext, propose_setup: ProposeAuthoritySetup) -> Self {
+        let propose_ix = propose_setup.build_instruction(ctx);
+        propose_ix.send_expect_success(ctx);
+        Self { propose_setup }
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with_signer(&self.propose_setup.new_authority)
+    }
+
+    pub fn build_instruction_with_signer(&self, new_authority: &Keypair) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = AcceptAuthorityBuilder::new();
+        builder
+            .new_authority(new_authority.pubkey())
+            .distribution(self.propose_setup.distribution_pda)
+            .event_authority(event_authority);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![new_authority.insecure_clone()],
+            name: "AcceptAuthority",
+        }
+    }
+}
+
+pub struct AcceptAuthorityFixture;
+
+impl InstructionTestFixture for AcceptAuthorityFixture {
+    const INSTRUCTION_NAME: &'static str = "AcceptAuthority";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = AcceptAuthoritySetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: new_authority
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 1: distribution
+    fn required_writable() -> &'static [usize] {
+        &[1]
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(3)
+    }
+
+    fn data_len() -> usize {
+        1 // discriminator only
+    }
+}
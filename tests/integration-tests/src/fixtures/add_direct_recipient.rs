@@ -78,6 +78,8 @@ impl AddDirectRecipientSetup {
             VestingSchedule::Linear { start_ts, .. } => *start_ts,
             VestingSchedule::Cliff { .. } => 0,
             VestingSchedule::CliffLinear { start_ts, .. } => *start_ts,
+            VestingSchedule::Stepped { start_ts, .. } | VestingSchedule::CliffStepped { start_ts, .. } => *start_ts,
+            VestingSchedule::Tranches { points, .. } => points.first().map_or(0, |point| point.timestamp),
         }
     }
 
@@ -87,6 +89,11 @@ impl AddDirectRecipientSetup {
             VestingSchedule::Linear { end_ts, .. } => *end_ts,
             VestingSchedule::Cliff { cliff_ts } => *cliff_ts,
             VestingSchedule::CliffLinear { end_ts, .. } => *end_ts,
+            VestingSchedule::Stepped { start_ts, period_secs, num_periods }
+            | VestingSchedule::CliffStepped { start_ts, period_secs, num_periods, .. } => {
+                *start_ts + (*period_secs * *num_periods as u64) as i64
+            }
+            VestingSchedule::Tranches { points, .. } => points.last().map_or(0, |point| point.timestamp),
         }
     }
 
@@ -0,0 +1,234 @@
+use rewards_program_client::{
+    instructions::AddDirectRecipientsBuilder,
+    types::{DirectRecipientEntry, VestingSchedule},
+};
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use spl_token_interface::ID as TOKEN_PROGRAM_ID;
+
+use crate::fixtures::{CreateDirectDistributionSetup, DEFAULT_RECIPIENT_AMOUNT};
+use crate::utils::{
+    find_direct_recipient_pda, find_event_authority_pda, InstructionTestFixture, TestContext, TestInstruction,
+};
+
+pub const DEFAULT_BATCH_SIZE: usize = 3;
+
+pub struct BatchRecipient {
+    pub recipient: Keypair,
+    pub recipient_pda: Pubkey,
+    pub recipient_bump: u8,
+    pub amount: u64,
+}
+
+pub struct AddDirectRecipientsSetup {
+    pub authority: Keypair,
+    pub distribution_pda: Pubkey,
+    pub recipients: Vec<BatchRecipient>,
+    pub schedule: VestingSchedule,
+    pub token_program: Pubkey,
+    pub mint: Pubkey,
+    pub distribution_vault: Pubkey,
+    pub authority_token_account: Pubkey,
+}
+
+impl AddDirectRecipientsSetup {
+    pub fn builder(ctx: &mut TestContext) -> AddDirectRecipientsSetupBuilder<'_> {
+        AddDirectRecipientsSetupBuilder::new(ctx)
+    }
+
+    pub fn new(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).build()
+    }
+
+    pub fn new_token_2022(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).token_2022().build()
+    }
+
+    pub fn total_amount(&self) -> u64 {
+        self.recipients.iter().map(|r| r.amount).sum()
+    }
+
+    pub fn entries(&self) -> Vec<DirectRecipientEntry> {
+        self.recipients
+            .iter()
+            .map(|r| DirectRecipientEntry { bump: r.recipient_bump, amount: r.amount, schedule: self.schedule.clone() })
+            .collect()
+    }
+
+    pub fn remaining_accounts(&self) -> Vec<AccountMeta> {
+        self.recipients
+            .iter()
+            .flat_map(|r| {
+                [AccountMeta::new(r.recipient_pda, false), AccountMeta::new_readonly(r.recipient.pubkey(), false)]
+            })
+            .collect()
+    }
+
+    pub fn build_instruction(&self, ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with(ctx, &self.authority, self.entries(), self.remaining_accounts())
+    }
+
+    pub fn build_instruction_with_wrong_authority(
+        &self,
+        ctx: &TestContext,
+        wrong_authority: &Keypair,
+    ) -> TestInstruction {
+        self.build_instruction_with(ctx, wrong_authority, self.entries(), self.remaining_accounts())
+    }
+
+    pub fn build_instruction_with(
+        &self,
+        ctx: &TestContext,
+        authority: &Keypair,
+        entries: Vec<DirectRecipientEntry>,
+        remaining_accounts: Vec<AccountMeta>,
+    ) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = AddDirectRecipientsBuilder::new();
+        builder
+            .payer(ctx.payer.pubkey())
+            .authority(authority.pubkey())
+            .distribution(self.distribution_pda)
+            .mint(self.mint)
+            .distribution_vault(self.distribution_vault)
+            .authority_token_account(self.authority_token_account)
+            .token_program(self.token_program)
+            .event_authority(event_authority)
+            .recipients(entries)
+            .add_remaining_accounts(&remaining_accounts);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![authority.insecure_clone()],
+            name: "AddDirectRecipients",
+        }
+    }
+}
+
+pub struct AddDirectRecipientsSetupBuilder<'a> {
+    ctx: &'a mut TestContext,
+    token_program: Pubkey,
+    amount: u64,
+    num_recipients: usize,
+    schedule: Option<VestingSchedule>,
+}
+
+impl<'a> AddDirectRecipientsSetupBuilder<'a> {
+    fn new(ctx: &'a mut TestContext) -> Self {
+        Self {
+            ctx,
+            token_program: TOKEN_PROGRAM_ID,
+            amount: DEFAULT_RECIPIENT_AMOUNT,
+            num_recipients: DEFAULT_BATCH_SIZE,
+            schedule: None,
+        }
+    }
+
+    pub fn token_2022(mut self) -> Self {
+        self.token_program = TOKEN_2022_PROGRAM_ID;
+        self
+    }
+
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = amount;
+        self
+    }
+
+    pub fn num_recipients(mut self, num: usize) -> Self {
+        self.num_recipients = num;
+        self
+    }
+
+    pub fn schedule(mut self, schedule: VestingSchedule) -> Self {
+        self.schedule = Some(schedule);
+        self
+    }
+
+    pub fn build(self) -> AddDirectRecipientsSetup {
+        let mut distribution_builder = CreateDirectDistributionSetup::builder(self.ctx);
+        if self.token_program == TOKEN_2022_PROGRAM_ID {
+            distribution_builder = distribution_builder.token_2022();
+        }
+        let distribution_setup = distribution_builder.build();
+
+        let instruction = distribution_setup.build_instruction(self.ctx);
+        instruction.send_expect_success(self.ctx);
+
+        let recipients = (0..self.num_recipients)
+            .map(|_| {
+                let recipient = Keypair::new();
+                let (recipient_pda, recipient_bump) =
+                    find_direct_recipient_pda(&distribution_setup.distribution_pda, &recipient.pubkey());
+                BatchRecipient { recipient, recipient_pda, recipient_bump, amount: self.amount }
+            })
+            .collect::<Vec<_>>();
+
+        let current_ts = self.ctx.get_current_timestamp();
+        let schedule =
+            self.schedule.unwrap_or(VestingSchedule::Linear { start_ts: current_ts, end_ts: current_ts + 86400 * 365 });
+
+        let authority_token_account = self.ctx.create_ata_for_program_with_balance(
+            &distribution_setup.authority.pubkey(),
+            &distribution_setup.mint.pubkey(),
+            self.amount * self.num_recipients as u64,
+            &self.token_program,
+        );
+
+        AddDirectRecipientsSetup {
+            authority: distribution_setup.authority,
+            distribution_pda: distribution_setup.distribution_pda,
+            recipients,
+            schedule,
+            token_program: self.token_program,
+            mint: distribution_setup.mint.pubkey(),
+            distribution_vault: distribution_setup.distribution_vault,
+            authority_token_account,
+        }
+    }
+}
+
+pub struct AddDirectRecipientsFixture;
+
+impl InstructionTestFixture for AddDirectRecipientsFixture {
+    const INSTRUCTION_NAME: &'static str = "AddDirectRecipients";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = AddDirectRecipientsSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: payer (handled by TestContext)
+    /// 1: authority
+    fn required_signers() -> &'static [usize] {
+        &[0, 1]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: payer (handled by TestContext)
+    /// 2: distribution
+    /// 4: distribution_vault
+    /// 5: authority_token_account
+    /// 10: first recipient_account (remaining accounts)
+    fn required_writable() -> &'static [usize] {
+        &[0, 2, 4, 5, 10]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(6)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(9)
+    }
+
+    fn data_len() -> usize {
+        // discriminator(1) + count(4) + 3 * (bump(1) + amount(8) + Linear schedule(17))
+        1 + 4 + DEFAULT_BATCH_SIZE * (1 + 8 + 17)
+    }
+}
@@ -0,0 +1,336 @@
+use rewards_program_client::{
+    instructions::{
+        AddBasketRecipientBuilder, ClaimBasketBuilder, CloseBasketDistributionBuilder, CloseBasketRecipientBuilder,
+        CreateBasketDistributionBuilder,
+    },
+    types::VestingSchedule,
+};
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use spl_token_interface::ID as TOKEN_PROGRAM_ID;
+
+use crate::fixtures::DEFAULT_RECIPIENT_AMOUNT;
+use crate::utils::{
+    find_basket_distribution_pda, find_basket_recipient_pda, find_event_authority_pda, InstructionTestFixture,
+    TestContext, TestInstruction,
+};
+
+/// One mint of a basket, with the authority's funding account
+pub struct BasketMint {
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub distribution_vault: Pubkey,
+    pub authority_token_account: Pubkey,
+}
+
+/// A basket distribution over an SPL Token mint and a Token-2022 mint
+pub struct BasketSetup {
+    pub authority: Keypair,
+    pub seed: Keypair,
+    pub distribution_pda: Pubkey,
+    pub bump: u8,
+    pub clawback_ts: i64,
+    pub mints: Vec<BasketMint>,
+}
+
+/// A basket recipient and the amounts allocated to them, one per basket mint
+pub struct BasketRecipientSetup {
+    pub recipient: Keypair,
+    pub recipient_pda: Pubkey,
+    pub recipient_bump: u8,
+    pub amounts: Vec<u64>,
+    pub schedule: VestingSchedule,
+    pub recipient_token_accounts: Vec<Pubkey>,
+}
+
+impl BasketSetup {
+    pub fn new(ctx: &mut TestContext) -> Self {
+        Self::with_token_programs(ctx, &[TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID])
+    }
+
+    /// One fresh mint per token program, in basket slot order
+    pub fn with_token_programs(ctx: &mut TestContext, token_programs: &[Pubkey]) -> Self {
+        let authority = ctx.create_funded_keypair();
+        let seed = Keypair::new();
+        let (distribution_pda, bump) = find_basket_distribution_pda(&authority.pubkey(), &seed.pubkey());
+
+        let mints = token_programs
+            .iter()
+            .map(|token_program| {
+                let mint = Keypair::new();
+                ctx.create_mint_for_program(&mint, &ctx.payer.pubkey(), 6, token_program);
+                let distribution_vault = spl_associated_token_account::get_associated_token_address_with_program_id(
+                    &distribution_pda,
+                    &mint.pubkey(),
+                    token_program,
+                );
+                let authority_token_account = ctx.create_ata_for_program_with_balance(
+                    &authority.pubkey(),
+                    &mint.pubkey(),
+                    DEFAULT_RECIPIENT_AMOUNT * 10,
+                    token_program,
+                );
+                BasketMint {
+                    mint: mint.pubkey(),
+                    token_program: *token_program,
+                    distribution_vault,
+                    authority_token_account,
+                }
+            })
+            .collect();
+
+        Self { authority, seed, distribution_pda, bump, clawback_ts: 0, mints }
+    }
+
+    /// Create the basket and its vaults
+    pub fn create(ctx: &mut TestContext) -> Self {
+        let setup = Self::new(ctx);
+        setup.build_create_instruction(ctx).send_expect_success(ctx);
+        setup
+    }
+
+    pub fn build_create_instruction(&self, ctx: &TestContext) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+        let legs: Vec<AccountMeta> = self
+            .mints
+            .iter()
+            .flat_map(|mint| {
+                [
+                    AccountMeta::new_readonly(mint.mint, false),
+                    AccountMeta::new(mint.distribution_vault, false),
+                    AccountMeta::new_readonly(mint.token_program, false),
+                ]
+            })
+            .collect();
+
+        let mut builder = CreateBasketDistributionBuilder::new();
+        builder
+            .payer(ctx.payer.pubkey())
+            .authority(self.authority.pubkey())
+            .seeds(self.seed.pubkey())
+            .distribution(self.distribution_pda)
+            .event_authority(event_authority)
+            .bump(self.bump)
+            .clawback_ts(self.clawback_ts)
+            .add_remaining_accounts(&legs);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![self.authority.insecure_clone(), self.seed.insecure_clone()],
+            name: "CreateBasketDistribution",
+        }
+    }
+
+    /// `[mint, distribution_vault, token_account, token_program]` per basket mint
+    pub fn leg_accounts(&self, token_accounts: &[Pubkey]) -> Vec<AccountMeta> {
+        self.mints
+            .iter()
+            .zip(token_accounts)
+            .flat_map(|(mint, token_account)| {
+                [
+                    AccountMeta::new_readonly(mint.mint, false),
+                    AccountMeta::new(mint.distribution_vault, false),
+                    AccountMeta::new(*token_account, false),
+                    AccountMeta::new_readonly(mint.token_program, false),
+                ]
+            })
+            .collect()
+    }
+
+    pub fn authority_token_accounts(&self) -> Vec<Pubkey> {
+        self.mints.iter().map(|mint| mint.authority_token_account).collect()
+    }
+
+    /// Recipient allocated `DEFAULT_RECIPIENT_AMOUNT` scaled by slot (1x, 2x, ...) of each mint
+    pub fn recipient_setup(&self, ctx: &mut TestContext, schedule: VestingSchedule) -> BasketRecipientSetup {
+        let amounts = (1..=self.mints.len() as u64).map(|slot| DEFAULT_RECIPIENT_AMOUNT * slot).collect();
+        self.recipient_setup_with_amounts(ctx, schedule, amounts)
+    }
+
+    pub fn recipient_setup_with_amounts(
+        &self,
+        ctx: &mut TestContext,
+        schedule: VestingSchedule,
+        amounts: Vec<u64>,
+    ) -> BasketRecipientSetup {
+        let recipient = ctx.create_funded_keypair();
+        let (recipient_pda, recipient_bump) = find_basket_recipient_pda(&self.distribution_pda, &recipient.pubkey());
+        let recipient_token_accounts = self
+            .mints
+            .iter()
+            .map(|mint| ctx.create_ata_for_program(&recipient.pubkey(), &mint.mint, &mint.token_program))
+            .collect();
+
+        BasketRecipientSetup { recipient, recipient_pda, recipient_bump, amounts, schedule, recipient_token_accounts }
+    }
+
+    pub fn build_add_recipient_instruction(
+        &self,
+        ctx: &TestContext,
+        recipient_setup: &BasketRecipientSetup,
+    ) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = AddBasketRecipientBuilder::new();
+        builder
+            .payer(ctx.payer.pubkey())
+            .authority(self.authority.pubkey())
+            .distribution(self.distribution_pda)
+            .recipient_account(recipient_setup.recipient_pda)
+            .recipient(recipient_setup.recipient.pubkey())
+            .event_authority(event_authority)
+            .bump(recipient_setup.recipient_bump)
+            .amounts(recipient_setup.amounts.clone())
+            .schedule(recipient_setup.schedule.clone())
+            .add_remaining_accounts(&self.leg_accounts(&self.authority_token_accounts()));
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![self.authority.insecure_clone()],
+            name: "AddBasketRecipient",
+        }
+    }
+
+    pub fn build_claim_instruction(&self, recipient_setup: &BasketRecipientSetup) -> TestInstruction {
+        self.build_claim_instruction_with(recipient_setup, self.leg_accounts(&recipient_setup.recipient_token_accounts))
+    }
+
+    pub fn build_claim_instruction_with(
+        &self,
+        recipient_setup: &BasketRecipientSetup,
+        legs: Vec<AccountMeta>,
+    ) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = ClaimBasketBuilder::new();
+        builder
+            .recipient(recipient_setup.recipient.pubkey())
+            .distribution(self.distribution_pda)
+            .recipient_account(recipient_setup.recipient_pda)
+            .event_authority(event_authority)
+            .add_remaining_accounts(&legs);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![recipient_setup.recipient.insecure_clone()],
+            name: "ClaimBasket",
+        }
+    }
+
+    pub fn build_close_instruction(&self) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = CloseBasketDistributionBuilder::new();
+        builder
+            .authority(self.authority.pubkey())
+            .distribution(self.distribution_pda)
+            .event_authority(event_authority)
+            .add_remaining_accounts(&self.leg_accounts(&self.authority_token_accounts()));
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![self.authority.insecure_clone()],
+            name: "CloseBasketDistribution",
+        }
+    }
+
+    pub fn build_close_recipient_instruction(
+        &self,
+        recipient_setup: &BasketRecipientSetup,
+        original_payer: Pubkey,
+    ) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = CloseBasketRecipientBuilder::new();
+        builder
+            .recipient(recipient_setup.recipient.pubkey())
+            .original_payer(original_payer)
+            .distribution(self.distribution_pda)
+            .recipient_account(recipient_setup.recipient_pda)
+            .event_authority(event_authority);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![recipient_setup.recipient.insecure_clone()],
+            name: "CloseBasketRecipient",
+        }
+    }
+}
+
+pub struct CreateBasketDistributionFixture;
+
+impl InstructionTestFixture for CreateBasketDistributionFixture {
+    const INSTRUCTION_NAME: &'static str = "CreateBasketDistribution";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = BasketSetup::new(ctx);
+        setup.build_create_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: payer
+    /// 1: authority
+    /// 2: seeds
+    fn required_signers() -> &'static [usize] {
+        &[0, 1, 2]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: payer
+    /// 3: distribution
+    /// 9: first distribution_vault
+    fn required_writable() -> &'static [usize] {
+        &[0, 3, 9]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(4)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(7)
+    }
+
+    fn data_len() -> usize {
+        1 + 1 + 8 // discriminator + bump + clawback_ts
+    }
+}
+
+pub struct ClaimBasketFixture;
+
+impl InstructionTestFixture for ClaimBasketFixture {
+    const INSTRUCTION_NAME: &'static str = "ClaimBasket";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = BasketSetup::create(ctx);
+        let recipient_setup = setup.recipient_setup(ctx, VestingSchedule::Immediate {});
+        setup.build_add_recipient_instruction(ctx, &recipient_setup).send_expect_success(ctx);
+        setup.build_claim_instruction(&recipient_setup)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: recipient
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 1: distribution
+    /// 2: recipient_account
+    /// 6, 7: first distribution_vault, recipient_token_account
+    fn required_writable() -> &'static [usize] {
+        &[1, 2, 6, 7]
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(4)
+    }
+
+    fn data_len() -> usize {
+        1 // discriminator
+    }
+}
@@ -0,0 +1,69 @@
+use rewards_program_client::instructions::CancelAuthorityProposalBuilder;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::ProposeAuthoritySetup;
+use crate::utils::{InstructionTestFixture, TestContext, TestInstruction};
+
+pub struct CancelAuthorityProposalSetup {
+    pub propose_setup: ProposeAuthoritySetup,
+}
+
+impl CancelAuthorityProposalSetup {
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let propose_setup = ProposeAuthoritySetup::new(ctx);
+        Self::from_propose_setup(ctx, propose_setup)
+    }
+
+    pub fn new_merkle(ctx: &mut TestContext) -> Self {
+        let propose_setup = ProposeAuthoritySetup::new_merkle(ctx);
+        Self::from_propose_setup(ctx, propose_setup)
+    }
+
+    pub fn from_propose_setup(ctx: &mut TestContext, propose_setup: ProposeAuthoritySetup) -> Self {
+        let propose_ix = propose_setup.build_instruction(ctx);
+        propose_ix.send_expect_success(ctx);
+        Self { propose_setup }
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with_authority(&self.propose_setup.authority)
+    }
+
+    pub fn build_instruction_with_authority(&self, authority: &Keypair) -> TestInstruction {
+        let mut builder = CancelAuthorityProposalBuilder::new();
+        builder.authority(authority.pubkey()).distribution(self.propose_setup.distribution_pda);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![authority.insecure_clone()],
+            name: "CancelAuthorityProposal",
+        }
+    }
+}
+
+pub struct CancelAuthorityProposalFixture;
+
+impl InstructionTestFixture for CancelAuthorityProposalFixture {
+    const INSTRUCTION_NAME: &'static str = "CancelAuthorityProposal";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = CancelAuthorityProposalSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: authority
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 1: distribution
+    fn required_writable() -> &'static [usize] {
+        &[1]
+    }
+
+    fn data_len() -> usize {
+        1 // discriminator only
+    }
+}
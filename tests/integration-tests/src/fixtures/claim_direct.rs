@@ -3,10 +3,10 @@ use solana_sdk::{
     pubkey::Pubkey,
     signature::{Keypair, Signer},
 };
-use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use spl_token_2022::{extension::ExtensionType, ID as TOKEN_2022_PROGRAM_ID};
 use spl_token_interface::ID as TOKEN_PROGRAM_ID;
 
-use rewards_program_client::types::VestingSchedule;
+use rewards_program_client::types::{TransferFeeMode, VestingSchedule};
 
 use crate::fixtures::{AddDirectRecipientSetup, CreateDirectDistributionSetup, DEFAULT_RECIPIENT_AMOUNT};
 use crate::utils::{
@@ -90,7 +90,8 @@ impl ClaimDirectSetup {
             .recipient_token_account(self.recipient_token_account)
             .token_program(self.token_program)
             .event_authority(event_authority)
-            .amount(claim_amount);
+            .amount(claim_amount)
+            .fee_mode(TransferFeeMode::Gross);
 
         TestInstruction {
             instruction: builder.instruction(),
@@ -118,7 +119,8 @@ impl ClaimDirectSetup {
             .recipient_token_account(wrong_token_account)
             .token_program(self.token_program)
             .event_authority(event_authority)
-            .amount(0);
+            .amount(0)
+            .fee_mode(TransferFeeMode::Gross);
 
         TestInstruction {
             instruction: builder.instruction(),
@@ -145,7 +147,8 @@ impl ClaimDirectSetup {
             .recipient_token_account(wrong_signer_token_account)
             .token_program(self.token_program)
             .event_authority(event_authority)
-            .amount(0);
+            .amount(0)
+            .fee_mode(TransferFeeMode::Gross);
 
         TestInstruction {
             instruction: builder.instruction(),
@@ -160,6 +163,8 @@ pub struct ClaimDirectSetupBuilder<'a> {
     token_program: Pubkey,
     amount: u64,
     schedule: Option<VestingSchedule>,
+    claim_window: (i64, i64),
+    mint_extension: Option<ExtensionType>,
     warp_to_end: bool,
 }
 
@@ -170,6 +175,8 @@ impl<'a> ClaimDirectSetupBuilder<'a> {
             token_program: TOKEN_PROGRAM_ID,
             amount: DEFAULT_RECIPIENT_AMOUNT,
             schedule: None,
+            claim_window: (0, 0),
+            mint_extension: None,
             warp_to_end: true,
         }
     }
@@ -194,14 +201,31 @@ impl<'a> ClaimDirectSetupBuilder<'a> {
         self
     }
 
+    pub fn claim_window(mut self, claim_start_ts: i64, claim_end_ts: i64) -> Self {
+        self.claim_window = (claim_start_ts, claim_end_ts);
+        self
+    }
+
+    pub fn mint_extension(mut self, extension_type: ExtensionType) -> Self {
+        self.token_program = TOKEN_2022_PROGRAM_ID;
+        self.mint_extension = Some(extension_type);
+        self
+    }
+
     pub fn warp_to_end(mut self, warp: bool) -> Self {
         self.warp_to_end = warp;
         self
     }
 
     pub fn build(self) -> ClaimDirectSetup {
-        let distribution_setup =
-            CreateDirectDistributionSetup::builder(self.ctx).token_program(self.token_program).build();
+        let (claim_start_ts, claim_end_ts) = self.claim_window;
+        let mut distribution_builder = CreateDirectDistributionSetup::builder(self.ctx)
+            .token_program(self.token_program)
+            .claim_window(claim_start_ts, claim_end_ts);
+        if let Some(extension_type) = self.mint_extension {
+            distribution_builder = distribution_builder.mint_extension(extension_type);
+        }
+        let distribution_setup = distribution_builder.build();
         let create_ix = distribution_setup.build_instruction(self.ctx);
         create_ix.send_expect_success(self.ctx);
 
@@ -212,6 +236,13 @@ impl<'a> ClaimDirectSetupBuilder<'a> {
         let (start_ts, end_ts) = match &schedule {
             VestingSchedule::Linear { start_ts, end_ts } => (*start_ts, *end_ts),
             VestingSchedule::CliffLinear { start_ts, end_ts, .. } => (*start_ts, *end_ts),
+            VestingSchedule::Stepped { start_ts, period_secs, num_periods }
+            | VestingSchedule::CliffStepped { start_ts, period_secs, num_periods, .. } => {
+                (*start_ts, *start_ts + (*period_secs * *num_periods as u64) as i64)
+            }
+            VestingSchedule::Tranches { points, .. } => {
+                (points.first().map_or(0, |point| point.timestamp), points.last().map_or(0, |point| point.timestamp))
+            }
             VestingSchedule::Cliff { cliff_ts } => (0, *cliff_ts),
             VestingSchedule::Immediate => (0, 0),
         };
@@ -303,6 +334,6 @@ impl InstructionTestFixture for ClaimDirectFixture {
     }
 
     fn data_len() -> usize {
-        9 // discriminator (1) + amount (8)
+        10 // discriminator (1) + amount (8) + fee_mode (1)
     }
 }
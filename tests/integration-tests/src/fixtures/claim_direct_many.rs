@@ -0,0 +1,246 @@
+use rewards_program_client::{
+    instructions::ClaimDirectManyBuilder,
+    types::{ClosePolicy, VestingSchedule},
+};
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use spl_token_interface::ID as TOKEN_PROGRAM_ID;
+
+use crate::fixtures::{AddDirectRecipientSetup, CreateDirectDistributionSetup, DEFAULT_RECIPIENT_AMOUNT};
+use crate::utils::{
+    find_direct_distribution_pda, find_direct_recipient_pda, find_event_authority_pda, InstructionTestFixture,
+    TestContext, TestInstruction,
+};
+
+pub const DEFAULT_GRANT_COUNT: usize = 3;
+
+/// One direct distribution of the shared mint with an allocation for the recipient
+pub struct Grant {
+    pub distribution_pda: Pubkey,
+    pub distribution_vault: Pubkey,
+    pub recipient_pda: Pubkey,
+    pub recipient_bump: u8,
+    pub amount: u64,
+}
+
+pub struct ClaimDirectManySetup {
+    pub authority: Keypair,
+    pub recipient: Keypair,
+    pub mint: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub token_program: Pubkey,
+    pub grants: Vec<Grant>,
+}
+
+impl ClaimDirectManySetup {
+    pub fn builder(ctx: &mut TestContext) -> ClaimDirectManySetupBuilder<'_> {
+        ClaimDirectManySetupBuilder::new(ctx)
+    }
+
+    pub fn new(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).build()
+    }
+
+    pub fn new_token_2022(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).token_2022().build()
+    }
+
+    pub fn total_amount(&self) -> u64 {
+        self.grants.iter().map(|grant| grant.amount).sum()
+    }
+
+    pub fn remaining_accounts(&self) -> Vec<AccountMeta> {
+        self.grants
+            .iter()
+            .flat_map(|grant| {
+                [
+                    AccountMeta::new(grant.distribution_pda, false),
+                    AccountMeta::new(grant.recipient_pda, false),
+                    AccountMeta::new(grant.distribution_vault, false),
+                ]
+            })
+            .collect()
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with(&self.recipient, self.remaining_accounts())
+    }
+
+    pub fn build_instruction_with(&self, recipient: &Keypair, remaining_accounts: Vec<AccountMeta>) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = ClaimDirectManyBuilder::new();
+        builder
+            .recipient(recipient.pubkey())
+            .mint(self.mint)
+            .recipient_token_account(self.recipient_token_account)
+            .token_program(self.token_program)
+            .event_authority(event_authority)
+            .add_remaining_accounts(&remaining_accounts);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![recipient.insecure_clone()],
+            name: "ClaimDirectMany",
+        }
+    }
+}
+
+pub struct ClaimDirectManySetupBuilder<'a> {
+    ctx: &'a mut TestContext,
+    token_program: Pubkey,
+    schedules: Option<Vec<VestingSchedule>>,
+    warp_to_end: bool,
+}
+
+impl<'a> ClaimDirectManySetupBuilder<'a> {
+    fn new(ctx: &'a mut TestContext) -> Self {
+        Self { ctx, token_program: TOKEN_PROGRAM_ID, schedules: None, warp_to_end: true }
+    }
+
+    pub fn token_2022(mut self) -> Self {
+        self.token_program = TOKEN_2022_PROGRAM_ID;
+        self
+    }
+
+    /// One grant per schedule
+    pub fn schedules(mut self, schedules: Vec<VestingSchedule>) -> Self {
+        self.schedules = Some(schedules);
+        self
+    }
+
+    pub fn warp_to_end(mut self, warp: bool) -> Self {
+        self.warp_to_end = warp;
+        self
+    }
+
+    pub fn build(self) -> ClaimDirectManySetup {
+        let authority = self.ctx.create_funded_keypair();
+        let recipient = self.ctx.create_funded_keypair();
+        let mint = Keypair::new();
+        self.ctx.create_mint_for_program(&mint, &self.ctx.payer.pubkey(), 6, &self.token_program);
+
+        let current_ts = self.ctx.get_current_timestamp();
+        let schedules = self.schedules.unwrap_or_else(|| {
+            (0..DEFAULT_GRANT_COUNT)
+                .map(|_| VestingSchedule::Linear { start_ts: current_ts, end_ts: current_ts + 86400 * 365 })
+                .collect()
+        });
+
+        let mut grants = Vec::with_capacity(schedules.len());
+        let mut end_ts = current_ts;
+        for schedule in schedules {
+            let seed = Keypair::new();
+            let (distribution_pda, bump) =
+                find_direct_distribution_pda(&mint.pubkey(), &authority.pubkey(), &seed.pubkey());
+            let distribution_vault =
+                self.ctx.create_ata_for_program(&distribution_pda, &mint.pubkey(), &self.token_program);
+
+            let distribution_setup = CreateDirectDistributionSetup {
+                authority: authority.insecure_clone(),
+                seed,
+                mint: mint.insecure_clone(),
+                distribution_vault,
+                distribution_pda,
+                bump,
+                token_program: self.token_program,
+                revocable: 0,
+                clawback_ts: 0,
+                close_policy: ClosePolicy::Sweep,
+                claim_start_ts: 0,
+                claim_end_ts: 0,
+            };
+            let create_ix = distribution_setup.build_instruction(self.ctx);
+            create_ix.send_expect_success(self.ctx);
+
+            let (recipient_pda, recipient_bump) = find_direct_recipient_pda(&distribution_pda, &recipient.pubkey());
+            let authority_token_account = self.ctx.create_ata_for_program_with_balance(
+                &authority.pubkey(),
+                &mint.pubkey(),
+                DEFAULT_RECIPIENT_AMOUNT,
+                &self.token_program,
+            );
+
+            let recipient_setup = AddDirectRecipientSetup {
+                authority: authority.insecure_clone(),
+                distribution_pda,
+                recipient: recipient.insecure_clone(),
+                recipient_pda,
+                recipient_bump,
+                amount: DEFAULT_RECIPIENT_AMOUNT,
+                schedule,
+                token_program: self.token_program,
+                mint: mint.pubkey(),
+                distribution_vault,
+                authority_token_account,
+            };
+            let add_recipient_ix = recipient_setup.build_instruction(self.ctx);
+            add_recipient_ix.send_expect_success(self.ctx);
+            end_ts = end_ts.max(recipient_setup.end_ts());
+
+            grants.push(Grant {
+                distribution_pda,
+                distribution_vault,
+                recipient_pda,
+                recipient_bump,
+                amount: DEFAULT_RECIPIENT_AMOUNT,
+            });
+        }
+
+        if self.warp_to_end {
+            self.ctx.warp_to_timestamp(end_ts);
+        }
+
+        let recipient_token_account =
+            self.ctx.create_ata_for_program(&recipient.pubkey(), &mint.pubkey(), &self.token_program);
+
+        ClaimDirectManySetup {
+            authority,
+            recipient,
+            mint: mint.pubkey(),
+            recipient_token_account,
+            token_program: self.token_program,
+            grants,
+        }
+    }
+}
+
+pub struct ClaimDirectManyFixture;
+
+impl InstructionTestFixture for ClaimDirectManyFixture {
+    const INSTRUCTION_NAME: &'static str = "ClaimDirectMany";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = ClaimDirectManySetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: recipient
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 2: recipient_token_account
+    /// 6, 7, 8: first distribution, recipient_account, distribution_vault
+    fn required_writable() -> &'static [usize] {
+        &[2, 6, 7, 8]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        None
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(5)
+    }
+
+    fn data_len() -> usize {
+        1 // discriminator
+    }
+}
@@ -1,4 +1,7 @@
-use rewards_program_client::{instructions::ClaimMerkleBuilder, types::VestingSchedule};
+use rewards_program_client::{
+    instructions::ClaimMerkleBuilder,
+    types::{TransferFeeMode, VestingSchedule},
+};
 use solana_sdk::{
     pubkey::Pubkey,
     signature::{Keypair, Signer},
@@ -8,8 +11,8 @@ use spl_token_interface::ID as TOKEN_PROGRAM_ID;
 
 use crate::fixtures::CreateMerkleDistributionSetup;
 use crate::utils::{
-    find_event_authority_pda, find_merkle_claim_pda, find_merkle_revocation_pda, InstructionTestFixture, MerkleLeaf,
-    MerkleTree, TestContext, TestInstruction,
+    find_event_authority_pda, find_merkle_claim_pda, find_merkle_redirect_pda, find_merkle_revocation_pda,
+    InstructionTestFixture, MerkleLeaf, MerkleTree, TestContext, TestInstruction,
 };
 
 pub const DEFAULT_CLAIMANT_AMOUNT: u64 = 1_000_000;
@@ -20,6 +23,7 @@ pub struct ClaimMerkleSetup {
     pub claim_pda: Pubkey,
     pub claim_bump: u8,
     pub revocation_pda: Pubkey,
+    pub redirect_pda: Pubkey,
     pub mint: Pubkey,
     pub distribution_vault: Pubkey,
     pub claimant_token_account: Pubkey,
@@ -50,6 +54,8 @@ impl ClaimMerkleSetup {
             VestingSchedule::Linear { start_ts, .. } => *start_ts,
             VestingSchedule::Cliff { .. } => 0,
             VestingSchedule::CliffLinear { start_ts, .. } => *start_ts,
+            VestingSchedule::Stepped { start_ts, .. } | VestingSchedule::CliffStepped { start_ts, .. } => *start_ts,
+            VestingSchedule::Tranches { points, .. } => points.first().map_or(0, |point| point.timestamp),
         }
     }
 
@@ -59,6 +65,11 @@ impl ClaimMerkleSetup {
             VestingSchedule::Linear { end_ts, .. } => *end_ts,
             VestingSchedule::Cliff { cliff_ts } => *cliff_ts,
             VestingSchedule::CliffLinear { end_ts, .. } => *end_ts,
+            VestingSchedule::Stepped { start_ts, period_secs, num_periods }
+            | VestingSchedule::CliffStepped { start_ts, period_secs, num_periods, .. } => {
+                *start_ts + (*period_secs * *num_periods as u64) as i64
+            }
+            VestingSchedule::Tranches { points, .. } => points.last().map_or(0, |point| point.timestamp),
         }
     }
 
@@ -76,6 +87,7 @@ impl ClaimMerkleSetup {
             .distribution(self.distribution_pda)
             .claim_account(self.claim_pda)
             .revocation_account(self.revocation_pda)
+            .redirect_account(self.redirect_pda)
             .mint(self.mint)
             .distribution_vault(self.distribution_vault)
             .claimant_token_account(self.claimant_token_account)
@@ -85,6 +97,7 @@ impl ClaimMerkleSetup {
             .total_amount(self.total_amount)
             .schedule(self.schedule.clone())
             .amount(claim_amount)
+            .fee_mode(TransferFeeMode::Gross)
             .proof(self.proof.clone());
 
         TestInstruction {
@@ -104,6 +117,7 @@ impl ClaimMerkleSetup {
         let (wrong_claim_pda, wrong_claim_bump) =
             find_merkle_claim_pda(&self.distribution_pda, &wrong_claimant.pubkey());
         let (wrong_revocation_pda, _) = find_merkle_revocation_pda(&self.distribution_pda, &wrong_claimant.pubkey());
+        let (wrong_redirect_pda, _) = find_merkle_redirect_pda(&self.distribution_pda, &wrong_claimant.pubkey());
 
         let mut builder = ClaimMerkleBuilder::new();
         builder
@@ -112,6 +126,7 @@ impl ClaimMerkleSetup {
             .distribution(self.distribution_pda)
             .claim_account(wrong_claim_pda)
             .revocation_account(wrong_revocation_pda)
+            .redirect_account(wrong_redirect_pda)
             .mint(self.mint)
             .distribution_vault(self.distribution_vault)
             .claimant_token_account(wrong_token_account)
@@ -121,6 +136,7 @@ impl ClaimMerkleSetup {
             .total_amount(self.total_amount)
             .schedule(self.schedule.clone())
             .amount(0)
+            .fee_mode(TransferFeeMode::Gross)
             .proof(self.proof.clone());
 
         TestInstruction {
@@ -140,6 +156,7 @@ impl ClaimMerkleSetup {
             .distribution(self.distribution_pda)
             .claim_account(self.claim_pda)
             .revocation_account(self.revocation_pda)
+            .redirect_account(self.redirect_pda)
             .mint(self.mint)
             .distribution_vault(self.distribution_vault)
             .claimant_token_account(self.claimant_token_account)
@@ -149,6 +166,7 @@ impl ClaimMerkleSetup {
             .total_amount(self.total_amount)
             .schedule(self.schedule.clone())
             .amount(0)
+            .fee_mode(TransferFeeMode::Gross)
             .proof(wrong_proof);
 
         TestInstruction {
@@ -168,6 +186,7 @@ impl ClaimMerkleSetup {
             .distribution(self.distribution_pda)
             .claim_account(self.claim_pda)
             .revocation_account(self.revocation_pda)
+            .redirect_account(self.redirect_pda)
             .mint(self.mint)
             .distribution_vault(self.distribution_vault)
             .claimant_token_account(self.claimant_token_account)
@@ -177,6 +196,7 @@ impl ClaimMerkleSetup {
             .total_amount(wrong_total_amount)
             .schedule(self.schedule.clone())
             .amount(0)
+            .fee_mode(TransferFeeMode::Gross)
             .proof(self.proof.clone());
 
         TestInstruction {
@@ -192,6 +212,7 @@ pub struct ClaimMerkleSetupBuilder<'a> {
     token_program: Pubkey,
     claimant_amount: u64,
     schedule: Option<VestingSchedule>,
+    claim_window: (i64, i64),
     warp_to_end: bool,
     num_claimants: usize,
 }
@@ -203,6 +224,7 @@ impl<'a> ClaimMerkleSetupBuilder<'a> {
             token_program: TOKEN_PROGRAM_ID,
             claimant_amount: DEFAULT_CLAIMANT_AMOUNT,
             schedule: None,
+            claim_window: (0, 0),
             warp_to_end: true,
             num_claimants: 2,
         }
@@ -237,6 +259,11 @@ impl<'a> ClaimMerkleSetupBuilder<'a> {
         self
     }
 
+    pub fn claim_window(mut self, claim_start_ts: i64, claim_end_ts: i64) -> Self {
+        self.claim_window = (claim_start_ts, claim_end_ts);
+        self
+    }
+
     pub fn warp_to_end(mut self, warp: bool) -> Self {
         self.warp_to_end = warp;
         self
@@ -257,6 +284,11 @@ impl<'a> ClaimMerkleSetupBuilder<'a> {
             VestingSchedule::Linear { end_ts, .. } => *end_ts,
             VestingSchedule::Cliff { cliff_ts } => *cliff_ts,
             VestingSchedule::CliffLinear { end_ts, .. } => *end_ts,
+            VestingSchedule::Stepped { start_ts, period_secs, num_periods }
+            | VestingSchedule::CliffStepped { start_ts, period_secs, num_periods, .. } => {
+                *start_ts + (*period_secs * *num_periods as u64) as i64
+            }
+            VestingSchedule::Tranches { points, .. } => points.last().map_or(0, |point| point.timestamp),
         };
 
         let claimant = self.ctx.create_funded_keypair();
@@ -275,12 +307,14 @@ impl<'a> ClaimMerkleSetupBuilder<'a> {
             .total_amount(total_distribution_amount)
             .merkle_root(merkle_tree.root)
             .token_program(self.token_program)
+            .claim_window(self.claim_window.0, self.claim_window.1)
             .build();
         let create_ix = distribution_setup.build_instruction(self.ctx);
         create_ix.send_expect_success(self.ctx);
 
         let (claim_pda, claim_bump) = find_merkle_claim_pda(&distribution_setup.distribution_pda, &claimant.pubkey());
         let (revocation_pda, _) = find_merkle_revocation_pda(&distribution_setup.distribution_pda, &claimant.pubkey());
+        let (redirect_pda, _) = find_merkle_redirect_pda(&distribution_setup.distribution_pda, &claimant.pubkey());
 
         let proof = merkle_tree.get_proof_for_claimant(&claimant.pubkey()).unwrap();
 
@@ -297,6 +331,7 @@ impl<'a> ClaimMerkleSetupBuilder<'a> {
             claim_pda,
             claim_bump,
             revocation_pda,
+            redirect_pda,
             mint: distribution_setup.mint.pubkey(),
             distribution_vault: distribution_setup.distribution_vault,
             claimant_token_account,
@@ -331,22 +366,23 @@ impl InstructionTestFixture for ClaimMerkleFixture {
     /// 0: payer
     /// 2: distribution
     /// 3: claim_account
-    /// 6: distribution_vault
-    /// 7: claimant_token_account
+    /// 7: distribution_vault
+    /// 8: claimant_token_account
     fn required_writable() -> &'static [usize] {
-        &[0, 2, 3, 6, 7]
+        &[0, 2, 3, 7, 8]
     }
 
     fn system_program_index() -> Option<usize> {
-        Some(8)
+        Some(9)
     }
 
     fn current_program_index() -> Option<usize> {
-        Some(11)
+        Some(12)
     }
 
     fn data_len() -> usize {
-        // discriminator(1) + claim_bump(1) + total_amount(8) + amount(8) + Linear schedule(17) + proof_len(4) + proof(32)
-        1 + 1 + 8 + 8 + 17 + 4 + 32
+        // discriminator(1) + claim_bump(1) + total_amount(8) + amount(8) + fee_mode(1) + Linear schedule(17)
+        // + proof_len(4) + proof(32)
+        1 + 1 + 8 + 8 + 1 + 17 + 4 + 32
     }
 }
@@ -0,0 +1,221 @@
+use rewards_program_client::instructions::{
+    ClaimMerkleBitmapBuilder, CloseMerkleClaimBitmapBuilder, CreateMerkleClaimBitmapBuilder,
+};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use spl_token_interface::ID as TOKEN_PROGRAM_ID;
+
+use crate::fixtures::CreateMerkleDistributionSetup;
+use crate::utils::{
+    find_event_authority_pda, find_merkle_claim_bitmap_pda, InstructionTestFixture, MerkleLeaf, MerkleTree,
+    TestContext, TestInstruction,
+};
+
+pub const DEFAULT_BITMAP_CLAIMANT_AMOUNT: u64 = 1_000_000;
+pub const CLAIM_BITMAP_CHUNK_BITS: u32 = 8192;
+
+pub struct ClaimMerkleBitmapSetup {
+    pub claimant: Keypair,
+    pub index: u32,
+    pub amount: u64,
+    pub distribution_pda: Pubkey,
+    pub claim_bitmap_pda: Pubkey,
+    pub chunk_index: u32,
+    pub chunk_payer: Keypair,
+    pub mint: Pubkey,
+    pub distribution_vault: Pubkey,
+    pub claimant_token_account: Pubkey,
+    pub token_program: Pubkey,
+    pub proof: Vec<[u8; 32]>,
+    pub merkle_tree: MerkleTree,
+    pub authority: Keypair,
+    pub clawback_ts: i64,
+}
+
+impl ClaimMerkleBitmapSetup {
+    pub fn builder(ctx: &mut TestContext) -> ClaimMerkleBitmapSetupBuilder<'_> {
+        ClaimMerkleBitmapSetupBuilder::new(ctx)
+    }
+
+    pub fn new(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).build()
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with(self.index, self.amount, self.proof.clone())
+    }
+
+    pub fn build_instruction_with(&self, index: u32, amount: u64, proof: Vec<[u8; 32]>) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = ClaimMerkleBitmapBuilder::new();
+        builder
+            .claimant(self.claimant.pubkey())
+            .distribution(self.distribution_pda)
+            .claim_bitmap(self.claim_bitmap_pda)
+            .mint(self.mint)
+            .distribution_vault(self.distribution_vault)
+            .claimant_token_account(self.claimant_token_account)
+            .token_program(self.token_program)
+            .event_authority(event_authority)
+            .index(index)
+            .amount(amount)
+            .proof(proof);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![self.claimant.insecure_clone()],
+            name: "ClaimMerkleBitmap",
+        }
+    }
+
+    pub fn build_create_chunk_instruction(payer: &Keypair, distribution: Pubkey, chunk_index: u32) -> TestInstruction {
+        let (claim_bitmap, _) = find_merkle_claim_bitmap_pda(&distribution, chunk_index);
+
+        let mut builder = CreateMerkleClaimBitmapBuilder::new();
+        builder.payer(payer.pubkey()).distribution(distribution).claim_bitmap(claim_bitmap).chunk_index(chunk_index);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![payer.insecure_clone()],
+            name: "CreateMerkleClaimBitmap",
+        }
+    }
+
+    pub fn build_close_chunk_instruction(&self, original_payer: &Keypair) -> TestInstruction {
+        let mut builder = CloseMerkleClaimBitmapBuilder::new();
+        builder
+            .original_payer(original_payer.pubkey())
+            .distribution(self.distribution_pda)
+            .claim_bitmap(self.claim_bitmap_pda);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![original_payer.insecure_clone()],
+            name: "CloseMerkleClaimBitmap",
+        }
+    }
+}
+
+pub struct ClaimMerkleBitmapSetupBuilder<'a> {
+    ctx: &'a mut TestContext,
+    amount: u64,
+    index: u32,
+    create_chunk: bool,
+}
+
+impl<'a> ClaimMerkleBitmapSetupBuilder<'a> {
+    fn new(ctx: &'a mut TestContext) -> Self {
+        Self { ctx, amount: DEFAULT_BITMAP_CLAIMANT_AMOUNT, index: 0, create_chunk: true }
+    }
+
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = amount;
+        self
+    }
+
+    /// Leaf index of the claimant; a second claimant always sits at index 0 or 1
+    pub fn index(mut self, index: u32) -> Self {
+        self.index = index;
+        self
+    }
+
+    pub fn create_chunk(mut self, create: bool) -> Self {
+        self.create_chunk = create;
+        self
+    }
+
+    pub fn build(self) -> ClaimMerkleBitmapSetup {
+        let claimant = self.ctx.create_funded_keypair();
+        let other_index = if self.index == 0 { 1 } else { 0 };
+        let leaves = vec![
+            MerkleLeaf::bitmap(claimant.pubkey(), self.index, self.amount),
+            MerkleLeaf::bitmap(Keypair::new().pubkey(), other_index, self.amount),
+        ];
+
+        let merkle_tree = MerkleTree::new(leaves);
+        let total_distribution_amount = self.amount * 2;
+
+        let distribution_setup = CreateMerkleDistributionSetup::builder(self.ctx)
+            .amount(total_distribution_amount)
+            .total_amount(total_distribution_amount)
+            .merkle_root(merkle_tree.root)
+            .token_program(TOKEN_PROGRAM_ID)
+            .bitmap()
+            .build();
+        let create_ix = distribution_setup.build_instruction(self.ctx);
+        create_ix.send_expect_success(self.ctx);
+
+        let chunk_index = self.index / CLAIM_BITMAP_CHUNK_BITS;
+        let (claim_bitmap_pda, _) = find_merkle_claim_bitmap_pda(&distribution_setup.distribution_pda, chunk_index);
+
+        let chunk_payer = self.ctx.create_funded_keypair();
+        if self.create_chunk {
+            let create_chunk_ix = ClaimMerkleBitmapSetup::build_create_chunk_instruction(
+                &chunk_payer,
+                distribution_setup.distribution_pda,
+                chunk_index,
+            );
+            create_chunk_ix.send_expect_success(self.ctx);
+        }
+
+        let proof = merkle_tree.get_proof_for_claimant(&claimant.pubkey()).unwrap();
+        let claimant_token_account =
+            self.ctx.create_ata_for_program(&claimant.pubkey(), &distribution_setup.mint.pubkey(), &TOKEN_PROGRAM_ID);
+
+        ClaimMerkleBitmapSetup {
+            claimant,
+            index: self.index,
+            amount: self.amount,
+            distribution_pda: distribution_setup.distribution_pda,
+            claim_bitmap_pda,
+            chunk_index,
+            chunk_payer,
+            mint: distribution_setup.mint.pubkey(),
+            distribution_vault: distribution_setup.distribution_vault,
+            claimant_token_account,
+            token_program: TOKEN_PROGRAM_ID,
+            proof,
+            merkle_tree,
+            authority: distribution_setup.authority,
+            clawback_ts: distribution_setup.clawback_ts,
+        }
+    }
+}
+
+pub struct ClaimMerkleBitmapFixture;
+
+impl InstructionTestFixture for ClaimMerkleBitmapFixture {
+    const INSTRUCTION_NAME: &'static str = "ClaimMerkleBitmap";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = ClaimMerkleBitmapSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: claimant
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 1: distribution
+    /// 2: claim_bitmap
+    /// 4: distribution_vault
+    /// 5: claimant_token_account
+    fn required_writable() -> &'static [usize] {
+        &[1, 2, 4, 5]
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(8)
+    }
+
+    fn data_len() -> usize {
+        // discriminator(1) + index(4) + amount(8) + proof_len(4) + proof(32)
+        1 + 4 + 8 + 4 + 32
+    }
+}
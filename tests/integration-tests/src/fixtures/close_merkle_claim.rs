@@ -13,6 +13,7 @@ use crate::utils::{
 
 pub struct CloseMerkleClaimSetup {
     pub claimant: Keypair,
+    pub original_payer: Pubkey,
     pub distribution_pda: Pubkey,
     pub claim_pda: Pubkey,
     pub token_program: Pubkey,
@@ -37,6 +38,7 @@ impl CloseMerkleClaimSetup {
         let mut builder = CloseMerkleClaimBuilder::new();
         builder
             .claimant(self.claimant.pubkey())
+            .original_payer(self.original_payer)
             .distribution(self.distribution_pda)
             .claim_account(self.claim_pda)
             .event_authority(event_authority);
@@ -59,6 +61,7 @@ impl CloseMerkleClaimSetup {
         let mut builder = CloseMerkleClaimBuilder::new();
         builder
             .claimant(wrong_claimant.pubkey())
+            .original_payer(self.original_payer)
             .distribution(self.distribution_pda)
             .claim_account(wrong_claim_pda)
             .event_authority(event_authority);
@@ -69,6 +72,14 @@ impl CloseMerkleClaimSetup {
             name: "CloseMerkleClaim",
         }
     }
+
+    pub fn build_instruction_with_wrong_original_payer(
+        &self,
+        ctx: &TestContext,
+        wrong_payer: Pubkey,
+    ) -> TestInstruction {
+        self.build_instruction(ctx).with_account_at(1, wrong_payer)
+    }
 }
 
 pub struct CloseMerkleClaimSetupBuilder<'a> {
@@ -128,6 +139,7 @@ impl<'a> CloseMerkleClaimSetupBuilder<'a> {
 
         CloseMerkleClaimSetup {
             claimant: claim_setup.claimant,
+            original_payer: self.ctx.payer.pubkey(),
             distribution_pda: claim_setup.distribution_pda,
             claim_pda: claim_setup.claim_pda,
             token_program: self.token_program,
@@ -152,10 +164,10 @@ impl InstructionTestFixture for CloseMerkleClaimFixture {
     }
 
     /// Account indices that must be writable:
-    /// 0: claimant
-    /// 2: claim_account
+    /// 1: original_payer
+    /// 3: claim_account
     fn required_writable() -> &'static [usize] {
-        &[0, 2]
+        &[1, 3]
     }
 
     fn system_program_index() -> Option<usize> {
@@ -163,7 +175,7 @@ impl InstructionTestFixture for CloseMerkleClaimFixture {
     }
 
     fn current_program_index() -> Option<usize> {
-        Some(4)
+        Some(5)
     }
 
     fn data_len() -> usize {
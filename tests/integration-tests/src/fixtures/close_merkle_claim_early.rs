@@ -0,0 +1,93 @@
+use rewards_program_client::instructions::CloseMerkleClaimEarlyBuilder;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+use crate::fixtures::ClaimMerkleSetup;
+use crate::utils::{find_event_authority_pda, InstructionTestFixture, TestContext, TestInstruction};
+
+pub struct CloseMerkleClaimEarlySetup {
+    pub claim: ClaimMerkleSetup,
+    pub original_payer: Pubkey,
+}
+
+impl CloseMerkleClaimEarlySetup {
+    /// A merkle claim that has claimed its whole leaf while the distribution is still open
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let claim = ClaimMerkleSetup::new(ctx);
+        claim.build_instruction(ctx).send_expect_success(ctx);
+        Self { claim, original_payer: ctx.payer.pubkey() }
+    }
+
+    /// A merkle claim that has claimed half of its leaf
+    pub fn new_partially_claimed(ctx: &mut TestContext) -> Self {
+        let claim = ClaimMerkleSetup::new(ctx);
+        claim.build_instruction_with_amount(ctx, claim.total_amount / 2).send_expect_success(ctx);
+        Self { claim, original_payer: ctx.payer.pubkey() }
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = CloseMerkleClaimEarlyBuilder::new();
+        builder
+            .claimant(self.claim.claimant.pubkey())
+            .original_payer(self.original_payer)
+            .distribution(self.claim.distribution_pda)
+            .claim_account(self.claim.claim_pda)
+            .event_authority(event_authority)
+            .total_amount(self.claim.total_amount)
+            .schedule(self.claim.schedule.clone())
+            .proof(self.claim.proof.clone());
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![self.claim.claimant.insecure_clone()],
+            name: "CloseMerkleClaimEarly",
+        }
+    }
+
+    pub fn build_instruction_with_wrong_original_payer(
+        &self,
+        ctx: &TestContext,
+        wrong_payer: Pubkey,
+    ) -> TestInstruction {
+        self.build_instruction(ctx).with_account_at(1, wrong_payer)
+    }
+}
+
+pub struct CloseMerkleClaimEarlyFixture;
+
+impl InstructionTestFixture for CloseMerkleClaimEarlyFixture {
+    const INSTRUCTION_NAME: &'static str = "CloseMerkleClaimEarly";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = CloseMerkleClaimEarlySetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: claimant
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: claimant
+    /// 1: original_payer
+    /// 3: claim_account
+    fn required_writable() -> &'static [usize] {
+        &[0, 1, 3]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(4)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(6)
+    }
+
+    fn data_len() -> usize {
+        // discriminator(1) + total_amount(8) + Linear schedule(17) + proof_len(4) + proof(32)
+        1 + 8 + 17 + 4 + 32
+    }
+}
@@ -0,0 +1,359 @@
+use rewards_program_client::{
+    accounts::CompressedDistribution,
+    instructions::{
+        AddCompressedRecipientBuilder, ClaimCompressedBuilder, CloseCompressedDistributionBuilder,
+        CreateCompressedDistributionBuilder,
+    },
+    types::{TransferFeeMode, VestingSchedule},
+};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use spl_token_interface::ID as TOKEN_PROGRAM_ID;
+
+use crate::fixtures::DEFAULT_RECIPIENT_AMOUNT;
+use crate::utils::{
+    find_compressed_distribution_pda, find_event_authority_pda, CompressedLeaf, CompressedTree, InstructionTestFixture,
+    TestContext, TestInstruction,
+};
+
+/// A compressed distribution and a mirror of its recipient tree.
+///
+/// `add_recipient` and `claim` keep the mirror in step with the on-chain tree, so
+/// `build_claim_instruction` always proves against the current root.
+pub struct CompressedSetup {
+    pub authority: Keypair,
+    pub seed: Keypair,
+    pub mint: Pubkey,
+    pub distribution_pda: Pubkey,
+    pub bump: u8,
+    pub distribution_vault: Pubkey,
+    pub authority_token_account: Pubkey,
+    pub token_program: Pubkey,
+    pub clawback_ts: i64,
+    pub tree: CompressedTree,
+}
+
+/// A compressed recipient's wallet and destination token account
+pub struct CompressedRecipientSetup {
+    pub recipient: Keypair,
+    pub recipient_token_account: Pubkey,
+    pub index: u32,
+}
+
+impl CompressedSetup {
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let authority = ctx.create_funded_keypair();
+        let seed = Keypair::new();
+        let mint = Keypair::new();
+        let token_program = TOKEN_PROGRAM_ID;
+        ctx.create_mint_for_program(&mint, &ctx.payer.pubkey(), 6, &token_program);
+
+        let (distribution_pda, bump) =
+            find_compressed_distribution_pda(&mint.pubkey(), &authority.pubkey(), &seed.pubkey());
+        let distribution_vault = spl_associated_token_account::get_associated_token_address_with_program_id(
+            &distribution_pda,
+            &mint.pubkey(),
+            &token_program,
+        );
+        let authority_token_account = ctx.create_ata_for_program_with_balance(
+            &authority.pubkey(),
+            &mint.pubkey(),
+            DEFAULT_RECIPIENT_AMOUNT * 10,
+            &token_program,
+        );
+
+        Self {
+            authority,
+            seed,
+            mint: mint.pubkey(),
+            distribution_pda,
+            bump,
+            distribution_vault,
+            authority_token_account,
+            token_program,
+            clawback_ts: 0,
+            tree: CompressedTree::default(),
+        }
+    }
+
+    /// Create the distribution, its tree and its vault
+    pub fn create(ctx: &mut TestContext) -> Self {
+        let setup = Self::new(ctx);
+        setup.build_create_instruction(ctx).send_expect_success(ctx);
+        setup
+    }
+
+    pub fn build_create_instruction(&self, ctx: &TestContext) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = CreateCompressedDistributionBuilder::new();
+        builder
+            .payer(ctx.payer.pubkey())
+            .authority(self.authority.pubkey())
+            .seeds(self.seed.pubkey())
+            .distribution(self.distribution_pda)
+            .mint(self.mint)
+            .distribution_vault(self.distribution_vault)
+            .token_program(self.token_program)
+            .event_authority(event_authority)
+            .bump(self.bump)
+            .clawback_ts(self.clawback_ts);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![self.authority.insecure_clone(), self.seed.insecure_clone()],
+            name: "CreateCompressedDistribution",
+        }
+    }
+
+    pub fn build_add_recipient_instruction(
+        &self,
+        recipient: &Pubkey,
+        amount: u64,
+        schedule: VestingSchedule,
+    ) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = AddCompressedRecipientBuilder::new();
+        builder
+            .authority(self.authority.pubkey())
+            .distribution(self.distribution_pda)
+            .mint(self.mint)
+            .distribution_vault(self.distribution_vault)
+            .authority_token_account(self.authority_token_account)
+            .token_program(self.token_program)
+            .event_authority(event_authority)
+            .recipient(*recipient)
+            .amount(amount)
+            .schedule(schedule);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![self.authority.insecure_clone()],
+            name: "AddCompressedRecipient",
+        }
+    }
+
+    /// Add a funded recipient with their own token account and record the leaf in the mirror
+    pub fn add_recipient(
+        &mut self,
+        ctx: &mut TestContext,
+        amount: u64,
+        schedule: VestingSchedule,
+    ) -> CompressedRecipientSetup {
+        let recipient = ctx.create_funded_keypair();
+        let recipient_token_account = ctx.create_ata_for_program(&recipient.pubkey(), &self.mint, &self.token_program);
+        let index = self.add_leaf(ctx, &recipient.pubkey(), amount, schedule);
+        CompressedRecipientSetup { recipient, recipient_token_account, index }
+    }
+
+    /// Append a leaf for `recipient` and return its index
+    pub fn add_leaf(
+        &mut self,
+        ctx: &mut TestContext,
+        recipient: &Pubkey,
+        amount: u64,
+        schedule: VestingSchedule,
+    ) -> u32 {
+        self.build_add_recipient_instruction(recipient, amount, schedule.clone()).send_expect_success(ctx);
+
+        // The program stamps each leaf with the distribution's vesting offset at append time
+        let account = ctx.get_account(&self.distribution_pda).expect("Compressed distribution should exist");
+        let base_vesting_offset = CompressedDistribution::from_bytes(&account.data).unwrap().vesting_offset;
+        self.tree.push(CompressedLeaf {
+            recipient: *recipient,
+            total_amount: amount,
+            claimed_amount: 0,
+            base_vesting_offset,
+            schedule,
+        })
+    }
+
+    /// Claim `amount` (0 = all available) against the mirror's current root
+    pub fn build_claim_instruction(&self, recipient_setup: &CompressedRecipientSetup, amount: u64) -> TestInstruction {
+        let leaf = &self.tree.leaves[recipient_setup.index as usize];
+        self.build_claim_instruction_with(
+            recipient_setup,
+            self.tree.root(),
+            leaf,
+            amount,
+            self.tree.get_proof(recipient_setup.index),
+        )
+    }
+
+    pub fn build_claim_instruction_with(
+        &self,
+        recipient_setup: &CompressedRecipientSetup,
+        root: [u8; 32],
+        leaf: &CompressedLeaf,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = ClaimCompressedBuilder::new();
+        builder
+            .recipient(recipient_setup.recipient.pubkey())
+            .distribution(self.distribution_pda)
+            .mint(self.mint)
+            .distribution_vault(self.distribution_vault)
+            .recipient_token_account(recipient_setup.recipient_token_account)
+            .token_program(self.token_program)
+            .event_authority(event_authority)
+            .root(root)
+            .index(recipient_setup.index)
+            .total_amount(leaf.total_amount)
+            .claimed_amount(leaf.claimed_amount)
+            .base_vesting_offset(leaf.base_vesting_offset)
+            .amount(amount)
+            .fee_mode(TransferFeeMode::Gross)
+            .schedule(leaf.schedule.clone())
+            .proof(proof);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![recipient_setup.recipient.insecure_clone()],
+            name: "ClaimCompressed",
+        }
+    }
+
+    /// Send a claim and record the recipient's new claimed amount in the mirror
+    pub fn claim(&mut self, ctx: &mut TestContext, recipient_setup: &CompressedRecipientSetup, amount: u64) {
+        let before = ctx.get_token_balance(&recipient_setup.recipient_token_account);
+        self.build_claim_instruction(recipient_setup, amount).send_expect_success(ctx);
+        let claimed = ctx.get_token_balance(&recipient_setup.recipient_token_account) - before;
+        self.tree.leaves[recipient_setup.index as usize].claimed_amount += claimed;
+    }
+
+    pub fn build_close_instruction(&self) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = CloseCompressedDistributionBuilder::new();
+        builder
+            .authority(self.authority.pubkey())
+            .distribution(self.distribution_pda)
+            .mint(self.mint)
+            .distribution_vault(self.distribution_vault)
+            .authority_token_account(self.authority_token_account)
+            .token_program(self.token_program)
+            .event_authority(event_authority);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![self.authority.insecure_clone()],
+            name: "CloseCompressedDistribution",
+        }
+    }
+}
+
+pub struct CreateCompressedDistributionFixture;
+
+impl InstructionTestFixture for CreateCompressedDistributionFixture {
+    const INSTRUCTION_NAME: &'static str = "CreateCompressedDistribution";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = CompressedSetup::new(ctx);
+        setup.build_create_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: payer
+    /// 1: authority
+    /// 2: seeds
+    fn required_signers() -> &'static [usize] {
+        &[0, 1, 2]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: payer
+    /// 3: distribution
+    /// 5: distribution_vault
+    fn required_writable() -> &'static [usize] {
+        &[0, 3, 5]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(6)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(10)
+    }
+
+    fn data_len() -> usize {
+        1 + 1 + 8 // discriminator + bump + clawback_ts
+    }
+}
+
+pub struct AddCompressedRecipientFixture;
+
+impl InstructionTestFixture for AddCompressedRecipientFixture {
+    const INSTRUCTION_NAME: &'static str = "AddCompressedRecipient";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = CompressedSetup::create(ctx);
+        let recipient = Pubkey::new_unique();
+        setup.build_add_recipient_instruction(&recipient, DEFAULT_RECIPIENT_AMOUNT, VestingSchedule::Immediate)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: authority
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 1: distribution
+    /// 3: distribution_vault
+    /// 4: authority_token_account
+    fn required_writable() -> &'static [usize] {
+        &[1, 3, 4]
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(7)
+    }
+
+    fn data_len() -> usize {
+        // discriminator(1) + recipient(32) + amount(8) + Immediate schedule(1) = 42
+        1 + 32 + 8 + 1
+    }
+}
+
+pub struct ClaimCompressedFixture;
+
+impl InstructionTestFixture for ClaimCompressedFixture {
+    const INSTRUCTION_NAME: &'static str = "ClaimCompressed";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let mut setup = CompressedSetup::create(ctx);
+        let recipient_setup = setup.add_recipient(ctx, DEFAULT_RECIPIENT_AMOUNT, VestingSchedule::Immediate);
+        setup.build_claim_instruction(&recipient_setup, 0)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: recipient
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 1: distribution
+    /// 3: distribution_vault
+    /// 4: recipient_token_account
+    fn required_writable() -> &'static [usize] {
+        &[1, 3, 4]
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(7)
+    }
+
+    fn data_len() -> usize {
+        // discriminator(1) + root(32) + index(4) + total_amount(8) + claimed_amount(8) + base_vesting_offset(8)
+        // + amount(8) + fee_mode(1) + Immediate schedule(1) + proof_len(4) + proof(16 * 32)
+        1 + 32 + 4 + 8 + 8 + 8 + 8 + 1 + 1 + 4 + 16 * 32
+    }
+}
@@ -0,0 +1,117 @@
+use rewards_program_client::{instructions::CrankClaimDirectBuilder, types::TransferFeeMode};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::{ClaimDirectSetup, SetClaimDelegateSetup};
+use crate::utils::{
+    find_claim_delegate_pda, find_event_authority_pda, InstructionTestFixture, TestContext, TestInstruction,
+};
+
+pub struct CrankClaimDirectSetup {
+    pub claim: ClaimDirectSetup,
+    pub cranker: Keypair,
+    pub claim_delegate_pda: Pubkey,
+}
+
+impl CrankClaimDirectSetup {
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let claim = ClaimDirectSetup::new(ctx);
+        Self::from_claim_setup(ctx, claim)
+    }
+
+    pub fn new_token_2022(ctx: &mut TestContext) -> Self {
+        let claim = ClaimDirectSetup::new_token_2022(ctx);
+        Self::from_claim_setup(ctx, claim)
+    }
+
+    pub fn from_claim_setup(ctx: &mut TestContext, claim: ClaimDirectSetup) -> Self {
+        let cranker = ctx.create_funded_keypair();
+        let (claim_delegate_pda, _) = find_claim_delegate_pda(&claim.distribution_pda, &claim.recipient.pubkey());
+
+        Self { claim, cranker, claim_delegate_pda }
+    }
+
+    /// Make `cranker` the recipient's claim delegate
+    pub fn set_cranker_as_delegate(&self, ctx: &mut TestContext) {
+        let mut set_setup =
+            SetClaimDelegateSetup::for_recipient(ctx, self.claim.distribution_pda, &self.claim.recipient);
+        set_setup.delegate = self.cranker.insecure_clone();
+        let set_ix = set_setup.build_instruction(ctx);
+        set_ix.send_expect_success(ctx);
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with(&self.cranker, self.claim.recipient_token_account)
+    }
+
+    pub fn build_instruction_with(&self, cranker: &Keypair, destination_token_account: Pubkey) -> TestInstruction {
+        self.build_instruction_with_amount(cranker, destination_token_account, 0, TransferFeeMode::Gross)
+    }
+
+    pub fn build_instruction_with_amount(
+        &self,
+        cranker: &Keypair,
+        destination_token_account: Pubkey,
+        amount: u64,
+        fee_mode: TransferFeeMode,
+    ) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = CrankClaimDirectBuilder::new();
+        builder
+            .cranker(cranker.pubkey())
+            .recipient(self.claim.recipient.pubkey())
+            .distribution(self.claim.distribution_pda)
+            .recipient_account(self.claim.recipient_pda)
+            .claim_delegate_account(self.claim_delegate_pda)
+            .mint(self.claim.mint)
+            .distribution_vault(self.claim.distribution_vault)
+            .destination_token_account(destination_token_account)
+            .token_program(self.claim.token_program)
+            .event_authority(event_authority)
+            .amount(amount)
+            .fee_mode(fee_mode);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![cranker.insecure_clone()],
+            name: "CrankClaimDirect",
+        }
+    }
+}
+
+pub struct CrankClaimDirectFixture;
+
+impl InstructionTestFixture for CrankClaimDirectFixture {
+    const INSTRUCTION_NAME: &'static str = "CrankClaimDirect";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = CrankClaimDirectSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: cranker
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 2: distribution
+    /// 3: recipient_account
+    /// 6: distribution_vault
+    /// 7: destination_token_account
+    fn required_writable() -> &'static [usize] {
+        &[2, 3, 6, 7]
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(10)
+    }
+
+    fn data_len() -> usize {
+        10 // discriminator (1) + amount (8) + fee_mode (1)
+    }
+}
@@ -0,0 +1,125 @@
+use rewards_program_client::{instructions::CrankClaimMerkleBuilder, types::TransferFeeMode};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::{ClaimMerkleSetup, SetClaimDelegateSetup};
+use crate::utils::{
+    find_claim_delegate_pda, find_event_authority_pda, InstructionTestFixture, TestContext, TestInstruction,
+};
+
+pub struct CrankClaimMerkleSetup {
+    pub claim: ClaimMerkleSetup,
+    pub cranker: Keypair,
+    pub claim_delegate_pda: Pubkey,
+}
+
+impl CrankClaimMerkleSetup {
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let claim = ClaimMerkleSetup::new(ctx);
+        Self::from_claim_setup(ctx, claim)
+    }
+
+    pub fn from_claim_setup(ctx: &mut TestContext, claim: ClaimMerkleSetup) -> Self {
+        let cranker = ctx.create_funded_keypair();
+        let (claim_delegate_pda, _) = find_claim_delegate_pda(&claim.distribution_pda, &claim.claimant.pubkey());
+
+        Self { claim, cranker, claim_delegate_pda }
+    }
+
+    /// Make `cranker` the claimant's claim delegate
+    pub fn set_cranker_as_delegate(&self, ctx: &mut TestContext) {
+        let mut set_setup =
+            SetClaimDelegateSetup::for_recipient(ctx, self.claim.distribution_pda, &self.claim.claimant);
+        set_setup.delegate = self.cranker.insecure_clone();
+        let set_ix = set_setup.build_instruction(ctx);
+        set_ix.send_expect_success(ctx);
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with(&self.cranker, self.claim.claimant_token_account)
+    }
+
+    pub fn build_instruction_with(&self, cranker: &Keypair, destination_token_account: Pubkey) -> TestInstruction {
+        self.build_instruction_with_amount(cranker, destination_token_account, 0, TransferFeeMode::Gross)
+    }
+
+    pub fn build_instruction_with_amount(
+        &self,
+        cranker: &Keypair,
+        destination_token_account: Pubkey,
+        amount: u64,
+        fee_mode: TransferFeeMode,
+    ) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = CrankClaimMerkleBuilder::new();
+        builder
+            .cranker(cranker.pubkey())
+            .claimant(self.claim.claimant.pubkey())
+            .distribution(self.claim.distribution_pda)
+            .claim_account(self.claim.claim_pda)
+            .revocation_account(self.claim.revocation_pda)
+            .redirect_account(self.claim.redirect_pda)
+            .claim_delegate_account(self.claim_delegate_pda)
+            .mint(self.claim.mint)
+            .distribution_vault(self.claim.distribution_vault)
+            .destination_token_account(destination_token_account)
+            .token_program(self.claim.token_program)
+            .event_authority(event_authority)
+            .claim_bump(self.claim.claim_bump)
+            .total_amount(self.claim.total_amount)
+            .schedule(self.claim.schedule.clone())
+            .amount(amount)
+            .fee_mode(fee_mode)
+            .proof(self.claim.proof.clone());
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![cranker.insecure_clone()],
+            name: "CrankClaimMerkle",
+        }
+    }
+}
+
+pub struct CrankClaimMerkleFixture;
+
+impl InstructionTestFixture for CrankClaimMerkleFixture {
+    const INSTRUCTION_NAME: &'static str = "CrankClaimMerkle";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = CrankClaimMerkleSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: cranker
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: cranker
+    /// 2: distribution
+    /// 3: claim_account
+    /// 8: distribution_vault
+    /// 9: destination_token_account
+    fn required_writable() -> &'static [usize] {
+        &[0, 2, 3, 8, 9]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(10)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(13)
+    }
+
+    fn data_len() -> usize {
+        // discriminator(1) + claim_bump(1) + total_amount(8) + amount(8) + fee_mode(1) + Linear schedule(17)
+        // + proof_len(4) + proof(32)
+        1 + 1 + 8 + 8 + 1 + 17 + 4 + 32
+    }
+}
@@ -1,9 +1,9 @@
-use rewards_program_client::instructions::CreateDirectDistributionBuilder;
+use rewards_program_client::{instructions::CreateDirectDistributionBuilder, types::ClosePolicy};
 use solana_sdk::{
     pubkey::Pubkey,
     signature::{Keypair, Signer},
 };
-use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use spl_token_2022::{extension::ExtensionType, ID as TOKEN_2022_PROGRAM_ID};
 use spl_token_interface::ID as TOKEN_PROGRAM_ID;
 
 use crate::utils::{
@@ -20,6 +20,9 @@ pub struct CreateDirectDistributionSetup {
     pub token_program: Pubkey,
     pub revocable: u8,
     pub clawback_ts: i64,
+    pub close_policy: ClosePolicy,
+    pub claim_start_ts: i64,
+    pub claim_end_ts: i64,
 }
 
 impl CreateDirectDistributionSetup {
@@ -50,7 +53,10 @@ impl CreateDirectDistributionSetup {
             .event_authority(event_authority)
             .bump(self.bump)
             .revocable(self.revocable)
-            .clawback_ts(self.clawback_ts);
+            .clawback_ts(self.clawback_ts)
+            .close_policy(self.close_policy)
+            .claim_start_ts(self.claim_start_ts)
+            .claim_end_ts(self.claim_end_ts);
 
         TestInstruction {
             instruction: builder.instruction(),
@@ -65,11 +71,24 @@ pub struct CreateDirectDistributionSetupBuilder<'a> {
     token_program: Pubkey,
     revocable: u8,
     clawback_ts: i64,
+    close_policy: ClosePolicy,
+    claim_start_ts: i64,
+    claim_end_ts: i64,
+    mint_extension: Option<ExtensionType>,
 }
 
 impl<'a> CreateDirectDistributionSetupBuilder<'a> {
     fn new(ctx: &'a mut TestContext) -> Self {
-        Self { ctx, token_program: TOKEN_PROGRAM_ID, revocable: 0, clawback_ts: 0 }
+        Self {
+            ctx,
+            token_program: TOKEN_PROGRAM_ID,
+            revocable: 0,
+            clawback_ts: 0,
+            close_policy: ClosePolicy::Sweep,
+            claim_start_ts: 0,
+            claim_end_ts: 0,
+            mint_extension: None,
+        }
     }
 
     pub fn token_2022(mut self) -> Self {
@@ -82,6 +101,12 @@ impl<'a> CreateDirectDistributionSetupBuilder<'a> {
         self
     }
 
+    pub fn mint_extension(mut self, extension_type: ExtensionType) -> Self {
+        self.token_program = TOKEN_2022_PROGRAM_ID;
+        self.mint_extension = Some(extension_type);
+        self
+    }
+
     pub fn revocable(mut self, revocable: u8) -> Self {
         self.revocable = revocable;
         self
@@ -92,13 +117,30 @@ impl<'a> CreateDirectDistributionSetupBuilder<'a> {
         self
     }
 
+    pub fn claim_window(mut self, claim_start_ts: i64, claim_end_ts: i64) -> Self {
+        self.claim_start_ts = claim_start_ts;
+        self.claim_end_ts = claim_end_ts;
+        self
+    }
+
+    /// Keep unclaimed allocations in the vault when the distribution is closed
+    pub fn protect_recipients(mut self) -> Self {
+        self.close_policy = ClosePolicy::ProtectRecipients;
+        self
+    }
+
     pub fn build(self) -> CreateDirectDistributionSetup {
         let authority = self.ctx.create_funded_keypair();
         let seeds = Keypair::new();
         let mint = Keypair::new();
         let token_program = self.token_program;
 
-        self.ctx.create_mint_for_program(&mint, &self.ctx.payer.pubkey(), 6, &token_program);
+        match self.mint_extension {
+            Some(extension_type) => {
+                self.ctx.create_token_2022_mint_with_extension(&mint, &self.ctx.payer.pubkey(), 6, extension_type)
+            }
+            None => self.ctx.create_mint_for_program(&mint, &self.ctx.payer.pubkey(), 6, &token_program),
+        }
 
         let (distribution_pda, bump) =
             find_direct_distribution_pda(&mint.pubkey(), &authority.pubkey(), &seeds.pubkey());
@@ -114,6 +156,9 @@ impl<'a> CreateDirectDistributionSetupBuilder<'a> {
             token_program,
             revocable: self.revocable,
             clawback_ts: self.clawback_ts,
+            close_policy: self.close_policy,
+            claim_start_ts: self.claim_start_ts,
+            claim_end_ts: self.claim_end_ts,
         }
     }
 }
@@ -153,6 +198,6 @@ impl InstructionTestFixture for CreateDirectDistributionFixture {
     }
 
     fn data_len() -> usize {
-        1 + 1 + 1 + 8 // discriminator + bump + revocable + clawback_ts
+        1 + 1 + 1 + 8 + 1 + 8 + 8 // discriminator + bump + revocable + clawback_ts + close_policy + claim_start_ts + claim_end_ts
     }
 }
@@ -1,9 +1,9 @@
-use rewards_program_client::instructions::CreateMerkleDistributionBuilder;
+use rewards_program_client::{instructions::CreateMerkleDistributionBuilder, types::MerkleClaimMode};
 use solana_sdk::{
     pubkey::Pubkey,
     signature::{Keypair, Signer},
 };
-use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use spl_token_2022::{extension::ExtensionType, ID as TOKEN_2022_PROGRAM_ID};
 use spl_token_interface::ID as TOKEN_PROGRAM_ID;
 
 use crate::utils::{
@@ -25,6 +25,9 @@ pub struct CreateMerkleDistributionSetup {
     pub total_amount: u64,
     pub merkle_root: [u8; 32],
     pub clawback_ts: i64,
+    pub claim_mode: MerkleClaimMode,
+    pub claim_start_ts: i64,
+    pub claim_end_ts: i64,
     pub token_program: Pubkey,
 }
 
@@ -60,7 +63,10 @@ impl CreateMerkleDistributionSetup {
             .amount(self.amount)
             .merkle_root(self.merkle_root)
             .total_amount(self.total_amount)
-            .clawback_ts(self.clawback_ts);
+            .clawback_ts(self.clawback_ts)
+            .claim_mode(self.claim_mode)
+            .claim_start_ts(self.claim_start_ts)
+            .claim_end_ts(self.claim_end_ts);
 
         TestInstruction {
             instruction: builder.instruction(),
@@ -92,7 +98,10 @@ impl CreateMerkleDistributionSetup {
             .amount(self.amount)
             .merkle_root(self.merkle_root)
             .total_amount(self.total_amount)
-            .clawback_ts(self.clawback_ts);
+            .clawback_ts(self.clawback_ts)
+            .claim_mode(self.claim_mode)
+            .claim_start_ts(self.claim_start_ts)
+            .claim_end_ts(self.claim_end_ts);
 
         TestInstruction {
             instruction: builder.instruction(),
@@ -110,6 +119,10 @@ pub struct CreateMerkleDistributionSetupBuilder<'a> {
     total_amount: Option<u64>,
     merkle_root: Option<[u8; 32]>,
     clawback_ts: Option<i64>,
+    claim_mode: MerkleClaimMode,
+    claim_start_ts: i64,
+    claim_end_ts: i64,
+    mint_extension: Option<ExtensionType>,
 }
 
 impl<'a> CreateMerkleDistributionSetupBuilder<'a> {
@@ -122,6 +135,10 @@ impl<'a> CreateMerkleDistributionSetupBuilder<'a> {
             total_amount: None,
             merkle_root: None,
             clawback_ts: None,
+            claim_mode: MerkleClaimMode::ClaimAccount,
+            claim_start_ts: 0,
+            claim_end_ts: 0,
+            mint_extension: None,
         }
     }
 
@@ -135,6 +152,12 @@ impl<'a> CreateMerkleDistributionSetupBuilder<'a> {
         self
     }
 
+    pub fn mint_extension(mut self, extension_type: ExtensionType) -> Self {
+        self.token_program = TOKEN_2022_PROGRAM_ID;
+        self.mint_extension = Some(extension_type);
+        self
+    }
+
     pub fn revocable(mut self, revocable: u8) -> Self {
         self.revocable = revocable;
         self
@@ -160,13 +183,29 @@ impl<'a> CreateMerkleDistributionSetupBuilder<'a> {
         self
     }
 
+    pub fn claim_window(mut self, claim_start_ts: i64, claim_end_ts: i64) -> Self {
+        self.claim_start_ts = claim_start_ts;
+        self.claim_end_ts = claim_end_ts;
+        self
+    }
+
+    pub fn bitmap(mut self) -> Self {
+        self.claim_mode = MerkleClaimMode::Bitmap;
+        self
+    }
+
     pub fn build(self) -> CreateMerkleDistributionSetup {
         let authority = self.ctx.create_funded_keypair();
         let seeds = Keypair::new();
         let mint = Keypair::new();
         let token_program = self.token_program;
 
-        self.ctx.create_mint_for_program(&mint, &self.ctx.payer.pubkey(), 6, &token_program);
+        match self.mint_extension {
+            Some(extension_type) => {
+                self.ctx.create_token_2022_mint_with_extension(&mint, &self.ctx.payer.pubkey(), 6, extension_type)
+            }
+            None => self.ctx.create_mint_for_program(&mint, &self.ctx.payer.pubkey(), 6, &token_program),
+        }
 
         let (distribution_pda, bump) =
             find_merkle_distribution_pda(&mint.pubkey(), &authority.pubkey(), &seeds.pubkey());
@@ -196,6 +235,9 @@ impl<'a> CreateMerkleDistributionSetupBuilder<'a> {
             total_amount,
             merkle_root,
             clawback_ts,
+            claim_mode: self.claim_mode,
+            claim_start_ts: self.claim_start_ts,
+            claim_end_ts: self.claim_end_ts,
             token_program,
         }
     }
@@ -237,6 +279,6 @@ impl InstructionTestFixture for CreateMerkleDistributionFixture {
     }
 
     fn data_len() -> usize {
-        1 + 1 + 1 + 8 + 32 + 8 + 8 // discriminator + bump + revocable + amount + merkle_root + total_amount + clawback_ts
+        1 + 1 + 1 + 8 + 32 + 8 + 8 + 1 + 8 + 8 // discriminator + bump + revocable + amount + merkle_root + total_amount + clawback_ts + claim_mode + claim_start_ts + claim_end_ts
     }
 }
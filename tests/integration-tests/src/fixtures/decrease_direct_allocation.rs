@@ -0,0 +1,86 @@
+use rewards_program_client::instructions::DecreaseDirectAllocationBuilder;
+use solana_sdk::signature::Signer;
+
+use crate::fixtures::RevokeDirectRecipientSetup;
+use crate::utils::{find_event_authority_pda, InstructionTestFixture, TestContext, TestInstruction};
+
+pub const DEFAULT_DECREASE_AMOUNT: u64 = 250_000;
+
+pub struct DecreaseDirectAllocationSetup {
+    /// The recipient being decreased, with a linear schedule starting now
+    pub recipient_setup: RevokeDirectRecipientSetup,
+    pub amount: u64,
+}
+
+impl DecreaseDirectAllocationSetup {
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let recipient_setup = RevokeDirectRecipientSetup::new(ctx);
+        Self::from_recipient(recipient_setup, DEFAULT_DECREASE_AMOUNT)
+    }
+
+    pub fn from_recipient(recipient_setup: RevokeDirectRecipientSetup, amount: u64) -> Self {
+        Self { recipient_setup, amount }
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        let setup = &self.recipient_setup;
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = DecreaseDirectAllocationBuilder::new();
+        builder
+            .authority(setup.authority.pubkey())
+            .distribution(setup.distribution_pda)
+            .recipient_account(setup.recipient_pda)
+            .recipient(setup.recipient.pubkey())
+            .mint(setup.mint)
+            .distribution_vault(setup.distribution_vault)
+            .authority_token_account(setup.authority_token_account)
+            .token_program(setup.token_program)
+            .event_authority(event_authority)
+            .amount(self.amount);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![setup.authority.insecure_clone()],
+            name: "DecreaseDirectAllocation",
+        }
+    }
+}
+
+pub struct DecreaseDirectAllocationFixture;
+
+impl InstructionTestFixture for DecreaseDirectAllocationFixture {
+    const INSTRUCTION_NAME: &'static str = "DecreaseDirectAllocation";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = DecreaseDirectAllocationSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: authority
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 1: distribution
+    /// 2: recipient_account
+    /// 5: distribution_vault
+    /// 6: authority_token_account
+    fn required_writable() -> &'static [usize] {
+        &[1, 2, 5, 6]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        None
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(9)
+    }
+
+    fn data_len() -> usize {
+        1 + 8 // discriminator + amount
+    }
+}
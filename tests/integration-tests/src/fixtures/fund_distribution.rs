@@ -0,0 +1,163 @@
+use rewards_program_client::instructions::FundDistributionBuilder;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use spl_token_interface::ID as TOKEN_PROGRAM_ID;
+
+use crate::fixtures::{CreateDirectDistributionSetup, CreateMerkleDistributionSetup};
+use crate::utils::{find_event_authority_pda, InstructionTestFixture, TestContext, TestInstruction};
+
+pub const DEFAULT_FUND_AMOUNT: u64 = 500_000;
+
+pub struct FundDistributionSetup {
+    pub funder: Keypair,
+    pub distribution_pda: Pubkey,
+    pub mint: Pubkey,
+    pub distribution_vault: Pubkey,
+    pub funder_token_account: Pubkey,
+    pub token_program: Pubkey,
+    pub amount: u64,
+    /// Amount already recorded in `total_funded` before this instruction runs
+    pub initial_funded: u64,
+}
+
+impl FundDistributionSetup {
+    pub fn builder(ctx: &mut TestContext) -> FundDistributionSetupBuilder<'_> {
+        FundDistributionSetupBuilder::new(ctx)
+    }
+
+    pub fn new(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).build()
+    }
+
+    pub fn new_merkle(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).merkle().build()
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with_funder(&self.funder, self.funder_token_account)
+    }
+
+    pub fn build_instruction_with_funder(&self, funder: &Keypair, funder_token_account: Pubkey) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = FundDistributionBuilder::new();
+        builder
+            .funder(funder.pubkey())
+            .distribution(self.distribution_pda)
+            .mint(self.mint)
+            .distribution_vault(self.distribution_vault)
+            .funder_token_account(funder_token_account)
+            .token_program(self.token_program)
+            .event_authority(event_authority)
+            .amount(self.amount);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![funder.insecure_clone()],
+            name: "FundDistribution",
+        }
+    }
+}
+
+pub struct FundDistributionSetupBuilder<'a> {
+    ctx: &'a mut TestContext,
+    token_program: Pubkey,
+    merkle: bool,
+    amount: u64,
+}
+
+impl<'a> FundDistributionSetupBuilder<'a> {
+    fn new(ctx: &'a mut TestContext) -> Self {
+        Self { ctx, token_program: TOKEN_PROGRAM_ID, merkle: false, amount: DEFAULT_FUND_AMOUNT }
+    }
+
+    pub fn token_2022(mut self) -> Self {
+        self.token_program = TOKEN_2022_PROGRAM_ID;
+        self
+    }
+
+    pub fn merkle(mut self) -> Self {
+        self.merkle = true;
+        self
+    }
+
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = amount;
+        self
+    }
+
+    pub fn build(self) -> FundDistributionSetup {
+        let (distribution_pda, mint, distribution_vault, initial_funded) = if self.merkle {
+            let distribution_setup =
+                CreateMerkleDistributionSetup::builder(self.ctx).token_program(self.token_program).build();
+            distribution_setup.build_instruction(self.ctx).send_expect_success(self.ctx);
+            (
+                distribution_setup.distribution_pda,
+                distribution_setup.mint.pubkey(),
+                distribution_setup.distribution_vault,
+                distribution_setup.amount,
+            )
+        } else {
+            let distribution_setup =
+                CreateDirectDistributionSetup::builder(self.ctx).token_program(self.token_program).build();
+            distribution_setup.build_instruction(self.ctx).send_expect_success(self.ctx);
+            (
+                distribution_setup.distribution_pda,
+                distribution_setup.mint.pubkey(),
+                distribution_setup.distribution_vault,
+                0,
+            )
+        };
+
+        let funder = self.ctx.create_funded_keypair();
+        let funder_token_account =
+            self.ctx.create_ata_for_program_with_balance(&funder.pubkey(), &mint, self.amount, &self.token_program);
+
+        FundDistributionSetup {
+            funder,
+            distribution_pda,
+            mint,
+            distribution_vault,
+            funder_token_account,
+            token_program: self.token_program,
+            amount: self.amount,
+            initial_funded,
+        }
+    }
+}
+
+pub struct FundDistributionFixture;
+
+impl InstructionTestFixture for FundDistributionFixture {
+    const INSTRUCTION_NAME: &'static str = "FundDistribution";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = FundDistributionSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: funder
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 1: distribution
+    /// 3: distribution_vault
+    /// 4: funder_token_account
+    fn required_writable() -> &'static [usize] {
+        &[1, 3, 4]
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(7)
+    }
+
+    fn data_len() -> usize {
+        1 + 8 // discriminator + amount
+    }
+}
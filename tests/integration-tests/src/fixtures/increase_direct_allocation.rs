@@ -0,0 +1,104 @@
+use rewards_program_client::instructions::IncreaseDirectAllocationBuilder;
+use rewards_program_client::types::VestingSchedule;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::RevokeDirectRecipientSetup;
+use crate::utils::{find_event_authority_pda, InstructionTestFixture, TestContext, TestInstruction};
+
+pub const DEFAULT_INCREASE_AMOUNT: u64 = 500_000;
+
+pub struct IncreaseDirectAllocationSetup {
+    /// The recipient being increased, with a linear schedule starting now
+    pub recipient_setup: RevokeDirectRecipientSetup,
+    pub payer: Keypair,
+    pub amount: u64,
+}
+
+impl IncreaseDirectAllocationSetup {
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let recipient_setup = RevokeDirectRecipientSetup::new(ctx);
+        Self::from_recipient(ctx, recipient_setup, DEFAULT_INCREASE_AMOUNT)
+    }
+
+    pub fn from_recipient(ctx: &mut TestContext, recipient_setup: RevokeDirectRecipientSetup, amount: u64) -> Self {
+        // Adding the recipient spent the authority's whole balance
+        ctx.set_token_balance(&recipient_setup.authority_token_account, amount);
+        Self { recipient_setup, payer: ctx.create_funded_keypair(), amount }
+    }
+
+    pub fn build_instruction(&self, ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with_schedule(ctx, None)
+    }
+
+    pub fn build_instruction_with_schedule(
+        &self,
+        _ctx: &TestContext,
+        schedule: Option<VestingSchedule>,
+    ) -> TestInstruction {
+        let setup = &self.recipient_setup;
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = IncreaseDirectAllocationBuilder::new();
+        builder
+            .payer(self.payer.pubkey())
+            .authority(setup.authority.pubkey())
+            .distribution(setup.distribution_pda)
+            .recipient_account(setup.recipient_pda)
+            .recipient(setup.recipient.pubkey())
+            .mint(setup.mint)
+            .distribution_vault(setup.distribution_vault)
+            .authority_token_account(setup.authority_token_account)
+            .token_program(setup.token_program)
+            .event_authority(event_authority)
+            .amount(self.amount);
+        if let Some(schedule) = schedule {
+            builder.schedule(schedule);
+        }
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![self.payer.insecure_clone(), setup.authority.insecure_clone()],
+            name: "IncreaseDirectAllocation",
+        }
+    }
+}
+
+pub struct IncreaseDirectAllocationFixture;
+
+impl InstructionTestFixture for IncreaseDirectAllocationFixture {
+    const INSTRUCTION_NAME: &'static str = "IncreaseDirectAllocation";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = IncreaseDirectAllocationSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: payer
+    /// 1: authority
+    fn required_signers() -> &'static [usize] {
+        &[0, 1]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: payer
+    /// 2: distribution
+    /// 3: recipient_account
+    /// 6: distribution_vault
+    /// 7: authority_token_account
+    fn required_writable() -> &'static [usize] {
+        &[0, 2, 3, 6, 7]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(8)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(11)
+    }
+
+    fn data_len() -> usize {
+        1 + 8 + 1 // discriminator + amount + schedule option flag
+    }
+}
@@ -0,0 +1,96 @@
+use rewards_program_client::instructions::MigrateAccountBuilder;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::CreateDirectDistributionSetup;
+use crate::utils::{InstructionTestFixture, TestContext, TestInstruction};
+
+/// Total account length of each DirectDistribution layout, starting at version 1
+pub const DIRECT_DISTRIBUTION_VERSION_LENS: [usize; 5] = [130, 194, 202, 218, 234];
+
+/// Total account length of each MerkleDistribution layout, starting at version 1
+pub const MERKLE_DISTRIBUTION_VERSION_LENS: [usize; 6] = [162, 226, 234, 242, 258, 274];
+
+/// Total account length of each MerkleClaim layout, starting at version 1
+pub const MERKLE_CLAIM_VERSION_LENS: [usize; 2] = [18, 50];
+
+pub struct MigrateAccountSetup {
+    pub payer: Keypair,
+    pub account: Pubkey,
+}
+
+impl MigrateAccountSetup {
+    /// A direct distribution rewritten as its v1 layout
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let distribution_setup = CreateDirectDistributionSetup::new(ctx);
+        distribution_setup.build_instruction(ctx).send_expect_success(ctx);
+        downgrade_account(ctx, &distribution_setup.distribution_pda, 1, DIRECT_DISTRIBUTION_VERSION_LENS[0]);
+
+        Self::from_account(ctx, distribution_setup.distribution_pda)
+    }
+
+    pub fn from_account(ctx: &mut TestContext, account: Pubkey) -> Self {
+        Self { payer: ctx.create_funded_keypair(), account }
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        let mut builder = MigrateAccountBuilder::new();
+        builder.payer(self.payer.pubkey()).account(self.account);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![self.payer.insecure_clone()],
+            name: "MigrateAccount",
+        }
+    }
+}
+
+/// Rewrite a program account as it was stored at an older layout `version`.
+///
+/// Layouts only ever appended fields, so the older layout is a prefix of the current data.
+/// The account keeps only the rent its old size needed.
+pub fn downgrade_account(ctx: &mut TestContext, account: &Pubkey, version: u8, len: usize) {
+    let mut legacy = ctx.get_account(account).expect("Account should exist");
+    legacy.data[1] = version;
+    legacy.data.truncate(len);
+    legacy.lamports = ctx.svm.minimum_balance_for_rent_exemption(len);
+    ctx.svm.set_account(*account, legacy).unwrap();
+}
+
+pub struct MigrateAccountFixture;
+
+impl InstructionTestFixture for MigrateAccountFixture {
+    const INSTRUCTION_NAME: &'static str = "MigrateAccount";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = MigrateAccountSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: payer
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: payer
+    /// 1: account
+    fn required_writable() -> &'static [usize] {
+        &[0, 1]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(2)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        None
+    }
+
+    fn data_len() -> usize {
+        1 // discriminator only
+    }
+}
@@ -1,25 +1,85 @@
+pub mod accept_authority;
 pub mod add_direct_recipient;
+pub mod add_direct_recipients;
+pub mod basket;
+pub mod cancel_authority_proposal;
 pub mod claim_direct;
+pub mod claim_direct_many;
 pub mod claim_merkle;
+pub mod claim_merkle_bitmap;
 pub mod close_direct_distribution;
 pub mod close_direct_recipient;
 pub mod close_merkle_claim;
+pub mod close_merkle_claim_early;
 pub mod close_merkle_distribution;
+pub mod compressed;
+pub mod crank_claim_direct;
+pub mod crank_claim_merkle;
 pub mod create_direct_distribution;
 pub mod create_merkle_distribution;
+pub mod decrease_direct_allocation;
+pub mod fund_distribution;
+pub mod increase_direct_allocation;
+pub mod migrate_account;
+pub mod native_sol;
+pub mod pause_distribution;
+pub mod propose_authority;
+pub mod remove_claim_delegate;
 pub mod revoke_direct_recipient;
 pub mod revoke_merkle_claim;
+pub mod set_claim_delegate;
+pub mod set_transferable;
+pub mod transfer_direct_recipient;
+pub mod transfer_merkle_claim;
+pub mod unpause_distribution;
+pub mod update_merkle_root;
 
+pub use accept_authority::{AcceptAuthorityFixture, AcceptAuthoritySetup};
 pub use add_direct_recipient::{AddDirectRecipientFixture, AddDirectRecipientSetup, DEFAULT_RECIPIENT_AMOUNT};
+pub use add_direct_recipients::{
+    AddDirectRecipientsFixture, AddDirectRecipientsSetup, BatchRecipient, DEFAULT_BATCH_SIZE,
+};
+pub use basket::{BasketMint, BasketRecipientSetup, BasketSetup, ClaimBasketFixture, CreateBasketDistributionFixture};
+pub use cancel_authority_proposal::{CancelAuthorityProposalFixture, CancelAuthorityProposalSetup};
 pub use claim_direct::{ClaimDirectFixture, ClaimDirectSetup};
+pub use claim_direct_many::{ClaimDirectManyFixture, ClaimDirectManySetup, Grant, DEFAULT_GRANT_COUNT};
 pub use claim_merkle::{ClaimMerkleFixture, ClaimMerkleSetup, DEFAULT_CLAIMANT_AMOUNT};
+pub use claim_merkle_bitmap::{ClaimMerkleBitmapFixture, ClaimMerkleBitmapSetup, CLAIM_BITMAP_CHUNK_BITS};
 pub use close_direct_distribution::{CloseDirectDistributionFixture, CloseDirectDistributionSetup};
 pub use close_direct_recipient::{CloseDirectRecipientFixture, CloseDirectRecipientSetup};
 pub use close_merkle_claim::{CloseMerkleClaimFixture, CloseMerkleClaimSetup};
+pub use close_merkle_claim_early::{CloseMerkleClaimEarlyFixture, CloseMerkleClaimEarlySetup};
 pub use close_merkle_distribution::{CloseMerkleDistributionFixture, CloseMerkleDistributionSetup};
+pub use compressed::{
+    AddCompressedRecipientFixture, ClaimCompressedFixture, CompressedRecipientSetup, CompressedSetup,
+    CreateCompressedDistributionFixture,
+};
+pub use crank_claim_direct::{CrankClaimDirectFixture, CrankClaimDirectSetup};
+pub use crank_claim_merkle::{CrankClaimMerkleFixture, CrankClaimMerkleSetup};
 pub use create_direct_distribution::{CreateDirectDistributionFixture, CreateDirectDistributionSetup};
 pub use create_merkle_distribution::{
     CreateMerkleDistributionFixture, CreateMerkleDistributionSetup, DEFAULT_MERKLE_DISTRIBUTION_AMOUNT,
 };
+pub use decrease_direct_allocation::{
+    DecreaseDirectAllocationFixture, DecreaseDirectAllocationSetup, DEFAULT_DECREASE_AMOUNT,
+};
+pub use fund_distribution::{FundDistributionFixture, FundDistributionSetup, DEFAULT_FUND_AMOUNT};
+pub use increase_direct_allocation::{
+    IncreaseDirectAllocationFixture, IncreaseDirectAllocationSetup, DEFAULT_INCREASE_AMOUNT,
+};
+pub use migrate_account::{
+    downgrade_account, MigrateAccountFixture, MigrateAccountSetup, DIRECT_DISTRIBUTION_VERSION_LENS,
+    MERKLE_CLAIM_VERSION_LENS, MERKLE_DISTRIBUTION_VERSION_LENS,
+};
+pub use native_sol::{NativeDirectDistributionSetup, NATIVE_SOL_MINT};
+pub use pause_distribution::{PauseDistributionFixture, PauseDistributionSetup};
+pub use propose_authority::{ProposeAuthorityFixture, ProposeAuthoritySetup};
+pub use remove_claim_delegate::{RemoveClaimDelegateFixture, RemoveClaimDelegateSetup};
 pub use revoke_direct_recipient::{RevokeDirectRecipientFixture, RevokeDirectRecipientSetup};
 pub use revoke_merkle_claim::{RevokeMerkleClaimFixture, RevokeMerkleClaimSetup};
+pub use set_claim_delegate::{SetClaimDelegateFixture, SetClaimDelegateSetup};
+pub use set_transferable::{SetTransferableFixture, SetTransferableSetup};
+pub use transfer_direct_recipient::{TransferDirectRecipientFixture, TransferDirectRecipientSetup};
+pub use transfer_merkle_claim::{TransferMerkleClaimFixture, TransferMerkleClaimSetup};
+pub use unpause_distribution::{UnpauseDistributionFixture, UnpauseDistributionSetup};
+pub use update_merkle_root::{UpdateMerkleRootFixture, UpdateMerkleRootSetup};
@@ -0,0 +1,133 @@
+use rewards_program_client::{
+    instructions::CreateDirectDistributionBuilder,
+    types::{ClosePolicy, VestingSchedule},
+};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+
+use crate::fixtures::{
+    AddDirectRecipientSetup, ClaimDirectSetup, CloseDirectDistributionSetup, DEFAULT_RECIPIENT_AMOUNT,
+};
+use crate::utils::{
+    find_direct_distribution_pda, find_direct_recipient_pda, find_event_authority_pda, find_native_vault_pda,
+    TestContext, TestInstruction,
+};
+
+/// Mint of native SOL distributions; the System program doubles as their token program
+pub const NATIVE_SOL_MINT: Pubkey = SYSTEM_PROGRAM_ID;
+
+/// A direct distribution paying native SOL out of a program-owned lamport vault
+pub struct NativeDirectDistributionSetup {
+    pub authority: Keypair,
+    pub seed: Keypair,
+    pub distribution_pda: Pubkey,
+    pub bump: u8,
+    pub distribution_vault: Pubkey,
+}
+
+impl NativeDirectDistributionSetup {
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let authority = ctx.create_funded_keypair();
+        let seed = Keypair::new();
+        let (distribution_pda, bump) =
+            find_direct_distribution_pda(&NATIVE_SOL_MINT, &authority.pubkey(), &seed.pubkey());
+        let (distribution_vault, _) = find_native_vault_pda(&distribution_pda);
+
+        Self { authority, seed, distribution_pda, bump, distribution_vault }
+    }
+
+    /// Create the distribution and its vault
+    pub fn create(ctx: &mut TestContext) -> Self {
+        let setup = Self::new(ctx);
+        setup.build_create_instruction(ctx).send_expect_success(ctx);
+        setup
+    }
+
+    pub fn build_create_instruction(&self, ctx: &TestContext) -> TestInstruction {
+        self.build_create_instruction_with(ctx, self.distribution_vault, SYSTEM_PROGRAM_ID)
+    }
+
+    pub fn build_create_instruction_with(
+        &self,
+        ctx: &TestContext,
+        distribution_vault: Pubkey,
+        token_program: Pubkey,
+    ) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = CreateDirectDistributionBuilder::new();
+        builder
+            .payer(ctx.payer.pubkey())
+            .authority(self.authority.pubkey())
+            .seeds(self.seed.pubkey())
+            .distribution(self.distribution_pda)
+            .mint(NATIVE_SOL_MINT)
+            .distribution_vault(distribution_vault)
+            .token_program(token_program)
+            .event_authority(event_authority)
+            .bump(self.bump)
+            .revocable(0)
+            .clawback_ts(0)
+            .close_policy(ClosePolicy::Sweep)
+            .claim_start_ts(0)
+            .claim_end_ts(0);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![self.authority.insecure_clone(), self.seed.insecure_clone()],
+            name: "CreateDirectDistribution",
+        }
+    }
+
+    /// Allocation funded from the authority's wallet
+    pub fn recipient_setup(&self, ctx: &mut TestContext, schedule: VestingSchedule) -> AddDirectRecipientSetup {
+        let recipient = ctx.create_funded_keypair();
+        let (recipient_pda, recipient_bump) = find_direct_recipient_pda(&self.distribution_pda, &recipient.pubkey());
+
+        AddDirectRecipientSetup {
+            authority: self.authority.insecure_clone(),
+            distribution_pda: self.distribution_pda,
+            recipient,
+            recipient_pda,
+            recipient_bump,
+            amount: DEFAULT_RECIPIENT_AMOUNT,
+            schedule,
+            token_program: SYSTEM_PROGRAM_ID,
+            mint: NATIVE_SOL_MINT,
+            distribution_vault: self.distribution_vault,
+            authority_token_account: self.authority.pubkey(),
+        }
+    }
+
+    /// Claim paying straight into the recipient's wallet
+    pub fn claim_setup(recipient_setup: &AddDirectRecipientSetup) -> ClaimDirectSetup {
+        ClaimDirectSetup {
+            recipient: recipient_setup.recipient.insecure_clone(),
+            distribution_pda: recipient_setup.distribution_pda,
+            recipient_pda: recipient_setup.recipient_pda,
+            recipient_bump: recipient_setup.recipient_bump,
+            mint: NATIVE_SOL_MINT,
+            distribution_vault: recipient_setup.distribution_vault,
+            recipient_token_account: recipient_setup.recipient.pubkey(),
+            token_program: SYSTEM_PROGRAM_ID,
+            amount: recipient_setup.amount,
+            start_ts: recipient_setup.start_ts(),
+            end_ts: recipient_setup.end_ts(),
+        }
+    }
+
+    /// Close returning the remaining lamports to the authority's wallet
+    pub fn close_setup(&self) -> CloseDirectDistributionSetup {
+        CloseDirectDistributionSetup {
+            authority: self.authority.insecure_clone(),
+            distribution_pda: self.distribution_pda,
+            mint: NATIVE_SOL_MINT,
+            distribution_vault: self.distribution_vault,
+            authority_token_account: self.authority.pubkey(),
+            token_program: SYSTEM_PROGRAM_ID,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use rewards_program_client::instructions::PauseDistributionBuilder;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::{CreateDirectDistributionSetup, CreateMerkleDistributionSetup};
+use crate::utils::{find_event_authority_pda, InstructionTestFixture, TestContext, TestInstruction};
+
+pub struct PauseDistributionSetup {
+    pub authority: Keypair,
+    pub distribution_pda: Pubkey,
+}
+
+impl PauseDistributionSetup {
+    pub fn builder(ctx: &mut TestContext) -> PauseDistributionSetupBuilder<'_> {
+        PauseDistributionSetupBuilder::new(ctx)
+    }
+
+    pub fn new(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).build()
+    }
+
+    pub fn new_merkle(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).merkle().build()
+    }
+
+    /// Target an existing distribution controlled by `authority`
+    pub fn for_distribution(distribution_pda: Pubkey, authority: &Keypair) -> Self {
+        Self { authority: authority.insecure_clone(), distribution_pda }
+    }
+
+    /// Pause an existing distribution
+    pub fn pause(ctx: &mut TestContext, distribution_pda: Pubkey, authority: &Keypair) {
+        let setup = Self::for_distribution(distribution_pda, authority);
+        let pause_ix = setup.build_instruction(ctx);
+        pause_ix.send_expect_success(ctx);
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with(&self.authority)
+    }
+
+    pub fn build_instruction_with(&self, authority: &Keypair) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = PauseDistributionBuilder::new();
+        builder.authority(authority.pubkey()).distribution(self.distribution_pda).event_authority(event_authority);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![authority.insecure_clone()],
+            name: "PauseDistribution",
+        }
+    }
+}
+
+pub struct PauseDistributionSetupBuilder<'a> {
+    ctx: &'a mut TestContext,
+    merkle: bool,
+}
+
+impl<'a> PauseDistributionSetupBuilder<'a> {
+    fn new(ctx: &'a mut TestContext) -> Self {
+        Self { ctx, merkle: false }
+    }
+
+    pub fn merkle(mut self) -> Self {
+        self.merkle = true;
+        self
+    }
+
+    pub fn build(self) -> PauseDistributionSetup {
+        if self.merkle {
+            let distribution_setup = CreateMerkleDistributionSetup::new(self.ctx);
+            let create_ix = distribution_setup.build_instruction(self.ctx);
+            create_ix.send_expect_success(self.ctx);
+
+            PauseDistributionSetup {
+                authority: distribution_setup.authority,
+                distribution_pda: distribution_setup.distribution_pda,
+            }
+        } else {
+            let distribution_setup = CreateDirectDistributionSetup::new(self.ctx);
+            let create_ix = distribution_setup.build_instruction(self.ctx);
+            create_ix.send_expect_success(self.ctx);
+
+            PauseDistributionSetup {
+                authority: distribution_setup.authority,
+                distribution_pda: distribution_setup.distribution_pda,
+            }
+        }
+    }
+}
+
+pub struct PauseDistributionFixture;
+
+impl InstructionTestFixture for PauseDistributionFixture {
+    const INSTRUCTION_NAME: &'static str = "PauseDistribution";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = PauseDistributionSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: authority
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 1: distribution
+    fn required_writable() -> &'static [usize] {
+        &[1]
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(3)
+    }
+
+    fn data_len() -> usize {
+        1 // discriminator only
+    }
+}
@@ -0,0 +1,124 @@
+use rewards_program_client::instructions::ProposeAuthorityBuilder;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::{CreateDirectDistributionSetup, CreateMerkleDistributionSetup};
+use crate::utils::{InstructionTestFixture, TestContext, TestInstruction};
+
+pub struct ProposeAuthoritySetup {
+    pub authority: Keypair,
+    pub new_authority: Keypair,
+    pub distribution_pda: Pubkey,
+    pub mint: Pubkey,
+    pub distribution_vault: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl ProposeAuthoritySetup {
+    pub fn builder(ctx: &mut TestContext) -> ProposeAuthoritySetupBuilder<'_> {
+        ProposeAuthoritySetupBuilder::new(ctx)
+    }
+
+    pub fn new(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).build()
+    }
+
+    pub fn new_merkle(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).merkle().build()
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with_authority(&self.authority)
+    }
+
+    pub fn build_instruction_with_authority(&self, authority: &Keypair) -> TestInstruction {
+        let mut builder = ProposeAuthorityBuilder::new();
+        builder
+            .authority(authority.pubkey())
+            .distribution(self.distribution_pda)
+            .new_authority(self.new_authority.pubkey());
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![authority.insecure_clone()],
+            name: "ProposeAuthority",
+        }
+    }
+}
+
+pub struct ProposeAuthoritySetupBuilder<'a> {
+    ctx: &'a mut TestContext,
+    merkle: bool,
+}
+
+impl<'a> ProposeAuthoritySetupBuilder<'a> {
+    fn new(ctx: &'a mut TestContext) -> Self {
+        Self { ctx, merkle: false }
+    }
+
+    pub fn merkle(mut self) -> Self {
+        self.merkle = true;
+        self
+    }
+
+    pub fn build(self) -> ProposeAuthoritySetup {
+        let new_authority = self.ctx.create_funded_keypair();
+
+        if self.merkle {
+            let distribution_setup = CreateMerkleDistributionSetup::new(self.ctx);
+            let create_ix = distribution_setup.build_instruction(self.ctx);
+            create_ix.send_expect_success(self.ctx);
+
+            ProposeAuthoritySetup {
+                authority: distribution_setup.authority,
+                new_authority,
+                distribution_pda: distribution_setup.distribution_pda,
+                mint: distribution_setup.mint.pubkey(),
+                distribution_vault: distribution_setup.distribution_vault,
+                token_program: distribution_setup.token_program,
+            }
+        } else {
+            let distribution_setup = CreateDirectDistributionSetup::new(self.ctx);
+            let create_ix = distribution_setup.build_instruction(self.ctx);
+            create_ix.send_expect_success(self.ctx);
+
+            ProposeAuthoritySetup {
+                authority: distribution_setup.authority,
+                new_authority,
+                distribution_pda: distribution_setup.distribution_pda,
+                mint: distribution_setup.mint.pubkey(),
+                distribution_vault: distribution_setup.distribution_vault,
+                token_program: distribution_setup.token_program,
+            }
+        }
+    }
+}
+
+pub struct ProposeAuthorityFixture;
+
+impl InstructionTestFixture for ProposeAuthorityFixture {
+    const INSTRUCTION_NAME: &'static str = "ProposeAuthority";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = ProposeAuthoritySetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: authority
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 1: distribution
+    fn required_writable() -> &'static [usize] {
+        &[1]
+    }
+
+    fn data_len() -> usize {
+        1 + 32 // discriminator + new_authority
+    }
+}
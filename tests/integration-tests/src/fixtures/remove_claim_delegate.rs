@@ -0,0 +1,88 @@
+use rewards_program_client::instructions::RemoveClaimDelegateBuilder;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::SetClaimDelegateSetup;
+use crate::utils::{find_event_authority_pda, InstructionTestFixture, TestContext, TestInstruction};
+
+pub struct RemoveClaimDelegateSetup {
+    pub recipient: Keypair,
+    pub delegate: Keypair,
+    pub distribution_pda: Pubkey,
+    pub claim_delegate_pda: Pubkey,
+}
+
+impl RemoveClaimDelegateSetup {
+    /// Create a distribution and set a claim delegate that can then be removed
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let set_setup = SetClaimDelegateSetup::new(ctx);
+        Self::from_set_setup(ctx, &set_setup)
+    }
+
+    pub fn from_set_setup(ctx: &mut TestContext, set_setup: &SetClaimDelegateSetup) -> Self {
+        let set_ix = set_setup.build_instruction(ctx);
+        set_ix.send_expect_success(ctx);
+
+        Self {
+            recipient: set_setup.recipient.insecure_clone(),
+            delegate: set_setup.delegate.insecure_clone(),
+            distribution_pda: set_setup.distribution_pda,
+            claim_delegate_pda: set_setup.claim_delegate_pda,
+        }
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with_recipient(&self.recipient)
+    }
+
+    pub fn build_instruction_with_recipient(&self, recipient: &Keypair) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = RemoveClaimDelegateBuilder::new();
+        builder
+            .recipient(recipient.pubkey())
+            .distribution(self.distribution_pda)
+            .claim_delegate_account(self.claim_delegate_pda)
+            .event_authority(event_authority);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![recipient.insecure_clone()],
+            name: "RemoveClaimDelegate",
+        }
+    }
+}
+
+pub struct RemoveClaimDelegateFixture;
+
+impl InstructionTestFixture for RemoveClaimDelegateFixture {
+    const INSTRUCTION_NAME: &'static str = "RemoveClaimDelegate";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = RemoveClaimDelegateSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: recipient
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: recipient
+    /// 2: claim_delegate_account
+    fn required_writable() -> &'static [usize] {
+        &[0, 2]
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(4)
+    }
+
+    fn data_len() -> usize {
+        1 // discriminator only
+    }
+}
@@ -7,7 +7,7 @@ use solana_sdk::{
 use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
 use spl_token_interface::ID as TOKEN_PROGRAM_ID;
 
-use crate::fixtures::CreateDirectDistributionSetup;
+use crate::fixtures::{ClaimDirectSetup, CloseDirectRecipientSetup, CreateDirectDistributionSetup};
 use crate::utils::{
     find_direct_recipient_pda, find_event_authority_pda, InstructionTestFixture, TestContext, TestInstruction,
 };
@@ -97,6 +97,34 @@ impl RevokeDirectRecipientSetup {
             name: "RevokeDirectRecipient",
         }
     }
+    /// Claim setup for the recipient, e.g. to claim what vested before a freeze
+    pub fn claim_setup(&self) -> ClaimDirectSetup {
+        let (_, recipient_bump) = find_direct_recipient_pda(&self.distribution_pda, &self.recipient.pubkey());
+        ClaimDirectSetup {
+            recipient: self.recipient.insecure_clone(),
+            distribution_pda: self.distribution_pda,
+            recipient_pda: self.recipient_pda,
+            recipient_bump,
+            mint: self.mint,
+            distribution_vault: self.distribution_vault,
+            recipient_token_account: self.recipient_token_account,
+            token_program: self.token_program,
+            amount: self.amount,
+            start_ts: self.start_ts,
+            end_ts: self.end_ts,
+        }
+    }
+
+    /// Close setup for the recipient account, which a freeze leaves open
+    pub fn close_setup(&self) -> CloseDirectRecipientSetup {
+        CloseDirectRecipientSetup {
+            recipient: self.recipient.insecure_clone(),
+            original_payer: self.payer.insecure_clone(),
+            distribution_pda: self.distribution_pda,
+            recipient_pda: self.recipient_pda,
+            token_program: self.token_program,
+        }
+    }
 }
 
 pub struct RevokeDirectRecipientSetupBuilder<'a> {
@@ -147,6 +175,13 @@ impl<'a> RevokeDirectRecipientSetupBuilder<'a> {
         let (start_ts, end_ts) = match &schedule {
             VestingSchedule::Linear { start_ts, end_ts } => (*start_ts, *end_ts),
             VestingSchedule::CliffLinear { start_ts, end_ts, .. } => (*start_ts, *end_ts),
+            VestingSchedule::Stepped { start_ts, period_secs, num_periods }
+            | VestingSchedule::CliffStepped { start_ts, period_secs, num_periods, .. } => {
+                (*start_ts, *start_ts + (*period_secs * *num_periods as u64) as i64)
+            }
+            VestingSchedule::Tranches { points, .. } => {
+                (points.first().map_or(0, |point| point.timestamp), points.last().map_or(0, |point| point.timestamp))
+            }
             VestingSchedule::Cliff { cliff_ts } => (0, *cliff_ts),
             VestingSchedule::Immediate => (0, 0),
         };
@@ -1,5 +1,5 @@
 use rewards_program_client::instructions::RevokeMerkleClaimBuilder;
-use rewards_program_client::types::{RevokeMode, VestingSchedule};
+use rewards_program_client::types::{RevokeMode, TransferFeeMode, VestingSchedule};
 use solana_sdk::{
     pubkey::Pubkey,
     signature::{Keypair, Signer},
@@ -9,8 +9,8 @@ use spl_token_interface::ID as TOKEN_PROGRAM_ID;
 
 use crate::fixtures::CreateMerkleDistributionSetup;
 use crate::utils::{
-    find_event_authority_pda, find_merkle_claim_pda, find_merkle_revocation_pda, InstructionTestFixture, MerkleLeaf,
-    MerkleTree, TestContext, TestInstruction,
+    find_event_authority_pda, find_merkle_claim_pda, find_merkle_redirect_pda, find_merkle_revocation_pda,
+    InstructionTestFixture, MerkleLeaf, MerkleTree, TestContext, TestInstruction,
 };
 
 pub const DEFAULT_REVOKE_MERKLE_AMOUNT: u64 = 1_000_000;
@@ -114,6 +114,7 @@ impl RevokeMerkleClaimSetup {
 
     pub fn build_claim_instruction(&self, ctx: &TestContext) -> TestInstruction {
         let (event_authority, _) = find_event_authority_pda();
+        let (redirect_pda, _) = find_merkle_redirect_pda(&self.distribution_pda, &self.claimant.pubkey());
 
         let mut builder = rewards_program_client::instructions::ClaimMerkleBuilder::new();
         builder
@@ -122,6 +123,7 @@ impl RevokeMerkleClaimSetup {
             .distribution(self.distribution_pda)
             .claim_account(self.claim_pda)
             .revocation_account(self.revocation_pda)
+            .redirect_account(redirect_pda)
             .mint(self.mint)
             .distribution_vault(self.distribution_vault)
             .claimant_token_account(self.claimant_token_account)
@@ -131,6 +133,7 @@ impl RevokeMerkleClaimSetup {
             .total_amount(self.total_amount)
             .schedule(self.schedule.clone())
             .amount(0)
+            .fee_mode(TransferFeeMode::Gross)
             .proof(self.proof.clone());
 
         TestInstruction {
@@ -195,6 +198,13 @@ impl<'a> RevokeMerkleClaimSetupBuilder<'a> {
         let (start_ts, end_ts) = match &schedule {
             VestingSchedule::Linear { start_ts, end_ts } => (*start_ts, *end_ts),
             VestingSchedule::CliffLinear { start_ts, end_ts, .. } => (*start_ts, *end_ts),
+            VestingSchedule::Stepped { start_ts, period_secs, num_periods }
+            | VestingSchedule::CliffStepped { start_ts, period_secs, num_periods, .. } => {
+                (*start_ts, *start_ts + (*period_secs * *num_periods as u64) as i64)
+            }
+            VestingSchedule::Tranches { points, .. } => {
+                (points.first().map_or(0, |point| point.timestamp), points.last().map_or(0, |point| point.timestamp))
+            }
             VestingSchedule::Cliff { cliff_ts } => (0, *cliff_ts),
             VestingSchedule::Immediate => (0, 0),
         };
@@ -0,0 +1,132 @@
+use rewards_program_client::instructions::SetClaimDelegateBuilder;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::{CreateDirectDistributionSetup, CreateMerkleDistributionSetup};
+use crate::utils::{
+    find_claim_delegate_pda, find_event_authority_pda, InstructionTestFixture, TestContext, TestInstruction,
+};
+
+pub struct SetClaimDelegateSetup {
+    pub recipient: Keypair,
+    pub delegate: Keypair,
+    pub distribution_pda: Pubkey,
+    pub claim_delegate_pda: Pubkey,
+}
+
+impl SetClaimDelegateSetup {
+    pub fn builder(ctx: &mut TestContext) -> SetClaimDelegateSetupBuilder<'_> {
+        SetClaimDelegateSetupBuilder::new(ctx)
+    }
+
+    pub fn new(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).build()
+    }
+
+    pub fn new_merkle(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).merkle().build()
+    }
+
+    /// Set up a delegate for an existing recipient (or claimant) of `distribution_pda`
+    pub fn for_recipient(ctx: &mut TestContext, distribution_pda: Pubkey, recipient: &Keypair) -> Self {
+        let delegate = ctx.create_funded_keypair();
+        let (claim_delegate_pda, _) = find_claim_delegate_pda(&distribution_pda, &recipient.pubkey());
+
+        Self { recipient: recipient.insecure_clone(), delegate, distribution_pda, claim_delegate_pda }
+    }
+
+    pub fn build_instruction(&self, ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with_delegate(ctx, self.delegate.pubkey())
+    }
+
+    pub fn build_instruction_with_delegate(&self, ctx: &TestContext, delegate: Pubkey) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = SetClaimDelegateBuilder::new();
+        builder
+            .payer(ctx.payer.pubkey())
+            .recipient(self.recipient.pubkey())
+            .distribution(self.distribution_pda)
+            .claim_delegate_account(self.claim_delegate_pda)
+            .event_authority(event_authority)
+            .delegate(delegate);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![self.recipient.insecure_clone()],
+            name: "SetClaimDelegate",
+        }
+    }
+}
+
+pub struct SetClaimDelegateSetupBuilder<'a> {
+    ctx: &'a mut TestContext,
+    merkle: bool,
+}
+
+impl<'a> SetClaimDelegateSetupBuilder<'a> {
+    fn new(ctx: &'a mut TestContext) -> Self {
+        Self { ctx, merkle: false }
+    }
+
+    pub fn merkle(mut self) -> Self {
+        self.merkle = true;
+        self
+    }
+
+    pub fn build(self) -> SetClaimDelegateSetup {
+        let distribution_pda = if self.merkle {
+            let distribution_setup = CreateMerkleDistributionSetup::new(self.ctx);
+            let create_ix = distribution_setup.build_instruction(self.ctx);
+            create_ix.send_expect_success(self.ctx);
+            distribution_setup.distribution_pda
+        } else {
+            let distribution_setup = CreateDirectDistributionSetup::new(self.ctx);
+            let create_ix = distribution_setup.build_instruction(self.ctx);
+            create_ix.send_expect_success(self.ctx);
+            distribution_setup.distribution_pda
+        };
+
+        let recipient = self.ctx.create_funded_keypair();
+        SetClaimDelegateSetup::for_recipient(self.ctx, distribution_pda, &recipient)
+    }
+}
+
+pub struct SetClaimDelegateFixture;
+
+impl InstructionTestFixture for SetClaimDelegateFixture {
+    const INSTRUCTION_NAME: &'static str = "SetClaimDelegate";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = SetClaimDelegateSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: payer (handled by TestContext)
+    /// 1: recipient
+    fn required_signers() -> &'static [usize] {
+        &[0, 1]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: payer
+    /// 3: claim_delegate_account
+    fn required_writable() -> &'static [usize] {
+        &[0, 3]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(4)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(6)
+    }
+
+    fn data_len() -> usize {
+        1 + 32 // discriminator + delegate
+    }
+}
@@ -0,0 +1,119 @@
+use rewards_program_client::instructions::SetTransferableBuilder;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::{CreateDirectDistributionSetup, CreateMerkleDistributionSetup};
+use crate::utils::{InstructionTestFixture, TestContext, TestInstruction};
+
+pub struct SetTransferableSetup {
+    pub authority: Keypair,
+    pub distribution_pda: Pubkey,
+}
+
+impl SetTransferableSetup {
+    pub fn builder(ctx: &mut TestContext) -> SetTransferableSetupBuilder<'_> {
+        SetTransferableSetupBuilder::new(ctx)
+    }
+
+    pub fn new(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).build()
+    }
+
+    pub fn new_merkle(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).merkle().build()
+    }
+
+    /// Target an existing distribution controlled by `authority`
+    pub fn for_distribution(distribution_pda: Pubkey, authority: &Keypair) -> Self {
+        Self { authority: authority.insecure_clone(), distribution_pda }
+    }
+
+    /// Allow recipient-initiated transfers on the distribution
+    pub fn enable(ctx: &mut TestContext, distribution_pda: Pubkey, authority: &Keypair) {
+        let setup = Self::for_distribution(distribution_pda, authority);
+        let set_ix = setup.build_instruction(ctx);
+        set_ix.send_expect_success(ctx);
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with(&self.authority, 1)
+    }
+
+    pub fn build_instruction_with(&self, authority: &Keypair, transferable: u8) -> TestInstruction {
+        let mut builder = SetTransferableBuilder::new();
+        builder.authority(authority.pubkey()).distribution(self.distribution_pda).transferable(transferable);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![authority.insecure_clone()],
+            name: "SetTransferable",
+        }
+    }
+}
+
+pub struct SetTransferableSetupBuilder<'a> {
+    ctx: &'a mut TestContext,
+    merkle: bool,
+}
+
+impl<'a> SetTransferableSetupBuilder<'a> {
+    fn new(ctx: &'a mut TestContext) -> Self {
+        Self { ctx, merkle: false }
+    }
+
+    pub fn merkle(mut self) -> Self {
+        self.merkle = true;
+        self
+    }
+
+    pub fn build(self) -> SetTransferableSetup {
+        if self.merkle {
+            let distribution_setup = CreateMerkleDistributionSetup::new(self.ctx);
+            let create_ix = distribution_setup.build_instruction(self.ctx);
+            create_ix.send_expect_success(self.ctx);
+
+            SetTransferableSetup {
+                authority: distribution_setup.authority,
+                distribution_pda: distribution_setup.distribution_pda,
+            }
+        } else {
+            let distribution_setup = CreateDirectDistributionSetup::new(self.ctx);
+            let create_ix = distribution_setup.build_instruction(self.ctx);
+            create_ix.send_expect_success(self.ctx);
+
+            SetTransferableSetup {
+                authority: distribution_setup.authority,
+                distribution_pda: distribution_setup.distribution_pda,
+            }
+        }
+    }
+}
+
+pub struct SetTransferableFixture;
+
+impl InstructionTestFixture for SetTransferableFixture {
+    const INSTRUCTION_NAME: &'static str = "SetTransferable";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = SetTransferableSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: authority
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 1: distribution
+    fn required_writable() -> &'static [usize] {
+        &[1]
+    }
+
+    fn data_len() -> usize {
+        1 + 1 // discriminator + transferable
+    }
+}
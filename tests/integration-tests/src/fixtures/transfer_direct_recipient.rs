@@ -0,0 +1,238 @@
+use rewards_program_client::instructions::{AddDirectRecipientBuilder, TransferDirectRecipientBuilder};
+use rewards_program_client::types::VestingSchedule;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use spl_token_interface::ID as TOKEN_PROGRAM_ID;
+
+use crate::fixtures::{
+    ClaimDirectSetup, CreateDirectDistributionSetup, SetTransferableSetup, DEFAULT_RECIPIENT_AMOUNT,
+};
+use crate::utils::{
+    find_direct_recipient_pda, find_event_authority_pda, InstructionTestFixture, TestContext, TestInstruction,
+};
+
+pub struct TransferDirectRecipientSetup {
+    pub authority: Keypair,
+    pub recipient: Keypair,
+    pub new_recipient: Keypair,
+    pub original_payer: Keypair,
+    pub distribution_pda: Pubkey,
+    pub recipient_pda: Pubkey,
+    pub new_recipient_pda: Pubkey,
+    pub new_recipient_bump: u8,
+    pub mint: Pubkey,
+    pub distribution_vault: Pubkey,
+    pub token_program: Pubkey,
+    pub amount: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+}
+
+impl TransferDirectRecipientSetup {
+    pub fn builder(ctx: &mut TestContext) -> TransferDirectRecipientSetupBuilder<'_> {
+        TransferDirectRecipientSetupBuilder::new(ctx)
+    }
+
+    pub fn new(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).build()
+    }
+
+    pub fn new_token_2022(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).token_2022().build()
+    }
+
+    pub fn build_instruction(&self, ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with(ctx, &self.recipient, self.new_recipient.pubkey(), self.original_payer.pubkey())
+    }
+
+    pub fn build_instruction_with(
+        &self,
+        ctx: &TestContext,
+        recipient: &Keypair,
+        new_recipient: Pubkey,
+        original_payer: Pubkey,
+    ) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+        let (recipient_pda, _) = find_direct_recipient_pda(&self.distribution_pda, &recipient.pubkey());
+        let (new_recipient_pda, new_recipient_bump) = find_direct_recipient_pda(&self.distribution_pda, &new_recipient);
+
+        let mut builder = TransferDirectRecipientBuilder::new();
+        builder
+            .payer(ctx.payer.pubkey())
+            .recipient(recipient.pubkey())
+            .new_recipient(new_recipient)
+            .distribution(self.distribution_pda)
+            .recipient_account(recipient_pda)
+            .new_recipient_account(new_recipient_pda)
+            .original_payer(original_payer)
+            .event_authority(event_authority)
+            .bump(new_recipient_bump);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![recipient.insecure_clone()],
+            name: "TransferDirectRecipient",
+        }
+    }
+
+    /// Claim setup for `recipient` against the position currently at `recipient_pda`
+    pub fn claim_setup_for(&self, ctx: &mut TestContext, recipient: &Keypair) -> ClaimDirectSetup {
+        let (recipient_pda, recipient_bump) = find_direct_recipient_pda(&self.distribution_pda, &recipient.pubkey());
+        let recipient_token_account = ctx.create_ata_for_program(&recipient.pubkey(), &self.mint, &self.token_program);
+
+        ClaimDirectSetup {
+            recipient: recipient.insecure_clone(),
+            distribution_pda: self.distribution_pda,
+            recipient_pda,
+            recipient_bump,
+            mint: self.mint,
+            distribution_vault: self.distribution_vault,
+            recipient_token_account,
+            token_program: self.token_program,
+            amount: self.amount,
+            start_ts: self.start_ts,
+            end_ts: self.end_ts,
+        }
+    }
+}
+
+pub struct TransferDirectRecipientSetupBuilder<'a> {
+    ctx: &'a mut TestContext,
+    token_program: Pubkey,
+    amount: u64,
+    transferable: bool,
+}
+
+impl<'a> TransferDirectRecipientSetupBuilder<'a> {
+    fn new(ctx: &'a mut TestContext) -> Self {
+        Self { ctx, token_program: TOKEN_PROGRAM_ID, amount: DEFAULT_RECIPIENT_AMOUNT, transferable: true }
+    }
+
+    pub fn token_2022(mut self) -> Self {
+        self.token_program = TOKEN_2022_PROGRAM_ID;
+        self
+    }
+
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = amount;
+        self
+    }
+
+    pub fn transferable(mut self, transferable: bool) -> Self {
+        self.transferable = transferable;
+        self
+    }
+
+    pub fn build(self) -> TransferDirectRecipientSetup {
+        let distribution_setup =
+            CreateDirectDistributionSetup::builder(self.ctx).token_program(self.token_program).build();
+        let create_ix = distribution_setup.build_instruction(self.ctx);
+        create_ix.send_expect_success(self.ctx);
+
+        if self.transferable {
+            SetTransferableSetup::enable(self.ctx, distribution_setup.distribution_pda, &distribution_setup.authority);
+        }
+
+        let start_ts = self.ctx.get_current_timestamp();
+        let end_ts = start_ts + 86400 * 365;
+
+        let recipient = self.ctx.create_funded_keypair();
+        let new_recipient = self.ctx.create_funded_keypair();
+        let rent_payer = self.ctx.create_funded_keypair();
+        let (recipient_pda, recipient_bump) =
+            find_direct_recipient_pda(&distribution_setup.distribution_pda, &recipient.pubkey());
+        let (new_recipient_pda, new_recipient_bump) =
+            find_direct_recipient_pda(&distribution_setup.distribution_pda, &new_recipient.pubkey());
+
+        let authority_token_account = self.ctx.create_ata_for_program_with_balance(
+            &distribution_setup.authority.pubkey(),
+            &distribution_setup.mint.pubkey(),
+            self.amount,
+            &self.token_program,
+        );
+
+        // Add the recipient with a dedicated payer (not ctx.payer) so rent refunds are observable
+        let (event_authority, _) = find_event_authority_pda();
+        let mut add_builder = AddDirectRecipientBuilder::new();
+        add_builder
+            .payer(rent_payer.pubkey())
+            .authority(distribution_setup.authority.pubkey())
+            .distribution(distribution_setup.distribution_pda)
+            .recipient_account(recipient_pda)
+            .recipient(recipient.pubkey())
+            .mint(distribution_setup.mint.pubkey())
+            .distribution_vault(distribution_setup.distribution_vault)
+            .authority_token_account(authority_token_account)
+            .token_program(self.token_program)
+            .event_authority(event_authority)
+            .bump(recipient_bump)
+            .amount(self.amount)
+            .schedule(VestingSchedule::Linear { start_ts, end_ts });
+
+        let add_ix = TestInstruction {
+            instruction: add_builder.instruction(),
+            signers: vec![rent_payer.insecure_clone(), distribution_setup.authority.insecure_clone()],
+            name: "AddDirectRecipient",
+        };
+        add_ix.send_expect_success(self.ctx);
+
+        TransferDirectRecipientSetup {
+            authority: distribution_setup.authority,
+            recipient,
+            new_recipient,
+            original_payer: rent_payer,
+            distribution_pda: distribution_setup.distribution_pda,
+            recipient_pda,
+            new_recipient_pda,
+            new_recipient_bump,
+            mint: distribution_setup.mint.pubkey(),
+            distribution_vault: distribution_setup.distribution_vault,
+            token_program: self.token_program,
+            amount: self.amount,
+            start_ts,
+            end_ts,
+        }
+    }
+}
+
+pub struct TransferDirectRecipientFixture;
+
+impl InstructionTestFixture for TransferDirectRecipientFixture {
+    const INSTRUCTION_NAME: &'static str = "TransferDirectRecipient";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = TransferDirectRecipientSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: payer (handled by TestContext)
+    /// 1: recipient
+    fn required_signers() -> &'static [usize] {
+        &[0, 1]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: payer
+    /// 4: recipient_account
+    /// 5: new_recipient_account
+    /// 6: original_payer
+    fn required_writable() -> &'static [usize] {
+        &[0, 4, 5, 6]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(7)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(9)
+    }
+
+    fn data_len() -> usize {
+        1 + 1 // discriminator + bump
+    }
+}
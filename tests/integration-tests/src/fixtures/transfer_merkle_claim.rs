@@ -0,0 +1,138 @@
+use rewards_program_client::{
+    instructions::{CrankClaimMerkleBuilder, TransferMerkleClaimBuilder},
+    types::TransferFeeMode,
+};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::{ClaimMerkleSetup, SetTransferableSetup};
+use crate::utils::{
+    find_claim_delegate_pda, find_event_authority_pda, InstructionTestFixture, TestContext, TestInstruction,
+};
+
+pub struct TransferMerkleClaimSetup {
+    pub claim: ClaimMerkleSetup,
+    pub new_owner: Keypair,
+}
+
+impl TransferMerkleClaimSetup {
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let claim = ClaimMerkleSetup::new(ctx);
+        Self::from_claim_setup(ctx, claim)
+    }
+
+    /// Setup on a distribution where the authority has not enabled transfers
+    pub fn new_non_transferable(ctx: &mut TestContext) -> Self {
+        let claim = ClaimMerkleSetup::new(ctx);
+        let new_owner = ctx.create_funded_keypair();
+        Self { claim, new_owner }
+    }
+
+    pub fn from_claim_setup(ctx: &mut TestContext, claim: ClaimMerkleSetup) -> Self {
+        SetTransferableSetup::enable(ctx, claim.distribution_pda, &claim.authority);
+        let new_owner = ctx.create_funded_keypair();
+        Self { claim, new_owner }
+    }
+
+    pub fn build_instruction(&self, ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with(ctx, &self.claim.claimant, self.new_owner.pubkey())
+    }
+
+    pub fn build_instruction_with(&self, ctx: &TestContext, owner: &Keypair, new_owner: Pubkey) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = TransferMerkleClaimBuilder::new();
+        builder
+            .payer(ctx.payer.pubkey())
+            .owner(owner.pubkey())
+            .claimant(self.claim.claimant.pubkey())
+            .new_owner(new_owner)
+            .distribution(self.claim.distribution_pda)
+            .redirect_account(self.claim.redirect_pda)
+            .revocation_account(self.claim.revocation_pda)
+            .event_authority(event_authority);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![owner.insecure_clone()],
+            name: "TransferMerkleClaim",
+        }
+    }
+
+    /// Crank the original leaf on behalf of `owner`, the position's current owner
+    pub fn build_crank_instruction(
+        &self,
+        cranker: &Keypair,
+        owner: &Pubkey,
+        destination_token_account: Pubkey,
+    ) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+        let (claim_delegate_pda, _) = find_claim_delegate_pda(&self.claim.distribution_pda, owner);
+
+        let mut builder = CrankClaimMerkleBuilder::new();
+        builder
+            .cranker(cranker.pubkey())
+            .claimant(self.claim.claimant.pubkey())
+            .distribution(self.claim.distribution_pda)
+            .claim_account(self.claim.claim_pda)
+            .revocation_account(self.claim.revocation_pda)
+            .redirect_account(self.claim.redirect_pda)
+            .claim_delegate_account(claim_delegate_pda)
+            .mint(self.claim.mint)
+            .distribution_vault(self.claim.distribution_vault)
+            .destination_token_account(destination_token_account)
+            .token_program(self.claim.token_program)
+            .event_authority(event_authority)
+            .claim_bump(self.claim.claim_bump)
+            .total_amount(self.claim.total_amount)
+            .schedule(self.claim.schedule.clone())
+            .amount(0)
+            .fee_mode(TransferFeeMode::Gross)
+            .proof(self.claim.proof.clone());
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![cranker.insecure_clone()],
+            name: "CrankClaimMerkle",
+        }
+    }
+}
+
+pub struct TransferMerkleClaimFixture;
+
+impl InstructionTestFixture for TransferMerkleClaimFixture {
+    const INSTRUCTION_NAME: &'static str = "TransferMerkleClaim";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = TransferMerkleClaimSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: payer (handled by TestContext)
+    /// 1: owner
+    fn required_signers() -> &'static [usize] {
+        &[0, 1]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: payer
+    /// 5: redirect_account
+    fn required_writable() -> &'static [usize] {
+        &[0, 5]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(7)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(9)
+    }
+
+    fn data_len() -> usize {
+        1 // discriminator only
+    }
+}
@@ -0,0 +1,98 @@
+use rewards_program_client::instructions::UnpauseDistributionBuilder;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::PauseDistributionSetup;
+use crate::utils::{find_event_authority_pda, InstructionTestFixture, TestContext, TestInstruction};
+
+pub struct UnpauseDistributionSetup {
+    pub authority: Keypair,
+    pub distribution_pda: Pubkey,
+}
+
+impl UnpauseDistributionSetup {
+    /// Create a direct distribution and pause it
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let pause_setup = PauseDistributionSetup::new(ctx);
+        Self::from_pause_setup(ctx, pause_setup)
+    }
+
+    /// Create a merkle distribution and pause it
+    pub fn new_merkle(ctx: &mut TestContext) -> Self {
+        let pause_setup = PauseDistributionSetup::new_merkle(ctx);
+        Self::from_pause_setup(ctx, pause_setup)
+    }
+
+    fn from_pause_setup(ctx: &mut TestContext, pause_setup: PauseDistributionSetup) -> Self {
+        let pause_ix = pause_setup.build_instruction(ctx);
+        pause_ix.send_expect_success(ctx);
+
+        Self { authority: pause_setup.authority, distribution_pda: pause_setup.distribution_pda }
+    }
+
+    /// Target an existing distribution controlled by `authority`
+    pub fn for_distribution(distribution_pda: Pubkey, authority: &Keypair) -> Self {
+        Self { authority: authority.insecure_clone(), distribution_pda }
+    }
+
+    /// Unpause an existing distribution
+    pub fn unpause(ctx: &mut TestContext, distribution_pda: Pubkey, authority: &Keypair, shift_vesting: bool) {
+        let setup = Self::for_distribution(distribution_pda, authority);
+        let unpause_ix = setup.build_instruction_with(&setup.authority, shift_vesting as u8);
+        unpause_ix.send_expect_success(ctx);
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with(&self.authority, 0)
+    }
+
+    pub fn build_instruction_with(&self, authority: &Keypair, shift_vesting: u8) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = UnpauseDistributionBuilder::new();
+        builder
+            .authority(authority.pubkey())
+            .distribution(self.distribution_pda)
+            .event_authority(event_authority)
+            .shift_vesting(shift_vesting);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![authority.insecure_clone()],
+            name: "UnpauseDistribution",
+        }
+    }
+}
+
+pub struct UnpauseDistributionFixture;
+
+impl InstructionTestFixture for UnpauseDistributionFixture {
+    const INSTRUCTION_NAME: &'static str = "UnpauseDistribution";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = UnpauseDistributionSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: authority
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 1: distribution
+    fn required_writable() -> &'static [usize] {
+        &[1]
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(3)
+    }
+
+    fn data_len() -> usize {
+        1 + 1 // discriminator + shift_vesting
+    }
+}
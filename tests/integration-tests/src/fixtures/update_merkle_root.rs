@@ -0,0 +1,86 @@
+use rewards_program_client::instructions::UpdateMerkleRootBuilder;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::CreateMerkleDistributionSetup;
+use crate::utils::{find_event_authority_pda, InstructionTestFixture, TestContext, TestInstruction};
+
+pub struct UpdateMerkleRootSetup {
+    pub authority: Keypair,
+    pub distribution_pda: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub epoch: u64,
+}
+
+impl UpdateMerkleRootSetup {
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let distribution_setup = CreateMerkleDistributionSetup::new(ctx);
+        let create_ix = distribution_setup.build_instruction(ctx);
+        create_ix.send_expect_success(ctx);
+
+        Self {
+            authority: distribution_setup.authority,
+            distribution_pda: distribution_setup.distribution_pda,
+            merkle_root: [2u8; 32],
+            total_amount: distribution_setup.total_amount,
+            epoch: 1,
+        }
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with_authority(&self.authority)
+    }
+
+    pub fn build_instruction_with_authority(&self, authority: &Keypair) -> TestInstruction {
+        let (event_authority, _) = find_event_authority_pda();
+
+        let mut builder = UpdateMerkleRootBuilder::new();
+        builder
+            .authority(authority.pubkey())
+            .distribution(self.distribution_pda)
+            .event_authority(event_authority)
+            .merkle_root(self.merkle_root)
+            .total_amount(self.total_amount)
+            .epoch(self.epoch);
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![authority.insecure_clone()],
+            name: "UpdateMerkleRoot",
+        }
+    }
+}
+
+pub struct UpdateMerkleRootFixture;
+
+impl InstructionTestFixture for UpdateMerkleRootFixture {
+    const INSTRUCTION_NAME: &'static str = "UpdateMerkleRoot";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = UpdateMerkleRootSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: authority
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 1: distribution
+    fn required_writable() -> &'static [usize] {
+        &[1]
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(3)
+    }
+
+    fn data_len() -> usize {
+        1 + 32 + 8 + 8 // discriminator + merkle_root + total_amount + epoch
+    }
+}
@@ -1,13 +1,25 @@
 pub mod fixtures;
 pub mod utils;
 
+#[cfg(test)]
+mod test_accept_authority;
 #[cfg(test)]
 mod test_add_direct_recipient;
 #[cfg(test)]
+mod test_add_direct_recipients;
+#[cfg(test)]
+mod test_basket;
+#[cfg(test)]
+mod test_cancel_authority_proposal;
+#[cfg(test)]
 mod test_claim_direct;
 #[cfg(test)]
+mod test_claim_direct_many;
+#[cfg(test)]
 mod test_claim_merkle;
 #[cfg(test)]
+mod test_claim_merkle_bitmap;
+#[cfg(test)]
 mod test_cliff_vesting;
 #[cfg(test)]
 mod test_close_direct_distribution;
@@ -15,13 +27,54 @@ mod test_close_direct_distribution;
 mod test_close_direct_recipient;
 #[cfg(test)]
 mod test_close_merkle_claim;
+mod test_close_merkle_claim_early;
 #[cfg(test)]
 mod test_close_merkle_distribution;
 #[cfg(test)]
+mod test_compressed;
+#[cfg(test)]
+mod test_crank_claim_direct;
+#[cfg(test)]
+mod test_crank_claim_merkle;
+#[cfg(test)]
 mod test_create_direct_distribution;
 #[cfg(test)]
 mod test_create_merkle_distribution;
 #[cfg(test)]
+mod test_decrease_direct_allocation;
+#[cfg(test)]
+mod test_events;
+#[cfg(test)]
+mod test_fund_distribution;
+#[cfg(test)]
+mod test_increase_direct_allocation;
+#[cfg(test)]
+mod test_migrate_account;
+#[cfg(test)]
+mod test_native_sol;
+#[cfg(test)]
+mod test_pause_distribution;
+#[cfg(test)]
+mod test_propose_authority;
+#[cfg(test)]
+mod test_remove_claim_delegate;
+#[cfg(test)]
 mod test_revoke_direct_recipient;
 #[cfg(test)]
 mod test_revoke_merkle_claim;
+#[cfg(test)]
+mod test_set_claim_delegate;
+#[cfg(test)]
+mod test_set_transferable;
+#[cfg(test)]
+mod test_stepped_vesting;
+#[cfg(test)]
+mod test_tranche_vesting;
+#[cfg(test)]
+mod test_transfer_direct_recipient;
+#[cfg(test)]
+mod test_transfer_merkle_claim;
+#[cfg(test)]
+mod test_unpause_distribution;
+#[cfg(test)]
+mod test_update_merkle_root;
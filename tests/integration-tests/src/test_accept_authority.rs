@@ -0,0 +1,125 @@
+use rewards_program_client::accounts::{DirectDistribution, MerkleDistribution};
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+use crate::fixtures::{AcceptAuthorityFixture, AcceptAuthoritySetup, CloseDirectDistributionSetup};
+use crate::utils::{
+    assert_account_closed, assert_rewards_error, test_missing_signer, test_not_writable, test_wrong_current_program,
+    RewardsError, TestContext,
+};
+
+#[test]
+fn test_accept_authority_missing_new_authority_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<AcceptAuthorityFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_accept_authority_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<AcceptAuthorityFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_accept_authority_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<AcceptAuthorityFixture>(&mut ctx);
+}
+
+#[test]
+fn test_accept_authority_direct_success() {
+    let mut ctx = TestContext::new();
+    let setup = AcceptAuthoritySetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.propose_setup.distribution_pda).unwrap();
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.authority, setup.propose_setup.new_authority.pubkey());
+    assert_eq!(distribution.seed_authority, setup.propose_setup.authority.pubkey());
+    assert_eq!(distribution.pending_authority, Pubkey::default());
+}
+
+#[test]
+fn test_accept_authority_merkle_success() {
+    let mut ctx = TestContext::new();
+    let setup = AcceptAuthoritySetup::new_merkle(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.propose_setup.distribution_pda).unwrap();
+    let distribution = MerkleDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.authority, setup.propose_setup.new_authority.pubkey());
+    assert_eq!(distribution.seed_authority, setup.propose_setup.authority.pubkey());
+    assert_eq!(distribution.pending_authority, Pubkey::default());
+}
+
+#[test]
+fn test_accept_authority_wrong_signer() {
+    let mut ctx = TestContext::new();
+    let setup = AcceptAuthoritySetup::new(&mut ctx);
+    let wrong_signer = ctx.create_funded_keypair();
+
+    let test_ix = setup.build_instruction_with_signer(&wrong_signer);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::UnauthorizedAuthority);
+}
+
+#[test]
+fn test_accept_authority_twice_fails() {
+    let mut ctx = TestContext::new();
+    let setup = AcceptAuthoritySetup::new(&mut ctx);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    ctx.advance_slot();
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::NoPendingAuthority);
+}
+
+#[test]
+fn test_accept_authority_new_authority_can_close_distribution() {
+    let mut ctx = TestContext::new();
+    let setup = AcceptAuthoritySetup::new(&mut ctx);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let propose_setup = &setup.propose_setup;
+    let new_authority_token_account =
+        ctx.create_token_account(&propose_setup.new_authority.pubkey(), &propose_setup.mint);
+
+    let close_setup = CloseDirectDistributionSetup {
+        authority: propose_setup.new_authority.insecure_clone(),
+        distribution_pda: propose_setup.distribution_pda,
+        mint: propose_setup.mint,
+        distribution_vault: propose_setup.distribution_vault,
+        authority_token_account: new_authority_token_account,
+        token_program: propose_setup.token_program,
+    };
+
+    close_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert_account_closed(&ctx, &propose_setup.distribution_pda);
+}
+
+#[test]
+fn test_accept_authority_previous_authority_loses_access() {
+    let mut ctx = TestContext::new();
+    let setup = AcceptAuthoritySetup::new(&mut ctx);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let propose_setup = &setup.propose_setup;
+    let old_authority_token_account = ctx.create_token_account(&propose_setup.authority.pubkey(), &propose_setup.mint);
+
+    let close_setup = CloseDirectDistributionSetup {
+        authority: propose_setup.authority.insecure_clone(),
+        distribution_pda: propose_setup.distribution_pda,
+        mint: propose_setup.mint,
+        distribution_vault: propose_setup.distribution_vault,
+        authority_token_account: old_authority_token_account,
+        token_program: propose_setup.token_program,
+    };
+
+    let error = close_setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::UnauthorizedAuthority);
+}
@@ -251,3 +251,30 @@ fn test_add_direct_recipient_insufficient_funds() {
     let instruction = setup.build_instruction(&ctx);
     let _error = instruction.send_expect_error(&mut ctx);
 }
+
+#[test]
+fn test_add_direct_recipient_schedule_ends_after_claim_window() {
+    let mut ctx = TestContext::new();
+    let claim_end_ts = ctx.get_current_timestamp() + 86400 * 30;
+    let distribution_setup = CreateDirectDistributionSetup::builder(&mut ctx).claim_window(0, claim_end_ts).build();
+
+    // Default schedule vests over a year, well past the claim window
+    let setup = AddDirectRecipientSetup::from_distribution_setup(&mut ctx, &distribution_setup);
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::InvalidTimeWindow);
+}
+
+#[test]
+fn test_add_direct_recipient_schedule_ends_at_claim_window_end() {
+    let mut ctx = TestContext::new();
+    let current_ts = ctx.get_current_timestamp();
+    let claim_end_ts = current_ts + 86400 * 30;
+    let distribution_setup = CreateDirectDistributionSetup::builder(&mut ctx).claim_window(0, claim_end_ts).build();
+
+    let mut setup = AddDirectRecipientSetup::from_distribution_setup(&mut ctx, &distribution_setup);
+    setup.schedule = VestingSchedule::Linear { start_ts: current_ts, end_ts: claim_end_ts };
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+}
@@ -0,0 +1,173 @@
+use rewards_program_client::{accounts::DirectDistribution, types::VestingSchedule};
+use solana_sdk::{instruction::InstructionError, signature::Signer};
+
+use crate::fixtures::{AddDirectRecipientsFixture, AddDirectRecipientsSetup};
+use crate::utils::{
+    assert_direct_recipient, assert_instruction_error, assert_rewards_error, test_empty_data, test_missing_signer,
+    test_not_writable, test_truncated_data, test_wrong_current_program, test_wrong_system_program, RewardsError,
+    TestContext,
+};
+
+#[test]
+fn test_add_direct_recipients_missing_authority_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<AddDirectRecipientsFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_add_direct_recipients_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<AddDirectRecipientsFixture>(&mut ctx, 2);
+}
+
+#[test]
+fn test_add_direct_recipients_recipient_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<AddDirectRecipientsFixture>(&mut ctx, 10);
+}
+
+#[test]
+fn test_add_direct_recipients_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<AddDirectRecipientsFixture>(&mut ctx);
+}
+
+#[test]
+fn test_add_direct_recipients_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<AddDirectRecipientsFixture>(&mut ctx);
+}
+
+#[test]
+fn test_add_direct_recipients_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<AddDirectRecipientsFixture>(&mut ctx);
+}
+
+#[test]
+fn test_add_direct_recipients_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<AddDirectRecipientsFixture>(&mut ctx);
+}
+
+#[test]
+fn test_add_direct_recipients_success() {
+    let mut ctx = TestContext::new();
+    let setup = AddDirectRecipientsSetup::new(&mut ctx);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    for recipient in &setup.recipients {
+        assert_direct_recipient(
+            &ctx,
+            &recipient.recipient_pda,
+            &recipient.recipient.pubkey(),
+            recipient.amount,
+            0,
+            recipient.recipient_bump,
+        );
+    }
+
+    assert_eq!(ctx.get_token_balance(&setup.distribution_vault), setup.total_amount());
+    assert_eq!(ctx.get_token_balance(&setup.authority_token_account), 0);
+
+    let account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.total_allocated, setup.total_amount());
+    assert_eq!(distribution.total_funded, setup.total_amount());
+}
+
+#[test]
+fn test_add_direct_recipients_success_token_2022() {
+    let mut ctx = TestContext::new();
+    let setup = AddDirectRecipientsSetup::new_token_2022(&mut ctx);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&setup.distribution_vault), setup.total_amount());
+}
+
+#[test]
+fn test_add_direct_recipients_single_recipient() {
+    let mut ctx = TestContext::new();
+    let setup =
+        AddDirectRecipientsSetup::builder(&mut ctx).num_recipients(1).schedule(VestingSchedule::Immediate).build();
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let recipient = &setup.recipients[0];
+    assert_direct_recipient(
+        &ctx,
+        &recipient.recipient_pda,
+        &recipient.recipient.pubkey(),
+        recipient.amount,
+        0,
+        recipient.recipient_bump,
+    );
+}
+
+#[test]
+fn test_add_direct_recipients_unauthorized() {
+    let mut ctx = TestContext::new();
+    let setup = AddDirectRecipientsSetup::new(&mut ctx);
+    let wrong_authority = ctx.create_funded_keypair();
+
+    let error = setup.build_instruction_with_wrong_authority(&ctx, &wrong_authority).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::UnauthorizedAuthority);
+}
+
+#[test]
+fn test_add_direct_recipients_missing_account_pair() {
+    let mut ctx = TestContext::new();
+    let setup = AddDirectRecipientsSetup::new(&mut ctx);
+
+    let mut remaining_accounts = setup.remaining_accounts();
+    remaining_accounts.truncate(remaining_accounts.len() - 2);
+
+    let error = setup
+        .build_instruction_with(&ctx, &setup.authority, setup.entries(), remaining_accounts)
+        .send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidRecipientCount);
+}
+
+#[test]
+fn test_add_direct_recipients_odd_remaining_accounts() {
+    let mut ctx = TestContext::new();
+    let setup = AddDirectRecipientsSetup::new(&mut ctx);
+
+    let mut remaining_accounts = setup.remaining_accounts();
+    remaining_accounts.pop();
+
+    let error = setup
+        .build_instruction_with(&ctx, &setup.authority, setup.entries(), remaining_accounts)
+        .send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidRecipientCount);
+}
+
+#[test]
+fn test_add_direct_recipients_zero_amount_entry() {
+    let mut ctx = TestContext::new();
+    let setup = AddDirectRecipientsSetup::new(&mut ctx);
+
+    let mut entries = setup.entries();
+    entries[1].amount = 0;
+
+    let error = setup
+        .build_instruction_with(&ctx, &setup.authority, entries, setup.remaining_accounts())
+        .send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidAmount);
+}
+
+#[test]
+fn test_add_direct_recipients_invalid_bump() {
+    let mut ctx = TestContext::new();
+    let setup = AddDirectRecipientsSetup::new(&mut ctx);
+
+    let mut entries = setup.entries();
+    entries[2].bump = entries[2].bump.wrapping_sub(1);
+
+    let error = setup
+        .build_instruction_with(&ctx, &setup.authority, entries, setup.remaining_accounts())
+        .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
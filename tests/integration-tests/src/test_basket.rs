@@ -0,0 +1,340 @@
+use rewards_program_client::{
+    accounts::{BasketDistribution, BasketRecipient},
+    events::RewardsEvent,
+    types::VestingSchedule,
+};
+use solana_sdk::{instruction::InstructionError, signature::Signer};
+use spl_token_interface::ID as TOKEN_PROGRAM_ID;
+
+use crate::fixtures::{BasketSetup, ClaimBasketFixture, CreateBasketDistributionFixture, DEFAULT_RECIPIENT_AMOUNT};
+use crate::utils::{
+    assert_account_closed, assert_instruction_error, assert_rewards_error, expected_linear_unlock, test_missing_signer,
+    test_not_writable, test_wrong_current_program, test_wrong_system_program, RewardsError, TestContext,
+};
+
+fn basket_recipient(ctx: &TestContext, setup: &crate::fixtures::BasketRecipientSetup) -> BasketRecipient {
+    let account = ctx.get_account(&setup.recipient_pda).expect("Basket recipient should exist");
+    BasketRecipient::from_bytes(&account.data).unwrap()
+}
+
+#[test]
+fn test_create_basket_distribution_missing_authority_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<CreateBasketDistributionFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_create_basket_distribution_vault_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<CreateBasketDistributionFixture>(&mut ctx, 9);
+}
+
+#[test]
+fn test_create_basket_distribution_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<CreateBasketDistributionFixture>(&mut ctx);
+}
+
+#[test]
+fn test_create_basket_distribution_success() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::create(&mut ctx);
+
+    let account = ctx.get_account(&setup.distribution_pda).expect("Basket should exist");
+    let basket = BasketDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(basket.authority, setup.authority.pubkey());
+    assert_eq!(basket.mint_count, 2);
+    assert_eq!(basket.mints[0], setup.mints[0].mint);
+    assert_eq!(basket.mints[1], setup.mints[1].mint);
+    assert_eq!(basket.bump, setup.bump);
+
+    for mint in &setup.mints {
+        assert_eq!(ctx.get_token_balance(&mint.distribution_vault), 0);
+    }
+}
+
+#[test]
+fn test_create_basket_distribution_rejects_duplicate_mints() {
+    let mut ctx = TestContext::new();
+    let mut setup = BasketSetup::with_token_programs(&mut ctx, &[TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID]);
+    setup.mints[1].mint = setup.mints[0].mint;
+    setup.mints[1].distribution_vault = setup.mints[0].distribution_vault;
+    setup.mints[1].token_program = setup.mints[0].token_program;
+
+    let error = setup.build_create_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidBasketMints);
+}
+
+#[test]
+fn test_create_basket_distribution_rejects_too_many_mints() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::with_token_programs(&mut ctx, &[TOKEN_PROGRAM_ID; 5]);
+
+    let error = setup.build_create_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidBasketMints);
+}
+
+#[test]
+fn test_add_basket_recipient_deposits_every_mint() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::create(&mut ctx);
+    let recipient_setup = setup.recipient_setup(&mut ctx, VestingSchedule::Immediate {});
+
+    setup.build_add_recipient_instruction(&ctx, &recipient_setup).send_expect_success(&mut ctx);
+
+    for (mint, amount) in setup.mints.iter().zip(&recipient_setup.amounts) {
+        assert_eq!(ctx.get_token_balance(&mint.distribution_vault), *amount);
+    }
+
+    let recipient = basket_recipient(&ctx, &recipient_setup);
+    assert_eq!(recipient.recipient, recipient_setup.recipient.pubkey());
+    assert_eq!(recipient.total_amounts, [DEFAULT_RECIPIENT_AMOUNT, DEFAULT_RECIPIENT_AMOUNT * 2, 0, 0]);
+    assert_eq!(recipient.claimed_amounts, [0; 4]);
+}
+
+#[test]
+fn test_add_basket_recipient_rejects_amount_count_mismatch() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::create(&mut ctx);
+    let recipient_setup =
+        setup.recipient_setup_with_amounts(&mut ctx, VestingSchedule::Immediate {}, vec![DEFAULT_RECIPIENT_AMOUNT]);
+
+    let error = setup.build_add_recipient_instruction(&ctx, &recipient_setup).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidBasketAccounts);
+}
+
+#[test]
+fn test_add_basket_recipient_skips_zero_amount_mint() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::create(&mut ctx);
+    let recipient_setup =
+        setup.recipient_setup_with_amounts(&mut ctx, VestingSchedule::Immediate {}, vec![0, DEFAULT_RECIPIENT_AMOUNT]);
+
+    setup.build_add_recipient_instruction(&ctx, &recipient_setup).send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&setup.mints[0].distribution_vault), 0);
+    assert_eq!(ctx.get_token_balance(&setup.mints[1].distribution_vault), DEFAULT_RECIPIENT_AMOUNT);
+}
+
+#[test]
+fn test_claim_basket_missing_recipient_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<ClaimBasketFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_claim_basket_recipient_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ClaimBasketFixture>(&mut ctx, 2);
+}
+
+#[test]
+fn test_claim_basket_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<ClaimBasketFixture>(&mut ctx);
+}
+
+#[test]
+fn test_claim_basket_pays_every_mint() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::create(&mut ctx);
+    let recipient_setup = setup.recipient_setup(&mut ctx, VestingSchedule::Immediate {});
+    setup.build_add_recipient_instruction(&ctx, &recipient_setup).send_expect_success(&mut ctx);
+
+    let events = setup.build_claim_instruction(&recipient_setup).send_expect_events(&mut ctx);
+
+    for (token_account, amount) in recipient_setup.recipient_token_accounts.iter().zip(&recipient_setup.amounts) {
+        assert_eq!(ctx.get_token_balance(token_account), *amount);
+    }
+    let recipient = basket_recipient(&ctx, &recipient_setup);
+    assert_eq!(recipient.claimed_amounts, recipient.total_amounts);
+
+    assert!(matches!(
+        events.as_slice(),
+        [RewardsEvent::BasketClaimed(event)] if event.amounts == recipient_setup.amounts
+    ));
+}
+
+#[test]
+fn test_claim_basket_vests_every_mint_on_one_schedule() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::create(&mut ctx);
+    let start_ts = ctx.get_current_timestamp();
+    let end_ts = start_ts + 1000;
+    let recipient_setup = setup.recipient_setup(&mut ctx, VestingSchedule::Linear { start_ts, end_ts });
+    setup.build_add_recipient_instruction(&ctx, &recipient_setup).send_expect_success(&mut ctx);
+
+    let midpoint = start_ts + 250;
+    ctx.warp_to_timestamp(midpoint);
+    setup.build_claim_instruction(&recipient_setup).send_expect_success(&mut ctx);
+
+    for (token_account, amount) in recipient_setup.recipient_token_accounts.iter().zip(&recipient_setup.amounts) {
+        let expected = expected_linear_unlock(*amount, start_ts, end_ts, midpoint);
+        assert_eq!(ctx.get_token_balance(token_account), expected);
+    }
+
+    // Claims are tracked per mint, so the rest pays out once fully vested
+    ctx.warp_to_timestamp(end_ts);
+    setup.build_claim_instruction(&recipient_setup).send_expect_success(&mut ctx);
+
+    for (token_account, amount) in recipient_setup.recipient_token_accounts.iter().zip(&recipient_setup.amounts) {
+        assert_eq!(ctx.get_token_balance(token_account), *amount);
+    }
+}
+
+#[test]
+fn test_claim_basket_nothing_to_claim() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::create(&mut ctx);
+    let recipient_setup = setup.recipient_setup(&mut ctx, VestingSchedule::Immediate {});
+    setup.build_add_recipient_instruction(&ctx, &recipient_setup).send_expect_success(&mut ctx);
+    setup.build_claim_instruction(&recipient_setup).send_expect_success(&mut ctx);
+    ctx.advance_slot();
+
+    let error = setup.build_claim_instruction(&recipient_setup).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::NothingToClaim);
+}
+
+#[test]
+fn test_claim_basket_rejects_reordered_mints() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::create(&mut ctx);
+    let recipient_setup = setup.recipient_setup(&mut ctx, VestingSchedule::Immediate {});
+    setup.build_add_recipient_instruction(&ctx, &recipient_setup).send_expect_success(&mut ctx);
+
+    let mut legs = setup.leg_accounts(&recipient_setup.recipient_token_accounts);
+    let second = legs.split_off(4);
+    let legs = [second, legs].concat();
+
+    let error = setup.build_claim_instruction_with(&recipient_setup, legs).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidBasketAccounts);
+}
+
+#[test]
+fn test_claim_basket_rejects_missing_mint() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::create(&mut ctx);
+    let recipient_setup = setup.recipient_setup(&mut ctx, VestingSchedule::Immediate {});
+    setup.build_add_recipient_instruction(&ctx, &recipient_setup).send_expect_success(&mut ctx);
+
+    let mut legs = setup.leg_accounts(&recipient_setup.recipient_token_accounts);
+    legs.truncate(4);
+
+    let error = setup.build_claim_instruction_with(&recipient_setup, legs).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidBasketAccounts);
+}
+
+#[test]
+fn test_claim_basket_wrong_recipient() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::create(&mut ctx);
+    let recipient_setup = setup.recipient_setup(&mut ctx, VestingSchedule::Immediate {});
+    setup.build_add_recipient_instruction(&ctx, &recipient_setup).send_expect_success(&mut ctx);
+
+    let mut impostor_setup = setup.recipient_setup(&mut ctx, VestingSchedule::Immediate {});
+    impostor_setup.recipient_pda = recipient_setup.recipient_pda;
+
+    let error = setup.build_claim_instruction(&impostor_setup).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::UnauthorizedRecipient);
+}
+
+#[test]
+fn test_close_basket_distribution_returns_every_mint() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::create(&mut ctx);
+    let recipient_setup = setup.recipient_setup(&mut ctx, VestingSchedule::Immediate {});
+    setup.build_add_recipient_instruction(&ctx, &recipient_setup).send_expect_success(&mut ctx);
+    let balances_before: Vec<u64> =
+        setup.mints.iter().map(|mint| ctx.get_token_balance(&mint.authority_token_account)).collect();
+
+    setup.build_close_instruction().send_expect_success(&mut ctx);
+
+    assert_account_closed(&ctx, &setup.distribution_pda);
+    for ((mint, before), amount) in setup.mints.iter().zip(balances_before).zip(&recipient_setup.amounts) {
+        assert_account_closed(&ctx, &mint.distribution_vault);
+        assert_eq!(ctx.get_token_balance(&mint.authority_token_account), before + amount);
+    }
+}
+
+#[test]
+fn test_close_basket_distribution_before_clawback() {
+    let mut ctx = TestContext::new();
+    let mut setup = BasketSetup::new(&mut ctx);
+    setup.clawback_ts = ctx.get_current_timestamp() + 86400;
+    setup.build_create_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let error = setup.build_close_instruction().send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::ClawbackNotReached);
+}
+
+#[test]
+fn test_close_basket_distribution_wrong_authority() {
+    let mut ctx = TestContext::new();
+    let mut setup = BasketSetup::create(&mut ctx);
+    setup.authority = ctx.create_funded_keypair();
+    for mint in &mut setup.mints {
+        mint.authority_token_account =
+            ctx.create_ata_for_program(&setup.authority.pubkey(), &mint.mint, &mint.token_program);
+    }
+
+    let error = setup.build_close_instruction().send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::UnauthorizedAuthority);
+}
+
+#[test]
+fn test_close_basket_distribution_wrong_vault() {
+    let mut ctx = TestContext::new();
+    let mut setup = BasketSetup::create(&mut ctx);
+    setup.mints[0].distribution_vault = setup.mints[0].authority_token_account;
+
+    let error = setup.build_close_instruction().send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
+
+#[test]
+fn test_close_basket_recipient_after_distribution_closed() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::create(&mut ctx);
+    let recipient_setup = setup.recipient_setup(&mut ctx, VestingSchedule::Immediate {});
+    setup.build_add_recipient_instruction(&ctx, &recipient_setup).send_expect_success(&mut ctx);
+    setup.build_close_instruction().send_expect_success(&mut ctx);
+    let payer_before = ctx.get_account(&ctx.payer.pubkey()).map(|a| a.lamports).unwrap_or(0);
+
+    let events =
+        setup.build_close_recipient_instruction(&recipient_setup, ctx.payer.pubkey()).send_expect_events(&mut ctx);
+
+    assert_account_closed(&ctx, &recipient_setup.recipient_pda);
+    let payer_after = ctx.get_account(&ctx.payer.pubkey()).map(|a| a.lamports).unwrap_or(0);
+    assert!(payer_after > payer_before, "Original payer should receive the rent refund");
+    let [RewardsEvent::ClaimClosed(event)] = events.as_slice() else {
+        panic!("Expected one ClaimClosed event, got {:?}", events);
+    };
+    assert_eq!(event.distribution, setup.distribution_pda);
+    assert_eq!(event.claimant, recipient_setup.recipient.pubkey());
+}
+
+#[test]
+fn test_close_basket_recipient_distribution_not_closed() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::create(&mut ctx);
+    let recipient_setup = setup.recipient_setup(&mut ctx, VestingSchedule::Immediate {});
+    setup.build_add_recipient_instruction(&ctx, &recipient_setup).send_expect_success(&mut ctx);
+
+    let error =
+        setup.build_close_recipient_instruction(&recipient_setup, ctx.payer.pubkey()).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+#[test]
+fn test_close_basket_recipient_wrong_original_payer() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::create(&mut ctx);
+    let recipient_setup = setup.recipient_setup(&mut ctx, VestingSchedule::Immediate {});
+    setup.build_add_recipient_instruction(&ctx, &recipient_setup).send_expect_success(&mut ctx);
+    setup.build_close_instruction().send_expect_success(&mut ctx);
+    let wrong_payer = ctx.create_funded_keypair();
+
+    let error =
+        setup.build_close_recipient_instruction(&recipient_setup, wrong_payer.pubkey()).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
@@ -0,0 +1,78 @@
+use rewards_program_client::accounts::{DirectDistribution, MerkleDistribution};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::fixtures::{
+    AcceptAuthoritySetup, CancelAuthorityProposalFixture, CancelAuthorityProposalSetup, ProposeAuthoritySetup,
+};
+use crate::utils::{assert_rewards_error, test_missing_signer, test_not_writable, RewardsError, TestContext};
+
+#[test]
+fn test_cancel_authority_proposal_missing_authority_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<CancelAuthorityProposalFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_cancel_authority_proposal_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<CancelAuthorityProposalFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_cancel_authority_proposal_direct_success() {
+    let mut ctx = TestContext::new();
+    let setup = CancelAuthorityProposalSetup::new(&mut ctx);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.propose_setup.distribution_pda).unwrap();
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.pending_authority, Pubkey::default());
+}
+
+#[test]
+fn test_cancel_authority_proposal_merkle_success() {
+    let mut ctx = TestContext::new();
+    let setup = CancelAuthorityProposalSetup::new_merkle(&mut ctx);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.propose_setup.distribution_pda).unwrap();
+    let distribution = MerkleDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.pending_authority, Pubkey::default());
+}
+
+#[test]
+fn test_cancel_authority_proposal_unauthorized() {
+    let mut ctx = TestContext::new();
+    let setup = CancelAuthorityProposalSetup::new(&mut ctx);
+
+    // The proposed authority cannot cancel; only the current authority can
+    let test_ix = setup.build_instruction_with_authority(&setup.propose_setup.new_authority);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::UnauthorizedAuthority);
+}
+
+#[test]
+fn test_cancel_authority_proposal_without_pending_fails() {
+    let mut ctx = TestContext::new();
+    let propose_setup = ProposeAuthoritySetup::new(&mut ctx);
+    let setup = CancelAuthorityProposalSetup { propose_setup };
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::NoPendingAuthority);
+}
+
+#[test]
+fn test_cancel_authority_proposal_blocks_accept() {
+    let mut ctx = TestContext::new();
+    let setup = CancelAuthorityProposalSetup::new(&mut ctx);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let accept_setup = AcceptAuthoritySetup { propose_setup: setup.propose_setup };
+    let error = accept_setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::NoPendingAuthority);
+}
@@ -250,3 +250,50 @@ fn test_claim_direct_exceeds_claimable_amount() {
 
     assert_rewards_error(error, RewardsError::ExceedsClaimableAmount);
 }
+
+#[test]
+fn test_claim_direct_before_claim_window_opens() {
+    let mut ctx = TestContext::new();
+    let claim_start_ts = ctx.get_current_timestamp() + 86400 * 30;
+    let setup = ClaimDirectSetup::builder(&mut ctx).claim_window(claim_start_ts, 0).warp_to_end(false).build();
+
+    ctx.warp_to_timestamp(claim_start_ts - 1);
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::ClaimWindowNotOpen);
+
+    // Tokens vested before the window opened are claimable once it does
+    ctx.warp_to_timestamp(claim_start_ts);
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&setup.recipient_token_account);
+    let expected = expected_linear_unlock(setup.amount, setup.start_ts, setup.end_ts, claim_start_ts);
+    assert_eq!(balance, expected);
+}
+
+#[test]
+fn test_claim_direct_inside_claim_window() {
+    let mut ctx = TestContext::new();
+    let claim_end_ts = ctx.get_current_timestamp() + 86400 * 400;
+    let setup = ClaimDirectSetup::builder(&mut ctx).claim_window(0, claim_end_ts).build();
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&setup.recipient_token_account);
+    assert_eq!(balance, setup.amount);
+}
+
+#[test]
+fn test_claim_direct_after_claim_window_closes() {
+    let mut ctx = TestContext::new();
+    let claim_end_ts = ctx.get_current_timestamp() + 86400 * 400;
+    let setup = ClaimDirectSetup::builder(&mut ctx).claim_window(0, claim_end_ts).build();
+
+    ctx.warp_to_timestamp(claim_end_ts);
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::ClaimWindowClosed);
+}
@@ -0,0 +1,201 @@
+use rewards_program_client::{events::RewardsEvent, types::VestingSchedule};
+use solana_sdk::{
+    instruction::{AccountMeta, InstructionError},
+    signature::Signer,
+};
+
+use crate::fixtures::{ClaimDirectManyFixture, ClaimDirectManySetup, PauseDistributionSetup, DEFAULT_GRANT_COUNT};
+use crate::utils::{
+    assert_direct_recipient, assert_instruction_error, assert_rewards_error, test_missing_signer, test_not_writable,
+    test_wrong_current_program, RewardsError, TestContext,
+};
+
+#[test]
+fn test_claim_direct_many_missing_recipient_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<ClaimDirectManyFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_claim_direct_many_recipient_token_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ClaimDirectManyFixture>(&mut ctx, 2);
+}
+
+#[test]
+fn test_claim_direct_many_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ClaimDirectManyFixture>(&mut ctx, 6);
+}
+
+#[test]
+fn test_claim_direct_many_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<ClaimDirectManyFixture>(&mut ctx);
+}
+
+#[test]
+fn test_claim_direct_many_claims_every_distribution() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimDirectManySetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&setup.recipient_token_account), setup.total_amount());
+    for grant in &setup.grants {
+        assert_direct_recipient(
+            &ctx,
+            &grant.recipient_pda,
+            &setup.recipient.pubkey(),
+            grant.amount,
+            grant.amount,
+            grant.recipient_bump,
+        );
+        assert_eq!(ctx.get_token_balance(&grant.distribution_vault), 0);
+    }
+}
+
+#[test]
+fn test_claim_direct_many_token_2022() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimDirectManySetup::new_token_2022(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&setup.recipient_token_account), setup.total_amount());
+}
+
+#[test]
+fn test_claim_direct_many_emits_claimed_event_per_distribution() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimDirectManySetup::new(&mut ctx);
+
+    let events = setup.build_instruction(&ctx).send_expect_events(&mut ctx);
+
+    assert_eq!(events.len(), DEFAULT_GRANT_COUNT);
+    for (event, grant) in events.iter().zip(&setup.grants) {
+        let RewardsEvent::Claimed(event) = event else {
+            panic!("Expected Claimed, got {:?}", event);
+        };
+        assert_eq!(event.distribution, grant.distribution_pda);
+        assert_eq!(event.claimant, setup.recipient.pubkey());
+        assert_eq!(event.amount, grant.amount);
+    }
+}
+
+#[test]
+fn test_claim_direct_many_skips_unvested_distribution() {
+    let mut ctx = TestContext::new();
+    let cliff_ts = ctx.get_current_timestamp() + 86400;
+    let setup = ClaimDirectManySetup::builder(&mut ctx)
+        .schedules(vec![VestingSchedule::Immediate, VestingSchedule::Cliff { cliff_ts }])
+        .warp_to_end(false)
+        .build();
+
+    let events = setup.build_instruction(&ctx).send_expect_events(&mut ctx);
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(ctx.get_token_balance(&setup.recipient_token_account), setup.grants[0].amount);
+    assert_direct_recipient(
+        &ctx,
+        &setup.grants[1].recipient_pda,
+        &setup.recipient.pubkey(),
+        setup.grants[1].amount,
+        0,
+        setup.grants[1].recipient_bump,
+    );
+}
+
+#[test]
+fn test_claim_direct_many_skips_paused_distribution() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimDirectManySetup::new(&mut ctx);
+    PauseDistributionSetup::pause(&mut ctx, setup.grants[0].distribution_pda, &setup.authority);
+
+    let events = setup.build_instruction(&ctx).send_expect_events(&mut ctx);
+
+    assert_eq!(events.len(), DEFAULT_GRANT_COUNT - 1);
+    assert_eq!(ctx.get_token_balance(&setup.recipient_token_account), setup.total_amount() - setup.grants[0].amount);
+    assert_direct_recipient(
+        &ctx,
+        &setup.grants[0].recipient_pda,
+        &setup.recipient.pubkey(),
+        setup.grants[0].amount,
+        0,
+        setup.grants[0].recipient_bump,
+    );
+}
+
+#[test]
+fn test_claim_direct_many_distribution_of_other_mint() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimDirectManySetup::new(&mut ctx);
+    let other = ClaimDirectManySetup::new(&mut ctx);
+    let other_grant = &other.grants[0];
+    // Vault check passes: an ATA of the other distribution for this setup's mint
+    let spoofed_vault = ctx.create_ata_for_program(&other_grant.distribution_pda, &setup.mint, &setup.token_program);
+
+    let mut remaining_accounts = setup.remaining_accounts();
+    remaining_accounts.extend([
+        AccountMeta::new(other_grant.distribution_pda, false),
+        AccountMeta::new(other_grant.recipient_pda, false),
+        AccountMeta::new(spoofed_vault, false),
+    ]);
+    let test_ix = setup.build_instruction_with(&setup.recipient, remaining_accounts);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
+
+#[test]
+fn test_claim_direct_many_nothing_to_claim() {
+    let mut ctx = TestContext::new();
+    let cliff_ts = ctx.get_current_timestamp() + 86400;
+    let setup = ClaimDirectManySetup::builder(&mut ctx)
+        .schedules(vec![VestingSchedule::Cliff { cliff_ts }, VestingSchedule::Cliff { cliff_ts }])
+        .warp_to_end(false)
+        .build();
+
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::NothingToClaim);
+}
+
+#[test]
+fn test_claim_direct_many_wrong_recipient() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimDirectManySetup::new(&mut ctx);
+    let wrong_recipient = ctx.create_funded_keypair();
+
+    let test_ix = setup.build_instruction_with(&wrong_recipient, setup.remaining_accounts());
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::UnauthorizedRecipient);
+}
+
+#[test]
+fn test_claim_direct_many_incomplete_triple() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimDirectManySetup::new(&mut ctx);
+
+    let mut remaining_accounts = setup.remaining_accounts();
+    remaining_accounts.pop();
+    let test_ix = setup.build_instruction_with(&setup.recipient, remaining_accounts);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::InvalidDistributionCount);
+}
+
+#[test]
+fn test_claim_direct_many_no_distributions() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimDirectManySetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction_with(&setup.recipient, vec![]);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::InvalidDistributionCount);
+}
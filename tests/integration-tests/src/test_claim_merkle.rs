@@ -29,13 +29,13 @@ fn test_claim_merkle_claim_account_not_writable() {
 #[test]
 fn test_claim_merkle_vault_not_writable() {
     let mut ctx = TestContext::new();
-    test_not_writable::<ClaimMerkleFixture>(&mut ctx, 6);
+    test_not_writable::<ClaimMerkleFixture>(&mut ctx, 7);
 }
 
 #[test]
 fn test_claim_merkle_claimant_token_account_not_writable() {
     let mut ctx = TestContext::new();
-    test_not_writable::<ClaimMerkleFixture>(&mut ctx, 7);
+    test_not_writable::<ClaimMerkleFixture>(&mut ctx, 8);
 }
 
 #[test]
@@ -291,3 +291,34 @@ fn test_claim_merkle_reclaim_after_full_claim_fails() {
     let error = instruction2.send_expect_error(&mut ctx);
     assert_rewards_error(error, RewardsError::NothingToClaim);
 }
+
+#[test]
+fn test_claim_merkle_before_claim_window_opens() {
+    let mut ctx = TestContext::new();
+    let claim_start_ts = ctx.get_current_timestamp() + 86400 * 30;
+    let setup = ClaimMerkleSetup::builder(&mut ctx).immediate().claim_window(claim_start_ts, 0).build();
+
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::ClaimWindowNotOpen);
+
+    ctx.warp_to_timestamp(claim_start_ts);
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&setup.claimant_token_account);
+    assert_eq!(balance, setup.total_amount);
+}
+
+#[test]
+fn test_claim_merkle_after_claim_window_closes() {
+    let mut ctx = TestContext::new();
+    let claim_end_ts = ctx.get_current_timestamp() + 86400 * 30;
+    let setup = ClaimMerkleSetup::builder(&mut ctx).immediate().claim_window(0, claim_end_ts).build();
+
+    ctx.warp_to_timestamp(claim_end_ts);
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::ClaimWindowClosed);
+}
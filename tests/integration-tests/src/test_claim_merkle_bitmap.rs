@@ -0,0 +1,211 @@
+use solana_sdk::{instruction::InstructionError, signature::Signer};
+
+use crate::fixtures::{
+    ClaimMerkleBitmapFixture, ClaimMerkleBitmapSetup, ClaimMerkleSetup, CloseMerkleDistributionSetup,
+    CreateMerkleDistributionSetup, CLAIM_BITMAP_CHUNK_BITS,
+};
+use crate::utils::{
+    assert_account_closed, assert_instruction_error, assert_rewards_error, test_missing_signer, test_not_writable,
+    test_wrong_current_program, RewardsError, TestContext,
+};
+
+/// Offset of the claim bits in a MerkleClaimBitmap account:
+/// discriminator(1) + version(1) + bump(1) + padding(3) + chunk_index(4) + payer(32)
+const BITMAP_BITS_OFFSET: usize = 42;
+
+fn is_leaf_claimed(ctx: &TestContext, setup: &ClaimMerkleBitmapSetup, index: u32) -> bool {
+    let account = ctx.get_account(&setup.claim_bitmap_pda).expect("Claim bitmap should exist");
+    let bit = index % CLAIM_BITMAP_CHUNK_BITS;
+    account.data[BITMAP_BITS_OFFSET + (bit / 8) as usize] & (1 << (bit % 8)) != 0
+}
+
+fn close_distribution(ctx: &mut TestContext, setup: &ClaimMerkleBitmapSetup) {
+    ctx.warp_to_timestamp(setup.clawback_ts + 1);
+
+    let close_dist_setup = CloseMerkleDistributionSetup {
+        authority: setup.authority.insecure_clone(),
+        distribution_pda: setup.distribution_pda,
+        mint: setup.mint,
+        distribution_vault: setup.distribution_vault,
+        authority_token_account: ctx.create_token_account(&setup.authority.pubkey(), &setup.mint),
+        token_program: setup.token_program,
+        funded_amount: 0,
+        clawback_ts: setup.clawback_ts,
+    };
+    close_dist_setup.build_instruction(ctx).send_expect_success(ctx);
+}
+
+#[test]
+fn test_claim_merkle_bitmap_missing_claimant_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<ClaimMerkleBitmapFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_claim_merkle_bitmap_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ClaimMerkleBitmapFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_claim_merkle_bitmap_claim_bitmap_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ClaimMerkleBitmapFixture>(&mut ctx, 2);
+}
+
+#[test]
+fn test_claim_merkle_bitmap_vault_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ClaimMerkleBitmapFixture>(&mut ctx, 4);
+}
+
+#[test]
+fn test_claim_merkle_bitmap_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<ClaimMerkleBitmapFixture>(&mut ctx);
+}
+
+#[test]
+fn test_claim_merkle_bitmap_success() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimMerkleBitmapSetup::new(&mut ctx);
+
+    assert!(!is_leaf_claimed(&ctx, &setup, setup.index));
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&setup.claimant_token_account), setup.amount);
+    assert!(is_leaf_claimed(&ctx, &setup, setup.index));
+    assert!(!is_leaf_claimed(&ctx, &setup, setup.index + 1));
+}
+
+#[test]
+fn test_claim_merkle_bitmap_second_chunk() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimMerkleBitmapSetup::builder(&mut ctx).index(CLAIM_BITMAP_CHUNK_BITS + 5).build();
+    assert_eq!(setup.chunk_index, 1);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&setup.claimant_token_account), setup.amount);
+    assert!(is_leaf_claimed(&ctx, &setup, setup.index));
+}
+
+#[test]
+fn test_claim_merkle_bitmap_double_claim_fails() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimMerkleBitmapSetup::new(&mut ctx);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    ctx.advance_slot();
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::LeafAlreadyClaimed);
+}
+
+#[test]
+fn test_claim_merkle_bitmap_wrong_amount() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimMerkleBitmapSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction_with(setup.index, setup.amount + 1, setup.proof.clone());
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidMerkleProof);
+}
+
+#[test]
+fn test_claim_merkle_bitmap_wrong_index() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimMerkleBitmapSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction_with(setup.index + 1, setup.amount, setup.proof.clone());
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidMerkleProof);
+}
+
+#[test]
+fn test_claim_merkle_bitmap_zero_amount() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimMerkleBitmapSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction_with(setup.index, 0, setup.proof.clone());
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidAmount);
+}
+
+#[test]
+fn test_claim_merkle_bitmap_chunk_not_created() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimMerkleBitmapSetup::builder(&mut ctx).create_chunk(false).build();
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+#[test]
+fn test_create_claim_bitmap_on_claim_account_distribution_fails() {
+    let mut ctx = TestContext::new();
+    let claim_setup = ClaimMerkleSetup::new(&mut ctx);
+    let payer = ctx.create_funded_keypair();
+
+    let test_ix = ClaimMerkleBitmapSetup::build_create_chunk_instruction(&payer, claim_setup.distribution_pda, 0);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::ClaimModeMismatch);
+}
+
+#[test]
+fn test_create_claim_bitmap_twice_fails() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimMerkleBitmapSetup::new(&mut ctx);
+
+    let test_ix = ClaimMerkleBitmapSetup::build_create_chunk_instruction(&setup.chunk_payer, setup.distribution_pda, 0);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::AccountAlreadyInitialized);
+}
+
+#[test]
+fn test_create_bitmap_distribution_revocable_fails() {
+    let mut ctx = TestContext::new();
+    let setup = CreateMerkleDistributionSetup::builder(&mut ctx).bitmap().revocable(1).build();
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidClaimMode);
+}
+
+#[test]
+fn test_close_claim_bitmap_refunds_payer() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimMerkleBitmapSetup::new(&mut ctx);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let bitmap_rent = ctx.get_account(&setup.claim_bitmap_pda).expect("Claim bitmap should exist").lamports;
+    close_distribution(&mut ctx, &setup);
+
+    let payer_before = ctx.get_account(&setup.chunk_payer.pubkey()).map(|a| a.lamports).unwrap_or(0);
+
+    setup.build_close_chunk_instruction(&setup.chunk_payer).send_expect_success(&mut ctx);
+
+    assert_account_closed(&ctx, &setup.claim_bitmap_pda);
+    let payer_after = ctx.get_account(&setup.chunk_payer.pubkey()).map(|a| a.lamports).unwrap_or(0);
+    assert_eq!(payer_after, payer_before + bitmap_rent);
+}
+
+#[test]
+fn test_close_claim_bitmap_distribution_not_closed() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimMerkleBitmapSetup::new(&mut ctx);
+
+    let error = setup.build_close_chunk_instruction(&setup.chunk_payer).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+#[test]
+fn test_close_claim_bitmap_wrong_payer() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimMerkleBitmapSetup::new(&mut ctx);
+    close_distribution(&mut ctx, &setup);
+
+    let wrong_payer = ctx.create_funded_keypair();
+    let error = setup.build_close_chunk_instruction(&wrong_payer).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
@@ -1,8 +1,9 @@
+use rewards_program_client::{accounts::DirectDistribution, events::RewardsEvent};
 use solana_sdk::signature::Signer;
 
 use crate::fixtures::{
-    AddDirectRecipientSetup, CloseDirectDistributionFixture, CloseDirectDistributionSetup,
-    CreateDirectDistributionSetup,
+    AddDirectRecipientSetup, ClaimDirectSetup, CloseDirectDistributionFixture, CloseDirectDistributionSetup,
+    CreateDirectDistributionSetup, PauseDistributionSetup, DEFAULT_RECIPIENT_AMOUNT,
 };
 use crate::utils::{
     assert_account_closed, assert_rewards_error, test_empty_data, test_missing_signer, test_not_writable,
@@ -182,3 +183,149 @@ fn test_close_direct_distribution_clawback_ts_after_timestamp_succeeds() {
     test_ix.send_expect_success(&mut ctx);
     assert_account_closed(&ctx, &close_setup.distribution_pda);
 }
+
+// ── ProtectRecipients close policy tests ───────────────────────────
+
+const UNALLOCATED_AMOUNT: u64 = 250_000;
+
+/// A `ProtectRecipients` distribution with one unclaimed recipient and some unallocated tokens in the vault
+fn protected_setup(ctx: &mut TestContext) -> (CloseDirectDistributionSetup, ClaimDirectSetup, AddDirectRecipientSetup) {
+    let distribution_setup = CreateDirectDistributionSetup::builder(ctx).protect_recipients().build();
+    let recipient_setup = AddDirectRecipientSetup::from_distribution_setup(ctx, &distribution_setup);
+    let claim_setup = ClaimDirectSetup::from_recipient_setup(ctx, &recipient_setup, false);
+    ctx.set_token_balance(&distribution_setup.distribution_vault, DEFAULT_RECIPIENT_AMOUNT + UNALLOCATED_AMOUNT);
+
+    let close_setup = CloseDirectDistributionSetup {
+        authority: distribution_setup.authority.insecure_clone(),
+        distribution_pda: distribution_setup.distribution_pda,
+        mint: distribution_setup.mint.pubkey(),
+        distribution_vault: distribution_setup.distribution_vault,
+        authority_token_account: recipient_setup.authority_token_account,
+        token_program: distribution_setup.token_program,
+    };
+    (close_setup, claim_setup, recipient_setup)
+}
+
+#[test]
+fn test_close_direct_distribution_protective_keeps_outstanding() {
+    let mut ctx = TestContext::new();
+    let (close_setup, _, _) = protected_setup(&mut ctx);
+    let authority_balance_before = ctx.get_token_balance(&close_setup.authority_token_account);
+
+    let events = close_setup.build_instruction(&ctx).send_expect_events(&mut ctx);
+
+    assert_eq!(events.len(), 1);
+    let RewardsEvent::DistributionSettled(event) = &events[0] else {
+        panic!("Expected DistributionSettled, got {:?}", events[0]);
+    };
+    assert_eq!(event.distribution, close_setup.distribution_pda);
+    assert_eq!(event.swept_amount, UNALLOCATED_AMOUNT);
+    assert_eq!(event.outstanding_amount, DEFAULT_RECIPIENT_AMOUNT);
+
+    assert_eq!(
+        ctx.get_token_balance(&close_setup.authority_token_account),
+        authority_balance_before + UNALLOCATED_AMOUNT
+    );
+    assert_eq!(ctx.get_token_balance(&close_setup.distribution_vault), DEFAULT_RECIPIENT_AMOUNT);
+
+    let account = ctx.get_account(&close_setup.distribution_pda).expect("Settled distribution should stay open");
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.settled, 1);
+}
+
+#[test]
+fn test_close_direct_distribution_protective_recipient_claims_after_settle() {
+    let mut ctx = TestContext::new();
+    let (close_setup, claim_setup, recipient_setup) = protected_setup(&mut ctx);
+    close_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    ctx.warp_to_timestamp(recipient_setup.end_ts());
+    claim_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&claim_setup.recipient_token_account), DEFAULT_RECIPIENT_AMOUNT);
+    assert_eq!(ctx.get_token_balance(&close_setup.distribution_vault), 0);
+}
+
+#[test]
+fn test_close_direct_distribution_protective_blocks_authority_actions() {
+    let mut ctx = TestContext::new();
+    let (close_setup, _, _) = protected_setup(&mut ctx);
+    close_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let pause_setup = PauseDistributionSetup {
+        authority: close_setup.authority.insecure_clone(),
+        distribution_pda: close_setup.distribution_pda,
+    };
+    let error = pause_setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::DistributionSettled);
+}
+
+#[test]
+fn test_close_direct_distribution_protective_reclose_after_claims() {
+    let mut ctx = TestContext::new();
+    let (close_setup, claim_setup, recipient_setup) = protected_setup(&mut ctx);
+    close_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    ctx.warp_to_timestamp(recipient_setup.end_ts());
+    claim_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    close_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert_account_closed(&ctx, &close_setup.distribution_pda);
+}
+
+#[test]
+fn test_close_direct_distribution_protective_paused_fails() {
+    let mut ctx = TestContext::new();
+    let (close_setup, _, _) = protected_setup(&mut ctx);
+
+    let pause_setup = PauseDistributionSetup {
+        authority: close_setup.authority.insecure_clone(),
+        distribution_pda: close_setup.distribution_pda,
+    };
+    pause_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let error = close_setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::DistributionPaused);
+}
+
+#[test]
+fn test_close_direct_distribution_protective_nothing_outstanding_closes() {
+    let mut ctx = TestContext::new();
+    let distribution_setup = CreateDirectDistributionSetup::builder(&mut ctx).protect_recipients().build();
+    let close_setup = CloseDirectDistributionSetup::from_distribution_setup(&mut ctx, &distribution_setup);
+
+    close_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert_account_closed(&ctx, &close_setup.distribution_pda);
+}
+
+#[test]
+fn test_close_direct_distribution_before_claim_window_end_fails() {
+    let mut ctx = TestContext::new();
+    let claim_end_ts = ctx.get_current_timestamp() + 86400 * 30;
+
+    // No clawback_ts: the end of the claim window is the earliest close
+    let distribution_setup = CreateDirectDistributionSetup::builder(&mut ctx).claim_window(0, claim_end_ts).build();
+    let create_ix = distribution_setup.build_instruction(&ctx);
+    create_ix.send_expect_success(&mut ctx);
+
+    let authority_token_account =
+        ctx.create_token_account(&distribution_setup.authority.pubkey(), &distribution_setup.mint.pubkey());
+
+    let close_setup = CloseDirectDistributionSetup {
+        authority: distribution_setup.authority.insecure_clone(),
+        distribution_pda: distribution_setup.distribution_pda,
+        mint: distribution_setup.mint.pubkey(),
+        distribution_vault: distribution_setup.distribution_vault,
+        authority_token_account,
+        token_program: distribution_setup.token_program,
+    };
+
+    let test_ix = close_setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::ClawbackNotReached);
+
+    ctx.warp_to_timestamp(claim_end_ts);
+    let test_ix = close_setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+    assert_account_closed(&ctx, &distribution_setup.distribution_pda);
+}
@@ -1,6 +1,9 @@
 use solana_sdk::signature::Signer;
 
-use crate::fixtures::{ClaimMerkleSetup, CloseMerkleClaimFixture, CloseMerkleClaimSetup, CloseMerkleDistributionSetup};
+use crate::fixtures::{
+    ClaimMerkleSetup, CloseMerkleClaimFixture, CloseMerkleClaimSetup, CloseMerkleDistributionSetup,
+    CrankClaimMerkleSetup,
+};
 use solana_sdk::instruction::InstructionError;
 
 use crate::utils::{
@@ -14,10 +17,16 @@ fn test_close_merkle_claim_missing_claimant_signer() {
     test_missing_signer::<CloseMerkleClaimFixture>(&mut ctx, 0, 0);
 }
 
+#[test]
+fn test_close_merkle_claim_original_payer_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<CloseMerkleClaimFixture>(&mut ctx, 1);
+}
+
 #[test]
 fn test_close_merkle_claim_claim_account_not_writable() {
     let mut ctx = TestContext::new();
-    test_not_writable::<CloseMerkleClaimFixture>(&mut ctx, 2);
+    test_not_writable::<CloseMerkleClaimFixture>(&mut ctx, 3);
 }
 
 #[test]
@@ -68,6 +77,7 @@ fn test_close_merkle_claim_distribution_not_closed() {
     // Try to close claim while distribution still open
     let close_setup = CloseMerkleClaimSetup {
         claimant: claim_setup.claimant.insecure_clone(),
+        original_payer: ctx.payer.pubkey(),
         distribution_pda: claim_setup.distribution_pda,
         claim_pda: claim_setup.claim_pda,
         token_program: claim_setup.token_program,
@@ -93,6 +103,19 @@ fn test_close_merkle_claim_wrong_claimant() {
     assert_instruction_error(error, InstructionError::InvalidAccountOwner);
 }
 
+#[test]
+fn test_close_merkle_claim_wrong_original_payer() {
+    let mut ctx = TestContext::new();
+    let setup = CloseMerkleClaimSetup::new(&mut ctx);
+
+    let wrong_payer = ctx.create_funded_keypair();
+
+    let test_ix = setup.build_instruction_with_wrong_original_payer(&ctx, wrong_payer.pubkey());
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
+
 #[test]
 fn test_close_merkle_claim_returns_rent() {
     let mut ctx = TestContext::new();
@@ -100,11 +123,11 @@ fn test_close_merkle_claim_returns_rent() {
     // Create claim with partial vesting and make a partial claim
     let claim_setup = ClaimMerkleSetup::builder(&mut ctx).linear().warp_to_end(false).build();
 
-    // Warp to 50% and make a partial claim
+    // Warp to 50% and make a partial claim, with the claimant paying rent for their own claim account
     let mid_point = claim_setup.start_ts() + (claim_setup.end_ts() - claim_setup.start_ts()) / 2;
     ctx.warp_to_timestamp(mid_point);
 
-    let claim_ix = claim_setup.build_instruction(&ctx);
+    let claim_ix = claim_setup.build_instruction(&ctx).with_account_at(0, claim_setup.claimant.pubkey());
     claim_ix.send_expect_success(&mut ctx);
 
     // Record claimant balance before close
@@ -134,6 +157,7 @@ fn test_close_merkle_claim_returns_rent() {
     // Now close the claim
     let close_claim_setup = CloseMerkleClaimSetup {
         claimant: claim_setup.claimant.insecure_clone(),
+        original_payer: claim_setup.claimant.pubkey(),
         distribution_pda: claim_setup.distribution_pda,
         claim_pda: claim_setup.claim_pda,
         token_program: claim_setup.token_program,
@@ -146,3 +170,55 @@ fn test_close_merkle_claim_returns_rent() {
     let claimant_sol_after = ctx.get_account(&claim_setup.claimant.pubkey()).map(|a| a.lamports).unwrap_or(0);
     assert_eq!(claimant_sol_after, claimant_sol_before + claim_rent);
 }
+
+#[test]
+fn test_close_merkle_claim_returns_rent_to_sponsor() {
+    let mut ctx = TestContext::new();
+
+    // A cranker sponsors the claimant's first claim and pays rent for the claim account
+    let setup = CrankClaimMerkleSetup::new(&mut ctx);
+    let crank_ix = setup.build_instruction(&ctx);
+    crank_ix.send_expect_success(&mut ctx);
+
+    let claim_setup = &setup.claim;
+    let claim_rent = ctx.get_account(&claim_setup.claim_pda).expect("Claim should exist").lamports;
+
+    // Warp to clawback and close distribution
+    ctx.warp_to_timestamp(claim_setup.end_ts() + 86400 * 365 + 1);
+
+    let close_dist_setup = CloseMerkleDistributionSetup {
+        authority: claim_setup.authority.insecure_clone(),
+        distribution_pda: claim_setup.distribution_pda,
+        mint: claim_setup.mint,
+        distribution_vault: claim_setup.distribution_vault,
+        authority_token_account: ctx.create_token_account(&claim_setup.authority.pubkey(), &claim_setup.mint),
+        token_program: claim_setup.token_program,
+        funded_amount: 0,
+        clawback_ts: claim_setup.end_ts() + 86400 * 365,
+    };
+
+    let close_dist_ix = close_dist_setup.build_instruction(&ctx);
+    close_dist_ix.send_expect_success(&mut ctx);
+
+    let claimant_sol_before = ctx.get_account(&claim_setup.claimant.pubkey()).map(|a| a.lamports).unwrap_or(0);
+    let cranker_sol_before = ctx.get_account(&setup.cranker.pubkey()).map(|a| a.lamports).unwrap_or(0);
+
+    // The claimant closes the claim; rent goes back to the sponsor, not the claimant
+    let close_claim_setup = CloseMerkleClaimSetup {
+        claimant: claim_setup.claimant.insecure_clone(),
+        original_payer: setup.cranker.pubkey(),
+        distribution_pda: claim_setup.distribution_pda,
+        claim_pda: claim_setup.claim_pda,
+        token_program: claim_setup.token_program,
+    };
+
+    let close_claim_ix = close_claim_setup.build_instruction(&ctx);
+    close_claim_ix.send_expect_success(&mut ctx);
+
+    assert_account_closed(&ctx, &claim_setup.claim_pda);
+
+    let claimant_sol_after = ctx.get_account(&claim_setup.claimant.pubkey()).map(|a| a.lamports).unwrap_or(0);
+    let cranker_sol_after = ctx.get_account(&setup.cranker.pubkey()).map(|a| a.lamports).unwrap_or(0);
+    assert_eq!(claimant_sol_after, claimant_sol_before);
+    assert_eq!(cranker_sol_after, cranker_sol_before + claim_rent, "Sponsor should receive exact rent lamports back");
+}
@@ -0,0 +1,250 @@
+use rewards_program_client::{accounts::MerkleClaim, events::RewardsEvent};
+use solana_sdk::{instruction::InstructionError, pubkey::Pubkey, signature::Signer};
+
+use crate::fixtures::{
+    CloseMerkleClaimEarlyFixture, CloseMerkleClaimEarlySetup, CloseMerkleClaimSetup, CloseMerkleDistributionSetup,
+    CrankClaimMerkleSetup, UpdateMerkleRootSetup,
+};
+use crate::utils::{
+    assert_account_closed, assert_instruction_error, assert_merkle_claim, assert_rewards_error, test_missing_signer,
+    test_not_writable, test_truncated_data, test_wrong_current_program, test_wrong_system_program, MerkleLeaf,
+    MerkleTree, RewardsError, TestContext,
+};
+
+fn lamports(ctx: &TestContext, pubkey: &Pubkey) -> u64 {
+    ctx.get_account(pubkey).map(|a| a.lamports).unwrap_or(0)
+}
+
+#[test]
+fn test_close_merkle_claim_early_missing_claimant_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<CloseMerkleClaimEarlyFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_close_merkle_claim_early_claimant_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<CloseMerkleClaimEarlyFixture>(&mut ctx, 0);
+}
+
+#[test]
+fn test_close_merkle_claim_early_original_payer_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<CloseMerkleClaimEarlyFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_close_merkle_claim_early_claim_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<CloseMerkleClaimEarlyFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_close_merkle_claim_early_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<CloseMerkleClaimEarlyFixture>(&mut ctx);
+}
+
+#[test]
+fn test_close_merkle_claim_early_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<CloseMerkleClaimEarlyFixture>(&mut ctx);
+}
+
+#[test]
+fn test_close_merkle_claim_early_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<CloseMerkleClaimEarlyFixture>(&mut ctx);
+}
+
+#[test]
+fn test_close_merkle_claim_early_success() {
+    let mut ctx = TestContext::new();
+    let setup = CloseMerkleClaimEarlySetup::new(&mut ctx);
+    let claim_rent = lamports(&ctx, &setup.claim.claim_pda);
+    let claimant_before = lamports(&ctx, &setup.claim.claimant.pubkey());
+
+    let events = setup.build_instruction(&ctx).send_expect_events(&mut ctx);
+
+    // The claim stays as a closed record of what was claimed, now funded by the claimant
+    let claim_account = ctx.get_account(&setup.claim.claim_pda).expect("Closed claim record should exist");
+    let closed = MerkleClaim::from_bytes(&claim_account.data).unwrap();
+    assert_eq!(closed.closed, 1);
+    assert_eq!(closed.claimed_amount, setup.claim.total_amount);
+    assert_eq!(closed.payer, setup.claim.claimant.pubkey());
+    assert_eq!(claim_account.lamports, claim_rent);
+
+    // The claimant takes over the rent the original payer is refunded
+    assert_eq!(lamports(&ctx, &setup.claim.claimant.pubkey()), claimant_before - claim_rent);
+
+    let [RewardsEvent::ClaimClosed(event)] = events.as_slice() else {
+        panic!("Expected one ClaimClosed event, got {:?}", events);
+    };
+    assert_eq!(event.distribution, setup.claim.distribution_pda);
+    assert_eq!(event.claimant, setup.claim.claimant.pubkey());
+}
+
+#[test]
+fn test_close_merkle_claim_early_refunds_sponsor_full_rent() {
+    let mut ctx = TestContext::new();
+
+    // A cranker sponsors the claimant's claim and pays rent for the claim account
+    let crank_setup = CrankClaimMerkleSetup::new(&mut ctx);
+    let sponsor = crank_setup.cranker.pubkey();
+    let sponsor_before = lamports(&ctx, &sponsor);
+    crank_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    let claim_rent = lamports(&ctx, &crank_setup.claim.claim_pda);
+    assert_eq!(lamports(&ctx, &sponsor), sponsor_before - claim_rent);
+
+    let setup = CloseMerkleClaimEarlySetup { claim: crank_setup.claim, original_payer: sponsor };
+    let claimant_before = lamports(&ctx, &setup.claim.claimant.pubkey());
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert_eq!(lamports(&ctx, &sponsor), sponsor_before, "Early close should refund the sponsor's full rent");
+
+    // Close the distribution and then the record; the record's rent goes back to the claimant
+    let clawback_ts = ctx.get_current_timestamp() + 86400 * 365;
+    let close_distribution = CloseMerkleDistributionSetup {
+        authority: setup.claim.authority.insecure_clone(),
+        distribution_pda: setup.claim.distribution_pda,
+        mint: setup.claim.mint,
+        distribution_vault: setup.claim.distribution_vault,
+        authority_token_account: ctx.create_token_account(&setup.claim.authority.pubkey(), &setup.claim.mint),
+        token_program: setup.claim.token_program,
+        funded_amount: 0,
+        clawback_ts,
+    };
+    ctx.warp_to_timestamp(clawback_ts + 1);
+    close_distribution.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let close_record = CloseMerkleClaimSetup {
+        claimant: setup.claim.claimant.insecure_clone(),
+        original_payer: setup.claim.claimant.pubkey(),
+        distribution_pda: setup.claim.distribution_pda,
+        claim_pda: setup.claim.claim_pda,
+        token_program: setup.claim.token_program,
+    };
+    close_record.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_account_closed(&ctx, &setup.claim.claim_pda);
+    assert_eq!(lamports(&ctx, &sponsor), sponsor_before, "Sponsor should end up with all of its rent back");
+    assert_eq!(lamports(&ctx, &setup.claim.claimant.pubkey()), claimant_before);
+}
+
+#[test]
+fn test_close_merkle_claim_early_blocks_second_payout() {
+    let mut ctx = TestContext::new();
+    let setup = CloseMerkleClaimEarlySetup::new(&mut ctx);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    let balance_before = ctx.get_token_balance(&setup.claim.claimant_token_account);
+
+    let error = setup.claim.build_instruction(&ctx).send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::NothingToClaim);
+    assert_eq!(ctx.get_token_balance(&setup.claim.claimant_token_account), balance_before);
+}
+
+#[test]
+fn test_close_merkle_claim_early_not_fully_claimed() {
+    let mut ctx = TestContext::new();
+    let setup = CloseMerkleClaimEarlySetup::new_partially_claimed(&mut ctx);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::ClaimNotFullyVested);
+}
+
+#[test]
+fn test_close_merkle_claim_early_wrong_original_payer() {
+    let mut ctx = TestContext::new();
+    let setup = CloseMerkleClaimEarlySetup::new(&mut ctx);
+    let wrong_payer = ctx.create_funded_keypair();
+
+    let error =
+        setup.build_instruction_with_wrong_original_payer(&ctx, wrong_payer.pubkey()).send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
+
+#[test]
+fn test_close_merkle_claim_early_already_closed() {
+    let mut ctx = TestContext::new();
+    let setup = CloseMerkleClaimEarlySetup::new(&mut ctx);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    ctx.advance_slot();
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
+
+#[test]
+fn test_close_merkle_claim_early_record_closes_after_distribution() {
+    let mut ctx = TestContext::new();
+    let setup = CloseMerkleClaimEarlySetup::new(&mut ctx);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    // Distributions have a default 1 year clawback
+    let current_ts = ctx.get_current_timestamp();
+    let clawback_ts = current_ts + 86400 * 365;
+    let close_distribution = CloseMerkleDistributionSetup {
+        authority: setup.claim.authority.insecure_clone(),
+        distribution_pda: setup.claim.distribution_pda,
+        mint: setup.claim.mint,
+        distribution_vault: setup.claim.distribution_vault,
+        authority_token_account: ctx.create_token_account(&setup.claim.authority.pubkey(), &setup.claim.mint),
+        token_program: setup.claim.token_program,
+        funded_amount: 0,
+        clawback_ts,
+    };
+    ctx.warp_to_timestamp(clawback_ts + 1);
+    close_distribution.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    // The claimant funds the closed record, so closing it refunds the claimant
+    let close_record = CloseMerkleClaimSetup {
+        claimant: setup.claim.claimant.insecure_clone(),
+        original_payer: setup.claim.claimant.pubkey(),
+        distribution_pda: setup.claim.distribution_pda,
+        claim_pda: setup.claim.claim_pda,
+        token_program: setup.claim.token_program,
+    };
+    close_record.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_account_closed(&ctx, &setup.claim.claim_pda);
+}
+
+#[test]
+fn test_close_merkle_claim_early_reopens_after_root_update() {
+    let mut ctx = TestContext::new();
+    let mut setup = CloseMerkleClaimEarlySetup::new(&mut ctx);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    // New cumulative leaf: claimant is now owed 1.5x their original allocation in total
+    let claim = &mut setup.claim;
+    let cumulative_amount = claim.total_amount + claim.total_amount / 2;
+    let mut leaves = claim.merkle_tree.leaves.clone();
+    leaves[0] = MerkleLeaf::new(claim.claimant.pubkey(), cumulative_amount, claim.schedule.clone());
+    let new_tree = MerkleTree::new(leaves);
+
+    let update_setup = UpdateMerkleRootSetup {
+        authority: claim.authority.insecure_clone(),
+        distribution_pda: claim.distribution_pda,
+        merkle_root: new_tree.root,
+        total_amount: cumulative_amount + claim.total_amount,
+        epoch: 1,
+    };
+    update_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    claim.total_amount = cumulative_amount;
+    claim.proof = new_tree.get_proof_for_claimant(&claim.claimant.pubkey()).unwrap();
+    claim.merkle_tree = new_tree;
+    ctx.advance_slot();
+    claim.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    // Only the delta is paid, and the reopened claim keeps the claimant as its payer
+    assert_eq!(ctx.get_token_balance(&claim.claimant_token_account), cumulative_amount);
+    assert_merkle_claim(&ctx, &claim.claim_pda, cumulative_amount, claim.claim_bump);
+    let account = ctx.get_account(&claim.claim_pda).expect("Claim account should exist");
+    let reopened = MerkleClaim::from_bytes(&account.data).unwrap();
+    assert_eq!(reopened.closed, 0);
+    assert_eq!(reopened.payer, claim.claimant.pubkey());
+}
@@ -1,6 +1,6 @@
 use solana_sdk::signature::Signer;
 
-use crate::fixtures::{CloseMerkleDistributionFixture, CloseMerkleDistributionSetup};
+use crate::fixtures::{CloseMerkleDistributionFixture, CloseMerkleDistributionSetup, CreateMerkleDistributionSetup};
 use crate::utils::{
     assert_account_closed, assert_rewards_error, test_empty_data, test_missing_signer, test_not_writable,
     test_wrong_current_program, RewardsError, TestContext,
@@ -149,3 +149,21 @@ fn test_close_merkle_distribution_closes_distribution_vault() {
     // Vault should be closed
     assert_account_closed(&ctx, &setup.distribution_vault);
 }
+
+#[test]
+fn test_close_merkle_distribution_before_claim_window_end() {
+    let mut ctx = TestContext::new();
+    let claim_end_ts = ctx.get_current_timestamp() + 86400 * 30;
+    let distribution_setup =
+        CreateMerkleDistributionSetup::builder(&mut ctx).clawback_ts(0).claim_window(0, claim_end_ts).build();
+    let setup = CloseMerkleDistributionSetup::from_distribution_setup(&mut ctx, &distribution_setup);
+
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::ClawbackNotReached);
+
+    ctx.warp_to_timestamp(claim_end_ts);
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+    assert_account_closed(&ctx, &setup.distribution_pda);
+}
@@ -0,0 +1,315 @@
+use rewards_program_client::{accounts::CompressedDistribution, events::RewardsEvent, types::VestingSchedule};
+use solana_sdk::{instruction::InstructionError, pubkey::Pubkey, signature::Signer};
+
+use crate::fixtures::{
+    AddCompressedRecipientFixture, ClaimCompressedFixture, CompressedSetup, CreateCompressedDistributionFixture,
+    FundDistributionSetup, PauseDistributionSetup, SetTransferableSetup, DEFAULT_RECIPIENT_AMOUNT,
+};
+use crate::utils::{
+    assert_account_closed, assert_instruction_error, assert_rewards_error, expected_linear_unlock, test_missing_signer,
+    test_not_writable, test_wrong_current_program, test_wrong_system_program, CompressedLeaf, RewardsError,
+    TestContext,
+};
+
+fn compressed_distribution(ctx: &TestContext, setup: &CompressedSetup) -> CompressedDistribution {
+    let account = ctx.get_account(&setup.distribution_pda).expect("Compressed distribution should exist");
+    CompressedDistribution::from_bytes(&account.data).unwrap()
+}
+
+#[test]
+fn test_create_compressed_distribution_missing_authority_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<CreateCompressedDistributionFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_create_compressed_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<CreateCompressedDistributionFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_create_compressed_distribution_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<CreateCompressedDistributionFixture>(&mut ctx);
+}
+
+#[test]
+fn test_create_compressed_distribution_success() {
+    let mut ctx = TestContext::new();
+    let setup = CompressedSetup::create(&mut ctx);
+
+    let distribution = compressed_distribution(&ctx, &setup);
+    assert_eq!(distribution.authority, setup.authority.pubkey());
+    assert_eq!(distribution.mint, setup.mint);
+    assert_eq!(distribution.bump, setup.bump);
+    assert_eq!(distribution.total_allocated, 0);
+    assert_eq!(ctx.get_token_balance(&setup.distribution_vault), 0);
+}
+
+#[test]
+fn test_add_compressed_recipient_missing_authority_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<AddCompressedRecipientFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_add_compressed_recipient_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<AddCompressedRecipientFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_add_compressed_recipient_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<AddCompressedRecipientFixture>(&mut ctx);
+}
+
+#[test]
+fn test_add_compressed_recipient_appends_leaves() {
+    let mut ctx = TestContext::new();
+    let mut setup = CompressedSetup::create(&mut ctx);
+    let first = setup.add_recipient(&mut ctx, DEFAULT_RECIPIENT_AMOUNT, VestingSchedule::Immediate);
+
+    let recipient = Pubkey::new_unique();
+    let schedule = VestingSchedule::Linear { start_ts: 0, end_ts: 1_000 };
+    let events = setup
+        .build_add_recipient_instruction(&recipient, DEFAULT_RECIPIENT_AMOUNT * 2, schedule)
+        .send_expect_events(&mut ctx);
+
+    assert_eq!(first.index, 0);
+    assert!(matches!(
+        events.as_slice(),
+        [RewardsEvent::CompressedRecipientAdded(event)]
+            if event.leaf_index == 1 && event.recipient == recipient && event.amount == DEFAULT_RECIPIENT_AMOUNT * 2
+    ));
+
+    let distribution = compressed_distribution(&ctx, &setup);
+    assert_eq!(distribution.total_allocated, DEFAULT_RECIPIENT_AMOUNT * 3);
+    assert_eq!(distribution.total_funded, DEFAULT_RECIPIENT_AMOUNT * 3);
+    assert_eq!(ctx.get_token_balance(&setup.distribution_vault), DEFAULT_RECIPIENT_AMOUNT * 3);
+}
+
+#[test]
+fn test_add_compressed_recipient_zero_amount() {
+    let mut ctx = TestContext::new();
+    let setup = CompressedSetup::create(&mut ctx);
+
+    let test_ix = setup.build_add_recipient_instruction(&Pubkey::new_unique(), 0, VestingSchedule::Immediate);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidAmount);
+}
+
+#[test]
+fn test_add_compressed_recipient_wrong_authority() {
+    let mut ctx = TestContext::new();
+    let mut setup = CompressedSetup::create(&mut ctx);
+    setup.authority = ctx.create_funded_keypair();
+    setup.authority_token_account = ctx.create_ata_for_program_with_balance(
+        &setup.authority.pubkey(),
+        &setup.mint,
+        DEFAULT_RECIPIENT_AMOUNT,
+        &setup.token_program,
+    );
+
+    let test_ix = setup.build_add_recipient_instruction(
+        &Pubkey::new_unique(),
+        DEFAULT_RECIPIENT_AMOUNT,
+        VestingSchedule::Immediate,
+    );
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::UnauthorizedAuthority);
+}
+
+#[test]
+fn test_claim_compressed_missing_recipient_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<ClaimCompressedFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_claim_compressed_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ClaimCompressedFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_claim_compressed_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<ClaimCompressedFixture>(&mut ctx);
+}
+
+#[test]
+fn test_claim_compressed_success() {
+    let mut ctx = TestContext::new();
+    let mut setup = CompressedSetup::create(&mut ctx);
+    let recipient_setup = setup.add_recipient(&mut ctx, DEFAULT_RECIPIENT_AMOUNT, VestingSchedule::Immediate);
+
+    let events = setup.build_claim_instruction(&recipient_setup, 0).send_expect_events(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&recipient_setup.recipient_token_account), DEFAULT_RECIPIENT_AMOUNT);
+    assert_eq!(compressed_distribution(&ctx, &setup).total_claimed, DEFAULT_RECIPIENT_AMOUNT);
+    assert!(matches!(
+        events.as_slice(),
+        [RewardsEvent::CompressedClaimed(event)]
+            if event.leaf_index == 0
+                && event.amount == DEFAULT_RECIPIENT_AMOUNT
+                && event.claimed_amount == DEFAULT_RECIPIENT_AMOUNT
+    ));
+}
+
+#[test]
+fn test_claim_compressed_linear_partial_claims() {
+    let mut ctx = TestContext::new();
+    let mut setup = CompressedSetup::create(&mut ctx);
+    let start_ts = ctx.get_current_timestamp();
+    let end_ts = start_ts + 1_000;
+    let schedule = VestingSchedule::Linear { start_ts, end_ts };
+    let recipient_setup = setup.add_recipient(&mut ctx, DEFAULT_RECIPIENT_AMOUNT, schedule);
+
+    ctx.warp_to_timestamp(start_ts + 250);
+    setup.claim(&mut ctx, &recipient_setup, 0);
+    let first = expected_linear_unlock(DEFAULT_RECIPIENT_AMOUNT, start_ts, end_ts, start_ts + 250);
+    assert_eq!(ctx.get_token_balance(&recipient_setup.recipient_token_account), first);
+
+    ctx.warp_to_timestamp(end_ts);
+    setup.claim(&mut ctx, &recipient_setup, 0);
+    assert_eq!(ctx.get_token_balance(&recipient_setup.recipient_token_account), DEFAULT_RECIPIENT_AMOUNT);
+    assert_eq!(setup.tree.leaves[0].claimed_amount, DEFAULT_RECIPIENT_AMOUNT);
+}
+
+#[test]
+fn test_claim_compressed_proof_survives_other_claims() {
+    let mut ctx = TestContext::new();
+    let mut setup = CompressedSetup::create(&mut ctx);
+    let alice = setup.add_recipient(&mut ctx, DEFAULT_RECIPIENT_AMOUNT, VestingSchedule::Immediate);
+    let bob = setup.add_recipient(&mut ctx, DEFAULT_RECIPIENT_AMOUNT, VestingSchedule::Immediate);
+
+    // Bob's proof is built before Alice's claim changes the root
+    let bob_ix = setup.build_claim_instruction(&bob, 0);
+    setup.claim(&mut ctx, &alice, 0);
+
+    bob_ix.send_expect_success(&mut ctx);
+    assert_eq!(ctx.get_token_balance(&bob.recipient_token_account), DEFAULT_RECIPIENT_AMOUNT);
+}
+
+#[test]
+fn test_claim_compressed_replayed_leaf_is_stale() {
+    let mut ctx = TestContext::new();
+    let mut setup = CompressedSetup::create(&mut ctx);
+    let start_ts = ctx.get_current_timestamp();
+    let schedule = VestingSchedule::Linear { start_ts, end_ts: start_ts + 1_000 };
+    let recipient_setup = setup.add_recipient(&mut ctx, DEFAULT_RECIPIENT_AMOUNT, schedule);
+
+    ctx.warp_to_timestamp(start_ts + 500);
+    let leaf = setup.tree.leaves[0].clone();
+    let root = setup.tree.root();
+    let proof = setup.tree.get_proof(0);
+    setup.claim(&mut ctx, &recipient_setup, 0);
+
+    // Resubmitting the pre-claim leaf would re-spend the same vested amount
+    ctx.warp_to_timestamp(start_ts + 600);
+    let test_ix = setup.build_claim_instruction_with(&recipient_setup, root, &leaf, 0, proof);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::StaleCompressedProof);
+}
+
+#[test]
+fn test_claim_compressed_wrong_total_amount() {
+    let mut ctx = TestContext::new();
+    let mut setup = CompressedSetup::create(&mut ctx);
+    let recipient_setup = setup.add_recipient(&mut ctx, DEFAULT_RECIPIENT_AMOUNT, VestingSchedule::Immediate);
+
+    let leaf = CompressedLeaf { total_amount: DEFAULT_RECIPIENT_AMOUNT * 2, ..setup.tree.leaves[0].clone() };
+    let test_ix =
+        setup.build_claim_instruction_with(&recipient_setup, setup.tree.root(), &leaf, 0, setup.tree.get_proof(0));
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidMerkleProof);
+}
+
+#[test]
+fn test_claim_compressed_wrong_recipient() {
+    let mut ctx = TestContext::new();
+    let mut setup = CompressedSetup::create(&mut ctx);
+    let alice = setup.add_recipient(&mut ctx, DEFAULT_RECIPIENT_AMOUNT, VestingSchedule::Immediate);
+    let mut mallory = setup.add_recipient(&mut ctx, DEFAULT_RECIPIENT_AMOUNT, VestingSchedule::Immediate);
+
+    // Mallory signs for Alice's leaf
+    mallory.index = alice.index;
+    let error = setup.build_claim_instruction(&mallory, 0).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidMerkleProof);
+}
+
+#[test]
+fn test_claim_compressed_paused() {
+    let mut ctx = TestContext::new();
+    let mut setup = CompressedSetup::create(&mut ctx);
+    let recipient_setup = setup.add_recipient(&mut ctx, DEFAULT_RECIPIENT_AMOUNT, VestingSchedule::Immediate);
+
+    let pause_setup = PauseDistributionSetup {
+        authority: setup.authority.insecure_clone(),
+        distribution_pda: setup.distribution_pda,
+    };
+    pause_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let error = setup.build_claim_instruction(&recipient_setup, 0).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::DistributionPaused);
+}
+
+#[test]
+fn test_fund_compressed_distribution() {
+    let mut ctx = TestContext::new();
+    let setup = CompressedSetup::create(&mut ctx);
+
+    let fund_setup = FundDistributionSetup {
+        funder: setup.authority.insecure_clone(),
+        distribution_pda: setup.distribution_pda,
+        mint: setup.mint,
+        distribution_vault: setup.distribution_vault,
+        funder_token_account: setup.authority_token_account,
+        token_program: setup.token_program,
+        amount: DEFAULT_RECIPIENT_AMOUNT,
+        initial_funded: 0,
+    };
+    fund_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_eq!(compressed_distribution(&ctx, &setup).total_funded, DEFAULT_RECIPIENT_AMOUNT);
+    assert_eq!(ctx.get_token_balance(&setup.distribution_vault), DEFAULT_RECIPIENT_AMOUNT);
+}
+
+#[test]
+fn test_set_transferable_compressed_distribution_fails() {
+    let mut ctx = TestContext::new();
+    let setup = CompressedSetup::create(&mut ctx);
+
+    let transferable_setup =
+        SetTransferableSetup { authority: setup.authority.insecure_clone(), distribution_pda: setup.distribution_pda };
+    let error = transferable_setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
+
+#[test]
+fn test_close_compressed_distribution_returns_unclaimed() {
+    let mut ctx = TestContext::new();
+    let mut setup = CompressedSetup::create(&mut ctx);
+    let recipient_setup = setup.add_recipient(&mut ctx, DEFAULT_RECIPIENT_AMOUNT, VestingSchedule::Immediate);
+    setup.add_recipient(&mut ctx, DEFAULT_RECIPIENT_AMOUNT, VestingSchedule::Immediate);
+    setup.claim(&mut ctx, &recipient_setup, 0);
+    let before = ctx.get_token_balance(&setup.authority_token_account);
+
+    setup.build_close_instruction().send_expect_success(&mut ctx);
+
+    assert_account_closed(&ctx, &setup.distribution_pda);
+    assert_eq!(ctx.get_token_balance(&setup.authority_token_account), before + DEFAULT_RECIPIENT_AMOUNT);
+}
+
+#[test]
+fn test_close_compressed_distribution_before_clawback() {
+    let mut ctx = TestContext::new();
+    let mut setup = CompressedSetup::new(&mut ctx);
+    setup.clawback_ts = ctx.get_current_timestamp() + 86400;
+    setup.build_create_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let error = setup.build_close_instruction().send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::ClawbackNotReached);
+}
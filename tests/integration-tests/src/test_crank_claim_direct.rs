@@ -0,0 +1,187 @@
+use rewards_program_client::types::TransferFeeMode;
+use solana_sdk::signature::Signer;
+use spl_token_2022::extension::ExtensionType;
+
+use crate::fixtures::{
+    ClaimDirectSetup, CrankClaimDirectFixture, CrankClaimDirectSetup, RemoveClaimDelegateSetup, SetClaimDelegateSetup,
+};
+use crate::utils::{
+    assert_direct_recipient, assert_rewards_error, test_empty_data, test_missing_signer, test_not_writable,
+    test_wrong_current_program, RewardsError, TestContext,
+};
+
+#[test]
+fn test_crank_claim_direct_missing_cranker_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<CrankClaimDirectFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_crank_claim_direct_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<CrankClaimDirectFixture>(&mut ctx, 2);
+}
+
+#[test]
+fn test_crank_claim_direct_destination_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<CrankClaimDirectFixture>(&mut ctx, 7);
+}
+
+#[test]
+fn test_crank_claim_direct_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<CrankClaimDirectFixture>(&mut ctx);
+}
+
+#[test]
+fn test_crank_claim_direct_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<CrankClaimDirectFixture>(&mut ctx);
+}
+
+#[test]
+fn test_crank_claim_direct_permissionless_to_recipient_ata() {
+    let mut ctx = TestContext::new();
+    let setup = CrankClaimDirectSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&setup.claim.recipient_token_account);
+    assert_eq!(balance, setup.claim.amount);
+
+    assert_direct_recipient(
+        &ctx,
+        &setup.claim.recipient_pda,
+        &setup.claim.recipient.pubkey(),
+        setup.claim.amount,
+        setup.claim.amount,
+        setup.claim.recipient_bump,
+    );
+}
+
+#[test]
+fn test_crank_claim_direct_token_2022() {
+    let mut ctx = TestContext::new();
+    let setup = CrankClaimDirectSetup::new_token_2022(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&setup.claim.recipient_token_account);
+    assert_eq!(balance, setup.claim.amount);
+}
+
+#[test]
+fn test_crank_claim_direct_permissionless_other_destination_fails() {
+    let mut ctx = TestContext::new();
+    let setup = CrankClaimDirectSetup::new(&mut ctx);
+
+    let cranker_token_account =
+        ctx.create_ata_for_program(&setup.cranker.pubkey(), &setup.claim.mint, &setup.claim.token_program);
+
+    let test_ix = setup.build_instruction_with(&setup.cranker, cranker_token_account);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::InvalidClaimDestination);
+}
+
+#[test]
+fn test_crank_claim_direct_delegate_to_any_destination() {
+    let mut ctx = TestContext::new();
+    let setup = CrankClaimDirectSetup::new(&mut ctx);
+    setup.set_cranker_as_delegate(&mut ctx);
+
+    let delegate_token_account =
+        ctx.create_ata_for_program(&setup.cranker.pubkey(), &setup.claim.mint, &setup.claim.token_program);
+
+    let test_ix = setup.build_instruction_with(&setup.cranker, delegate_token_account);
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&delegate_token_account), setup.claim.amount);
+    assert_eq!(ctx.get_token_balance(&setup.claim.recipient_token_account), 0);
+}
+
+#[test]
+fn test_crank_claim_direct_non_delegate_other_destination_fails() {
+    let mut ctx = TestContext::new();
+    let setup = CrankClaimDirectSetup::new(&mut ctx);
+    setup.set_cranker_as_delegate(&mut ctx);
+
+    let relayer = ctx.create_funded_keypair();
+    let relayer_token_account =
+        ctx.create_ata_for_program(&relayer.pubkey(), &setup.claim.mint, &setup.claim.token_program);
+
+    let test_ix = setup.build_instruction_with(&relayer, relayer_token_account);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::InvalidClaimDestination);
+}
+
+#[test]
+fn test_crank_claim_direct_removed_delegate_restricted_to_ata() {
+    let mut ctx = TestContext::new();
+    let setup = CrankClaimDirectSetup::new(&mut ctx);
+
+    let mut set_setup =
+        SetClaimDelegateSetup::for_recipient(&mut ctx, setup.claim.distribution_pda, &setup.claim.recipient);
+    set_setup.delegate = setup.cranker.insecure_clone();
+    let remove_setup = RemoveClaimDelegateSetup::from_set_setup(&mut ctx, &set_setup);
+    let remove_ix = remove_setup.build_instruction(&ctx);
+    remove_ix.send_expect_success(&mut ctx);
+
+    let cranker_token_account =
+        ctx.create_ata_for_program(&setup.cranker.pubkey(), &setup.claim.mint, &setup.claim.token_program);
+
+    let test_ix = setup.build_instruction_with(&setup.cranker, cranker_token_account);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::InvalidClaimDestination);
+}
+
+#[test]
+fn test_crank_claim_direct_permissionless_cannot_choose_amount_on_transfer_fee_mint() {
+    let mut ctx = TestContext::new();
+    let claim = ClaimDirectSetup::builder(&mut ctx).mint_extension(ExtensionType::TransferFeeConfig).build();
+    let setup = CrankClaimDirectSetup::from_claim_setup(&mut ctx, claim);
+    ctx.set_transfer_fee(&setup.claim.mint, 100, u64::MAX);
+    let vault_balance = ctx.get_token_balance(&setup.claim.distribution_vault);
+
+    // Each 1-unit transfer would pay a full unit of rounded-up fee
+    let recipient_ata = setup.claim.recipient_token_account;
+    let test_ix = setup.build_instruction_with_amount(&setup.cranker, recipient_ata, 1, TransferFeeMode::Gross);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::CrankAmountRestricted);
+
+    let test_ix = setup.build_instruction_with_amount(&setup.cranker, recipient_ata, 0, TransferFeeMode::Net);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::CrankAmountRestricted);
+
+    assert_eq!(ctx.get_token_balance(&setup.claim.distribution_vault), vault_balance);
+}
+
+#[test]
+fn test_crank_claim_direct_delegate_chooses_amount() {
+    let mut ctx = TestContext::new();
+    let setup = CrankClaimDirectSetup::new(&mut ctx);
+    setup.set_cranker_as_delegate(&mut ctx);
+
+    let recipient_ata = setup.claim.recipient_token_account;
+    let test_ix = setup.build_instruction_with_amount(&setup.cranker, recipient_ata, 1, TransferFeeMode::Gross);
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&recipient_ata), 1);
+}
+
+#[test]
+fn test_crank_claim_direct_nothing_vested() {
+    let mut ctx = TestContext::new();
+    let claim = ClaimDirectSetup::builder(&mut ctx).warp_to_end(false).build();
+    let setup = CrankClaimDirectSetup::from_claim_setup(&mut ctx, claim);
+
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::NothingToClaim);
+}
@@ -0,0 +1,120 @@
+use rewards_program_client::types::TransferFeeMode;
+use solana_sdk::signature::Signer;
+
+use crate::fixtures::{ClaimMerkleSetup, CrankClaimMerkleFixture, CrankClaimMerkleSetup};
+use crate::utils::{
+    assert_merkle_claim, assert_rewards_error, test_missing_signer, test_not_writable, test_wrong_current_program,
+    test_wrong_system_program, RewardsError, TestContext,
+};
+
+#[test]
+fn test_crank_claim_merkle_missing_cranker_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<CrankClaimMerkleFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_crank_claim_merkle_claim_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<CrankClaimMerkleFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_crank_claim_merkle_destination_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<CrankClaimMerkleFixture>(&mut ctx, 9);
+}
+
+#[test]
+fn test_crank_claim_merkle_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<CrankClaimMerkleFixture>(&mut ctx);
+}
+
+#[test]
+fn test_crank_claim_merkle_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<CrankClaimMerkleFixture>(&mut ctx);
+}
+
+#[test]
+fn test_crank_claim_merkle_permissionless_to_claimant_ata() {
+    let mut ctx = TestContext::new();
+    let setup = CrankClaimMerkleSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&setup.claim.claimant_token_account);
+    assert_eq!(balance, setup.claim.total_amount);
+
+    assert_merkle_claim(&ctx, &setup.claim.claim_pda, setup.claim.total_amount, setup.claim.claim_bump);
+}
+
+#[test]
+fn test_crank_claim_merkle_cranker_pays_claim_rent() {
+    let mut ctx = TestContext::new();
+    let setup = CrankClaimMerkleSetup::new(&mut ctx);
+    let cranker_balance_before = ctx.get_account(&setup.cranker.pubkey()).unwrap().lamports;
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let claim_rent = ctx.get_account(&setup.claim.claim_pda).unwrap().lamports;
+    let cranker_balance_after = ctx.get_account(&setup.cranker.pubkey()).unwrap().lamports;
+    assert_eq!(cranker_balance_after, cranker_balance_before - claim_rent);
+}
+
+#[test]
+fn test_crank_claim_merkle_permissionless_other_destination_fails() {
+    let mut ctx = TestContext::new();
+    let setup = CrankClaimMerkleSetup::new(&mut ctx);
+
+    let cranker_token_account =
+        ctx.create_ata_for_program(&setup.cranker.pubkey(), &setup.claim.mint, &setup.claim.token_program);
+
+    let test_ix = setup.build_instruction_with(&setup.cranker, cranker_token_account);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::InvalidClaimDestination);
+}
+
+#[test]
+fn test_crank_claim_merkle_permissionless_partial_amount_fails() {
+    let mut ctx = TestContext::new();
+    let setup = CrankClaimMerkleSetup::new(&mut ctx);
+
+    let claimant_ata = setup.claim.claimant_token_account;
+    let test_ix = setup.build_instruction_with_amount(&setup.cranker, claimant_ata, 1, TransferFeeMode::Gross);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::CrankAmountRestricted);
+}
+
+#[test]
+fn test_crank_claim_merkle_delegate_to_any_destination() {
+    let mut ctx = TestContext::new();
+    let setup = CrankClaimMerkleSetup::new(&mut ctx);
+    setup.set_cranker_as_delegate(&mut ctx);
+
+    let delegate_token_account =
+        ctx.create_ata_for_program(&setup.cranker.pubkey(), &setup.claim.mint, &setup.claim.token_program);
+
+    let test_ix = setup.build_instruction_with(&setup.cranker, delegate_token_account);
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&delegate_token_account), setup.claim.total_amount);
+    assert_eq!(ctx.get_token_balance(&setup.claim.claimant_token_account), 0);
+}
+
+#[test]
+fn test_crank_claim_merkle_nothing_vested() {
+    let mut ctx = TestContext::new();
+    let claim = ClaimMerkleSetup::builder(&mut ctx).warp_to_end(false).build();
+    let setup = CrankClaimMerkleSetup::from_claim_setup(&mut ctx, claim);
+
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::NothingToClaim);
+}
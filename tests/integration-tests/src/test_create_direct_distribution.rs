@@ -1,9 +1,10 @@
 use solana_sdk::signer::Signer;
+use spl_token_2022::extension::ExtensionType;
 
 use crate::fixtures::{CreateDirectDistributionFixture, CreateDirectDistributionSetup};
 use crate::utils::{
-    assert_direct_distribution, test_empty_data, test_missing_signer, test_not_writable, test_truncated_data,
-    test_wrong_current_program, test_wrong_system_program, TestContext,
+    assert_direct_distribution, assert_rewards_error, test_empty_data, test_missing_signer, test_not_writable,
+    test_truncated_data, test_wrong_current_program, test_wrong_system_program, RewardsError, TestContext,
 };
 
 #[test]
@@ -87,3 +88,54 @@ fn test_create_direct_distribution_success_token_2022() {
         setup.bump,
     );
 }
+
+#[test]
+fn test_create_direct_distribution_rejects_non_transferable_mint() {
+    let mut ctx = TestContext::new();
+    let setup = CreateDirectDistributionSetup::builder(&mut ctx).mint_extension(ExtensionType::NonTransferable).build();
+
+    let instruction = setup.build_instruction(&ctx);
+    let error = instruction.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::UnsupportedMintExtension);
+}
+
+#[test]
+fn test_create_direct_distribution_rejects_pausable_mint() {
+    let mut ctx = TestContext::new();
+    let setup = CreateDirectDistributionSetup::builder(&mut ctx).mint_extension(ExtensionType::Pausable).build();
+
+    let instruction = setup.build_instruction(&ctx);
+    let error = instruction.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::UnsupportedMintExtension);
+}
+
+#[test]
+fn test_create_direct_distribution_claim_window_start_after_end() {
+    let mut ctx = TestContext::new();
+    let current_ts = ctx.get_current_timestamp();
+    let setup = CreateDirectDistributionSetup::builder(&mut ctx)
+        .claim_window(current_ts + 86400 * 30, current_ts + 86400 * 10)
+        .build();
+
+    let instruction = setup.build_instruction(&ctx);
+    let error = instruction.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::InvalidTimeWindow);
+}
+
+#[test]
+fn test_create_direct_distribution_clawback_inside_claim_window() {
+    let mut ctx = TestContext::new();
+    let current_ts = ctx.get_current_timestamp();
+    let setup = CreateDirectDistributionSetup::builder(&mut ctx)
+        .clawback_ts(current_ts + 86400 * 10)
+        .claim_window(0, current_ts + 86400 * 30)
+        .build();
+
+    let instruction = setup.build_instruction(&ctx);
+    let error = instruction.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::InvalidTimeWindow);
+}
@@ -1,4 +1,5 @@
 use solana_sdk::signer::Signer;
+use spl_token_2022::extension::ExtensionType;
 
 use crate::fixtures::{CreateMerkleDistributionFixture, CreateMerkleDistributionSetup};
 use crate::utils::{
@@ -177,3 +178,65 @@ fn test_create_merkle_distribution_custom_clawback() {
         setup.bump,
     );
 }
+
+#[test]
+fn test_create_merkle_distribution_rejects_non_transferable_mint() {
+    let mut ctx = TestContext::new();
+    let setup = CreateMerkleDistributionSetup::builder(&mut ctx).mint_extension(ExtensionType::NonTransferable).build();
+
+    let instruction = setup.build_instruction(&ctx);
+    let error = instruction.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::UnsupportedMintExtension);
+}
+
+#[test]
+fn test_create_merkle_distribution_rejects_permanent_delegate_mint() {
+    let mut ctx = TestContext::new();
+    let setup =
+        CreateMerkleDistributionSetup::builder(&mut ctx).mint_extension(ExtensionType::PermanentDelegate).build();
+
+    let instruction = setup.build_instruction(&ctx);
+    let error = instruction.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::UnsupportedMintExtension);
+}
+
+#[test]
+fn test_create_merkle_distribution_bitmap_rejects_transfer_fee_mint() {
+    let mut ctx = TestContext::new();
+    let setup = CreateMerkleDistributionSetup::builder(&mut ctx)
+        .mint_extension(ExtensionType::TransferFeeConfig)
+        .bitmap()
+        .build();
+
+    let instruction = setup.build_instruction(&ctx);
+    let error = instruction.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::UnsupportedMintExtension);
+}
+
+#[test]
+fn test_create_merkle_distribution_claim_account_allows_transfer_fee_mint() {
+    let mut ctx = TestContext::new();
+    let setup =
+        CreateMerkleDistributionSetup::builder(&mut ctx).mint_extension(ExtensionType::TransferFeeConfig).build();
+
+    let instruction = setup.build_instruction(&ctx);
+    instruction.send_expect_success(&mut ctx);
+}
+
+#[test]
+fn test_create_merkle_distribution_claim_window_after_clawback() {
+    let mut ctx = TestContext::new();
+    let current_ts = ctx.get_current_timestamp();
+    let setup = CreateMerkleDistributionSetup::builder(&mut ctx)
+        .clawback_ts(current_ts + 86400 * 10)
+        .claim_window(0, current_ts + 86400 * 30)
+        .build();
+
+    let instruction = setup.build_instruction(&ctx);
+    let error = instruction.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::InvalidTimeWindow);
+}
@@ -0,0 +1,172 @@
+use rewards_program_client::accounts::{DirectDistribution, DirectRecipient};
+use rewards_program_client::events::RewardsEvent;
+use rewards_program_client::types::RevokeMode;
+use solana_sdk::signature::Signer;
+
+use crate::fixtures::{DecreaseDirectAllocationFixture, DecreaseDirectAllocationSetup, RevokeDirectRecipientSetup};
+use crate::utils::{
+    assert_account_closed, assert_rewards_error, expected_linear_unlock, test_empty_data, test_missing_signer,
+    test_not_writable, test_wrong_current_program, RewardsError, TestContext,
+};
+
+// ── Generic fixture tests ──────────────────────────────────────────
+
+#[test]
+fn test_decrease_allocation_missing_authority_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<DecreaseDirectAllocationFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_decrease_allocation_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<DecreaseDirectAllocationFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_decrease_allocation_recipient_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<DecreaseDirectAllocationFixture>(&mut ctx, 2);
+}
+
+#[test]
+fn test_decrease_allocation_vault_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<DecreaseDirectAllocationFixture>(&mut ctx, 5);
+}
+
+#[test]
+fn test_decrease_allocation_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<DecreaseDirectAllocationFixture>(&mut ctx);
+}
+
+#[test]
+fn test_decrease_allocation_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<DecreaseDirectAllocationFixture>(&mut ctx);
+}
+
+// ── Happy paths ────────────────────────────────────────────────────
+
+#[test]
+fn test_decrease_allocation_returns_tokens() {
+    let mut ctx = TestContext::new();
+    let setup = DecreaseDirectAllocationSetup::new(&mut ctx);
+    let recipient_setup = &setup.recipient_setup;
+    let vault_balance_before = ctx.get_token_balance(&recipient_setup.distribution_vault);
+    let authority_balance_before = ctx.get_token_balance(&recipient_setup.authority_token_account);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let new_total = recipient_setup.amount - setup.amount;
+    assert_eq!(ctx.get_token_balance(&recipient_setup.distribution_vault), vault_balance_before - setup.amount);
+    assert_eq!(
+        ctx.get_token_balance(&recipient_setup.authority_token_account),
+        authority_balance_before + setup.amount
+    );
+
+    let recipient_account = ctx.get_account(&recipient_setup.recipient_pda).unwrap();
+    let recipient = DirectRecipient::from_bytes(&recipient_account.data).unwrap();
+    assert_eq!(recipient.total_amount, new_total);
+    assert_eq!(recipient.schedule_amount, recipient_setup.amount);
+
+    let dist_account = ctx.get_account(&recipient_setup.distribution_pda).unwrap();
+    let dist = DirectDistribution::from_bytes(&dist_account.data).unwrap();
+    assert_eq!(dist.total_allocated, new_total);
+}
+
+#[test]
+fn test_decrease_allocation_trims_vesting_tail() {
+    let mut ctx = TestContext::new();
+    let recipient_setup = RevokeDirectRecipientSetup::new(&mut ctx);
+
+    let midpoint = recipient_setup.start_ts + (recipient_setup.end_ts - recipient_setup.start_ts) / 2;
+    ctx.warp_to_timestamp(midpoint);
+    let vested =
+        expected_linear_unlock(recipient_setup.amount, recipient_setup.start_ts, recipient_setup.end_ts, midpoint);
+
+    // Take back everything unvested; what has vested stays claimable and nothing more vests
+    let amount = recipient_setup.amount - vested;
+    let setup = DecreaseDirectAllocationSetup::from_recipient(recipient_setup, amount);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let recipient_setup = &setup.recipient_setup;
+    ctx.warp_to_timestamp(recipient_setup.end_ts + 1);
+    let recipient_balance_before = ctx.get_token_balance(&recipient_setup.recipient_token_account);
+    recipient_setup.claim_setup().build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert_eq!(ctx.get_token_balance(&recipient_setup.recipient_token_account), recipient_balance_before + vested);
+
+    recipient_setup.close_setup().build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert_account_closed(&ctx, &recipient_setup.recipient_pda);
+}
+
+#[test]
+fn test_decrease_allocation_emits_event() {
+    let mut ctx = TestContext::new();
+    let setup = DecreaseDirectAllocationSetup::new(&mut ctx);
+    let recipient_setup = &setup.recipient_setup;
+
+    let events = setup.build_instruction(&ctx).send_expect_events(&mut ctx);
+
+    assert_eq!(events.len(), 1);
+    let RewardsEvent::AllocationChanged(event) = &events[0] else {
+        panic!("Expected AllocationChanged, got {:?}", events[0]);
+    };
+    assert_eq!(event.distribution, recipient_setup.distribution_pda);
+    assert_eq!(event.recipient, recipient_setup.recipient.pubkey());
+    assert_eq!(event.previous_amount, recipient_setup.amount);
+    assert_eq!(event.new_amount, recipient_setup.amount - setup.amount);
+}
+
+// ── Error paths ────────────────────────────────────────────────────
+
+#[test]
+fn test_decrease_allocation_exceeds_unvested() {
+    let mut ctx = TestContext::new();
+    let recipient_setup = RevokeDirectRecipientSetup::new(&mut ctx);
+
+    let midpoint = recipient_setup.start_ts + (recipient_setup.end_ts - recipient_setup.start_ts) / 2;
+    ctx.warp_to_timestamp(midpoint);
+    let vested =
+        expected_linear_unlock(recipient_setup.amount, recipient_setup.start_ts, recipient_setup.end_ts, midpoint);
+
+    let amount = recipient_setup.amount - vested + 1;
+    let setup = DecreaseDirectAllocationSetup::from_recipient(recipient_setup, amount);
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InsufficientUnvestedAmount);
+}
+
+#[test]
+fn test_decrease_allocation_requires_non_vested_revocation() {
+    let mut ctx = TestContext::new();
+    let recipient_setup = RevokeDirectRecipientSetup::builder(&mut ctx).revocable(2).build();
+
+    let setup = DecreaseDirectAllocationSetup::from_recipient(recipient_setup, 1_000);
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::DistributionNotRevocable);
+}
+
+#[test]
+fn test_decrease_allocation_wrong_authority() {
+    let mut ctx = TestContext::new();
+    let mut setup = DecreaseDirectAllocationSetup::new(&mut ctx);
+    setup.recipient_setup.authority = ctx.create_funded_keypair();
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::UnauthorizedAuthority);
+}
+
+#[test]
+fn test_decrease_allocation_frozen_recipient() {
+    let mut ctx = TestContext::new();
+    let recipient_setup = RevokeDirectRecipientSetup::builder(&mut ctx).revocable(5).build();
+
+    let midpoint = recipient_setup.start_ts + (recipient_setup.end_ts - recipient_setup.start_ts) / 2;
+    ctx.warp_to_timestamp(midpoint);
+    recipient_setup.build_instruction(&ctx, RevokeMode::Freeze).send_expect_success(&mut ctx);
+
+    let setup = DecreaseDirectAllocationSetup::from_recipient(recipient_setup, 1_000);
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::ClaimantAlreadyRevoked);
+}
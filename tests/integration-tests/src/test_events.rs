@@ -0,0 +1,54 @@
+use rewards_program_client::{events::RewardsEvent, types::DistributionCreatedData};
+use solana_sdk::signature::Signer;
+
+use crate::fixtures::{ClaimDirectSetup, CreateDirectDistributionSetup, PauseDistributionSetup};
+use crate::utils::TestContext;
+
+#[test]
+fn test_create_direct_distribution_emits_created_event() {
+    let mut ctx = TestContext::new();
+    let setup = CreateDirectDistributionSetup::new(&mut ctx);
+
+    let events = setup.build_instruction(&ctx).send_expect_events(&mut ctx);
+
+    assert_eq!(events.len(), 1);
+    let RewardsEvent::DistributionCreated(event) = &events[0] else {
+        panic!("Expected DistributionCreated, got {:?}", events[0]);
+    };
+    assert_eq!(event.authority, setup.authority.pubkey());
+    assert_eq!(event.mint, setup.mint.pubkey());
+    assert_eq!(event.seed, setup.seed.pubkey());
+    assert_eq!(event.type_data, DistributionCreatedData::Direct { clawback_ts: setup.clawback_ts });
+}
+
+#[test]
+fn test_claim_direct_emits_claimed_event() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimDirectSetup::new(&mut ctx);
+
+    let events = setup.build_instruction(&ctx).send_expect_events(&mut ctx);
+
+    assert_eq!(events.len(), 1);
+    let RewardsEvent::Claimed(event) = &events[0] else {
+        panic!("Expected Claimed, got {:?}", events[0]);
+    };
+    assert_eq!(event.distribution, setup.distribution_pda);
+    assert_eq!(event.claimant, setup.recipient.pubkey());
+    assert_eq!(event.amount, setup.amount);
+}
+
+#[test]
+fn test_pause_distribution_emits_paused_event() {
+    let mut ctx = TestContext::new();
+    let setup = PauseDistributionSetup::new(&mut ctx);
+    let paused_at = ctx.get_current_timestamp();
+
+    let events = setup.build_instruction(&ctx).send_expect_events(&mut ctx);
+
+    assert_eq!(events.len(), 1);
+    let RewardsEvent::DistributionPaused(event) = &events[0] else {
+        panic!("Expected DistributionPaused, got {:?}", events[0]);
+    };
+    assert_eq!(event.distribution, setup.distribution_pda);
+    assert_eq!(event.paused_at, paused_at);
+}
@@ -0,0 +1,163 @@
+use rewards_program_client::accounts::{DirectDistribution, MerkleDistribution};
+use solana_sdk::signature::Signer;
+
+use crate::fixtures::{ClaimDirectSetup, ClaimMerkleSetup, FundDistributionFixture, FundDistributionSetup};
+use crate::utils::{
+    assert_rewards_error, test_missing_signer, test_not_writable, test_truncated_data, test_wrong_current_program,
+    RewardsError, TestContext,
+};
+
+#[test]
+fn test_fund_distribution_missing_funder_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<FundDistributionFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_fund_distribution_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<FundDistributionFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_fund_distribution_vault_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<FundDistributionFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_fund_distribution_funder_token_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<FundDistributionFixture>(&mut ctx, 4);
+}
+
+#[test]
+fn test_fund_distribution_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<FundDistributionFixture>(&mut ctx);
+}
+
+#[test]
+fn test_fund_distribution_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<FundDistributionFixture>(&mut ctx);
+}
+
+#[test]
+fn test_fund_distribution_direct_success() {
+    let mut ctx = TestContext::new();
+    let setup = FundDistributionSetup::new(&mut ctx);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&setup.distribution_vault), setup.amount);
+    assert_eq!(ctx.get_token_balance(&setup.funder_token_account), 0);
+
+    let account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.total_funded, setup.amount);
+}
+
+#[test]
+fn test_fund_distribution_merkle_success() {
+    let mut ctx = TestContext::new();
+    let setup = FundDistributionSetup::new_merkle(&mut ctx);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&setup.distribution_vault), setup.initial_funded + setup.amount);
+
+    let account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let distribution = MerkleDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.total_funded, setup.initial_funded + setup.amount);
+}
+
+#[test]
+fn test_fund_distribution_token_2022() {
+    let mut ctx = TestContext::new();
+    let setup = FundDistributionSetup::builder(&mut ctx).token_2022().build();
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&setup.distribution_vault), setup.amount);
+}
+
+#[test]
+fn test_fund_distribution_any_funder_accumulates() {
+    let mut ctx = TestContext::new();
+    let setup = FundDistributionSetup::new(&mut ctx);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let other_funder = ctx.create_funded_keypair();
+    let other_token_account = ctx.create_ata_for_program_with_balance(
+        &other_funder.pubkey(),
+        &setup.mint,
+        setup.amount,
+        &setup.token_program,
+    );
+    setup.build_instruction_with_funder(&other_funder, other_token_account).send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&setup.distribution_vault), setup.amount * 2);
+
+    let account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.total_funded, setup.amount * 2);
+}
+
+#[test]
+fn test_fund_distribution_zero_amount_fails() {
+    let mut ctx = TestContext::new();
+    let setup = FundDistributionSetup::builder(&mut ctx).amount(0).build();
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidAmount);
+}
+
+#[test]
+fn test_claim_direct_underfunded_vault_fails() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimDirectSetup::new(&mut ctx);
+    ctx.set_token_balance(&setup.distribution_vault, setup.amount - 1);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InsufficientFunds);
+}
+
+#[test]
+fn test_claim_merkle_underfunded_vault_fails() {
+    let mut ctx = TestContext::new();
+    let setup = ClaimMerkleSetup::new(&mut ctx);
+    ctx.set_token_balance(&setup.distribution_vault, 0);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InsufficientFunds);
+}
+
+#[test]
+fn test_claim_direct_succeeds_after_funding() {
+    let mut ctx = TestContext::new();
+    let claim_setup = ClaimDirectSetup::new(&mut ctx);
+    ctx.set_token_balance(&claim_setup.distribution_vault, 0);
+
+    let funder = ctx.create_funded_keypair();
+    let funder_token_account = ctx.create_ata_for_program_with_balance(
+        &funder.pubkey(),
+        &claim_setup.mint,
+        claim_setup.amount,
+        &claim_setup.token_program,
+    );
+    let fund_setup = FundDistributionSetup {
+        funder,
+        distribution_pda: claim_setup.distribution_pda,
+        mint: claim_setup.mint,
+        distribution_vault: claim_setup.distribution_vault,
+        funder_token_account,
+        token_program: claim_setup.token_program,
+        amount: claim_setup.amount,
+        initial_funded: claim_setup.amount,
+    };
+    fund_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    claim_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert_eq!(ctx.get_token_balance(&claim_setup.recipient_token_account), claim_setup.amount);
+}
@@ -0,0 +1,267 @@
+use rewards_program_client::accounts::{DirectDistribution, DirectRecipient};
+use rewards_program_client::events::RewardsEvent;
+use rewards_program_client::types::{RevokeMode, VestingSchedule};
+use solana_sdk::signature::Signer;
+
+use crate::fixtures::{
+    IncreaseDirectAllocationFixture, IncreaseDirectAllocationSetup, RevokeDirectRecipientSetup, DEFAULT_INCREASE_AMOUNT,
+};
+use crate::utils::{
+    assert_rewards_error, expected_linear_unlock, test_empty_data, test_missing_signer, test_not_writable,
+    test_wrong_current_program, test_wrong_system_program, RewardsError, TestContext,
+};
+
+// ── Generic fixture tests ──────────────────────────────────────────
+
+#[test]
+fn test_increase_allocation_missing_payer_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<IncreaseDirectAllocationFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_increase_allocation_missing_authority_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<IncreaseDirectAllocationFixture>(&mut ctx, 1, 1);
+}
+
+#[test]
+fn test_increase_allocation_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<IncreaseDirectAllocationFixture>(&mut ctx, 2);
+}
+
+#[test]
+fn test_increase_allocation_recipient_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<IncreaseDirectAllocationFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_increase_allocation_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<IncreaseDirectAllocationFixture>(&mut ctx);
+}
+
+#[test]
+fn test_increase_allocation_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<IncreaseDirectAllocationFixture>(&mut ctx);
+}
+
+#[test]
+fn test_increase_allocation_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<IncreaseDirectAllocationFixture>(&mut ctx);
+}
+
+// ── Happy paths ────────────────────────────────────────────────────
+
+#[test]
+fn test_increase_allocation_keeps_schedule() {
+    let mut ctx = TestContext::new();
+    let setup = IncreaseDirectAllocationSetup::new(&mut ctx);
+    let recipient_setup = &setup.recipient_setup;
+    let vault_balance_before = ctx.get_token_balance(&recipient_setup.distribution_vault);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let new_total = recipient_setup.amount + setup.amount;
+    assert_eq!(ctx.get_token_balance(&recipient_setup.distribution_vault), vault_balance_before + setup.amount);
+    assert_eq!(ctx.get_token_balance(&recipient_setup.authority_token_account), 0);
+
+    let recipient_account = ctx.get_account(&recipient_setup.recipient_pda).unwrap();
+    let recipient = DirectRecipient::from_bytes(&recipient_account.data).unwrap();
+    assert_eq!(recipient.total_amount, new_total);
+    assert_eq!(recipient.schedule_amount, new_total);
+    assert_eq!(
+        recipient.schedule,
+        VestingSchedule::Linear { start_ts: recipient_setup.start_ts, end_ts: recipient_setup.end_ts }
+    );
+
+    let dist_account = ctx.get_account(&recipient_setup.distribution_pda).unwrap();
+    let dist = DirectDistribution::from_bytes(&dist_account.data).unwrap();
+    assert_eq!(dist.total_allocated, new_total);
+    assert_eq!(dist.total_funded, new_total);
+}
+
+#[test]
+fn test_increase_allocation_vests_on_current_curve() {
+    let mut ctx = TestContext::new();
+    let setup = IncreaseDirectAllocationSetup::new(&mut ctx);
+    let recipient_setup = &setup.recipient_setup;
+
+    let midpoint = recipient_setup.start_ts + (recipient_setup.end_ts - recipient_setup.start_ts) / 2;
+    ctx.warp_to_timestamp(midpoint);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let recipient_balance_before = ctx.get_token_balance(&recipient_setup.recipient_token_account);
+    recipient_setup.claim_setup().build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    // The added tokens vest as if they had been part of the grant from the start
+    let new_total = recipient_setup.amount + setup.amount;
+    let expected = expected_linear_unlock(new_total, recipient_setup.start_ts, recipient_setup.end_ts, midpoint);
+    assert_eq!(ctx.get_token_balance(&recipient_setup.recipient_token_account), recipient_balance_before + expected);
+}
+
+#[test]
+fn test_increase_allocation_with_new_schedule_resizes_account() {
+    let mut ctx = TestContext::new();
+    let setup = IncreaseDirectAllocationSetup::new(&mut ctx);
+    let recipient_setup = &setup.recipient_setup;
+    let len_before = ctx.get_account(&recipient_setup.recipient_pda).unwrap().data.len();
+
+    // The same curve with more tokens, so none of the original grant unlocks later
+    let schedule = VestingSchedule::CliffLinear {
+        start_ts: recipient_setup.start_ts,
+        cliff_ts: recipient_setup.start_ts,
+        end_ts: recipient_setup.end_ts,
+    };
+    setup.build_instruction_with_schedule(&ctx, Some(schedule.clone())).send_expect_success(&mut ctx);
+
+    let recipient_account = ctx.get_account(&recipient_setup.recipient_pda).unwrap();
+    assert_eq!(recipient_account.data.len(), len_before + 8); // CliffLinear is 8 bytes longer than Linear
+    assert_eq!(recipient_account.lamports, ctx.svm.minimum_balance_for_rent_exemption(recipient_account.data.len()));
+
+    let recipient = DirectRecipient::from_bytes(&recipient_account.data).unwrap();
+    assert_eq!(recipient.schedule, schedule);
+    assert_eq!(recipient.total_amount, recipient_setup.amount + setup.amount);
+    assert_eq!(recipient.schedule_amount, recipient.total_amount);
+}
+
+#[test]
+fn test_increase_allocation_with_new_schedule_speeds_up_existing_tokens() {
+    let mut ctx = TestContext::new();
+    let setup = IncreaseDirectAllocationSetup::new(&mut ctx);
+    let recipient_setup = &setup.recipient_setup;
+
+    let new_end_ts = recipient_setup.start_ts + (recipient_setup.end_ts - recipient_setup.start_ts) / 2;
+    let schedule = VestingSchedule::Linear { start_ts: recipient_setup.start_ts, end_ts: new_end_ts };
+    setup.build_instruction_with_schedule(&ctx, Some(schedule)).send_expect_success(&mut ctx);
+
+    ctx.warp_to_timestamp(new_end_ts);
+    recipient_setup.claim_setup().build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    // The original grant follows the shorter curve too and completes at the new end
+    let new_total = recipient_setup.amount + setup.amount;
+    assert_eq!(ctx.get_token_balance(&recipient_setup.recipient_token_account), new_total);
+}
+
+#[test]
+fn test_increase_allocation_non_revocable_keeps_original_dates() {
+    let mut ctx = TestContext::new();
+    let recipient_setup = RevokeDirectRecipientSetup::builder(&mut ctx).revocable(0).build();
+    let setup = IncreaseDirectAllocationSetup::from_recipient(&mut ctx, recipient_setup, DEFAULT_INCREASE_AMOUNT);
+    let recipient_setup = &setup.recipient_setup;
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    // The grant unlocks on its original curve, now carrying the added tokens as well
+    let new_total = recipient_setup.amount + setup.amount;
+    let midpoint = recipient_setup.start_ts + (recipient_setup.end_ts - recipient_setup.start_ts) / 2;
+    ctx.warp_to_timestamp(midpoint);
+    recipient_setup.claim_setup().build_instruction(&ctx).send_expect_success(&mut ctx);
+    let expected = expected_linear_unlock(new_total, recipient_setup.start_ts, recipient_setup.end_ts, midpoint);
+    assert!(expected >= recipient_setup.amount / 2);
+    assert_eq!(ctx.get_token_balance(&recipient_setup.recipient_token_account), expected);
+
+    ctx.warp_to_timestamp(recipient_setup.end_ts);
+    recipient_setup.claim_setup().build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert_eq!(ctx.get_token_balance(&recipient_setup.recipient_token_account), new_total);
+}
+
+#[test]
+fn test_increase_allocation_emits_event() {
+    let mut ctx = TestContext::new();
+    let setup = IncreaseDirectAllocationSetup::new(&mut ctx);
+    let recipient_setup = &setup.recipient_setup;
+
+    let events = setup.build_instruction(&ctx).send_expect_events(&mut ctx);
+
+    assert_eq!(events.len(), 1);
+    let RewardsEvent::AllocationChanged(event) = &events[0] else {
+        panic!("Expected AllocationChanged, got {:?}", events[0]);
+    };
+    assert_eq!(event.distribution, recipient_setup.distribution_pda);
+    assert_eq!(event.recipient, recipient_setup.recipient.pubkey());
+    assert_eq!(event.previous_amount, recipient_setup.amount);
+    assert_eq!(event.new_amount, recipient_setup.amount + setup.amount);
+}
+
+// ── Error paths ────────────────────────────────────────────────────
+
+#[test]
+fn test_increase_allocation_zero_amount() {
+    let mut ctx = TestContext::new();
+    let recipient_setup = RevokeDirectRecipientSetup::new(&mut ctx);
+    let setup = IncreaseDirectAllocationSetup::from_recipient(&mut ctx, recipient_setup, 0);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidAmount);
+}
+
+#[test]
+fn test_increase_allocation_wrong_authority() {
+    let mut ctx = TestContext::new();
+    let mut setup = IncreaseDirectAllocationSetup::new(&mut ctx);
+    setup.recipient_setup.authority = ctx.create_funded_keypair();
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::UnauthorizedAuthority);
+}
+
+#[test]
+fn test_increase_allocation_schedule_relocks_vested_tokens() {
+    let mut ctx = TestContext::new();
+    let setup = IncreaseDirectAllocationSetup::new(&mut ctx);
+    let recipient_setup = &setup.recipient_setup;
+
+    let midpoint = recipient_setup.start_ts + (recipient_setup.end_ts - recipient_setup.start_ts) / 2;
+    ctx.warp_to_timestamp(midpoint);
+
+    // Restarting vesting now would take back the half that has vested
+    let schedule = VestingSchedule::Linear { start_ts: midpoint, end_ts: recipient_setup.end_ts };
+    let error = setup.build_instruction_with_schedule(&ctx, Some(schedule)).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::ScheduleRelocksVestedTokens);
+}
+
+#[test]
+fn test_increase_allocation_frozen_recipient() {
+    let mut ctx = TestContext::new();
+    let recipient_setup = RevokeDirectRecipientSetup::builder(&mut ctx).revocable(4).build();
+
+    let midpoint = recipient_setup.start_ts + (recipient_setup.end_ts - recipient_setup.start_ts) / 2;
+    ctx.warp_to_timestamp(midpoint);
+    recipient_setup.build_instruction(&ctx, RevokeMode::Freeze).send_expect_success(&mut ctx);
+
+    let setup = IncreaseDirectAllocationSetup::from_recipient(&mut ctx, recipient_setup, 1_000);
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::ClaimantAlreadyRevoked);
+}
+
+#[test]
+fn test_increase_allocation_schedule_not_revocable() {
+    let mut ctx = TestContext::new();
+    let recipient_setup = RevokeDirectRecipientSetup::builder(&mut ctx).revocable(0).build();
+    let setup = IncreaseDirectAllocationSetup::from_recipient(&mut ctx, recipient_setup, DEFAULT_INCREASE_AMOUNT);
+    let recipient_setup = &setup.recipient_setup;
+
+    // Even a faster curve is refused: a non-revocable grant keeps the schedule it was given
+    let new_end_ts = recipient_setup.start_ts + (recipient_setup.end_ts - recipient_setup.start_ts) / 2;
+    let schedule = VestingSchedule::Linear { start_ts: recipient_setup.start_ts, end_ts: new_end_ts };
+    let error = setup.build_instruction_with_schedule(&ctx, Some(schedule)).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::DistributionNotRevocable);
+}
+
+#[test]
+fn test_increase_allocation_schedule_delays_unlocks() {
+    let mut ctx = TestContext::new();
+    let setup = IncreaseDirectAllocationSetup::new(&mut ctx);
+    let recipient_setup = &setup.recipient_setup;
+
+    // Nothing has vested yet, but stretching the curve would unlock the original grant later
+    let new_end_ts = recipient_setup.end_ts + (recipient_setup.end_ts - recipient_setup.start_ts);
+    let schedule = VestingSchedule::Linear { start_ts: recipient_setup.start_ts, end_ts: new_end_ts };
+    let error = setup.build_instruction_with_schedule(&ctx, Some(schedule)).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::ScheduleDelaysUnlocks);
+}
@@ -0,0 +1,260 @@
+use rewards_program_client::accounts::{DirectDistribution, DirectRecipient, MerkleClaim, MerkleDistribution};
+use solana_sdk::{instruction::InstructionError, signature::Signer};
+
+use crate::fixtures::{
+    downgrade_account, AddDirectRecipientSetup, ClaimDirectSetup, ClaimMerkleSetup, CloseMerkleClaimSetup,
+    CreateDirectDistributionSetup, CreateMerkleDistributionSetup, MigrateAccountFixture, MigrateAccountSetup,
+    PauseDistributionSetup, SetClaimDelegateSetup, DIRECT_DISTRIBUTION_VERSION_LENS, MERKLE_CLAIM_VERSION_LENS,
+    MERKLE_DISTRIBUTION_VERSION_LENS,
+};
+use crate::utils::{
+    assert_account_closed, assert_instruction_error, assert_rewards_error, test_missing_signer, test_not_writable,
+    test_wrong_system_program, RewardsError, TestContext,
+};
+
+#[test]
+fn test_migrate_account_missing_payer_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<MigrateAccountFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_migrate_account_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<MigrateAccountFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_migrate_account_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<MigrateAccountFixture>(&mut ctx);
+}
+
+#[test]
+fn test_migrate_account_direct_distribution_success() {
+    let mut ctx = TestContext::new();
+    let setup = MigrateAccountSetup::new(&mut ctx);
+
+    let legacy = ctx.get_account(&setup.account).unwrap();
+    assert_eq!(legacy.data.len(), DIRECT_DISTRIBUTION_VERSION_LENS[0]);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.account).unwrap();
+    let current_len = *DIRECT_DISTRIBUTION_VERSION_LENS.last().unwrap();
+    assert_eq!(account.data.len(), current_len);
+    assert_eq!(account.data[1], DIRECT_DISTRIBUTION_VERSION_LENS.len() as u8);
+    assert_eq!(account.lamports, ctx.svm.minimum_balance_for_rent_exemption(current_len));
+    assert_eq!(&account.data[..legacy.data.len()][2..], &legacy.data[2..]);
+
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.seed_authority, distribution.authority);
+    assert_eq!(distribution.total_funded, distribution.total_allocated);
+    assert_eq!(distribution.paused_at, 0);
+    assert_eq!(distribution.claim_start_ts, 0);
+    assert_eq!(distribution.claim_end_ts, 0);
+}
+
+#[test]
+fn test_migrate_account_merkle_distribution_each_version() {
+    for version in 1..MERKLE_DISTRIBUTION_VERSION_LENS.len() {
+        let mut ctx = TestContext::new();
+        let distribution_setup = CreateMerkleDistributionSetup::new(&mut ctx);
+        distribution_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+        let original = ctx.get_account(&distribution_setup.distribution_pda).unwrap();
+
+        downgrade_account(
+            &mut ctx,
+            &distribution_setup.distribution_pda,
+            version as u8,
+            MERKLE_DISTRIBUTION_VERSION_LENS[version - 1],
+        );
+
+        let setup = MigrateAccountSetup::from_account(&mut ctx, distribution_setup.distribution_pda);
+        setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+        // Every field the default distribution sets survives a round trip through any older layout
+        let account = ctx.get_account(&setup.account).unwrap();
+        assert_eq!(account.data, original.data);
+
+        let distribution = MerkleDistribution::from_bytes(&account.data).unwrap();
+        assert_eq!(distribution.merkle_root, distribution_setup.merkle_root);
+        assert_eq!(distribution.total_amount, distribution_setup.total_amount);
+    }
+}
+
+#[test]
+fn test_migrate_account_already_current() {
+    let mut ctx = TestContext::new();
+    let distribution_setup = CreateDirectDistributionSetup::new(&mut ctx);
+    distribution_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let setup = MigrateAccountSetup::from_account(&mut ctx, distribution_setup.distribution_pda);
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::AccountAlreadyMigrated);
+}
+
+#[test]
+fn test_migrate_account_twice_fails() {
+    let mut ctx = TestContext::new();
+    let setup = MigrateAccountSetup::new(&mut ctx);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    ctx.advance_slot();
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::AccountAlreadyMigrated);
+}
+
+#[test]
+fn test_migrate_account_direct_recipient_each_version() {
+    // v1 recipients ended at the schedule; v2 added revoked_at, v3 schedule_amount and v4 base_vesting_offset
+    for (version, trimmed_len) in [(1u8, 24), (2, 16), (3, 8)] {
+        let mut ctx = TestContext::new();
+        let recipient_setup = AddDirectRecipientSetup::new(&mut ctx);
+        recipient_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+        let original = ctx.get_account(&recipient_setup.recipient_pda).unwrap();
+
+        downgrade_account(&mut ctx, &recipient_setup.recipient_pda, version, original.data.len() - trimmed_len);
+
+        let setup = MigrateAccountSetup::from_account(&mut ctx, recipient_setup.recipient_pda);
+        setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+        let account = ctx.get_account(&recipient_setup.recipient_pda).unwrap();
+        assert_eq!(account.data, original.data);
+        assert_eq!(account.lamports, original.lamports);
+
+        let recipient = DirectRecipient::from_bytes(&account.data).unwrap();
+        assert_eq!(recipient.revoked_at, 0);
+        assert_eq!(recipient.schedule_amount, recipient.total_amount);
+        assert_eq!(recipient.base_vesting_offset, 0);
+    }
+}
+
+#[test]
+fn test_migrate_account_single_version_account() {
+    let mut ctx = TestContext::new();
+    let delegate_setup = SetClaimDelegateSetup::new(&mut ctx);
+    delegate_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let setup = MigrateAccountSetup::from_account(&mut ctx, delegate_setup.claim_delegate_pda);
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::AccountAlreadyMigrated);
+}
+
+#[test]
+fn test_migrate_account_unknown_version() {
+    let mut ctx = TestContext::new();
+    let distribution_setup = CreateDirectDistributionSetup::new(&mut ctx);
+    distribution_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let current_len = *DIRECT_DISTRIBUTION_VERSION_LENS.last().unwrap();
+    downgrade_account(&mut ctx, &distribution_setup.distribution_pda, u8::MAX, current_len);
+
+    let setup = MigrateAccountSetup::from_account(&mut ctx, distribution_setup.distribution_pda);
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidAccountVersion);
+}
+
+#[test]
+fn test_migrate_account_unmigrated_distribution_rejects_pause() {
+    let mut ctx = TestContext::new();
+    let distribution_setup = CreateDirectDistributionSetup::new(&mut ctx);
+    distribution_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    downgrade_account(&mut ctx, &distribution_setup.distribution_pda, 1, DIRECT_DISTRIBUTION_VERSION_LENS[0]);
+
+    // v1 has no room for paused_at, and pausing has no payer to grow the account
+    let pause_setup =
+        PauseDistributionSetup::for_distribution(distribution_setup.distribution_pda, &distribution_setup.authority);
+    let error = pause_setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::AccountNotMigrated);
+
+    let setup = MigrateAccountSetup::from_account(&mut ctx, distribution_setup.distribution_pda);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    ctx.advance_slot();
+    pause_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&distribution_setup.distribution_pda).unwrap();
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.paused, 1);
+}
+
+#[test]
+fn test_migrate_account_unmigrated_direct_claim_keeps_stored_version() {
+    let mut ctx = TestContext::new();
+    let recipient_setup = AddDirectRecipientSetup::new(&mut ctx);
+    let claim = ClaimDirectSetup::from_recipient_setup(&mut ctx, &recipient_setup, true);
+
+    downgrade_account(&mut ctx, &claim.distribution_pda, 1, DIRECT_DISTRIBUTION_VERSION_LENS[0]);
+    let recipient_len = ctx.get_account(&claim.recipient_pda).unwrap().data.len() - 24;
+    downgrade_account(&mut ctx, &claim.recipient_pda, 1, recipient_len);
+
+    // A claim only touches claimed amounts, which every layout has
+    claim.build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert_eq!(ctx.get_token_balance(&claim.recipient_token_account), claim.amount);
+
+    let account = ctx.get_account(&claim.distribution_pda).unwrap();
+    assert_eq!(account.data.len(), DIRECT_DISTRIBUTION_VERSION_LENS[0]);
+    assert_eq!(account.data[1], 1);
+    let total_claimed = u64::from_le_bytes(account.data[2 + 112..2 + 120].try_into().unwrap());
+    assert_eq!(total_claimed, claim.amount);
+
+    let account = ctx.get_account(&claim.recipient_pda).unwrap();
+    assert_eq!(account.data.len(), recipient_len);
+    assert_eq!(account.data[1], 1);
+    let claimed_amount = u64::from_le_bytes(account.data[2 + 105..2 + 113].try_into().unwrap());
+    assert_eq!(claimed_amount, claim.amount);
+}
+
+#[test]
+fn test_migrate_account_unmigrated_merkle_claim_migrates_on_write() {
+    let mut ctx = TestContext::new();
+    let claim = ClaimMerkleSetup::builder(&mut ctx).linear().warp_to_end(false).build();
+    let mid_point = claim.start_ts() + (claim.end_ts() - claim.start_ts()) / 2;
+    ctx.warp_to_timestamp(mid_point);
+    claim.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    downgrade_account(&mut ctx, &claim.distribution_pda, 1, MERKLE_DISTRIBUTION_VERSION_LENS[0]);
+    downgrade_account(&mut ctx, &claim.claim_pda, 1, MERKLE_CLAIM_VERSION_LENS[0]);
+
+    // ClaimMerkle has a payer, so both accounts grow to the current layout on the way
+    ctx.warp_to_timestamp(claim.end_ts());
+    claim.build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert_eq!(ctx.get_token_balance(&claim.claimant_token_account), claim.total_amount);
+
+    let distribution_len = *MERKLE_DISTRIBUTION_VERSION_LENS.last().unwrap();
+    let account = ctx.get_account(&claim.distribution_pda).unwrap();
+    assert_eq!(account.data.len(), distribution_len);
+    assert_eq!(account.data[1], MERKLE_DISTRIBUTION_VERSION_LENS.len() as u8);
+    assert_eq!(account.lamports, ctx.svm.minimum_balance_for_rent_exemption(distribution_len));
+    let distribution = MerkleDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.total_claimed, claim.total_amount);
+
+    let claim_len = *MERKLE_CLAIM_VERSION_LENS.last().unwrap();
+    let account = ctx.get_account(&claim.claim_pda).unwrap();
+    assert_eq!(account.data.len(), claim_len);
+    assert_eq!(account.lamports, ctx.svm.minimum_balance_for_rent_exemption(claim_len));
+    let migrated = MerkleClaim::from_bytes(&account.data).unwrap();
+    assert_eq!(migrated.claimed_amount, claim.total_amount);
+}
+
+#[test]
+fn test_migrate_account_merkle_claim_refunds_claimant() {
+    let mut ctx = TestContext::new();
+    let close_setup = CloseMerkleClaimSetup::new(&mut ctx);
+
+    // v1 claims did not record a payer; the claimant paid for them
+    downgrade_account(&mut ctx, &close_setup.claim_pda, 1, MERKLE_CLAIM_VERSION_LENS[0]);
+
+    let setup = MigrateAccountSetup::from_account(&mut ctx, close_setup.claim_pda);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&close_setup.claim_pda).unwrap();
+    assert_eq!(account.data.len(), *MERKLE_CLAIM_VERSION_LENS.last().unwrap());
+
+    let error = close_setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+
+    close_setup.build_instruction(&ctx).with_account_at(1, close_setup.claimant.pubkey()).send_expect_success(&mut ctx);
+    assert_account_closed(&ctx, &close_setup.claim_pda);
+}
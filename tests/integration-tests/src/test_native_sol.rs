@@ -0,0 +1,134 @@
+use rewards_program_client::types::VestingSchedule;
+use solana_sdk::{instruction::InstructionError, pubkey::Pubkey, signature::Signer};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+use spl_token_interface::ID as TOKEN_PROGRAM_ID;
+
+use crate::fixtures::{NativeDirectDistributionSetup, DEFAULT_RECIPIENT_AMOUNT, NATIVE_SOL_MINT};
+use crate::utils::{
+    assert_account_closed, assert_direct_distribution, assert_direct_recipient, assert_instruction_error,
+    assert_rewards_error, RewardsError, TestContext, PROGRAM_ID,
+};
+
+fn lamports(ctx: &TestContext, address: &Pubkey) -> u64 {
+    ctx.get_account(address).map(|account| account.lamports).unwrap_or(0)
+}
+
+#[test]
+fn test_native_create_direct_distribution_creates_lamport_vault() {
+    let mut ctx = TestContext::new();
+    let setup = NativeDirectDistributionSetup::create(&mut ctx);
+
+    assert_direct_distribution(&ctx, &setup.distribution_pda, &setup.authority.pubkey(), &NATIVE_SOL_MINT, setup.bump);
+
+    let vault = ctx.get_account(&setup.distribution_vault).expect("Native vault should exist");
+    assert_eq!(vault.owner, PROGRAM_ID);
+    assert!(vault.data.is_empty());
+    assert_eq!(vault.lamports, ctx.svm.minimum_balance_for_rent_exemption(0));
+}
+
+#[test]
+fn test_native_create_direct_distribution_wrong_vault() {
+    let mut ctx = TestContext::new();
+    let setup = NativeDirectDistributionSetup::new(&mut ctx);
+
+    let test_ix = setup.build_create_instruction_with(&ctx, Pubkey::new_unique(), SYSTEM_PROGRAM_ID);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
+
+#[test]
+fn test_native_create_direct_distribution_rejects_token_program() {
+    let mut ctx = TestContext::new();
+    let setup = NativeDirectDistributionSetup::new(&mut ctx);
+
+    let test_ix = setup.build_create_instruction_with(&ctx, setup.distribution_vault, TOKEN_PROGRAM_ID);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::IncorrectProgramId);
+}
+
+#[test]
+fn test_native_add_direct_recipient_deposits_lamports() {
+    let mut ctx = TestContext::new();
+    let setup = NativeDirectDistributionSetup::create(&mut ctx);
+    let vault_before = lamports(&ctx, &setup.distribution_vault);
+    let authority_before = lamports(&ctx, &setup.authority.pubkey());
+
+    let recipient_setup = setup.recipient_setup(&mut ctx, VestingSchedule::Immediate);
+    recipient_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_eq!(lamports(&ctx, &setup.distribution_vault), vault_before + DEFAULT_RECIPIENT_AMOUNT);
+    assert_eq!(lamports(&ctx, &setup.authority.pubkey()), authority_before - DEFAULT_RECIPIENT_AMOUNT);
+    assert_direct_recipient(
+        &ctx,
+        &recipient_setup.recipient_pda,
+        &recipient_setup.recipient.pubkey(),
+        DEFAULT_RECIPIENT_AMOUNT,
+        0,
+        recipient_setup.recipient_bump,
+    );
+}
+
+#[test]
+fn test_native_claim_direct_pays_lamports_to_wallet() {
+    let mut ctx = TestContext::new();
+    let setup = NativeDirectDistributionSetup::create(&mut ctx);
+    let recipient_setup = setup.recipient_setup(&mut ctx, VestingSchedule::Immediate);
+    recipient_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    let vault_before = lamports(&ctx, &setup.distribution_vault);
+    let recipient_before = lamports(&ctx, &recipient_setup.recipient.pubkey());
+
+    let claim_setup = NativeDirectDistributionSetup::claim_setup(&recipient_setup);
+    claim_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_eq!(lamports(&ctx, &recipient_setup.recipient.pubkey()), recipient_before + DEFAULT_RECIPIENT_AMOUNT);
+    assert_eq!(lamports(&ctx, &setup.distribution_vault), vault_before - DEFAULT_RECIPIENT_AMOUNT);
+    assert_direct_recipient(
+        &ctx,
+        &recipient_setup.recipient_pda,
+        &recipient_setup.recipient.pubkey(),
+        DEFAULT_RECIPIENT_AMOUNT,
+        DEFAULT_RECIPIENT_AMOUNT,
+        recipient_setup.recipient_bump,
+    );
+}
+
+#[test]
+fn test_native_claim_direct_keeps_vault_rent() {
+    let mut ctx = TestContext::new();
+    let setup = NativeDirectDistributionSetup::create(&mut ctx);
+    let recipient_setup = setup.recipient_setup(&mut ctx, VestingSchedule::Immediate);
+    recipient_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    // Drain the allocation so the vault only holds its rent reserve
+    let vault_rent = ctx.svm.minimum_balance_for_rent_exemption(0);
+    let mut vault = ctx.get_account(&setup.distribution_vault).unwrap();
+    vault.lamports = vault_rent;
+    ctx.svm.set_account(setup.distribution_vault, vault).unwrap();
+
+    let claim_setup = NativeDirectDistributionSetup::claim_setup(&recipient_setup);
+    let error = claim_setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::InsufficientFunds);
+    assert_eq!(lamports(&ctx, &setup.distribution_vault), vault_rent);
+}
+
+#[test]
+fn test_native_close_direct_distribution_returns_lamports() {
+    let mut ctx = TestContext::new();
+    let setup = NativeDirectDistributionSetup::create(&mut ctx);
+    let recipient_setup = setup.recipient_setup(&mut ctx, VestingSchedule::Immediate);
+    recipient_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let authority_before = lamports(&ctx, &setup.authority.pubkey());
+    let vault_before = lamports(&ctx, &setup.distribution_vault);
+    let distribution_before = lamports(&ctx, &setup.distribution_pda);
+
+    let close_setup = setup.close_setup();
+    close_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_account_closed(&ctx, &setup.distribution_vault);
+    assert_account_closed(&ctx, &setup.distribution_pda);
+    assert_eq!(lamports(&ctx, &setup.authority.pubkey()), authority_before + vault_before + distribution_before);
+}
@@ -0,0 +1,128 @@
+use rewards_program_client::accounts::{DirectDistribution, MerkleDistribution};
+
+use crate::fixtures::{
+    AddDirectRecipientSetup, ClaimDirectSetup, ClaimMerkleSetup, CrankClaimDirectSetup, PauseDistributionFixture,
+    PauseDistributionSetup,
+};
+use crate::utils::{
+    assert_rewards_error, test_missing_signer, test_not_writable, test_wrong_current_program, RewardsError, TestContext,
+};
+
+#[test]
+fn test_pause_distribution_missing_authority_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<PauseDistributionFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_pause_distribution_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<PauseDistributionFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_pause_distribution_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<PauseDistributionFixture>(&mut ctx);
+}
+
+#[test]
+fn test_pause_distribution_direct_success() {
+    let mut ctx = TestContext::new();
+    let setup = PauseDistributionSetup::new(&mut ctx);
+    let paused_at = ctx.get_current_timestamp();
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.paused, 1);
+    assert_eq!(distribution.paused_at, paused_at);
+    assert_eq!(distribution.vesting_offset, 0);
+}
+
+#[test]
+fn test_pause_distribution_merkle_success() {
+    let mut ctx = TestContext::new();
+    let setup = PauseDistributionSetup::new_merkle(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let distribution = MerkleDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.paused, 1);
+}
+
+#[test]
+fn test_pause_distribution_already_paused() {
+    let mut ctx = TestContext::new();
+    let setup = PauseDistributionSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    ctx.advance_slot();
+
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::DistributionPaused);
+}
+
+#[test]
+fn test_pause_distribution_unauthorized() {
+    let mut ctx = TestContext::new();
+    let setup = PauseDistributionSetup::new(&mut ctx);
+    let wrong_authority = ctx.create_funded_keypair();
+
+    let test_ix = setup.build_instruction_with(&wrong_authority);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::UnauthorizedAuthority);
+}
+
+#[test]
+fn test_pause_distribution_blocks_claim_direct() {
+    let mut ctx = TestContext::new();
+    let recipient_setup = AddDirectRecipientSetup::new(&mut ctx);
+    let claim = ClaimDirectSetup::from_recipient_setup(&mut ctx, &recipient_setup, true);
+
+    PauseDistributionSetup::pause(&mut ctx, claim.distribution_pda, &recipient_setup.authority);
+
+    let test_ix = claim.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::DistributionPaused);
+    assert_eq!(ctx.get_token_balance(&claim.recipient_token_account), 0);
+}
+
+#[test]
+fn test_pause_distribution_blocks_crank_claim_direct() {
+    let mut ctx = TestContext::new();
+    let recipient_setup = AddDirectRecipientSetup::new(&mut ctx);
+    let claim = ClaimDirectSetup::from_recipient_setup(&mut ctx, &recipient_setup, true);
+    let crank = CrankClaimDirectSetup::from_claim_setup(&mut ctx, claim);
+
+    PauseDistributionSetup::pause(&mut ctx, crank.claim.distribution_pda, &recipient_setup.authority);
+
+    let test_ix = crank.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::DistributionPaused);
+}
+
+#[test]
+fn test_pause_distribution_blocks_claim_merkle() {
+    let mut ctx = TestContext::new();
+    let claim = ClaimMerkleSetup::new(&mut ctx);
+
+    PauseDistributionSetup::pause(&mut ctx, claim.distribution_pda, &claim.authority);
+
+    let test_ix = claim.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::DistributionPaused);
+    assert_eq!(ctx.get_token_balance(&claim.claimant_token_account), 0);
+}
@@ -0,0 +1,92 @@
+use rewards_program_client::accounts::{DirectDistribution, MerkleDistribution};
+use solana_sdk::signature::Signer;
+
+use crate::fixtures::{ProposeAuthorityFixture, ProposeAuthoritySetup};
+use crate::utils::{
+    assert_rewards_error, test_missing_signer, test_not_writable, test_truncated_data, RewardsError, TestContext,
+};
+
+#[test]
+fn test_propose_authority_missing_authority_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<ProposeAuthorityFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_propose_authority_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ProposeAuthorityFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_propose_authority_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<ProposeAuthorityFixture>(&mut ctx);
+}
+
+#[test]
+fn test_propose_authority_direct_success() {
+    let mut ctx = TestContext::new();
+    let setup = ProposeAuthoritySetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.authority, setup.authority.pubkey());
+    assert_eq!(distribution.pending_authority, setup.new_authority.pubkey());
+}
+
+#[test]
+fn test_propose_authority_merkle_success() {
+    let mut ctx = TestContext::new();
+    let setup = ProposeAuthoritySetup::new_merkle(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let distribution = MerkleDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.authority, setup.authority.pubkey());
+    assert_eq!(distribution.pending_authority, setup.new_authority.pubkey());
+}
+
+#[test]
+fn test_propose_authority_unauthorized() {
+    let mut ctx = TestContext::new();
+    let setup = ProposeAuthoritySetup::new(&mut ctx);
+    let wrong_authority = ctx.create_funded_keypair();
+
+    let test_ix = setup.build_instruction_with_authority(&wrong_authority);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::UnauthorizedAuthority);
+}
+
+#[test]
+fn test_propose_authority_default_address_fails() {
+    let mut ctx = TestContext::new();
+    let setup = ProposeAuthoritySetup::new(&mut ctx);
+
+    // Zero out the new_authority bytes (after the discriminator)
+    let test_ix = setup.build_instruction(&ctx).with_data_len(1).with_data_len(33);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::InvalidNewAuthority);
+}
+
+#[test]
+fn test_propose_authority_replaces_pending() {
+    let mut ctx = TestContext::new();
+    let mut setup = ProposeAuthoritySetup::new(&mut ctx);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    setup.new_authority = ctx.create_funded_keypair();
+    ctx.advance_slot();
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.pending_authority, setup.new_authority.pubkey());
+}
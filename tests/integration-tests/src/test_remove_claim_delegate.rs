@@ -0,0 +1,55 @@
+use solana_sdk::{instruction::InstructionError, signature::Signer};
+
+use crate::fixtures::{RemoveClaimDelegateFixture, RemoveClaimDelegateSetup};
+use crate::utils::{
+    assert_account_closed, assert_instruction_error, test_missing_signer, test_not_writable,
+    test_wrong_current_program, TestContext,
+};
+
+#[test]
+fn test_remove_claim_delegate_missing_recipient_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<RemoveClaimDelegateFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_remove_claim_delegate_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<RemoveClaimDelegateFixture>(&mut ctx, 2);
+}
+
+#[test]
+fn test_remove_claim_delegate_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<RemoveClaimDelegateFixture>(&mut ctx);
+}
+
+#[test]
+fn test_remove_claim_delegate_success() {
+    let mut ctx = TestContext::new();
+    let setup = RemoveClaimDelegateSetup::new(&mut ctx);
+
+    let delegate_rent = ctx.get_account(&setup.claim_delegate_pda).unwrap().lamports;
+    let recipient_balance_before = ctx.get_account(&setup.recipient.pubkey()).unwrap().lamports;
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_account_closed(&ctx, &setup.claim_delegate_pda);
+
+    // The recipient pays no transaction fee (the context payer does) and receives the rent
+    let recipient_balance_after = ctx.get_account(&setup.recipient.pubkey()).unwrap().lamports;
+    assert_eq!(recipient_balance_after, recipient_balance_before + delegate_rent);
+}
+
+#[test]
+fn test_remove_claim_delegate_wrong_recipient_fails() {
+    let mut ctx = TestContext::new();
+    let setup = RemoveClaimDelegateSetup::new(&mut ctx);
+    let wrong_recipient = ctx.create_funded_keypair();
+
+    let test_ix = setup.build_instruction_with_recipient(&wrong_recipient);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
@@ -1,4 +1,4 @@
-use rewards_program_client::accounts::DirectDistribution;
+use rewards_program_client::accounts::{DirectDistribution, DirectRecipient};
 use rewards_program_client::types::{RevokeMode, VestingSchedule};
 use solana_sdk::signature::Signer;
 
@@ -151,8 +151,8 @@ fn test_revoke_invalid_mode() {
     // Build a valid instruction then patch the data byte to an invalid mode
     let mut test_ix = setup.build_instruction(&ctx, RevokeMode::NonVested);
     // Instruction data layout: [discriminator(1), revoke_mode(1)]
-    // Patch revoke_mode byte to invalid value 2
-    test_ix.instruction.data[1] = 2;
+    // Patch revoke_mode byte to invalid value 3
+    test_ix.instruction.data[1] = 3;
     let error = test_ix.send_expect_error(&mut ctx);
     assert_rewards_error(error, RewardsError::InvalidRevokeMode);
 }
@@ -467,3 +467,117 @@ fn test_revoke_full_rejected_when_revocable_0() {
     let error = revoke_ix.send_expect_error(&mut ctx);
     assert_rewards_error(error, RewardsError::DistributionNotRevocable);
 }
+
+#[test]
+fn test_revoke_freeze_rejected_when_freeze_bit_unset() {
+    let mut ctx = TestContext::new();
+    let setup = RevokeDirectRecipientSetup::builder(&mut ctx).revocable(3).build();
+
+    let revoke_ix = setup.build_instruction(&ctx, RevokeMode::Freeze);
+    let error = revoke_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::DistributionNotRevocable);
+}
+
+// ── Freeze ────────────────────────────────────────────────────────
+
+#[test]
+fn test_revoke_freeze_at_midpoint() {
+    let mut ctx = TestContext::new();
+    let setup = RevokeDirectRecipientSetup::builder(&mut ctx).revocable(4).build();
+
+    let midpoint = setup.start_ts + (setup.end_ts - setup.start_ts) / 2;
+    ctx.warp_to_timestamp(midpoint);
+
+    let recipient_balance_before = ctx.get_token_balance(&setup.recipient_token_account);
+    let authority_balance_before = ctx.get_token_balance(&setup.authority_token_account);
+
+    let revoke_ix = setup.build_instruction(&ctx, RevokeMode::Freeze);
+    revoke_ix.send_expect_success(&mut ctx);
+
+    let expected_vested = expected_linear_unlock(setup.amount, setup.start_ts, setup.end_ts, midpoint);
+    let expected_unvested = setup.amount - expected_vested;
+
+    assert_eq!(ctx.get_token_balance(&setup.recipient_token_account), recipient_balance_before);
+    assert_eq!(ctx.get_token_balance(&setup.authority_token_account), authority_balance_before + expected_unvested);
+
+    let recipient_account = ctx.get_account(&setup.recipient_pda).expect("Frozen recipient should stay open");
+    let recipient = DirectRecipient::from_bytes(&recipient_account.data).unwrap();
+    assert_eq!(recipient.revoked_at, midpoint);
+    assert_eq!(recipient.total_amount, setup.amount);
+
+    let dist_account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let dist = DirectDistribution::from_bytes(&dist_account.data).unwrap();
+    assert_eq!(dist.total_allocated, setup.amount - expected_unvested);
+    assert_eq!(dist.total_claimed, 0);
+}
+
+#[test]
+fn test_revoke_freeze_recipient_claims_vested_later() {
+    let mut ctx = TestContext::new();
+    let setup = RevokeDirectRecipientSetup::builder(&mut ctx).revocable(4).build();
+
+    let midpoint = setup.start_ts + (setup.end_ts - setup.start_ts) / 2;
+    ctx.warp_to_timestamp(midpoint);
+    setup.build_instruction(&ctx, RevokeMode::Freeze).send_expect_success(&mut ctx);
+
+    // Vesting stopped at the freeze, so waiting past the schedule end adds nothing
+    ctx.warp_to_timestamp(setup.end_ts + 1);
+    let recipient_balance_before = ctx.get_token_balance(&setup.recipient_token_account);
+    setup.claim_setup().build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let expected_vested = expected_linear_unlock(setup.amount, setup.start_ts, setup.end_ts, midpoint);
+    assert_eq!(ctx.get_token_balance(&setup.recipient_token_account), recipient_balance_before + expected_vested);
+
+    setup.close_setup().build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert_account_closed(&ctx, &setup.recipient_pda);
+}
+
+#[test]
+fn test_revoke_freeze_close_before_claiming_fails() {
+    let mut ctx = TestContext::new();
+    let setup = RevokeDirectRecipientSetup::builder(&mut ctx).revocable(4).build();
+
+    let midpoint = setup.start_ts + (setup.end_ts - setup.start_ts) / 2;
+    ctx.warp_to_timestamp(midpoint);
+    setup.build_instruction(&ctx, RevokeMode::Freeze).send_expect_success(&mut ctx);
+
+    let error = setup.close_setup().build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::ClaimNotFullyVested);
+}
+
+#[test]
+fn test_revoke_freeze_twice_fails() {
+    let mut ctx = TestContext::new();
+    let setup = RevokeDirectRecipientSetup::builder(&mut ctx).revocable(4).build();
+
+    let midpoint = setup.start_ts + (setup.end_ts - setup.start_ts) / 2;
+    ctx.warp_to_timestamp(midpoint);
+    setup.build_instruction(&ctx, RevokeMode::Freeze).send_expect_success(&mut ctx);
+
+    ctx.warp_to_timestamp(setup.end_ts);
+    let error = setup.build_instruction(&ctx, RevokeMode::Freeze).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::ClaimantAlreadyRevoked);
+}
+
+#[test]
+fn test_revoke_full_after_freeze_returns_vested() {
+    let mut ctx = TestContext::new();
+    let setup = RevokeDirectRecipientSetup::builder(&mut ctx).revocable(7).build();
+
+    let midpoint = setup.start_ts + (setup.end_ts - setup.start_ts) / 2;
+    ctx.warp_to_timestamp(midpoint);
+    setup.build_instruction(&ctx, RevokeMode::Freeze).send_expect_success(&mut ctx);
+
+    ctx.warp_to_timestamp(setup.end_ts);
+    let authority_balance_before = ctx.get_token_balance(&setup.authority_token_account);
+    setup.build_instruction(&ctx, RevokeMode::Full).send_expect_success(&mut ctx);
+
+    // Only the vested part is left; the unvested part went back at the freeze
+    let expected_vested = expected_linear_unlock(setup.amount, setup.start_ts, setup.end_ts, midpoint);
+    assert_eq!(ctx.get_token_balance(&setup.authority_token_account), authority_balance_before + expected_vested);
+    assert_account_closed(&ctx, &setup.recipient_pda);
+
+    let dist_account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let dist = DirectDistribution::from_bytes(&dist_account.data).unwrap();
+    assert_eq!(dist.total_allocated, 0);
+}
@@ -92,9 +92,19 @@ fn test_revoke_merkle_invalid_mode() {
     let setup = RevokeMerkleClaimSetup::new(&mut ctx);
 
     let mut revoke_ix = setup.build_instruction(&ctx, RevokeMode::NonVested);
-    // Patch revoke_mode byte to invalid value 2
+    // Patch revoke_mode byte to invalid value 3
     // Data layout: [discriminator(1), revoke_mode(1), ...]
-    revoke_ix.instruction.data[1] = 2;
+    revoke_ix.instruction.data[1] = 3;
+    let error = revoke_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidRevokeMode);
+}
+
+#[test]
+fn test_revoke_merkle_freeze_rejected() {
+    let mut ctx = TestContext::new();
+    let setup = RevokeMerkleClaimSetup::builder(&mut ctx).revocable(7).build();
+
+    let revoke_ix = setup.build_instruction(&ctx, RevokeMode::Freeze);
     let error = revoke_ix.send_expect_error(&mut ctx);
     assert_rewards_error(error, RewardsError::InvalidRevokeMode);
 }
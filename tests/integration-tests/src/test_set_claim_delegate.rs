@@ -0,0 +1,108 @@
+use rewards_program_client::accounts::ClaimDelegate;
+use solana_sdk::signature::Signer;
+
+use crate::fixtures::{SetClaimDelegateFixture, SetClaimDelegateSetup};
+use crate::utils::{
+    assert_rewards_error, find_claim_delegate_pda, test_missing_signer, test_not_writable, test_truncated_data,
+    test_wrong_current_program, test_wrong_system_program, RewardsError, TestContext,
+};
+
+#[test]
+fn test_set_claim_delegate_missing_recipient_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<SetClaimDelegateFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_set_claim_delegate_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<SetClaimDelegateFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_set_claim_delegate_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<SetClaimDelegateFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_claim_delegate_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<SetClaimDelegateFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_claim_delegate_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<SetClaimDelegateFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_claim_delegate_direct_success() {
+    let mut ctx = TestContext::new();
+    let setup = SetClaimDelegateSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let (_, expected_bump) = find_claim_delegate_pda(&setup.distribution_pda, &setup.recipient.pubkey());
+    let account = ctx.get_account(&setup.claim_delegate_pda).unwrap();
+    let claim_delegate = ClaimDelegate::from_bytes(&account.data).unwrap();
+    assert_eq!(claim_delegate.bump, expected_bump);
+    assert_eq!(claim_delegate.delegate, setup.delegate.pubkey());
+}
+
+#[test]
+fn test_set_claim_delegate_merkle_success() {
+    let mut ctx = TestContext::new();
+    let setup = SetClaimDelegateSetup::new_merkle(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.claim_delegate_pda).unwrap();
+    let claim_delegate = ClaimDelegate::from_bytes(&account.data).unwrap();
+    assert_eq!(claim_delegate.delegate, setup.delegate.pubkey());
+}
+
+#[test]
+fn test_set_claim_delegate_replaces_existing() {
+    let mut ctx = TestContext::new();
+    let setup = SetClaimDelegateSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    ctx.advance_slot();
+
+    let new_delegate = ctx.create_funded_keypair();
+    let test_ix = setup.build_instruction_with_delegate(&ctx, new_delegate.pubkey());
+    test_ix.send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.claim_delegate_pda).unwrap();
+    let claim_delegate = ClaimDelegate::from_bytes(&account.data).unwrap();
+    assert_eq!(claim_delegate.delegate, new_delegate.pubkey());
+}
+
+#[test]
+fn test_set_claim_delegate_default_address_fails() {
+    let mut ctx = TestContext::new();
+    let setup = SetClaimDelegateSetup::new(&mut ctx);
+
+    // Zero out the delegate bytes (after the discriminator)
+    let test_ix = setup.build_instruction(&ctx).with_data_len(1).with_data_len(33);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::InvalidClaimDelegate);
+}
+
+#[test]
+fn test_set_claim_delegate_self_fails() {
+    let mut ctx = TestContext::new();
+    let setup = SetClaimDelegateSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction_with_delegate(&ctx, setup.recipient.pubkey());
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::InvalidClaimDelegate);
+}
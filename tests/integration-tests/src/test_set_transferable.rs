@@ -0,0 +1,97 @@
+use rewards_program_client::accounts::{DirectDistribution, MerkleDistribution};
+use solana_sdk::instruction::InstructionError;
+
+use crate::fixtures::{SetTransferableFixture, SetTransferableSetup};
+use crate::utils::{
+    assert_instruction_error, assert_rewards_error, test_missing_signer, test_not_writable, test_truncated_data,
+    RewardsError, TestContext,
+};
+
+#[test]
+fn test_set_transferable_missing_authority_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<SetTransferableFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_set_transferable_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<SetTransferableFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_set_transferable_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<SetTransferableFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_transferable_direct_success() {
+    let mut ctx = TestContext::new();
+    let setup = SetTransferableSetup::new(&mut ctx);
+
+    let account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.transferable, 0);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.transferable, 1);
+}
+
+#[test]
+fn test_set_transferable_merkle_success() {
+    let mut ctx = TestContext::new();
+    let setup = SetTransferableSetup::new_merkle(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let distribution = MerkleDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.transferable, 1);
+}
+
+#[test]
+fn test_set_transferable_disable() {
+    let mut ctx = TestContext::new();
+    let setup = SetTransferableSetup::new(&mut ctx);
+
+    let enable_ix = setup.build_instruction(&ctx);
+    enable_ix.send_expect_success(&mut ctx);
+
+    ctx.advance_slot();
+
+    let disable_ix = setup.build_instruction_with(&setup.authority, 0);
+    disable_ix.send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.transferable, 0);
+}
+
+#[test]
+fn test_set_transferable_unauthorized() {
+    let mut ctx = TestContext::new();
+    let setup = SetTransferableSetup::new(&mut ctx);
+    let wrong_authority = ctx.create_funded_keypair();
+
+    let test_ix = setup.build_instruction_with(&wrong_authority, 1);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::UnauthorizedAuthority);
+}
+
+#[test]
+fn test_set_transferable_non_boolean_value_fails() {
+    let mut ctx = TestContext::new();
+    let setup = SetTransferableSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction_with(&setup.authority, 2);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
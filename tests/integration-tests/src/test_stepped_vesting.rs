@@ -0,0 +1,227 @@
+use rewards_program_client::types::VestingSchedule;
+use solana_sdk::signature::Signer;
+
+use crate::fixtures::{AddDirectRecipientSetup, ClaimDirectSetup, ClaimMerkleSetup};
+use crate::utils::{assert_direct_recipient, assert_rewards_error, RewardsError, TestContext};
+
+const PERIOD_SECS: u64 = 1000;
+const NUM_PERIODS: u32 = 4;
+
+// ── Stepped (type=4) via Direct Distribution ──
+
+#[test]
+fn test_stepped_direct_nothing_before_first_step() {
+    let mut ctx = TestContext::new();
+    let start_ts = ctx.get_current_timestamp();
+
+    let setup = AddDirectRecipientSetup::builder(&mut ctx)
+        .schedule(VestingSchedule::Stepped { start_ts, period_secs: PERIOD_SECS, num_periods: NUM_PERIODS })
+        .build();
+
+    let claim_setup = ClaimDirectSetup::from_recipient_setup(&mut ctx, &setup, false);
+
+    ctx.warp_to_timestamp(start_ts + PERIOD_SECS as i64 - 1);
+    let test_ix = claim_setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::NothingToClaim);
+}
+
+#[test]
+fn test_stepped_direct_unlocks_in_discrete_steps() {
+    let mut ctx = TestContext::new();
+    let start_ts = ctx.get_current_timestamp();
+
+    let setup = AddDirectRecipientSetup::builder(&mut ctx)
+        .schedule(VestingSchedule::Stepped { start_ts, period_secs: PERIOD_SECS, num_periods: NUM_PERIODS })
+        .build();
+
+    let claim_setup = ClaimDirectSetup::from_recipient_setup(&mut ctx, &setup, false);
+
+    ctx.warp_to_timestamp(start_ts + PERIOD_SECS as i64);
+    let test_ix = claim_setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&claim_setup.recipient_token_account);
+    assert_eq!(balance, setup.amount / 4);
+
+    // Halfway into the second period nothing new has unlocked
+    ctx.warp_to_timestamp(start_ts + PERIOD_SECS as i64 * 3 / 2);
+    let test_ix = claim_setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::NothingToClaim);
+
+    ctx.warp_to_timestamp(start_ts + PERIOD_SECS as i64 * 2);
+    let test_ix = claim_setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&claim_setup.recipient_token_account);
+    assert_eq!(balance, setup.amount / 2);
+}
+
+#[test]
+fn test_stepped_direct_full_at_last_step() {
+    let mut ctx = TestContext::new();
+    let start_ts = ctx.get_current_timestamp();
+
+    let setup = AddDirectRecipientSetup::builder(&mut ctx)
+        .schedule(VestingSchedule::Stepped { start_ts, period_secs: PERIOD_SECS, num_periods: NUM_PERIODS })
+        .build();
+
+    let claim_setup = ClaimDirectSetup::from_recipient_setup(&mut ctx, &setup, false);
+
+    ctx.warp_to_timestamp(start_ts + PERIOD_SECS as i64 * NUM_PERIODS as i64);
+    let test_ix = claim_setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&claim_setup.recipient_token_account);
+    assert_eq!(balance, setup.amount);
+}
+
+// ── CliffStepped (type=5) via Direct Distribution ──
+
+#[test]
+fn test_cliff_stepped_direct_accumulated_at_cliff() {
+    let mut ctx = TestContext::new();
+    let start_ts = ctx.get_current_timestamp();
+    let cliff_ts = start_ts + PERIOD_SECS as i64 * 2;
+
+    let setup = AddDirectRecipientSetup::builder(&mut ctx)
+        .schedule(VestingSchedule::CliffStepped {
+            start_ts,
+            cliff_ts,
+            period_secs: PERIOD_SECS,
+            num_periods: NUM_PERIODS,
+        })
+        .build();
+
+    let claim_setup = ClaimDirectSetup::from_recipient_setup(&mut ctx, &setup, false);
+
+    ctx.warp_to_timestamp(cliff_ts - 1);
+    let test_ix = claim_setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::NothingToClaim);
+
+    ctx.warp_to_timestamp(cliff_ts);
+    let test_ix = claim_setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&claim_setup.recipient_token_account);
+    assert_eq!(balance, setup.amount / 2);
+}
+
+// ── Stepped via Merkle Distribution ──
+
+#[test]
+fn test_stepped_merkle_unlocks_first_step() {
+    let mut ctx = TestContext::new();
+    let start_ts = ctx.get_current_timestamp();
+
+    let setup = ClaimMerkleSetup::builder(&mut ctx)
+        .schedule(VestingSchedule::Stepped { start_ts, period_secs: PERIOD_SECS, num_periods: NUM_PERIODS })
+        .warp_to_end(false)
+        .build();
+
+    ctx.warp_to_timestamp(start_ts + PERIOD_SECS as i64);
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&setup.claimant_token_account);
+    assert_eq!(balance, setup.total_amount / 4);
+}
+
+#[test]
+fn test_cliff_stepped_merkle_full_at_end() {
+    let mut ctx = TestContext::new();
+    let start_ts = ctx.get_current_timestamp();
+    let cliff_ts = start_ts + PERIOD_SECS as i64;
+
+    let setup = ClaimMerkleSetup::builder(&mut ctx)
+        .schedule(VestingSchedule::CliffStepped {
+            start_ts,
+            cliff_ts,
+            period_secs: PERIOD_SECS,
+            num_periods: NUM_PERIODS,
+        })
+        .warp_to_end(false)
+        .build();
+
+    ctx.warp_to_timestamp(start_ts + PERIOD_SECS as i64 * NUM_PERIODS as i64);
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&setup.claimant_token_account);
+    assert_eq!(balance, setup.total_amount);
+}
+
+// ── Validation Tests ──
+
+#[test]
+fn test_stepped_zero_period_invalid() {
+    let mut ctx = TestContext::new();
+    let start_ts = ctx.get_current_timestamp();
+
+    let setup = AddDirectRecipientSetup::builder(&mut ctx)
+        .schedule(VestingSchedule::Stepped { start_ts, period_secs: 0, num_periods: NUM_PERIODS })
+        .build();
+
+    let instruction = setup.build_instruction(&ctx);
+    let error = instruction.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidTimeWindow);
+}
+
+#[test]
+fn test_stepped_zero_num_periods_invalid() {
+    let mut ctx = TestContext::new();
+    let start_ts = ctx.get_current_timestamp();
+
+    let setup = AddDirectRecipientSetup::builder(&mut ctx)
+        .schedule(VestingSchedule::Stepped { start_ts, period_secs: PERIOD_SECS, num_periods: 0 })
+        .build();
+
+    let instruction = setup.build_instruction(&ctx);
+    let error = instruction.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidTimeWindow);
+}
+
+#[test]
+fn test_cliff_stepped_invalid_cliff_after_end() {
+    let mut ctx = TestContext::new();
+    let start_ts = ctx.get_current_timestamp();
+
+    let setup = AddDirectRecipientSetup::builder(&mut ctx)
+        .schedule(VestingSchedule::CliffStepped {
+            start_ts,
+            cliff_ts: start_ts + PERIOD_SECS as i64 * NUM_PERIODS as i64 + 1, // cliff after last step
+            period_secs: PERIOD_SECS,
+            num_periods: NUM_PERIODS,
+        })
+        .build();
+
+    let instruction = setup.build_instruction(&ctx);
+    let error = instruction.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidCliffTimestamp);
+}
+
+// ── Account State ──
+
+#[test]
+fn test_stepped_direct_account_state() {
+    let mut ctx = TestContext::new();
+    let start_ts = ctx.get_current_timestamp();
+
+    let setup = AddDirectRecipientSetup::builder(&mut ctx)
+        .schedule(VestingSchedule::Stepped { start_ts, period_secs: PERIOD_SECS, num_periods: NUM_PERIODS })
+        .build();
+
+    let instruction = setup.build_instruction(&ctx);
+    instruction.send_expect_success(&mut ctx);
+
+    assert_direct_recipient(
+        &ctx,
+        &setup.recipient_pda,
+        &setup.recipient.pubkey(),
+        setup.amount,
+        0,
+        setup.recipient_bump,
+    );
+}
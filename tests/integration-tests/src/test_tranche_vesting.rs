@@ -0,0 +1,219 @@
+use rewards_program_client::types::{TrancheInterpolation, TranchePoint, VestingSchedule};
+use solana_sdk::signature::Signer;
+
+use crate::fixtures::{AddDirectRecipientSetup, ClaimDirectSetup, ClaimMerkleSetup};
+use crate::utils::{assert_direct_recipient, assert_rewards_error, RewardsError, TestContext};
+
+/// 10% at TGE, 25% at +3000, 100% at +9000
+fn tge_tranches(tge_ts: i64, interpolation: TrancheInterpolation) -> VestingSchedule {
+    VestingSchedule::Tranches {
+        interpolation,
+        points: vec![
+            TranchePoint { timestamp: tge_ts, cumulative_bps: 1_000 },
+            TranchePoint { timestamp: tge_ts + 3000, cumulative_bps: 2_500 },
+            TranchePoint { timestamp: tge_ts + 9000, cumulative_bps: 10_000 },
+        ],
+    }
+}
+
+// ── Tranches (type=6) via Direct Distribution ──
+
+#[test]
+fn test_tranches_direct_nothing_before_tge() {
+    let mut ctx = TestContext::new();
+    let tge_ts = ctx.get_current_timestamp() + 1000;
+
+    let setup =
+        AddDirectRecipientSetup::builder(&mut ctx).schedule(tge_tranches(tge_ts, TrancheInterpolation::Step)).build();
+
+    let claim_setup = ClaimDirectSetup::from_recipient_setup(&mut ctx, &setup, false);
+
+    let test_ix = claim_setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::NothingToClaim);
+}
+
+#[test]
+fn test_tranches_direct_step_unlocks_at_points() {
+    let mut ctx = TestContext::new();
+    let tge_ts = ctx.get_current_timestamp() + 1000;
+
+    let setup =
+        AddDirectRecipientSetup::builder(&mut ctx).schedule(tge_tranches(tge_ts, TrancheInterpolation::Step)).build();
+
+    let claim_setup = ClaimDirectSetup::from_recipient_setup(&mut ctx, &setup, false);
+
+    ctx.warp_to_timestamp(tge_ts);
+    let test_ix = claim_setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&claim_setup.recipient_token_account);
+    assert_eq!(balance, setup.amount / 10);
+
+    // Between points nothing new unlocks in step mode
+    ctx.warp_to_timestamp(tge_ts + 2000);
+    let test_ix = claim_setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::NothingToClaim);
+
+    ctx.warp_to_timestamp(tge_ts + 3000);
+    let test_ix = claim_setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&claim_setup.recipient_token_account);
+    assert_eq!(balance, setup.amount / 4);
+}
+
+#[test]
+fn test_tranches_direct_linear_interpolates_between_points() {
+    let mut ctx = TestContext::new();
+    let tge_ts = ctx.get_current_timestamp() + 1000;
+
+    let setup =
+        AddDirectRecipientSetup::builder(&mut ctx).schedule(tge_tranches(tge_ts, TrancheInterpolation::Linear)).build();
+
+    let claim_setup = ClaimDirectSetup::from_recipient_setup(&mut ctx, &setup, false);
+
+    // Halfway between 25% and 100%
+    ctx.warp_to_timestamp(tge_ts + 6000);
+    let test_ix = claim_setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&claim_setup.recipient_token_account);
+    let expected = setup.amount * 6_250 / 10_000;
+    assert!(balance >= expected - 1 && balance <= expected + 1, "Expected ~{expected}, got {balance}");
+}
+
+#[test]
+fn test_tranches_direct_full_at_last_point() {
+    let mut ctx = TestContext::new();
+    let tge_ts = ctx.get_current_timestamp() + 1000;
+
+    let setup =
+        AddDirectRecipientSetup::builder(&mut ctx).schedule(tge_tranches(tge_ts, TrancheInterpolation::Linear)).build();
+
+    let claim_setup = ClaimDirectSetup::from_recipient_setup(&mut ctx, &setup, false);
+
+    ctx.warp_to_timestamp(tge_ts + 9000);
+    let test_ix = claim_setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&claim_setup.recipient_token_account);
+    assert_eq!(balance, setup.amount);
+}
+
+// ── Tranches via Merkle Distribution ──
+
+#[test]
+fn test_tranches_merkle_step_unlocks_tge() {
+    let mut ctx = TestContext::new();
+    let tge_ts = ctx.get_current_timestamp() + 1000;
+
+    let setup = ClaimMerkleSetup::builder(&mut ctx)
+        .schedule(tge_tranches(tge_ts, TrancheInterpolation::Step))
+        .warp_to_end(false)
+        .build();
+
+    ctx.warp_to_timestamp(tge_ts);
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&setup.claimant_token_account);
+    assert_eq!(balance, setup.total_amount / 10);
+}
+
+#[test]
+fn test_tranches_merkle_full_at_last_point() {
+    let mut ctx = TestContext::new();
+    let tge_ts = ctx.get_current_timestamp() + 1000;
+
+    let setup = ClaimMerkleSetup::builder(&mut ctx)
+        .schedule(tge_tranches(tge_ts, TrancheInterpolation::Linear))
+        .warp_to_end(false)
+        .build();
+
+    ctx.warp_to_timestamp(tge_ts + 9000);
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&setup.claimant_token_account);
+    assert_eq!(balance, setup.total_amount);
+}
+
+// ── Validation Tests ──
+
+#[test]
+fn test_tranches_not_ending_at_full_invalid() {
+    let mut ctx = TestContext::new();
+    let tge_ts = ctx.get_current_timestamp();
+
+    let setup = AddDirectRecipientSetup::builder(&mut ctx)
+        .schedule(VestingSchedule::Tranches {
+            interpolation: TrancheInterpolation::Step,
+            points: vec![
+                TranchePoint { timestamp: tge_ts, cumulative_bps: 1_000 },
+                TranchePoint { timestamp: tge_ts + 1000, cumulative_bps: 9_000 }, // never reaches 100%
+            ],
+        })
+        .build();
+
+    let instruction = setup.build_instruction(&ctx);
+    let error = instruction.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidTrancheSchedule);
+}
+
+#[test]
+fn test_tranches_unordered_timestamps_invalid() {
+    let mut ctx = TestContext::new();
+    let tge_ts = ctx.get_current_timestamp();
+
+    let setup = AddDirectRecipientSetup::builder(&mut ctx)
+        .schedule(VestingSchedule::Tranches {
+            interpolation: TrancheInterpolation::Linear,
+            points: vec![
+                TranchePoint { timestamp: tge_ts + 1000, cumulative_bps: 1_000 },
+                TranchePoint { timestamp: tge_ts, cumulative_bps: 10_000 }, // earlier than previous point
+            ],
+        })
+        .build();
+
+    let instruction = setup.build_instruction(&ctx);
+    let error = instruction.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidTrancheSchedule);
+}
+
+#[test]
+fn test_tranches_empty_invalid() {
+    let mut ctx = TestContext::new();
+
+    let setup = AddDirectRecipientSetup::builder(&mut ctx)
+        .schedule(VestingSchedule::Tranches { interpolation: TrancheInterpolation::Step, points: vec![] })
+        .build();
+
+    let instruction = setup.build_instruction(&ctx);
+    let error = instruction.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidTrancheSchedule);
+}
+
+// ── Account State ──
+
+#[test]
+fn test_tranches_direct_account_state() {
+    let mut ctx = TestContext::new();
+    let tge_ts = ctx.get_current_timestamp();
+
+    let setup =
+        AddDirectRecipientSetup::builder(&mut ctx).schedule(tge_tranches(tge_ts, TrancheInterpolation::Step)).build();
+
+    let instruction = setup.build_instruction(&ctx);
+    instruction.send_expect_success(&mut ctx);
+
+    assert_direct_recipient(
+        &ctx,
+        &setup.recipient_pda,
+        &setup.recipient.pubkey(),
+        setup.amount,
+        0,
+        setup.recipient_bump,
+    );
+}
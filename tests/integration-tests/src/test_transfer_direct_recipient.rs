@@ -0,0 +1,202 @@
+use rewards_program_client::accounts::DirectRecipient;
+use solana_sdk::{instruction::InstructionError, signature::Signer};
+
+use crate::fixtures::{TransferDirectRecipientFixture, TransferDirectRecipientSetup};
+use crate::utils::{
+    assert_account_closed, assert_direct_recipient, assert_instruction_error, assert_rewards_error,
+    test_missing_signer, test_not_writable, test_truncated_data, test_wrong_current_program, test_wrong_system_program,
+    RewardsError, TestContext,
+};
+
+#[test]
+fn test_transfer_direct_recipient_missing_recipient_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<TransferDirectRecipientFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_transfer_direct_recipient_recipient_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<TransferDirectRecipientFixture>(&mut ctx, 4);
+}
+
+#[test]
+fn test_transfer_direct_recipient_new_recipient_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<TransferDirectRecipientFixture>(&mut ctx, 5);
+}
+
+#[test]
+fn test_transfer_direct_recipient_original_payer_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<TransferDirectRecipientFixture>(&mut ctx, 6);
+}
+
+#[test]
+fn test_transfer_direct_recipient_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<TransferDirectRecipientFixture>(&mut ctx);
+}
+
+#[test]
+fn test_transfer_direct_recipient_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<TransferDirectRecipientFixture>(&mut ctx);
+}
+
+#[test]
+fn test_transfer_direct_recipient_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<TransferDirectRecipientFixture>(&mut ctx);
+}
+
+#[test]
+fn test_transfer_direct_recipient_success() {
+    let mut ctx = TestContext::new();
+    let setup = TransferDirectRecipientSetup::new(&mut ctx);
+
+    let original_payer_before = ctx.get_account(&setup.original_payer.pubkey()).unwrap().lamports;
+    let recipient_rent = ctx.get_account(&setup.recipient_pda).unwrap().lamports;
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_account_closed(&ctx, &setup.recipient_pda);
+    assert_direct_recipient(
+        &ctx,
+        &setup.new_recipient_pda,
+        &setup.new_recipient.pubkey(),
+        setup.amount,
+        0,
+        setup.new_recipient_bump,
+    );
+
+    let account = ctx.get_account(&setup.new_recipient_pda).unwrap();
+    let new_recipient = DirectRecipient::from_bytes(&account.data).unwrap();
+    assert_eq!(new_recipient.payer, ctx.payer.pubkey());
+
+    let original_payer_after = ctx.get_account(&setup.original_payer.pubkey()).unwrap().lamports;
+    assert_eq!(original_payer_after, original_payer_before + recipient_rent);
+}
+
+#[test]
+fn test_transfer_direct_recipient_carries_claimed_amount() {
+    let mut ctx = TestContext::new();
+    let setup = TransferDirectRecipientSetup::new(&mut ctx);
+
+    // Claim half way through the schedule before transferring
+    ctx.warp_to_timestamp(setup.start_ts + (setup.end_ts - setup.start_ts) / 2);
+    let claim_setup = setup.claim_setup_for(&mut ctx, &setup.recipient);
+    let claim_ix = claim_setup.build_instruction(&ctx);
+    claim_ix.send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.recipient_pda).unwrap();
+    let claimed_before = DirectRecipient::from_bytes(&account.data).unwrap().claimed_amount;
+    assert!(claimed_before > 0);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_direct_recipient(
+        &ctx,
+        &setup.new_recipient_pda,
+        &setup.new_recipient.pubkey(),
+        setup.amount,
+        claimed_before,
+        setup.new_recipient_bump,
+    );
+
+    // The new recipient claims the remainder once fully vested
+    ctx.warp_to_timestamp(setup.end_ts);
+    let new_claim_setup = setup.claim_setup_for(&mut ctx, &setup.new_recipient);
+    let new_claim_ix = new_claim_setup.build_instruction(&ctx);
+    new_claim_ix.send_expect_success(&mut ctx);
+
+    let balance = ctx.get_token_balance(&new_claim_setup.recipient_token_account);
+    assert_eq!(balance, setup.amount - claimed_before);
+}
+
+#[test]
+fn test_transfer_direct_recipient_old_recipient_cannot_claim() {
+    let mut ctx = TestContext::new();
+    let setup = TransferDirectRecipientSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    ctx.warp_to_timestamp(setup.end_ts);
+    let claim_setup = setup.claim_setup_for(&mut ctx, &setup.recipient);
+    let claim_ix = claim_setup.build_instruction(&ctx);
+    let error = claim_ix.send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+#[test]
+fn test_transfer_direct_recipient_token_2022() {
+    let mut ctx = TestContext::new();
+    let setup = TransferDirectRecipientSetup::new_token_2022(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_account_closed(&ctx, &setup.recipient_pda);
+    assert_direct_recipient(
+        &ctx,
+        &setup.new_recipient_pda,
+        &setup.new_recipient.pubkey(),
+        setup.amount,
+        0,
+        setup.new_recipient_bump,
+    );
+}
+
+#[test]
+fn test_transfer_direct_recipient_transfers_disabled() {
+    let mut ctx = TestContext::new();
+    let setup = TransferDirectRecipientSetup::builder(&mut ctx).transferable(false).build();
+
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::RecipientTransfersDisabled);
+}
+
+#[test]
+fn test_transfer_direct_recipient_to_self_fails() {
+    let mut ctx = TestContext::new();
+    let setup = TransferDirectRecipientSetup::new(&mut ctx);
+
+    let test_ix =
+        setup.build_instruction_with(&ctx, &setup.recipient, setup.recipient.pubkey(), setup.original_payer.pubkey());
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::InvalidNewRecipient);
+}
+
+#[test]
+fn test_transfer_direct_recipient_wrong_original_payer() {
+    let mut ctx = TestContext::new();
+    let setup = TransferDirectRecipientSetup::new(&mut ctx);
+    let wrong_payer = ctx.create_funded_keypair();
+
+    let test_ix =
+        setup.build_instruction_with(&ctx, &setup.recipient, setup.new_recipient.pubkey(), wrong_payer.pubkey());
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
+
+#[test]
+fn test_transfer_direct_recipient_wrong_recipient() {
+    let mut ctx = TestContext::new();
+    let setup = TransferDirectRecipientSetup::new(&mut ctx);
+    let stranger = ctx.create_funded_keypair();
+
+    // A wallet without a position in the distribution has no recipient account to move
+    let test_ix =
+        setup.build_instruction_with(&ctx, &stranger, setup.new_recipient.pubkey(), setup.original_payer.pubkey());
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
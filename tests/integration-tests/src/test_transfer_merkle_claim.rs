@@ -0,0 +1,197 @@
+use rewards_program_client::accounts::MerkleRedirect;
+use solana_sdk::signature::Signer;
+
+use crate::fixtures::{ClaimMerkleSetup, TransferMerkleClaimFixture, TransferMerkleClaimSetup};
+use crate::utils::{
+    assert_merkle_claim, assert_rewards_error, expected_linear_unlock, find_merkle_redirect_pda, test_missing_signer,
+    test_not_writable, test_wrong_current_program, test_wrong_system_program, RewardsError, TestContext,
+};
+
+#[test]
+fn test_transfer_merkle_claim_missing_owner_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<TransferMerkleClaimFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_transfer_merkle_claim_redirect_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<TransferMerkleClaimFixture>(&mut ctx, 5);
+}
+
+#[test]
+fn test_transfer_merkle_claim_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<TransferMerkleClaimFixture>(&mut ctx);
+}
+
+#[test]
+fn test_transfer_merkle_claim_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<TransferMerkleClaimFixture>(&mut ctx);
+}
+
+#[test]
+fn test_transfer_merkle_claim_success() {
+    let mut ctx = TestContext::new();
+    let setup = TransferMerkleClaimSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let (_, expected_bump) = find_merkle_redirect_pda(&setup.claim.distribution_pda, &setup.claim.claimant.pubkey());
+    let account = ctx.get_account(&setup.claim.redirect_pda).unwrap();
+    let redirect = MerkleRedirect::from_bytes(&account.data).unwrap();
+    assert_eq!(redirect.bump, expected_bump);
+    assert_eq!(redirect.new_claimant, setup.new_owner.pubkey());
+}
+
+#[test]
+fn test_transfer_merkle_claim_blocks_original_claimant() {
+    let mut ctx = TestContext::new();
+    let setup = TransferMerkleClaimSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let claim_ix = setup.claim.build_instruction(&ctx);
+    let error = claim_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::ClaimantRedirected);
+}
+
+#[test]
+fn test_transfer_merkle_claim_new_owner_claims_to_any_destination() {
+    let mut ctx = TestContext::new();
+    let setup = TransferMerkleClaimSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let other_wallet = ctx.create_funded_keypair();
+    let destination = ctx.create_ata_for_program(&other_wallet.pubkey(), &setup.claim.mint, &setup.claim.token_program);
+
+    let crank_ix = setup.build_crank_instruction(&setup.new_owner, &setup.new_owner.pubkey(), destination);
+    crank_ix.send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&destination), setup.claim.total_amount);
+    assert_merkle_claim(&ctx, &setup.claim.claim_pda, setup.claim.total_amount, setup.claim.claim_bump);
+}
+
+#[test]
+fn test_transfer_merkle_claim_permissionless_crank_pays_new_owner() {
+    let mut ctx = TestContext::new();
+    let setup = TransferMerkleClaimSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let cranker = ctx.create_funded_keypair();
+
+    // The original claimant's ATA is no longer an allowed destination
+    let crank_ix =
+        setup.build_crank_instruction(&cranker, &setup.new_owner.pubkey(), setup.claim.claimant_token_account);
+    let error = crank_ix.send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidClaimDestination);
+
+    let new_owner_ata =
+        ctx.create_ata_for_program(&setup.new_owner.pubkey(), &setup.claim.mint, &setup.claim.token_program);
+    let crank_ix = setup.build_crank_instruction(&cranker, &setup.new_owner.pubkey(), new_owner_ata);
+    crank_ix.send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&new_owner_ata), setup.claim.total_amount);
+}
+
+#[test]
+fn test_transfer_merkle_claim_carries_claimed_amount() {
+    let mut ctx = TestContext::new();
+    let claim = ClaimMerkleSetup::builder(&mut ctx).warp_to_end(false).build();
+    let setup = TransferMerkleClaimSetup::from_claim_setup(&mut ctx, claim);
+
+    let start_ts = setup.claim.start_ts();
+    let end_ts = setup.claim.end_ts();
+    let mid_ts = start_ts + (end_ts - start_ts) / 2;
+    ctx.warp_to_timestamp(mid_ts);
+
+    let claim_ix = setup.claim.build_instruction(&ctx);
+    claim_ix.send_expect_success(&mut ctx);
+    let claimed_before = expected_linear_unlock(setup.claim.total_amount, start_ts, end_ts, mid_ts);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    ctx.warp_to_timestamp(end_ts);
+    let new_owner_ata =
+        ctx.create_ata_for_program(&setup.new_owner.pubkey(), &setup.claim.mint, &setup.claim.token_program);
+    let crank_ix = setup.build_crank_instruction(&setup.new_owner, &setup.new_owner.pubkey(), new_owner_ata);
+    crank_ix.send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&new_owner_ata), setup.claim.total_amount - claimed_before);
+    assert_merkle_claim(&ctx, &setup.claim.claim_pda, setup.claim.total_amount, setup.claim.claim_bump);
+}
+
+#[test]
+fn test_transfer_merkle_claim_new_owner_can_transfer_again() {
+    let mut ctx = TestContext::new();
+    let setup = TransferMerkleClaimSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let third_owner = ctx.create_funded_keypair();
+    let retransfer_ix = setup.build_instruction_with(&ctx, &setup.new_owner, third_owner.pubkey());
+    retransfer_ix.send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.claim.redirect_pda).unwrap();
+    let redirect = MerkleRedirect::from_bytes(&account.data).unwrap();
+    assert_eq!(redirect.new_claimant, third_owner.pubkey());
+}
+
+#[test]
+fn test_transfer_merkle_claim_previous_owner_cannot_transfer() {
+    let mut ctx = TestContext::new();
+    let setup = TransferMerkleClaimSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let other_wallet = ctx.create_funded_keypair();
+    let test_ix = setup.build_instruction_with(&ctx, &setup.claim.claimant, other_wallet.pubkey());
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::UnauthorizedRecipient);
+}
+
+#[test]
+fn test_transfer_merkle_claim_non_claimant_cannot_start_transfer() {
+    let mut ctx = TestContext::new();
+    let setup = TransferMerkleClaimSetup::new(&mut ctx);
+    let stranger = ctx.create_funded_keypair();
+
+    let test_ix = setup.build_instruction_with(&ctx, &stranger, setup.new_owner.pubkey());
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::UnauthorizedRecipient);
+}
+
+#[test]
+fn test_transfer_merkle_claim_transfers_disabled() {
+    let mut ctx = TestContext::new();
+    let setup = TransferMerkleClaimSetup::new_non_transferable(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::RecipientTransfersDisabled);
+}
+
+#[test]
+fn test_transfer_merkle_claim_back_to_claimant_fails() {
+    let mut ctx = TestContext::new();
+    let setup = TransferMerkleClaimSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction_with(&ctx, &setup.claim.claimant, setup.claim.claimant.pubkey());
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::InvalidNewRecipient);
+}
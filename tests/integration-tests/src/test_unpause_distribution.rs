@@ -0,0 +1,240 @@
+use rewards_program_client::{
+    accounts::{DirectDistribution, DirectRecipient, MerkleDistribution},
+    types::VestingSchedule,
+};
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signature::Signer;
+
+use crate::fixtures::{
+    AddDirectRecipientSetup, ClaimDirectSetup, ClaimMerkleSetup, CreateDirectDistributionSetup, PauseDistributionSetup,
+    UnpauseDistributionFixture, UnpauseDistributionSetup,
+};
+use crate::utils::{
+    assert_instruction_error, assert_rewards_error, expected_linear_unlock, find_direct_recipient_pda,
+    test_missing_signer, test_not_writable, test_truncated_data, test_wrong_current_program, RewardsError, TestContext,
+};
+
+#[test]
+fn test_unpause_distribution_missing_authority_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<UnpauseDistributionFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_unpause_distribution_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<UnpauseDistributionFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_unpause_distribution_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<UnpauseDistributionFixture>(&mut ctx);
+}
+
+#[test]
+fn test_unpause_distribution_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<UnpauseDistributionFixture>(&mut ctx);
+}
+
+#[test]
+fn test_unpause_distribution_invalid_shift_vesting() {
+    let mut ctx = TestContext::new();
+    let setup = UnpauseDistributionSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction_with(&setup.authority, 2);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_unpause_distribution_not_paused() {
+    let mut ctx = TestContext::new();
+    let pause_setup = PauseDistributionSetup::new(&mut ctx);
+    let setup = UnpauseDistributionSetup::for_distribution(pause_setup.distribution_pda, &pause_setup.authority);
+
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::DistributionNotPaused);
+}
+
+#[test]
+fn test_unpause_distribution_unauthorized() {
+    let mut ctx = TestContext::new();
+    let setup = UnpauseDistributionSetup::new(&mut ctx);
+    let wrong_authority = ctx.create_funded_keypair();
+
+    let test_ix = setup.build_instruction_with(&wrong_authority, 0);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_rewards_error(error, RewardsError::UnauthorizedAuthority);
+}
+
+#[test]
+fn test_unpause_distribution_direct_success() {
+    let mut ctx = TestContext::new();
+    let setup = UnpauseDistributionSetup::new(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.paused, 0);
+    assert_eq!(distribution.vesting_offset, 0);
+}
+
+#[test]
+fn test_unpause_distribution_merkle_success() {
+    let mut ctx = TestContext::new();
+    let setup = UnpauseDistributionSetup::new_merkle(&mut ctx);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let distribution = MerkleDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.paused, 0);
+}
+
+#[test]
+fn test_unpause_distribution_without_shift_vesting_catches_up() {
+    let mut ctx = TestContext::new();
+    let recipient_setup = AddDirectRecipientSetup::new(&mut ctx);
+    let claim = ClaimDirectSetup::from_recipient_setup(&mut ctx, &recipient_setup, false);
+    let quarter = (claim.end_ts - claim.start_ts) / 4;
+
+    ctx.warp_to_timestamp(claim.start_ts + quarter);
+    PauseDistributionSetup::pause(&mut ctx, claim.distribution_pda, &recipient_setup.authority);
+
+    let unpause_ts = claim.start_ts + 2 * quarter;
+    ctx.warp_to_timestamp(unpause_ts);
+    UnpauseDistributionSetup::unpause(&mut ctx, claim.distribution_pda, &recipient_setup.authority, false);
+
+    let test_ix = claim.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let expected = expected_linear_unlock(claim.amount, claim.start_ts, claim.end_ts, unpause_ts);
+    assert_eq!(ctx.get_token_balance(&claim.recipient_token_account), expected);
+}
+
+#[test]
+fn test_unpause_distribution_shift_vesting_excludes_paused_period() {
+    let mut ctx = TestContext::new();
+    let recipient_setup = AddDirectRecipientSetup::new(&mut ctx);
+    let claim = ClaimDirectSetup::from_recipient_setup(&mut ctx, &recipient_setup, false);
+    let quarter = (claim.end_ts - claim.start_ts) / 4;
+
+    let paused_at = claim.start_ts + quarter;
+    ctx.warp_to_timestamp(paused_at);
+    PauseDistributionSetup::pause(&mut ctx, claim.distribution_pda, &recipient_setup.authority);
+
+    let unpause_ts = claim.start_ts + 2 * quarter;
+    ctx.warp_to_timestamp(unpause_ts);
+    UnpauseDistributionSetup::unpause(&mut ctx, claim.distribution_pda, &recipient_setup.authority, true);
+
+    let account = ctx.get_account(&claim.distribution_pda).unwrap();
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.vesting_offset, unpause_ts - paused_at);
+
+    let test_ix = claim.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let expected = expected_linear_unlock(claim.amount, claim.start_ts, claim.end_ts, paused_at);
+    assert_eq!(ctx.get_token_balance(&claim.recipient_token_account), expected);
+}
+
+#[test]
+fn test_unpause_distribution_shift_vesting_extends_claim_window() {
+    let mut ctx = TestContext::new();
+    let current_ts = ctx.get_current_timestamp();
+    let claim_end_ts = current_ts + 86400 * 30;
+    let distribution_setup = CreateDirectDistributionSetup::builder(&mut ctx).claim_window(0, claim_end_ts).build();
+
+    // Ends a day before the window closes; a two-day shift alone would push it past the close
+    let mut recipient_setup = AddDirectRecipientSetup::from_distribution_setup(&mut ctx, &distribution_setup);
+    let vesting_end_ts = claim_end_ts - 86400;
+    recipient_setup.schedule = VestingSchedule::Linear { start_ts: current_ts, end_ts: vesting_end_ts };
+    let claim = ClaimDirectSetup::from_recipient_setup(&mut ctx, &recipient_setup, false);
+
+    let distribution_pda = distribution_setup.distribution_pda;
+    PauseDistributionSetup::pause(&mut ctx, distribution_pda, &distribution_setup.authority);
+    let shift = 86400 * 2;
+    ctx.warp_to_timestamp(current_ts + shift);
+    UnpauseDistributionSetup::unpause(&mut ctx, distribution_pda, &distribution_setup.authority, true);
+
+    let account = ctx.get_account(&distribution_pda).unwrap();
+    let distribution = DirectDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.paused, 0);
+    assert_eq!(distribution.vesting_offset, shift);
+    assert_eq!(distribution.claim_end_ts, claim_end_ts + shift);
+
+    // The shifted schedule fully vests after the original close, and is still claimable in full
+    ctx.warp_to_timestamp(vesting_end_ts + shift);
+    claim.build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert_eq!(ctx.get_token_balance(&claim.recipient_token_account), claim.amount);
+}
+
+#[test]
+fn test_unpause_distribution_shift_vesting_skips_later_recipients() {
+    let mut ctx = TestContext::new();
+    let recipient_setup = AddDirectRecipientSetup::new(&mut ctx);
+    let claim = ClaimDirectSetup::from_recipient_setup(&mut ctx, &recipient_setup, false);
+    let quarter = (claim.end_ts - claim.start_ts) / 4;
+
+    let paused_at = claim.start_ts + quarter;
+    ctx.warp_to_timestamp(paused_at);
+    PauseDistributionSetup::pause(&mut ctx, claim.distribution_pda, &recipient_setup.authority);
+
+    let unpause_ts = claim.start_ts + 2 * quarter;
+    ctx.warp_to_timestamp(unpause_ts);
+    UnpauseDistributionSetup::unpause(&mut ctx, claim.distribution_pda, &recipient_setup.authority, true);
+
+    // Added after the pause, so its schedule runs on the real clock
+    let recipient = ctx.create_funded_keypair();
+    let (recipient_pda, recipient_bump) = find_direct_recipient_pda(&claim.distribution_pda, &recipient.pubkey());
+    let late_setup = AddDirectRecipientSetup {
+        recipient,
+        recipient_pda,
+        recipient_bump,
+        schedule: VestingSchedule::Linear { start_ts: unpause_ts, end_ts: unpause_ts + 4 * quarter },
+        ..recipient_setup
+    };
+    ctx.set_token_balance(&late_setup.authority_token_account, late_setup.amount);
+    let late_claim = ClaimDirectSetup::from_recipient_setup(&mut ctx, &late_setup, false);
+
+    let account = ctx.get_account(&late_claim.recipient_pda).unwrap();
+    let recipient = DirectRecipient::from_bytes(&account.data).unwrap();
+    assert_eq!(recipient.base_vesting_offset, unpause_ts - paused_at);
+
+    let claim_ts = unpause_ts + 2 * quarter;
+    ctx.warp_to_timestamp(claim_ts);
+    late_claim.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let expected = expected_linear_unlock(late_claim.amount, late_claim.start_ts, late_claim.end_ts, claim_ts);
+    assert_eq!(ctx.get_token_balance(&late_claim.recipient_token_account), expected);
+}
+
+#[test]
+fn test_unpause_distribution_shift_vesting_merkle() {
+    let mut ctx = TestContext::new();
+    let claim = ClaimMerkleSetup::builder(&mut ctx).warp_to_end(false).build();
+    let quarter = (claim.end_ts() - claim.start_ts()) / 4;
+
+    let paused_at = claim.start_ts() + quarter;
+    ctx.warp_to_timestamp(paused_at);
+    PauseDistributionSetup::pause(&mut ctx, claim.distribution_pda, &claim.authority);
+
+    let unpause_ts = claim.start_ts() + 2 * quarter;
+    ctx.warp_to_timestamp(unpause_ts);
+    UnpauseDistributionSetup::unpause(&mut ctx, claim.distribution_pda, &claim.authority, true);
+
+    let test_ix = claim.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let expected = expected_linear_unlock(claim.total_amount, claim.start_ts(), claim.end_ts(), paused_at);
+    assert_eq!(ctx.get_token_balance(&claim.claimant_token_account), expected);
+}
@@ -0,0 +1,130 @@
+use rewards_program_client::{accounts::MerkleDistribution, types::VestingSchedule};
+use solana_sdk::signature::Signer;
+
+use crate::fixtures::{ClaimMerkleSetup, UpdateMerkleRootFixture, UpdateMerkleRootSetup};
+use crate::utils::{
+    assert_rewards_error, test_missing_signer, test_not_writable, test_truncated_data, test_wrong_current_program,
+    MerkleLeaf, MerkleTree, RewardsError, TestContext,
+};
+
+#[test]
+fn test_update_merkle_root_missing_authority_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<UpdateMerkleRootFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_update_merkle_root_distribution_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<UpdateMerkleRootFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_update_merkle_root_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<UpdateMerkleRootFixture>(&mut ctx);
+}
+
+#[test]
+fn test_update_merkle_root_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<UpdateMerkleRootFixture>(&mut ctx);
+}
+
+#[test]
+fn test_update_merkle_root_success() {
+    let mut ctx = TestContext::new();
+    let setup = UpdateMerkleRootSetup::new(&mut ctx);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let account = ctx.get_account(&setup.distribution_pda).unwrap();
+    let distribution = MerkleDistribution::from_bytes(&account.data).unwrap();
+    assert_eq!(distribution.merkle_root, setup.merkle_root);
+    assert_eq!(distribution.total_amount, setup.total_amount);
+    assert_eq!(distribution.epoch, 1);
+}
+
+#[test]
+fn test_update_merkle_root_unauthorized() {
+    let mut ctx = TestContext::new();
+    let setup = UpdateMerkleRootSetup::new(&mut ctx);
+    let wrong_authority = ctx.create_funded_keypair();
+
+    let error = setup.build_instruction_with_authority(&wrong_authority).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::UnauthorizedAuthority);
+}
+
+#[test]
+fn test_update_merkle_root_same_epoch_fails() {
+    let mut ctx = TestContext::new();
+    let mut setup = UpdateMerkleRootSetup::new(&mut ctx);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    setup.merkle_root = [3u8; 32];
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidEpoch);
+}
+
+#[test]
+fn test_update_merkle_root_zero_total_amount_fails() {
+    let mut ctx = TestContext::new();
+    let mut setup = UpdateMerkleRootSetup::new(&mut ctx);
+    setup.total_amount = 0;
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidAmount);
+}
+
+#[test]
+fn test_update_merkle_root_claimed_amount_carries_over() {
+    let mut ctx = TestContext::new();
+    let mut claim_setup = ClaimMerkleSetup::builder(&mut ctx).immediate().build();
+
+    claim_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    let balance_after_first_claim = ctx.get_token_balance(&claim_setup.claimant_token_account);
+    assert_eq!(balance_after_first_claim, claim_setup.total_amount);
+
+    // New cumulative leaf: claimant is now owed 1.5x their original allocation in total
+    let cumulative_amount = claim_setup.total_amount + claim_setup.total_amount / 2;
+    let mut leaves = claim_setup.merkle_tree.leaves.clone();
+    leaves[0] = MerkleLeaf::new(claim_setup.claimant.pubkey(), cumulative_amount, VestingSchedule::Immediate);
+    let new_tree = MerkleTree::new(leaves);
+
+    let update_setup = UpdateMerkleRootSetup {
+        authority: claim_setup.authority.insecure_clone(),
+        distribution_pda: claim_setup.distribution_pda,
+        merkle_root: new_tree.root,
+        total_amount: cumulative_amount + claim_setup.total_amount,
+        epoch: 1,
+    };
+    update_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    claim_setup.total_amount = cumulative_amount;
+    claim_setup.proof = new_tree.get_proof_for_claimant(&claim_setup.claimant.pubkey()).unwrap();
+    claim_setup.merkle_tree = new_tree;
+    ctx.advance_slot();
+    claim_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    // Only the delta between the cumulative leaf and the prior claim is paid out
+    let balance_after_second_claim = ctx.get_token_balance(&claim_setup.claimant_token_account);
+    assert_eq!(balance_after_second_claim, cumulative_amount);
+}
+
+#[test]
+fn test_update_merkle_root_old_proof_rejected() {
+    let mut ctx = TestContext::new();
+    let claim_setup = ClaimMerkleSetup::builder(&mut ctx).immediate().build();
+
+    let update_setup = UpdateMerkleRootSetup {
+        authority: claim_setup.authority.insecure_clone(),
+        distribution_pda: claim_setup.distribution_pda,
+        merkle_root: [9u8; 32],
+        total_amount: claim_setup.total_amount,
+        epoch: 1,
+    };
+    update_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let error = claim_setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_rewards_error(error, RewardsError::InvalidMerkleProof);
+}
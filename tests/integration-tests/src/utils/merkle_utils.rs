@@ -1,12 +1,12 @@
-use rewards_program_client::types::VestingSchedule;
+use rewards_program_client::types::{TrancheInterpolation, VestingSchedule};
 use solana_sdk::pubkey::Pubkey;
 use tiny_keccak::{Hasher, Keccak};
 
 const LEAF_PREFIX: &[u8] = &[0];
 
 /// Maximum byte length of a leaf's inner hash input:
-/// 32 (claimant) + 8 (total_amount) + 25 (max schedule = CliffLinear)
-const MAX_LEAF_DATA_LEN: usize = 65;
+/// 32 (claimant) + 8 (total_amount) + 166 (max schedule = Tranches with 16 points)
+const MAX_LEAF_DATA_LEN: usize = 206;
 
 fn schedule_to_bytes(schedule: &VestingSchedule) -> Vec<u8> {
     match schedule {
@@ -32,6 +32,37 @@ fn schedule_to_bytes(schedule: &VestingSchedule) -> Vec<u8> {
             bytes.extend_from_slice(&end_ts.to_le_bytes());
             bytes
         }
+        VestingSchedule::Stepped { start_ts, period_secs, num_periods } => {
+            let mut bytes = Vec::with_capacity(21);
+            bytes.push(4);
+            bytes.extend_from_slice(&start_ts.to_le_bytes());
+            bytes.extend_from_slice(&period_secs.to_le_bytes());
+            bytes.extend_from_slice(&num_periods.to_le_bytes());
+            bytes
+        }
+        VestingSchedule::CliffStepped { start_ts, cliff_ts, period_secs, num_periods } => {
+            let mut bytes = Vec::with_capacity(29);
+            bytes.push(5);
+            bytes.extend_from_slice(&start_ts.to_le_bytes());
+            bytes.extend_from_slice(&cliff_ts.to_le_bytes());
+            bytes.extend_from_slice(&period_secs.to_le_bytes());
+            bytes.extend_from_slice(&num_periods.to_le_bytes());
+            bytes
+        }
+        VestingSchedule::Tranches { interpolation, points } => {
+            let mut bytes = Vec::with_capacity(6 + points.len() * 10);
+            bytes.push(6);
+            bytes.push(match interpolation {
+                TrancheInterpolation::Step => 0,
+                TrancheInterpolation::Linear => 1,
+            });
+            bytes.extend_from_slice(&(points.len() as u32).to_le_bytes());
+            for point in points {
+                bytes.extend_from_slice(&point.timestamp.to_le_bytes());
+                bytes.extend_from_slice(&point.cumulative_bps.to_le_bytes());
+            }
+            bytes
+        }
     }
 }
 
@@ -63,6 +94,56 @@ pub fn compute_leaf_hash(claimant: &Pubkey, total_amount: u64, schedule: &Vestin
     keccak256(&outer_data)
 }
 
+/// Compute the merkle leaf hash for a Bitmap-mode claim.
+/// Matches the on-chain computation in merkle_utils.rs
+pub fn compute_bitmap_leaf_hash(claimant: &Pubkey, index: u32, amount: u64) -> [u8; 32] {
+    let mut inner_data = [0u8; 32 + 4 + 8];
+    inner_data[0..32].copy_from_slice(claimant.as_ref());
+    inner_data[32..36].copy_from_slice(&index.to_le_bytes());
+    inner_data[36..44].copy_from_slice(&amount.to_le_bytes());
+
+    let inner_hash = keccak256(&inner_data);
+
+    let mut outer_data = [0u8; 1 + 32];
+    outer_data[0..1].copy_from_slice(LEAF_PREFIX);
+    outer_data[1..33].copy_from_slice(&inner_hash);
+
+    keccak256(&outer_data)
+}
+
+/// Compute the leaf hash for a compressed distribution recipient.
+/// Matches the on-chain computation in merkle_utils.rs
+pub fn compute_compressed_leaf_hash(
+    recipient: &Pubkey,
+    total_amount: u64,
+    claimed_amount: u64,
+    base_vesting_offset: i64,
+    schedule: &VestingSchedule,
+) -> [u8; 32] {
+    let mut inner_data = Vec::with_capacity(56 + MAX_LEAF_DATA_LEN);
+    inner_data.extend_from_slice(recipient.as_ref());
+    inner_data.extend_from_slice(&total_amount.to_le_bytes());
+    inner_data.extend_from_slice(&claimed_amount.to_le_bytes());
+    inner_data.extend_from_slice(&base_vesting_offset.to_le_bytes());
+    inner_data.extend_from_slice(&schedule_to_bytes(schedule));
+
+    let inner_hash = keccak256(&inner_data);
+
+    let mut outer_data = [0u8; 1 + 32];
+    outer_data[0..1].copy_from_slice(LEAF_PREFIX);
+    outer_data[1..33].copy_from_slice(&inner_hash);
+
+    keccak256(&outer_data)
+}
+
+/// Hash two nodes together in position order (left first), as the concurrent tree does.
+pub fn hash_tree_nodes(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut data = [0u8; 64];
+    data[0..32].copy_from_slice(left);
+    data[32..64].copy_from_slice(right);
+    keccak256(&data)
+}
+
 /// Hash two nodes together in sorted order (smaller first).
 pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
     let mut data = [0u8; 64];
@@ -90,6 +171,12 @@ impl MerkleLeaf {
         let leaf_hash = compute_leaf_hash(&claimant, total_amount, &schedule);
         Self { claimant, total_amount, schedule, leaf_hash }
     }
+
+    /// Leaf for a Bitmap-mode distribution; always vests immediately
+    pub fn bitmap(claimant: Pubkey, index: u32, amount: u64) -> Self {
+        let leaf_hash = compute_bitmap_leaf_hash(&claimant, index, amount);
+        Self { claimant, total_amount: amount, schedule: VestingSchedule::Immediate, leaf_hash }
+    }
 }
 
 /// A simple merkle tree builder for testing
@@ -193,3 +280,76 @@ impl MerkleTree {
         self.leaves.iter().find(|l| l.claimant == *claimant)
     }
 }
+
+/// Depth of a compressed distribution's on-chain tree
+pub const COMPRESSED_TREE_DEPTH: usize = 16;
+
+/// A compressed distribution recipient; only its hash lives on-chain
+#[derive(Clone, Debug)]
+pub struct CompressedLeaf {
+    pub recipient: Pubkey,
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub base_vesting_offset: i64,
+    pub schedule: VestingSchedule,
+}
+
+impl CompressedLeaf {
+    pub fn leaf_hash(&self) -> [u8; 32] {
+        compute_compressed_leaf_hash(
+            &self.recipient,
+            self.total_amount,
+            self.claimed_amount,
+            self.base_vesting_offset,
+            &self.schedule,
+        )
+    }
+}
+
+/// Fixed-depth mirror of a compressed distribution's tree, padded with empty leaves
+#[derive(Clone, Debug, Default)]
+pub struct CompressedTree {
+    pub leaves: Vec<CompressedLeaf>,
+}
+
+impl CompressedTree {
+    pub fn push(&mut self, leaf: CompressedLeaf) -> u32 {
+        self.leaves.push(leaf);
+        (self.leaves.len() - 1) as u32
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.levels().last().unwrap()[0]
+    }
+
+    /// Sibling hashes from the leaf at `index` up to the root
+    pub fn get_proof(&self, index: u32) -> Vec<[u8; 32]> {
+        assert!((index as usize) < self.leaves.len(), "Index out of bounds");
+
+        let levels = self.levels();
+        let mut empty = [0u8; 32];
+        let mut proof = Vec::with_capacity(COMPRESSED_TREE_DEPTH);
+        for (level, nodes) in levels.iter().take(COMPRESSED_TREE_DEPTH).enumerate() {
+            let sibling = ((index as usize) >> level) ^ 1;
+            proof.push(nodes.get(sibling).copied().unwrap_or(empty));
+            empty = hash_tree_nodes(&empty, &empty);
+        }
+        proof
+    }
+
+    fn levels(&self) -> Vec<Vec<[u8; 32]>> {
+        let mut levels = vec![self.leaves.iter().map(CompressedLeaf::leaf_hash).collect::<Vec<_>>()];
+        let mut empty = [0u8; 32];
+        for _ in 0..COMPRESSED_TREE_DEPTH {
+            let level = levels.last().unwrap();
+            let next_level = if level.is_empty() {
+                vec![hash_tree_nodes(&empty, &empty)]
+            } else {
+                level.chunks(2).map(|pair| hash_tree_nodes(&pair[0], pair.get(1).unwrap_or(&empty))).collect()
+            };
+            levels.push(next_level);
+            empty = hash_tree_nodes(&empty, &empty);
+        }
+        levels
+    }
+}
@@ -6,6 +6,13 @@ const DIRECT_RECIPIENT_SEED: &[u8] = b"direct_recipient";
 const MERKLE_DISTRIBUTION_SEED: &[u8] = b"merkle_distribution";
 const MERKLE_CLAIM_SEED: &[u8] = b"merkle_claim";
 const MERKLE_REVOCATION_SEED: &[u8] = b"merkle_revocation";
+const CLAIM_DELEGATE_SEED: &[u8] = b"claim_delegate";
+const MERKLE_REDIRECT_SEED: &[u8] = b"merkle_redirect";
+const MERKLE_CLAIM_BITMAP_SEED: &[u8] = b"merkle_claim_bitmap";
+const NATIVE_VAULT_SEED: &[u8] = b"native_vault";
+const BASKET_DISTRIBUTION_SEED: &[u8] = b"basket_distribution";
+const BASKET_RECIPIENT_SEED: &[u8] = b"basket_recipient";
+const COMPRESSED_DISTRIBUTION_SEED: &[u8] = b"compressed_distribution";
 const EVENT_AUTHORITY_SEED: &[u8] = b"event_authority";
 
 pub fn find_direct_distribution_pda(mint: &Pubkey, authority: &Pubkey, seeds: &Pubkey) -> (Pubkey, u8) {
@@ -43,3 +50,40 @@ pub fn find_merkle_revocation_pda(distribution: &Pubkey, claimant: &Pubkey) -> (
         &REWARDS_PROGRAM_ID,
     )
 }
+
+pub fn find_claim_delegate_pda(distribution: &Pubkey, recipient: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CLAIM_DELEGATE_SEED, distribution.as_ref(), recipient.as_ref()], &REWARDS_PROGRAM_ID)
+}
+
+pub fn find_merkle_redirect_pda(distribution: &Pubkey, claimant: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MERKLE_REDIRECT_SEED, distribution.as_ref(), claimant.as_ref()], &REWARDS_PROGRAM_ID)
+}
+
+pub fn find_merkle_claim_bitmap_pda(distribution: &Pubkey, chunk_index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MERKLE_CLAIM_BITMAP_SEED, distribution.as_ref(), &chunk_index.to_le_bytes()],
+        &REWARDS_PROGRAM_ID,
+    )
+}
+
+pub fn find_native_vault_pda(distribution: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[NATIVE_VAULT_SEED, distribution.as_ref()], &REWARDS_PROGRAM_ID)
+}
+
+pub fn find_basket_distribution_pda(authority: &Pubkey, seeds: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BASKET_DISTRIBUTION_SEED, authority.as_ref(), seeds.as_ref()], &REWARDS_PROGRAM_ID)
+}
+
+pub fn find_basket_recipient_pda(distribution: &Pubkey, recipient: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BASKET_RECIPIENT_SEED, distribution.as_ref(), recipient.as_ref()],
+        &REWARDS_PROGRAM_ID,
+    )
+}
+
+pub fn find_compressed_distribution_pda(mint: &Pubkey, authority: &Pubkey, seeds: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[COMPRESSED_DISTRIBUTION_SEED, mint.as_ref(), authority.as_ref(), seeds.as_ref()],
+        &REWARDS_PROGRAM_ID,
+    )
+}
@@ -1,4 +1,5 @@
 use litesvm::LiteSVM;
+use rewards_program_client::events::{decode_events, RewardsEvent};
 use solana_program::clock::Clock;
 use solana_sdk::{
     account::Account,
@@ -85,22 +86,42 @@ impl TestContext {
         self.send_transaction_inner(instruction, signers).expect_err("Transaction should fail")
     }
 
+    /// Send a transaction and decode the Rewards events it emitted through `EmitEvent`
+    pub fn send_transaction_with_events(
+        &mut self,
+        instruction: Instruction,
+        signers: &[&Keypair],
+    ) -> Result<Vec<RewardsEvent>, Box<dyn std::error::Error>> {
+        let transaction = self.sign_transaction(instruction, signers);
+        let account_keys = transaction.message.account_keys.clone();
+        let meta = self.svm.send_transaction(transaction).map_err(|e| format!("Transaction failed: {:?}", e.err))?;
+
+        let inner_instructions = meta.inner_instructions.iter().flatten().map(|inner| {
+            let instruction = &inner.instruction;
+            (&account_keys[instruction.program_id_index as usize], instruction.data.as_slice())
+        });
+        Ok(decode_events(inner_instructions)?)
+    }
+
     fn send_transaction_inner(
         &mut self,
         instruction: Instruction,
         signers: &[&Keypair],
     ) -> Result<u64, TransactionError> {
+        let transaction = self.sign_transaction(instruction, signers);
+        self.svm.send_transaction(transaction).map(|meta| meta.compute_units_consumed).map_err(|e| e.err)
+    }
+
+    fn sign_transaction(&self, instruction: Instruction, signers: &[&Keypair]) -> Transaction {
         let mut all_signers = vec![&self.payer as &dyn Signer];
         all_signers.extend(signers.iter().map(|k| *k as &dyn Signer));
 
-        let transaction = Transaction::new_signed_with_payer(
+        Transaction::new_signed_with_payer(
             &[instruction],
             Some(&self.payer.pubkey()),
             &all_signers,
             self.svm.latest_blockhash(),
-        );
-
-        self.svm.send_transaction(transaction).map(|meta| meta.compute_units_consumed).map_err(|e| e.err)
+        )
     }
 
     pub fn get_account(&self, pubkey: &Pubkey) -> Option<Account> {
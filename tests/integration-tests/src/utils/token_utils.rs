@@ -6,6 +6,7 @@ use solana_sdk::{
     signature::{Keypair, Signer},
 };
 use spl_associated_token_account::{get_associated_token_address, get_associated_token_address_with_program_id};
+use spl_token_2022::extension::{AccountType, ExtensionType};
 use spl_token_interface::state::{Account as TokenAccount, AccountState, Mint};
 
 use super::TestContext;
@@ -114,6 +115,63 @@ impl TestContext {
             .unwrap();
     }
 
+    /// Creates a Token-2022 mint carrying a single zero-initialized extension.
+    pub fn create_token_2022_mint_with_extension(
+        &mut self,
+        mint: &Keypair,
+        mint_authority: &Pubkey,
+        decimals: u8,
+        extension_type: ExtensionType,
+    ) {
+        let mint_state = Mint {
+            mint_authority: COption::Some(*mint_authority),
+            supply: 0,
+            decimals,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+
+        let extension_len = extension_type.try_get_type_len().unwrap();
+        let account_len = ExtensionType::try_calculate_account_len::<Mint>(&[extension_type]).unwrap();
+
+        // Base mint, padded to the token account length, then account type and TLV entry
+        let mut data = vec![0u8; account_len];
+        mint_state.pack_into_slice(&mut data[..Mint::LEN]);
+        data[TokenAccount::LEN] = AccountType::Mint as u8;
+        let tlv_start = TokenAccount::LEN + 1;
+        data[tlv_start..tlv_start + 2].copy_from_slice(&(extension_type as u16).to_le_bytes());
+        data[tlv_start + 2..tlv_start + 4].copy_from_slice(&(extension_len as u16).to_le_bytes());
+
+        self.svm
+            .set_account(
+                mint.pubkey(),
+                Account {
+                    lamports: self.svm.minimum_balance_for_rent_exemption(account_len),
+                    data,
+                    owner: TOKEN_2022_PROGRAM_ID,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            )
+            .unwrap();
+    }
+
+    /// Sets the fee on a mint created with a TransferFeeConfig extension, for every epoch.
+    pub fn set_transfer_fee(&mut self, mint: &Pubkey, basis_points: u16, maximum_fee: u64) {
+        let mut account = self.svm.get_account(mint).expect("Mint not found");
+
+        // TransferFeeConfig value: two authorities and withheld_amount, then older and newer fees
+        let value_start = TokenAccount::LEN + 1 + 4;
+        for fee_offset in [72, 90] {
+            let fee_start = value_start + fee_offset;
+            account.data[fee_start..fee_start + 8].copy_from_slice(&0u64.to_le_bytes());
+            account.data[fee_start + 8..fee_start + 16].copy_from_slice(&maximum_fee.to_le_bytes());
+            account.data[fee_start + 16..fee_start + 18].copy_from_slice(&basis_points.to_le_bytes());
+        }
+
+        self.svm.set_account(*mint, account).unwrap();
+    }
+
     pub fn create_token_2022_account(&mut self, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
         self.create_token_2022_account_with_balance(owner, mint, 0)
     }
@@ -1,3 +1,4 @@
+use rewards_program_client::events::RewardsEvent;
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
@@ -32,6 +33,12 @@ impl TestInstruction {
         cus
     }
 
+    /// Send this instruction expecting it to succeed, returning the decoded events
+    pub fn send_expect_events(self, ctx: &mut TestContext) -> Vec<RewardsEvent> {
+        let signer_refs: Vec<&Keypair> = self.signers.iter().collect();
+        ctx.send_transaction_with_events(self.instruction, &signer_refs).expect("Transaction should succeed")
+    }
+
     /// Send this instruction expecting it to fail, returning the error
     pub fn send_expect_error(self, ctx: &mut TestContext) -> TransactionError {
         let signer_refs: Vec<&Keypair> = self.signers.iter().collect();